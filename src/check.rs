@@ -0,0 +1,91 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use similar::TextDiff;
+
+/// Compares the rendered files against what's in the output directory,
+///  printing a unified diff for each file which differs, and returns
+///  how many did. Nothing on disk is touched.
+///
+/// Files in the generated directory which would no longer be rendered
+///  count as differing too, since regenerating would delete them.
+pub fn diff_output (
+    output_dir: &Path,
+    generated_dir: &Path,
+
+    files: &BTreeMap<String, String>
+) -> Result<usize> {
+    let mut differing = 0;
+
+    for (file_name, rendered) in files.iter() {
+        let path = output_dir.join(file_name);
+        let existing = if path.exists() {
+            std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?
+        } else {
+            String::new()
+        };
+
+        if existing != *rendered {
+            differing += 1;
+            print_diff(&path, &existing, rendered);
+        }
+    }
+
+    // Look for stale files, which the write would remove
+    if generated_dir.exists() {
+        let rendered_paths = files.keys()
+            .map(|file_name| output_dir.join(file_name))
+            .collect::<BTreeSet<_>>();
+
+        let mut stale_paths = Vec::new();
+        for path in files_in(generated_dir)? {
+            if !rendered_paths.contains(&path) {
+                stale_paths.push(path);
+            }
+        }
+        stale_paths.sort();
+
+        for path in stale_paths {
+            let existing = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+
+            differing += 1;
+            eprintln!("Stale file {}, which would be removed", path.display());
+            print_diff(&path, &existing, "");
+        }
+    }
+
+    Ok(differing)
+}
+/// Every file in a directory and its subdirectories
+fn files_in ( dir: &Path ) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read {}", dir.display()))?
+    {
+        let path = entry.context("Failed to read a directory entry")?.path();
+        if path.is_dir() {
+            files.extend(files_in(&path)?);
+        } else if path.is_file() {
+            files.push(path);
+        }
+    }
+
+    Ok(files)
+}
+/// Prints the unified diff from the existing file to the rendered one
+fn print_diff (
+    path: &Path,
+
+    existing: &str,
+    rendered: &str
+) {
+    let path = path.display().to_string();
+    let diff = TextDiff::from_lines(existing, rendered);
+
+    eprint!("{}", diff.unified_diff()
+        .context_radius(3)
+        .header(&format!("a/{path}"), &format!("b/{path}")));
+}
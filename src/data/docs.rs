@@ -0,0 +1,279 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+/// Where the spec's relative links point
+const DOCS_ORIGIN: &str = "https://platform.openai.com";
+/// The longest a line of prose may be before it's wrapped
+const WRAP_WIDTH: usize = 100;
+/// HTML tags rustdoc renders, rather than warning about
+const HTML_TAGS: &[&str] = &[
+    "a", "b", "br", "code", "details", "div", "em", "hr", "i", "img", "kbd", "li",
+    "ol", "p", "pre", "span", "strong", "sub", "summary", "sup", "table", "td", "th",
+    "tr", "ul"
+];
+
+/// The start of a list item, with its marker
+static LIST_ITEM: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\s*([-*+]|\d+[.)])\s+").unwrap());
+/// A URL which isn't already a link's target or text
+static BARE_URL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(^|[^(<\["'=])(https?://[^\s<>()\[\]"']*[^\s<>()\[\]"'.,;:!?])"#).unwrap()
+});
+/// Words which would stop a wrapped line being part of its paragraph
+static BLOCK_START: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^([#>|=*+-]|\d+[.)]$)").unwrap());
+
+/// Renders a spec description as `///` lines, indented by `indent`
+pub fn doc_comment ( description: &str, indent: &str ) -> String {
+    sanitize(description).lines()
+        .map(|line| if line.is_empty() {
+            format!("{indent}///\n")
+        } else {
+            format!("{indent}/// {line}\n")
+        })
+        .collect()
+}
+
+/// Turns a spec description's Markdown into Markdown rustdoc is happy with:
+///
+/// - Code blocks are fenced and tagged `json` or `text`, so they aren't
+///   compiled as doctests
+/// - Relative links are made absolute, and bare URLs are made links
+/// - Brackets which aren't links are escaped, so they aren't taken as
+///   intra-doc links, as are `<` which don't start an HTML tag
+/// - Long lines are wrapped
+pub fn sanitize ( description: &str ) -> String {
+    let lines = description.lines()
+        .map(|line| line.trim_end())
+        .collect::<Vec<&str>>();
+    let mut sanitized: Vec<String> = Vec::new();
+
+    let mut index = 0;
+    while index < lines.len() {
+        let line = lines[index];
+        let trimmed = line.trim_start();
+        let indentation = &line[..line.len() - trimmed.len()];
+
+        // Fenced code blocks, which are kept as they are apart from their tag
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            let fence_char = trimmed.chars().next().unwrap_or('`');
+            let fence = trimmed.chars()
+                .take_while(|character| *character == fence_char)
+                .collect::<String>();
+            let info = trimmed[fence.len()..].trim();
+
+            let end = lines[index + 1..].iter()
+                .position(|line| line.trim_start().starts_with(&fence))
+                .map(|position| index + 1 + position)
+                .unwrap_or(lines.len());
+            let body = &lines[index + 1..end];
+
+            let language = match info {
+                "" | "rust" => code_language(body),
+                info => info
+            };
+            sanitized.push(format!("{indentation}{fence}{language}"));
+            sanitized.extend(body.iter().map(|line| line.to_string()));
+            sanitized.push(format!("{indentation}{fence}"));
+
+            index = end + 1;
+            continue;
+        }
+
+        // Indented code blocks, which rustdoc would compile, unless
+        //  they're the continuation of a list item
+        let after_blank = sanitized.last().is_none_or(|line| line.trim().is_empty());
+        if is_indented_code(line) && after_blank && !continues_list(&lines[..index]) {
+            let end = lines[index..].iter()
+                .position(|line| !line.trim().is_empty() && !is_indented_code(line))
+                .map(|position| index + position)
+                .unwrap_or(lines.len());
+            let mut body = lines[index..end].to_vec();
+
+            // Blank lines after the block belong to what follows
+            let mut trailing_blank_lines = 0;
+            while body.last().is_some_and(|line| line.trim().is_empty()) {
+                body.pop();
+                trailing_blank_lines += 1;
+            }
+
+            sanitized.push(format!("```{}", code_language(&body)));
+            sanitized.extend(body.iter().map(|line| dedent(line).to_string()));
+            sanitized.push("```".to_string());
+            sanitized.extend(std::iter::repeat_n(String::new(), trailing_blank_lines));
+
+            index = end;
+            continue;
+        }
+
+        // Tables and headings can't be wrapped
+        let line = fix_prose(line);
+        if trimmed.starts_with('|') || trimmed.starts_with('#') {
+            sanitized.push(line);
+        } else {
+            sanitized.extend(wrap(&line));
+        }
+
+        index += 1;
+    }
+
+    sanitized.join("\n")
+}
+
+/// `json` for a code block which looks like JSON, otherwise `text`
+fn code_language ( body: &[&str] ) -> &'static str {
+    let first = body.iter()
+        .map(|line| line.trim())
+        .find(|line| !line.is_empty());
+
+    match first {
+        Some(first) if first.starts_with('{') || first.starts_with('[') => "json",
+        _ => "text"
+    }
+}
+fn is_indented_code ( line: &str ) -> bool {
+    line.starts_with("    ") || line.starts_with('\t')
+}
+fn dedent ( line: &str ) -> &str {
+    line.strip_prefix("    ")
+        .or_else(|| line.strip_prefix('\t'))
+        .unwrap_or(line)
+}
+/// Whether the last non-blank line is part of a list item
+fn continues_list ( lines: &[&str] ) -> bool {
+    lines.iter()
+        .rev()
+        .find(|line| !line.trim().is_empty())
+        .is_some_and(|line| LIST_ITEM.is_match(line) || line.starts_with(' '))
+}
+
+/// Fixes the links, brackets and HTML in a line of prose, leaving its
+///  code spans alone
+fn fix_prose ( line: &str ) -> String {
+    let mut fixed = String::new();
+
+    // Split the line into prose and code spans, by runs of backticks
+    let mut rest = line;
+    while !rest.is_empty() {
+        let Some(start) = rest.find('`') else {
+            fixed.push_str(&fix_prose_text(rest));
+            break;
+        };
+        fixed.push_str(&fix_prose_text(&rest[..start]));
+
+        let ticks = rest[start..].chars()
+            .take_while(|character| *character == '`')
+            .count();
+        let fence = "`".repeat(ticks);
+        match rest[start + ticks..].find(&fence) {
+            Some(end) => {
+                let end = start + ticks + end + ticks;
+                fixed.push_str(&rest[start..end]);
+                rest = &rest[end..];
+            },
+            None => {
+                // An unmatched backtick is just a backtick
+                fixed.push_str(&rest[start..start + ticks]);
+                rest = &rest[start + ticks..];
+            }
+        }
+    }
+
+    fixed
+}
+fn fix_prose_text ( text: &str ) -> String {
+    // Relative links
+    let text = text.replace("](/", &format!("]({DOCS_ORIGIN}/"))
+        .replace("href=\"/", &format!("href=\"{DOCS_ORIGIN}/"));
+
+    // Bare URLs, which aren't already a link's target or text
+    let text = BARE_URL.replace_all(&text, "$1<$2>");
+
+    // Brackets which aren't links, and `<` which aren't tags or autolinks
+    let mut escaped = String::new();
+    let characters = text.char_indices().collect::<Vec<(usize, char)>>();
+    let mut position = 0;
+    while position < characters.len() {
+        let (offset, character) = characters[position];
+        let previous = position.checked_sub(1).map(|previous| characters[previous].1);
+
+        match character {
+            '[' if previous != Some('\\') => {
+                // `[text](url)`, `[text][reference]` and `[reference]: url` are links
+                let closing = characters[position..].iter()
+                    .position(|(_offset, character)| *character == ']')
+                    .map(|closing| position + closing);
+                let is_link = closing
+                    .and_then(|closing| characters.get(closing + 1))
+                    .is_some_and(|(_offset, next)| ['(', '[', ':'].contains(next));
+
+                match closing {
+                    Some(closing) if !is_link => {
+                        escaped.push_str("\\[");
+                        escaped.extend(characters[position + 1..closing].iter().map(|(_offset, character)| *character));
+                        escaped.push_str("\\]");
+                        position = closing + 1;
+                    },
+                    _ => {
+                        escaped.push('[');
+                        position += 1;
+                    }
+                }
+            },
+            '<' => {
+                let after = &text[offset + 1..];
+                let name = after.strip_prefix('/').unwrap_or(after);
+                let tag = name.split(|character: char| !character.is_ascii_alphanumeric())
+                    .next()
+                    .unwrap_or("");
+                let is_tag = HTML_TAGS.contains(&tag.to_lowercase().as_str())
+                    && name[tag.len()..].starts_with(['>', ' ', '/']);
+                let is_autolink = after.starts_with("http://") || after.starts_with("https://");
+
+                if !is_tag && !is_autolink {
+                    escaped.push('\\');
+                }
+                escaped.push('<');
+                position += 1;
+            },
+            character => {
+                escaped.push(character);
+                position += 1;
+            }
+        }
+    }
+
+    escaped
+}
+
+/// Wraps a line of prose at `WRAP_WIDTH`, indenting a list item's
+///  continuation lines under its text
+fn wrap ( line: &str ) -> Vec<String> {
+    if line.chars().count() <= WRAP_WIDTH {
+        return vec![line.to_string()];
+    }
+
+    let continuation_indent = match LIST_ITEM.find(line) {
+        Some(marker) => " ".repeat(marker.as_str().chars().count()),
+        None => line[..line.len() - line.trim_start().len()].to_string()
+    };
+    let indentation = &line[..line.len() - line.trim_start().len()];
+    let mut words = line.trim_start().split(' ');
+
+    let mut wrapped = Vec::new();
+    let mut current = format!("{indentation}{}", words.next().unwrap_or(""));
+    for word in words {
+        let fits = current.chars().count() + 1 + word.chars().count() <= WRAP_WIDTH;
+        let can_break = !word.is_empty() && !BLOCK_START.is_match(word);
+
+        if !fits && can_break {
+            wrapped.push(current);
+            current = format!("{continuation_indent}{word}");
+        } else {
+            current.push(' ');
+            current.push_str(word);
+        }
+    }
+    wrapped.push(current);
+
+    wrapped
+}
@@ -1,13 +1,17 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 use convert_case::{Case, Casing};
 use regex::{Captures, Regex};
 
+use super::docs::doc_comment;
+
 #[derive(Debug, PartialEq)]
 pub enum EnumType {
     Standard,
     OneOf,
     AnyOf,
+    /// Internally tagged by the named property
+    Tagged(String),
 }
 #[derive(Debug)]
 pub struct Enum {
@@ -23,6 +27,29 @@ pub struct Enum {
     pub wanted_by: BTreeSet<String>,
     /// Which types this enum relies on
     pub relies_on: BTreeSet<String>,
+    /// The tag value of each variant, for `Tagged` enums
+    pub tags: BTreeMap<String, String>,
+}
+impl Enum {
+    /// The Rust variant a `Standard` enum's value is written as
+    pub fn variant_name ( value: &str ) -> String {
+        // Variants can't start with a number
+        if value.chars().next().is_some_and(|character| character.is_numeric()) {
+            return format!("Type{}", value);
+        }
+
+        let fix_numbers = Regex::new(r"(\d)-(\d)").unwrap();
+        let fixed_value = fix_numbers.replace_all(value, |caps: &Captures| {
+            format!("{}_{}", &caps[1], &caps[2])
+        }).replace(".", "_");
+
+        // Convert the value to `UpperCamel` case
+        fixed_value
+            .replace(".", "_")
+            .replace("[", "")
+            .replace("]", "")
+            .to_case(Case::UpperCamel)
+    }
 }
 impl std::fmt::Display for Enum {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -30,9 +57,7 @@ impl std::fmt::Display for Enum {
 
         // Write the enum description
         if let Some(ref description) = self.description {
-            for line in description.lines() {
-                body.push_str(&format!("/// {}\n", line));
-            }
+            body.push_str(&doc_comment(description, ""));
         }
 
         // Write derives and tell `serde` to convert 
@@ -44,6 +69,9 @@ impl std::fmt::Display for Enum {
         if self.enum_type == EnumType::OneOf || self.enum_type == EnumType::AnyOf {
             body.push_str("#[serde(untagged)]\n");
         }
+        if let EnumType::Tagged(ref tag) = self.enum_type {
+            body.push_str(&format!("#[serde(tag = \"{}\")]\n", tag));
+        }
 
         // Write the enum name
         body.push_str(&format!("pub enum {} {{\n", self.name.replace("[]", "")));
@@ -51,6 +79,16 @@ impl std::fmt::Display for Enum {
         // Write the enum values
         let mut printed_object = false; // Only print `Object(serde_json::Value)` once
         for value in self.values.iter() {
+            // If it's a tagged enum, rename the variant to its tag
+            if let EnumType::Tagged(_) = self.enum_type {
+                if let Some(tag) = self.tags.get(value) {
+                    body.push_str(&format!("\t#[serde(rename = \"{}\")]\n", tag));
+                }
+
+                body.push_str(&format!("\t{},\n", value));
+                continue;
+            }
+
             // If it's a `oneOf` or `anyOf` enum, don't adjust the value
             if self.enum_type == EnumType::OneOf || self.enum_type == EnumType::AnyOf {
                 if value == "Object(serde_json::Value)" {
@@ -64,18 +102,7 @@ impl std::fmt::Display for Enum {
                 continue;
             }
 
-            let fix_numbers = Regex::new(r"(\d)-(\d)").unwrap();
-            let fixed_value = fix_numbers.replace_all(value, |caps: &Captures| {
-                format!("{}_{}", &caps[1], &caps[2])
-            }).replace(".", "_");
-
-            // Convert the value to `UpperCamel` case
-            let converted = fixed_value
-                .replace(".", "_")
-                .replace("[", "")
-                .replace("]", "")
-                .to_case(Case::UpperCamel);
-
+            let converted = Enum::variant_name(value);
             
             let primitives = vec!(
                 "String(String)",
@@ -91,7 +118,7 @@ impl std::fmt::Display for Enum {
                 body.push_str(&value);
                 body.push_str("\")]\n");
 
-                body.push_str(&format!("\tType{},\n", value));
+                body.push_str(&format!("\t{},\n", converted));
 
                 continue;
             }
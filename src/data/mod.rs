@@ -1,2 +1,4 @@
 pub mod enums;
-pub mod objects;
\ No newline at end of file
+pub mod objects;
+pub mod docs;
+pub mod operations;
@@ -1,8 +1,15 @@
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::BTreeSet;
+
+use convert_case::{Case, Casing};
+use hashlink::LinkedHashMap;
+
+use super::docs::doc_comment;
 
 #[derive(Debug, Clone)]
 pub enum FieldValue {
     ExternalType(String),
+    /// A type which would otherwise contain itself
+    Boxed(String),
     Array(String),
     String,
     Integer,
@@ -12,6 +19,7 @@ impl std::fmt::Display for FieldValue {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             FieldValue::ExternalType(value) => write!(f, "{}", value.replace("[]", "")),
+            FieldValue::Boxed(value) => write!(f, "Box<{}>", value.replace("[]", "")),
             FieldValue::Array(value) => write!(f, "Vec<{}>", value.replace("[]", "")),
             FieldValue::String => write!(f, "String"),
             FieldValue::Integer => write!(f, "i64"),
@@ -19,6 +27,39 @@ impl std::fmt::Display for FieldValue {
         }
     }
 }
+/// The order a struct's fields are written in
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum FieldOrder {
+    /// As the properties are declared in the spec
+    #[default]
+    Spec,
+    /// Required fields first, each half still in the spec's order
+    RequiredFirst,
+}
+/// Which builder API, if any, is generated for a struct
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Builders {
+    /// No builder, only the struct literal
+    #[default]
+    None,
+    /// A `new` constructor taking the required fields, with
+    ///  chainable setters for the optional ones
+    Constructor,
+    /// A separate builder whose `build` only exists once every
+    ///  required field has been set
+    Typestate,
+}
+/// How a generated struct treats keys which aren't in the spec
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum UnknownFields {
+    /// Silently drop them, `serde`'s default
+    #[default]
+    Drop,
+    /// Collect them into an `extra` map so they round-trip
+    Preserve,
+    /// Fail deserialization, for contract tests
+    Deny,
+}
 #[derive(Debug, Clone)]
 pub struct Field {
     /// The description of the field
@@ -27,6 +68,27 @@ pub struct Field {
     pub value: FieldValue,
     /// Whether or not the field is required
     pub required: bool,
+    /// Whether the field can be `null`
+    pub nullable: bool,
+}
+impl Field {
+    /// The type a required field is written with, which is an `Option`
+    ///  when the field can be `null`
+    fn required_type ( &self ) -> String {
+        if self.nullable {
+            format!("Option<{}>", self.value)
+        } else {
+            self.value.to_string()
+        }
+    }
+}
+/// A Rust example of building an object, converted from the spec
+#[derive(Debug, Clone)]
+pub struct Example {
+    /// What the example shows, when an operation has several
+    pub title: Option<String>,
+    /// The example's code, including any hidden `#` lines
+    pub code: String,
 }
 #[derive(Debug)]
 pub struct Object {
@@ -34,12 +96,211 @@ pub struct Object {
     pub name: String,
     /// The description of the object
     pub description: Option<String>,
-    /// The properties of the object
-    pub properties: BTreeMap<String, Field>,
+    /// The properties of the object, in the spec's declaration order
+    pub properties: LinkedHashMap<String, Field>,
     /// Which paths wants this object
     pub wanted_by: BTreeSet<String>,
     /// Which types this object relies on
     pub relies_on: BTreeSet<String>,
+    /// How unknown keys are handled when deserializing
+    pub unknown_fields: UnknownFields,
+    /// Which builder API to generate alongside the struct
+    pub builders: Builders,
+    /// The order the struct's fields are written in
+    pub field_order: FieldOrder,
+    /// Examples of building the object, from the operations taking it
+    pub examples: Vec<Example>,
+}
+/// Converts a property key into a valid Rust identifier
+pub fn field_name ( key: &str ) -> String {
+    let key = key.replace("/", "_")
+        .replace("-", "_");
+
+    if key == "type" {
+        "r#type"
+    } else if key == "static" {
+        "r#static"
+    } else {
+        &key
+    }.replace("[]", "")
+}
+impl Object {
+    /// The properties in the order they're written in
+    fn ordered_properties ( &self ) -> Vec<(&String, &Field)> {
+        let mut properties = self.properties.iter()
+            .collect::<Vec<_>>();
+
+        // A stable sort keeps the spec's order within each half
+        if self.field_order == FieldOrder::RequiredFirst {
+            properties.sort_by_key(|(_key, value)| !value.required);
+        }
+
+        properties
+    }
+    /// Writes the `impl` blocks for the requested builder API
+    fn builder_body ( &self ) -> String {
+        let mut body = String::new();
+
+        let required = self.properties.iter()
+            .filter(|(_key, value)| value.required)
+            .map(|(key, value)| (field_name(key), value))
+            .collect::<Vec<_>>();
+        let optional = self.properties.iter()
+            .filter(|(_key, value)| !value.required)
+            .map(|(key, value)| (field_name(key), value))
+            .collect::<Vec<_>>();
+
+        // The chainable setters are identical for both builders
+        let mut setters = String::new();
+        for (key, value) in optional.iter() {
+            setters.push_str(&format!("\t/// Sets the optional `{}` field\n", key.replace("r#", "")));
+            setters.push_str(&format!("\tpub fn {}(mut self, {}: impl Into<{}>) -> Self {{\n", key, key, value.value));
+            setters.push_str(&format!("\t\tself.{} = Some({}.into());\n", key, key));
+            setters.push_str("\t\tself\n");
+            setters.push_str("\t}\n");
+        }
+        let extra = if self.unknown_fields == UnknownFields::Preserve {
+            "\t\t\textra: serde_json::Map::new(),\n"
+        } else {
+            ""
+        };
+
+        match self.builders {
+            Builders::None => { },
+            Builders::Constructor => {
+                body.push_str(&format!("impl {} {{\n", self.name));
+
+                // The constructor takes every required field
+                body.push_str(&format!("\t/// Creates a new `{}` from its required fields\n", self.name));
+                body.push_str("\t#[allow(clippy::too_many_arguments)]\n");
+                body.push_str(&format!("\tpub fn new({}) -> Self {{\n",
+                    required.iter()
+                        .map(|(key, value)| format!("{}: impl Into<{}>", key, value.required_type()))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ));
+                body.push_str("\t\tSelf {\n");
+                for (key, _value) in required.iter() {
+                    body.push_str(&format!("\t\t\t{}: {}.into(),\n", key, key));
+                }
+                for (key, _value) in optional.iter() {
+                    body.push_str(&format!("\t\t\t{}: None,\n", key));
+                }
+                body.push_str(extra);
+                body.push_str("\t\t}\n");
+                body.push_str("\t}\n");
+
+                body.push_str(&setters);
+                body.push('}');
+            },
+            Builders::Typestate => {
+                // Each required field gets a type parameter, which is `()`
+                //  until the field is set and the field's type afterwards
+                let builder_name = format!("{}Builder", self.name);
+                let states = required.iter()
+                    .map(|(key, _value)| format!("{}State", key.replace("r#", "").to_case(Case::UpperCamel)))
+                    .collect::<Vec<String>>();
+                let generics = |states: &[String]| if states.is_empty() {
+                    String::new()
+                } else {
+                    format!("<{}>", states.join(", "))
+                };
+
+                body.push_str(&format!("/// Builds a [`{}`], only allowing `build` once every required field is set\n", self.name));
+                body.push_str("#[derive(Debug, Clone)]\n");
+                body.push_str(&format!("pub struct {}{} {{\n", builder_name, generics(&states)));
+                for ((key, _value), state) in required.iter().zip(states.iter()) {
+                    body.push_str(&format!("\t{}: {},\n", key, state));
+                }
+                for (key, value) in optional.iter() {
+                    body.push_str(&format!("\t{}: Option<{}>,\n", key, value.value));
+                }
+                if !extra.is_empty() {
+                    body.push_str("\textra: serde_json::Map<String, serde_json::Value>,\n");
+                }
+                body.push_str("}\n");
+
+                // The entrypoint, with nothing set yet
+                let unset = required.iter()
+                    .map(|_| "()".to_string())
+                    .collect::<Vec<String>>();
+                body.push_str(&format!("impl {} {{\n", self.name));
+                body.push_str(&format!("\t/// Starts building a `{}`\n", self.name));
+                body.push_str(&format!("\tpub fn builder() -> {}{} {{\n", builder_name, generics(&unset)));
+                body.push_str(&format!("\t\t{} {{\n", builder_name));
+                for (key, _value) in required.iter() {
+                    body.push_str(&format!("\t\t\t{}: (),\n", key));
+                }
+                for (key, _value) in optional.iter() {
+                    body.push_str(&format!("\t\t\t{}: None,\n", key));
+                }
+                body.push_str(extra);
+                body.push_str("\t\t}\n");
+                body.push_str("\t}\n");
+                body.push_str("}\n");
+
+                // One setter per required field, moving its state from `()`
+                //  to the field's type while keeping every other state
+                for (index, (key, value)) in required.iter().enumerate() {
+                    let mut before = states.clone();
+                    before[index] = "()".to_string();
+                    let mut after = states.clone();
+                    after[index] = value.required_type();
+                    let free = states.iter()
+                        .enumerate()
+                        .filter(|(other_index, _state)| *other_index != index)
+                        .map(|(_other_index, state)| state.to_string())
+                        .collect::<Vec<String>>();
+
+                    body.push_str(&format!("impl{} {}{} {{\n", generics(&free), builder_name, generics(&before)));
+                    body.push_str(&format!("\t/// Sets the required `{}` field\n", key.replace("r#", "")));
+                    body.push_str(&format!("\tpub fn {}(self, {}: impl Into<{}>) -> {}{} {{\n", key, key, value.required_type(), builder_name, generics(&after)));
+                    body.push_str(&format!("\t\t{} {{\n", builder_name));
+                    for (other_key, _other_value) in required.iter() {
+                        if other_key == key {
+                            body.push_str(&format!("\t\t\t{}: {}.into(),\n", key, key));
+                        } else {
+                            body.push_str(&format!("\t\t\t{}: self.{},\n", other_key, other_key));
+                        }
+                    }
+                    for (other_key, _other_value) in optional.iter() {
+                        body.push_str(&format!("\t\t\t{}: self.{},\n", other_key, other_key));
+                    }
+                    if !extra.is_empty() {
+                        body.push_str("\t\t\textra: self.extra,\n");
+                    }
+                    body.push_str("\t\t}\n");
+                    body.push_str("\t}\n");
+                    body.push_str("}\n");
+                }
+
+                // The optional setters work in any state
+                body.push_str(&format!("impl{} {}{} {{\n", generics(&states), builder_name, generics(&states)));
+                body.push_str(&setters);
+                body.push_str("}\n");
+
+                // Only a fully-set builder can be built
+                let set = required.iter()
+                    .map(|(_key, value)| value.required_type())
+                    .collect::<Vec<String>>();
+                body.push_str(&format!("impl {}{} {{\n", builder_name, generics(&set)));
+                body.push_str(&format!("\t/// Finishes building the `{}`\n", self.name));
+                body.push_str(&format!("\tpub fn build(self) -> {} {{\n", self.name));
+                body.push_str(&format!("\t\t{} {{\n", self.name));
+                for (key, _value) in required.iter().chain(optional.iter()) {
+                    body.push_str(&format!("\t\t\t{}: self.{},\n", key, key));
+                }
+                if !extra.is_empty() {
+                    body.push_str("\t\t\textra: self.extra,\n");
+                }
+                body.push_str("\t\t}\n");
+                body.push_str("\t}\n");
+                body.push('}');
+            }
+        }
+
+        body
+    }
 }
 impl std::fmt::Display for Object {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -47,8 +308,32 @@ impl std::fmt::Display for Object {
 
         // Write the struct description
         if let Some(ref description) = self.description {
-            for line in description.lines() {
-                body.push_str(&format!("/// {}\n", line));
+            body.push_str(&doc_comment(description, ""));
+        }
+
+        // Write the examples, which rustdoc compiles but doesn't run
+        if !self.examples.is_empty() {
+            if self.description.is_some() {
+                body.push_str("///\n");
+            }
+            body.push_str("/// # Examples\n");
+
+            for example in self.examples.iter() {
+                body.push_str("///\n");
+                if let Some(ref title) = example.title {
+                    body.push_str(&doc_comment(&format!("{title}:"), ""));
+                    body.push_str("///\n");
+                }
+
+                body.push_str("/// ```no_run\n");
+                for line in example.code.lines() {
+                    if line.is_empty() {
+                        body.push_str("///\n");
+                    } else {
+                        body.push_str(&format!("/// {}\n", line));
+                    }
+                }
+                body.push_str("/// ```\n");
             }
         }
 
@@ -59,50 +344,52 @@ impl std::fmt::Display for Object {
             body.push_str("#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]\n");
         }
 
+        if self.unknown_fields == UnknownFields::Deny {
+            body.push_str("#[serde(deny_unknown_fields)]\n");
+        }
+
         // Write the object name
         body.push_str(&format!("pub struct {} {{\n", self.name));
 
         // Write the object properties
-        for (key, value) in self.properties.iter() {
+        for (key, value) in self.ordered_properties() {
             if let Some(ref description) = value.description {
-                for line in description.lines() {
-                    body.push_str(&format!("\t/// {}\n", line));
-                }
+                body.push_str(&doc_comment(description, "\t"));
             }
 
-            // Fix the `type` key
-            let key = if key.contains("/") || key.contains("-") {
+            // Fix keys which aren't valid Rust identifiers
+            if key.contains("/") || key.contains("-") {
                 body.push_str("\t#[serde(rename = \"");
                 body.push_str(key);
                 body.push_str("\")]\n");
-                key.replace("/", "_")
-                    .replace("-", "_")
-            } else {
-                key.to_string()
-            };
-
-            let key = if key == "type" {
-                "r#type"
-            } else if key == "static" {
-                "r#static"
-            } else {
-                &key
-            }.replace("[]", "");
-
-            // Check if it needs to be renamed
-            
+            }
+            let key = field_name(key);
 
             if !value.required {
                 body.push_str("\t#[serde(skip_serializing_if = \"Option::is_none\")]\n");
                 body.push_str(&format!("\tpub {}: Option<{}>,\n", key, value.value));
             } else {
-                body.push_str(&format!("\tpub {}: {},\n", key, value.value));
+                body.push_str(&format!("\tpub {}: {},\n", key, value.required_type()));
             }
         }
 
+        // Catch any keys we haven't generated a field for, so they
+        //  survive being deserialized and re-serialized
+        if self.unknown_fields == UnknownFields::Preserve {
+            body.push_str("\t/// Any keys which aren't part of the spec\n");
+            body.push_str("\t#[serde(flatten)]\n");
+            body.push_str("\tpub extra: serde_json::Map<String, serde_json::Value>,\n");
+        }
+
         // Close the struct definition
         body.push_str("}");
 
+        // Write the builder, if one was requested
+        if self.builders != Builders::None && !self.properties.is_empty() {
+            body.push('\n');
+            body.push_str(&self.builder_body());
+        }
+
         write!(f, "{}", body)
     }
 }
\ No newline at end of file
@@ -0,0 +1,364 @@
+use super::{docs::doc_comment, objects::field_name};
+
+use convert_case::{Case, Casing};
+
+/// One server-sent event of an example streamed response
+#[derive(Debug, Clone)]
+pub struct Event {
+    /// The `event:` name, if the stream names its events
+    pub name: Option<String>,
+    /// The `data:` payload
+    pub data: String,
+}
+/// Where a parameter is sent
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParameterLocation {
+    Path,
+    Query,
+}
+/// A path or query parameter of an operation
+#[derive(Debug, Clone)]
+pub struct Parameter {
+    /// The parameter's name, as sent
+    pub name: String,
+    /// Where it's sent
+    pub location: ParameterLocation,
+    /// The description of the parameter
+    pub description: Option<String>,
+    /// Whether or not the parameter is required
+    pub required: bool,
+    /// The Rust type of its value
+    pub r#type: String,
+}
+/// One method of one of the spec's `paths`
+#[derive(Debug)]
+pub struct Operation {
+    /// The operation's `operationId`
+    pub id: String,
+    /// The HTTP method, in upper case
+    pub method: String,
+    /// The path, with `{parameter}` placeholders
+    pub path: String,
+    /// The API reference group the operation belongs to
+    pub group: String,
+    /// The operation's summary
+    pub summary: Option<String>,
+    /// Whether `x-oaiMeta` marks the operation as beta
+    pub beta: bool,
+    /// The `OpenAI-Beta` header its examples send, such as `assistants=v2`
+    pub beta_header: Option<String>,
+    /// The path and query parameters, in the spec's order
+    pub parameters: Vec<Parameter>,
+    /// The generated type of the JSON request body, if it takes one
+    pub request_type: Option<String>,
+    /// Whether the request body is JSON, which is typed by `request_type`
+    ///  when the spec names its schema
+    pub json_body: bool,
+    /// Whether the request body is `multipart/form-data`
+    pub multipart: bool,
+    /// The type a successful JSON response is read as, or `None` when
+    ///  the response is raw bytes
+    pub response_type: Option<String>,
+    /// The generated type of each server-sent event, if it can stream
+    pub stream_type: Option<String>,
+    /// The type of each item of a cursor-paginated list response, found
+    ///  once the types are parsed
+    pub page_item: Option<String>,
+    /// The status of a successful response
+    pub status: u16,
+    /// The content type of a successful response
+    pub content_type: Option<String>,
+    /// An example successful response body, from the spec's examples or
+    ///  synthesized from its schema
+    pub response_example: Option<String>,
+    /// The events of an example streamed response
+    pub stream_example: Vec<Event>,
+}
+impl Operation {
+    /// The name of the operation's client method
+    pub fn method_name ( &self ) -> String {
+        self.id.to_case(Case::Snake)
+    }
+    /// The name of the struct holding the operation's query parameters
+    fn query_name ( &self ) -> String {
+        format!("{}Query", self.id.to_case(Case::UpperCamel))
+    }
+    fn query_parameters ( &self ) -> impl Iterator<Item = &Parameter> {
+        self.parameters.iter()
+            .filter(|parameter| parameter.location == ParameterLocation::Query)
+    }
+
+    /// Writes the struct of the operation's query parameters, if it has
+    ///  any, behind the `#[cfg]` line `gate`
+    pub fn query_struct ( &self, gate: &str ) -> Option<String> {
+        self.query_parameters().next()?;
+
+        let mut body = String::new();
+        body.push_str(&format!("/// The query parameters of [`Client::{}`]\n", self.method_name()));
+        body.push_str(gate);
+        body.push_str("#[derive(Debug, Clone, Default, PartialEq)]\n");
+        body.push_str(&format!("pub struct {} {{\n", self.query_name()));
+        for parameter in self.query_parameters() {
+            if let Some(ref description) = parameter.description {
+                body.push_str(&doc_comment(description, "\t"));
+            }
+
+            let r#type = if parameter.required || parameter.r#type.starts_with("Vec<") {
+                parameter.r#type.clone()
+            } else {
+                format!("Option<{}>", parameter.r#type)
+            };
+            body.push_str(&format!("\tpub {}: {},\n", field_name(&parameter.name), r#type));
+        }
+        body.push_str("}\n");
+
+        // Lists repeat their key, and missing values are left out
+        body.push_str(gate);
+        body.push_str(&format!("impl {} {{\n", self.query_name()));
+        body.push_str("\t/// The parameters as they're sent, in order\n");
+        body.push_str("\tpub fn pairs(&self) -> Vec<(&'static str, String)> {\n");
+        body.push_str("\t\tlet mut pairs = Vec::new();\n");
+        for parameter in self.query_parameters() {
+            let field = field_name(&parameter.name);
+            if parameter.r#type.starts_with("Vec<") {
+                body.push_str(&format!(
+                    "\t\tfor value in self.{field}.iter() {{\n\t\t\tpairs.push((\"{}\", value.to_string()));\n\t\t}}\n",
+                    parameter.name
+                ));
+            } else if parameter.required {
+                body.push_str(&format!("\t\tpairs.push((\"{}\", self.{field}.to_string()));\n", parameter.name));
+            } else {
+                body.push_str(&format!(
+                    "\t\tif let Some(ref value) = self.{field} {{\n\t\t\tpairs.push((\"{}\", value.to_string()));\n\t\t}}\n",
+                    parameter.name
+                ));
+            }
+        }
+        body.push_str("\t\tpairs\n");
+        body.push_str("\t}\n");
+        body.push_str("}\n");
+
+        Some(body)
+    }
+
+    /// Writes the operation's methods on the generated client, plus a
+    ///  `_stream` method when it can stream server-sent events, each
+    ///  behind the `#[cfg]` line `gate`.
+    ///
+    /// Multipart uploads aren't supported yet, so they get no methods.
+    pub fn client_methods ( &self, gate: &str ) -> Option<String> {
+        if self.multipart {
+            return None;
+        }
+        let gate = if gate.is_empty() { String::new() } else { format!("\t{gate}") };
+
+        // Path parameters are taken as `&str`s, in the order they appear
+        let mut arguments = vec!("&self".to_string());
+        let mut path_format = String::new();
+        let mut path_arguments = Vec::new();
+        for (index, segment) in self.path.split(['{', '}']).enumerate() {
+            if index % 2 == 0 {
+                path_format.push_str(segment);
+                continue;
+            }
+
+            let name = field_name(&segment.to_case(Case::Snake));
+            arguments.push(format!("{name}: &str"));
+            path_format.push_str("{}");
+            path_arguments.push(format!("encode({name})"));
+        }
+        let path = if path_arguments.is_empty() {
+            format!("\"{}\"", self.path)
+        } else {
+            format!("&format!(\"{path_format}\", {})", path_arguments.join(", "))
+        };
+
+        let query = if self.query_parameters().next().is_some() {
+            arguments.push(format!("query: &{}", self.query_name()));
+            "&query.pairs()"
+        } else {
+            "&[]"
+        };
+
+        let request_type = match self.request_type {
+            Some(ref request_type) => Some(format!("super::{}", request_type.replace("[]", ""))),
+            None if self.json_body => Some("serde_json::Value".to_string()),
+            None => None
+        };
+        if let Some(ref request_type) = request_type {
+            arguments.push(format!("request: &{request_type}"));
+        }
+
+        let mut docs = String::new();
+        if let Some(ref summary) = self.summary {
+            docs.push_str(&doc_comment(summary, "\t"));
+            docs.push_str("\t///\n");
+        }
+        docs.push_str(&format!("\t/// `{} {}`\n", self.method, self.path));
+        if self.beta {
+            docs.push_str("\t///\n\t/// **Beta**");
+            match self.beta_header {
+                Some(ref beta_header) => docs.push_str(&format!(
+                    ": sends `OpenAI-Beta: {beta_header}`, which [`Client::with_beta`] can pin to another version.\n"
+                )),
+                None => docs.push_str(".\n")
+            }
+        }
+
+        // The successful response is a generated type, `()` or a binary
+        //  download
+        let (response_type, reader) = match self.response_type.as_deref() {
+            Some(response_type @ ("()" | "serde_json::Value")) => (response_type.to_string(), "json"),
+            Some(response_type) => (format!("super::{}", response_type.replace("[]", "")), "json"),
+            None => ("Download".to_string(), "download")
+        };
+
+        let mut body = String::new();
+        body.push_str(&docs);
+        body.push_str(&gate);
+        body.push_str(&format!(
+            "\tpub async fn {}({}) -> Result<ApiResponse<{response_type}>, ClientError> {{\n",
+            self.method_name(),
+            arguments.join(", ")
+        ));
+        let request_body = if request_type.is_some() {
+            body.push_str("\t\tlet body = serde_json::to_vec(request)?;\n");
+            "Some(body)"
+        } else {
+            "None"
+        };
+        body.push_str(&format!(
+            "\t\tself.{reader}(\"{}\", \"{}\", {path}, {query}, {request_body}).await\n",
+            self.id,
+            self.method
+        ));
+        body.push_str("\t}\n");
+
+        // Streams are asked for with `"stream": true` in the JSON body
+        if let (Some(stream_type), Some(_)) = (&self.stream_type, &request_type) {
+            body.push_str(&docs);
+            body.push_str("\t///\n\t/// Streams the response as server-sent events.\n");
+            body.push_str(&gate);
+            body.push_str(&format!(
+                "\tpub async fn {}_stream({}) -> Result<EventStream<super::{}>, ClientError> {{\n",
+                self.method_name(),
+                arguments.join(", "),
+                stream_type.replace("[]", "")
+            ));
+            body.push_str("\t\tlet mut body = serde_json::to_value(request)?;\n");
+            body.push_str("\t\tif let Some(body) = body.as_object_mut() {\n");
+            body.push_str("\t\t\tbody.insert(\"stream\".to_string(), serde_json::Value::Bool(true));\n");
+            body.push_str("\t\t}\n");
+            body.push_str("\t\tlet body = serde_json::to_vec(&body)?;\n");
+            body.push_str(&format!(
+                "\t\tself.events(\"{}\", \"{}\", {path}, {query}, Some(body)).await\n",
+                self.id,
+                self.method
+            ));
+            body.push_str("\t}\n");
+        }
+
+        // Paginated lists also get a stream of every item, and always
+        //  have query parameters since they take a cursor
+        if let Some(ref page_item) = self.page_item {
+            let owned_path = if path_arguments.is_empty() {
+                format!("{path}.to_string()")
+            } else {
+                path.trim_start_matches('&').to_string()
+            };
+
+            body.push_str(&docs);
+            body.push_str("\t///\n\t/// Streams every item, fetching pages as they're needed.\n");
+            body.push_str(&gate);
+            body.push_str(&format!(
+                "\tpub fn {}_all({}) -> Paginated<super::{}> {{\n",
+                self.method_name(),
+                arguments.join(", "),
+                page_item.replace("[]", "")
+            ));
+            body.push_str(&format!("\t\tPaginated::new(self.clone(), \"{}\", {owned_path}, query.pairs())\n", self.id));
+            body.push_str("\t}\n");
+        }
+
+        Some(body)
+    }
+    /// The Rust types the operation's client methods name
+    pub fn named_types ( &self ) -> Vec<&str> {
+        self.request_type.iter()
+            .chain(self.response_type.iter())
+            .chain(self.stream_type.iter())
+            .chain(self.page_item.iter())
+            .map(|r#type| r#type.as_str())
+            .chain(self.query_parameters().map(|parameter| parameter.r#type.as_str()))
+            .collect()
+    }
+    /// Writes the operation's entry in the client's table of `OpenAI-Beta`
+    ///  headers, if it's beta and sends one
+    pub fn beta_entry ( &self ) -> Option<String> {
+        let beta_header = self.beta_header.as_ref().filter(|_| self.beta)?;
+
+        Some(format!("\t(\"{}\", \"{}\"),\n", self.id, beta_header))
+    }
+    /// Writes the operation's entry in the mock server's routing table,
+    ///  behind the `#[cfg]` line `gate`
+    pub fn mock_entry ( &self, gate: &str ) -> String {
+        let mut body = String::new();
+
+        if !gate.is_empty() {
+            body.push_str(&format!("\t{gate}"));
+        }
+        body.push_str("\tMockOperation {\n");
+        body.push_str(&format!("\t\tid: \"{}\",\n", self.id));
+        body.push_str(&format!("\t\tmethod: \"{}\",\n", self.method));
+        body.push_str(&format!("\t\tpath: \"{}\",\n", self.path));
+
+        // Bodies are checked by deserializing them as the request type
+        match self.request_type {
+            Some(ref request_type) => body.push_str(&format!(
+                "\t\tvalidate: Some(validate::<super::{}>),\n",
+                request_type.replace("[]", "")
+            )),
+            None => body.push_str("\t\tvalidate: None,\n")
+        }
+
+        body.push_str(&format!("\t\tstatus: {},\n", self.status));
+        body.push_str(&format!(
+            "\t\tcontent_type: \"{}\",\n",
+            self.content_type.as_deref().unwrap_or("application/json")
+        ));
+        body.push_str(&format!(
+            "\t\tresponse: {},\n",
+            raw_string(self.response_example.as_deref().unwrap_or(""))
+        ));
+
+        if self.stream_example.is_empty() {
+            body.push_str("\t\tevents: &[],\n");
+        } else {
+            body.push_str("\t\tevents: &[\n");
+            for event in self.stream_example.iter() {
+                let name = match event.name {
+                    Some(ref name) => format!("Some(\"{}\")", name),
+                    None => "None".to_string()
+                };
+
+                body.push_str(&format!("\t\t\t({}, {}),\n", name, raw_string(&event.data)));
+            }
+            body.push_str("\t\t],\n");
+        }
+
+        body.push_str("\t},\n");
+
+        body
+    }
+}
+
+/// Writes `text` as a raw string literal, with enough `#`s that nothing
+///  in it ends the literal early
+fn raw_string ( text: &str ) -> String {
+    let mut hashes = String::new();
+    while text.contains(&format!("\"{hashes}")) {
+        hashes.push('#');
+    }
+
+    format!("r{hashes}\"{text}\"{hashes}")
+}
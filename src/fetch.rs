@@ -0,0 +1,280 @@
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+
+/// What we know about a previous download of a spec
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheMeta {
+    /// The URL the spec was downloaded from
+    url: String,
+    /// The `ETag` header it was served with
+    etag: Option<String>,
+    /// The `Last-Modified` header it was served with
+    last_modified: Option<String>,
+}
+/// The pinned spec, recorded so that later runs notice upstream changes
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct SpecLock {
+    /// The URL the spec was downloaded from
+    url: String,
+    /// The SHA-256 of the spec's contents, in hex
+    sha256: String,
+}
+
+/// Downloads the spec at `url`, or reuses the cached copy if the server
+///  says it hasn't changed, then checks it against the lock file.
+///
+/// A spec whose hash doesn't match the lock is refused unless `update`
+///  is set, in which case the lock is rewritten to pin the new contents.
+///
+/// When `read_only` is set, as it is for `--check`, neither the cache
+///  nor the lock file is written.
+pub fn fetch_spec (
+    url: &str,
+    cache_dir: &Path,
+    lock_path: &Path,
+    update: bool,
+    read_only: bool
+) -> Result<String> {
+    // Each URL gets its own cache entry
+    let cache_key = hex(&Sha256::digest(url.as_bytes()));
+    let cached_spec_path = cache_dir.join(format!("{cache_key}.spec"));
+    let cached_meta_path = cache_dir.join(format!("{cache_key}.json"));
+
+    let cached_meta = if cached_spec_path.exists() && cached_meta_path.exists() {
+        let meta_raw = std::fs::read_to_string(&cached_meta_path)
+            .context("Failed to read the spec cache metadata")?;
+
+        serde_json::from_str::<CacheMeta>(&meta_raw)
+            .ok()
+    } else {
+        None
+    };
+
+    // Ask the server to skip the body if our copy is still current
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .http_status_as_error(false)
+        .build()
+        .into();
+    let mut request = agent.get(url);
+    if let Some(ref meta) = cached_meta {
+        if let Some(ref etag) = meta.etag {
+            request = request.header("If-None-Match", etag);
+        }
+        if let Some(ref last_modified) = meta.last_modified {
+            request = request.header("If-Modified-Since", last_modified);
+        }
+    }
+    let mut response = request.call()
+        .with_context(|| format!("Failed to request the spec from {url}"))?;
+
+    let spec = match response.status().as_u16() {
+        304 if cached_meta.is_some() => {
+            println!("Spec at {url} is unchanged, using the cached copy");
+
+            std::fs::read_to_string(&cached_spec_path)
+                .context("Failed to read the cached spec")?
+        },
+        200 => {
+            let header = |name: &str| response.headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(|value| value.to_string());
+            let meta = CacheMeta {
+                url: url.to_string(),
+                etag: header("etag"),
+                last_modified: header("last-modified"),
+            };
+
+            let spec = response.body_mut()
+                .with_config()
+                .limit(64 * 1024 * 1024)
+                .read_to_string()
+                .context("Failed to read the spec's body")?;
+
+            if !read_only {
+                std::fs::create_dir_all(cache_dir)
+                    .context("Failed to create the spec cache directory")?;
+                std::fs::write(&cached_spec_path, &spec)
+                    .context("Failed to cache the spec")?;
+                std::fs::write(&cached_meta_path, serde_json::to_string_pretty(&meta)?)
+                    .context("Failed to write the spec cache metadata")?;
+            }
+
+            spec
+        },
+        status => {
+            bail!("Downloading the spec from {url} failed with status {status}");
+        }
+    };
+
+    // Compare against what's pinned
+    let lock = SpecLock {
+        url: url.to_string(),
+        sha256: hex(&Sha256::digest(spec.as_bytes())),
+    };
+    let pinned = if lock_path.exists() {
+        let lock_raw = std::fs::read_to_string(lock_path)
+            .context("Failed to read the spec lock file")?;
+
+        Some(serde_json::from_str::<SpecLock>(&lock_raw)
+            .context("Failed to parse the spec lock file")?)
+    } else {
+        None
+    };
+
+    match pinned {
+        Some(ref pinned) if *pinned == lock => { },
+        Some(ref pinned) if !update => {
+            bail!(
+                "The spec at {} (SHA-256 {}) doesn't match the one pinned in {} ({} at {}). Pass `--update` to accept it",
+                lock.url, lock.sha256, lock_path.display(), pinned.sha256, pinned.url
+            );
+        },
+        _ if read_only => {
+            println!("Not pinning the spec at {url} to SHA-256 {}, since nothing is written", lock.sha256);
+        },
+        _ => {
+            println!("Pinning the spec at {url} to SHA-256 {}", lock.sha256);
+
+            std::fs::write(lock_path, serde_json::to_string_pretty(&lock)? + "\n")
+                .context("Failed to write the spec lock file")?;
+        }
+    }
+
+    Ok(spec)
+}
+/// Formats bytes as lowercase hex
+fn hex ( bytes: &[u8] ) -> String {
+    bytes.iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::path::PathBuf;
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    /// A local server for one spec, which honors `If-None-Match`
+    struct SpecServer {
+        url: String,
+        /// The spec being served, and its ETag
+        spec: Arc<Mutex<(String, String)>>,
+        /// How many requests were answered with 304
+        not_modified: Arc<Mutex<usize>>,
+    }
+    impl SpecServer {
+        fn start ( spec: &str, etag: &str ) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}/openapi.yaml", listener.local_addr().unwrap());
+            let spec = Arc::new(Mutex::new((spec.to_string(), etag.to_string())));
+            let not_modified = Arc::new(Mutex::new(0));
+
+            let (served, counted) = (spec.clone(), not_modified.clone());
+            std::thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let mut stream = stream.unwrap();
+
+                    // Only the headers matter, and the requests have no body
+                    let mut if_none_match = None;
+                    let mut reader = BufReader::new(&stream);
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        let line = line.trim_end();
+                        if line.is_empty() {
+                            break;
+                        }
+                        if let Some((name, value)) = line.split_once(':')
+                            && name.eq_ignore_ascii_case("if-none-match")
+                        {
+                            if_none_match = Some(value.trim().to_string());
+                        }
+                    }
+
+                    let (spec, etag) = served.lock().unwrap().clone();
+                    let response = if if_none_match.as_deref() == Some(etag.as_str()) {
+                        *counted.lock().unwrap() += 1;
+                        format!("HTTP/1.1 304 Not Modified\r\nETag: {etag}\r\nConnection: close\r\n\r\n")
+                    } else {
+                        format!(
+                            "HTTP/1.1 200 OK\r\nETag: {etag}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{spec}",
+                            spec.len()
+                        )
+                    };
+                    stream.write_all(response.as_bytes()).unwrap();
+                }
+            });
+
+            Self { url, spec, not_modified }
+        }
+        fn serve ( &self, spec: &str, etag: &str ) {
+            *self.spec.lock().unwrap() = (spec.to_string(), etag.to_string());
+        }
+        fn not_modified ( &self ) -> usize {
+            *self.not_modified.lock().unwrap()
+        }
+    }
+
+    /// An empty scratch directory for one test
+    fn scratch_dir ( name: &str ) -> PathBuf {
+        let dir = std::env::temp_dir()
+            .join(format!("specgen-fetch-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        dir
+    }
+
+    #[test]
+    fn unchanged_spec_is_read_from_the_cache () {
+        let server = SpecServer::start("openapi: 3.1.0\n", "\"v1\"");
+        let dir = scratch_dir("cache");
+        let (cache_dir, lock_path) = (dir.join("cache"), dir.join("openapi.lock"));
+
+        let first = fetch_spec(&server.url, &cache_dir, &lock_path, false, false).unwrap();
+        assert_eq!(server.not_modified(), 0);
+
+        let second = fetch_spec(&server.url, &cache_dir, &lock_path, false, false).unwrap();
+        assert_eq!(server.not_modified(), 1);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn changed_spec_is_refused_unless_updating () {
+        let server = SpecServer::start("openapi: 3.1.0\n", "\"v1\"");
+        let dir = scratch_dir("lock");
+        let (cache_dir, lock_path) = (dir.join("cache"), dir.join("openapi.lock"));
+
+        fetch_spec(&server.url, &cache_dir, &lock_path, false, false).unwrap();
+        let pinned = std::fs::read_to_string(&lock_path).unwrap();
+
+        server.serve("openapi: 3.1.1\n", "\"v2\"");
+        let error = fetch_spec(&server.url, &cache_dir, &lock_path, false, false).unwrap_err();
+        assert!(error.to_string().contains("Pass `--update` to accept it"), "{error}");
+        assert_eq!(std::fs::read_to_string(&lock_path).unwrap(), pinned);
+
+        let spec = fetch_spec(&server.url, &cache_dir, &lock_path, true, false).unwrap();
+        assert_eq!(spec, "openapi: 3.1.1\n");
+        let lock: SpecLock = serde_json::from_str(&std::fs::read_to_string(&lock_path).unwrap()).unwrap();
+        assert_eq!(lock.sha256, hex(&Sha256::digest(spec.as_bytes())));
+    }
+
+    #[test]
+    fn read_only_fetch_writes_nothing () {
+        let server = SpecServer::start("openapi: 3.1.0\n", "\"v1\"");
+        let dir = scratch_dir("read-only");
+        let (cache_dir, lock_path) = (dir.join("cache"), dir.join("openapi.lock"));
+
+        fetch_spec(&server.url, &cache_dir, &lock_path, false, true).unwrap();
+        assert!(!cache_dir.exists());
+        assert!(!lock_path.exists());
+    }
+}
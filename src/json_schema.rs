@@ -0,0 +1,304 @@
+use super::parsing::{Data, Alias};
+use super::data::enums::{Enum, EnumType};
+use super::data::objects::{Object, FieldValue, UnknownFields};
+
+use std::collections::BTreeMap;
+
+use serde_json::{Map, Value, json};
+
+/// The dialect the bundle is written in
+const DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Writes the parsed types as a JSON Schema 2020-12 bundle, with one
+///  `$defs` entry per generated type under its Rust name.
+///
+/// Each definition accepts exactly what the type deserializes from,
+///  so it mirrors `serde`'s handling rather than the original spec:
+///  optional fields accept `null`, untagged enums try their variants
+///  in order, and an internally tagged enum's struct variants are
+///  written out with the tag among their properties, so that the tag
+///  passes when unknown keys are denied.
+pub fn bundle (
+    schemas: &BTreeMap<String, Data>,
+    aliases: &BTreeMap<String, Alias>,
+
+    spec_version: &str
+) -> Value {
+    let mut defs = Map::new();
+    for (key, data) in schemas.iter() {
+        let schema = match data {
+            Data::Object(object) => object_schema(object),
+            Data::Enum(r#enum) => enum_schema(r#enum, schemas)
+        };
+
+        defs.insert(key.replace("[]", ""), schema);
+    }
+    for (key, alias) in aliases.iter() {
+        let mut schema = type_schema(&alias.r#type);
+        describe(&mut schema, alias.description.as_deref());
+
+        defs.insert(key.replace("[]", ""), schema);
+    }
+
+    json!({
+        "$schema": DIALECT,
+        "title": "OpenAI API types",
+        "description": format!("The generated Rust types for version {spec_version} of the OpenAI API's OpenAPI spec"),
+        "$defs": defs
+    })
+}
+
+fn object_schema ( object: &Object ) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for (key, field) in object.properties.iter() {
+        let mut schema = field_schema(&field.value);
+
+        // `Option` fields can be `null`, and optional ones can be missing
+        if field.required {
+            required.push(Value::String(key.clone()));
+        }
+        if !field.required || field.nullable {
+            schema = json!({ "anyOf": [ schema, { "type": "null" } ] });
+        }
+        describe(&mut schema, field.description.as_deref());
+
+        properties.insert(key.clone(), schema);
+    }
+
+    let mut schema = json!({
+        "type": "object",
+        "properties": properties,
+    });
+    if !required.is_empty() {
+        schema["required"] = Value::Array(required);
+    }
+    if object.unknown_fields == UnknownFields::Deny {
+        schema["unevaluatedProperties"] = Value::Bool(false);
+    }
+    describe(&mut schema, object.description.as_deref());
+
+    schema
+}
+fn field_schema ( value: &FieldValue ) -> Value {
+    match value {
+        FieldValue::ExternalType(r#type) | FieldValue::Boxed(r#type) => type_schema(r#type),
+        FieldValue::Array(r#type) => json!({ "type": "array", "items": type_schema(r#type) }),
+        FieldValue::String => json!({ "type": "string" }),
+        FieldValue::Integer => json!({ "type": "integer" }),
+        FieldValue::Boolean => json!({ "type": "boolean" })
+    }
+}
+
+fn enum_schema ( r#enum: &Enum, schemas: &BTreeMap<String, Data> ) -> Value {
+    let mut schema = match r#enum.enum_type {
+        // Unit variants are their original values, and `String(String)` is
+        //  externally tagged like any other newtype variant
+        EnumType::Standard => {
+            let values = r#enum.values.iter()
+                .filter(|value| value.as_str() != "String(String)")
+                .map(|value| Value::String(value.clone()))
+                .collect::<Vec<Value>>();
+            let values = json!({ "type": "string", "enum": values });
+
+            if r#enum.values.iter().any(|value| value == "String(String)") {
+                json!({ "oneOf": [
+                    values,
+                    {
+                        "type": "object",
+                        "properties": { "string": { "type": "string" } },
+                        "required": [ "string" ],
+                        "additionalProperties": false
+                    }
+                ] })
+            } else {
+                values
+            }
+        },
+        // Untagged enums take the first variant which fits
+        EnumType::OneOf | EnumType::AnyOf => {
+            let mut variants = Vec::new();
+            let mut printed_object = false;
+            for value in r#enum.values.iter() {
+                if value == "Object(serde_json::Value)" {
+                    if printed_object {
+                        continue;
+                    }
+                    printed_object = true;
+                }
+
+                variants.push(match variant_type(value) {
+                    Some(r#type) => type_schema(r#type),
+                    None => json!({ "type": "null" })
+                });
+            }
+
+            json!({ "anyOf": variants })
+        },
+        // Internally tagged enums hold the tag alongside the variant's fields
+        EnumType::Tagged(ref tag) => {
+            let variants = r#enum.values.iter()
+                .map(|value| {
+                    let tag_value = r#enum.tags.get(value)
+                        .cloned()
+                        .unwrap_or_else(|| value.split('(').next().unwrap_or(value).to_lowercase());
+                    let tag_property = json!({ "const": tag_value });
+
+                    // A struct's `unevaluatedProperties` can't see a tag
+                    //  declared beside its `$ref`, so the struct is copied
+                    //  in with the tag added to its properties
+                    let variant_object = variant_type(value)
+                        .and_then(|r#type| schemas.get(r#type));
+                    if let Some(Data::Object(object)) = variant_object {
+                        let mut schema = object_schema(object);
+                        schema["properties"][tag.as_str()] = tag_property;
+                        match schema["required"].as_array_mut() {
+                            Some(required) => required.insert(0, Value::String(tag.clone())),
+                            None => schema["required"] = json!([ tag ])
+                        }
+
+                        return schema;
+                    }
+
+                    let mut tag_properties = Map::new();
+                    tag_properties.insert(tag.clone(), tag_property);
+                    let tag_schema = json!({
+                        "type": "object",
+                        "properties": tag_properties,
+                        "required": [ tag ]
+                    });
+
+                    match variant_type(value) {
+                        Some(r#type) => json!({ "allOf": [ tag_schema, type_schema(r#type) ] }),
+                        None => tag_schema
+                    }
+                })
+                .collect::<Vec<Value>>();
+
+            json!({ "oneOf": variants })
+        }
+    };
+    describe(&mut schema, r#enum.description.as_deref());
+
+    schema
+}
+/// The type a `Variant(Type)` holds, if it holds one
+fn variant_type ( value: &str ) -> Option<&str> {
+    value.split_once('(')?
+        .1
+        .strip_suffix(')')
+}
+
+/// The schema of a Rust type, as written in fields, variants and aliases
+fn type_schema ( r#type: &str ) -> Value {
+    let r#type = r#type.replace("[]", "");
+
+    if let Some(inner) = generic(&r#type, "Vec<") {
+        return json!({ "type": "array", "items": type_schema(inner) });
+    }
+    if let Some(inner) = generic(&r#type, "Box<") {
+        return type_schema(inner);
+    }
+    if let Some(inner) = generic(&r#type, "HashMap<String, ") {
+        return json!({ "type": "object", "additionalProperties": type_schema(inner) });
+    }
+
+    match r#type.as_str() {
+        "String" => json!({ "type": "string" }),
+        "i64" => json!({ "type": "integer" }),
+        "f64" => json!({ "type": "number" }),
+        "bool" => json!({ "type": "boolean" }),
+        "serde_json::Value" => json!({}),
+        name => json!({ "$ref": format!("#/$defs/{name}") })
+    }
+}
+/// The type inside `Wrapper<...>`, if `type` is one
+fn generic<'a> ( r#type: &'a str, wrapper: &str ) -> Option<&'a str> {
+    r#type.strip_prefix(wrapper)?
+        .strip_suffix('>')
+}
+
+/// Adds a description to a schema, if there is one
+fn describe ( schema: &mut Value, description: Option<&str> ) {
+    if let (Some(description), Value::Object(schema)) = (description, schema) {
+        schema.insert("description".to_string(), Value::String(description.to_string()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use hashlink::LinkedHashMap;
+
+    use super::*;
+    use crate::data::objects::Field;
+
+    /// An internally tagged `Event` with one struct variant and one unit
+    ///  variant, whose struct denies unknown keys
+    fn tagged_schemas () -> BTreeMap<String, Data> {
+        let mut properties = LinkedHashMap::new();
+        properties.insert("event_id".to_string(), Field {
+            description: None,
+            value: FieldValue::String,
+            required: true,
+            nullable: false,
+        });
+        let cleared = Object {
+            name: "Cleared".to_string(),
+            description: None,
+            properties,
+            wanted_by: BTreeSet::new(),
+            relies_on: BTreeSet::new(),
+            unknown_fields: UnknownFields::Deny,
+            builders: Default::default(),
+            field_order: Default::default(),
+            examples: Vec::new(),
+        };
+        let event = Enum {
+            name: "Event".to_string(),
+            description: None,
+            values: vec![ "Cleared(Cleared)".to_string(), "Closed".to_string() ],
+            enum_type: EnumType::Tagged("type".to_string()),
+            wanted_by: BTreeSet::new(),
+            relies_on: BTreeSet::new(),
+            tags: BTreeMap::from([
+                ( "Cleared(Cleared)".to_string(), "buffer.cleared".to_string() ),
+                ( "Closed".to_string(), "closed".to_string() ),
+            ]),
+        };
+
+        BTreeMap::from([
+            ( "Cleared".to_string(), Data::Object(cleared) ),
+            ( "Event".to_string(), Data::Enum(event) ),
+        ])
+    }
+    /// Whether the bundle's definition of `name` accepts `instance`
+    fn accepts ( bundle: &Value, name: &str, instance: Value ) -> bool {
+        let mut schema = bundle.clone();
+        schema["$ref"] = json!(format!("#/$defs/{name}"));
+
+        jsonschema::draft202012::new(&schema)
+            .expect("The bundle should be a valid schema")
+            .is_valid(&instance)
+    }
+
+    #[test]
+    fn tagged_variants_accept_their_tag_when_unknown_keys_are_denied () {
+        let bundle = bundle(&tagged_schemas(), &BTreeMap::new(), "1.0.0");
+
+        assert!(accepts(&bundle, "Event", json!({ "type": "buffer.cleared", "event_id": "event_1" })));
+        assert!(accepts(&bundle, "Event", json!({ "type": "closed" })));
+        assert!(!accepts(&bundle, "Event", json!({ "type": "buffer.cleared", "event_id": "event_1", "extra": 1 })));
+        assert!(!accepts(&bundle, "Event", json!({ "type": "closed.not", "event_id": "event_1" })));
+        assert!(!accepts(&bundle, "Event", json!({ "event_id": "event_1" })));
+    }
+
+    #[test]
+    fn structs_deny_unknown_keys_outside_of_enums () {
+        let bundle = bundle(&tagged_schemas(), &BTreeMap::new(), "1.0.0");
+
+        assert!(accepts(&bundle, "Cleared", json!({ "event_id": "event_1" })));
+        assert!(!accepts(&bundle, "Cleared", json!({ "event_id": "event_1", "type": "buffer.cleared" })));
+    }
+}
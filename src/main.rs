@@ -3,18 +3,87 @@ mod _schemas;
 
 mod parsing;
 mod data;
+mod options;
+mod check;
+mod fetch;
+mod json_schema;
 
 use _schemas::{CreateResponse, CreateResponseInput, Response, OutputItem, OutputContent};
 
 use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
 
 use saphyr::Yaml;
 use anyhow::{bail, Context, Result};
-use parsing::{Data, Alias, parse};
+use parsing::{Data, Alias, parse, parse_tagged_enum, link, box_cycles, referenced_types, load_spec, normalize, resolve_refs, apply_patch, infer_types, RequestExample, request_examples, rust_expression, parse_operations, find_pagination};
+use options::Options;
+use data::objects::{UnknownFields, Example, Field, FieldValue};
+use data::enums::{Enum, EnumType};
+
+/// The Realtime API's event enums, which the Realtime session sends and receives
+const REALTIME_EVENTS: [&str; 2] = ["RealtimeClientEvent", "RealtimeServerEvent"];
+/// The first line of a generated crate's `Cargo.toml`, which marks its
+///  `src` as safe to replace
+const GENERATED_MARKER: &str = "# Generated by async-openai-specgen. Everything in `src` is replaced when it's regenerated";
+
 fn main() -> Result<()>{
-    let openapi_yaml_raw = include_str!("../assets/openapi.yaml");
-    let docs = Yaml::load_from_str(&openapi_yaml_raw)
-        .context("Failed to load OpenAPI YAML")?;
+    let options = Options::from_args(std::env::args().skip(1))
+        .context("Failed to parse the command-line arguments")?;
+
+    let openapi_yaml_raw = match (&options.spec_url, &options.spec_path) {
+        (Some(spec_url), _) => fetch::fetch_spec(
+            spec_url,
+            &options.spec_cache,
+            &options.spec_lock,
+            options.update,
+            options.check
+        )
+            .context("Failed to fetch the OpenAPI spec")?,
+        (None, Some(spec_path)) => std::fs::read_to_string(spec_path)
+            .with_context(|| format!("Failed to read the OpenAPI spec at {}", spec_path.display()))?,
+        (None, None) => include_str!("../assets/openapi.yaml").to_string()
+    };
+    let mut docs = load_spec(&openapi_yaml_raw)
+        .context("Failed to load the OpenAPI spec")?;
+    let spec = docs.first_mut()
+        .context("The OpenAPI spec is empty")?;
+
+    // Fix the spec up before anything reads it
+    if options.default_overlay {
+        let default_overlay = load_spec(include_str!("../assets/overlays/default.yaml"))
+            .context("Failed to load the default overlay")?;
+
+        apply_patch(spec, &default_overlay[0])
+            .context("Failed to apply the default overlay")?;
+    }
+    for overlay_path in options.overlays.iter() {
+        println!("Applying overlay {}", overlay_path.display());
+
+        let overlay_raw = std::fs::read_to_string(overlay_path)
+            .with_context(|| format!("Failed to read the overlay {}", overlay_path.display()))?;
+        let overlay = load_spec(&overlay_raw)
+            .with_context(|| format!("Failed to load the overlay {}", overlay_path.display()))?;
+
+        apply_patch(spec, overlay.first().context("The overlay is empty")?)
+            .with_context(|| format!("Failed to apply the overlay {}", overlay_path.display()))?;
+    }
+
+    // File refs are relative to the spec's own file, if it has one
+    let base_dir = match options.spec_path {
+        Some(ref spec_path) => spec_path.parent()
+            .map(|parent| parent.to_path_buf())
+            .unwrap_or_default(),
+        None => PathBuf::new()
+    };
+    let base_dir = if base_dir.as_os_str().is_empty() {
+        PathBuf::from(".")
+    } else {
+        base_dir
+    };
+    resolve_refs(spec, &base_dir)
+        .context("Failed to resolve the OpenAPI spec's references")?;
+    normalize(spec);
+    let inferences = infer_types(spec);
 
     let schemas_yaml = docs[0]["components"]["schemas"]
         .as_hash()
@@ -22,51 +91,151 @@ fn main() -> Result<()>{
 
     let mut schemas: BTreeMap<String, Data> = BTreeMap::new();
     let mut aliases: BTreeMap<String, Alias> = BTreeMap::new();
+    let mut examples: Vec<(String, RequestExample)> = Vec::new();
+
+    // Every operation, for the client and the mock server
+    let mut operations = parse_operations(&docs[0])
+        .context("Failed to parse the operations")?;
+
+    // Parse the types each operation sends and receives, in the
+    //  operation's group
+    for operation in operations.iter() {
+        println!("Operation: {} {}", operation.method, operation.path);
+
+        let operation_yaml = &docs[0]["paths"][operation.path.as_str()][operation.method.to_lowercase().as_str()];
+        // Responses which aren't a generated type are read as `()`,
+        //  `serde_json::Value` or bytes
+        let response_ref = operation.response_type.as_ref()
+            .filter(|response_type| schemas_yaml.contains_key(&Yaml::String(response_type.to_string())));
+        let schema_refs = [operation.request_type.as_ref(), response_ref, operation.stream_type.as_ref()]
+            .into_iter()
+            .flatten();
+        for schema_ref in schema_refs {
+            println!("Schema ref: {}", schema_ref);
+
+            // Find the schema reference in the schemas
+            let Some(schema_yaml) = schemas_yaml.get(&Yaml::String(schema_ref.to_string())) else {
+                bail!("Schema reference {} not found in schemas", schema_ref);
+            };
 
-    let paths = docs[0]["paths"]
-        .as_hash()
-        .context("Failed to get paths")?;
+            parse(
+                &docs[0],
+                &mut schemas,
+                &mut aliases,
+                operation.group.clone(),
+                schema_ref,
+                schema_yaml
+            )
+                .context("Failed to parse the schema")?;
+        }
 
-    for (path, yaml) in paths {
-        if let Some(path_string) = path.as_str() {
-            let root_path = path_string.split('/')
-                .skip(1)
-                .next()
-                .context("Failed to get root path")?;
-
-            println!("Path: {}", root_path);
-
-            for (_sub_field_name, sub_field_yaml) in yaml.as_hash()
-                .context("Path YAML for route was not a hashmap!")?
-            {
-                if let Some(schema_ref) = sub_field_yaml["requestBody"]["content"]["application/json"]["schema"]["$ref"].as_str() {
-                    let schema_ref = schema_ref.split("/")
-                        .skip(3)
-                        .next()
-                        .context("Failed to get schema reference")?;
-
-                    println!("Schema ref: {}", schema_ref);
-
-                    // Find the schema reference in the schemas
-                    if let Some(schema_yaml) = schemas_yaml.get(&Yaml::String(schema_ref.to_string())) {
-                        // Parse the schema
-                        parse(
-                            &docs[0],
-                            &mut schemas,
-                            &mut aliases,
-                            root_path.to_string(),
-                            schema_ref,
-                            schema_yaml
-                        )
-                        .context("Failed to parse the schema")?;
-                    } else {
-                        bail!("Schema reference {} not found in schemas", schema_ref);
-                    }
-                }
+        // Keep the operation's examples for the request type's docs
+        if let Some(ref request_type) = operation.request_type {
+            for example in request_examples(operation_yaml) {
+                examples.push((request_type.clone(), example));
+            }
+        }
+    }
+
+    // The Realtime API's events are only ever sent over a WebSocket,
+    //  so no path refers to them. Other providers' specs may not have
+    //  them, and only get the Realtime session if they have both.
+    let mut has_realtime_session = true;
+    for event_key in REALTIME_EVENTS {
+        let Some(event_yaml) = schemas_yaml.get(&Yaml::String(event_key.to_string())) else {
+            println!("Skipping the Realtime session, since schema {event_key} isn't in the spec");
+            has_realtime_session = false;
+            continue;
+        };
+
+        parse_tagged_enum(
+            &docs[0],
+            &mut schemas,
+            &mut aliases,
+            "realtime".to_string(),
+            event_key,
+            event_yaml
+        )
+            .with_context(|| format!("Failed to parse the tagged enum {event_key}"))?;
+    }
+
+    // The client reads failures as `ErrorResponse`s, and streams send
+    //  `ErrorEvent`s, though no path refers to either. Other providers'
+    //  specs may not have them, and their errors are kept as raw bodies.
+    for error_key in ["ErrorResponse", "ErrorEvent"] {
+        let Some(error_yaml) = schemas_yaml.get(&Yaml::String(error_key.to_string())) else {
+            println!("Skipping schema {error_key}, which isn't in the spec");
+            continue;
+        };
+
+        parse(
+            &docs[0],
+            &mut schemas,
+            &mut aliases,
+            "shared".to_string(),
+            error_key,
+            error_yaml
+        )
+            .with_context(|| format!("Failed to parse the schema {error_key}"))?;
+    }
+
+    // Give every type the groups of the types relying on it
+    link(&mut schemas, &aliases);
+    box_cycles(&mut schemas, &aliases);
+
+    // Now the types are final, find which lists can be paged through
+    find_pagination(&mut operations, &schemas);
+
+    // Apply the generation options to the parsed types
+    for data in schemas.values_mut() {
+        if let Data::Object(object) = data {
+            object.unknown_fields = options.unknown_fields;
+            object.builders = options.builders;
+            object.field_order = options.field_order;
+        }
+    }
+
+    // Convert the curl examples into Rust, now the types are final
+    let crate_ident = options.crate_name.replace('-', "_");
+    let example_count = examples.len();
+    let mut converted_examples: Vec<(String, Example)> = Vec::new();
+    for (type_name, example) in examples {
+        let expression = match rust_expression(&schemas, &aliases, &type_name, &example.body, 0) {
+            Ok(expression) => expression,
+            Err(error) => {
+                println!("Skipping an example of {type_name} ({}): {error:#}", example.title.as_deref().unwrap_or("untitled"));
+                continue;
             }
+        };
+
+        let mut code = format!("# use {crate_ident}::*;\n");
+        if expression.contains("HashMap::") {
+            code.push_str("# use std::collections::HashMap;\n");
+        }
+        code.push_str(&format!("let request = {expression};\n"));
+
+        converted_examples.push((type_name, Example { title: example.title, code }));
+    }
+    println!("Converted {} of {example_count} request example(s) into Rust", converted_examples.len());
+    for (type_name, example) in converted_examples {
+        if let Some(Data::Object(object)) = schemas.get_mut(&type_name) {
+            object.examples.push(example);
         }
     }
 
+    // Export the final types for validators in other languages
+    if let Some(ref json_schema_path) = options.json_schema {
+        let spec_version = docs[0]["info"]["version"].as_str()
+            .context("Failed to get the spec's `info.version`")?;
+        let bundle = json_schema::bundle(&schemas, &aliases, spec_version);
+        let bundle = serde_json::to_string_pretty(&bundle)
+            .context("Failed to serialize the JSON Schema bundle")?;
+
+        std::fs::write(json_schema_path, bundle + "\n")
+            .with_context(|| format!("Failed to write the JSON Schema bundle to {}", json_schema_path.display()))?;
+        println!("Wrote the JSON Schema bundle to {}", json_schema_path.display());
+    }
+
     /*
     for (key, value) in schemas_yaml.iter() {
         let key = key.as_str().context("Failed to get key")?;
@@ -84,117 +253,435 @@ fn main() -> Result<()>{
     } 
     */
 
+    // Find which modules each alias needs, looking through any
+    //  aliases it names
+    let mut alias_modules: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for key in aliases.keys() {
+        let mut modules = BTreeSet::new();
+        let mut pending = vec!(key.clone());
+        let mut visited = BTreeSet::new();
+        while let Some(typename) = pending.pop() {
+            if !visited.insert(typename.clone()) {
+                continue;
+            }
+
+            if let Some(data) = schemas.get(&typename) {
+                modules.insert(module_name(data.wanted_by()));
+            } else if let Some(alias) = aliases.get(&typename) {
+                pending.extend(referenced_types(&schemas, &aliases, &alias.r#type));
+            }
+        }
+
+        alias_modules.insert(key.clone(), modules);
+    }
+
     // Print the schema and alias Rust types
     let mut rust_schema_bodies: BTreeMap<String, (String, BTreeSet<String>)> = BTreeMap::new();
+    let mut module_dependencies: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
     for ( key, value ) in schemas.iter() {
-        let relies_on;
-        let wanted_by;
         let stringified = match value {
-            Data::Object(object) => {
-                wanted_by = object.wanted_by.clone();
-                relies_on = object.relies_on.clone();
-                format!("{}", object)
-            },
-            Data::Enum(r#enum) => {
-                wanted_by = r#enum.wanted_by.clone();
-                relies_on = r#enum.relies_on.clone();
-                format!("{}", r#enum)
-            }
-        };
-        let file_title = if wanted_by.is_empty() {
-            "uncategorized".to_string()
-        } else if wanted_by.len() == 1 {
-            format!("{}", wanted_by.iter().next().context("Unreachable wanted_by")?)
-        } else {
-            format!("{}", wanted_by.iter().map(|st| st.to_owned()).collect::<Vec<String>>().join("_"))
+            Data::Object(object) => format!("{}", object),
+            Data::Enum(r#enum) => format!("{}", r#enum)
         };
+        let file_title = module_name(value.wanted_by());
 
         let rust_schema_body = rust_schema_bodies
             .entry(file_title.clone())
             .or_insert_with(|| (String::new(), BTreeSet::new()));
 
         // Copy the relies_on to the file as `use` statements
-        for typename in relies_on.iter() {
+        for typename in value.relies_on().iter() {
             // Find the file title of the type schema
-            let typename_schema = match schemas.get(typename) {
-                Some(Data::Object(object)) => {
-                    object.wanted_by.clone()
-                },
-                Some(Data::Enum(r#enum)) => {
-                    r#enum.wanted_by.clone()
-                },
-                _ => {
-                    // Check if it's an alias
-                    if let Some(_) = aliases.get(typename) {
-                        BTreeSet::from(["aliases".to_string()])
-                    } else {
-                        bail!("Type {} (needed for {key}) not found in schemas or aliases", typename);
-                    }
-                }
+            let inner_file_title = if let Some(data) = schemas.get(typename) {
+                module_name(data.wanted_by())
+            } else if aliases.contains_key(typename) {
+                "aliases".to_string()
+            } else {
+                bail!("Type {} (needed for {key}) not found in schemas or aliases", typename);
             };
 
-            let inner_file_title = if typename_schema.is_empty() {
-                "uncategorized".to_string()
-            } else if typename_schema.len() == 1 {
-                format!("{}", typename_schema.iter().next().context("Unreachable wanted_by")?)
+            // Track which other modules this module needs, for feature gating
+            let dependencies = module_dependencies.entry(file_title.clone())
+                .or_default();
+            if inner_file_title == "aliases" {
+                dependencies.extend(alias_modules.get(typename).into_iter().flatten().cloned());
             } else {
-                format!("{}", typename_schema.iter().map(|st| st.to_owned()).collect::<Vec<String>>().join("_"))
-            };
+                dependencies.insert(inner_file_title.clone());
+            }
+            dependencies.remove(&file_title);
 
             // Add the `use` statement to the file if it's not in the same file
             if file_title.as_str() == inner_file_title.as_str() {
                 continue;
             }
-            (*rust_schema_body).1.insert(format!("use super::{}::{};", inner_file_title, typename));
+            rust_schema_body.1.insert(format!("use super::{}::{};", inner_file_title, typename));
         }
-        (*rust_schema_body).0 += &stringified
-            .replace("(/docs", "(https://platform.openai.com/docs");
-        (*rust_schema_body).0 += "\n";
+        rust_schema_body.0 += &stringified;
+        rust_schema_body.0 += "\n";
     }
 
-    // First, delete and create the schemas directory
-    std::fs::remove_dir_all("src/schemas")
-        .context("Failed to remove schemas directory")?;
-    std::fs::create_dir_all("src/schemas")
-        .context("Failed to create schemas directory")?;
+    // Render every file in memory first, keyed by its path within the
+    //  output directory. A crate keeps its modules under `src`.
+    let mut files: BTreeMap<String, String> = BTreeMap::new();
+    let source_dir = if options.output_crate.is_some() { "src/" } else { "" };
 
-    // Create the `mod.rs` file
+    // Create the `mod.rs` file, gating each module behind its feature
     let mod_content = rust_schema_bodies.keys()
-        .map(|file_name| format!("pub mod {};\n", file_name.replace(".rs", "")))
+        .map(|file_name| format!("{}pub mod {};\n", feature_gate(file_name), file_name.replace(".rs", "")))
         .collect::<String>()
         +
+        "pub mod aliases;\n"
+        +
+        if has_realtime_session { "#[cfg(feature = \"realtime-session\")]\npub mod realtime_session;\n" } else { "" }
+        +
+        "#[cfg(feature = \"mock-server\")]\npub mod mock_server;\n"
+        +
+        "#[cfg(feature = \"client\")]\npub mod client;\n"
+        +
+        "#[cfg(feature = \"client\")]\npub mod cassette;\n"
+        +
+        "#[cfg(feature = \"client\")]\npub mod retry;\n"
+        +
+        "#[cfg(feature = \"client\")]\npub mod provider;\n"
+        +
+        "#[cfg(feature = \"client\")]\npub mod download;\n"
+        +
         "\n\n"
         +
         &rust_schema_bodies
             .keys()
-            .map(|file_name| format!("pub use {}::*;\n", file_name.replace(".rs", "")))
-            .collect::<String>();
-    std::fs::write("src/schemas/mod.rs", &mod_content)
-        .context("Failed to write schemas mod.rs file")?;
+            .map(|file_name| format!("{}pub use {}::*;\n", feature_gate(file_name), file_name.replace(".rs", "")))
+            .collect::<String>()
+        +
+        "pub use aliases::*;\n";
 
     // Write the useful snippets to files
     for (file_name, body) in rust_schema_bodies.iter() {
+        // Types merged in through `allOf` are relied on without being
+        //  named, so only what the code names is imported
+        let uses = body.1.iter()
+            .filter(|st| st.trim_end_matches(';')
+                .rsplit("::")
+                .next()
+                .is_some_and(|typename| names_identifier(&body.0, typename)))
+            .map(|st| format!("{st}\n"))
+            .collect::<String>();
         let body = format!(
-            "{}\nuse std::collections::HashMap;\nuse serde::{{Serialize, Deserialize}};\n\n{}",
-            body.1.iter().map(|st| format!("{st}\n")).collect::<String>(),
+            "{}\n{}use serde::{{Serialize, Deserialize}};\n\n{}",
+            uses,
+            if names_identifier(&body.0, "HashMap") { "use std::collections::HashMap;\n" } else { "" },
             body.0
         );
 
-        std::fs::write(format!("src/schemas/{}.rs", file_name), body)
-            .context("Failed to write OpenAPI Rust schemas to file")?;
+        files.insert(format!("{}{}.rs", source_dir, file_name), body);
+    }
+
+    // Write the Realtime WebSocket session, which builds on the
+    //  generated event enums
+    if has_realtime_session {
+        files.insert(
+            format!("{}realtime_session.rs", source_dir),
+            include_str!("templates/realtime_session.rs").to_string()
+        );
+    }
+
+    // Each operation's client methods and mock route are only compiled
+    //  along with the modules of the types they name
+    let operation_modules = operations.iter()
+        .map(|operation| {
+            let mut modules = BTreeSet::new();
+            for r#type in operation.named_types() {
+                for typename in referenced_types(&schemas, &aliases, r#type) {
+                    match schemas.get(&typename) {
+                        Some(data) => { modules.insert(module_name(data.wanted_by())); },
+                        None => modules.extend(alias_modules.get(&typename).into_iter().flatten().cloned())
+                    }
+                }
+            }
+
+            modules
+        })
+        .collect::<Vec<BTreeSet<String>>>();
+    let operation_gates = operation_modules.iter()
+        .map(features_gate)
+        .collect::<Vec<String>>();
+
+    // Write the mock server, routing every operation
+    files.insert(
+        format!("{}mock_server.rs", source_dir),
+        format!(
+            "{}\n/// Every operation in the spec\nstatic OPERATIONS: &[MockOperation] = &[\n{}];\n",
+            include_str!("templates/mock_server.rs"),
+            operations.iter()
+                .zip(operation_gates.iter())
+                .map(|(operation, gate)| operation.mock_entry(gate))
+                .collect::<String>()
+        )
+    );
+
+    // Write the client, with a method per operation
+    let mut method_names = BTreeSet::new();
+    let mut client_methods = String::new();
+    let mut query_structs = String::new();
+    for (operation, gate) in operations.iter().zip(operation_gates.iter()) {
+        let method_name = operation.method_name();
+        let is_unique = method_names.insert(method_name.clone())
+            && method_names.insert(format!("{method_name}_all"))
+            && method_names.insert(format!("{method_name}_stream"));
+        if !is_unique {
+            bail!("Operations share the client method name {method_name}");
+        }
+
+        let Some(methods) = operation.client_methods(gate) else {
+            println!("Skipping the client method for {}, which uploads multipart form data", operation.id);
+            continue;
+        };
+        client_methods += &methods;
+        if let Some(query_struct) = operation.query_struct(gate) {
+            query_structs += &query_struct;
+        }
     }
+    let reads_errors = reads_error_responses(&schemas);
+    let error_glue = if reads_errors {
+        include_str!("templates/client_errors.rs")
+    } else {
+        println!("Keeping API errors as raw bodies, since the spec has no `ErrorResponse` holding an `Error`");
+        include_str!("templates/client_raw_errors.rs")
+    };
+    files.insert(
+        format!("{}client.rs", source_dir),
+        format!(
+            "{}\n{}
+impl Client {{\n{}}}\n\n{}\n/// The `OpenAI-Beta` header each beta operation sends\nstatic BETA_HEADERS: &[(&str, &str)] = &[\n{}];\n",
+            include_str!("templates/client.rs"),
+            error_glue,
+            client_methods,
+            query_structs,
+            operations.iter()
+                .filter_map(|operation| operation.beta_entry())
+                .collect::<String>()
+        )
+    );
+    files.insert(
+        format!("{}retry.rs", source_dir),
+        include_str!("templates/retry.rs").to_string()
+    );
+    files.insert(
+        format!("{}cassette.rs", source_dir),
+        include_str!("templates/cassette.rs").to_string()
+    );
+    files.insert(
+        format!("{}provider.rs", source_dir),
+        include_str!("templates/provider.rs").to_string()
+    );
+    files.insert(
+        format!("{}download.rs", source_dir),
+        include_str!("templates/download.rs").to_string() + &speech_format_impls(&schemas)
+    );
 
     let mut aliases = aliases.into_iter().collect::<Vec<_>>();
     let mut alias_body = String::new();
+    let mut alias_uses = BTreeSet::new();
     aliases.sort();
     aliases.dedup();
-    for ( _key, value ) in aliases.iter() {
+    for ( key, value ) in aliases.iter() {
+        // Only keep the alias when the modules it names are enabled
+        let alias_gate = alias_modules.get(key)
+            .map(features_gate)
+            .unwrap_or_default();
         let alias = format!("{}", value);
-        alias_body += &alias;
+        alias_body += &alias.replacen("pub type", &format!("{alias_gate}pub type"), 1);
         alias_body += "\n";
+
+        // Aliases can name generated types too
+        for typename in referenced_types(&schemas, &BTreeMap::new(), &value.r#type) {
+            if let Some(data) = schemas.get(&typename) {
+                let inner_file_title = module_name(data.wanted_by());
+                alias_uses.insert(format!("{}use super::{}::{};", feature_gate(&inner_file_title), inner_file_title, typename));
+            }
+        }
+    }
+    let alias_body = format!(
+        "{}\n{}\n{}",
+        alias_uses.iter().map(|st| format!("{st}\n")).collect::<String>(),
+        if names_identifier(&alias_body, "HashMap") { "use std::collections::HashMap;\n" } else { "" },
+        alias_body
+    );
+    files.insert(format!("{}aliases.rs", source_dir), alias_body);
+
+    // Write the `[features]` table, where each module's feature
+    //  enables the features of the modules it imports from
+    let mut features_content = String::from("[features]\n");
+    features_content += "default = [\"full\"]\n";
+    features_content += &format!("full = [{}]\n",
+        rust_schema_bodies.keys()
+            .filter(|file_name| is_gated(file_name))
+            .map(|file_name| format!("\"{}\"", file_name))
+            .collect::<Vec<String>>()
+            .join(", ")
+    );
+    for file_name in rust_schema_bodies.keys().filter(|file_name| is_gated(file_name)) {
+        features_content += &format!("{} = [{}]\n",
+            file_name,
+            module_dependencies.get(file_name)
+                .into_iter()
+                .flatten()
+                .filter(|dependency| is_gated(dependency))
+                .map(|dependency| format!("\"{}\"", dependency))
+                .collect::<Vec<String>>()
+                .join(", ")
+        );
+    }
+    if has_realtime_session {
+        // The session needs whichever modules the event enums ended up in
+        let event_modules = REALTIME_EVENTS.iter()
+            .filter_map(|event_key| schemas.get(*event_key))
+            .map(|data| module_name(data.wanted_by()))
+            .filter(|module_name| is_gated(module_name))
+            .collect::<BTreeSet<String>>();
+        features_content += &format!("realtime-session = [{}\"dep:tokio\", \"dep:tokio-tungstenite\", \"dep:futures-util\"]\n",
+            event_modules.iter()
+                .map(|module_name| format!("\"{module_name}\", "))
+                .collect::<String>()
+        );
+    }
+    features_content += "mock-server = [\"dep:axum\", \"dep:tokio\", \"tokio/rt\", \"tokio/sync\"]\n";
+
+    // The client only needs the modules its errors are read from, since
+    //  each operation's methods follow the features of the types they name
+    let error_modules = ["ErrorResponse", "Error"].iter()
+        .filter(|_| reads_errors)
+        .filter_map(|error_key| schemas.get(*error_key))
+        .map(|data| module_name(data.wanted_by()))
+        .filter(|module_name| is_gated(module_name))
+        .collect::<BTreeSet<String>>();
+    features_content += &format!("client = [{}\"dep:reqwest\", \"dep:futures-util\", \"dep:tokio\", \"tokio/time\", \"tokio/fs\", \"tokio/io-util\", \"dep:serde_path_to_error\"]\n",
+        error_modules.iter()
+            .map(|module_name| format!("\"{module_name}\", "))
+            .collect::<String>()
+    );
+
+    let spec_version = docs[0]["info"]["version"].as_str()
+        .context("Failed to get the spec's `info.version`")?;
+    if options.output_crate.is_some() {
+        // A standalone crate gets a manifest and a documented `lib.rs`
+        files.insert("Cargo.toml".to_string(), format!(
+            include_str!("templates/Cargo.toml.in"),
+            marker = GENERATED_MARKER,
+            name = options.crate_name,
+            license = options.license.as_ref()
+                .map(|license| format!("license = \"{license}\"\n"))
+                .unwrap_or_default(),
+            version = spec_version,
+            serde_json_features = if options.unknown_fields == UnknownFields::Preserve {
+                ", features = [\"preserve_order\"]"
+            } else {
+                ""
+            },
+            features = features_content
+        ));
+        files.insert("src/lib.rs".to_string(), format!(
+            include_str!("templates/lib.rs.in"),
+            version = spec_version,
+            modules = mod_content
+        ));
+
+        // The crate's integration tests, each behind the features it
+        //  needs, and only written when the spec has what it uses
+        let mut tests = Vec::new();
+        if has_realtime_session {
+            tests.push((
+                "realtime_session.rs",
+                BTreeSet::from([ "realtime-session".to_string() ]),
+                include_str!("templates/tests/realtime_session.rs")
+            ));
+        }
+        let client_tests: [(&str, &[&str], &str); 2] = [
+            ("cassette.rs", &["createChatCompletion"], include_str!("templates/tests/cassette.rs")),
+            ("retry.rs", &["listModels", "createEmbedding"], include_str!("templates/tests/retry.rs")),
+        ];
+        for (file_name, operation_ids, test) in client_tests {
+            let mut features = BTreeSet::from([ "client".to_string(), "mock-server".to_string() ]);
+            for operation_id in operation_ids {
+                match operations.iter().position(|operation| operation.id == *operation_id) {
+                    Some(index) => features.extend(operation_modules[index].iter().cloned()),
+                    None => {
+                        println!("Skipping the test {file_name}, since the spec has no operation {operation_id}");
+                        features.clear();
+                        break;
+                    }
+                }
+            }
+            if !features.is_empty() {
+                tests.push((file_name, features, test));
+            }
+        }
+
+        // They name the crate by its identifier
+        for (file_name, features, test) in tests {
+            files.insert(
+                format!("tests/{file_name}"),
+                features_gate(&features).replacen("#[", "#![", 1)
+                    + &test.replace("openai_types::", &format!("{crate_ident}::"))
+            );
+        }
+    } else {
+        files.insert("mod.rs".to_string(), mod_content);
+        files.insert("features.toml".to_string(), features_content);
+    }
+
+    // Repeat the inferred types below the parsing log, where they're seen
+    if !inferences.is_empty() {
+        println!("Inferred the `type` of {} schema(s) which didn't declare one:", inferences.len());
+        for inference in inferences.iter() {
+            println!(" - {inference}");
+        }
+    }
+
+    // Lastly, replace the output directory's generated files
+    let output_dir = options.output_crate.clone()
+        .unwrap_or_else(|| PathBuf::from("src/schemas"));
+    let generated_dir = output_dir.join(source_dir);
+
+    // Unless we're only checking them for drift
+    if options.check {
+        let differing = check::diff_output(&output_dir, &generated_dir, &files)
+            .context("Failed to compare against the generated files")?;
+        if differing > 0 {
+            bail!("{differing} generated file(s) in {} are out of date", output_dir.display());
+        }
+
+        println!("Generated files in {} are up to date", output_dir.display());
+        return Ok(())
+    }
+
+    // A crate's sources are only replaced if we generated them, so
+    //  `--crate` can't wipe a hand-written crate
+    if options.output_crate.is_some() && !is_empty_dir(&generated_dir)? {
+        let manifest = std::fs::read_to_string(output_dir.join("Cargo.toml"))
+            .unwrap_or_default();
+        if !manifest.starts_with(GENERATED_MARKER) {
+            bail!(
+                "Refusing to replace {}, whose Cargo.toml wasn't written by this generator",
+                generated_dir.display()
+            );
+        }
+    }
+    if generated_dir.exists() {
+        std::fs::remove_dir_all(&generated_dir)
+            .context("Failed to remove the generated directory")?;
+    }
+    std::fs::create_dir_all(&generated_dir)
+        .context("Failed to create the generated directory")?;
+    for (file_name, body) in files.iter() {
+        let path = output_dir.join(file_name);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create the directory for {}", file_name))?;
+        }
+        std::fs::write(path, body)
+            .with_context(|| format!("Failed to write {}", file_name))?;
     }
-    std::fs::write("src/schemas/aliases.rs", &alias_body)
-        .context("Failed to write OpenAPI Rust aliases to file")?;
 
     // Write the useful snippets to files
     /*
@@ -267,3 +754,173 @@ fn main() -> Result<()>{
 
     Ok(())
 }
+
+/// Whether a directory is missing or has nothing in it
+fn is_empty_dir ( dir: &std::path::Path ) -> Result<bool> {
+    if !dir.exists() {
+        return Ok(true);
+    }
+
+    let mut entries = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read {}", dir.display()))?;
+    Ok(entries.next().is_none())
+}
+/// Whether Rust code names an identifier outside of its comments
+fn names_identifier ( code: &str, identifier: &str ) -> bool {
+    let is_identifier = |character: char| character.is_alphanumeric() || character == '_';
+
+    code.lines()
+        .filter(|line| !line.trim_start().starts_with("//"))
+        .any(|line| line.match_indices(identifier).any(|(index, _)| {
+            let before = line[..index].chars().next_back();
+            let after = line[index + identifier.len()..].chars().next();
+
+            !before.is_some_and(is_identifier) && !after.is_some_and(is_identifier)
+        }))
+}
+
+/// Decides which module a type is written to from the groups wanting it.
+///
+/// Types wanted by one group live in that group's module and types
+///  wanted by several live in `shared`, so a type's path only changes
+///  when it gains its second group, never as more groups start using it.
+fn module_name ( wanted_by: &BTreeSet<String> ) -> String {
+    match wanted_by.len() {
+        0 => "uncategorized".to_string(),
+        1 => wanted_by.iter()
+            .next()
+            .expect("Unreachable wanted_by")
+            .to_string(),
+        _ => "shared".to_string()
+    }
+}
+
+/// Whether a module is behind a Cargo feature of the same name.
+///  Every group is, but the catch-all modules are always compiled.
+fn is_gated ( module_name: &str ) -> bool {
+    !matches!(module_name, "aliases" | "uncategorized")
+}
+/// The `#[cfg]` attribute line gating an item behind a module's feature
+fn feature_gate ( module_name: &str ) -> String {
+    features_gate(&BTreeSet::from([ module_name.to_string() ]))
+}
+/// The `#[cfg]` attribute line gating an item behind every given module's feature
+fn features_gate ( module_names: &BTreeSet<String> ) -> String {
+    let features = module_names.iter()
+        .filter(|module_name| is_gated(module_name))
+        .map(|module_name| format!("feature = \"{}\"", module_name))
+        .collect::<Vec<String>>();
+
+    match features.len() {
+        0 => String::new(),
+        1 => format!("#[cfg({})]\n", features[0]),
+        _ => format!("#[cfg(all({}))]\n", features.join(", "))
+    }
+}
+/// The MIME type and file extension helpers of the speech formats the
+///  spec lists, and of the speech request, behind their modules' features
+fn speech_format_impls ( schemas: &BTreeMap<String, Data> ) -> String {
+    let Some(Data::Enum(format)) = schemas.get("CreateSpeechRequestResponseFormat") else {
+        println!("Skipping the speech format helpers, since the spec has no `CreateSpeechRequestResponseFormat`");
+        return String::new();
+    };
+    if format.enum_type != EnumType::Standard {
+        println!("Skipping the speech format helpers, since `CreateSpeechRequestResponseFormat` isn't a plain enum");
+        return String::new();
+    }
+
+    // Formats this doesn't know the MIME type of are sent as plain bytes
+    let mut mime_type_arms = String::new();
+    let mut extension_arms = String::new();
+    for value in format.values.iter() {
+        let (pattern, mime_type, extension) = match value.as_str() {
+            "String(String)" => ("String(_)".to_string(), "application/octet-stream", "bin"),
+            value => (
+                Enum::variant_name(value),
+                match value {
+                    "mp3" => "audio/mpeg",
+                    "opus" => "audio/opus",
+                    "aac" => "audio/aac",
+                    "flac" => "audio/flac",
+                    "wav" => "audio/wav",
+                    "pcm" => "audio/pcm",
+                    _ => "application/octet-stream"
+                },
+                value
+            )
+        };
+        mime_type_arms += &format!("            Self::{pattern} => \"{mime_type}\",\n");
+        extension_arms += &format!("            Self::{pattern} => \"{extension}\",\n");
+    }
+
+    let format_module = module_name(&format.wanted_by);
+    let mut impls = format!(
+        "
+{}impl super::CreateSpeechRequestResponseFormat {{
+    /// The MIME type of audio in this format
+    pub fn mime_type(&self) -> &'static str {{
+        match self {{
+{}        }}
+    }}
+    /// The file extension for audio in this format, without the dot
+    pub fn extension(&self) -> &'static str {{
+        match self {{
+{}        }}
+    }}
+}}
+",
+        feature_gate(&format_module),
+        mime_type_arms,
+        extension_arms
+    );
+
+    // The request's format defaults to `mp3`, when it's optional
+    let Some(Data::Object(request)) = schemas.get("CreateSpeechRequest") else {
+        return impls;
+    };
+    let has_optional_format = matches!(
+        request.properties.get("response_format"),
+        Some(Field { value: FieldValue::ExternalType(name), required: false, .. }) if name == "CreateSpeechRequestResponseFormat"
+    );
+    if has_optional_format && format.values.iter().any(|value| value == "mp3") {
+        impls += &format!(
+            "{}impl super::CreateSpeechRequest {{
+    /// The format the audio will be in, which is `mp3` unless the request
+    ///  asks for another
+    pub fn format(&self) -> super::CreateSpeechRequestResponseFormat {{
+        self.response_format.clone()
+            .unwrap_or(super::CreateSpeechRequestResponseFormat::Mp3)
+    }}
+    /// The MIME type of the audio the request asks for
+    pub fn mime_type(&self) -> &'static str {{
+        self.format().mime_type()
+    }}
+}}
+",
+            features_gate(&BTreeSet::from([ format_module, module_name(&request.wanted_by) ]))
+        );
+    }
+
+    impls
+}
+/// Whether the spec's `ErrorResponse` has the shape the client reads: an
+///  `error` holding an `Error` with a `message`, a `type` and a `code`
+fn reads_error_responses ( schemas: &BTreeMap<String, Data> ) -> bool {
+    let (Some(Data::Object(response)), Some(Data::Object(error))) = (schemas.get("ErrorResponse"), schemas.get("Error")) else {
+        return false;
+    };
+    // Whether the field is a `String`, or an `Option` of one when it's
+    //  optional or nullable
+    let is_string = |key: &str, option: bool| matches!(
+        error.properties.get(key),
+        Some(Field { value: FieldValue::String, required, nullable, .. }) if (!*required || *nullable) == option
+    );
+
+    matches!(
+        response.properties.get("error"),
+        Some(Field { value: FieldValue::ExternalType(name), required: true, nullable: false, .. }) if name == "Error"
+    )
+        && is_string("message", false)
+        && is_string("type", false)
+        && is_string("code", true)
+}
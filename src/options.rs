@@ -0,0 +1,170 @@
+use super::data::objects::{UnknownFields, Builders, FieldOrder};
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result, bail};
+
+/// The options controlling a generation run
+#[derive(Debug)]
+pub struct Options {
+    /// How generated structs treat keys which aren't in the spec
+    pub unknown_fields: UnknownFields,
+    /// Which builder API to generate for structs
+    pub builders: Builders,
+    /// The order struct fields are written in
+    pub field_order: FieldOrder,
+    /// Where to write a standalone crate, instead of `src/schemas`
+    pub output_crate: Option<PathBuf>,
+    /// The package name of the standalone crate
+    pub crate_name: String,
+    /// The SPDX license expression of the standalone crate, if it has one
+    pub license: Option<String>,
+    /// Only compare against the existing output, writing nothing
+    pub check: bool,
+    /// A local YAML or JSON spec to read, instead of the bundled copy
+    pub spec_path: Option<PathBuf>,
+    /// Where to download the spec from, instead of the bundled copy
+    pub spec_url: Option<String>,
+    /// Where downloaded specs are cached
+    pub spec_cache: PathBuf,
+    /// The lock file pinning the downloaded spec's hash
+    pub spec_lock: PathBuf,
+    /// Accept a downloaded spec which doesn't match the lock file
+    pub update: bool,
+    /// Overlays or JSON Patches to apply to the spec, in order
+    pub overlays: Vec<PathBuf>,
+    /// Skip the bundled overlay of upstream spec fixes
+    pub default_overlay: bool,
+    /// Where to also write the types as a JSON Schema bundle
+    pub json_schema: Option<PathBuf>,
+}
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            unknown_fields: UnknownFields::default(),
+            builders: Builders::default(),
+            field_order: FieldOrder::default(),
+            output_crate: None,
+            crate_name: "openai-types".to_string(),
+            license: None,
+            check: false,
+            spec_path: None,
+            spec_url: None,
+            spec_cache: PathBuf::from("assets/spec-cache"),
+            spec_lock: PathBuf::from("openapi.lock"),
+            update: false,
+            overlays: Vec::new(),
+            default_overlay: true,
+            json_schema: None,
+        }
+    }
+}
+impl Options {
+    /// Parses the options from the command-line arguments,
+    ///  excluding the binary name
+    pub fn from_args (
+        args: impl IntoIterator<Item = String>
+    ) -> Result<Self> {
+        let mut options = Options::default();
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--unknown-fields" => {
+                    let value = args.next()
+                        .context("`--unknown-fields` expects one of `drop`, `preserve` or `deny`")?;
+
+                    options.unknown_fields = match value.as_str() {
+                        "drop" => UnknownFields::Drop,
+                        "preserve" => UnknownFields::Preserve,
+                        "deny" => UnknownFields::Deny,
+                        other => bail!("Unknown `--unknown-fields` value `{other}`")
+                    };
+                },
+                "--builders" => {
+                    let value = args.next()
+                        .context("`--builders` expects one of `none`, `constructor` or `typestate`")?;
+
+                    options.builders = match value.as_str() {
+                        "none" => Builders::None,
+                        "constructor" => Builders::Constructor,
+                        "typestate" => Builders::Typestate,
+                        other => bail!("Unknown `--builders` value `{other}`")
+                    };
+                },
+                "--field-order" => {
+                    let value = args.next()
+                        .context("`--field-order` expects one of `spec` or `required-first`")?;
+
+                    options.field_order = match value.as_str() {
+                        "spec" => FieldOrder::Spec,
+                        "required-first" => FieldOrder::RequiredFirst,
+                        other => bail!("Unknown `--field-order` value `{other}`")
+                    };
+                },
+                "--check" => {
+                    options.check = true;
+                },
+                "--spec" => {
+                    let value = args.next()
+                        .context("`--spec` expects the path of a YAML or JSON spec")?;
+
+                    options.spec_path = Some(PathBuf::from(value));
+                },
+                "--spec-url" => {
+                    options.spec_url = Some(args.next()
+                        .context("`--spec-url` expects the URL to download the spec from")?);
+                },
+                "--spec-cache" => {
+                    let value = args.next()
+                        .context("`--spec-cache` expects the directory to cache specs in")?;
+
+                    options.spec_cache = PathBuf::from(value);
+                },
+                "--spec-lock" => {
+                    let value = args.next()
+                        .context("`--spec-lock` expects the path of the lock file")?;
+
+                    options.spec_lock = PathBuf::from(value);
+                },
+                "--update" => {
+                    options.update = true;
+                },
+                "--overlay" => {
+                    let value = args.next()
+                        .context("`--overlay` expects the path of an OpenAPI Overlay or JSON Patch")?;
+
+                    options.overlays.push(PathBuf::from(value));
+                },
+                "--no-default-overlay" => {
+                    options.default_overlay = false;
+                },
+                "--crate" => {
+                    let value = args.next()
+                        .context("`--crate` expects the directory to write the crate to")?;
+
+                    options.output_crate = Some(PathBuf::from(value));
+                },
+                "--json-schema" => {
+                    let value = args.next()
+                        .context("`--json-schema` expects the path to write the bundle to")?;
+
+                    options.json_schema = Some(PathBuf::from(value));
+                },
+                "--crate-name" => {
+                    options.crate_name = args.next()
+                        .context("`--crate-name` expects a package name")?;
+                },
+                "--license" => {
+                    options.license = Some(args.next()
+                        .context("`--license` expects an SPDX license expression, such as `MIT`")?);
+                },
+                other => {
+                    bail!("Unknown argument `{other}`");
+                }
+            }
+        }
+
+        Ok(options)
+    }
+}
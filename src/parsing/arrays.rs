@@ -1,5 +1,5 @@
 use super::{
-    Data, Alias, parse,
+    Data, Alias, parse, schema_name,
 
     enums::parse_enum,
     objects::parse_object,
@@ -71,11 +71,9 @@ pub(super) fn parse_array (
 
     // Second, check if the array type is a direct external reference
     if let Some(referred_type) = property_value["items"]["$ref"].as_str() {
-        let parsed_referred_type = referred_type.split("/")
-            .skip(3)
-            .next()
+        let parsed_referred_type = schema_name(referred_type)
             .context("Failed to parse the referred type")?;
-        let referred_type_yaml = &global_yaml["components"]["schemas"][parsed_referred_type];
+        let referred_type_yaml = &global_yaml["components"]["schemas"][parsed_referred_type.as_str()];
         println!("Referred type: {} - {:#?}", parsed_referred_type, referred_type_yaml);
         
         // Add the requested type recursively
@@ -84,18 +82,18 @@ pub(super) fn parse_array (
             schemas,
             aliases,
             wanted_by.clone(),
-            parsed_referred_type,
+            &parsed_referred_type,
             &referred_type_yaml,
         )
             .with_context(|| format!("Couldn't parse the object {parsed_referred_type}"))?;
         println!("Finished recusively adding external type {parsed_referred_type}, continuing object {key}");
 
-        match schemas.get(parsed_referred_type) {
+        match schemas.get(&parsed_referred_type) {
             Some(Data::Object(_)) | Some(Data::Enum(_)) => {
                 return Ok(FieldValue::Array(parsed_referred_type.to_string()));
             },
             None => {
-                match aliases.get(parsed_referred_type) {
+                match aliases.get(&parsed_referred_type) {
                     Some(alias) => {
                         return Ok(FieldValue::Array(alias.name.clone()));
                     },
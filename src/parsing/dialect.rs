@@ -0,0 +1,387 @@
+use saphyr::Yaml;
+use anyhow::{Context, Result};
+
+/// Keys whose values map names to schemas, rather than being schemas
+pub(super) const NAME_MAPS: &[&str] = &["properties", "patternProperties", "$defs", "definitions", "schemas"];
+/// Keys whose values are instances, such as example payloads, rather
+///  than schemas
+pub(super) const VALUE_KEYWORDS: &[&str] = &["example", "examples", "default", "const", "enum"];
+
+/// Whether a key of a schema can hold a schema, so a walk over every
+///  schema should descend into it. Extensions such as `x-oaiMeta` and
+///  instance values such as `example` are skipped
+pub(super) fn is_schema_key ( key: &str ) -> bool {
+    !key.starts_with("x-") && !VALUE_KEYWORDS.contains(&key)
+}
+
+/// Loads a spec from YAML or JSON text
+///
+/// JSON is converted by hand rather than read as YAML, since not every
+///  JSON document is valid YAML (tabs, long keys and some escapes).
+pub fn load_spec ( raw: &str ) -> Result<Vec<Yaml>> {
+    if raw.trim_start().starts_with(['{', '[']) {
+        let json: serde_json::Value = serde_json::from_str(raw)
+            .context("Failed to load OpenAPI JSON")?;
+
+        return Ok(vec![json_to_yaml(json)]);
+    }
+
+    Yaml::load_from_str(raw)
+        .context("Failed to load OpenAPI YAML")
+}
+fn json_to_yaml ( json: serde_json::Value ) -> Yaml {
+    match json {
+        serde_json::Value::Null => Yaml::Null,
+        serde_json::Value::Bool(value) => Yaml::Boolean(value),
+        serde_json::Value::Number(number) => match number.as_i64() {
+            Some(integer) => Yaml::Integer(integer),
+            None => Yaml::Real(number.to_string())
+        },
+        serde_json::Value::String(string) => Yaml::String(string),
+        serde_json::Value::Array(values) => Yaml::Array(
+            values.into_iter()
+                .map(json_to_yaml)
+                .collect()
+        ),
+        serde_json::Value::Object(map) => Yaml::Hash(
+            map.into_iter()
+                .map(|(key, value)| (Yaml::String(key), json_to_yaml(value)))
+                .collect()
+        )
+    }
+}
+/// Converts YAML back into JSON, such as a schema's `example`, writing
+///  out keys which aren't strings as text
+pub(super) fn yaml_to_json ( yaml: &Yaml ) -> serde_json::Value {
+    match yaml {
+        Yaml::Null | Yaml::BadValue | Yaml::Alias(_) => serde_json::Value::Null,
+        Yaml::Boolean(value) => serde_json::Value::Bool(*value),
+        Yaml::Integer(integer) => serde_json::Value::from(*integer),
+        Yaml::Real(real) => real.parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map(serde_json::Value::Number)
+            .unwrap_or_else(|| serde_json::Value::String(real.clone())),
+        Yaml::String(string) => serde_json::Value::String(string.clone()),
+        Yaml::Array(values) => serde_json::Value::Array(
+            values.iter()
+                .map(yaml_to_json)
+                .collect()
+        ),
+        Yaml::Hash(hash) => serde_json::Value::Object(
+            hash.iter()
+                .map(|(key, value)| {
+                    let key = match key.as_str() {
+                        Some(key) => key.to_string(),
+                        None => yaml_key(key)
+                    };
+
+                    (key, yaml_to_json(value))
+                })
+                .collect()
+        )
+    }
+}
+fn yaml_key ( key: &Yaml ) -> String {
+    match key {
+        Yaml::Integer(integer) => integer.to_string(),
+        Yaml::Real(real) => real.clone(),
+        Yaml::Boolean(value) => value.to_string(),
+        _ => String::new()
+    }
+}
+
+/// Rewrites OpenAPI 3.1 (JSON Schema 2020-12) constructs into their
+///  OpenAPI 3.0 equivalents, so the parser only has to know one dialect.
+///
+/// - `type: [T, "null"]` becomes `type: T` with `nullable: true`, and
+///   several non-null types become an `anyOf`
+/// - `anyOf`/`oneOf` members of `type: "null"` become `nullable: true`
+/// - `const: v` becomes `enum: [v]`
+/// - `examples: [..]` becomes `example`, using the first one
+/// - `prefixItems` becomes `items`, as a `oneOf` if the items differ
+///
+/// Refs into `$defs` are hoisted by [`resolve_refs`](super::resolve_refs),
+///  which should run first so that schemas from other files are covered.
+///
+/// 3.0 documents pass through unchanged.
+pub fn normalize ( spec: &mut Yaml ) {
+    normalize_node(spec, false);
+}
+
+/// Normalizes a node's children, then the node itself, unless it maps
+///  names to schemas (where a property could be called `const`)
+fn normalize_node ( node: &mut Yaml, is_name_map: bool ) {
+    match node {
+        Yaml::Hash(hash) => {
+            for (child_key, child) in hash.iter_mut() {
+                if !is_name_map && child_key.as_str().is_some_and(|child_key| !is_schema_key(child_key)) {
+                    continue;
+                }
+                let child_is_name_map = !is_name_map && child_key.as_str()
+                    .is_some_and(|child_key| NAME_MAPS.contains(&child_key));
+
+                normalize_node(child, child_is_name_map);
+            }
+
+            if !is_name_map {
+                normalize_schema(hash);
+            }
+        },
+        Yaml::Array(values) => {
+            for value in values.iter_mut() {
+                normalize_node(value, false);
+            }
+        },
+        _ => { }
+    }
+}
+fn normalize_schema ( schema: &mut saphyr::Hash ) {
+    // `type: [T, "null"]`
+    if let Some(Yaml::Array(types)) = schema.get(&key("type")) {
+        let nullable = types.iter().any(|r#type| r#type.as_str() == Some("null"));
+        let types = types.iter()
+            .filter(|r#type| r#type.as_str() != Some("null"))
+            .cloned()
+            .collect::<Vec<Yaml>>();
+
+        match types.len() {
+            0 => {
+                schema.insert(key("type"), key("null"));
+            },
+            1 => {
+                schema.insert(key("type"), types[0].clone());
+            },
+            _ => {
+                schema.remove(&key("type"));
+                if !schema.contains_key(&key("anyOf")) {
+                    let variants = types.into_iter()
+                        .map(|r#type| Yaml::Hash([(key("type"), r#type)].into_iter().collect()))
+                        .collect();
+
+                    schema.insert(key("anyOf"), Yaml::Array(variants));
+                }
+            }
+        }
+        if nullable && !types_is_null(schema) {
+            schema.insert(key("nullable"), Yaml::Boolean(true));
+        }
+    }
+
+    // `anyOf: [T, { type: "null" }]`
+    for combinator in ["anyOf", "oneOf"] {
+        let Some(Yaml::Array(variants)) = schema.get(&key(combinator)) else {
+            continue;
+        };
+        if !variants.iter().any(is_null_schema) {
+            continue;
+        }
+
+        let mut variants = variants.iter()
+            .filter(|variant| !is_null_schema(variant))
+            .cloned()
+            .collect::<Vec<Yaml>>();
+        schema.insert(key("nullable"), Yaml::Boolean(true));
+
+        if variants.len() == 1 {
+            // A lone variant is the schema itself
+            schema.remove(&key(combinator));
+            if let Some(Yaml::Hash(variant)) = variants.pop() {
+                for (variant_key, variant_value) in variant {
+                    schema.entry(variant_key)
+                        .or_insert(variant_value);
+                }
+            }
+        } else {
+            schema.insert(key(combinator), Yaml::Array(variants));
+        }
+    }
+
+    // `const: v`
+    if let Some(value) = schema.remove(&key("const")) {
+        if !schema.contains_key(&key("type")) {
+            let r#type = match value {
+                Yaml::String(_) => Some("string"),
+                Yaml::Integer(_) => Some("integer"),
+                Yaml::Real(_) => Some("number"),
+                Yaml::Boolean(_) => Some("boolean"),
+                _ => None
+            };
+            if let Some(r#type) = r#type {
+                schema.insert(key("type"), key(r#type));
+            }
+        }
+        schema.entry(key("enum"))
+            .or_insert(Yaml::Array(vec![value]));
+    }
+
+    // `examples: [..]`, as opposed to a media type's map of examples
+    if let Some(Yaml::Array(examples)) = schema.get(&key("examples")) {
+        if let Some(example) = examples.first().cloned() {
+            schema.entry(key("example"))
+                .or_insert(example);
+        }
+        schema.remove(&key("examples"));
+    }
+
+    // `prefixItems: [..]`
+    let has_items = schema.get(&key("items"))
+        .is_some_and(|items| *items != Yaml::Boolean(false));
+    if let Some(Yaml::Array(prefix_items)) = schema.remove(&key("prefixItems")) {
+        // Without tuples, an explicit `items` is the closest fit
+        if has_items {
+            return;
+        }
+
+        let mut distinct: Vec<Yaml> = Vec::new();
+        for item in prefix_items {
+            if !distinct.contains(&item) {
+                distinct.push(item);
+            }
+        }
+
+        let items = if distinct.len() == 1 {
+            distinct.remove(0)
+        } else {
+            Yaml::Hash([(key("oneOf"), Yaml::Array(distinct))].into_iter().collect())
+        };
+        schema.insert(key("items"), items);
+    }
+}
+fn types_is_null ( schema: &saphyr::Hash ) -> bool {
+    schema.get(&key("type")).and_then(|r#type| r#type.as_str()) == Some("null")
+}
+fn is_null_schema ( schema: &Yaml ) -> bool {
+    schema.as_hash()
+        .is_some_and(types_is_null)
+}
+
+fn key ( name: &str ) -> Yaml {
+    Yaml::String(name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Normalizes the `input` schema, checking it gives `expected`
+    fn assert_normalized ( input: &str, expected: &str ) {
+        let mut spec = load_spec(input).unwrap().remove(0);
+        normalize(&mut spec);
+
+        assert_eq!(spec, load_spec(expected).unwrap().remove(0));
+    }
+
+    #[test]
+    fn type_array_becomes_nullable () {
+        assert_normalized("
+type: [string, 'null']
+", "
+type: string
+nullable: true
+");
+    }
+
+    #[test]
+    fn type_array_of_several_types_becomes_any_of () {
+        assert_normalized("
+type: [string, integer, 'null']
+", "
+anyOf:
+  - type: string
+  - type: integer
+nullable: true
+");
+    }
+
+    #[test]
+    fn null_member_of_any_of_becomes_nullable () {
+        assert_normalized("
+description: A pet
+anyOf:
+  - $ref: '#/components/schemas/Pet'
+  - type: 'null'
+", "
+description: A pet
+nullable: true
+$ref: '#/components/schemas/Pet'
+");
+        assert_normalized("
+oneOf:
+  - type: string
+  - type: integer
+  - type: 'null'
+", "
+nullable: true
+oneOf:
+  - type: string
+  - type: integer
+");
+    }
+
+    #[test]
+    fn const_becomes_enum () {
+        assert_normalized("
+const: auto
+", "
+type: string
+enum: [auto]
+");
+    }
+
+    #[test]
+    fn examples_becomes_example () {
+        assert_normalized("
+type: string
+examples: [first, second]
+", "
+type: string
+example: first
+");
+    }
+
+    #[test]
+    fn prefix_items_becomes_items () {
+        assert_normalized("
+type: array
+prefixItems:
+  - type: string
+  - type: string
+", "
+type: array
+items:
+  type: string
+");
+        assert_normalized("
+type: array
+prefixItems:
+  - type: string
+  - type: integer
+", "
+type: array
+items:
+  oneOf:
+    - type: string
+    - type: integer
+");
+    }
+
+    #[test]
+    fn properties_named_like_keywords_are_kept () {
+        assert_normalized("
+type: object
+properties:
+  const:
+    type: string
+  examples:
+    type: string
+", "
+type: object
+properties:
+  const:
+    type: string
+  examples:
+    type: string
+");
+    }
+}
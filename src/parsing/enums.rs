@@ -1,5 +1,5 @@
 use super::{
-    Data, Alias, parse,
+    Data, Alias, parse, schema_name,
 
     super::data::enums::{Enum, EnumType},
     arrays::parse_array
@@ -27,9 +27,7 @@ fn reserialize_yaml (
 
                 if key == "$ref" {
                     if let Some(referred_type) = value.as_str() {
-                        let referred_type = referred_type.split("/")
-                            .skip(3)
-                            .next()
+                        let referred_type = schema_name(referred_type)
                             .context("Failed to parse the referred type")?;
                         yaml_string.push_str(&format!("\t{},\n", referred_type));
 
@@ -95,9 +93,7 @@ pub(super) fn parse_enum (
         {
             // Check that it's not a foreign struct
             if let Some(referred_struct_raw) = enum_option["$ref"].as_str() {
-                let parsed_referred_struct = referred_struct_raw.split("/")
-                    .skip(3)
-                    .next()
+                let parsed_referred_struct = schema_name(referred_struct_raw)
                     .context("Failed to parse the referred struct")?;
 
                 // Add the requested struct recursively
@@ -108,15 +104,15 @@ pub(super) fn parse_enum (
                     schemas,
                     aliases,
                     wanted_by.clone(),
-                    parsed_referred_struct,
-                    &global_yaml["components"]["schemas"][parsed_referred_struct]
+                    &parsed_referred_struct,
+                    &global_yaml["components"]["schemas"][parsed_referred_struct.as_str()]
                 )
                     .with_context(|| format!("Couldn't parse the object {parsed_referred_struct}"))?;
                 println!("Finished parsing {parsed_referred_struct}, continuing with enum {key}");
 
                 // Copy all of the enum variants into this one, but first check 
                 //  that it wasn't turned into an alias.
-                if let Some(alias) = aliases.get(parsed_referred_struct) {
+                if let Some(alias) = aliases.get(&parsed_referred_struct) {
                     println!("Casting enum `{}` to `serde_json::Value` because of child enum `{}`", key, alias);
 
                     let mut description = String::from("Any of:\n---------------\n");
@@ -137,7 +133,7 @@ pub(super) fn parse_enum (
                 }
                 
                 // If not, add the referred struct's enum values to this one
-                match schemas.get(parsed_referred_struct) {
+                match schemas.get(&parsed_referred_struct) {
                     Some(Data::Enum(referred_enum)) => {
                         for value in &referred_enum.values {
                             enum_values.push(value.to_string());
@@ -202,6 +198,7 @@ pub(super) fn parse_enum (
                 enum_type: EnumType::AnyOf,
                 wanted_by: BTreeSet::from([ wanted_by.clone() ]),
                 relies_on,
+                tags: BTreeMap::new(),
             })
         );
 
@@ -214,9 +211,7 @@ pub(super) fn parse_enum (
         for enum_option in enum_options {
             // Check that it's not a foreign struct
             if let Some(referred_struct_raw) = enum_option["$ref"].as_str() {
-                let parsed_referred_struct = referred_struct_raw.split("/")
-                    .skip(3)
-                    .next()
+                let parsed_referred_struct = schema_name(referred_struct_raw)
                     .context("Failed to parse the referred struct")?;
 
                 // Add the requested struct recursively
@@ -227,14 +222,14 @@ pub(super) fn parse_enum (
                     schemas,
                     aliases,
                     wanted_by.clone(),
-                    parsed_referred_struct,
-                    &global_yaml["components"]["schemas"][parsed_referred_struct]
+                    &parsed_referred_struct,
+                    &global_yaml["components"]["schemas"][parsed_referred_struct.as_str()]
                 )
                     .with_context(|| format!("Couldn't parse the object {parsed_referred_struct}"))?;
                 println!("Finished parsing {parsed_referred_struct}, continuing with enum {key}");
 
                 // Add the referred struct to the enum
-                match schemas.get(parsed_referred_struct) {
+                match schemas.get(&parsed_referred_struct) {
                     Some(Data::Object(_)) => {
                         enum_values.push(format!("{}({})", parsed_referred_struct, parsed_referred_struct.to_case(Case::UpperCamel)));
                     },
@@ -243,7 +238,7 @@ pub(super) fn parse_enum (
                     },
                     None => {
                         // Check if it's an alias
-                        match aliases.get(parsed_referred_struct) {
+                        match aliases.get(&parsed_referred_struct) {
                             Some(alias) => {
                                 enum_values.push(format!("{}({})", parsed_referred_struct, alias.name));
                             },
@@ -277,9 +272,7 @@ pub(super) fn parse_enum (
                 let cased_enum_type = enum_type.to_case(Case::UpperCamel);
                 if enum_type == "array" {
                     let array_type = if let Some(referred_type) = enum_option["items"]["$ref"].as_str() {
-                        let referred_type = referred_type.split("/")
-                            .skip(3)
-                            .next()
+                        let referred_type = schema_name(referred_type)
                             .context("Failed to parse the referred type")?;
 
                         // Add the requested struct recursively
@@ -290,8 +283,8 @@ pub(super) fn parse_enum (
                             schemas,
                             aliases,
                             wanted_by.clone(),
-                            referred_type,
-                            &global_yaml["components"]["schemas"][referred_type]
+                            &referred_type,
+                            &global_yaml["components"]["schemas"][referred_type.as_str()]
                         )
                             .with_context(|| format!("Couldn't parse the object {referred_type}"))?;
                         println!("Finished parsing {referred_type}, continuing with enum {key}");
@@ -371,6 +364,7 @@ pub(super) fn parse_enum (
                 enum_type: EnumType::OneOf,
                 wanted_by: BTreeSet::from([ wanted_by ]),
                 relies_on,
+                tags: BTreeMap::new(),
             })
         );
     
@@ -396,9 +390,99 @@ pub(super) fn parse_enum (
             enum_type: EnumType::Standard,
             wanted_by: BTreeSet::from([ wanted_by.clone() ]),
             relies_on,
+            tags: BTreeMap::new(),
         })
     );
     println!("Added enum: {}", key);
 
     Ok(())
 }
+pub fn parse_tagged_enum (
+    global_yaml: &Yaml,
+
+    schemas: &mut BTreeMap<String, Data>,
+    aliases: &mut BTreeMap<String, Alias>,
+    wanted_by: String,
+
+    key: &str,
+    value: &Yaml
+) -> Result<()> {
+    println!("Parsing as tagged enum: {value:#?}");
+
+    let description = value["description"].as_str();
+    let tag = value["discriminator"]["propertyName"].as_str()
+        .context("Tagged enums need a `discriminator.propertyName`")?;
+    let enum_options = value["anyOf"].as_vec()
+        .or(value["oneOf"].as_vec())
+        .context("Tagged enums need an `anyOf` or `oneOf`")?;
+
+    let mut enum_values = Vec::new();
+    let mut tags = BTreeMap::new();
+    let mut relies_on = BTreeSet::new();
+    for enum_option in enum_options {
+        let referred_struct_raw = enum_option["$ref"].as_str()
+            .context("Tagged enum variants must be references")?;
+        let parsed_referred_struct = schema_name(referred_struct_raw)
+            .context("Failed to parse the referred struct")?;
+        let referred_struct_yaml = &global_yaml["components"]["schemas"][parsed_referred_struct.as_str()];
+
+        // The variant's tag is the only value its tag property allows
+        let tag_value = referred_struct_yaml["properties"][tag]["enum"][0].as_str()
+            .with_context(|| format!("Variant {parsed_referred_struct} has no single `{tag}` value"))?;
+
+        // Add the requested struct recursively
+        println!("Need to recurse for tagged enum: {parsed_referred_struct}");
+        relies_on.insert(parsed_referred_struct.to_string());
+        parse(
+            global_yaml,
+            schemas,
+            aliases,
+            wanted_by.clone(),
+            &parsed_referred_struct,
+            referred_struct_yaml
+        )
+            .with_context(|| format!("Couldn't parse the object {parsed_referred_struct}"))?;
+        println!("Finished parsing {parsed_referred_struct}, continuing with tagged enum {key}");
+
+        // `serde` reads and writes the tag itself, so the variant
+        //  can't also have it as a field
+        match schemas.get_mut(&parsed_referred_struct) {
+            Some(Data::Object(referred_object)) => {
+                referred_object.properties.remove(tag);
+            },
+            _ => {
+                bail!("Referred type {parsed_referred_struct} is not an object in a tagged enum");
+            }
+        }
+
+        // Which also leaves the single-value enum generated for it unused
+        schemas.remove(&format!("{}{}", parsed_referred_struct, tag.to_case(Case::UpperCamel)));
+
+        // Drop the enum's own name from the variant, so that
+        //  `RealtimeServerEventError` becomes `Error`
+        let variant = parsed_referred_struct.strip_prefix(key)
+            .filter(|variant| !variant.is_empty())
+            .unwrap_or(&parsed_referred_struct);
+        let enum_value = format!("{}({})", variant, parsed_referred_struct);
+
+        tags.insert(enum_value.clone(), tag_value.to_string());
+        enum_values.push(enum_value);
+    }
+
+    // Add the enum to the schema
+    schemas.insert(
+        key.to_string(),
+        Data::Enum(Enum {
+            name: key.to_string(),
+            description: description.map(|s| s.to_string()),
+            values: enum_values,
+            enum_type: EnumType::Tagged(tag.to_string()),
+            wanted_by: BTreeSet::from([ wanted_by ]),
+            relies_on,
+            tags,
+        })
+    );
+    println!("Added tagged enum: {}", key);
+
+    Ok(())
+}
@@ -0,0 +1,271 @@
+use super::{
+    Data, Alias,
+    super::data::{enums::{Enum, EnumType}, objects::{UnknownFields, field_name}}
+};
+
+use std::collections::BTreeMap;
+
+use saphyr::Yaml;
+use anyhow::{Context, Result, bail};
+
+/// The indentation of each level of an example
+const INDENT: &str = "    ";
+
+/// A request body from one of an operation's `x-oaiMeta` examples
+#[derive(Debug)]
+pub struct RequestExample {
+    /// What the example shows, when the operation has several
+    pub title: Option<String>,
+    /// The JSON body its curl command sends
+    pub body: serde_json::Value,
+}
+
+/// Gets the JSON bodies of an operation's curl examples, skipping any
+///  which don't send valid JSON
+pub fn request_examples ( operation: &Yaml ) -> Vec<RequestExample> {
+    // Operations have either one example, or a list of titled ones
+    let examples = match &operation["x-oaiMeta"]["examples"] {
+        Yaml::Array(examples) => examples.iter().collect::<Vec<&Yaml>>(),
+        Yaml::Hash(_) => vec!(&operation["x-oaiMeta"]["examples"]),
+        _ => Vec::new()
+    };
+
+    let mut request_examples = Vec::new();
+    for example in examples {
+        let title = example["title"].as_str()
+            .map(|title| title.to_string());
+        let Some(curl) = example["request"]["curl"].as_str() else {
+            continue;
+        };
+
+        match curl_body(curl) {
+            Ok(Some(body)) => request_examples.push(RequestExample { title, body }),
+            Ok(None) => { },
+            Err(error) => {
+                println!("Skipping a curl example ({}): {error:#}", title.as_deref().unwrap_or("untitled"));
+            }
+        }
+    }
+
+    request_examples
+}
+/// The JSON a curl command sends with `-d '...'`, if it sends any
+fn curl_body ( curl: &str ) -> Result<Option<serde_json::Value>> {
+    let Some(start) = ["-d '", "--data '", "--data-raw '"].iter()
+        .find_map(|flag| curl.find(flag).map(|start| start + flag.len()))
+    else {
+        return Ok(None);
+    };
+    let end = curl.rfind('\'')
+        .filter(|end| *end >= start)
+        .context("The body's quote is never closed")?;
+
+    let body = curl[start..end].trim();
+    if body.is_empty() {
+        return Ok(None);
+    }
+
+    serde_json::from_str(body)
+        .map(Some)
+        .context("The body isn't valid JSON")
+}
+
+/// Writes the Rust expression building `value` as the generated type
+///  `type`, the way it would deserialize
+pub fn rust_expression (
+    schemas: &BTreeMap<String, Data>,
+    aliases: &BTreeMap<String, Alias>,
+
+    r#type: &str,
+    value: &serde_json::Value,
+    depth: usize
+) -> Result<String> {
+    let r#type = r#type.replace("[]", "");
+
+    // Containers
+    if let Some(inner) = generic(&r#type, "Vec<") {
+        let values = value.as_array()
+            .with_context(|| format!("Expected a list for {}", r#type))?;
+
+        let items = values.iter()
+            .map(|value| rust_expression(schemas, aliases, inner, value, depth + 1))
+            .collect::<Result<Vec<String>>>()?;
+        return Ok(multiline("vec![", items, "]", depth));
+    }
+    if let Some(inner) = generic(&r#type, "Box<") {
+        return Ok(format!("Box::new({})", rust_expression(schemas, aliases, inner, value, depth)?));
+    }
+    if let Some(inner) = generic(&r#type, "HashMap<String, ") {
+        let entries = value.as_object()
+            .with_context(|| format!("Expected a map for {}", r#type))?;
+        if entries.is_empty() {
+            return Ok("HashMap::new()".to_string());
+        }
+
+        let entries = entries.iter()
+            .map(|(key, value)| Ok(format!(
+                "({:?}.to_string(), {})",
+                key,
+                rust_expression(schemas, aliases, inner, value, depth + 1)?
+            )))
+            .collect::<Result<Vec<String>>>()?;
+        return Ok(multiline("HashMap::from([", entries, "])", depth));
+    }
+
+    // Primitives
+    match (r#type.as_str(), value) {
+        ("String", serde_json::Value::String(string)) => return Ok(format!("{:?}.to_string()", string)),
+        ("bool", serde_json::Value::Bool(boolean)) => return Ok(boolean.to_string()),
+        ("i64", serde_json::Value::Number(number)) if number.is_i64() => return Ok(number.to_string()),
+        ("f64", serde_json::Value::Number(number)) => {
+            let number = number.as_f64()
+                .context("The number doesn't fit in an `f64`")?;
+
+            return Ok(format!("{:?}", number));
+        },
+        ("serde_json::Value", value) => return Ok(format!("serde_json::json!({})", value)),
+        ("String" | "bool" | "i64" | "f64", value) => bail!("Expected {}, found {value}", r#type),
+        _ => { }
+    }
+
+    // Generated types
+    match schemas.get(&r#type) {
+        Some(Data::Object(object)) => {
+            let fields = value.as_object()
+                .with_context(|| format!("Expected an object for {}", object.name))?;
+            if let Some(unknown) = fields.keys().find(|key| !object.properties.contains_key(*key)) {
+                bail!("`{unknown}` isn't a field of {}", object.name);
+            }
+
+            // Leave out the unset fields if they can all default
+            let has_default = object.properties.values().all(|field| !field.required);
+            let mut lines = Vec::new();
+            for (key, field) in object.properties.iter() {
+                let is_option = !field.required || field.nullable;
+                let value = fields.get(key)
+                    .filter(|value| !is_option || !value.is_null());
+                let expression = match value {
+                    Some(value) => rust_expression(schemas, aliases, &field.value.to_string(), value, depth + 1)
+                        .with_context(|| format!("Failed to write {}.{key}", object.name))?,
+                    None if field.required && !field.nullable => bail!("{}.{key} is required", object.name),
+                    None if has_default => continue,
+                    None => "None".to_string()
+                };
+
+                if !is_option || expression == "None" {
+                    lines.push(format!("{}: {expression}", field_name(key)));
+                } else {
+                    lines.push(format!("{}: Some({expression})", field_name(key)));
+                }
+            }
+
+            // Fields can't be listed after `..Default::default()`, so
+            //  the preserved unknown keys only go in when it's absent
+            let defaulted = has_default && lines.len() < object.properties.len();
+            if !defaulted && object.unknown_fields == UnknownFields::Preserve {
+                lines.push("extra: serde_json::Map::new()".to_string());
+            }
+
+            let indentation = INDENT.repeat(depth + 1);
+            let mut expression = format!("{} {{\n", object.name);
+            for line in lines {
+                expression.push_str(&format!("{indentation}{line},\n"));
+            }
+            if defaulted {
+                expression.push_str(&format!("{indentation}..Default::default()\n"));
+            }
+            expression.push_str(&format!("{}}}", INDENT.repeat(depth)));
+
+            Ok(expression)
+        },
+        Some(Data::Enum(r#enum)) => enum_expression(schemas, aliases, r#enum, value, depth),
+        None => match aliases.get(&r#type) {
+            Some(alias) => rust_expression(schemas, aliases, &alias.r#type, value, depth),
+            None => bail!("Unknown type {}", r#type)
+        }
+    }
+}
+fn enum_expression (
+    schemas: &BTreeMap<String, Data>,
+    aliases: &BTreeMap<String, Alias>,
+
+    r#enum: &Enum,
+    value: &serde_json::Value,
+    depth: usize
+) -> Result<String> {
+    let name = r#enum.name.replace("[]", "");
+
+    match r#enum.enum_type {
+        EnumType::Standard => {
+            let string = value.as_str()
+                .with_context(|| format!("Expected a string for {name}"))?;
+
+            if r#enum.values.iter().any(|value| value == string) {
+                Ok(format!("{name}::{}", Enum::variant_name(string)))
+            } else if r#enum.values.iter().any(|value| value == "String(String)") {
+                Ok(format!("{name}::String({:?}.to_string())", string))
+            } else {
+                bail!("`{string}` isn't a value of {name}")
+            }
+        },
+        // Untagged enums take the first variant which fits, like `serde` does
+        EnumType::OneOf | EnumType::AnyOf => {
+            for variant in r#enum.values.iter() {
+                let Some((variant_name, inner)) = variant_parts(variant) else {
+                    continue;
+                };
+
+                if let Ok(expression) = rust_expression(schemas, aliases, inner, value, depth) {
+                    return Ok(format!("{name}::{variant_name}({expression})"));
+                }
+            }
+
+            bail!("No variant of {name} fits {value}")
+        },
+        EnumType::Tagged(ref tag) => {
+            let mut fields = value.as_object()
+                .with_context(|| format!("Expected an object for {name}"))?
+                .clone();
+            let tag_value = fields.remove(tag)
+                .with_context(|| format!("Expected a `{tag}` for {name}"))?;
+
+            let variant = r#enum.values.iter()
+                .find(|variant| r#enum.tags.get(*variant).map(|tag| tag.as_str()) == tag_value.as_str())
+                .with_context(|| format!("No variant of {name} is tagged {tag_value}"))?;
+
+            match variant_parts(variant) {
+                Some((variant_name, inner)) => Ok(format!(
+                    "{name}::{variant_name}({})",
+                    rust_expression(schemas, aliases, inner, &serde_json::Value::Object(fields), depth)?
+                )),
+                None => Ok(format!("{name}::{variant}"))
+            }
+        }
+    }
+}
+
+/// Splits `Variant(Type)` into its name and type
+fn variant_parts ( variant: &str ) -> Option<(&str, &str)> {
+    let (variant_name, inner) = variant.split_once('(')?;
+
+    Some((variant_name, inner.strip_suffix(')')?))
+}
+/// The type inside `Wrapper<...>`, if `type` is one
+fn generic<'a> ( r#type: &'a str, wrapper: &str ) -> Option<&'a str> {
+    r#type.strip_prefix(wrapper)?
+        .strip_suffix('>')
+}
+/// Writes items one per line between `open` and `close`, indented one
+///  level deeper than `depth`
+fn multiline ( open: &str, items: Vec<String>, close: &str, depth: usize ) -> String {
+    if items.is_empty() {
+        return format!("{open}{close}");
+    }
+
+    let indentation = INDENT.repeat(depth + 1);
+    let items = items.iter()
+        .map(|item| format!("{indentation}{item},\n"))
+        .collect::<String>();
+
+    format!("{open}\n{items}{}{close}", INDENT.repeat(depth))
+}
@@ -0,0 +1,242 @@
+use super::{
+    Data, Alias,
+    super::data::{enums::EnumType, objects::FieldValue}
+};
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use regex::Regex;
+
+/// Finds the generated types named in a Rust type, such as the
+///  `ChatCompletionTool` in `Vec<ChatCompletionTool>`
+pub fn referenced_types (
+    schemas: &BTreeMap<String, Data>,
+    aliases: &BTreeMap<String, Alias>,
+
+    r#type: &str
+) -> BTreeSet<String> {
+    let identifier = Regex::new(r"[A-Za-z_][A-Za-z0-9_]*").unwrap();
+
+    identifier.find_iter(r#type)
+        .map(|found| found.as_str())
+        .filter(|name| schemas.contains_key(*name) || aliases.contains_key(*name))
+        .map(|name| name.to_string())
+        .collect()
+}
+
+/// Completes each type's `relies_on` with every type its fields and
+///  variants name, then gives each type the groups of all the types
+///  relying on it.
+///
+/// Parsing only records the group which reached a type first, so this
+///  is what makes `wanted_by` independent of the order paths are walked in.
+pub fn link (
+    schemas: &mut BTreeMap<String, Data>,
+    aliases: &BTreeMap<String, Alias>
+) {
+    // Collect the types named by each schema
+    let mut named = BTreeMap::new();
+    for (key, data) in schemas.iter() {
+        let mut relies_on = BTreeSet::new();
+
+        match data {
+            Data::Object(object) => {
+                for field in object.properties.values() {
+                    relies_on.extend(referenced_types(schemas, aliases, &format!("{}", field.value)));
+                }
+            },
+            Data::Enum(r#enum) => {
+                // Only `Variant(Type)` values name a type
+                if r#enum.enum_type != EnumType::Standard {
+                    for value in r#enum.values.iter() {
+                        if let Some((_variant, r#type)) = value.split_once('(') {
+                            relies_on.extend(referenced_types(schemas, aliases, r#type));
+                        }
+                    }
+                }
+            }
+        }
+        relies_on.remove(key);
+
+        named.insert(key.clone(), relies_on);
+    }
+    for (key, relies_on) in named.into_iter() {
+        if let Some(data) = schemas.get_mut(&key) {
+            data.relies_on_mut().extend(relies_on);
+        }
+    }
+
+    // Aliases aren't grouped, so look through them to what they name
+    let mut alias_targets: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for (key, alias) in aliases.iter() {
+        alias_targets.insert(key.clone(), referenced_types(schemas, aliases, &alias.r#type));
+    }
+
+    // Push each type's groups down to what it relies on, until nothing changes
+    loop {
+        let mut additions = Vec::new();
+        for data in schemas.values() {
+            let mut pending = data.relies_on().iter().cloned().collect::<Vec<String>>();
+            let mut visited = BTreeSet::new();
+
+            while let Some(typename) = pending.pop() {
+                if !visited.insert(typename.clone()) {
+                    continue;
+                }
+
+                if let Some(relied_on) = schemas.get(&typename) {
+                    if !data.wanted_by().is_subset(relied_on.wanted_by()) {
+                        additions.push((typename, data.wanted_by().clone()));
+                    }
+                } else if let Some(targets) = alias_targets.get(&typename) {
+                    pending.extend(targets.iter().cloned());
+                }
+            }
+        }
+
+        if additions.is_empty() {
+            break;
+        }
+        for (typename, wanted_by) in additions {
+            if let Some(data) = schemas.get_mut(&typename) {
+                data.wanted_by_mut().extend(wanted_by);
+            }
+        }
+    }
+}
+
+/// Where a type holds another by value
+#[derive(Debug, Clone, PartialEq)]
+enum Holder {
+    /// A struct's field, by its key
+    Field(String),
+    /// An enum's variant, by its index
+    Variant(usize),
+}
+
+/// Boxes the fields and variants which would make a type contain itself,
+///  such as a filter whose field holds another filter.
+///
+/// Only by-value references count, as `Vec` and `HashMap` already put
+///  their items behind a pointer. The type graph is walked depth-first
+///  and each edge back to a type still being walked is boxed, which
+///  leaves no by-value cycles.
+pub fn box_cycles (
+    schemas: &mut BTreeMap<String, Data>,
+    aliases: &BTreeMap<String, Alias>
+) {
+    // Collect what each type holds by value
+    let mut held = BTreeMap::new();
+    for (key, data) in schemas.iter() {
+        let mut edges = Vec::new();
+
+        match data {
+            Data::Object(object) => {
+                for (field_key, field) in object.properties.iter() {
+                    if let FieldValue::ExternalType(ref r#type) = field.value
+                        && let Some(target) = held_type(schemas, aliases, r#type)
+                    {
+                        edges.push((Holder::Field(field_key.clone()), target));
+                    }
+                }
+            },
+            Data::Enum(r#enum) => {
+                if r#enum.enum_type != EnumType::Standard {
+                    for (index, value) in r#enum.values.iter().enumerate() {
+                        let held_by_variant = value.split_once('(')
+                            .and_then(|(_variant, r#type)| r#type.strip_suffix(')'))
+                            .and_then(|r#type| held_type(schemas, aliases, r#type));
+
+                        if let Some(target) = held_by_variant {
+                            edges.push((Holder::Variant(index), target));
+                        }
+                    }
+                }
+            }
+        }
+
+        held.insert(key.clone(), edges);
+    }
+
+    // Find the edges which close a cycle
+    let mut finished = BTreeSet::new();
+    let mut walking = Vec::new();
+    let mut back_edges = Vec::new();
+    for key in held.keys() {
+        find_back_edges(&held, key, &mut walking, &mut finished, &mut back_edges);
+    }
+
+    for (key, holder) in back_edges {
+        println!("Boxing {key} {holder:?} to break a cycle");
+
+        match (schemas.get_mut(&key), holder) {
+            (Some(Data::Object(object)), Holder::Field(field_key)) => {
+                if let Some(field) = object.properties.get_mut(&field_key)
+                    && let FieldValue::ExternalType(ref r#type) = field.value
+                {
+                    field.value = FieldValue::Boxed(r#type.clone());
+                }
+            },
+            (Some(Data::Enum(r#enum)), Holder::Variant(index)) => {
+                let value = r#enum.values[index].clone();
+                if let Some((variant, r#type)) = value.split_once('(')
+                    .and_then(|(variant, r#type)| Some((variant, r#type.strip_suffix(')')?)))
+                {
+                    let boxed = format!("{}(Box<{}>)", variant, r#type);
+
+                    // Tagged enums key their tags by variant
+                    if let Some(tag) = r#enum.tags.remove(&value) {
+                        r#enum.tags.insert(boxed.clone(), tag);
+                    }
+                    r#enum.values[index] = boxed;
+                }
+            },
+            _ => { }
+        }
+    }
+}
+/// The generated type a field or variant of `type` holds by value,
+///  looking through aliases which merely rename one
+fn held_type (
+    schemas: &BTreeMap<String, Data>,
+    aliases: &BTreeMap<String, Alias>,
+
+    r#type: &str
+) -> Option<String> {
+    let mut r#type = r#type.replace("[]", "");
+    let mut visited = BTreeSet::new();
+
+    while visited.insert(r#type.clone()) {
+        if schemas.contains_key(&r#type) {
+            return Some(r#type);
+        }
+
+        r#type = aliases.get(&r#type)?.r#type.clone();
+    }
+
+    None
+}
+fn find_back_edges (
+    held: &BTreeMap<String, Vec<(Holder, String)>>,
+    key: &String,
+
+    walking: &mut Vec<String>,
+    finished: &mut BTreeSet<String>,
+    back_edges: &mut Vec<(String, Holder)>
+) {
+    if finished.contains(key) || walking.contains(key) {
+        return;
+    }
+
+    walking.push(key.clone());
+    for (holder, target) in held.get(key).into_iter().flatten() {
+        if walking.contains(target) {
+            back_edges.push((key.clone(), holder.clone()));
+        } else {
+            find_back_edges(held, target, walking, finished, back_edges);
+        }
+    }
+    walking.pop();
+
+    finished.insert(key.clone());
+}
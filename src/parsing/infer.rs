@@ -0,0 +1,140 @@
+use super::dialect::{NAME_MAPS, is_schema_key};
+
+use saphyr::Yaml;
+
+/// Keys which make a schema a combination or a reference, so it needs no `type`
+const UNTYPED_FORMS: &[&str] = &["$ref", "anyOf", "oneOf", "allOf", "not"];
+/// Constraints which only apply to numbers
+const NUMERIC_CONSTRAINTS: &[&str] = &["minimum", "maximum", "exclusiveMinimum", "exclusiveMaximum", "multipleOf"];
+
+/// A `type` filled in for a schema which didn't declare one
+#[derive(Debug)]
+pub struct Inference {
+    /// The JSON Pointer of the schema
+    pub pointer: String,
+    /// The inferred type
+    pub r#type: &'static str,
+    /// What it was inferred from
+    pub reason: &'static str,
+}
+impl std::fmt::Display for Inference {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} has no `type`, inferred `{}` from {}", self.pointer, self.r#type, self.reason)
+    }
+}
+
+/// Gives every schema without a `type` the one its other keywords imply:
+///
+/// - `properties`, `additionalProperties` or a `required` list mean `object`
+/// - an `enum` of only strings means `string`, and of only integers `integer`
+/// - `items` means `array`
+/// - numeric constraints such as `minimum` mean `number`
+///
+/// A named schema with none of these, such as `{}` or one with only a
+///  `description`, accepts any value, so it's left untyped to become a
+///  `serde_json::Value`.
+///
+/// Each inference is returned so it can be reported.
+pub fn infer_types ( spec: &mut Yaml ) -> Vec<Inference> {
+    let mut inferences = Vec::new();
+    infer_node(spec, "#".to_string(), false, false, &mut inferences);
+
+    inferences
+}
+fn infer_node (
+    node: &mut Yaml,
+    pointer: String,
+    is_name_map: bool,
+    is_named_schema: bool,
+
+    inferences: &mut Vec<Inference>
+) {
+    match node {
+        Yaml::Hash(hash) => {
+            for (child_key, child) in hash.iter_mut() {
+                let Some(child_key) = child_key.as_str() else {
+                    continue;
+                };
+                if !is_name_map && !is_schema_key(child_key) {
+                    continue;
+                }
+                let child_is_name_map = !is_name_map && NAME_MAPS.contains(&child_key);
+
+                infer_node(
+                    child,
+                    format!("{pointer}/{}", child_key.replace('~', "~0").replace('/', "~1")),
+                    child_is_name_map,
+                    is_name_map,
+                    inferences
+                );
+            }
+
+            if is_name_map || hash.contains_key(&key("type")) {
+                return;
+            }
+            if UNTYPED_FORMS.iter().any(|form| hash.contains_key(&key(form))) {
+                return;
+            }
+
+            if let Some((r#type, reason)) = infer_type(hash) {
+                let inference = Inference { pointer, r#type, reason };
+                println!("Diagnostic: {inference}");
+
+                hash.insert(key("type"), key(r#type));
+                inferences.push(inference);
+            } else if is_named_schema {
+                // Only named schemas are known to be schemas, rather than
+                //  some other part of the spec, so only they're reported
+                let inference = Inference {
+                    pointer,
+                    r#type: "serde_json::Value",
+                    reason: "having no keyword that implies a type"
+                };
+                println!("Diagnostic: {inference}");
+
+                inferences.push(inference);
+            }
+        },
+        Yaml::Array(values) => {
+            for (index, value) in values.iter_mut().enumerate() {
+                infer_node(value, format!("{pointer}/{index}"), false, false, inferences);
+            }
+        },
+        _ => { }
+    }
+}
+fn infer_type ( schema: &saphyr::Hash ) -> Option<(&'static str, &'static str)> {
+    if schema.contains_key(&key("properties")) {
+        return Some(("object", "its `properties`"));
+    }
+    if schema.contains_key(&key("additionalProperties")) {
+        return Some(("object", "its `additionalProperties`"));
+    }
+    // Parameters have a boolean `required`, schemas list their required properties
+    if let Some(Yaml::Array(_)) = schema.get(&key("required")) {
+        return Some(("object", "its `required` list"));
+    }
+
+    if let Some(Yaml::Array(values)) = schema.get(&key("enum")) {
+        if !values.is_empty() && values.iter().all(|value| matches!(value, Yaml::String(_))) {
+            return Some(("string", "its `enum` of strings"));
+        }
+        if !values.is_empty() && values.iter().all(|value| matches!(value, Yaml::Integer(_))) {
+            return Some(("integer", "its `enum` of integers"));
+        }
+    }
+
+    if let Some(Yaml::Hash(_)) = schema.get(&key("items")) {
+        return Some(("array", "its `items`"));
+    }
+
+    if NUMERIC_CONSTRAINTS.iter().any(|constraint| schema.contains_key(&key(constraint))) {
+        return Some(("number", "its numeric constraints"));
+    }
+
+    None
+}
+
+fn key ( name: &str ) -> Yaml {
+    Yaml::String(name.to_string())
+}
@@ -1,14 +1,30 @@
 mod enums;
 mod objects;
 mod arrays;
+mod graph;
+mod dialect;
+mod refs;
+mod overlay;
+mod infer;
+mod examples;
+mod operations;
 
 use super::data::enums::Enum;
 use super::data::objects::Object;
+use super::data::docs::doc_comment;
 use enums::parse_enum;
 use objects::parse_object;
+pub use enums::parse_tagged_enum;
+pub use graph::{link, box_cycles, referenced_types};
+pub use dialect::{load_spec, normalize};
+pub use refs::{resolve_refs, schema_name};
+pub use overlay::apply_patch;
+pub use infer::infer_types;
+pub use examples::{RequestExample, request_examples, rust_expression};
+pub use operations::{parse_operations, find_pagination};
 use arrays::parse_array;
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use saphyr::Yaml;
 use anyhow::{Context, Result, bail};
@@ -18,6 +34,34 @@ pub enum Data {
     Object(Object),
     Enum(Enum)
 }
+impl Data {
+    /// Which groups want this type
+    pub fn wanted_by ( &self ) -> &BTreeSet<String> {
+        match self {
+            Data::Object(object) => &object.wanted_by,
+            Data::Enum(r#enum) => &r#enum.wanted_by
+        }
+    }
+    pub fn wanted_by_mut ( &mut self ) -> &mut BTreeSet<String> {
+        match self {
+            Data::Object(object) => &mut object.wanted_by,
+            Data::Enum(r#enum) => &mut r#enum.wanted_by
+        }
+    }
+    /// Which types this type relies on
+    pub fn relies_on ( &self ) -> &BTreeSet<String> {
+        match self {
+            Data::Object(object) => &object.relies_on,
+            Data::Enum(r#enum) => &r#enum.relies_on
+        }
+    }
+    pub fn relies_on_mut ( &mut self ) -> &mut BTreeSet<String> {
+        match self {
+            Data::Object(object) => &mut object.relies_on,
+            Data::Enum(r#enum) => &mut r#enum.relies_on
+        }
+    }
+}
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Alias {
     /// The name of the alias
@@ -33,9 +77,7 @@ impl std::fmt::Display for Alias {
 
         // Write the alias description
         if let Some(ref description) = self.description {
-            for line in description.lines() {
-                body.push_str(&format!("/// {}\n", line));
-            }
+            body.push_str(&doc_comment(description, ""));
         }
 
         // Write the alias name
@@ -75,6 +117,47 @@ pub fn parse (
         return Ok(())
     }
 
+    // Stand in for the type while it's parsed, so a reference back to it
+    //  (directly or through other types) finds it instead of recursing
+    //  forever. Whatever it's parsed as replaces this
+    schemas.insert(key.to_string(), Data::Object(Object {
+        name: key.to_string(),
+        description: None,
+        properties: Default::default(),
+        wanted_by: BTreeSet::from([ wanted_by.clone() ]),
+        relies_on: BTreeSet::new(),
+        unknown_fields: Default::default(),
+        builders: Default::default(),
+        field_order: Default::default(),
+        examples: Vec::new(),
+    }));
+
+    let parsed = parse_schema(
+        global_yaml,
+        schemas,
+        aliases,
+        wanted_by,
+        key,
+        value
+    );
+
+    // Types parsed as aliases, or not at all, leave the stand-in behind
+    if parsed.is_err() || aliases.contains_key(key) {
+        schemas.remove(key);
+    }
+
+    parsed
+}
+fn parse_schema (
+    global_yaml: &Yaml,
+
+    schemas: &mut BTreeMap<String, Data>,
+    aliases: &mut BTreeMap<String, Alias>,
+    wanted_by: String,
+
+    key: &str,
+    value: &Yaml
+) -> Result<()> {
     // Check if the schema is an enum with `anyOf`, `oneOf`
     if value["anyOf"].as_vec().is_some() || value["oneOf"].as_vec().is_some() {
         parse_enum(
@@ -228,68 +311,36 @@ pub fn parse (
 
             println!("Finished parsing {key} (boolean)");
         }
+        Some(r#type @ ("integer" | "number")) => {
+            // Create an alias for the number
+            let description = value["description"].as_str()
+                .map(|st| st.to_string());
+
+            aliases.insert(key.to_string(), Alias {
+                name: key.to_string(),
+                r#type: if r#type == "integer" { "i64" } else { "f64" }.to_string(),
+                description,
+            });
+
+            println!("Finished parsing {} ({})", key, r#type);
+        },
         Some(other) => {
             println!("Unknown type `{:#?}`!\nErroneous Value: {:#?}", other, value);
             bail!("Unsupported type found");
         },
         None => {
-            let assured_objects = vec!(
-                "ImagesResponse",
-                "OpenAIFile",
-                "ListMessagesResponse",
-                "Model",
-                "ListRunStepsResponse",
-                "ListThreadsResponse",
-                "ListVectorStoreFilesResponse",
-                "ListVectorStoresResponse"
-            );
-
-            if assured_objects.contains(&key) {
-                println!("Bypassing struct `{key}`.");
-                parse_object(
-                    &global_yaml,
-                    schemas,
-                    aliases,
-                    wanted_by,
-                    key,
-                    value
-                )
-                    .with_context(|| format!("Couldn't parse the object {key}"))?;
-                println!("Finished parsing {key} (object)");
-
-                return Ok(())
-            }
-
-            // If we still aren't sure, but there's an `items` field, we 
-            //  can assume it's an array
-            if let Some(_) = value["items"].as_hash() {
-                // Create an alias for the array
-                let array_field_value = parse_array(
-                    &global_yaml,
-                    schemas,
-                    aliases,
-                    wanted_by,
-                    key,
-                    key,
-                    value
-                )
-                    .with_context(|| format!("Couldn't parse the array {key}"))?;
-                let description = value["description"].as_str()
-                    .and_then(|st| Some(st.to_string()));
-
-                aliases.insert(key.to_string(), Alias {
-                    name: key.to_string(),
-                    r#type: format!("{}", array_field_value),
-                    description,
-                });
-
-                println!("Finished parsing {key} (array)");
+            // `infer_types` has already typed everything it could, so
+            //  what's left accepts any value
+            let description = value["description"].as_str()
+                .map(|st| st.to_string());
 
-                return Ok(())
-            }
+            aliases.insert(key.to_string(), Alias {
+                name: key.to_string(),
+                r#type: "serde_json::Value".to_string(),
+                description,
+            });
 
-            println!("No type!\nErroneous Value: {:#?}", value);
-            bail!("No type found");
+            println!("Finished parsing {key} (untyped)");
         }
     }
 
@@ -1,6 +1,6 @@
 use super::{
-    Data, Alias, parse,
-    super::data::objects::{Object, FieldValue, Field},
+    Data, Alias, parse, schema_name,
+    super::data::objects::{Object, FieldValue, Field, UnknownFields, Builders, FieldOrder},
     enums::parse_enum,
     arrays::parse_array
 };
@@ -81,9 +81,13 @@ pub(super) fn parse_object (
     let mut object = Object {
         name: key.to_string(),
         description: description.map(|s| s.to_string()),
-        properties: BTreeMap::new(),
+        properties: LinkedHashMap::new(),
+        field_order: FieldOrder::default(),
         wanted_by: BTreeSet::from([ wanted_by.clone() ]),
         relies_on: BTreeSet::new(),
+        unknown_fields: UnknownFields::default(),
+        builders: Builders::default(),
+        examples: Vec::new(),
     };
 
     // Check if it's an `allOf` object
@@ -92,11 +96,9 @@ pub(super) fn parse_object (
         for sub_object in sub_objects {
             // Get the referred type, and steal its properties
             if let Some(referred_type_raw) = sub_object["$ref"].as_str() {
-                let referred_type = referred_type_raw.split("/")
-                    .skip(3)
-                    .next()
+                let referred_type = schema_name(referred_type_raw)
                     .context("Failed to parse the referred type")?;
-                let referred_type_yaml = &global_yaml["components"]["schemas"][referred_type];
+                let referred_type_yaml = &global_yaml["components"]["schemas"][referred_type.as_str()];
 
                 // Add the requested type recursively
                 println!("Need to recurse for `allOf` object: {referred_type}");
@@ -106,13 +108,13 @@ pub(super) fn parse_object (
                     schemas,
                     aliases,
                     wanted_by.clone(),
-                    referred_type,
+                    &referred_type,
                     &referred_type_yaml,
                 )
                     .with_context(|| format!("Couldn't parse the object {referred_type}"))?;
                 println!("Finished recusively adding object {referred_type}, continuing object {key}");
 
-                match schemas.get(referred_type) {
+                match schemas.get(&referred_type) {
                     Some(Data::Object(referred_object)) => {
                         for (property_key, field) in &referred_object.properties {
                             object.properties.insert(property_key.to_string(), field.clone());
@@ -214,15 +216,6 @@ fn process_properties (
     //  to a new YAML object with `subkey` within `properties`,
     //  and ignores valid properties
     let mut fixed_bad_properties: LinkedHashMap<Yaml, Yaml> = LinkedHashMap::new();
-    let good_properties = properties.iter()
-        .filter(|(property_key, _)| {
-            if let Some(property_key) = property_key.as_str() {
-                return !property_key.contains('.');
-            }
-
-            panic!("Invalid property key: {:#?}", property_key);
-        })
-        .collect::<BTreeMap<&Yaml, &Yaml>>();
     for (bad_property_key, bad_property_value) in properties.iter()
         .filter(|(property_key, _)| {
             if let Some(property_key) = property_key.as_str() {
@@ -264,10 +257,28 @@ fn process_properties (
         );
     }
 
-    // Zip the bad properties into the good properties
-    let properties = good_properties.into_iter()
-        .chain(fixed_bad_properties.iter())
-        .collect::<BTreeMap<&Yaml, &Yaml>>();
+    // Zip the bad properties into the good properties, where they first
+    //  appeared, so that the spec's declaration order is kept
+    let mut ordered_properties: LinkedHashMap<&Yaml, &Yaml> = LinkedHashMap::new();
+    for (property_key, property_value) in properties.iter() {
+        let fixed_property_key = property_key.as_str()
+            .context("Failed to get the property key")?
+            .split('.')
+            .next()
+            .context("Failed to split the property key")?;
+
+        match fixed_bad_properties.get_key_value(&Yaml::String(fixed_property_key.to_string())) {
+            Some((fixed_key, fixed_value)) => {
+                if !ordered_properties.contains_key(fixed_key) {
+                    ordered_properties.insert(fixed_key, fixed_value);
+                }
+            },
+            None => {
+                ordered_properties.insert(property_key, property_value);
+            }
+        }
+    }
+    let properties = ordered_properties;
 
     for (property_key, property_value) in properties.iter() {
         let property_key = property_key.as_str().context("Failed to get key")?;
@@ -441,13 +452,11 @@ fn process_properties (
 
                     FieldValue::ExternalType(field_type_key)
                 } else if let Some(referred_type) = property_value["$ref"].as_str() {
-                    let parsed_referred_type = referred_type.split("/")
-                        .skip(3)
-                        .next()
+                    let parsed_referred_type = schema_name(referred_type)
                         .context("Failed to parse the referred type")?;
                     println!("Referred type: {}", parsed_referred_type);
 
-                    let referred_type_yaml = &global_yaml["components"]["schemas"][parsed_referred_type];
+                    let referred_type_yaml = &global_yaml["components"]["schemas"][parsed_referred_type.as_str()];
                     println!("Referred type: {:#?}", referred_type_yaml);
                     
                     // Add the requested type recursively
@@ -456,18 +465,18 @@ fn process_properties (
                         schemas,
                         aliases,
                         wanted_by.clone(),
-                        parsed_referred_type,
+                        &parsed_referred_type,
                         &referred_type_yaml,
                     )
                         .with_context(|| format!("Couldn't parse the object {parsed_referred_type}"))?;
                     println!("Finished recusively adding external type {parsed_referred_type}, continuing object {key}");
 
-                    match schemas.get(parsed_referred_type) {
+                    match schemas.get(&parsed_referred_type) {
                         Some(Data::Object(_)) | Some(Data::Enum(_)) => {
                             FieldValue::ExternalType(parsed_referred_type.to_string())
                         },
                         None => {
-                            match aliases.get(parsed_referred_type) {
+                            match aliases.get(&parsed_referred_type) {
                                 Some(alias) => {
                                     FieldValue::ExternalType(alias.name.clone())
                                 },
@@ -499,6 +508,9 @@ fn process_properties (
                             property_value
                         )
                             .with_context(|| format!("Couldn't parse the array {key}"))?
+                    } else if property_value["type"].as_str().is_none() {
+                        // `infer_types` couldn't type it, so it accepts any value
+                        FieldValue::ExternalType("serde_json::Value".to_string())
                     } else {
                         println!("Unknown type `{:#?}`!\nErroneous Value: {:#?}", property_value["type"], property_value);
                         bail!("No type found");
@@ -511,7 +523,8 @@ fn process_properties (
         object.properties.insert(property_key.to_string(), Field {
             description: description.map(|s| s.to_string()),
             value: field_value,
-            required: required.contains(&property_key)
+            required: required.contains(&property_key),
+            nullable: property_value["nullable"].as_bool().unwrap_or(false)
         });
     }
 
@@ -0,0 +1,366 @@
+use super::{
+    Data,
+    dialect::yaml_to_json,
+    refs::lookup,
+    schema_name,
+    super::data::{
+        objects::FieldValue,
+        operations::{Event, Operation, Parameter, ParameterLocation}
+    }
+};
+
+use std::collections::BTreeMap;
+
+use convert_case::{Case, Casing};
+use saphyr::Yaml;
+use anyhow::{Context, Result};
+
+/// The HTTP methods an OpenAPI path item can have
+const METHODS: &[&str] = &["get", "put", "post", "delete", "options", "head", "patch", "trace"];
+/// How many `$ref`s deep a synthesized example follows before giving up
+const MAX_SYNTHESIS_DEPTH: usize = 8;
+
+/// Reads every operation in the spec's `paths`, with an example
+///  successful response for each
+pub fn parse_operations ( spec: &Yaml ) -> Result<Vec<Operation>> {
+    let paths = spec["paths"].as_hash()
+        .context("Failed to get paths")?;
+
+    let mut operations = Vec::new();
+    for (path, path_yaml) in paths.iter() {
+        let path = path.as_str()
+            .context("Paths must be strings")?;
+        let root_path = path.split('/')
+            .nth(1)
+            .context("Failed to get root path")?;
+
+        for method in METHODS {
+            let operation_yaml = &path_yaml[*method];
+            if operation_yaml.is_badvalue() {
+                continue;
+            }
+
+            let id = operation_yaml["operationId"].as_str()
+                .map(|id| id.to_string())
+                .unwrap_or_else(|| format!("{method}{}", path.replace(['{', '}'], "").to_case(Case::UpperCamel)));
+
+            // Grouped like the types, by API reference group, then first
+            //  tag and then root path
+            let group = operation_yaml["x-oaiMeta"]["group"].as_str()
+                .or(operation_yaml["tags"][0].as_str())
+                .unwrap_or(root_path)
+                .to_case(Case::Snake);
+            let summary = operation_yaml["summary"].as_str()
+                .map(|summary| summary.to_string());
+            let beta = operation_yaml["x-oaiMeta"]["beta"].as_bool().unwrap_or(false);
+            let beta_header = beta_header(operation_yaml);
+            if beta_header.is_some() && !beta {
+                println!("Not sending the `OpenAI-Beta` header of {id}, which isn't marked beta");
+            }
+
+            // The operation's parameters override the path's of the same name
+            let mut parameters: Vec<Parameter> = Vec::new();
+            for parameter_yaml in path_yaml["parameters"].as_vec().into_iter().flatten()
+                .chain(operation_yaml["parameters"].as_vec().into_iter().flatten())
+            {
+                let Some(parameter) = parse_parameter(spec, parameter_yaml, &id) else {
+                    continue;
+                };
+
+                parameters.retain(|existing| existing.name != parameter.name || existing.location != parameter.location);
+                parameters.push(parameter);
+            }
+
+            let request_type = match operation_yaml["requestBody"]["content"]["application/json"]["schema"]["$ref"].as_str() {
+                Some(reference) => Some(schema_name(reference)
+                    .with_context(|| format!("Failed to get the request type of {id}"))?),
+                None => None
+            };
+            let json_body = !operation_yaml["requestBody"]["content"]["application/json"].is_badvalue();
+            let multipart = !operation_yaml["requestBody"]["content"]["multipart/form-data"].is_badvalue();
+
+            // The first successful response, and its first content type
+            let (status, response_yaml) = operation_yaml["responses"].as_hash()
+                .into_iter()
+                .flatten()
+                .filter_map(|(status, response)| {
+                    let status = match status {
+                        Yaml::Integer(status) => u16::try_from(*status).ok()?,
+                        status => status.as_str()?.parse::<u16>().ok()?
+                    };
+
+                    Some((status, response))
+                })
+                .find(|(status, _response)| (200..300).contains(status))
+                .unwrap_or((200, &Yaml::BadValue));
+            let content_types = response_yaml["content"].as_hash()
+                .into_iter()
+                .flatten()
+                .filter_map(|(content_type, _content)| content_type.as_str())
+                .collect::<Vec<&str>>();
+            let content_type = content_types.iter()
+                .find(|content_type| **content_type != "text/event-stream")
+                .or(content_types.first())
+                .map(|content_type| content_type.to_string());
+
+            // Responses without a body are read as `()`, and JSON ones which
+            //  aren't a generated type as `serde_json::Value`. Anything else
+            //  is left as bytes
+            let json_schema = &response_yaml["content"]["application/json"]["schema"];
+            let response_type = if content_types.is_empty() {
+                Some("()".to_string())
+            } else if let Some(reference) = json_schema["$ref"].as_str() {
+                Some(schema_name(reference)
+                    .with_context(|| format!("Failed to get the response type of {id}"))?)
+            } else if !json_schema.is_badvalue() && json_schema["type"].as_str() != Some("string") {
+                Some("serde_json::Value".to_string())
+            } else {
+                None
+            };
+            let stream_type = match response_yaml["content"]["text/event-stream"]["schema"]["$ref"].as_str() {
+                Some(reference) => Some(schema_name(reference)
+                    .with_context(|| format!("Failed to get the stream event type of {id}"))?),
+                None => None
+            };
+
+            // Prefer the documented example, falling back to a minimal
+            //  instance of the response schema
+            let (mut response_example, stream_example) = response_examples(operation_yaml);
+            let is_json = content_type.as_deref().is_none_or(|content_type| content_type == "application/json");
+            if response_example.is_none() && is_json {
+                let schema = &response_yaml["content"]["application/json"]["schema"];
+                if !schema.is_badvalue() {
+                    println!("Synthesizing an example response for {id}");
+                    response_example = Some(synthesize(spec, schema, 0).to_string());
+                }
+            }
+
+            operations.push(Operation {
+                id,
+                method: method.to_uppercase(),
+                path: path.to_string(),
+                group,
+                summary,
+                beta,
+                beta_header,
+                parameters,
+                request_type,
+                json_body,
+                multipart,
+                response_type,
+                stream_type,
+                page_item: None,
+                status,
+                content_type,
+                response_example,
+                stream_example,
+            });
+        }
+    }
+
+    Ok(operations)
+}
+
+/// Finds the cursor-paginated list operations, which take an `after`
+///  cursor and answer with a page of `data` and whether it `has_more`
+pub fn find_pagination ( operations: &mut [Operation], schemas: &BTreeMap<String, Data> ) {
+    for operation in operations.iter_mut() {
+        let has_cursor = operation.parameters.iter()
+            .any(|parameter| parameter.name == "after" && parameter.location == ParameterLocation::Query);
+        let response = operation.response_type.as_ref()
+            .and_then(|response_type| schemas.get(response_type));
+        let Some(Data::Object(response)) = response else {
+            continue;
+        };
+        if !has_cursor || !response.properties.contains_key("has_more") {
+            continue;
+        }
+
+        if let Some(FieldValue::Array(item)) = response.properties.get("data").map(|field| &field.value) {
+            println!("Paginating {} over its `data` of {item}", operation.id);
+            operation.page_item = Some(item.clone());
+        }
+    }
+}
+
+/// Reads a path or query parameter, skipping any whose value can't be
+///  written in a URL as a plain value
+fn parse_parameter ( spec: &Yaml, parameter: &Yaml, id: &str ) -> Option<Parameter> {
+    let name = parameter["name"].as_str()?;
+    let location = match parameter["in"].as_str()? {
+        "path" => ParameterLocation::Path,
+        "query" => ParameterLocation::Query,
+        _ => return None
+    };
+
+    let Some(r#type) = parameter_type(spec, &parameter["schema"]) else {
+        println!("Skipping the parameter `{name}` of {id}, which isn't a plain value");
+        return None;
+    };
+
+    Some(Parameter {
+        name: name.to_string(),
+        location,
+        description: parameter["description"].as_str()
+            .map(|description| description.to_string()),
+        required: parameter["required"].as_bool().unwrap_or(false) || location == ParameterLocation::Path,
+        r#type,
+    })
+}
+/// The Rust type of a parameter's schema, which must be a primitive or a
+///  list of them
+fn parameter_type ( spec: &Yaml, schema: &Yaml ) -> Option<String> {
+    if let Some(reference) = schema["$ref"].as_str() {
+        let target = reference.strip_prefix('#')
+            .and_then(|pointer| lookup(spec, pointer))?;
+
+        return parameter_type(spec, target);
+    }
+
+    match schema["type"].as_str() {
+        Some("string") => Some("String".to_string()),
+        Some("integer") => Some("i64".to_string()),
+        Some("number") => Some("f64".to_string()),
+        Some("boolean") => Some("bool".to_string()),
+        Some("array") => Some(format!("Vec<{}>", parameter_type(spec, &schema["items"])?)),
+        _ => None
+    }
+}
+
+/// The `OpenAI-Beta` header sent by an operation's `x-oaiMeta` example
+///  requests, such as `assistants=v2`
+fn beta_header ( operation: &Yaml ) -> Option<String> {
+    let examples = match &operation["x-oaiMeta"]["examples"] {
+        Yaml::Array(examples) => examples.iter().collect::<Vec<&Yaml>>(),
+        Yaml::Hash(_) => vec!(&operation["x-oaiMeta"]["examples"]),
+        _ => Vec::new()
+    };
+
+    examples.into_iter()
+        .filter_map(|example| example["request"]["curl"].as_str())
+        .flat_map(|curl| curl.split('"'))
+        .find_map(|part| part.trim().strip_prefix("OpenAI-Beta:"))
+        .map(|value| value.trim().to_string())
+}
+/// The first plain and the first streamed response in an operation's
+///  `x-oaiMeta` examples
+fn response_examples ( operation: &Yaml ) -> (Option<String>, Vec<Event>) {
+    let examples = match &operation["x-oaiMeta"]["examples"] {
+        Yaml::Array(examples) => examples.iter().collect::<Vec<&Yaml>>(),
+        Yaml::Hash(_) => vec!(&operation["x-oaiMeta"]["examples"]),
+        _ => Vec::new()
+    };
+
+    let mut response_example = None;
+    let mut stream_example = Vec::new();
+    for example in examples {
+        let Some(response) = example["response"].as_str() else {
+            continue;
+        };
+
+        // Bodies are kept compact, and only if they're valid JSON
+        if let Ok(json) = serde_json::from_str::<serde_json::Value>(response) {
+            response_example.get_or_insert_with(|| json.to_string());
+        } else if stream_example.is_empty() {
+            stream_example = parse_events(response);
+        }
+    }
+
+    (response_example, stream_example)
+}
+/// Reads an example stream, which is either server-sent events or JSON
+///  chunks separated by blank lines. Chunks which aren't JSON, such as
+///  `...`, are skipped
+fn parse_events ( stream: &str ) -> Vec<Event> {
+    let mut events = Vec::new();
+    for chunk in stream.split("\n\n") {
+        let chunk = chunk.trim();
+
+        let mut name = None;
+        let mut data = Vec::new();
+        if chunk.starts_with("event:") || chunk.starts_with("data:") {
+            for line in chunk.lines() {
+                if let Some(event_name) = line.strip_prefix("event:") {
+                    name = Some(event_name.trim().to_string());
+                } else if let Some(line_data) = line.strip_prefix("data:") {
+                    data.push(line_data.trim());
+                }
+            }
+        } else {
+            data.push(chunk);
+        }
+
+        let data = data.join("\n");
+        let data = match serde_json::from_str::<serde_json::Value>(&data) {
+            Ok(json) => json.to_string(),
+            Err(_) if data == "[DONE]" => data,
+            Err(_) => continue
+        };
+
+        events.push(Event { name, data });
+    }
+
+    events
+}
+
+/// Builds the smallest instance of a schema: its `example` if it has one,
+///  otherwise only the required properties, empty lists and zero values
+fn synthesize ( spec: &Yaml, schema: &Yaml, depth: usize ) -> serde_json::Value {
+    if depth > MAX_SYNTHESIS_DEPTH {
+        return serde_json::Value::Null;
+    }
+    if !schema["example"].is_badvalue() {
+        return yaml_to_json(&schema["example"]);
+    }
+
+    if let Some(reference) = schema["$ref"].as_str() {
+        return match reference.strip_prefix('#').and_then(|pointer| lookup(spec, pointer)) {
+            Some(target) => synthesize(spec, target, depth + 1),
+            None => serde_json::Value::Null
+        };
+    }
+    if let Some(members) = schema["allOf"].as_vec() {
+        let mut merged = serde_json::Map::new();
+        for member in members {
+            if let serde_json::Value::Object(fields) = synthesize(spec, member, depth + 1) {
+                merged.extend(fields);
+            }
+        }
+
+        return serde_json::Value::Object(merged);
+    }
+    if let Some(member) = schema["oneOf"].as_vec().or(schema["anyOf"].as_vec()).and_then(|members| members.first()) {
+        return synthesize(spec, member, depth + 1);
+    }
+    if let Some(value) = schema["enum"].as_vec().and_then(|values| values.first()) {
+        return yaml_to_json(value);
+    }
+
+    match schema["type"].as_str() {
+        Some("object") | None if !schema["properties"].is_badvalue() => {
+            let required = schema["required"].as_vec()
+                .into_iter()
+                .flatten()
+                .filter_map(|required| required.as_str())
+                .collect::<Vec<&str>>();
+
+            let mut fields = serde_json::Map::new();
+            for (key, property) in schema["properties"].as_hash().into_iter().flatten() {
+                let Some(key) = key.as_str().filter(|key| required.contains(key)) else {
+                    continue;
+                };
+
+                fields.insert(key.to_string(), synthesize(spec, property, depth + 1));
+            }
+
+            serde_json::Value::Object(fields)
+        },
+        Some("object") => serde_json::json!({}),
+        Some("array") => serde_json::json!([]),
+        Some("string") => serde_json::json!("string"),
+        Some("integer") => serde_json::json!(0),
+        Some("number") => serde_json::json!(0.0),
+        Some("boolean") => serde_json::json!(false),
+        _ => serde_json::Value::Null
+    }
+}
@@ -0,0 +1,537 @@
+use super::refs::{segments, lookup};
+
+use saphyr::Yaml;
+use anyhow::{Context, Result, bail};
+
+/// One step into a YAML document
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+/// Applies a patch to the spec, which is either an [OpenAPI Overlay]
+///  (a map with `overlay` and `actions`) or a [JSON Patch] (a list of
+///  operations).
+///
+/// [OpenAPI Overlay]: https://spec.openapis.org/overlay/v1.0.0.html
+/// [JSON Patch]: https://datatracker.ietf.org/doc/html/rfc6902
+pub fn apply_patch ( spec: &mut Yaml, patch: &Yaml ) -> Result<()> {
+    match patch {
+        Yaml::Array(operations) => {
+            for (index, operation) in operations.iter().enumerate() {
+                apply_json_patch_operation(spec, operation)
+                    .with_context(|| format!("Failed to apply JSON Patch operation {index}"))?;
+            }
+        },
+        Yaml::Hash(_) if patch["overlay"].as_str().is_some() => {
+            let actions = patch["actions"].as_vec()
+                .context("The overlay has no `actions`")?;
+
+            for (index, action) in actions.iter().enumerate() {
+                apply_overlay_action(spec, action)
+                    .with_context(|| format!("Failed to apply overlay action {index}"))?;
+            }
+        },
+        _ => {
+            bail!("Patches must be an OpenAPI Overlay or a JSON Patch");
+        }
+    }
+
+    Ok(())
+}
+
+fn apply_overlay_action ( spec: &mut Yaml, action: &Yaml ) -> Result<()> {
+    let target = action["target"].as_str()
+        .context("The action has no `target`")?;
+    if let Some(description) = action["description"].as_str() {
+        println!("Applying overlay action: {description}");
+    }
+
+    let mut paths = select(spec, target)
+        .with_context(|| format!("Failed to select `{target}`"))?;
+    if paths.is_empty() {
+        println!("Overlay target `{target}` matched nothing, skipping it");
+
+        return Ok(());
+    }
+
+    if action["remove"].as_bool() == Some(true) {
+        // Remove later array items first, so earlier indices stay put
+        paths.reverse();
+        for path in paths {
+            remove(spec, &path)
+                .with_context(|| format!("Failed to remove `{target}`"))?;
+        }
+
+        return Ok(());
+    }
+
+    let update = match action["update"] {
+        Yaml::BadValue => bail!("The action has neither `update` nor `remove: true`"),
+        ref update => update
+    };
+    for path in paths {
+        let node = get_mut(spec, &path)
+            .with_context(|| format!("`{target}` matched a node which doesn't exist"))?;
+
+        // Overlays append to lists, and merge into anything else
+        match node {
+            Yaml::Array(values) => values.push(update.clone()),
+            node => merge(node, update)
+        }
+    }
+
+    Ok(())
+}
+/// Recursively merges `update` into `node`, appending to lists and
+///  replacing scalars
+fn merge ( node: &mut Yaml, update: &Yaml ) {
+    match (node, update) {
+        (Yaml::Hash(hash), Yaml::Hash(update)) => {
+            for (key, value) in update.iter() {
+                match hash.get_mut(key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        hash.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        },
+        (Yaml::Array(values), Yaml::Array(update)) => {
+            values.extend(update.iter().cloned());
+        },
+        (node, update) => {
+            *node = update.clone();
+        }
+    }
+}
+
+/// Finds the nodes a JSONPath selects.
+///
+/// Supports the subset overlays are written with: `$`, `.name`,
+///  `['name']`, `[n]`, `.*` and `[*]`.
+fn select ( spec: &Yaml, target: &str ) -> Result<Vec<Vec<Segment>>> {
+    let mut rest = target.strip_prefix('$')
+        .context("JSONPath targets must start with `$`")?;
+
+    let mut selected = vec![Vec::new()];
+    while !rest.is_empty() {
+        // Parse the next selector
+        let selector;
+        if rest.starts_with("..") {
+            bail!("Recursive descent (`..`) isn't supported");
+        } else if let Some(after) = rest.strip_prefix('.') {
+            let end = after.find(['.', '['])
+                .unwrap_or(after.len());
+
+            selector = Some(after[..end].to_string()).filter(|name| name != "*");
+            rest = &after[end..];
+        } else if let Some(after) = rest.strip_prefix('[') {
+            let after = after.trim_start();
+
+            if after.starts_with('?') {
+                bail!("Filter expressions (`[?...]`) aren't supported");
+            }
+            if after.starts_with(['\'', '"']) {
+                // Quoted names can hold `]`, so they're scanned to their
+                //  closing quote rather than to the first `]`
+                let (name, after_name) = quoted_name(after)?;
+                let after_name = after_name.trim_start();
+
+                selector = Some(name);
+                rest = after_name.strip_prefix(']')
+                    .context("Unclosed `[` in JSONPath")?;
+            } else {
+                let end = after.find(']')
+                    .context("Unclosed `[` in JSONPath")?;
+
+                selector = Some(after[..end].trim().to_string()).filter(|name| name != "*");
+                rest = &after[end + 1..];
+            }
+        } else {
+            bail!("Unexpected `{rest}` in JSONPath");
+        }
+
+        // Step every selected node through it
+        let mut next = Vec::new();
+        for path in selected {
+            let Some(node) = get(spec, &path) else {
+                continue;
+            };
+
+            match (node, selector.as_deref()) {
+                (Yaml::Hash(hash), Some(name)) if hash.contains_key(&Yaml::String(name.to_string())) => {
+                    next.push(extend(&path, Segment::Key(name.to_string())));
+                },
+                (Yaml::Hash(hash), None) => {
+                    for key in hash.keys().filter_map(|key| key.as_str()) {
+                        next.push(extend(&path, Segment::Key(key.to_string())));
+                    }
+                },
+                (Yaml::Array(values), Some(index)) => {
+                    if let Ok(index) = index.parse::<usize>()
+                        && index < values.len()
+                    {
+                        next.push(extend(&path, Segment::Index(index)));
+                    }
+                },
+                (Yaml::Array(values), None) => {
+                    for index in 0..values.len() {
+                        next.push(extend(&path, Segment::Index(index)));
+                    }
+                },
+                _ => { }
+            }
+        }
+        selected = next;
+    }
+
+    Ok(selected)
+}
+/// Reads a quoted JSONPath name, such as `'a]b'` or `'it\'s'`, returning
+///  it unescaped along with the text after its closing quote
+fn quoted_name ( text: &str ) -> Result<(String, &str)> {
+    let mut chars = text.char_indices();
+    let Some((_, quote)) = chars.next() else {
+        bail!("Expected a quoted name in JSONPath");
+    };
+
+    let mut name = String::new();
+    while let Some((index, char)) = chars.next() {
+        match char {
+            '\\' => {
+                let (_, escaped) = chars.next()
+                    .context("Unfinished escape in JSONPath name")?;
+
+                name.push(match escaped {
+                    'n' => '\n',
+                    't' => '\t',
+                    'r' => '\r',
+                    'b' => '\u{8}',
+                    'f' => '\u{c}',
+                    // `\\`, `\/` and either quote stand for themselves
+                    escaped => escaped
+                });
+            },
+            char if char == quote => {
+                return Ok((name, &text[index + char.len_utf8()..]));
+            },
+            char => name.push(char)
+        }
+    }
+
+    bail!("Unclosed quote in JSONPath name `{text}`")
+}
+fn extend ( path: &[Segment], segment: Segment ) -> Vec<Segment> {
+    let mut path = path.to_vec();
+    path.push(segment);
+
+    path
+}
+
+fn apply_json_patch_operation ( spec: &mut Yaml, operation: &Yaml ) -> Result<()> {
+    let op = operation["op"].as_str()
+        .context("The operation has no `op`")?;
+    let path = operation["path"].as_str()
+        .context("The operation has no `path`")?;
+    let value = || match operation["value"] {
+        Yaml::BadValue => None,
+        ref value => Some(value.clone())
+    };
+    let from = || operation["from"].as_str()
+        .context("The operation has no `from`");
+
+    match op {
+        "add" => {
+            let value = value().context("`add` needs a `value`")?;
+            add(spec, path, value)?;
+        },
+        "remove" => {
+            remove(spec, &pointer_path(spec, path)?)?;
+        },
+        "replace" => {
+            let value = value().context("`replace` needs a `value`")?;
+            let node = get_mut(spec, &pointer_path(spec, path)?)
+                .with_context(|| format!("Nothing is at `{path}` to replace"))?;
+
+            *node = value;
+        },
+        "move" => {
+            let from = from()?;
+            let value = lookup(spec, from)
+                .with_context(|| format!("Nothing is at `{from}` to move"))?
+                .clone();
+
+            remove(spec, &pointer_path(spec, from)?)?;
+            add(spec, path, value)?;
+        },
+        "copy" => {
+            let from = from()?;
+            let value = lookup(spec, from)
+                .with_context(|| format!("Nothing is at `{from}` to copy"))?
+                .clone();
+
+            add(spec, path, value)?;
+        },
+        "test" => {
+            let value = value().context("`test` needs a `value`")?;
+            if lookup(spec, path) != Some(&value) {
+                bail!("Test failed, `{path}` isn't {value:?}");
+            }
+        },
+        other => {
+            bail!("Unknown JSON Patch operation `{other}`");
+        }
+    }
+
+    Ok(())
+}
+/// Adds `value` at a JSON Pointer, replacing a map's key or inserting
+///  into a list (at the end for `-`)
+fn add ( spec: &mut Yaml, path: &str, value: Yaml ) -> Result<()> {
+    let mut segments = segments(path);
+    let Some(last) = segments.pop() else {
+        *spec = value;
+
+        return Ok(());
+    };
+
+    let parent_pointer = path.rsplit_once('/')
+        .map(|(parent, _last)| parent)
+        .unwrap_or("");
+    let parent = get_mut(spec, &pointer_path(spec, parent_pointer)?)
+        .with_context(|| format!("Nothing is at `{parent_pointer}` to add to"))?;
+
+    match parent {
+        Yaml::Hash(hash) => {
+            hash.insert(Yaml::String(last), value);
+        },
+        Yaml::Array(values) if last == "-" => {
+            values.push(value);
+        },
+        Yaml::Array(values) => {
+            let index = last.parse::<usize>()
+                .ok()
+                .filter(|index| *index <= values.len())
+                .with_context(|| format!("`{last}` isn't a position in `{parent_pointer}`"))?;
+
+            values.insert(index, value);
+        },
+        _ => {
+            bail!("`{parent_pointer}` isn't a map or a list");
+        }
+    }
+
+    Ok(())
+}
+/// Turns a JSON Pointer into the path of the node it points at
+fn pointer_path ( spec: &Yaml, pointer: &str ) -> Result<Vec<Segment>> {
+    let mut path = Vec::new();
+    let mut node = spec;
+    for segment in segments(pointer) {
+        let (next, step) = match node {
+            Yaml::Hash(hash) => (
+                hash.get(&Yaml::String(segment.clone())),
+                Segment::Key(segment.clone())
+            ),
+            Yaml::Array(values) => {
+                let index = segment.parse::<usize>()
+                    .with_context(|| format!("`{segment}` isn't a list index"))?;
+
+                (values.get(index), Segment::Index(index))
+            },
+            _ => (None, Segment::Key(segment.clone()))
+        };
+
+        node = next.with_context(|| format!("Nothing is at `{pointer}`"))?;
+        path.push(step);
+    }
+
+    Ok(path)
+}
+
+fn get<'a> ( node: &'a Yaml, path: &[Segment] ) -> Option<&'a Yaml> {
+    path.iter()
+        .try_fold(node, |node, segment| match (node, segment) {
+            (Yaml::Hash(hash), Segment::Key(key)) => hash.get(&Yaml::String(key.clone())),
+            (Yaml::Array(values), Segment::Index(index)) => values.get(*index),
+            _ => None
+        })
+}
+fn get_mut<'a> ( node: &'a mut Yaml, path: &[Segment] ) -> Option<&'a mut Yaml> {
+    path.iter()
+        .try_fold(node, |node, segment| match (node, segment) {
+            (Yaml::Hash(hash), Segment::Key(key)) => hash.get_mut(&Yaml::String(key.clone())),
+            (Yaml::Array(values), Segment::Index(index)) => values.get_mut(*index),
+            _ => None
+        })
+}
+fn remove ( spec: &mut Yaml, path: &[Segment] ) -> Result<()> {
+    let Some((last, parent_path)) = path.split_last() else {
+        bail!("Can't remove the whole document");
+    };
+
+    match (get_mut(spec, parent_path), last) {
+        (Some(Yaml::Hash(hash)), Segment::Key(key)) => {
+            hash.remove(&Yaml::String(key.clone()))
+                .context("Nothing to remove")?;
+        },
+        (Some(Yaml::Array(values)), Segment::Index(index)) if *index < values.len() => {
+            values.remove(*index);
+        },
+        _ => {
+            bail!("Nothing to remove");
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::load_spec;
+
+    fn yaml ( raw: &str ) -> Yaml {
+        load_spec(raw).unwrap().remove(0)
+    }
+
+    /// Applies `patch` to `spec`, checking it gives `expected`
+    fn assert_patched ( spec: &str, patch: &str, expected: &str ) {
+        let mut spec = yaml(spec);
+        apply_patch(&mut spec, &yaml(patch)).unwrap();
+
+        assert_eq!(spec, yaml(expected));
+    }
+
+    const SPEC: &str = "
+info:
+  title: API
+  tags: [a, b]
+schemas:
+  Pet:
+    type: object
+";
+
+    #[test]
+    fn overlay_update_merges_and_appends () {
+        assert_patched(SPEC, "
+overlay: 1.0.0
+actions:
+  - target: $.info
+    update:
+      title: Patched
+      version: '2'
+  - target: $.info.tags
+    update: c
+", "
+info:
+  title: Patched
+  tags: [a, b, c]
+  version: '2'
+schemas:
+  Pet:
+    type: object
+");
+    }
+
+    #[test]
+    fn overlay_remove_deletes_every_match () {
+        assert_patched(SPEC, "
+overlay: 1.0.0
+actions:
+  - target: $.info.tags[*]
+    remove: true
+  - target: $.schemas.Pet.type
+    remove: true
+", "
+info:
+  title: API
+  tags: []
+schemas:
+  Pet: {}
+");
+    }
+
+    #[test]
+    fn quoted_names_can_hold_brackets_and_quotes () {
+        assert_patched("
+'a]b': 1
+\"it's\": 2
+", r#"
+overlay: 1.0.0
+actions:
+  - target: $['a]b']
+    remove: true
+  - target: "$['it\\'s']"
+    remove: true
+"#, "{}");
+    }
+
+    #[test]
+    fn json_patch_add_remove_and_replace () {
+        assert_patched(SPEC, "
+- op: add
+  path: /info/tags/1
+  value: z
+- op: add
+  path: /info/tags/-
+  value: end
+- op: add
+  path: /info/version
+  value: '2'
+- op: remove
+  path: /info/tags/0
+- op: replace
+  path: /schemas/Pet/type
+  value: string
+", "
+info:
+  title: API
+  tags: [z, b, end]
+  version: '2'
+schemas:
+  Pet:
+    type: string
+");
+    }
+
+    #[test]
+    fn json_patch_move_and_copy () {
+        assert_patched(SPEC, "
+- op: copy
+  from: /schemas/Pet
+  path: /schemas/Cat
+- op: move
+  from: /info/title
+  path: /schemas/Pet/title
+", "
+info:
+  tags: [a, b]
+schemas:
+  Pet:
+    type: object
+    title: API
+  Cat:
+    type: object
+");
+    }
+
+    #[test]
+    fn json_patch_test_checks_the_value () {
+        assert_patched(SPEC, "
+- op: test
+  path: /info/tags
+  value: [a, b]
+", SPEC);
+
+        let mut spec = yaml(SPEC);
+        let error = apply_patch(&mut spec, &yaml("
+- op: test
+  path: /info/title
+  value: Other
+")).unwrap_err();
+        assert!(format!("{error:#}").contains("Test failed"), "{error:#}");
+    }
+}
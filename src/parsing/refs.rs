@@ -0,0 +1,532 @@
+use super::load_spec;
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use convert_case::{Case, Casing};
+use saphyr::Yaml;
+use anyhow::{Context, Result, bail};
+
+/// The only kind of `$ref` left once [`resolve_refs`] has run
+const SCHEMA_PREFIX: &str = "#/components/schemas/";
+/// Pointer segments which say where a schema sits, rather than naming it
+const STRUCTURAL_SEGMENTS: &[&str] = &[
+    "properties", "items", "additionalProperties", "allOf", "anyOf", "oneOf",
+    "$defs", "definitions", "schema", "content"
+];
+
+/// Gets the schema named by a `#/components/schemas/Name` reference, with
+///  its JSON Pointer escapes (`~1` and `~0`) decoded
+pub fn schema_name ( reference: &str ) -> Result<String> {
+    reference.strip_prefix(SCHEMA_PREFIX)
+        .filter(|name| !name.is_empty() && !name.contains('/'))
+        .map(unescape)
+        .with_context(|| format!("`{reference}` doesn't refer to a component schema"))
+}
+
+/// Resolves every `$ref` in the spec, so that the parser only sees
+///  `#/components/schemas/Name` references.
+///
+/// - Refs to other component sections (parameters, responses, request
+///   bodies, ...) are replaced by a copy of what they refer to
+/// - Refs to schemas anywhere else, whether nested in another schema,
+///   under `$defs` or in another file, are hoisted into
+///   `components.schemas` and pointed at there
+///
+/// File refs (`other.yaml#/Pointer`) are relative to the file they're
+///  written in, with the spec's own refs relative to `base_dir`.
+pub fn resolve_refs ( spec: &mut Yaml, base_dir: &Path ) -> Result<()> {
+    let taken = spec["components"]["schemas"].as_hash()
+        .map(|schemas| schemas.keys()
+            .filter_map(|name| name.as_str())
+            .map(|name| name.to_string())
+            .collect())
+        .unwrap_or_default();
+    let mut resolver = Resolver {
+        base_dir: base_dir.to_path_buf(),
+        root: spec.clone(),
+        documents: BTreeMap::new(),
+        hoisted: BTreeMap::new(),
+        taken,
+        pending: Vec::new(),
+    };
+
+    resolver.resolve_node(spec, &mut Vec::new())?;
+
+    // Hoisted schemas can refer onwards, so resolve them in turn
+    while let Some((name, mut schema)) = resolver.pending.pop() {
+        resolver.resolve_node(&mut schema, &mut Vec::new())
+            .with_context(|| format!("Failed to resolve the refs in hoisted schema {name}"))?;
+
+        let Yaml::Hash(root) = spec else {
+            bail!("The spec isn't a map");
+        };
+        let components = root.entry(Yaml::String("components".to_string()))
+            .or_insert_with(|| Yaml::Hash(Default::default()));
+        let Yaml::Hash(components) = components else {
+            bail!("`components` isn't a map");
+        };
+        let schemas = components.entry(Yaml::String("schemas".to_string()))
+            .or_insert_with(|| Yaml::Hash(Default::default()));
+        let Yaml::Hash(schemas) = schemas else {
+            bail!("`components.schemas` isn't a map");
+        };
+
+        schemas.insert(Yaml::String(name), schema);
+    }
+
+    Ok(())
+}
+
+/// Where a `$ref` points
+struct Target {
+    /// The file it's in, or `None` for the spec itself
+    document: Option<PathBuf>,
+    /// The JSON Pointer within that file, starting with `/` (or empty)
+    pointer: String,
+}
+impl std::fmt::Display for Target {
+    fn fmt ( &self, f: &mut std::fmt::Formatter ) -> std::fmt::Result {
+        match self.document {
+            Some(ref document) => write!(f, "{}#{}", document.display(), self.pointer),
+            None => write!(f, "#{}", self.pointer)
+        }
+    }
+}
+
+struct Resolver {
+    /// The directory the spec's own file refs are relative to
+    base_dir: PathBuf,
+    /// The spec as it was before any refs were resolved
+    root: Yaml,
+    /// Other files, by their canonical path
+    documents: BTreeMap<PathBuf, Yaml>,
+    /// The name each hoisted schema was given, by its target
+    hoisted: BTreeMap<String, String>,
+    /// Every name in `components.schemas`, including hoisted ones
+    taken: BTreeSet<String>,
+    /// Hoisted schemas which haven't been resolved and added yet
+    pending: Vec<(String, Yaml)>,
+}
+impl Resolver {
+    /// Resolves the refs at and below `node`.
+    ///
+    /// `inlining` holds the refs currently being inlined, so that a ref
+    ///  which ends up inside itself is caught rather than followed forever.
+    fn resolve_node ( &mut self, node: &mut Yaml, inlining: &mut Vec<String> ) -> Result<()> {
+        match node {
+            Yaml::Hash(hash) => {
+                let reference = hash.get(&Yaml::String("$ref".to_string()))
+                    .and_then(|reference| reference.as_str())
+                    .map(|reference| reference.to_string());
+                let Some(reference) = reference else {
+                    for child in hash.values_mut() {
+                        self.resolve_node(child, inlining)?;
+                    }
+
+                    return Ok(());
+                };
+
+                let target = self.locate(&reference)
+                    .with_context(|| format!("Failed to locate `$ref` {reference}"))?;
+
+                // Already in the form the parser reads
+                if target.document.is_none() && schema_name(&format!("#{}", target.pointer)).is_ok() {
+                    return Ok(());
+                }
+
+                if is_schema(&target) {
+                    let name = self.hoist(&target)
+                        .with_context(|| format!("Failed to hoist `$ref` {reference}"))?;
+
+                    hash.insert(
+                        Yaml::String("$ref".to_string()),
+                        Yaml::String(format!("{SCHEMA_PREFIX}{name}"))
+                    );
+
+                    return Ok(());
+                }
+
+                let id = target.to_string();
+                if inlining.contains(&id) {
+                    bail!("Reference cycle: {} -> {id}", inlining.join(" -> "));
+                }
+                println!("Inlining `$ref` {id}");
+
+                // Keep whatever was written beside the ref, such as a description
+                let mut inlined = self.copy(&target)?;
+                if let Yaml::Hash(inlined_hash) = &mut inlined {
+                    for (key, value) in hash.iter() {
+                        if key.as_str() != Some("$ref") {
+                            inlined_hash.entry(key.clone())
+                                .or_insert_with(|| value.clone());
+                        }
+                    }
+                }
+                *node = inlined;
+
+                inlining.push(id);
+                self.resolve_node(node, inlining)?;
+                inlining.pop();
+            },
+            Yaml::Array(values) => {
+                for value in values.iter_mut() {
+                    self.resolve_node(value, inlining)?;
+                }
+            },
+            _ => { }
+        }
+
+        Ok(())
+    }
+
+    /// Splits a ref into its file and pointer, loading the file if it's new
+    fn locate ( &mut self, reference: &str ) -> Result<Target> {
+        let (file, pointer) = reference.split_once('#')
+            .unwrap_or((reference, ""));
+
+        let document = if file.is_empty() {
+            None
+        } else {
+            let path = self.base_dir.join(file);
+            let path = path.canonicalize()
+                .with_context(|| format!("Failed to find the spec file {}", path.display()))?;
+
+            if !self.documents.contains_key(&path) {
+                println!("Loading referenced spec file {}", path.display());
+
+                let raw = std::fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read the spec file {}", path.display()))?;
+                let document = load_spec(&raw)?
+                    .into_iter()
+                    .next()
+                    .with_context(|| format!("The spec file {} is empty", path.display()))?;
+
+                self.documents.insert(path.clone(), document);
+            }
+
+            Some(path)
+        };
+
+        Ok(Target {
+            document,
+            pointer: pointer.to_string(),
+        })
+    }
+
+    /// Copies what a target points at, with its refs rewritten to be
+    ///  relative to the spec rather than its own file
+    fn copy ( &self, target: &Target ) -> Result<Yaml> {
+        let document = match target.document {
+            Some(ref path) => &self.documents[path],
+            None => &self.root
+        };
+
+        let mut copy = lookup(document, &target.pointer)
+            .with_context(|| format!("Nothing is at `{target}`"))?
+            .clone();
+        if let Some(ref path) = target.document {
+            rebase_refs(&mut copy, path);
+        }
+
+        Ok(copy)
+    }
+
+    /// Adds the target schema to `components.schemas`, returning its name
+    fn hoist ( &mut self, target: &Target ) -> Result<String> {
+        let id = target.to_string();
+        if let Some(name) = self.hoisted.get(&id) {
+            return Ok(name.clone());
+        }
+
+        let schema = self.copy(target)?;
+
+        let mut candidates = hoisted_names(target);
+        if let Some(file_stem) = target.document.as_ref()
+            .and_then(|path| path.file_stem())
+            .and_then(|file_stem| file_stem.to_str())
+        {
+            let file_stem = file_stem.to_case(Case::UpperCamel);
+            let prefixed = candidates.iter()
+                .map(|candidate| format!("{file_stem}{candidate}"))
+                .collect::<Vec<String>>();
+
+            candidates.extend(prefixed);
+        }
+
+        for candidate in candidates.iter() {
+            // Another file may hold a copy of one of our own schemas
+            if self.root["components"]["schemas"][candidate.as_str()] == schema {
+                self.hoisted.insert(id, candidate.clone());
+
+                return Ok(candidate.clone());
+            }
+            if self.taken.contains(candidate) {
+                continue;
+            }
+
+            println!("Hoisting `$ref` {id} to {SCHEMA_PREFIX}{candidate}");
+
+            self.taken.insert(candidate.clone());
+            self.hoisted.insert(id, candidate.clone());
+            self.pending.push((candidate.clone(), schema));
+
+            return Ok(candidate.clone());
+        }
+
+        bail!("Couldn't find a free name for `{id}`, tried {}", candidates.join(", "))
+    }
+}
+
+/// Whether a target is a schema, to be hoisted, rather than another kind
+///  of component, to be inlined
+fn is_schema ( target: &Target ) -> bool {
+    let segments = segments(&target.pointer);
+
+    match segments.first().map(|segment| segment.as_str()) {
+        Some("components") => segments.get(1).map(|section| section.as_str()) == Some("schemas"),
+        Some("paths") => false,
+        Some("$defs") | Some("definitions") => true,
+        // Files of bare schemas
+        _ => target.document.is_some()
+    }
+}
+
+/// The names a hoisted schema could be given, best first
+fn hoisted_names ( target: &Target ) -> Vec<String> {
+    let segments = segments(&target.pointer);
+    let named = |segments: &[String]| segments.iter()
+        .filter(|segment| !STRUCTURAL_SEGMENTS.contains(&segment.as_str()))
+        .filter(|segment| segment.parse::<usize>().is_err())
+        .map(|segment| segment.to_case(Case::UpperCamel))
+        .collect::<Vec<String>>();
+
+    // `#/components/schemas/Parent/...`
+    let (parent, rest) = match segments.as_slice() {
+        [components, schemas, parent, rest @ ..] if components == "components" && schemas == "schemas" => {
+            (Some(parent.clone()), rest)
+        },
+        _ => (None, segments.as_slice())
+    };
+
+    let mut names = Vec::new();
+    if rest.is_empty() {
+        match parent {
+            Some(parent) => names.push(parent),
+            None => {
+                if let Some(file_stem) = target.document.as_ref()
+                    .and_then(|path| path.file_stem())
+                    .and_then(|file_stem| file_stem.to_str())
+                {
+                    names.push(file_stem.to_case(Case::UpperCamel));
+                }
+            }
+        }
+
+        return names;
+    }
+
+    // A definition keeps its own name where it can
+    let is_definition = rest.len() >= 2
+        && ["$defs", "definitions"].contains(&rest[rest.len() - 2].as_str());
+    if let Some(last) = named(rest).pop().filter(|_| is_definition || parent.is_none()) {
+        names.push(last);
+    }
+
+    let mut path = named(rest);
+    if let Some(parent) = parent {
+        path.insert(0, parent);
+    }
+    let joined = path.concat();
+    if !joined.is_empty() && !names.contains(&joined) {
+        names.push(joined);
+    }
+
+    names
+}
+
+/// Splits a JSON Pointer into its unescaped segments
+pub(super) fn segments ( pointer: &str ) -> Vec<String> {
+    pointer.split('/')
+        .skip(1)
+        .map(unescape)
+        .collect()
+}
+/// Decodes the escapes in a JSON Pointer segment, `~1` before `~0` so
+///  that `~01` becomes `~1` rather than `/`
+fn unescape ( segment: &str ) -> String {
+    segment.replace("~1", "/").replace("~0", "~")
+}
+/// Finds the node a JSON Pointer points at
+pub(super) fn lookup<'a> ( document: &'a Yaml, pointer: &str ) -> Option<&'a Yaml> {
+    let mut node = document;
+    for segment in segments(pointer) {
+        node = match node {
+            Yaml::Hash(hash) => hash.get(&Yaml::String(segment))?,
+            Yaml::Array(values) => values.get(segment.parse::<usize>().ok()?)?,
+            _ => return None
+        };
+    }
+
+    Some(node)
+}
+/// Rewrites the refs in a copy taken from the file at `path`, so that
+///  they still point at the same places from the spec
+fn rebase_refs ( node: &mut Yaml, path: &Path ) {
+    match node {
+        Yaml::Hash(hash) => {
+            for (key, value) in hash.iter_mut() {
+                if key.as_str() != Some("$ref") {
+                    rebase_refs(value, path);
+                    continue;
+                }
+                let Some(reference) = value.as_str() else {
+                    continue;
+                };
+
+                let (file, pointer) = reference.split_once('#')
+                    .unwrap_or((reference, ""));
+                let file = if file.is_empty() {
+                    path.to_path_buf()
+                } else {
+                    path.parent()
+                        .map(|directory| directory.join(file))
+                        .unwrap_or_else(|| PathBuf::from(file))
+                };
+
+                *value = Yaml::String(format!("{}#{}", file.display(), pointer));
+            }
+        },
+        Yaml::Array(values) => {
+            for value in values.iter_mut() {
+                rebase_refs(value, path);
+            }
+        },
+        _ => { }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec ( raw: &str ) -> Yaml {
+        load_spec(raw).unwrap().remove(0)
+    }
+
+    /// An empty scratch directory for one test
+    fn scratch_dir ( name: &str ) -> PathBuf {
+        let dir = std::env::temp_dir()
+            .join(format!("specgen-refs-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        dir
+    }
+
+    #[test]
+    fn ref_cycle_is_an_error () {
+        let mut spec = spec("
+paths:
+  /pets:
+    get:
+      parameters:
+        - $ref: '#/components/parameters/A'
+components:
+  parameters:
+    A:
+      $ref: '#/components/parameters/B'
+    B:
+      $ref: '#/components/parameters/A'
+");
+
+        let error = resolve_refs(&mut spec, Path::new(".")).unwrap_err();
+        assert!(format!("{error:#}").contains("Reference cycle"), "{error:#}");
+    }
+
+    #[test]
+    fn file_ref_is_hoisted_with_its_refs_rebased () {
+        let dir = scratch_dir("file");
+        std::fs::create_dir_all(dir.join("models")).unwrap();
+        std::fs::write(dir.join("models/pet.yaml"), "
+type: object
+properties:
+  owner:
+    $ref: 'owner.yaml'
+").unwrap();
+        std::fs::write(dir.join("models/owner.yaml"), "
+type: object
+properties:
+  name:
+    type: string
+").unwrap();
+        let mut spec = spec("
+components:
+  schemas:
+    Holder:
+      type: object
+      properties:
+        pet:
+          $ref: 'models/pet.yaml'
+");
+
+        resolve_refs(&mut spec, &dir).unwrap();
+        let schemas = &spec["components"]["schemas"];
+        assert_eq!(schemas["Holder"]["properties"]["pet"]["$ref"].as_str(), Some("#/components/schemas/Pet"));
+        assert_eq!(schemas["Pet"]["properties"]["owner"]["$ref"].as_str(), Some("#/components/schemas/Owner"));
+        assert_eq!(schemas["Owner"]["properties"]["name"]["type"].as_str(), Some("string"));
+    }
+
+    #[test]
+    fn nested_pointer_is_hoisted () {
+        let mut spec = spec("
+components:
+  schemas:
+    Parent:
+      type: object
+      properties:
+        child:
+          type: object
+          properties:
+            id:
+              type: string
+    Sibling:
+      type: object
+      properties:
+        child:
+          $ref: '#/components/schemas/Parent/properties/child'
+");
+
+        resolve_refs(&mut spec, Path::new(".")).unwrap();
+        let schemas = &spec["components"]["schemas"];
+        assert_eq!(schemas["Sibling"]["properties"]["child"]["$ref"].as_str(), Some("#/components/schemas/ParentChild"));
+        assert_eq!(schemas["ParentChild"], schemas["Parent"]["properties"]["child"]);
+    }
+
+    #[test]
+    fn escaped_name_is_decoded () {
+        assert_eq!(schema_name("#/components/schemas/Foo~1Bar").unwrap(), "Foo/Bar");
+        assert_eq!(schema_name("#/components/schemas/Foo~0Bar").unwrap(), "Foo~Bar");
+        assert_eq!(schema_name("#/components/schemas/Foo~01Bar").unwrap(), "Foo~1Bar");
+        assert!(schema_name("#/components/schemas/Foo/Bar").is_err());
+
+        // Escaped refs are already in the form the parser reads
+        let mut spec = spec("
+components:
+  schemas:
+    Foo/Bar:
+      type: string
+    Holder:
+      type: object
+      properties:
+        foo:
+          $ref: '#/components/schemas/Foo~1Bar'
+");
+
+        resolve_refs(&mut spec, Path::new(".")).unwrap();
+        let reference = spec["components"]["schemas"]["Holder"]["properties"]["foo"]["$ref"].as_str().unwrap();
+        assert_eq!(reference, "#/components/schemas/Foo~1Bar");
+        let name = schema_name(reference).unwrap();
+        assert_eq!(spec["components"]["schemas"][name.as_str()]["type"].as_str(), Some("string"));
+    }
+}
@@ -0,0 +1,677 @@
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AdminApiKey {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub object: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub redacted_value: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub value: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub created_at: Option<i64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub owner: Option<AdminApiKeyOwner>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AdminApiKeyOwner {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub r#type: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub created_at: Option<i64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub role: Option<String>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct ApiKeyList {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub object: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub data: Option<Vec<AdminApiKey>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub has_more: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub first_id: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub last_id: Option<String>,
+}
+/// Represents an individual `invite` to the organization.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Invite {
+	/// The object type, which is always `organization.invite`
+	pub object: InviteObject,
+	/// The identifier, which can be referenced in API endpoints
+	pub id: String,
+	/// The email address of the individual to whom the invite was sent
+	pub email: String,
+	/// `owner` or `reader`
+	pub role: InviteRole,
+	/// `accepted`,`expired`, or `pending`
+	pub status: InviteStatus,
+	/// The Unix timestamp (in seconds) of when the invite was sent.
+	pub invited_at: i64,
+	/// The Unix timestamp (in seconds) of when the invite expires.
+	pub expires_at: i64,
+	/// The Unix timestamp (in seconds) of when the invite was accepted.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub accepted_at: Option<i64>,
+	/// The projects that were granted membership upon acceptance of the invite.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub projects: Option<Vec<InviteProjectsItem>>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct InviteDeleteResponse {
+	/// The object type, which is always `organization.invite.deleted`
+	pub object: InviteDeleteResponseObject,
+	pub id: String,
+	pub deleted: bool,
+}
+/// The object type, which is always `organization.invite.deleted`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum InviteDeleteResponseObject {
+	#[serde(rename = "organization.invite.deleted")]
+	OrganizationInviteDeleted,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct InviteListResponse {
+	/// The object type, which is always `list`
+	pub object: InviteListResponseObject,
+	pub data: Vec<Invite>,
+	/// The first `invite_id` in the retrieved `list`
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub first_id: Option<String>,
+	/// The last `invite_id` in the retrieved `list`
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub last_id: Option<String>,
+	/// The `has_more` property is used for pagination to indicate there are additional results.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub has_more: Option<bool>,
+}
+/// The object type, which is always `list`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum InviteListResponseObject {
+	List,
+}
+/// The object type, which is always `organization.invite`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum InviteObject {
+	#[serde(rename = "organization.invite")]
+	OrganizationInvite,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct InviteProjectsItem {
+	/// Project's public ID
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	/// Project membership role
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub role: Option<InviteProjectsItemRole>,
+}
+/// Project membership role
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum InviteProjectsItemRole {
+	Member,
+	Owner,
+}
+/// # Examples
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = InviteRequest {
+///     email: "anotheruser@example.com".to_string(),
+///     role: InviteRequestRole::Reader,
+///     projects: Some(vec![
+///         InviteRequestProjectsItem {
+///             id: "project-xyz".to_string(),
+///             role: InviteRequestProjectsItemRole::Member,
+///         },
+///         InviteRequestProjectsItem {
+///             id: "project-abc".to_string(),
+///             role: InviteRequestProjectsItemRole::Owner,
+///         },
+///     ]),
+/// };
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct InviteRequest {
+	/// Send an email to this address
+	pub email: String,
+	/// `owner` or `reader`
+	pub role: InviteRequestRole,
+	/// An array of projects to which membership is granted at the same time the org invite is accepted. If
+	/// omitted, the user will be invited to the default project for compatibility with legacy behavior.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub projects: Option<Vec<InviteRequestProjectsItem>>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct InviteRequestProjectsItem {
+	/// Project's public ID
+	pub id: String,
+	/// Project membership role
+	pub role: InviteRequestProjectsItemRole,
+}
+/// Project membership role
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum InviteRequestProjectsItemRole {
+	Member,
+	Owner,
+}
+/// `owner` or `reader`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum InviteRequestRole {
+	Reader,
+	Owner,
+}
+/// `owner` or `reader`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum InviteRole {
+	Owner,
+	Reader,
+}
+/// `accepted`,`expired`, or `pending`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum InviteStatus {
+	Accepted,
+	Expired,
+	Pending,
+}
+/// Represents an individual project.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Project {
+	/// The identifier, which can be referenced in API endpoints
+	pub id: String,
+	/// The object type, which is always `organization.project`
+	pub object: ProjectObject,
+	/// The name of the project. This appears in reporting.
+	pub name: String,
+	/// The Unix timestamp (in seconds) of when the project was created.
+	pub created_at: i64,
+	/// The Unix timestamp (in seconds) of when the project was archived or `null`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub archived_at: Option<i64>,
+	/// `active` or `archived`
+	pub status: ProjectStatus,
+}
+/// Represents an individual API key in a project.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ProjectApiKey {
+	/// The object type, which is always `organization.project.api_key`
+	pub object: ProjectApiKeyObject,
+	/// The redacted value of the API key
+	pub redacted_value: String,
+	/// The name of the API key
+	pub name: String,
+	/// The Unix timestamp (in seconds) of when the API key was created
+	pub created_at: i64,
+	/// The identifier, which can be referenced in API endpoints
+	pub id: String,
+	pub owner: ProjectApiKeyOwner,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ProjectApiKeyDeleteResponse {
+	pub object: ProjectApiKeyDeleteResponseObject,
+	pub id: String,
+	pub deleted: bool,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectApiKeyDeleteResponseObject {
+	#[serde(rename = "organization.project.api_key.deleted")]
+	OrganizationProjectApiKeyDeleted,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ProjectApiKeyListResponse {
+	pub object: ProjectApiKeyListResponseObject,
+	pub data: Vec<ProjectApiKey>,
+	pub first_id: String,
+	pub last_id: String,
+	pub has_more: bool,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectApiKeyListResponseObject {
+	List,
+}
+/// The object type, which is always `organization.project.api_key`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectApiKeyObject {
+	#[serde(rename = "organization.project.api_key")]
+	OrganizationProjectApiKey,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct ProjectApiKeyOwner {
+	/// `user` or `service_account`
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub r#type: Option<ProjectApiKeyOwnerType>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub user: Option<ProjectUser>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub service_account: Option<ProjectServiceAccount>,
+}
+/// `user` or `service_account`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectApiKeyOwnerType {
+	User,
+	#[serde(rename = "service_account")]
+	ServiceAccount,
+}
+/// # Examples
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = ProjectCreateRequest {
+///     name: "Project ABC".to_string(),
+/// };
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ProjectCreateRequest {
+	/// The friendly name of the project, this name appears in reports.
+	pub name: String,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ProjectListResponse {
+	pub object: ProjectListResponseObject,
+	pub data: Vec<Project>,
+	pub first_id: String,
+	pub last_id: String,
+	pub has_more: bool,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectListResponseObject {
+	List,
+}
+/// The object type, which is always `organization.project`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectObject {
+	#[serde(rename = "organization.project")]
+	OrganizationProject,
+}
+/// Represents a project rate limit config.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ProjectRateLimit {
+	/// The object type, which is always `project.rate_limit`
+	pub object: ProjectRateLimitObject,
+	/// The identifier, which can be referenced in API endpoints.
+	pub id: String,
+	/// The model this rate limit applies to.
+	pub model: String,
+	/// The maximum requests per minute.
+	pub max_requests_per_1_minute: i64,
+	/// The maximum tokens per minute.
+	pub max_tokens_per_1_minute: i64,
+	/// The maximum images per minute. Only present for relevant models.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_images_per_1_minute: Option<i64>,
+	/// The maximum audio megabytes per minute. Only present for relevant models.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_audio_megabytes_per_1_minute: Option<i64>,
+	/// The maximum requests per day. Only present for relevant models.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_requests_per_1_day: Option<i64>,
+	/// The maximum batch input tokens per day. Only present for relevant models.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub batch_1_day_max_input_tokens: Option<i64>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ProjectRateLimitListResponse {
+	pub object: ProjectRateLimitListResponseObject,
+	pub data: Vec<ProjectRateLimit>,
+	pub first_id: String,
+	pub last_id: String,
+	pub has_more: bool,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectRateLimitListResponseObject {
+	List,
+}
+/// The object type, which is always `project.rate_limit`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectRateLimitObject {
+	#[serde(rename = "project.rate_limit")]
+	ProjectRateLimit,
+}
+/// # Examples
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = ProjectRateLimitUpdateRequest {
+///     max_requests_per_1_minute: Some(500),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct ProjectRateLimitUpdateRequest {
+	/// The maximum requests per minute.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_requests_per_1_minute: Option<i64>,
+	/// The maximum tokens per minute.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_tokens_per_1_minute: Option<i64>,
+	/// The maximum images per minute. Only relevant for certain models.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_images_per_1_minute: Option<i64>,
+	/// The maximum audio megabytes per minute. Only relevant for certain models.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_audio_megabytes_per_1_minute: Option<i64>,
+	/// The maximum requests per day. Only relevant for certain models.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_requests_per_1_day: Option<i64>,
+	/// The maximum batch input tokens per day. Only relevant for certain models.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub batch_1_day_max_input_tokens: Option<i64>,
+}
+/// Represents an individual service account in a project.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ProjectServiceAccount {
+	/// The object type, which is always `organization.project.service_account`
+	pub object: ProjectServiceAccountObject,
+	/// The identifier, which can be referenced in API endpoints
+	pub id: String,
+	/// The name of the service account
+	pub name: String,
+	/// `owner` or `member`
+	pub role: ProjectServiceAccountRole,
+	/// The Unix timestamp (in seconds) of when the service account was created
+	pub created_at: i64,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ProjectServiceAccountApiKey {
+	/// The object type, which is always `organization.project.service_account.api_key`
+	pub object: ProjectServiceAccountApiKeyObject,
+	pub value: String,
+	pub name: String,
+	pub created_at: i64,
+	pub id: String,
+}
+/// The object type, which is always `organization.project.service_account.api_key`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectServiceAccountApiKeyObject {
+	#[serde(rename = "organization.project.service_account.api_key")]
+	OrganizationProjectServiceAccountApiKey,
+}
+/// # Examples
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = ProjectServiceAccountCreateRequest {
+///     name: "Production App".to_string(),
+/// };
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ProjectServiceAccountCreateRequest {
+	/// The name of the service account being created.
+	pub name: String,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ProjectServiceAccountCreateResponse {
+	pub object: ProjectServiceAccountCreateResponseObject,
+	pub id: String,
+	pub name: String,
+	/// Service accounts can only have one role of type `member`
+	pub role: ProjectServiceAccountCreateResponseRole,
+	pub created_at: i64,
+	pub api_key: ProjectServiceAccountApiKey,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectServiceAccountCreateResponseObject {
+	#[serde(rename = "organization.project.service_account")]
+	OrganizationProjectServiceAccount,
+}
+/// Service accounts can only have one role of type `member`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectServiceAccountCreateResponseRole {
+	Member,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ProjectServiceAccountDeleteResponse {
+	pub object: ProjectServiceAccountDeleteResponseObject,
+	pub id: String,
+	pub deleted: bool,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectServiceAccountDeleteResponseObject {
+	#[serde(rename = "organization.project.service_account.deleted")]
+	OrganizationProjectServiceAccountDeleted,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ProjectServiceAccountListResponse {
+	pub object: ProjectServiceAccountListResponseObject,
+	pub data: Vec<ProjectServiceAccount>,
+	pub first_id: String,
+	pub last_id: String,
+	pub has_more: bool,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectServiceAccountListResponseObject {
+	List,
+}
+/// The object type, which is always `organization.project.service_account`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectServiceAccountObject {
+	#[serde(rename = "organization.project.service_account")]
+	OrganizationProjectServiceAccount,
+}
+/// `owner` or `member`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectServiceAccountRole {
+	Owner,
+	Member,
+}
+/// `active` or `archived`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectStatus {
+	Active,
+	Archived,
+}
+/// # Examples
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = ProjectUpdateRequest {
+///     name: "Project DEF".to_string(),
+/// };
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ProjectUpdateRequest {
+	/// The updated name of the project, this name appears in reports.
+	pub name: String,
+}
+/// Represents an individual user in a project.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ProjectUser {
+	/// The object type, which is always `organization.project.user`
+	pub object: ProjectUserObject,
+	/// The identifier, which can be referenced in API endpoints
+	pub id: String,
+	/// The name of the user
+	pub name: String,
+	/// The email address of the user
+	pub email: String,
+	/// `owner` or `member`
+	pub role: ProjectUserRole,
+	/// The Unix timestamp (in seconds) of when the project was added.
+	pub added_at: i64,
+}
+/// # Examples
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = ProjectUserCreateRequest {
+///     user_id: "user_abc".to_string(),
+///     role: ProjectUserCreateRequestRole::Member,
+/// };
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ProjectUserCreateRequest {
+	/// The ID of the user.
+	pub user_id: String,
+	/// `owner` or `member`
+	pub role: ProjectUserCreateRequestRole,
+}
+/// `owner` or `member`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectUserCreateRequestRole {
+	Owner,
+	Member,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ProjectUserDeleteResponse {
+	pub object: ProjectUserDeleteResponseObject,
+	pub id: String,
+	pub deleted: bool,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectUserDeleteResponseObject {
+	#[serde(rename = "organization.project.user.deleted")]
+	OrganizationProjectUserDeleted,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ProjectUserListResponse {
+	pub object: String,
+	pub data: Vec<ProjectUser>,
+	pub first_id: String,
+	pub last_id: String,
+	pub has_more: bool,
+}
+/// The object type, which is always `organization.project.user`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectUserObject {
+	#[serde(rename = "organization.project.user")]
+	OrganizationProjectUser,
+}
+/// `owner` or `member`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectUserRole {
+	Owner,
+	Member,
+}
+/// # Examples
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = ProjectUserUpdateRequest {
+///     role: ProjectUserUpdateRequestRole::Owner,
+/// };
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ProjectUserUpdateRequest {
+	/// `owner` or `member`
+	pub role: ProjectUserUpdateRequestRole,
+}
+/// `owner` or `member`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectUserUpdateRequestRole {
+	Owner,
+	Member,
+}
+/// Represents an individual `user` within an organization.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct User {
+	/// The object type, which is always `organization.user`
+	pub object: UserObject,
+	/// The identifier, which can be referenced in API endpoints
+	pub id: String,
+	/// The name of the user
+	pub name: String,
+	/// The email address of the user
+	pub email: String,
+	/// `owner` or `reader`
+	pub role: UserRole,
+	/// The Unix timestamp (in seconds) of when the user was added.
+	pub added_at: i64,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct UserDeleteResponse {
+	pub object: UserDeleteResponseObject,
+	pub id: String,
+	pub deleted: bool,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum UserDeleteResponseObject {
+	#[serde(rename = "organization.user.deleted")]
+	OrganizationUserDeleted,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct UserListResponse {
+	pub object: UserListResponseObject,
+	pub data: Vec<User>,
+	pub first_id: String,
+	pub last_id: String,
+	pub has_more: bool,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum UserListResponseObject {
+	List,
+}
+/// The object type, which is always `organization.user`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum UserObject {
+	#[serde(rename = "organization.user")]
+	OrganizationUser,
+}
+/// `owner` or `reader`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum UserRole {
+	Owner,
+	Reader,
+}
+/// # Examples
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = UserRoleUpdateRequest {
+///     role: UserRoleUpdateRequestRole::Owner,
+/// };
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct UserRoleUpdateRequest {
+	/// `owner` or `reader`
+	pub role: UserRoleUpdateRequestRole,
+}
+/// `owner` or `reader`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum UserRoleUpdateRequestRole {
+	Owner,
+	Reader,
+}
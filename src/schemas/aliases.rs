@@ -1,19 +1,56 @@
+#[cfg(feature = "chat")]
+use super::chat::ChatCompletionMessageToolCall;
+#[cfg(feature = "chat")]
+use super::chat::ChatCompletionRequestAssistantMessageContentPart;
+#[cfg(feature = "chat")]
+use super::chat::ChatCompletionRequestMessageContentPartText;
+#[cfg(feature = "chat")]
+use super::chat::ChatCompletionRequestSystemMessageContentPart;
+#[cfg(feature = "chat")]
+use super::chat::ChatCompletionRequestToolMessageContentPart;
+#[cfg(feature = "chat")]
+use super::chat::ChatCompletionRequestUserMessageContentPart;
+#[cfg(feature = "chat")]
+use super::chat::ResponseModalitiesItem;
+#[cfg(feature = "moderations")]
+use super::moderations::CreateModerationRequestInputItems;
+#[cfg(feature = "responses")]
+use super::responses::InputContent;
+#[cfg(feature = "responses")]
+use super::responses::InputItem;
+#[cfg(feature = "threads")]
+use super::threads::AssistantsApiToolChoiceOption;
+#[cfg(feature = "threads")]
+use super::threads::CreateMessageRequestContentItems;
+
+use std::collections::HashMap;
+
 /// The tool calls generated by the model, such as function calls.
+#[cfg(feature = "chat")]
 pub type ChatCompletionMessageToolCalls = Vec<ChatCompletionMessageToolCall>;
 
-/// An array of content parts with a defined type. Can be one or more of type `text`, or exactly one of type `refusal`.
+/// An array of content parts with a defined type. Can be one or more of type `text`, or exactly one of
+/// type `refusal`.
+#[cfg(feature = "chat")]
 pub type ChatCompletionRequestAssistantMessageContentChatCompletionRequestAssistantMessageContentPartArray = Vec<ChatCompletionRequestAssistantMessageContentPart>;
 
-/// An array of content parts with a defined type. For developer messages, only type `text` is supported.
+/// An array of content parts with a defined type. For developer messages, only type `text` is
+/// supported.
+#[cfg(feature = "chat")]
 pub type ChatCompletionRequestDeveloperMessageContentChatCompletionRequestMessageContentPartTextArray = Vec<ChatCompletionRequestMessageContentPartText>;
 
 /// An array of content parts with a defined type. For system messages, only type `text` is supported.
+#[cfg(feature = "chat")]
 pub type ChatCompletionRequestSystemMessageContentChatCompletionRequestSystemMessageContentPartArray = Vec<ChatCompletionRequestSystemMessageContentPart>;
 
 /// An array of content parts with a defined type. For tool messages, only type `text` is supported.
+#[cfg(feature = "chat")]
 pub type ChatCompletionRequestToolMessageContentChatCompletionRequestToolMessageContentPartArray = Vec<ChatCompletionRequestToolMessageContentPart>;
 
-/// An array of content parts with a defined type. Supported options differ based on the [model](/docs/models) being used to generate the response. Can contain text, image, or audio inputs.
+/// An array of content parts with a defined type. Supported options differ based on the
+/// [model](https://platform.openai.com/docs/models) being used to generate the response. Can contain
+/// text, image, or audio inputs.
+#[cfg(feature = "chat")]
 pub type ChatCompletionRequestUserMessageContentChatCompletionRequestUserMessageContentPartArray = Vec<ChatCompletionRequestUserMessageContentPart>;
 
 /// Any of:
@@ -55,6 +92,9 @@ pub type CreateCompletionRequestPromptIntegerArray = Vec<i64>;
 
 pub type CreateCompletionRequestPromptStringArray = Vec<String>;
 
+/// JSON Schema
+pub type CreateCompletionResponseChoicesItemLogprobsTopLogprobs = HashMap<String, f64>;
+
 /// The array of arrays containing integers that will be turned into an embedding.
 pub type CreateEmbeddingRequestInputArrayArray = Vec<Vec<Vec<i64>>>;
 
@@ -124,13 +164,17 @@ pub type CreateFineTuningJobRequestModel = serde_json::Value;
 /// ---------------
 pub type CreateImageRequestModel = serde_json::Value;
 
-/// An array of content parts with a defined type, each can be of type `text` or images can be passed with `image_url` or `image_file`. Image types are only supported on [Vision-compatible models](/docs/models).
+/// An array of content parts with a defined type, each can be of type `text` or images can be passed
+/// with `image_url` or `image_file`. Image types are only supported on [Vision-compatible
+/// models](https://platform.openai.com/docs/models).
+#[cfg(feature = "threads")]
 pub type CreateMessageRequestContentVariedArray = Vec<CreateMessageRequestContentItems>;
 
 /// An array of strings to classify for moderation.
 pub type CreateModerationRequestInputStringArray = Vec<String>;
 
 /// An array of multi-modal inputs to the moderation model.
+#[cfg(feature = "moderations")]
 pub type CreateModerationRequestInputVariedArray = Vec<CreateModerationRequestInputItems>;
 
 /// Any of:
@@ -154,8 +198,9 @@ pub type CreateModerationRequestInputVariedArray = Vec<CreateModerationRequestIn
 /// ---------------
 pub type CreateModerationRequestModel = serde_json::Value;
 
-/// A list of one or many input items to the model, containing 
+/// A list of one or many input items to the model, containing
 /// different content types.
+#[cfg(feature = "responses")]
 pub type CreateResponseInputInputItemArray = Vec<InputItem>;
 
 /// Any of:
@@ -171,6 +216,7 @@ pub type CreateResponseInputInputItemArray = Vec<InputItem>;
 /// ---------------
 pub type CreateRunRequestModel = serde_json::Value;
 
+#[cfg(feature = "threads")]
 pub type CreateRunRequestToolChoice = AssistantsApiToolChoiceOption;
 
 /// Any of:
@@ -236,21 +282,25 @@ pub type CreateSpeechRequestModel = serde_json::Value;
 /// ---------------
 pub type CreateThreadAndRunRequestModel = serde_json::Value;
 
+#[cfg(feature = "threads")]
 pub type CreateThreadAndRunRequestToolChoice = AssistantsApiToolChoiceOption;
 
-/// The parameters the functions accepts, described as a JSON Schema object. See the [guide](/docs/guides/function-calling) for examples, and the [JSON Schema reference](https://json-schema.org/understanding-json-schema/) for documentation about the format. 
-/// 
+/// The parameters the functions accepts, described as a JSON Schema object. See the
+/// [guide](https://platform.openai.com/docs/guides/function-calling) for examples, and the [JSON Schema
+/// reference](https://json-schema.org/understanding-json-schema/) for documentation about the format.
+///
 /// Omitting `parameters` defines a function with an empty parameter list.
 pub type FunctionParameters = serde_json::Value;
 
-/// A list of one or many input items to the model, containing different content 
+/// A list of one or many input items to the model, containing different content
 /// types.
+#[cfg(feature = "responses")]
 pub type InputMessageContentList = Vec<InputContent>;
 
 /// Set of 16 key-value pairs that can be attached to an object. This can be
 /// useful for storing additional information about the object in a structured
-/// format, and querying for objects via API or the dashboard. 
-/// 
+/// format, and querying for objects via API or the dashboard.
+///
 /// Keys are strings with a maximum length of 64 characters. Values are strings
 /// with a maximum length of 512 characters.
 pub type Metadata = HashMap<String, String>;
@@ -348,10 +398,15 @@ pub type ModelIdsShared = serde_json::Value;
 /// ---------------
 pub type ModifyAssistantRequestModel = serde_json::Value;
 
-/// Whether to enable [parallel function calling](/docs/guides/function-calling#configuring-parallel-function-calling) during tool use.
+/// Whether to enable [parallel function
+/// calling](https://platform.openai.com/docs/guides/function-calling#configuring-parallel-function-calling)
+/// during tool use.
 pub type ParallelToolCalls = bool;
 
-/// An array of content parts with a defined type. Supported options differ based on the [model](/docs/models) being used to generate the response. Can contain text inputs.
+/// An array of content parts with a defined type. Supported options differ based on the
+/// [model](https://platform.openai.com/docs/models) being used to generate the response. Can contain
+/// text inputs.
+#[cfg(feature = "chat")]
 pub type PredictionContentContentChatCompletionRequestMessageContentPartTextArray = Vec<ChatCompletionRequestMessageContentPartText>;
 
 /// The schema for the response format, described as a JSON Schema object.
@@ -360,22 +415,26 @@ pub type ResponseFormatJsonSchemaSchema = serde_json::Value;
 
 /// Output types that you would like the model to generate.
 /// Most models are capable of generating text, which is the default:
-/// 
+///
 /// `["text"]`
-/// 
-/// The `gpt-4o-audio-preview` model can also be used to 
-/// [generate audio](/docs/guides/audio). To request that this model generate 
+///
+/// The `gpt-4o-audio-preview` model can also be used to
+/// [generate audio](https://platform.openai.com/docs/guides/audio). To request that this model generate
 /// both text and audio responses, you can use:
-/// 
+///
 /// `["text", "audio"]`
+#[cfg(feature = "chat")]
 pub type ResponseModalities = Vec<ResponseModalitiesItem>;
 
+#[cfg(feature = "threads")]
+pub type RunObjectToolChoice = AssistantsApiToolChoiceOption;
+
 pub type StopConfigurationStringArray = Vec<String>;
 
-/// Set of 16 key-value pairs that can be attached to an object. This can be 
-/// useful for storing additional information about the object in a structured 
-/// format, and querying for objects via API or the dashboard. Keys are strings 
-/// with a maximum length of 64 characters. Values are strings with a maximum 
+/// Set of 16 key-value pairs that can be attached to an object. This can be
+/// useful for storing additional information about the object in a structured
+/// format, and querying for objects via API or the dashboard. Keys are strings
+/// with a maximum length of 64 characters. Values are strings with a maximum
 /// length of 512 characters, booleans, or numbers.
 pub type VectorStoreFileAttributes = serde_json::Value;
 
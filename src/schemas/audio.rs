@@ -1,24 +1,44 @@
+use super::aliases::CreateSpeechRequestModel;
+use super::aliases::VoiceIdsShared;
+use super::shared::LogProbProperties;
 
-use std::collections::HashMap;
 use serde::{Serialize, Deserialize};
 
+/// # Examples
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateSpeechRequest {
+///     model: serde_json::json!("gpt-4o-mini-tts"),
+///     input: "The quick brown fox jumped over the lazy dog.".to_string(),
+///     instructions: None,
+///     voice: serde_json::json!("alloy"),
+///     response_format: None,
+///     speed: None,
+/// };
+/// ```
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateSpeechRequest {
+	/// One of the available [TTS models](https://platform.openai.com/docs/models#tts): `tts-1`, `tts-1-hd`
+	/// or `gpt-4o-mini-tts`.
+	pub model: CreateSpeechRequestModel,
 	/// The text to generate audio for. The maximum length is 4096 characters.
 	pub input: String,
-	/// Control the voice of your generated audio with additional instructions. Does not work with `tts-1` or `tts-1-hd`.
+	/// Control the voice of your generated audio with additional instructions. Does not work with `tts-1`
+	/// or `tts-1-hd`.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub instructions: Option<String>,
-	/// One of the available [TTS models](https://platform.openai.com/docs/models#tts): `tts-1`, `tts-1-hd` or `gpt-4o-mini-tts`.
-	pub model: CreateSpeechRequestModel,
+	/// The voice to use when generating the audio. Supported voices are `alloy`, `ash`, `ballad`, `coral`,
+	/// `echo`, `fable`, `onyx`, `nova`, `sage`, `shimmer`, and `verse`. Previews of the voices are
+	/// available in the [Text to speech
+	/// guide](https://platform.openai.com/docs/guides/text-to-speech#voice-options).
+	pub voice: VoiceIdsShared,
 	/// The format to audio in. Supported formats are `mp3`, `opus`, `aac`, `flac`, `wav`, and `pcm`.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub response_format: Option<CreateSpeechRequestResponseFormat>,
 	/// The speed of the generated audio. Select a value from `0.25` to `4.0`. `1.0` is the default.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub speed: Option<f64>,
-	/// The voice to use when generating the audio. Supported voices are `alloy`, `ash`, `ballad`, `coral`, `echo`, `fable`, `onyx`, `nova`, `sage`, `shimmer`, and `verse`. Previews of the voices are available in the [Text to speech guide](https://platform.openai.com/docs/guides/text-to-speech#voice-options).
-	pub voice: VoiceIdsShared,
 }
 /// The format to audio in. Supported formats are `mp3`, `opus`, `aac`, `flac`, `wav`, and `pcm`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -31,3 +51,56 @@ pub enum CreateSpeechRequestResponseFormat {
 	Wav,
 	Pcm,
 }
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(untagged)]
+pub enum CreateTranscriptionResponseStreamEvent {
+	TranscriptTextDeltaEvent(TranscriptTextDeltaEvent),
+	TranscriptTextDoneEvent(TranscriptTextDoneEvent),
+}
+/// Emitted when there is an additional text delta. This is also the first event emitted when the
+/// transcription starts. Only emitted when you [create a
+/// transcription](https://platform.openai.com/docs/api-reference/audio/create-transcription) with the
+/// `Stream` parameter set to `true`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TranscriptTextDeltaEvent {
+	/// The type of the event. Always `transcript.text.delta`.
+	pub r#type: TranscriptTextDeltaEventType,
+	/// The text delta that was additionally transcribed.
+	pub delta: String,
+	/// The log probabilities of the delta. Only included if you [create a
+	/// transcription](https://platform.openai.com/docs/api-reference/audio/create-transcription) with the
+	/// `include[]` parameter set to `logprobs`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub logprobs: Option<Vec<LogProbProperties>>,
+}
+/// The type of the event. Always `transcript.text.delta`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum TranscriptTextDeltaEventType {
+	#[serde(rename = "transcript.text.delta")]
+	TranscriptTextDelta,
+}
+/// Emitted when the transcription is complete. Contains the complete transcription text. Only emitted
+/// when you [create a
+/// transcription](https://platform.openai.com/docs/api-reference/audio/create-transcription) with the
+/// `Stream` parameter set to `true`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TranscriptTextDoneEvent {
+	/// The type of the event. Always `transcript.text.done`.
+	pub r#type: TranscriptTextDoneEventType,
+	/// The text that was transcribed.
+	pub text: String,
+	/// The log probabilities of the individual tokens in the transcription. Only included if you [create a
+	/// transcription](https://platform.openai.com/docs/api-reference/audio/create-transcription) with the
+	/// `include[]` parameter set to `logprobs`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub logprobs: Option<Vec<LogProbProperties>>,
+}
+/// The type of the event. Always `transcript.text.done`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum TranscriptTextDoneEventType {
+	#[serde(rename = "transcript.text.done")]
+	TranscriptTextDone,
+}
@@ -0,0 +1,543 @@
+
+use serde::{Serialize, Deserialize};
+
+/// A log of a user action or configuration change within this organization.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AuditLog {
+	/// The ID of this log.
+	pub id: String,
+	pub r#type: AuditLogEventType,
+	/// The Unix timestamp (in seconds) of the event.
+	pub effective_at: i64,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub project: Option<AuditLogProject>,
+	pub actor: AuditLogActor,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub api_key: Option<AuditLogApiKey>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub invite: Option<AuditLogInvite>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub login: Option<AuditLogLogin>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub logout: Option<AuditLogLogout>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub organization: Option<AuditLogOrganization>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub rate_limit: Option<AuditLogRateLimit>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub service_account: Option<AuditLogServiceAccount>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub user: Option<AuditLogUser>,
+}
+/// The actor who performed the audit logged action.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogActor {
+	/// The type of actor. Is either `session` or `api_key`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub r#type: Option<AuditLogActorType>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub session: Option<AuditLogActorSession>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub api_key: Option<AuditLogActorApiKey>,
+}
+/// The API Key used to perform the audit logged action.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogActorApiKey {
+	/// The tracking id of the API key.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	/// The type of API key. Can be either `user` or `service_account`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub r#type: Option<AuditLogActorApiKeyType>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub user: Option<AuditLogActorUser>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub service_account: Option<AuditLogActorServiceAccount>,
+}
+/// The type of API key. Can be either `user` or `service_account`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditLogActorApiKeyType {
+	User,
+	#[serde(rename = "service_account")]
+	ServiceAccount,
+}
+/// The service account that performed the audit logged action.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogActorServiceAccount {
+	/// The service account id.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+}
+/// The session in which the audit logged action was performed.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogActorSession {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub user: Option<AuditLogActorUser>,
+	/// The IP address from which the action was performed.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub ip_address: Option<String>,
+}
+/// The type of actor. Is either `session` or `api_key`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditLogActorType {
+	Session,
+	#[serde(rename = "api_key")]
+	ApiKey,
+}
+/// The user who performed the audit logged action.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogActorUser {
+	/// The user id.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	/// The user email.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub email: Option<String>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogApiKey {
+	/// The details for events with this `type`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub created: Option<AuditLogApiKeyCreated>,
+	/// The details for events with this `type`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub updated: Option<AuditLogApiKeyUpdated>,
+	/// The details for events with this `type`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub deleted: Option<AuditLogApiKeyDeleted>,
+}
+/// The details for events with this `type`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogApiKeyCreated {
+	/// The tracking ID of the API key.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	/// The payload used to create the API key.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub data: Option<AuditLogApiKeyCreatedData>,
+}
+/// The payload used to create the API key.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogApiKeyCreatedData {
+	/// A list of scopes allowed for the API key, e.g. `["api.model.request"]`
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub scopes: Option<Vec<String>>,
+}
+/// The details for events with this `type`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogApiKeyDeleted {
+	/// The tracking ID of the API key.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+}
+/// The details for events with this `type`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogApiKeyUpdated {
+	/// The tracking ID of the API key.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	/// The payload used to update the API key.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub changes_requested: Option<AuditLogApiKeyUpdatedChangesRequested>,
+}
+/// The payload used to update the API key.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogApiKeyUpdatedChangesRequested {
+	/// A list of scopes allowed for the API key, e.g. `["api.model.request"]`
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub scopes: Option<Vec<String>>,
+}
+/// The event type.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditLogEventType {
+	#[serde(rename = "api_key.created")]
+	ApiKeyCreated,
+	#[serde(rename = "api_key.updated")]
+	ApiKeyUpdated,
+	#[serde(rename = "api_key.deleted")]
+	ApiKeyDeleted,
+	#[serde(rename = "invite.sent")]
+	InviteSent,
+	#[serde(rename = "invite.accepted")]
+	InviteAccepted,
+	#[serde(rename = "invite.deleted")]
+	InviteDeleted,
+	#[serde(rename = "login.succeeded")]
+	LoginSucceeded,
+	#[serde(rename = "login.failed")]
+	LoginFailed,
+	#[serde(rename = "logout.succeeded")]
+	LogoutSucceeded,
+	#[serde(rename = "logout.failed")]
+	LogoutFailed,
+	#[serde(rename = "organization.updated")]
+	OrganizationUpdated,
+	#[serde(rename = "project.created")]
+	ProjectCreated,
+	#[serde(rename = "project.updated")]
+	ProjectUpdated,
+	#[serde(rename = "project.archived")]
+	ProjectArchived,
+	#[serde(rename = "service_account.created")]
+	ServiceAccountCreated,
+	#[serde(rename = "service_account.updated")]
+	ServiceAccountUpdated,
+	#[serde(rename = "service_account.deleted")]
+	ServiceAccountDeleted,
+	#[serde(rename = "rate_limit.updated")]
+	RateLimitUpdated,
+	#[serde(rename = "rate_limit.deleted")]
+	RateLimitDeleted,
+	#[serde(rename = "user.added")]
+	UserAdded,
+	#[serde(rename = "user.updated")]
+	UserUpdated,
+	#[serde(rename = "user.deleted")]
+	UserDeleted,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogInvite {
+	/// The details for events with this `type`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub sent: Option<AuditLogInviteSent>,
+	/// The details for events with this `type`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub accepted: Option<AuditLogInviteAccepted>,
+	/// The details for events with this `type`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub deleted: Option<AuditLogInviteDeleted>,
+}
+/// The details for events with this `type`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogInviteAccepted {
+	/// The ID of the invite.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+}
+/// The details for events with this `type`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogInviteDeleted {
+	/// The ID of the invite.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+}
+/// The details for events with this `type`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogInviteSent {
+	/// The ID of the invite.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	/// The payload used to create the invite.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub data: Option<AuditLogInviteSentData>,
+}
+/// The payload used to create the invite.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogInviteSentData {
+	/// The email invited to the organization.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub email: Option<String>,
+	/// The role the email was invited to be. Is either `owner` or `member`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub role: Option<String>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogLogin {
+	/// The details for events with this `type`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub failed: Option<AuditLogLoginFailed>,
+}
+/// The details for events with this `type`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogLoginFailed {
+	/// The error code of the failure.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub error_code: Option<String>,
+	/// The error message of the failure.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub error_message: Option<String>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogLogout {
+	/// The details for events with this `type`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub failed: Option<AuditLogLogoutFailed>,
+}
+/// The details for events with this `type`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogLogoutFailed {
+	/// The error code of the failure.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub error_code: Option<String>,
+	/// The error message of the failure.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub error_message: Option<String>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogOrganization {
+	/// The details for events with this `type`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub updated: Option<AuditLogOrganizationUpdated>,
+}
+/// The details for events with this `type`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogOrganizationUpdated {
+	/// The organization ID.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	/// The payload used to update the organization settings.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub changes_requested: Option<AuditLogOrganizationUpdatedChangesRequested>,
+}
+/// The payload used to update the organization settings.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogOrganizationUpdatedChangesRequested {
+	/// The organization title.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub title: Option<String>,
+	/// The organization description.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub description: Option<String>,
+	/// The organization name.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub settings: Option<AuditLogOrganizationUpdatedChangesRequestedSettings>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogOrganizationUpdatedChangesRequestedSettings {
+	/// Visibility of the threads page which shows messages created with the Assistants API and Playground.
+	/// One of `ANY_ROLE`, `OWNERS`, or `NONE`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub threads_ui_visibility: Option<String>,
+	/// Visibility of the usage dashboard which shows activity and costs for your organization. One of
+	/// `ANY_ROLE` or `OWNERS`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub usage_dashboard_visibility: Option<String>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogProject {
+	/// The details for events with this `type`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub created: Option<AuditLogProjectCreated>,
+	/// The details for events with this `type`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub updated: Option<AuditLogProjectUpdated>,
+	/// The details for events with this `type`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub archived: Option<AuditLogProjectArchived>,
+}
+/// The details for events with this `type`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogProjectArchived {
+	/// The project ID.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+}
+/// The details for events with this `type`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogProjectCreated {
+	/// The project ID.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	/// The payload used to create the project.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub data: Option<AuditLogProjectCreatedData>,
+}
+/// The payload used to create the project.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogProjectCreatedData {
+	/// The project name.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+	/// The title of the project as seen on the dashboard.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub title: Option<String>,
+}
+/// The details for events with this `type`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogProjectUpdated {
+	/// The project ID.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	/// The payload used to update the project.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub changes_requested: Option<AuditLogProjectUpdatedChangesRequested>,
+}
+/// The payload used to update the project.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogProjectUpdatedChangesRequested {
+	/// The title of the project as seen on the dashboard.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub title: Option<String>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogRateLimit {
+	/// The details for events with this `type`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub updated: Option<AuditLogRateLimitUpdated>,
+	/// The details for events with this `type`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub deleted: Option<AuditLogRateLimitDeleted>,
+}
+/// The details for events with this `type`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogRateLimitDeleted {
+	/// The rate limit ID
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+}
+/// The details for events with this `type`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogRateLimitUpdated {
+	/// The rate limit ID
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	/// The payload used to update the rate limits.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub changes_requested: Option<AuditLogRateLimitUpdatedChangesRequested>,
+}
+/// The payload used to update the rate limits.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogRateLimitUpdatedChangesRequested {
+	/// The maximum requests per minute.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_requests_per_1_minute: Option<i64>,
+	/// The maximum tokens per minute.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_tokens_per_1_minute: Option<i64>,
+	/// The maximum images per minute. Only relevant for certain models.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_images_per_1_minute: Option<i64>,
+	/// The maximum audio megabytes per minute. Only relevant for certain models.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_audio_megabytes_per_1_minute: Option<i64>,
+	/// The maximum requests per day. Only relevant for certain models.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_requests_per_1_day: Option<i64>,
+	/// The maximum batch input tokens per day. Only relevant for certain models.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub batch_1_day_max_input_tokens: Option<i64>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogServiceAccount {
+	/// The details for events with this `type`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub created: Option<AuditLogServiceAccountCreated>,
+	/// The details for events with this `type`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub updated: Option<AuditLogServiceAccountUpdated>,
+	/// The details for events with this `type`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub deleted: Option<AuditLogServiceAccountDeleted>,
+}
+/// The details for events with this `type`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogServiceAccountCreated {
+	/// The service account ID.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	/// The payload used to create the service account.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub data: Option<AuditLogServiceAccountCreatedData>,
+}
+/// The payload used to create the service account.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogServiceAccountCreatedData {
+	/// The role of the service account. Is either `owner` or `member`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub role: Option<String>,
+}
+/// The details for events with this `type`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogServiceAccountDeleted {
+	/// The service account ID.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+}
+/// The details for events with this `type`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogServiceAccountUpdated {
+	/// The service account ID.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	/// The payload used to updated the service account.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub changes_requested: Option<AuditLogServiceAccountUpdatedChangesRequested>,
+}
+/// The payload used to updated the service account.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogServiceAccountUpdatedChangesRequested {
+	/// The role of the service account. Is either `owner` or `member`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub role: Option<String>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogUser {
+	/// The details for events with this `type`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub added: Option<AuditLogUserAdded>,
+	/// The details for events with this `type`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub updated: Option<AuditLogUserUpdated>,
+	/// The details for events with this `type`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub deleted: Option<AuditLogUserDeleted>,
+}
+/// The details for events with this `type`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogUserAdded {
+	/// The user ID.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	/// The payload used to add the user to the project.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub data: Option<AuditLogUserAddedData>,
+}
+/// The payload used to add the user to the project.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogUserAddedData {
+	/// The role of the user. Is either `owner` or `member`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub role: Option<String>,
+}
+/// The details for events with this `type`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogUserDeleted {
+	/// The user ID.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+}
+/// The details for events with this `type`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogUserUpdated {
+	/// The project ID.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	/// The payload used to update the user.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub changes_requested: Option<AuditLogUserUpdatedChangesRequested>,
+}
+/// The payload used to update the user.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AuditLogUserUpdatedChangesRequested {
+	/// The role of the user. Is either `owner` or `member`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub role: Option<String>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ListAuditLogsResponse {
+	pub object: ListAuditLogsResponseObject,
+	pub data: Vec<AuditLog>,
+	pub first_id: String,
+	pub last_id: String,
+	pub has_more: bool,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ListAuditLogsResponseObject {
+	List,
+}
@@ -0,0 +1,125 @@
+use super::aliases::Metadata;
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Batch {
+	pub id: String,
+	/// The object type, which is always `batch`.
+	pub object: BatchObject,
+	/// The OpenAI API endpoint used by the batch.
+	pub endpoint: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub errors: Option<BatchErrors>,
+	/// The ID of the input file for the batch.
+	pub input_file_id: String,
+	/// The time frame within which the batch should be processed.
+	pub completion_window: String,
+	/// The current status of the batch.
+	pub status: BatchStatus,
+	/// The ID of the file containing the outputs of successfully executed requests.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub output_file_id: Option<String>,
+	/// The ID of the file containing the outputs of requests with errors.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub error_file_id: Option<String>,
+	/// The Unix timestamp (in seconds) for when the batch was created.
+	pub created_at: i64,
+	/// The Unix timestamp (in seconds) for when the batch started processing.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub in_progress_at: Option<i64>,
+	/// The Unix timestamp (in seconds) for when the batch will expire.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub expires_at: Option<i64>,
+	/// The Unix timestamp (in seconds) for when the batch started finalizing.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub finalizing_at: Option<i64>,
+	/// The Unix timestamp (in seconds) for when the batch was completed.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub completed_at: Option<i64>,
+	/// The Unix timestamp (in seconds) for when the batch failed.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub failed_at: Option<i64>,
+	/// The Unix timestamp (in seconds) for when the batch expired.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub expired_at: Option<i64>,
+	/// The Unix timestamp (in seconds) for when the batch started cancelling.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub cancelling_at: Option<i64>,
+	/// The Unix timestamp (in seconds) for when the batch was cancelled.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub cancelled_at: Option<i64>,
+	/// The request counts for different statuses within the batch.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub request_counts: Option<BatchRequestCounts>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub metadata: Option<Metadata>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct BatchErrors {
+	/// The object type, which is always `list`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub object: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub data: Option<Vec<BatchErrorsDataItem>>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct BatchErrorsDataItem {
+	/// An error code identifying the error type.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub code: Option<String>,
+	/// A human-readable message providing more details about the error.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub message: Option<String>,
+	/// The name of the parameter that caused the error, if applicable.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub param: Option<String>,
+	/// The line number of the input file where the error occurred, if applicable.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub line: Option<i64>,
+}
+/// The object type, which is always `batch`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum BatchObject {
+	Batch,
+}
+/// The request counts for different statuses within the batch.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BatchRequestCounts {
+	/// Total number of requests in the batch.
+	pub total: i64,
+	/// Number of requests that have been completed successfully.
+	pub completed: i64,
+	/// Number of requests that have failed.
+	pub failed: i64,
+}
+/// The current status of the batch.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum BatchStatus {
+	Validating,
+	Failed,
+	#[serde(rename = "in_progress")]
+	InProgress,
+	Finalizing,
+	Completed,
+	Expired,
+	Cancelling,
+	Cancelled,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ListBatchesResponse {
+	pub data: Vec<Batch>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub first_id: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub last_id: Option<String>,
+	pub has_more: bool,
+	pub object: ListBatchesResponseObject,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ListBatchesResponseObject {
+	List,
+}
@@ -1,12 +1,47 @@
-use super::assistants_chat::ResponseFormatJsonSchema;
-use super::assistants_chat_responses::ResponseFormatJsonObject;
-use super::assistants_chat_responses::ResponseFormatText;
-use super::chat_responses::CreateModelResponseProperties;
+use super::aliases::ChatCompletionMessageToolCalls;
+use super::aliases::ChatCompletionRequestAssistantMessageContentChatCompletionRequestAssistantMessageContentPartArray;
+use super::aliases::ChatCompletionRequestDeveloperMessageContentChatCompletionRequestMessageContentPartTextArray;
+use super::aliases::ChatCompletionRequestSystemMessageContentChatCompletionRequestSystemMessageContentPartArray;
+use super::aliases::ChatCompletionRequestToolMessageContentChatCompletionRequestToolMessageContentPartArray;
+use super::aliases::ChatCompletionRequestUserMessageContentChatCompletionRequestUserMessageContentPartArray;
+use super::aliases::FunctionParameters;
+use super::aliases::Metadata;
+use super::aliases::ModelIdsShared;
+use super::aliases::ParallelToolCalls;
+use super::aliases::PredictionContentContentChatCompletionRequestMessageContentPartTextArray;
+use super::aliases::ResponseModalities;
+use super::aliases::VoiceIdsShared;
+use super::shared::ChatCompletionStreamOptions;
+use super::shared::CompletionUsage;
+use super::shared::FunctionObject;
+use super::shared::ReasoningEffort;
+use super::shared::ResponseFormatJsonObject;
+use super::shared::ResponseFormatJsonSchema;
+use super::shared::ResponseFormatText;
+use super::shared::StopConfiguration;
+use super::shared::WebSearchContextSize;
+use super::shared::WebSearchLocation;
 
-use std::collections::HashMap;
 use serde::{Serialize, Deserialize};
 
-/// Specifying a particular function via `{"name": "my_function"}` forces the model to call that function.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ChatCompletionDeleted {
+	/// The type of object being deleted.
+	pub object: ChatCompletionDeletedObject,
+	/// The ID of the chat completion that was deleted.
+	pub id: String,
+	/// Whether the chat completion was deleted.
+	pub deleted: bool,
+}
+/// The type of object being deleted.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChatCompletionDeletedObject {
+	#[serde(rename = "chat.completion.deleted")]
+	ChatCompletionDeleted,
+}
+/// Specifying a particular function via `{"name": "my_function"}` forces the model to call that
+/// function.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ChatCompletionFunctionCallOption {
 	/// The name of the function to call.
@@ -14,30 +49,130 @@ pub struct ChatCompletionFunctionCallOption {
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ChatCompletionFunctions {
-	/// A description of what the function does, used by the model to choose when and how to call the function.
+	/// A description of what the function does, used by the model to choose when and how to call the
+	/// function.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub description: Option<String>,
-	/// The name of the function to be called. Must be a-z, A-Z, 0-9, or contain underscores and dashes, with a maximum length of 64.
+	/// The name of the function to be called. Must be a-z, A-Z, 0-9, or contain underscores and dashes,
+	/// with a maximum length of 64.
 	pub name: String,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub parameters: Option<FunctionParameters>,
 }
+/// An object representing a list of Chat Completions.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ChatCompletionList {
+	/// The type of this object. It is always set to "list".
+	pub object: ChatCompletionListObject,
+	/// An array of chat completion objects.
+	pub data: Vec<CreateChatCompletionResponse>,
+	/// The identifier of the first chat completion in the data array.
+	pub first_id: String,
+	/// The identifier of the last chat completion in the data array.
+	pub last_id: String,
+	/// Indicates whether there are more Chat Completions available.
+	pub has_more: bool,
+}
+/// The type of this object. It is always set to "list".
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChatCompletionListObject {
+	List,
+}
+/// An object representing a list of chat completion messages.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ChatCompletionMessageList {
+	/// The type of this object. It is always set to "list".
+	pub object: ChatCompletionMessageListObject,
+	/// An array of chat completion message objects.
+	pub data: Vec<ChatCompletionMessageListData>,
+	/// The identifier of the first chat message in the data array.
+	pub first_id: String,
+	/// The identifier of the last chat message in the data array.
+	pub last_id: String,
+	/// Indicates whether there are more chat messages available.
+	pub has_more: bool,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ChatCompletionMessageListData {
+	/// The contents of the message.
+	pub content: Option<String>,
+	/// The refusal message generated by the model.
+	pub refusal: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub tool_calls: Option<ChatCompletionMessageToolCalls>,
+	/// Annotations for the message, when applicable, as when using the
+	/// [web search tool](https://platform.openai.com/docs/guides/tools-web-search?api-mode=chat).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub annotations: Option<Vec<ChatCompletionResponseMessageAnnotationsItem>>,
+	/// The role of the author of this message.
+	pub role: ChatCompletionResponseMessageRole,
+	/// Deprecated and replaced by `tool_calls`. The name and arguments of a function that should be called,
+	/// as generated by the model.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub function_call: Option<ChatCompletionResponseMessageFunctionCall>,
+	/// If the audio output modality is requested, this object contains data
+	/// about the audio response from the model. [Learn
+	/// more](https://platform.openai.com/docs/guides/audio).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub audio: Option<ChatCompletionResponseMessageAudio>,
+	/// The identifier of the chat message.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+}
+/// The type of this object. It is always set to "list".
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChatCompletionMessageListObject {
+	List,
+}
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ChatCompletionMessageToolCall {
-	/// The function that the model called.
-	pub function: ChatCompletionMessageToolCallFunction,
 	/// The ID of the tool call.
 	pub id: String,
 	/// The type of the tool. Currently, only `function` is supported.
 	pub r#type: ChatCompletionMessageToolCallType,
+	/// The function that the model called.
+	pub function: ChatCompletionMessageToolCallFunction,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ChatCompletionMessageToolCallChunk {
+	pub index: i64,
+	/// The ID of the tool call.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	/// The type of the tool. Currently, only `function` is supported.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub r#type: Option<ChatCompletionMessageToolCallChunkType>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub function: Option<ChatCompletionMessageToolCallChunkFunction>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct ChatCompletionMessageToolCallChunkFunction {
+	/// The name of the function to call.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+	/// The arguments to call the function with, as generated by the model in JSON format. Note that the
+	/// model does not always generate valid JSON, and may hallucinate parameters not defined by your
+	/// function schema. Validate the arguments in your code before calling your function.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub arguments: Option<String>,
+}
+/// The type of the tool. Currently, only `function` is supported.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChatCompletionMessageToolCallChunkType {
+	Function,
 }
 /// The function that the model called.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ChatCompletionMessageToolCallFunction {
-	/// The arguments to call the function with, as generated by the model in JSON format. Note that the model does not always generate valid JSON, and may hallucinate parameters not defined by your function schema. Validate the arguments in your code before calling your function.
-	pub arguments: String,
 	/// The name of the function to call.
 	pub name: String,
+	/// The arguments to call the function with, as generated by the model in JSON format. Note that the
+	/// model does not always generate valid JSON, and may hallucinate parameters not defined by your
+	/// function schema. Validate the arguments in your code before calling your function.
+	pub arguments: String,
 }
 /// The type of the tool. Currently, only `function` is supported.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -48,9 +183,9 @@ pub enum ChatCompletionMessageToolCallType {
 /// Specifies a tool the model should use. Use to force the model to call a specific function.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ChatCompletionNamedToolChoice {
-	pub function: ChatCompletionNamedToolChoiceFunction,
 	/// The type of the tool. Currently, only `function` is supported.
 	pub r#type: ChatCompletionNamedToolChoiceType,
+	pub function: ChatCompletionNamedToolChoiceFunction,
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ChatCompletionNamedToolChoiceFunction {
@@ -66,28 +201,30 @@ pub enum ChatCompletionNamedToolChoiceType {
 /// Messages sent by the model in response to user messages.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ChatCompletionRequestAssistantMessage {
-	/// Data about a previous audio response from the model. 
-	/// [Learn more](https://platform.openai.com/docs/guides/audio).
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub audio: Option<ChatCompletionRequestAssistantMessageAudio>,
 	/// The contents of the assistant message. Required unless `tool_calls` or `function_call` is specified.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub content: Option<ChatCompletionRequestAssistantMessageContent>,
-	/// Deprecated and replaced by `tool_calls`. The name and arguments of a function that should be called, as generated by the model.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub function_call: Option<ChatCompletionRequestAssistantMessageFunctionCall>,
-	/// An optional name for the participant. Provides the model information to differentiate between participants of the same role.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub name: Option<String>,
 	/// The refusal message by the assistant.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub refusal: Option<String>,
 	/// The role of the messages author, in this case `assistant`.
 	pub role: ChatCompletionRequestAssistantMessageRole,
+	/// An optional name for the participant. Provides the model information to differentiate between
+	/// participants of the same role.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+	/// Data about a previous audio response from the model.
+	/// [Learn more](https://platform.openai.com/docs/guides/audio).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub audio: Option<ChatCompletionRequestAssistantMessageAudio>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub tool_calls: Option<ChatCompletionMessageToolCalls>,
+	/// Deprecated and replaced by `tool_calls`. The name and arguments of a function that should be called,
+	/// as generated by the model.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub function_call: Option<ChatCompletionRequestAssistantMessageFunctionCall>,
 }
-/// Data about a previous audio response from the model. 
+/// Data about a previous audio response from the model.
 /// [Learn more](https://platform.openai.com/docs/guides/audio).
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ChatCompletionRequestAssistantMessageAudio {
@@ -109,10 +246,13 @@ pub enum ChatCompletionRequestAssistantMessageContentPart {
 	ChatCompletionRequestMessageContentPartText(ChatCompletionRequestMessageContentPartText),
 	ChatCompletionRequestMessageContentPartRefusal(ChatCompletionRequestMessageContentPartRefusal),
 }
-/// Deprecated and replaced by `tool_calls`. The name and arguments of a function that should be called, as generated by the model.
+/// Deprecated and replaced by `tool_calls`. The name and arguments of a function that should be called,
+/// as generated by the model.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ChatCompletionRequestAssistantMessageFunctionCall {
-	/// The arguments to call the function with, as generated by the model in JSON format. Note that the model does not always generate valid JSON, and may hallucinate parameters not defined by your function schema. Validate the arguments in your code before calling your function.
+	/// The arguments to call the function with, as generated by the model in JSON format. Note that the
+	/// model does not always generate valid JSON, and may hallucinate parameters not defined by your
+	/// function schema. Validate the arguments in your code before calling your function.
 	pub arguments: String,
 	/// The name of the function to call.
 	pub name: String,
@@ -130,11 +270,12 @@ pub enum ChatCompletionRequestAssistantMessageRole {
 pub struct ChatCompletionRequestDeveloperMessage {
 	/// The contents of the developer message.
 	pub content: ChatCompletionRequestDeveloperMessageContent,
-	/// An optional name for the participant. Provides the model information to differentiate between participants of the same role.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub name: Option<String>,
 	/// The role of the messages author, in this case `developer`.
 	pub role: ChatCompletionRequestDeveloperMessageRole,
+	/// An optional name for the participant. Provides the model information to differentiate between
+	/// participants of the same role.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
 }
 /// The contents of the developer message.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -152,12 +293,12 @@ pub enum ChatCompletionRequestDeveloperMessageRole {
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ChatCompletionRequestFunctionMessage {
+	/// The role of the messages author, in this case `function`.
+	pub role: ChatCompletionRequestFunctionMessageRole,
 	/// The contents of the function message.
-	pub content: String,
+	pub content: Option<String>,
 	/// The name of the function to call.
 	pub name: String,
-	/// The role of the messages author, in this case `function`.
-	pub role: ChatCompletionRequestFunctionMessageRole,
 }
 /// The role of the messages author, in this case `function`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -179,9 +320,9 @@ pub enum ChatCompletionRequestMessage {
 /// Learn about [audio inputs](https://platform.openai.com/docs/guides/audio).
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ChatCompletionRequestMessageContentPartAudio {
-	pub input_audio: ChatCompletionRequestMessageContentPartAudioInputAudio,
 	/// The type of the content part. Always `input_audio`.
 	pub r#type: ChatCompletionRequestMessageContentPartAudioType,
+	pub input_audio: ChatCompletionRequestMessageContentPartAudioInputAudio,
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ChatCompletionRequestMessageContentPartAudioInputAudio {
@@ -207,23 +348,23 @@ pub enum ChatCompletionRequestMessageContentPartAudioType {
 /// Learn about [file inputs](https://platform.openai.com/docs/guides/text) for text generation.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ChatCompletionRequestMessageContentPartFile {
-	pub file: ChatCompletionRequestMessageContentPartFileFile,
 	/// The type of the content part. Always `file`.
 	pub r#type: ChatCompletionRequestMessageContentPartFileType,
+	pub file: ChatCompletionRequestMessageContentPartFileFile,
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct ChatCompletionRequestMessageContentPartFileFile {
-	/// The base64 encoded file data, used when passing the file to the model 
+	/// The name of the file, used when passing the file to the model as a
+	/// string.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub filename: Option<String>,
+	/// The base64 encoded file data, used when passing the file to the model
 	/// as a string.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub file_data: Option<String>,
 	/// The ID of an uploaded file to use as input.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub file_id: Option<String>,
-	/// The name of the file, used when passing the file to the model as a 
-	/// string.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub filename: Option<String>,
 }
 /// The type of the content part. Always `file`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -234,19 +375,21 @@ pub enum ChatCompletionRequestMessageContentPartFileType {
 /// Learn about [image inputs](https://platform.openai.com/docs/guides/vision).
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ChatCompletionRequestMessageContentPartImage {
-	pub image_url: ChatCompletionRequestMessageContentPartImageImageUrl,
 	/// The type of the content part.
 	pub r#type: ChatCompletionRequestMessageContentPartImageType,
+	pub image_url: ChatCompletionRequestMessageContentPartImageImageUrl,
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ChatCompletionRequestMessageContentPartImageImageUrl {
-	/// Specifies the detail level of the image. Learn more in the [Vision guide](https://platform.openai.com/docs/guides/vision#low-or-high-fidelity-image-understanding).
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub detail: Option<ChatCompletionRequestMessageContentPartImageImageUrlDetail>,
 	/// Either a URL of the image or the base64 encoded image data.
 	pub url: String,
+	/// Specifies the detail level of the image. Learn more in the [Vision
+	/// guide](https://platform.openai.com/docs/guides/vision#low-or-high-fidelity-image-understanding).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub detail: Option<ChatCompletionRequestMessageContentPartImageImageUrlDetail>,
 }
-/// Specifies the detail level of the image. Learn more in the [Vision guide](https://platform.openai.com/docs/guides/vision#low-or-high-fidelity-image-understanding).
+/// Specifies the detail level of the image. Learn more in the [Vision
+/// guide](https://platform.openai.com/docs/guides/vision#low-or-high-fidelity-image-understanding).
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum ChatCompletionRequestMessageContentPartImageImageUrlDetail {
@@ -263,10 +406,10 @@ pub enum ChatCompletionRequestMessageContentPartImageType {
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ChatCompletionRequestMessageContentPartRefusal {
-	/// The refusal message generated by the model.
-	pub refusal: String,
 	/// The type of the content part.
 	pub r#type: ChatCompletionRequestMessageContentPartRefusalType,
+	/// The refusal message generated by the model.
+	pub refusal: String,
 }
 /// The type of the content part.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -277,10 +420,10 @@ pub enum ChatCompletionRequestMessageContentPartRefusalType {
 /// Learn about [text inputs](https://platform.openai.com/docs/guides/text-generation).
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ChatCompletionRequestMessageContentPartText {
-	/// The text content.
-	pub text: String,
 	/// The type of the content part.
 	pub r#type: ChatCompletionRequestMessageContentPartTextType,
+	/// The text content.
+	pub text: String,
 }
 /// The type of the content part.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -295,11 +438,12 @@ pub enum ChatCompletionRequestMessageContentPartTextType {
 pub struct ChatCompletionRequestSystemMessage {
 	/// The contents of the system message.
 	pub content: ChatCompletionRequestSystemMessageContent,
-	/// An optional name for the participant. Provides the model information to differentiate between participants of the same role.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub name: Option<String>,
 	/// The role of the messages author, in this case `system`.
 	pub role: ChatCompletionRequestSystemMessageRole,
+	/// An optional name for the participant. Provides the model information to differentiate between
+	/// participants of the same role.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
 }
 /// The contents of the system message.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -323,10 +467,10 @@ pub enum ChatCompletionRequestSystemMessageRole {
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ChatCompletionRequestToolMessage {
-	/// The contents of the tool message.
-	pub content: ChatCompletionRequestToolMessageContent,
 	/// The role of the messages author, in this case `tool`.
 	pub role: ChatCompletionRequestToolMessageRole,
+	/// The contents of the tool message.
+	pub content: ChatCompletionRequestToolMessageContent,
 	/// Tool call that this message is responding to.
 	pub tool_call_id: String,
 }
@@ -356,11 +500,12 @@ pub enum ChatCompletionRequestToolMessageRole {
 pub struct ChatCompletionRequestUserMessage {
 	/// The contents of the user message.
 	pub content: ChatCompletionRequestUserMessageContent,
-	/// An optional name for the participant. Provides the model information to differentiate between participants of the same role.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub name: Option<String>,
 	/// The role of the messages author, in this case `user`.
 	pub role: ChatCompletionRequestUserMessageRole,
+	/// An optional name for the participant. Provides the model information to differentiate between
+	/// participants of the same role.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
 }
 /// The contents of the user message.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -385,18 +530,176 @@ pub enum ChatCompletionRequestUserMessageContentPart {
 pub enum ChatCompletionRequestUserMessageRole {
 	User,
 }
+/// A chat completion message generated by the model.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ChatCompletionResponseMessage {
+	/// The contents of the message.
+	pub content: Option<String>,
+	/// The refusal message generated by the model.
+	pub refusal: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub tool_calls: Option<ChatCompletionMessageToolCalls>,
+	/// Annotations for the message, when applicable, as when using the
+	/// [web search tool](https://platform.openai.com/docs/guides/tools-web-search?api-mode=chat).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub annotations: Option<Vec<ChatCompletionResponseMessageAnnotationsItem>>,
+	/// The role of the author of this message.
+	pub role: ChatCompletionResponseMessageRole,
+	/// Deprecated and replaced by `tool_calls`. The name and arguments of a function that should be called,
+	/// as generated by the model.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub function_call: Option<ChatCompletionResponseMessageFunctionCall>,
+	/// If the audio output modality is requested, this object contains data
+	/// about the audio response from the model. [Learn
+	/// more](https://platform.openai.com/docs/guides/audio).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub audio: Option<ChatCompletionResponseMessageAudio>,
+}
+/// A URL citation when using web search.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ChatCompletionResponseMessageAnnotationsItem {
+	/// The type of the URL citation. Always `url_citation`.
+	pub r#type: ChatCompletionResponseMessageAnnotationsItemType,
+	/// A URL citation when using web search.
+	pub url_citation: ChatCompletionResponseMessageAnnotationsItemUrlCitation,
+}
+/// The type of the URL citation. Always `url_citation`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChatCompletionResponseMessageAnnotationsItemType {
+	#[serde(rename = "url_citation")]
+	UrlCitation,
+}
+/// A URL citation when using web search.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ChatCompletionResponseMessageAnnotationsItemUrlCitation {
+	/// The index of the last character of the URL citation in the message.
+	pub end_index: i64,
+	/// The index of the first character of the URL citation in the message.
+	pub start_index: i64,
+	/// The URL of the web resource.
+	pub url: String,
+	/// The title of the web resource.
+	pub title: String,
+}
+/// If the audio output modality is requested, this object contains data
+/// about the audio response from the model. [Learn
+/// more](https://platform.openai.com/docs/guides/audio).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ChatCompletionResponseMessageAudio {
+	/// Unique identifier for this audio response.
+	pub id: String,
+	/// The Unix timestamp (in seconds) for when this audio response will
+	/// no longer be accessible on the server for use in multi-turn
+	/// conversations.
+	pub expires_at: i64,
+	/// Base64 encoded audio bytes generated by the model, in the format
+	/// specified in the request.
+	pub data: String,
+	/// Transcript of the audio generated by the model.
+	pub transcript: String,
+}
+/// Deprecated and replaced by `tool_calls`. The name and arguments of a function that should be called,
+/// as generated by the model.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ChatCompletionResponseMessageFunctionCall {
+	/// The arguments to call the function with, as generated by the model in JSON format. Note that the
+	/// model does not always generate valid JSON, and may hallucinate parameters not defined by your
+	/// function schema. Validate the arguments in your code before calling your function.
+	pub arguments: String,
+	/// The name of the function to call.
+	pub name: String,
+}
+/// The role of the author of this message.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChatCompletionResponseMessageRole {
+	Assistant,
+}
+/// A chat completion delta generated by streamed model responses.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct ChatCompletionStreamResponseDelta {
+	/// The contents of the chunk message.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub content: Option<String>,
+	/// Deprecated and replaced by `tool_calls`. The name and arguments of a function that should be called,
+	/// as generated by the model.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub function_call: Option<ChatCompletionStreamResponseDeltaFunctionCall>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub tool_calls: Option<Vec<ChatCompletionMessageToolCallChunk>>,
+	/// The role of the author of this message.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub role: Option<ChatCompletionStreamResponseDeltaRole>,
+	/// The refusal message generated by the model.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub refusal: Option<String>,
+}
+/// Deprecated and replaced by `tool_calls`. The name and arguments of a function that should be called,
+/// as generated by the model.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct ChatCompletionStreamResponseDeltaFunctionCall {
+	/// The arguments to call the function with, as generated by the model in JSON format. Note that the
+	/// model does not always generate valid JSON, and may hallucinate parameters not defined by your
+	/// function schema. Validate the arguments in your code before calling your function.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub arguments: Option<String>,
+	/// The name of the function to call.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+}
+/// The role of the author of this message.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChatCompletionStreamResponseDeltaRole {
+	Developer,
+	System,
+	User,
+	Assistant,
+	Tool,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ChatCompletionTokenLogprob {
+	/// The token.
+	pub token: String,
+	/// The log probability of this token, if it is within the top 20 most likely tokens. Otherwise, the
+	/// value `-9999.0` is used to signify that the token is very unlikely.
+	pub logprob: f64,
+	/// A list of integers representing the UTF-8 bytes representation of the token. Useful in instances
+	/// where characters are represented by multiple tokens and their byte representations must be combined
+	/// to generate the correct text representation. Can be `null` if there is no bytes representation for
+	/// the token.
+	pub bytes: Option<Vec<i64>>,
+	/// List of the most likely tokens and their log probability, at this token position. In rare cases,
+	/// there may be fewer than the number of requested `top_logprobs` returned.
+	pub top_logprobs: Vec<ChatCompletionTokenLogprobTopLogprobsItem>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ChatCompletionTokenLogprobTopLogprobsItem {
+	/// The token.
+	pub token: String,
+	/// The log probability of this token, if it is within the top 20 most likely tokens. Otherwise, the
+	/// value `-9999.0` is used to signify that the token is very unlikely.
+	pub logprob: f64,
+	/// A list of integers representing the UTF-8 bytes representation of the token. Useful in instances
+	/// where characters are represented by multiple tokens and their byte representations must be combined
+	/// to generate the correct text representation. Can be `null` if there is no bytes representation for
+	/// the token.
+	pub bytes: Option<Vec<i64>>,
+}
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ChatCompletionTool {
-	pub function: FunctionObject,
 	/// The type of the tool. Currently, only `function` is supported.
 	pub r#type: ChatCompletionToolType,
+	pub function: FunctionObject,
 }
 /// Controls which (if any) tool is called by the model.
 /// `none` means the model will not call any tool and instead generates a message.
 /// `auto` means the model can pick between generating a message or calling one or more tools.
 /// `required` means the model must call one or more tools.
-/// Specifying a particular tool via `{"type": "function", "function": {"name": "my_function"}}` forces the model to call that tool.
-/// 
+/// Specifying a particular tool via `{"type": "function", "function": {"name": "my_function"}}` forces
+/// the model to call that tool.
+///
 /// `none` is the default when no tools are present. `auto` is the default if tools are present.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -413,182 +716,323 @@ pub enum ChatCompletionToolChoiceOption {
 pub enum ChatCompletionToolType {
 	Function,
 }
+/// # Examples
+///
+/// Default:
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateChatCompletionRequest {
+///     messages: Some(vec![
+///         ChatCompletionRequestMessage::ChatCompletionRequestDeveloperMessage(ChatCompletionRequestDeveloperMessage {
+///             content: ChatCompletionRequestDeveloperMessageContent::String("You are a helpful assistant.".to_string()),
+///             role: ChatCompletionRequestDeveloperMessageRole::Developer,
+///             name: None,
+///         }),
+///         ChatCompletionRequestMessage::ChatCompletionRequestUserMessage(ChatCompletionRequestUserMessage {
+///             content: ChatCompletionRequestUserMessageContent::String("Hello!".to_string()),
+///             role: ChatCompletionRequestUserMessageRole::User,
+///             name: None,
+///         }),
+///     ]),
+///     model: Some(serde_json::json!("VAR_chat_model_id")),
+///     ..Default::default()
+/// };
+/// ```
+///
+/// Image input:
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateChatCompletionRequest {
+///     messages: Some(vec![
+///         ChatCompletionRequestMessage::ChatCompletionRequestUserMessage(ChatCompletionRequestUserMessage {
+///             content: ChatCompletionRequestUserMessageContent::ChatCompletionRequestUserMessageContentChatCompletionRequestUserMessageContentPartArray(vec![
+///                 ChatCompletionRequestUserMessageContentPart::ChatCompletionRequestMessageContentPartText(ChatCompletionRequestMessageContentPartText {
+///                     r#type: ChatCompletionRequestMessageContentPartTextType::Text,
+///                     text: "What is in this image?".to_string(),
+///                 }),
+///                 ChatCompletionRequestUserMessageContentPart::ChatCompletionRequestMessageContentPartImage(ChatCompletionRequestMessageContentPartImage {
+///                     r#type: ChatCompletionRequestMessageContentPartImageType::ImageUrl,
+///                     image_url: ChatCompletionRequestMessageContentPartImageImageUrl {
+///                         url: "https://upload.wikimedia.org/wikipedia/commons/thumb/d/dd/Gfp-wisconsin-madison-the-nature-boardwalk.jpg/2560px-Gfp-wisconsin-madison-the-nature-boardwalk.jpg".to_string(),
+///                         detail: None,
+///                     },
+///                 }),
+///             ]),
+///             role: ChatCompletionRequestUserMessageRole::User,
+///             name: None,
+///         }),
+///     ]),
+///     model: Some(serde_json::json!("gpt-4o")),
+///     max_tokens: Some(300),
+///     ..Default::default()
+/// };
+/// ```
+///
+/// Streaming:
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateChatCompletionRequest {
+///     messages: Some(vec![
+///         ChatCompletionRequestMessage::ChatCompletionRequestDeveloperMessage(ChatCompletionRequestDeveloperMessage {
+///             content: ChatCompletionRequestDeveloperMessageContent::String("You are a helpful assistant.".to_string()),
+///             role: ChatCompletionRequestDeveloperMessageRole::Developer,
+///             name: None,
+///         }),
+///         ChatCompletionRequestMessage::ChatCompletionRequestUserMessage(ChatCompletionRequestUserMessage {
+///             content: ChatCompletionRequestUserMessageContent::String("Hello!".to_string()),
+///             role: ChatCompletionRequestUserMessageRole::User,
+///             name: None,
+///         }),
+///     ]),
+///     model: Some(serde_json::json!("VAR_chat_model_id")),
+///     stream: Some(true),
+///     ..Default::default()
+/// };
+/// ```
+///
+/// Functions:
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateChatCompletionRequest {
+///     messages: Some(vec![
+///         ChatCompletionRequestMessage::ChatCompletionRequestUserMessage(ChatCompletionRequestUserMessage {
+///             content: ChatCompletionRequestUserMessageContent::String("What is the weather like in Boston today?".to_string()),
+///             role: ChatCompletionRequestUserMessageRole::User,
+///             name: None,
+///         }),
+///     ]),
+///     model: Some(serde_json::json!("gpt-4o")),
+///     tools: Some(vec![
+///         ChatCompletionTool {
+///             r#type: ChatCompletionToolType::Function,
+///             function: FunctionObject {
+///                 description: Some("Get the current weather in a given location".to_string()),
+///                 name: "get_current_weather".to_string(),
+///                 parameters: Some(serde_json::json!({"type":"object","properties":{"location":{"type":"string","description":"The city and state, e.g. San Francisco, CA"},"unit":{"type":"string","enum":["celsius","fahrenheit"]}},"required":["location"]})),
+///                 strict: None,
+///             },
+///         },
+///     ]),
+///     tool_choice: Some(ChatCompletionToolChoiceOption::None("auto".to_string())),
+///     ..Default::default()
+/// };
+/// ```
+///
+/// Logprobs:
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateChatCompletionRequest {
+///     messages: Some(vec![
+///         ChatCompletionRequestMessage::ChatCompletionRequestUserMessage(ChatCompletionRequestUserMessage {
+///             content: ChatCompletionRequestUserMessageContent::String("Hello!".to_string()),
+///             role: ChatCompletionRequestUserMessageRole::User,
+///             name: None,
+///         }),
+///     ]),
+///     model: Some(serde_json::json!("VAR_chat_model_id")),
+///     top_logprobs: Some(2),
+///     logprobs: Some(true),
+///     ..Default::default()
+/// };
+/// ```
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct CreateChatCompletionRequest {
-	/// Parameters for audio output. Required when audio output is requested with
-	/// `modalities: ["audio"]`. [Learn more](https://platform.openai.com/docs/guides/audio).
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub audio: Option<CreateChatCompletionRequestAudio>,
-	/// Number between -2.0 and 2.0. Positive values penalize new tokens based on
-	/// their existing frequency in the text so far, decreasing the model's
-	/// likelihood to repeat the same line verbatim.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub frequency_penalty: Option<f64>,
-	/// Deprecated in favor of `tool_choice`.
-	/// 
-	/// Controls which (if any) function is called by the model.
-	/// 
-	/// `none` means the model will not call a function and instead generates a
-	/// message.
-	/// 
-	/// `auto` means the model can pick between generating a message or calling a
-	/// function.
-	/// 
-	/// Specifying a particular function via `{"name": "my_function"}` forces the
-	/// model to call that function.
-	/// 
-	/// `none` is the default when no functions are present. `auto` is the default
-	/// if functions are present.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub function_call: Option<CreateChatCompletionRequestFunctionCall>,
-	/// Deprecated in favor of `tools`.
-	/// 
-	/// A list of functions the model may generate JSON inputs for.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub functions: Option<Vec<ChatCompletionFunctions>>,
-	/// Modify the likelihood of specified tokens appearing in the completion.
-	/// 
-	/// Accepts a JSON object that maps tokens (specified by their token ID in the
-	/// tokenizer) to an associated bias value from -100 to 100. Mathematically,
-	/// the bias is added to the logits generated by the model prior to sampling.
-	/// The exact effect will vary per model, but values between -1 and 1 should
-	/// decrease or increase likelihood of selection; values like -100 or 100
-	/// should result in a ban or exclusive selection of the relevant token.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub logit_bias: Option<serde_json::Value>,
-	/// Whether to return log probabilities of the output tokens or not. If true,
-	/// returns the log probabilities of each output token returned in the
-	/// `content` of `message`.
+	pub metadata: Option<Metadata>,
+	/// What sampling temperature to use, between 0 and 2. Higher values like 0.8 will make the output more
+	/// random, while lower values like 0.2 will make it more focused and deterministic.
+	/// We generally recommend altering this or `top_p` but not both.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub logprobs: Option<bool>,
-	/// An upper bound for the number of tokens that can be generated for a completion, including visible output tokens and [reasoning tokens](https://platform.openai.com/docs/guides/reasoning).
+	pub temperature: Option<f64>,
+	/// An alternative to sampling with temperature, called nucleus sampling,
+	/// where the model considers the results of the tokens with top_p probability
+	/// mass. So 0.1 means only the tokens comprising the top 10% probability mass
+	/// are considered.
+	///
+	/// We generally recommend altering this or `temperature` but not both.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub max_completion_tokens: Option<i64>,
-	/// The maximum number of [tokens](/tokenizer) that can be generated in the
-	/// chat completion. This value can be used to control
-	/// [costs](https://openai.com/api/pricing/) for text generated via API.
-	/// 
-	/// This value is now deprecated in favor of `max_completion_tokens`, and is
-	/// not compatible with [o1 series models](https://platform.openai.com/docs/guides/reasoning).
+	pub top_p: Option<f64>,
+	/// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse.
+	/// [Learn more](https://platform.openai.com/docs/guides/safety-best-practices#end-user-ids).
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub max_tokens: Option<i64>,
+	pub user: Option<String>,
 	/// A list of messages comprising the conversation so far. Depending on the
 	/// [model](https://platform.openai.com/docs/models) you use, different message types (modalities) are
 	/// supported, like [text](https://platform.openai.com/docs/guides/text-generation),
-	/// [images](https://platform.openai.com/docs/guides/vision), and [audio](https://platform.openai.com/docs/guides/audio).
+	/// [images](https://platform.openai.com/docs/guides/vision), and
+	/// [audio](https://platform.openai.com/docs/guides/audio).
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub messages: Option<Vec<ChatCompletionRequestMessage>>,
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub metadata: Option<Metadata>,
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub modalities: Option<ResponseModalities>,
 	/// Model ID used to generate the response, like `gpt-4o` or `o1`. OpenAI
 	/// offers a wide range of models with different capabilities, performance
-	/// characteristics, and price points. Refer to the [model guide](https://platform.openai.com/docs/models)
+	/// characteristics, and price points. Refer to the [model
+	/// guide](https://platform.openai.com/docs/models)
 	/// to browse and compare available models.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub model: Option<ModelIdsShared>,
-	/// How many chat completion choices to generate for each input message. Note that you will be charged based on the number of generated tokens across all of the choices. Keep `n` as `1` to minimize costs.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub n: Option<i64>,
+	pub modalities: Option<ResponseModalities>,
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub parallel_tool_calls: Option<ParallelToolCalls>,
-	/// Configuration for a [Predicted Output](https://platform.openai.com/docs/guides/predicted-outputs),
-	/// which can greatly improve response times when large parts of the model
-	/// response are known ahead of time. This is most common when you are
-	/// regenerating a file with only minor changes to most of the content.
+	pub reasoning_effort: Option<ReasoningEffort>,
+	/// An upper bound for the number of tokens that can be generated for a completion, including visible
+	/// output tokens and [reasoning tokens](https://platform.openai.com/docs/guides/reasoning).
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub prediction: Option<CreateChatCompletionRequestPrediction>,
+	pub max_completion_tokens: Option<i64>,
+	/// Number between -2.0 and 2.0. Positive values penalize new tokens based on
+	/// their existing frequency in the text so far, decreasing the model's
+	/// likelihood to repeat the same line verbatim.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub frequency_penalty: Option<f64>,
 	/// Number between -2.0 and 2.0. Positive values penalize new tokens based on
 	/// whether they appear in the text so far, increasing the model's likelihood
 	/// to talk about new topics.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub presence_penalty: Option<f64>,
+	/// This tool searches the web for relevant results to use in a response.
+	/// Learn more about the [web search
+	/// tool](https://platform.openai.com/docs/guides/tools-web-search?api-mode=chat).
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub reasoning_effort: Option<ReasoningEffort>,
+	pub web_search_options: Option<CreateChatCompletionRequestWebSearchOptions>,
+	/// An integer between 0 and 20 specifying the number of most likely tokens to
+	/// return at each token position, each with an associated log probability.
+	/// `logprobs` must be set to `true` if this parameter is used.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub top_logprobs: Option<i64>,
 	/// An object specifying the format that the model must output.
-	/// 
+	///
 	/// Setting to `{ "type": "json_schema", "json_schema": {...} }` enables
 	/// Structured Outputs which ensures the model will match your supplied JSON
 	/// schema. Learn more in the [Structured Outputs
 	/// guide](https://platform.openai.com/docs/guides/structured-outputs).
-	/// 
+	///
 	/// Setting to `{ "type": "json_object" }` enables the older JSON mode, which
 	/// ensures the message the model generates is valid JSON. Using `json_schema`
 	/// is preferred for models that support it.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub response_format: Option<CreateChatCompletionRequestResponseFormat>,
-	/// This feature is in Beta.
-	/// If specified, our system will make a best effort to sample deterministically, such that repeated requests with the same `seed` and parameters should return the same result.
-	/// Determinism is not guaranteed, and you should refer to the `system_fingerprint` response parameter to monitor changes in the backend.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub seed: Option<i64>,
-	/// Specifies the latency tier to use for processing the request. This parameter is relevant for customers subscribed to the scale tier service:
+	/// Specifies the latency tier to use for processing the request. This parameter is relevant for
+	/// customers subscribed to the scale tier service:
 	///   - If set to 'auto', and the Project is Scale tier enabled, the system
 	///     will utilize scale tier credits until they are exhausted.
-	///   - If set to 'auto', and the Project is not Scale tier enabled, the request will be processed using the default service tier with a lower uptime SLA and no latency guarentee.
-	///   - If set to 'default', the request will be processed using the default service tier with a lower uptime SLA and no latency guarentee.
+	///   - If set to 'auto', and the Project is not Scale tier enabled, the request will be processed using
+	///     the default service tier with a lower uptime SLA and no latency guarentee.
+	///   - If set to 'default', the request will be processed using the default service tier with a lower
+	///     uptime SLA and no latency guarentee.
 	///   - When not set, the default behavior is 'auto'.
-	/// 
+	///
 	///   When this parameter is set, the response body will include the `service_tier` utilized.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub service_tier: Option<CreateChatCompletionRequestServiceTier>,
+	/// Parameters for audio output. Required when audio output is requested with
+	/// `modalities: ["audio"]`. [Learn more](https://platform.openai.com/docs/guides/audio).
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub stop: Option<StopConfiguration>,
-	/// Whether or not to store the output of this chat completion request for 
+	pub audio: Option<CreateChatCompletionRequestAudio>,
+	/// Whether or not to store the output of this chat completion request for
 	/// use in our [model distillation](https://platform.openai.com/docs/guides/distillation) or
 	/// [evals](https://platform.openai.com/docs/guides/evals) products.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub store: Option<bool>,
 	/// If set to true, the model response data will be streamed to the client
-	/// as it is generated using [server-sent events](https://developer.mozilla.org/en-US/docs/Web/API/Server-sent_events/Using_server-sent_events#Event_stream_format).
+	/// as it is generated using [server-sent
+	/// events](https://developer.mozilla.org/en-US/docs/Web/API/Server-sent_events/Using_server-sent_events#Event_stream_format).
 	/// See the [Streaming section below](https://platform.openai.com/docs/api-reference/chat/streaming)
-	/// for more information, along with the [streaming responses](https://platform.openai.com/docs/guides/streaming-responses)
+	/// for more information, along with the [streaming
+	/// responses](https://platform.openai.com/docs/guides/streaming-responses)
 	/// guide for more information on how to handle the streaming events.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub stream: Option<bool>,
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub stream_options: Option<ChatCompletionStreamOptions>,
-	/// What sampling temperature to use, between 0 and 2. Higher values like 0.8 will make the output more random, while lower values like 0.2 will make it more focused and deterministic.
-	/// We generally recommend altering this or `top_p` but not both.
+	pub stop: Option<StopConfiguration>,
+	/// Modify the likelihood of specified tokens appearing in the completion.
+	///
+	/// Accepts a JSON object that maps tokens (specified by their token ID in the
+	/// tokenizer) to an associated bias value from -100 to 100. Mathematically,
+	/// the bias is added to the logits generated by the model prior to sampling.
+	/// The exact effect will vary per model, but values between -1 and 1 should
+	/// decrease or increase likelihood of selection; values like -100 or 100
+	/// should result in a ban or exclusive selection of the relevant token.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub temperature: Option<f64>,
+	pub logit_bias: Option<serde_json::Value>,
+	/// Whether to return log probabilities of the output tokens or not. If true,
+	/// returns the log probabilities of each output token returned in the
+	/// `content` of `message`.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub tool_choice: Option<ChatCompletionToolChoiceOption>,
-	/// A list of tools the model may call. Currently, only functions are supported as a tool. Use this to provide a list of functions the model may generate JSON inputs for. A max of 128 functions are supported.
+	pub logprobs: Option<bool>,
+	/// The maximum number of [tokens](https://platform.openai.com/tokenizer) that can be generated in the
+	/// chat completion. This value can be used to control
+	/// [costs](https://openai.com/api/pricing/) for text generated via API.
+	///
+	/// This value is now deprecated in favor of `max_completion_tokens`, and is
+	/// not compatible with [o1 series models](https://platform.openai.com/docs/guides/reasoning).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_tokens: Option<i64>,
+	/// How many chat completion choices to generate for each input message. Note that you will be charged
+	/// based on the number of generated tokens across all of the choices. Keep `n` as `1` to minimize
+	/// costs.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub n: Option<i64>,
+	/// Configuration for a [Predicted Output](https://platform.openai.com/docs/guides/predicted-outputs),
+	/// which can greatly improve response times when large parts of the model
+	/// response are known ahead of time. This is most common when you are
+	/// regenerating a file with only minor changes to most of the content.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub prediction: Option<CreateChatCompletionRequestPrediction>,
+	/// This feature is in Beta.
+	/// If specified, our system will make a best effort to sample deterministically, such that repeated
+	/// requests with the same `seed` and parameters should return the same result.
+	/// Determinism is not guaranteed, and you should refer to the `system_fingerprint` response parameter
+	/// to monitor changes in the backend.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub seed: Option<i64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub stream_options: Option<ChatCompletionStreamOptions>,
+	/// A list of tools the model may call. Currently, only functions are supported as a tool. Use this to
+	/// provide a list of functions the model may generate JSON inputs for. A max of 128 functions are
+	/// supported.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub tools: Option<Vec<ChatCompletionTool>>,
-	/// An integer between 0 and 20 specifying the number of most likely tokens to
-	/// return at each token position, each with an associated log probability.
-	/// `logprobs` must be set to `true` if this parameter is used.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub top_logprobs: Option<i64>,
-	/// An alternative to sampling with temperature, called nucleus sampling,
-	/// where the model considers the results of the tokens with top_p probability
-	/// mass. So 0.1 means only the tokens comprising the top 10% probability mass
-	/// are considered.
-	/// 
-	/// We generally recommend altering this or `temperature` but not both.
+	pub tool_choice: Option<ChatCompletionToolChoiceOption>,
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub top_p: Option<f64>,
-	/// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse. [Learn more](https://platform.openai.com/docs/guides/safety-best-practices#end-user-ids).
+	pub parallel_tool_calls: Option<ParallelToolCalls>,
+	/// Deprecated in favor of `tool_choice`.
+	///
+	/// Controls which (if any) function is called by the model.
+	///
+	/// `none` means the model will not call a function and instead generates a
+	/// message.
+	///
+	/// `auto` means the model can pick between generating a message or calling a
+	/// function.
+	///
+	/// Specifying a particular function via `{"name": "my_function"}` forces the
+	/// model to call that function.
+	///
+	/// `none` is the default when no functions are present. `auto` is the default
+	/// if functions are present.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub user: Option<String>,
-	/// This tool searches the web for relevant results to use in a response.
-	/// Learn more about the [web search tool](https://platform.openai.com/docs/guides/tools-web-search?api-mode=chat).
+	pub function_call: Option<CreateChatCompletionRequestFunctionCall>,
+	/// Deprecated in favor of `tools`.
+	///
+	/// A list of functions the model may generate JSON inputs for.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub web_search_options: Option<CreateChatCompletionRequestWebSearchOptions>,
+	pub functions: Option<Vec<ChatCompletionFunctions>>,
 }
 /// Parameters for audio output. Required when audio output is requested with
 /// `modalities: ["audio"]`. [Learn more](https://platform.openai.com/docs/guides/audio).
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateChatCompletionRequestAudio {
+	/// The voice the model uses to respond. Supported voices are
+	/// `alloy`, `ash`, `ballad`, `coral`, `echo`, `sage`, and `shimmer`.
+	pub voice: VoiceIdsShared,
 	/// Specifies the output audio format. Must be one of `wav`, `mp3`, `flac`,
 	/// `opus`, or `pcm16`.
 	pub format: CreateChatCompletionRequestAudioFormat,
-	/// The voice the model uses to respond. Supported voices are 
-	/// `alloy`, `ash`, `ballad`, `coral`, `echo`, `sage`, and `shimmer`.
-	pub voice: VoiceIdsShared,
 }
 /// Specifies the output audio format. Must be one of `wav`, `mp3`, `flac`,
 /// `opus`, or `pcm16`.
@@ -602,18 +1046,18 @@ pub enum CreateChatCompletionRequestAudioFormat {
 	Pcm16,
 }
 /// Deprecated in favor of `tool_choice`.
-/// 
+///
 /// Controls which (if any) function is called by the model.
-/// 
+///
 /// `none` means the model will not call a function and instead generates a
 /// message.
-/// 
+///
 /// `auto` means the model can pick between generating a message or calling a
 /// function.
-/// 
+///
 /// Specifying a particular function via `{"name": "my_function"}` forces the
 /// model to call that function.
-/// 
+///
 /// `none` is the default when no functions are present. `auto` is the default
 /// if functions are present.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -635,12 +1079,12 @@ pub enum CreateChatCompletionRequestPrediction {
 	PredictionContent(PredictionContent),
 }
 /// An object specifying the format that the model must output.
-/// 
+///
 /// Setting to `{ "type": "json_schema", "json_schema": {...} }` enables
 /// Structured Outputs which ensures the model will match your supplied JSON
 /// schema. Learn more in the [Structured Outputs
 /// guide](https://platform.openai.com/docs/guides/structured-outputs).
-/// 
+///
 /// Setting to `{ "type": "json_object" }` enables the older JSON mode, which
 /// ensures the message the model generates is valid JSON. Using `json_schema`
 /// is preferred for models that support it.
@@ -652,13 +1096,16 @@ pub enum CreateChatCompletionRequestResponseFormat {
 	ResponseFormatJsonSchema(ResponseFormatJsonSchema),
 	ResponseFormatJsonObject(ResponseFormatJsonObject),
 }
-/// Specifies the latency tier to use for processing the request. This parameter is relevant for customers subscribed to the scale tier service:
+/// Specifies the latency tier to use for processing the request. This parameter is relevant for
+/// customers subscribed to the scale tier service:
 ///   - If set to 'auto', and the Project is Scale tier enabled, the system
 ///     will utilize scale tier credits until they are exhausted.
-///   - If set to 'auto', and the Project is not Scale tier enabled, the request will be processed using the default service tier with a lower uptime SLA and no latency guarentee.
-///   - If set to 'default', the request will be processed using the default service tier with a lower uptime SLA and no latency guarentee.
+///   - If set to 'auto', and the Project is not Scale tier enabled, the request will be processed using
+///     the default service tier with a lower uptime SLA and no latency guarentee.
+///   - If set to 'default', the request will be processed using the default service tier with a lower
+///     uptime SLA and no latency guarentee.
 ///   - When not set, the default behavior is 'auto'.
-/// 
+///
 ///   When this parameter is set, the response body will include the `service_tier` utilized.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -667,21 +1114,22 @@ pub enum CreateChatCompletionRequestServiceTier {
 	Default,
 }
 /// This tool searches the web for relevant results to use in a response.
-/// Learn more about the [web search tool](https://platform.openai.com/docs/guides/tools-web-search?api-mode=chat).
+/// Learn more about the [web search
+/// tool](https://platform.openai.com/docs/guides/tools-web-search?api-mode=chat).
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct CreateChatCompletionRequestWebSearchOptions {
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub search_context_size: Option<WebSearchContextSize>,
 	/// Approximate location parameters for the search.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub user_location: Option<CreateChatCompletionRequestWebSearchOptionsUserLocation>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub search_context_size: Option<WebSearchContextSize>,
 }
 /// Approximate location parameters for the search.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateChatCompletionRequestWebSearchOptionsUserLocation {
-	pub approximate: WebSearchLocation,
 	/// The type of location approximation. Always `approximate`.
 	pub r#type: CreateChatCompletionRequestWebSearchOptionsUserLocationType,
+	pub approximate: WebSearchLocation,
 }
 /// The type of location approximation. Always `approximate`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -689,37 +1137,190 @@ pub struct CreateChatCompletionRequestWebSearchOptionsUserLocation {
 pub enum CreateChatCompletionRequestWebSearchOptionsUserLocationType {
 	Approximate,
 }
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-pub struct ModelResponseProperties {
+/// Represents a chat completion response returned by model, based on the provided input.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CreateChatCompletionResponse {
+	/// A unique identifier for the chat completion.
+	pub id: String,
+	/// A list of chat completion choices. Can be more than one if `n` is greater than 1.
+	pub choices: Vec<CreateChatCompletionResponseChoicesItem>,
+	/// The Unix timestamp (in seconds) of when the chat completion was created.
+	pub created: i64,
+	/// The model used for the chat completion.
+	pub model: String,
+	/// The service tier used for processing the request.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub metadata: Option<Metadata>,
-	/// What sampling temperature to use, between 0 and 2. Higher values like 0.8 will make the output more random, while lower values like 0.2 will make it more focused and deterministic.
-	/// We generally recommend altering this or `top_p` but not both.
+	pub service_tier: Option<CreateChatCompletionResponseServiceTier>,
+	/// This fingerprint represents the backend configuration that the model runs with.
+	///
+	/// Can be used in conjunction with the `seed` request parameter to understand when backend changes have
+	/// been made that might impact determinism.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub temperature: Option<f64>,
-	/// An alternative to sampling with temperature, called nucleus sampling,
-	/// where the model considers the results of the tokens with top_p probability
-	/// mass. So 0.1 means only the tokens comprising the top 10% probability mass
-	/// are considered.
-	/// 
-	/// We generally recommend altering this or `temperature` but not both.
+	pub system_fingerprint: Option<String>,
+	/// The object type, which is always `chat.completion`.
+	pub object: CreateChatCompletionResponseObject,
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub top_p: Option<f64>,
-	/// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse. [Learn more](https://platform.openai.com/docs/guides/safety-best-practices#end-user-ids).
+	pub usage: Option<CompletionUsage>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CreateChatCompletionResponseChoicesItem {
+	/// The reason the model stopped generating tokens. This will be `stop` if the model hit a natural stop
+	/// point or a provided stop sequence,
+	/// `length` if the maximum number of tokens specified in the request was reached,
+	/// `content_filter` if content was omitted due to a flag from our content filters,
+	/// `tool_calls` if the model called a tool, or `function_call` (deprecated) if the model called a
+	/// function.
+	pub finish_reason: CreateChatCompletionResponseChoicesItemFinishReason,
+	/// The index of the choice in the list of choices.
+	pub index: i64,
+	pub message: ChatCompletionResponseMessage,
+	/// Log probability information for the choice.
+	pub logprobs: Option<CreateChatCompletionResponseChoicesItemLogprobs>,
+}
+/// The reason the model stopped generating tokens. This will be `stop` if the model hit a natural stop
+/// point or a provided stop sequence,
+/// `length` if the maximum number of tokens specified in the request was reached,
+/// `content_filter` if content was omitted due to a flag from our content filters,
+/// `tool_calls` if the model called a tool, or `function_call` (deprecated) if the model called a
+/// function.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CreateChatCompletionResponseChoicesItemFinishReason {
+	Stop,
+	Length,
+	#[serde(rename = "tool_calls")]
+	ToolCalls,
+	#[serde(rename = "content_filter")]
+	ContentFilter,
+	#[serde(rename = "function_call")]
+	FunctionCall,
+}
+/// Log probability information for the choice.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CreateChatCompletionResponseChoicesItemLogprobs {
+	/// A list of message content tokens with log probability information.
+	pub content: Option<Vec<ChatCompletionTokenLogprob>>,
+	/// A list of message refusal tokens with log probability information.
+	pub refusal: Option<Vec<ChatCompletionTokenLogprob>>,
+}
+/// The object type, which is always `chat.completion`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CreateChatCompletionResponseObject {
+	#[serde(rename = "chat.completion")]
+	ChatCompletion,
+}
+/// The service tier used for processing the request.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CreateChatCompletionResponseServiceTier {
+	Scale,
+	Default,
+}
+/// Represents a streamed chunk of a chat completion response returned
+/// by the model, based on the provided input.
+/// [Learn more](https://platform.openai.com/docs/guides/streaming-responses).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CreateChatCompletionStreamResponse {
+	/// A unique identifier for the chat completion. Each chunk has the same ID.
+	pub id: String,
+	/// A list of chat completion choices. Can contain more than one elements if `n` is greater than 1. Can
+	/// also be empty for the
+	/// last chunk if you set `stream_options: {"include_usage": true}`.
+	pub choices: Vec<CreateChatCompletionStreamResponseChoicesItem>,
+	/// The Unix timestamp (in seconds) of when the chat completion was created. Each chunk has the same
+	/// timestamp.
+	pub created: i64,
+	/// The model to generate the completion.
+	pub model: String,
+	/// The service tier used for processing the request.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub user: Option<String>,
+	pub service_tier: Option<CreateChatCompletionStreamResponseServiceTier>,
+	/// This fingerprint represents the backend configuration that the model runs with.
+	/// Can be used in conjunction with the `seed` request parameter to understand when backend changes have
+	/// been made that might impact determinism.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub system_fingerprint: Option<String>,
+	/// The object type, which is always `chat.completion.chunk`.
+	pub object: CreateChatCompletionStreamResponseObject,
+	/// An optional field that will only be present when you set
+	/// `stream_options: {"include_usage": true}` in your request. When present, it
+	/// contains a null value **except for the last chunk** which contains the
+	/// token usage statistics for the entire request.
+	///
+	/// **NOTE:** If the stream is interrupted or cancelled, you may not
+	/// receive the final usage chunk which contains the total token usage for
+	/// the request.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub usage: Option<CompletionUsage>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CreateChatCompletionStreamResponseChoicesItem {
+	pub delta: ChatCompletionStreamResponseDelta,
+	/// Log probability information for the choice.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub logprobs: Option<CreateChatCompletionStreamResponseChoicesItemLogprobs>,
+	/// The reason the model stopped generating tokens. This will be `stop` if the model hit a natural stop
+	/// point or a provided stop sequence,
+	/// `length` if the maximum number of tokens specified in the request was reached,
+	/// `content_filter` if content was omitted due to a flag from our content filters,
+	/// `tool_calls` if the model called a tool, or `function_call` (deprecated) if the model called a
+	/// function.
+	pub finish_reason: Option<CreateChatCompletionStreamResponseChoicesItemFinishReason>,
+	/// The index of the choice in the list of choices.
+	pub index: i64,
+}
+/// The reason the model stopped generating tokens. This will be `stop` if the model hit a natural stop
+/// point or a provided stop sequence,
+/// `length` if the maximum number of tokens specified in the request was reached,
+/// `content_filter` if content was omitted due to a flag from our content filters,
+/// `tool_calls` if the model called a tool, or `function_call` (deprecated) if the model called a
+/// function.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CreateChatCompletionStreamResponseChoicesItemFinishReason {
+	Stop,
+	Length,
+	#[serde(rename = "tool_calls")]
+	ToolCalls,
+	#[serde(rename = "content_filter")]
+	ContentFilter,
+	#[serde(rename = "function_call")]
+	FunctionCall,
+}
+/// Log probability information for the choice.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CreateChatCompletionStreamResponseChoicesItemLogprobs {
+	/// A list of message content tokens with log probability information.
+	pub content: Option<Vec<ChatCompletionTokenLogprob>>,
+	/// A list of message refusal tokens with log probability information.
+	pub refusal: Option<Vec<ChatCompletionTokenLogprob>>,
+}
+/// The object type, which is always `chat.completion.chunk`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CreateChatCompletionStreamResponseObject {
+	#[serde(rename = "chat.completion.chunk")]
+	ChatCompletionChunk,
+}
+/// The service tier used for processing the request.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CreateChatCompletionStreamResponseServiceTier {
+	Scale,
+	Default,
 }
 /// Static predicted output content, such as the content of a text file that is
 /// being regenerated.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct PredictionContent {
+	/// The type of the predicted content you want to provide. This type is
+	/// currently always `content`.
+	pub r#type: PredictionContentType,
 	/// The content that should be matched when generating a model response.
 	/// If generated tokens would match this content, the entire model response
 	/// can be returned much more quickly.
 	pub content: PredictionContentContent,
-	/// The type of the predicted content you want to provide. This type is
-	/// currently always `content`.
-	pub r#type: PredictionContentType,
 }
 /// The content that should be matched when generating a model response.
 /// If generated tokens would match this content, the entire model response
@@ -0,0 +1,3703 @@
+//! An async client for the OpenAI API, with one method per operation.
+//!
+//! Requests go through a [`Transport`], which is [`ReqwestTransport`]
+//!  unless another is given with [`Client::with_transport`]. Tests can
+//!  use a [`CassetteTransport`](super::cassette::CassetteTransport) to
+//!  record real traffic once and replay it offline.
+//!
+//! Cursor-paginated lists also get an `_all` method, which returns a
+//!  [`Paginated`] stream of every item, fetching pages as they're needed.
+//!
+//! Failed calls are retried by a [`RetryPolicy`], and every response
+//!  carries the [`RateLimits`] the API reported with it.
+//!
+//! Requests go to OpenAI unless the client is given another
+//!  [`Provider`], such as Azure OpenAI or an OpenAI-compatible server.
+//!
+//! Beta operations, such as the Assistants API's, send the `OpenAI-Beta`
+//!  header their examples do, unless [`Client::with_beta`] pins another.
+//!
+//! Binary responses, such as file contents and speech, are returned as
+//!  a [`Download`], which streams the body rather than reading it all.
+//!
+//! Multipart uploads don't have methods yet.
+//!
+//! Needs the `reqwest` (with the `stream` feature), `futures-util`,
+//!  `tokio` and `serde_path_to_error` crates.
+use super::download::Download;
+use super::provider::Provider;
+use super::retry::{is_retryable_status, RateLimits, RetryPolicy};
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Instant;
+
+use futures_util::{Stream, StreamExt};
+
+/// The OpenAI API's base URL
+pub const DEFAULT_BASE_URL: &str = "https://api.openai.com/v1";
+
+/// A boxed future, as returned by a [`Transport`]
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+/// A response body, as it arrives
+pub type ByteStream = Pin<Box<dyn Stream<Item = Result<Vec<u8>, ClientError>> + Send>>;
+
+/// An error from the client
+#[derive(Debug)]
+pub enum ClientError {
+    /// The request couldn't be sent, or the response couldn't be read
+    Transport(String),
+    /// The request body couldn't be encoded
+    Encode(serde_json::Error),
+    /// A response body wasn't what the spec says it should be
+    Decode(DecodeError),
+    /// The API answered with an error
+    Api(Box<ApiError>),
+    /// A cassette couldn't be read or written, or had no matching interaction
+    Cassette(String),
+    /// The client's provider doesn't support the operation, so the
+    ///  request wasn't sent
+    Unsupported {
+        /// The operation's `operationId`
+        operation_id: String,
+        /// The provider's name
+        provider: String,
+    },
+    /// A file couldn't be read or written
+    Io(std::io::Error),
+}
+impl ClientError {
+    /// The HTTP status the API answered with, if it answered
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            ClientError::Api(error) => Some(error.status),
+            _ => None,
+        }
+    }
+    /// The ID the API gave the failed request, for support requests
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            ClientError::Api(error) => error.request_id.as_deref(),
+            _ => None,
+        }
+    }
+    /// The error the API described, if it answered with one
+    pub fn api_error(&self) -> Option<&ErrorBody> {
+        match self {
+            ClientError::Api(error) => error.error.as_ref(),
+            _ => None,
+        }
+    }
+    /// Whether the request was rate limited, and can be sent again
+    ///  later. Running out of quota isn't a rate limit
+    pub fn is_rate_limit(&self) -> bool {
+        self.status() == Some(429) && !self.is_insufficient_quota()
+    }
+    /// Whether the account has run out of quota
+    pub fn is_insufficient_quota(&self) -> bool {
+        self.api_error().is_some_and(|error| {
+            error.r#type == "insufficient_quota" || error.code.as_deref() == Some("insufficient_quota")
+        })
+    }
+    /// Whether the input was too long for the model's context window
+    pub fn is_context_length_exceeded(&self) -> bool {
+        self.api_error().is_some_and(|error| error.code.as_deref() == Some("context_length_exceeded"))
+    }
+    /// Whether sending the same request again could succeed: when it
+    ///  couldn't be sent, was rate limited or met a server error
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ClientError::Transport(_) => true,
+            ClientError::Api(error) => is_retryable_status(error.status) && !self.is_insufficient_quota(),
+            _ => false,
+        }
+    }
+}
+impl std::fmt::Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ClientError::Transport(error) => write!(f, "Transport error: {}", error),
+            ClientError::Encode(error) => write!(f, "Failed to encode the request body: {}", error),
+            ClientError::Decode(error) => write!(f, "{}", error),
+            ClientError::Api(error) => write!(f, "{}", error),
+            ClientError::Cassette(error) => write!(f, "Cassette error: {}", error),
+            ClientError::Unsupported { operation_id, provider } => write!(f, "`{}` is unsupported on this provider ({})", operation_id, provider),
+            ClientError::Io(error) => write!(f, "IO error: {}", error),
+        }
+    }
+}
+impl std::error::Error for ClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ClientError::Encode(error) => Some(error),
+            ClientError::Decode(error) => Some(&error.error),
+            ClientError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+impl From<serde_json::Error> for ClientError {
+    fn from(error: serde_json::Error) -> Self {
+        ClientError::Encode(error)
+    }
+}
+impl From<std::io::Error> for ClientError {
+    fn from(error: std::io::Error) -> Self {
+        ClientError::Io(error)
+    }
+}
+
+/// A response body which couldn't be decoded
+#[derive(Debug)]
+pub struct DecodeError {
+    /// Where in the body decoding failed, such as `choices[0].finish_reason`
+    pub path: String,
+    /// Why decoding failed
+    pub error: serde_json::Error,
+    /// The body, as it was received
+    pub body: String,
+}
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.path.is_empty() || self.path == "." {
+            write!(f, "Failed to decode the response: {}", self.error)
+        } else {
+            write!(f, "Failed to decode the response at `{}`: {}", self.path, self.error)
+        }
+    }
+}
+
+/// An error the API answered with
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiError {
+    /// The HTTP status
+    pub status: u16,
+    /// The ID the API gave the request, from `x-request-id`
+    pub request_id: Option<String>,
+    /// The error, when the body described one
+    pub error: Option<ErrorBody>,
+    /// The body, as it was received
+    pub body: String,
+}
+impl ApiError {
+    /// Reads an error response
+    pub fn new(status: u16, headers: &[(String, String)], body: &[u8]) -> Self {
+        ApiError {
+            status,
+            request_id: header(headers, "x-request-id").map(|request_id| request_id.to_string()),
+            error: parse_error_response(body),
+            body: String::from_utf8_lossy(body).into_owned(),
+        }
+    }
+}
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.error {
+            Some(ref error) => write!(f, "The API answered {} ({}): {}", self.status, error.r#type, error.message)?,
+            None => write!(f, "The API answered {}: {}", self.status, self.body)?,
+        }
+        if let Some(ref request_id) = self.request_id {
+            write!(f, " (request {})", request_id)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A request, as handed to a [`Transport`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpRequest {
+    /// The HTTP method, in upper case
+    pub method: String,
+    /// The full URL, including the query
+    pub url: String,
+    /// The headers, in the order they're sent
+    pub headers: Vec<(String, String)>,
+    /// The body, if there is one
+    pub body: Option<Vec<u8>>,
+}
+
+/// A response, as returned by a [`Transport`]
+pub struct HttpResponse {
+    /// The HTTP status
+    pub status: u16,
+    /// The headers, with lower case names
+    pub headers: Vec<(String, String)>,
+    /// The body, which is read as it arrives
+    pub body: ByteStream,
+}
+impl HttpResponse {
+    /// The value of the first header with the given name, ignoring case
+    pub fn header(&self, name: &str) -> Option<&str> {
+        header(&self.headers, name)
+    }
+    /// The rate limits the API reported
+    pub fn rate_limits(&self) -> RateLimits {
+        RateLimits::from_headers(&self.headers)
+    }
+    /// Reads the whole body
+    pub async fn bytes(mut self) -> Result<Vec<u8>, ClientError> {
+        let mut bytes = Vec::new();
+        while let Some(chunk) = self.body.next().await {
+            bytes.extend(chunk?);
+        }
+
+        Ok(bytes)
+    }
+}
+impl std::fmt::Debug for HttpResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("HttpResponse")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A successful response read as `T`, which it dereferences to, along
+///  with what the API said about it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiResponse<T> {
+    /// The body. Named so it doesn't hide a `data` field of `T`
+    pub body: T,
+    /// The HTTP status
+    pub status: u16,
+    /// The headers, with lower case names
+    pub headers: Vec<(String, String)>,
+    /// The rate limits the API reported
+    pub rate_limits: RateLimits,
+}
+impl<T> ApiResponse<T> {
+    /// The body, without the rest of the response
+    pub fn into_inner(self) -> T {
+        self.body
+    }
+    /// The value of the first header with the given name, ignoring case
+    pub fn header(&self, name: &str) -> Option<&str> {
+        header(&self.headers, name)
+    }
+    /// The ID the API gave the request, for support requests
+    pub fn request_id(&self) -> Option<&str> {
+        self.header("x-request-id")
+    }
+}
+impl<T> Deref for ApiResponse<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.body
+    }
+}
+
+/// Sends requests for a [`Client`]
+pub trait Transport: Send + Sync {
+    /// Sends a request, returning as soon as the response's headers arrive
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, ClientError>>;
+}
+
+/// Sends requests over the network with `reqwest`
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+impl ReqwestTransport {
+    /// Sends requests with a configured `reqwest` client
+    pub fn new(client: reqwest::Client) -> Self {
+        ReqwestTransport { client }
+    }
+}
+impl Transport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, ClientError>> {
+        Box::pin(async move {
+            let method = reqwest::Method::from_bytes(request.method.as_bytes())
+                .map_err(|error| ClientError::Transport(error.to_string()))?;
+
+            let mut builder = self.client.request(method, &request.url);
+            for (name, value) in request.headers {
+                builder = builder.header(name, value);
+            }
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            }
+
+            let response = builder.send()
+                .await
+                .map_err(|error| ClientError::Transport(error.to_string()))?;
+            let status = response.status().as_u16();
+            let headers = response.headers()
+                .iter()
+                .filter_map(|(name, value)| Some((name.as_str().to_string(), value.to_str().ok()?.to_string())))
+                .collect();
+            let body = response.bytes_stream()
+                .map(|chunk| chunk
+                    .map(|chunk| chunk.to_vec())
+                    .map_err(|error| ClientError::Transport(error.to_string())));
+
+            Ok(HttpResponse { status, headers, body: Box::pin(body) })
+        })
+    }
+}
+
+/// A client for the OpenAI API
+#[derive(Clone)]
+pub struct Client {
+    provider: Provider,
+    api_key: Option<String>,
+    headers: Vec<(String, String)>,
+    beta: Vec<String>,
+    retry: RetryPolicy,
+    transport: Arc<dyn Transport>,
+}
+impl Client {
+    /// A client for the OpenAI API with the given key
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Client {
+            provider: Provider::openai(),
+            api_key: Some(api_key.into()),
+            headers: Vec::new(),
+            beta: Vec::new(),
+            retry: RetryPolicy::default(),
+            transport: Arc::new(ReqwestTransport::default()),
+        }
+    }
+    /// A client configured by `OPENAI_API_KEY` and, if it's set,
+    ///  `OPENAI_BASE_URL`
+    pub fn from_env() -> Self {
+        let mut client = Client::new(String::new());
+        client.api_key = std::env::var("OPENAI_API_KEY").ok();
+        if let Ok(base_url) = std::env::var("OPENAI_BASE_URL") {
+            client = client.with_base_url(base_url);
+        }
+
+        client
+    }
+    /// Sends requests to another base URL, such as a mock server's
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.provider = self.provider.with_base_url(base_url);
+        self
+    }
+    /// Sends requests to another provider, such as Azure OpenAI
+    pub fn with_provider(mut self, provider: Provider) -> Self {
+        self.provider = provider;
+        self
+    }
+    /// Sends requests through another transport
+    pub fn with_transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Arc::new(transport);
+        self
+    }
+    /// Sends an extra header with every request
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+    /// Sends another `OpenAI-Beta` value, such as `assistants=v1`, for the
+    ///  beta operations whose own value names the same feature
+    pub fn with_beta(mut self, beta: impl Into<String>) -> Self {
+        let beta = beta.into();
+        self.beta.retain(|pinned| beta_feature(pinned) != beta_feature(&beta));
+        self.beta.push(beta);
+        self
+    }
+    /// Retries failed calls by another policy
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+    /// The base URL requests are sent to
+    pub fn base_url(&self) -> &str {
+        &self.provider.base_url
+    }
+    /// The provider requests are sent to
+    pub fn provider(&self) -> &Provider {
+        &self.provider
+    }
+    /// The `OpenAI-Beta` header sent with an operation, if it's beta
+    pub fn beta_header(&self, operation_id: &str) -> Option<&str> {
+        let (_operation_id, beta) = BETA_HEADERS.iter()
+            .find(|(beta_operation_id, _beta)| *beta_operation_id == operation_id)?;
+
+        self.beta.iter()
+            .find(|pinned| beta_feature(pinned) == beta_feature(beta))
+            .map(|pinned| pinned.as_str())
+            .or(Some(*beta))
+    }
+
+    /// Sends a request for an operation to a path under the base URL,
+    ///  retrying it by the client's [`RetryPolicy`], and failing with
+    ///  [`ClientError::Api`] unless a response is successful, or with
+    ///  [`ClientError::Unsupported`] if the provider doesn't support the
+    ///  operation
+    pub async fn send(
+        &self,
+        operation_id: &str,
+        method: &str,
+        path: &str,
+        query: &[(&str, String)],
+        body: Option<Vec<u8>>,
+    ) -> Result<HttpResponse, ClientError> {
+        self.provider.check(operation_id)?;
+
+        let mut url = self.provider.url(path);
+        let provider_query = self.provider.query.iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()));
+        let query = query.iter()
+            .map(|(name, value)| (*name, value.as_str()));
+        for (index, (name, value)) in provider_query.chain(query).enumerate() {
+            url.push(if index == 0 { '?' } else { '&' });
+            url.push_str(&format!("{}={}", encode(name), encode(value)));
+        }
+
+        let mut headers = Vec::new();
+        if let Some(header) = self.api_key.as_ref().and_then(|api_key| self.provider.auth_header(api_key)) {
+            headers.push(header);
+        }
+        if body.is_some() {
+            headers.push(("content-type".to_string(), "application/json".to_string()));
+        }
+        // An `OpenAI-Beta` header given to the client replaces the operation's
+        if let Some(beta) = self.beta_header(operation_id) {
+            if header(&self.headers, "openai-beta").is_none() {
+                headers.push(("openai-beta".to_string(), beta.to_string()));
+            }
+        }
+        headers.extend(self.headers.iter().cloned());
+
+        let request = HttpRequest { method: method.to_string(), url, headers, body };
+        let retryable = self.retry.allows(operation_id, method);
+        let started = Instant::now();
+        let mut retry = 0;
+        loop {
+            let (error, delay) = match self.transport.send(request.clone()).await {
+                Ok(response) if (200..300).contains(&response.status) => return Ok(response),
+                Ok(response) => {
+                    let status = response.status;
+                    let headers = response.headers.clone();
+                    let body = response.bytes().await?;
+                    let error = ClientError::Api(Box::new(ApiError::new(status, &headers, &body)));
+                    let delay = self.retry.should_retry(&error, &headers)
+                        .then(|| self.retry.delay(retry, &headers));
+
+                    (error, delay)
+                },
+                Err(error @ ClientError::Transport(_)) => (error, Some(self.retry.delay(retry, &[]))),
+                Err(error) => return Err(error),
+            };
+
+            // Give up when out of retries, or when the wait would take the
+            //  call past its budget
+            let delay = match delay {
+                Some(delay) if retryable
+                    && retry < self.retry.max_retries
+                    && started.elapsed() + delay <= self.retry.budget => delay,
+                _ => return Err(error),
+            };
+            tokio::time::sleep(delay).await;
+            retry += 1;
+        }
+    }
+    /// Sends a request and reads the response as JSON, reading an empty
+    ///  body as `null`
+    async fn json<T: serde::de::DeserializeOwned>(
+        &self,
+        operation_id: &str,
+        method: &str,
+        path: &str,
+        query: &[(&str, String)],
+        body: Option<Vec<u8>>,
+    ) -> Result<ApiResponse<T>, ClientError> {
+        let response = self.raw(operation_id, method, path, query, body).await?;
+        let body = if response.body.iter().all(|byte| byte.is_ascii_whitespace()) {
+            decode(b"null")?
+        } else {
+            decode(&response.body)?
+        };
+
+        Ok(ApiResponse { body, status: response.status, headers: response.headers, rate_limits: response.rate_limits })
+    }
+    /// Sends a request and reads the whole response body
+    async fn raw(
+        &self,
+        operation_id: &str,
+        method: &str,
+        path: &str,
+        query: &[(&str, String)],
+        body: Option<Vec<u8>>,
+    ) -> Result<ApiResponse<Vec<u8>>, ClientError> {
+        let response = self.send(operation_id, method, path, query, body).await?;
+        let status = response.status;
+        let headers = response.headers.clone();
+        let rate_limits = response.rate_limits();
+
+        Ok(ApiResponse { body: response.bytes().await?, status, headers, rate_limits })
+    }
+    /// Sends a request and streams the binary response body
+    async fn download(
+        &self,
+        operation_id: &str,
+        method: &str,
+        path: &str,
+        query: &[(&str, String)],
+        body: Option<Vec<u8>>,
+    ) -> Result<ApiResponse<Download>, ClientError> {
+        let response = self.send(operation_id, method, path, query, body).await?;
+        let status = response.status;
+        let headers = response.headers.clone();
+        let rate_limits = response.rate_limits();
+
+        Ok(ApiResponse { body: Download::new(response), status, headers, rate_limits })
+    }
+    /// Sends a request and reads the response as server-sent events
+    // Unused when no enabled group has an operation which streams
+    #[allow(dead_code)]
+    async fn events<T: serde::de::DeserializeOwned>(
+        &self,
+        operation_id: &str,
+        method: &str,
+        path: &str,
+        query: &[(&str, String)],
+        body: Option<Vec<u8>>,
+    ) -> Result<EventStream<T>, ClientError> {
+        let response = self.send(operation_id, method, path, query, body).await?;
+
+        Ok(EventStream::new(response))
+    }
+}
+impl std::fmt::Debug for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Client")
+            .field("provider", &self.provider)
+            .field("api_key", &self.api_key.as_ref().map(|_| "[REDACTED]"))
+            .field("headers", &self.headers)
+            .field("beta", &self.beta)
+            .field("retry", &self.retry)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The `data:` of each server-sent event in a response, read as `T`
+///  until the stream ends or sends `[DONE]`
+pub struct EventStream<T> {
+    status: u16,
+    headers: Vec<(String, String)>,
+    rate_limits: RateLimits,
+    body: ByteStream,
+    buffer: Vec<u8>,
+    pending: VecDeque<Result<T, ClientError>>,
+    done: bool,
+    _event: PhantomData<fn() -> T>,
+}
+impl<T: serde::de::DeserializeOwned> EventStream<T> {
+    /// Reads the events from a response's body
+    pub fn new(response: HttpResponse) -> Self {
+        EventStream {
+            status: response.status,
+            rate_limits: response.rate_limits(),
+            headers: response.headers,
+            body: response.body,
+            buffer: Vec::new(),
+            pending: VecDeque::new(),
+            done: false,
+            _event: PhantomData,
+        }
+    }
+    /// The rate limits the API reported when the stream started
+    pub fn rate_limits(&self) -> &RateLimits {
+        &self.rate_limits
+    }
+    /// Reads every complete event in the buffer, or all of it once the
+    ///  body has ended
+    fn read_events(&mut self, ended: bool) {
+        loop {
+            let end = self.buffer.windows(2).position(|window| window == b"\n\n");
+            let event = match end {
+                Some(end) => self.buffer.drain(..end + 2).collect::<Vec<u8>>(),
+                None if ended && !self.buffer.is_empty() => std::mem::take(&mut self.buffer),
+                None => return,
+            };
+
+            let event = String::from_utf8_lossy(&event);
+            let data = event.lines()
+                .filter_map(|line| line.strip_prefix("data:"))
+                .map(|data| data.strip_prefix(' ').unwrap_or(data))
+                .collect::<Vec<&str>>()
+                .join("\n");
+            if data.is_empty() {
+                continue;
+            }
+            if data == "[DONE]" {
+                self.done = true;
+                return;
+            }
+
+            self.pending.push_back(self.read_event(&event, &data));
+        }
+    }
+    /// Reads an event's data, or the error it sends instead, either as an
+    ///  `error` event or as an error response
+    fn read_event(&self, event: &str, data: &str) -> Result<T, ClientError> {
+        let is_error_event = event.lines()
+            .any(|line| line.strip_prefix("event:").is_some_and(|name| name.trim() == "error"));
+        if is_error_event {
+            let mut error = ApiError::new(self.status, &self.headers, data.as_bytes());
+            error.error = parse_error_object(data).or(error.error);
+            return Err(ClientError::Api(Box::new(error)));
+        }
+
+        decode(data.as_bytes()).map_err(|error| {
+            if parse_error_response(data.as_bytes()).is_some() {
+                ClientError::Api(Box::new(ApiError::new(self.status, &self.headers, data.as_bytes())))
+            } else {
+                error
+            }
+        })
+    }
+}
+// Nothing in the stream is pinned, whatever the event type
+impl<T> Unpin for EventStream<T> {}
+impl<T: serde::de::DeserializeOwned> Stream for EventStream<T> {
+    type Item = Result<T, ClientError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Poll::Ready(Some(event));
+            }
+            if self.done {
+                return Poll::Ready(None);
+            }
+
+            match self.body.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(chunk))) => {
+                    // Line endings are normalized so events split on `\n\n`
+                    self.buffer.extend(chunk.into_iter().filter(|byte| *byte != b'\r'));
+                    self.read_events(false);
+                },
+                Poll::Ready(Some(Err(error))) => {
+                    self.done = true;
+                    return Poll::Ready(Some(Err(error)));
+                },
+                Poll::Ready(None) => {
+                    self.read_events(true);
+                    self.done = true;
+                },
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Every item of a cursor-paginated list, fetched a page at a time as
+///  the stream is polled.
+///
+/// Each page is requested with `after` set to the previous page's
+///  `last_id`, or the `id` of its last item, until one says it doesn't
+///  have more.
+pub struct Paginated<T> {
+    client: Client,
+    operation_id: &'static str,
+    path: String,
+    query: Vec<(&'static str, String)>,
+    page_size: Option<i64>,
+    max_items: Option<usize>,
+    after: Option<String>,
+    items: VecDeque<Result<T, ClientError>>,
+    yielded: usize,
+    done: bool,
+    page: Option<BoxFuture<'static, Result<serde_json::Value, ClientError>>>,
+}
+impl<T: serde::de::DeserializeOwned> Paginated<T> {
+    /// Pages through an operation's list at a path, with the list's own
+    ///  query parameters. Any `after` cursor in them is where paging starts
+    pub fn new(
+        client: Client,
+        operation_id: &'static str,
+        path: String,
+        query: Vec<(&'static str, String)>,
+    ) -> Self {
+        let after = query.iter()
+            .find(|(name, _value)| *name == "after")
+            .map(|(_name, value)| value.clone());
+        let query = query.into_iter()
+            .filter(|(name, _value)| *name != "after")
+            .collect();
+
+        Paginated {
+            client,
+            operation_id,
+            path,
+            query,
+            page_size: None,
+            max_items: None,
+            after,
+            items: VecDeque::new(),
+            yielded: 0,
+            done: false,
+            page: None,
+        }
+    }
+    /// Asks for pages of this many items, instead of the query's `limit`
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+    /// Stops after this many items, without fetching pages past them
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    fn fetch_page(&self) -> BoxFuture<'static, Result<serde_json::Value, ClientError>> {
+        let mut query = self.query.clone();
+        if let Some(page_size) = self.page_size {
+            query.retain(|(name, _value)| *name != "limit");
+            query.push(("limit", page_size.to_string()));
+        }
+        if let Some(ref after) = self.after {
+            query.push(("after", after.clone()));
+        }
+
+        let client = self.client.clone();
+        let operation_id = self.operation_id;
+        let path = self.path.clone();
+        Box::pin(async move {
+            client.json(operation_id, "GET", &path, &query, None)
+                .await
+                .map(ApiResponse::into_inner)
+        })
+    }
+    /// Queues a page's items, and decides where the next page starts
+    fn read_page(&mut self, page: serde_json::Value) {
+        let items = match page.get("data") {
+            Some(serde_json::Value::Array(items)) => items.clone(),
+            _ => Vec::new(),
+        };
+        let cursor = page.get("last_id")
+            .and_then(|last_id| last_id.as_str())
+            .or_else(|| items.last().and_then(|item| item.get("id")).and_then(|id| id.as_str()))
+            .map(|cursor| cursor.to_string());
+        let has_more = page.get("has_more")
+            .and_then(|has_more| has_more.as_bool())
+            .unwrap_or(false);
+
+        // A page which doesn't move the cursor would be fetched forever
+        if !has_more || items.is_empty() || cursor.is_none() || cursor == self.after {
+            self.done = true;
+        }
+        self.after = cursor;
+
+        self.items.extend(items.into_iter().map(|item| decode(item.to_string().as_bytes())));
+    }
+}
+// Nothing in the stream is pinned, whatever the item type
+impl<T> Unpin for Paginated<T> {}
+impl<T: serde::de::DeserializeOwned> Stream for Paginated<T> {
+    type Item = Result<T, ClientError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if self.max_items.is_some_and(|max_items| self.yielded >= max_items) {
+                return Poll::Ready(None);
+            }
+            if let Some(item) = self.items.pop_front() {
+                self.yielded += 1;
+                return Poll::Ready(Some(item));
+            }
+            if self.done {
+                return Poll::Ready(None);
+            }
+
+            let mut page = match self.page.take() {
+                Some(page) => page,
+                None => self.fetch_page(),
+            };
+            match page.as_mut().poll(cx) {
+                Poll::Ready(Ok(page)) => self.read_page(page),
+                Poll::Ready(Err(error)) => {
+                    self.done = true;
+                    return Poll::Ready(Some(Err(error)));
+                },
+                Poll::Pending => {
+                    self.page = Some(page);
+                    return Poll::Pending;
+                },
+            }
+        }
+    }
+}
+
+/// Decodes a JSON body, noting where in it decoding failed
+fn decode<T: serde::de::DeserializeOwned>(body: &[u8]) -> Result<T, ClientError> {
+    let mut deserializer = serde_json::Deserializer::from_slice(body);
+    let decoded = serde_path_to_error::deserialize(&mut deserializer)
+        .map_err(|error| (error.path().to_string(), error.into_inner()))
+        .and_then(|value| deserializer.end()
+            .map(|()| value)
+            .map_err(|error| (String::new(), error)));
+
+    decoded.map_err(|(path, error)| ClientError::Decode(DecodeError {
+        path,
+        error,
+        body: String::from_utf8_lossy(body).into_owned(),
+    }))
+}
+/// The feature an `OpenAI-Beta` value is for, such as `assistants` for
+///  `assistants=v2`
+fn beta_feature(beta: &str) -> &str {
+    beta.split('=').next().unwrap_or(beta).trim()
+}
+/// The value of the first header with the given name, ignoring case
+fn header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers.iter()
+        .find(|(header, _value)| header.eq_ignore_ascii_case(name))
+        .map(|(_header, value)| value.as_str())
+}
+
+/// Percent-encodes a path segment or query value
+pub(crate) fn encode(value: &str) -> String {
+    let mut encoded = String::new();
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+
+    encoded
+}
+
+/// The error object an [`ErrorResponse`](super::ErrorResponse) carries
+pub type ErrorBody = super::Error;
+
+/// Reads the error out of an [`ErrorResponse`](super::ErrorResponse) body
+fn parse_error_response(body: &[u8]) -> Option<ErrorBody> {
+    serde_json::from_slice::<super::ErrorResponse>(body)
+        .ok()
+        .map(|response| response.error)
+}
+/// Reads a bare error object, as sent by an `error` event
+fn parse_error_object(data: &str) -> Option<ErrorBody> {
+    serde_json::from_str::<ErrorBody>(data).ok()
+}
+
+impl Client {
+	/// Returns a list of assistants.
+	///
+	/// `GET /assistants`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	#[cfg(feature = "assistants")]
+	pub async fn list_assistants(&self, query: &ListAssistantsQuery) -> Result<ApiResponse<super::ListAssistantsResponse>, ClientError> {
+		self.json("listAssistants", "GET", "/assistants", &query.pairs(), None).await
+	}
+	/// Returns a list of assistants.
+	///
+	/// `GET /assistants`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	///
+	/// Streams every item, fetching pages as they're needed.
+	#[cfg(feature = "assistants")]
+	pub fn list_assistants_all(&self, query: &ListAssistantsQuery) -> Paginated<super::AssistantObject> {
+		Paginated::new(self.clone(), "listAssistants", "/assistants".to_string(), query.pairs())
+	}
+	/// Create an assistant with a model and instructions.
+	///
+	/// `POST /assistants`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	#[cfg(feature = "assistants")]
+	pub async fn create_assistant(&self, request: &super::CreateAssistantRequest) -> Result<ApiResponse<super::AssistantObject>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("createAssistant", "POST", "/assistants", &[], Some(body)).await
+	}
+	/// Retrieves an assistant.
+	///
+	/// `GET /assistants/{assistant_id}`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	#[cfg(feature = "assistants")]
+	pub async fn get_assistant(&self, assistant_id: &str) -> Result<ApiResponse<super::AssistantObject>, ClientError> {
+		self.json("getAssistant", "GET", &format!("/assistants/{}", encode(assistant_id)), &[], None).await
+	}
+	/// Modifies an assistant.
+	///
+	/// `POST /assistants/{assistant_id}`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	#[cfg(feature = "assistants")]
+	pub async fn modify_assistant(&self, assistant_id: &str, request: &super::ModifyAssistantRequest) -> Result<ApiResponse<super::AssistantObject>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("modifyAssistant", "POST", &format!("/assistants/{}", encode(assistant_id)), &[], Some(body)).await
+	}
+	/// Delete an assistant.
+	///
+	/// `DELETE /assistants/{assistant_id}`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	#[cfg(feature = "assistants")]
+	pub async fn delete_assistant(&self, assistant_id: &str) -> Result<ApiResponse<super::DeleteAssistantResponse>, ClientError> {
+		self.json("deleteAssistant", "DELETE", &format!("/assistants/{}", encode(assistant_id)), &[], None).await
+	}
+	/// Generates audio from the input text.
+	///
+	/// `POST /audio/speech`
+	#[cfg(feature = "audio")]
+	pub async fn create_speech(&self, request: &super::CreateSpeechRequest) -> Result<ApiResponse<Download>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.download("createSpeech", "POST", "/audio/speech", &[], Some(body)).await
+	}
+	/// List your organization's batches.
+	///
+	/// `GET /batches`
+	#[cfg(feature = "batch")]
+	pub async fn list_batches(&self, query: &ListBatchesQuery) -> Result<ApiResponse<super::ListBatchesResponse>, ClientError> {
+		self.json("listBatches", "GET", "/batches", &query.pairs(), None).await
+	}
+	/// List your organization's batches.
+	///
+	/// `GET /batches`
+	///
+	/// Streams every item, fetching pages as they're needed.
+	#[cfg(feature = "batch")]
+	pub fn list_batches_all(&self, query: &ListBatchesQuery) -> Paginated<super::Batch> {
+		Paginated::new(self.clone(), "listBatches", "/batches".to_string(), query.pairs())
+	}
+	/// Creates and executes a batch from an uploaded file of requests
+	///
+	/// `POST /batches`
+	#[cfg(feature = "batch")]
+	pub async fn create_batch(&self, request: &serde_json::Value) -> Result<ApiResponse<super::Batch>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("createBatch", "POST", "/batches", &[], Some(body)).await
+	}
+	/// Retrieves a batch.
+	///
+	/// `GET /batches/{batch_id}`
+	#[cfg(feature = "batch")]
+	pub async fn retrieve_batch(&self, batch_id: &str) -> Result<ApiResponse<super::Batch>, ClientError> {
+		self.json("retrieveBatch", "GET", &format!("/batches/{}", encode(batch_id)), &[], None).await
+	}
+	/// Cancels an in-progress batch. The batch will be in status `cancelling` for up to 10 minutes, before
+	/// changing to `cancelled`, where it will have partial results (if any) available in the output file.
+	///
+	/// `POST /batches/{batch_id}/cancel`
+	#[cfg(feature = "batch")]
+	pub async fn cancel_batch(&self, batch_id: &str) -> Result<ApiResponse<super::Batch>, ClientError> {
+		self.json("cancelBatch", "POST", &format!("/batches/{}/cancel", encode(batch_id)), &[], None).await
+	}
+	/// List stored Chat Completions. Only Chat Completions that have been stored
+	/// with the `store` parameter set to `true` will be returned.
+	///
+	/// `GET /chat/completions`
+	#[cfg(feature = "chat")]
+	pub async fn list_chat_completions(&self, query: &ListChatCompletionsQuery) -> Result<ApiResponse<super::ChatCompletionList>, ClientError> {
+		self.json("listChatCompletions", "GET", "/chat/completions", &query.pairs(), None).await
+	}
+	/// List stored Chat Completions. Only Chat Completions that have been stored
+	/// with the `store` parameter set to `true` will be returned.
+	///
+	/// `GET /chat/completions`
+	///
+	/// Streams every item, fetching pages as they're needed.
+	#[cfg(feature = "chat")]
+	pub fn list_chat_completions_all(&self, query: &ListChatCompletionsQuery) -> Paginated<super::CreateChatCompletionResponse> {
+		Paginated::new(self.clone(), "listChatCompletions", "/chat/completions".to_string(), query.pairs())
+	}
+	/// **Starting a new project?** We recommend trying
+	/// [Responses](https://platform.openai.com/docs/api-reference/responses)
+	/// to take advantage of the latest OpenAI platform features. Compare
+	/// [Chat Completions with
+	/// Responses](https://platform.openai.com/docs/guides/responses-vs-chat-completions?api-mode=responses).
+	///
+	/// ---
+	///
+	/// Creates a model response for the given chat conversation. Learn more in the
+	/// [text generation](https://platform.openai.com/docs/guides/text-generation),
+	/// [vision](https://platform.openai.com/docs/guides/vision),
+	/// and [audio](https://platform.openai.com/docs/guides/audio) guides.
+	///
+	/// Parameter support can differ depending on the model used to generate the
+	/// response, particularly for newer reasoning models. Parameters that are only
+	/// supported for reasoning models are noted below. For the current state of
+	/// unsupported parameters in reasoning models,
+	/// [refer to the reasoning guide](https://platform.openai.com/docs/guides/reasoning).
+	///
+	/// `POST /chat/completions`
+	#[cfg(feature = "chat")]
+	pub async fn create_chat_completion(&self, request: &super::CreateChatCompletionRequest) -> Result<ApiResponse<super::CreateChatCompletionResponse>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("createChatCompletion", "POST", "/chat/completions", &[], Some(body)).await
+	}
+	/// **Starting a new project?** We recommend trying
+	/// [Responses](https://platform.openai.com/docs/api-reference/responses)
+	/// to take advantage of the latest OpenAI platform features. Compare
+	/// [Chat Completions with
+	/// Responses](https://platform.openai.com/docs/guides/responses-vs-chat-completions?api-mode=responses).
+	///
+	/// ---
+	///
+	/// Creates a model response for the given chat conversation. Learn more in the
+	/// [text generation](https://platform.openai.com/docs/guides/text-generation),
+	/// [vision](https://platform.openai.com/docs/guides/vision),
+	/// and [audio](https://platform.openai.com/docs/guides/audio) guides.
+	///
+	/// Parameter support can differ depending on the model used to generate the
+	/// response, particularly for newer reasoning models. Parameters that are only
+	/// supported for reasoning models are noted below. For the current state of
+	/// unsupported parameters in reasoning models,
+	/// [refer to the reasoning guide](https://platform.openai.com/docs/guides/reasoning).
+	///
+	/// `POST /chat/completions`
+	///
+	/// Streams the response as server-sent events.
+	#[cfg(feature = "chat")]
+	pub async fn create_chat_completion_stream(&self, request: &super::CreateChatCompletionRequest) -> Result<EventStream<super::CreateChatCompletionStreamResponse>, ClientError> {
+		let mut body = serde_json::to_value(request)?;
+		if let Some(body) = body.as_object_mut() {
+			body.insert("stream".to_string(), serde_json::Value::Bool(true));
+		}
+		let body = serde_json::to_vec(&body)?;
+		self.events("createChatCompletion", "POST", "/chat/completions", &[], Some(body)).await
+	}
+	/// Get a stored chat completion. Only Chat Completions that have been created
+	/// with the `store` parameter set to `true` will be returned.
+	///
+	/// `GET /chat/completions/{completion_id}`
+	#[cfg(feature = "chat")]
+	pub async fn get_chat_completion(&self, completion_id: &str) -> Result<ApiResponse<super::CreateChatCompletionResponse>, ClientError> {
+		self.json("getChatCompletion", "GET", &format!("/chat/completions/{}", encode(completion_id)), &[], None).await
+	}
+	/// Modify a stored chat completion. Only Chat Completions that have been
+	/// created with the `store` parameter set to `true` can be modified. Currently,
+	/// the only supported modification is to update the `metadata` field.
+	///
+	/// `POST /chat/completions/{completion_id}`
+	#[cfg(feature = "chat")]
+	pub async fn update_chat_completion(&self, completion_id: &str, request: &serde_json::Value) -> Result<ApiResponse<super::CreateChatCompletionResponse>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("updateChatCompletion", "POST", &format!("/chat/completions/{}", encode(completion_id)), &[], Some(body)).await
+	}
+	/// Delete a stored chat completion. Only Chat Completions that have been
+	/// created with the `store` parameter set to `true` can be deleted.
+	///
+	/// `DELETE /chat/completions/{completion_id}`
+	#[cfg(feature = "chat")]
+	pub async fn delete_chat_completion(&self, completion_id: &str) -> Result<ApiResponse<super::ChatCompletionDeleted>, ClientError> {
+		self.json("deleteChatCompletion", "DELETE", &format!("/chat/completions/{}", encode(completion_id)), &[], None).await
+	}
+	/// Get the messages in a stored chat completion. Only Chat Completions that
+	/// have been created with the `store` parameter set to `true` will be
+	/// returned.
+	///
+	/// `GET /chat/completions/{completion_id}/messages`
+	#[cfg(feature = "chat")]
+	pub async fn get_chat_completion_messages(&self, completion_id: &str, query: &GetChatCompletionMessagesQuery) -> Result<ApiResponse<super::ChatCompletionMessageList>, ClientError> {
+		self.json("getChatCompletionMessages", "GET", &format!("/chat/completions/{}/messages", encode(completion_id)), &query.pairs(), None).await
+	}
+	/// Get the messages in a stored chat completion. Only Chat Completions that
+	/// have been created with the `store` parameter set to `true` will be
+	/// returned.
+	///
+	/// `GET /chat/completions/{completion_id}/messages`
+	///
+	/// Streams every item, fetching pages as they're needed.
+	#[cfg(feature = "chat")]
+	pub fn get_chat_completion_messages_all(&self, completion_id: &str, query: &GetChatCompletionMessagesQuery) -> Paginated<super::ChatCompletionMessageListData> {
+		Paginated::new(self.clone(), "getChatCompletionMessages", format!("/chat/completions/{}/messages", encode(completion_id)), query.pairs())
+	}
+	/// Creates a completion for the provided prompt and parameters.
+	///
+	/// `POST /completions`
+	#[cfg(feature = "completions")]
+	pub async fn create_completion(&self, request: &super::CreateCompletionRequest) -> Result<ApiResponse<super::CreateCompletionResponse>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("createCompletion", "POST", "/completions", &[], Some(body)).await
+	}
+	/// Creates an embedding vector representing the input text.
+	///
+	/// `POST /embeddings`
+	#[cfg(feature = "embeddings")]
+	pub async fn create_embedding(&self, request: &super::CreateEmbeddingRequest) -> Result<ApiResponse<super::CreateEmbeddingResponse>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("createEmbedding", "POST", "/embeddings", &[], Some(body)).await
+	}
+	/// Returns a list of files.
+	///
+	/// `GET /files`
+	#[cfg(all(feature = "files", feature = "shared"))]
+	pub async fn list_files(&self, query: &ListFilesQuery) -> Result<ApiResponse<super::ListFilesResponse>, ClientError> {
+		self.json("listFiles", "GET", "/files", &query.pairs(), None).await
+	}
+	/// Returns a list of files.
+	///
+	/// `GET /files`
+	///
+	/// Streams every item, fetching pages as they're needed.
+	#[cfg(all(feature = "files", feature = "shared"))]
+	pub fn list_files_all(&self, query: &ListFilesQuery) -> Paginated<super::OpenAIFile> {
+		Paginated::new(self.clone(), "listFiles", "/files".to_string(), query.pairs())
+	}
+	/// Returns information about a specific file.
+	///
+	/// `GET /files/{file_id}`
+	#[cfg(feature = "shared")]
+	pub async fn retrieve_file(&self, file_id: &str) -> Result<ApiResponse<super::OpenAIFile>, ClientError> {
+		self.json("retrieveFile", "GET", &format!("/files/{}", encode(file_id)), &[], None).await
+	}
+	/// Delete a file.
+	///
+	/// `DELETE /files/{file_id}`
+	#[cfg(feature = "files")]
+	pub async fn delete_file(&self, file_id: &str) -> Result<ApiResponse<super::DeleteFileResponse>, ClientError> {
+		self.json("deleteFile", "DELETE", &format!("/files/{}", encode(file_id)), &[], None).await
+	}
+	/// Returns the contents of the specified file.
+	///
+	/// `GET /files/{file_id}/content`
+	pub async fn download_file(&self, file_id: &str) -> Result<ApiResponse<Download>, ClientError> {
+		self.download("downloadFile", "GET", &format!("/files/{}/content", encode(file_id)), &[], None).await
+	}
+	/// **NOTE:** This endpoint requires an [admin API key](../admin-api-keys).
+	///
+	/// Organization owners can use this endpoint to view all permissions for a fine-tuned model checkpoint.
+	///
+	/// `GET /fine_tuning/checkpoints/{permission_id}/permissions`
+	#[cfg(feature = "fine_tuning")]
+	pub async fn list_fine_tuning_checkpoint_permissions(&self, permission_id: &str, query: &ListFineTuningCheckpointPermissionsQuery) -> Result<ApiResponse<super::ListFineTuningCheckpointPermissionResponse>, ClientError> {
+		self.json("listFineTuningCheckpointPermissions", "GET", &format!("/fine_tuning/checkpoints/{}/permissions", encode(permission_id)), &query.pairs(), None).await
+	}
+	/// **NOTE:** This endpoint requires an [admin API key](../admin-api-keys).
+	///
+	/// Organization owners can use this endpoint to view all permissions for a fine-tuned model checkpoint.
+	///
+	/// `GET /fine_tuning/checkpoints/{permission_id}/permissions`
+	///
+	/// Streams every item, fetching pages as they're needed.
+	#[cfg(feature = "fine_tuning")]
+	pub fn list_fine_tuning_checkpoint_permissions_all(&self, permission_id: &str, query: &ListFineTuningCheckpointPermissionsQuery) -> Paginated<super::FineTuningCheckpointPermission> {
+		Paginated::new(self.clone(), "listFineTuningCheckpointPermissions", format!("/fine_tuning/checkpoints/{}/permissions", encode(permission_id)), query.pairs())
+	}
+	/// **NOTE:** Calling this endpoint requires an [admin API key](../admin-api-keys).
+	///
+	/// This enables organization owners to share fine-tuned models with other projects in their
+	/// organization.
+	///
+	/// `POST /fine_tuning/checkpoints/{permission_id}/permissions`
+	#[cfg(feature = "fine_tuning")]
+	pub async fn create_fine_tuning_checkpoint_permission(&self, permission_id: &str, request: &super::CreateFineTuningCheckpointPermissionRequest) -> Result<ApiResponse<super::ListFineTuningCheckpointPermissionResponse>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("createFineTuningCheckpointPermission", "POST", &format!("/fine_tuning/checkpoints/{}/permissions", encode(permission_id)), &[], Some(body)).await
+	}
+	/// **NOTE:** This endpoint requires an [admin API key](../admin-api-keys).
+	///
+	/// Organization owners can use this endpoint to delete a permission for a fine-tuned model checkpoint.
+	///
+	/// `DELETE /fine_tuning/checkpoints/{permission_id}/permissions`
+	#[cfg(feature = "fine_tuning")]
+	pub async fn delete_fine_tuning_checkpoint_permission(&self, permission_id: &str) -> Result<ApiResponse<super::DeleteFineTuningCheckpointPermissionResponse>, ClientError> {
+		self.json("deleteFineTuningCheckpointPermission", "DELETE", &format!("/fine_tuning/checkpoints/{}/permissions", encode(permission_id)), &[], None).await
+	}
+	/// List your organization's fine-tuning jobs
+	///
+	/// `GET /fine_tuning/jobs`
+	#[cfg(feature = "fine_tuning")]
+	pub async fn list_paginated_fine_tuning_jobs(&self, query: &ListPaginatedFineTuningJobsQuery) -> Result<ApiResponse<super::ListPaginatedFineTuningJobsResponse>, ClientError> {
+		self.json("listPaginatedFineTuningJobs", "GET", "/fine_tuning/jobs", &query.pairs(), None).await
+	}
+	/// List your organization's fine-tuning jobs
+	///
+	/// `GET /fine_tuning/jobs`
+	///
+	/// Streams every item, fetching pages as they're needed.
+	#[cfg(feature = "fine_tuning")]
+	pub fn list_paginated_fine_tuning_jobs_all(&self, query: &ListPaginatedFineTuningJobsQuery) -> Paginated<super::FineTuningJob> {
+		Paginated::new(self.clone(), "listPaginatedFineTuningJobs", "/fine_tuning/jobs".to_string(), query.pairs())
+	}
+	/// Creates a fine-tuning job which begins the process of creating a new model from a given dataset.
+	///
+	/// Response includes details of the enqueued job including job status and the name of the fine-tuned
+	/// models once complete.
+	///
+	/// [Learn more about fine-tuning](https://platform.openai.com/docs/guides/fine-tuning)
+	///
+	/// `POST /fine_tuning/jobs`
+	#[cfg(feature = "fine_tuning")]
+	pub async fn create_fine_tuning_job(&self, request: &super::CreateFineTuningJobRequest) -> Result<ApiResponse<super::FineTuningJob>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("createFineTuningJob", "POST", "/fine_tuning/jobs", &[], Some(body)).await
+	}
+	/// Get info about a fine-tuning job.
+	///
+	/// [Learn more about fine-tuning](https://platform.openai.com/docs/guides/fine-tuning)
+	///
+	/// `GET /fine_tuning/jobs/{fine_tuning_job_id}`
+	#[cfg(feature = "fine_tuning")]
+	pub async fn retrieve_fine_tuning_job(&self, fine_tuning_job_id: &str) -> Result<ApiResponse<super::FineTuningJob>, ClientError> {
+		self.json("retrieveFineTuningJob", "GET", &format!("/fine_tuning/jobs/{}", encode(fine_tuning_job_id)), &[], None).await
+	}
+	/// Immediately cancel a fine-tune job.
+	///
+	/// `POST /fine_tuning/jobs/{fine_tuning_job_id}/cancel`
+	#[cfg(feature = "fine_tuning")]
+	pub async fn cancel_fine_tuning_job(&self, fine_tuning_job_id: &str) -> Result<ApiResponse<super::FineTuningJob>, ClientError> {
+		self.json("cancelFineTuningJob", "POST", &format!("/fine_tuning/jobs/{}/cancel", encode(fine_tuning_job_id)), &[], None).await
+	}
+	/// List checkpoints for a fine-tuning job.
+	///
+	/// `GET /fine_tuning/jobs/{fine_tuning_job_id}/checkpoints`
+	#[cfg(feature = "fine_tuning")]
+	pub async fn list_fine_tuning_job_checkpoints(&self, fine_tuning_job_id: &str, query: &ListFineTuningJobCheckpointsQuery) -> Result<ApiResponse<super::ListFineTuningJobCheckpointsResponse>, ClientError> {
+		self.json("listFineTuningJobCheckpoints", "GET", &format!("/fine_tuning/jobs/{}/checkpoints", encode(fine_tuning_job_id)), &query.pairs(), None).await
+	}
+	/// List checkpoints for a fine-tuning job.
+	///
+	/// `GET /fine_tuning/jobs/{fine_tuning_job_id}/checkpoints`
+	///
+	/// Streams every item, fetching pages as they're needed.
+	#[cfg(feature = "fine_tuning")]
+	pub fn list_fine_tuning_job_checkpoints_all(&self, fine_tuning_job_id: &str, query: &ListFineTuningJobCheckpointsQuery) -> Paginated<super::FineTuningJobCheckpoint> {
+		Paginated::new(self.clone(), "listFineTuningJobCheckpoints", format!("/fine_tuning/jobs/{}/checkpoints", encode(fine_tuning_job_id)), query.pairs())
+	}
+	/// Get status updates for a fine-tuning job.
+	///
+	/// `GET /fine_tuning/jobs/{fine_tuning_job_id}/events`
+	#[cfg(feature = "fine_tuning")]
+	pub async fn list_fine_tuning_events(&self, fine_tuning_job_id: &str, query: &ListFineTuningEventsQuery) -> Result<ApiResponse<super::ListFineTuningJobEventsResponse>, ClientError> {
+		self.json("listFineTuningEvents", "GET", &format!("/fine_tuning/jobs/{}/events", encode(fine_tuning_job_id)), &query.pairs(), None).await
+	}
+	/// Get status updates for a fine-tuning job.
+	///
+	/// `GET /fine_tuning/jobs/{fine_tuning_job_id}/events`
+	///
+	/// Streams every item, fetching pages as they're needed.
+	#[cfg(feature = "fine_tuning")]
+	pub fn list_fine_tuning_events_all(&self, fine_tuning_job_id: &str, query: &ListFineTuningEventsQuery) -> Paginated<super::FineTuningJobEvent> {
+		Paginated::new(self.clone(), "listFineTuningEvents", format!("/fine_tuning/jobs/{}/events", encode(fine_tuning_job_id)), query.pairs())
+	}
+	/// Creates an image given a prompt.
+	///
+	/// `POST /images/generations`
+	#[cfg(feature = "images")]
+	pub async fn create_image(&self, request: &super::CreateImageRequest) -> Result<ApiResponse<super::ImagesResponse>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("createImage", "POST", "/images/generations", &[], Some(body)).await
+	}
+	/// Lists the currently available models, and provides basic information about each one such as the
+	/// owner and availability.
+	///
+	/// `GET /models`
+	#[cfg(feature = "models")]
+	pub async fn list_models(&self) -> Result<ApiResponse<super::ListModelsResponse>, ClientError> {
+		self.json("listModels", "GET", "/models", &[], None).await
+	}
+	/// Retrieves a model instance, providing basic information about the model such as the owner and
+	/// permissioning.
+	///
+	/// `GET /models/{model}`
+	#[cfg(feature = "models")]
+	pub async fn retrieve_model(&self, model: &str) -> Result<ApiResponse<super::Model>, ClientError> {
+		self.json("retrieveModel", "GET", &format!("/models/{}", encode(model)), &[], None).await
+	}
+	/// Delete a fine-tuned model. You must have the Owner role in your organization to delete a model.
+	///
+	/// `DELETE /models/{model}`
+	#[cfg(feature = "models")]
+	pub async fn delete_model(&self, model: &str) -> Result<ApiResponse<super::DeleteModelResponse>, ClientError> {
+		self.json("deleteModel", "DELETE", &format!("/models/{}", encode(model)), &[], None).await
+	}
+	/// Classifies if text and/or image inputs are potentially harmful. Learn
+	/// more in the [moderation guide](https://platform.openai.com/docs/guides/moderation).
+	///
+	/// `POST /moderations`
+	#[cfg(feature = "moderations")]
+	pub async fn create_moderation(&self, request: &super::CreateModerationRequest) -> Result<ApiResponse<super::CreateModerationResponse>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("createModeration", "POST", "/moderations", &[], Some(body)).await
+	}
+	/// List organization API keys
+	///
+	/// `GET /organization/admin_api_keys`
+	#[cfg(feature = "administration")]
+	pub async fn admin_api_keys_list(&self, query: &AdminApiKeysListQuery) -> Result<ApiResponse<super::ApiKeyList>, ClientError> {
+		self.json("admin-api-keys-list", "GET", "/organization/admin_api_keys", &query.pairs(), None).await
+	}
+	/// List organization API keys
+	///
+	/// `GET /organization/admin_api_keys`
+	///
+	/// Streams every item, fetching pages as they're needed.
+	#[cfg(feature = "administration")]
+	pub fn admin_api_keys_list_all(&self, query: &AdminApiKeysListQuery) -> Paginated<super::AdminApiKey> {
+		Paginated::new(self.clone(), "admin-api-keys-list", "/organization/admin_api_keys".to_string(), query.pairs())
+	}
+	/// Create an organization admin API key
+	///
+	/// `POST /organization/admin_api_keys`
+	#[cfg(feature = "administration")]
+	pub async fn admin_api_keys_create(&self, request: &serde_json::Value) -> Result<ApiResponse<super::AdminApiKey>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("admin-api-keys-create", "POST", "/organization/admin_api_keys", &[], Some(body)).await
+	}
+	/// Retrieve a single organization API key
+	///
+	/// `GET /organization/admin_api_keys/{key_id}`
+	#[cfg(feature = "administration")]
+	pub async fn admin_api_keys_get(&self, key_id: &str) -> Result<ApiResponse<super::AdminApiKey>, ClientError> {
+		self.json("admin-api-keys-get", "GET", &format!("/organization/admin_api_keys/{}", encode(key_id)), &[], None).await
+	}
+	/// Delete an organization admin API key
+	///
+	/// `DELETE /organization/admin_api_keys/{key_id}`
+	pub async fn admin_api_keys_delete(&self, key_id: &str) -> Result<ApiResponse<serde_json::Value>, ClientError> {
+		self.json("admin-api-keys-delete", "DELETE", &format!("/organization/admin_api_keys/{}", encode(key_id)), &[], None).await
+	}
+	/// List user actions and configuration changes within this organization.
+	///
+	/// `GET /organization/audit_logs`
+	#[cfg(feature = "audit_logs")]
+	pub async fn list_audit_logs(&self, query: &ListAuditLogsQuery) -> Result<ApiResponse<super::ListAuditLogsResponse>, ClientError> {
+		self.json("list-audit-logs", "GET", "/organization/audit_logs", &query.pairs(), None).await
+	}
+	/// List user actions and configuration changes within this organization.
+	///
+	/// `GET /organization/audit_logs`
+	///
+	/// Streams every item, fetching pages as they're needed.
+	#[cfg(feature = "audit_logs")]
+	pub fn list_audit_logs_all(&self, query: &ListAuditLogsQuery) -> Paginated<super::AuditLog> {
+		Paginated::new(self.clone(), "list-audit-logs", "/organization/audit_logs".to_string(), query.pairs())
+	}
+	/// Get costs details for the organization.
+	///
+	/// `GET /organization/costs`
+	#[cfg(feature = "shared")]
+	pub async fn usage_costs(&self, query: &UsageCostsQuery) -> Result<ApiResponse<super::UsageResponse>, ClientError> {
+		self.json("usage-costs", "GET", "/organization/costs", &query.pairs(), None).await
+	}
+	/// Returns a list of invites in the organization.
+	///
+	/// `GET /organization/invites`
+	#[cfg(feature = "administration")]
+	pub async fn list_invites(&self, query: &ListInvitesQuery) -> Result<ApiResponse<super::InviteListResponse>, ClientError> {
+		self.json("list-invites", "GET", "/organization/invites", &query.pairs(), None).await
+	}
+	/// Returns a list of invites in the organization.
+	///
+	/// `GET /organization/invites`
+	///
+	/// Streams every item, fetching pages as they're needed.
+	#[cfg(feature = "administration")]
+	pub fn list_invites_all(&self, query: &ListInvitesQuery) -> Paginated<super::Invite> {
+		Paginated::new(self.clone(), "list-invites", "/organization/invites".to_string(), query.pairs())
+	}
+	/// Create an invite for a user to the organization. The invite must be accepted by the user before they
+	/// have access to the organization.
+	///
+	/// `POST /organization/invites`
+	#[cfg(feature = "administration")]
+	pub async fn invite_user(&self, request: &super::InviteRequest) -> Result<ApiResponse<super::Invite>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("inviteUser", "POST", "/organization/invites", &[], Some(body)).await
+	}
+	/// Retrieves an invite.
+	///
+	/// `GET /organization/invites/{invite_id}`
+	#[cfg(feature = "administration")]
+	pub async fn retrieve_invite(&self, invite_id: &str) -> Result<ApiResponse<super::Invite>, ClientError> {
+		self.json("retrieve-invite", "GET", &format!("/organization/invites/{}", encode(invite_id)), &[], None).await
+	}
+	/// Delete an invite. If the invite has already been accepted, it cannot be deleted.
+	///
+	/// `DELETE /organization/invites/{invite_id}`
+	#[cfg(feature = "administration")]
+	pub async fn delete_invite(&self, invite_id: &str) -> Result<ApiResponse<super::InviteDeleteResponse>, ClientError> {
+		self.json("delete-invite", "DELETE", &format!("/organization/invites/{}", encode(invite_id)), &[], None).await
+	}
+	/// Returns a list of projects.
+	///
+	/// `GET /organization/projects`
+	#[cfg(feature = "administration")]
+	pub async fn list_projects(&self, query: &ListProjectsQuery) -> Result<ApiResponse<super::ProjectListResponse>, ClientError> {
+		self.json("list-projects", "GET", "/organization/projects", &query.pairs(), None).await
+	}
+	/// Returns a list of projects.
+	///
+	/// `GET /organization/projects`
+	///
+	/// Streams every item, fetching pages as they're needed.
+	#[cfg(feature = "administration")]
+	pub fn list_projects_all(&self, query: &ListProjectsQuery) -> Paginated<super::Project> {
+		Paginated::new(self.clone(), "list-projects", "/organization/projects".to_string(), query.pairs())
+	}
+	/// Create a new project in the organization. Projects can be created and archived, but cannot be
+	/// deleted.
+	///
+	/// `POST /organization/projects`
+	#[cfg(feature = "administration")]
+	pub async fn create_project(&self, request: &super::ProjectCreateRequest) -> Result<ApiResponse<super::Project>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("create-project", "POST", "/organization/projects", &[], Some(body)).await
+	}
+	/// Retrieves a project.
+	///
+	/// `GET /organization/projects/{project_id}`
+	#[cfg(feature = "administration")]
+	pub async fn retrieve_project(&self, project_id: &str) -> Result<ApiResponse<super::Project>, ClientError> {
+		self.json("retrieve-project", "GET", &format!("/organization/projects/{}", encode(project_id)), &[], None).await
+	}
+	/// Modifies a project in the organization.
+	///
+	/// `POST /organization/projects/{project_id}`
+	#[cfg(feature = "administration")]
+	pub async fn modify_project(&self, project_id: &str, request: &super::ProjectUpdateRequest) -> Result<ApiResponse<super::Project>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("modify-project", "POST", &format!("/organization/projects/{}", encode(project_id)), &[], Some(body)).await
+	}
+	/// Returns a list of API keys in the project.
+	///
+	/// `GET /organization/projects/{project_id}/api_keys`
+	#[cfg(feature = "administration")]
+	pub async fn list_project_api_keys(&self, project_id: &str, query: &ListProjectApiKeysQuery) -> Result<ApiResponse<super::ProjectApiKeyListResponse>, ClientError> {
+		self.json("list-project-api-keys", "GET", &format!("/organization/projects/{}/api_keys", encode(project_id)), &query.pairs(), None).await
+	}
+	/// Returns a list of API keys in the project.
+	///
+	/// `GET /organization/projects/{project_id}/api_keys`
+	///
+	/// Streams every item, fetching pages as they're needed.
+	#[cfg(feature = "administration")]
+	pub fn list_project_api_keys_all(&self, project_id: &str, query: &ListProjectApiKeysQuery) -> Paginated<super::ProjectApiKey> {
+		Paginated::new(self.clone(), "list-project-api-keys", format!("/organization/projects/{}/api_keys", encode(project_id)), query.pairs())
+	}
+	/// Retrieves an API key in the project.
+	///
+	/// `GET /organization/projects/{project_id}/api_keys/{key_id}`
+	#[cfg(feature = "administration")]
+	pub async fn retrieve_project_api_key(&self, project_id: &str, key_id: &str) -> Result<ApiResponse<super::ProjectApiKey>, ClientError> {
+		self.json("retrieve-project-api-key", "GET", &format!("/organization/projects/{}/api_keys/{}", encode(project_id), encode(key_id)), &[], None).await
+	}
+	/// Deletes an API key from the project.
+	///
+	/// `DELETE /organization/projects/{project_id}/api_keys/{key_id}`
+	#[cfg(feature = "administration")]
+	pub async fn delete_project_api_key(&self, project_id: &str, key_id: &str) -> Result<ApiResponse<super::ProjectApiKeyDeleteResponse>, ClientError> {
+		self.json("delete-project-api-key", "DELETE", &format!("/organization/projects/{}/api_keys/{}", encode(project_id), encode(key_id)), &[], None).await
+	}
+	/// Archives a project in the organization. Archived projects cannot be used or updated.
+	///
+	/// `POST /organization/projects/{project_id}/archive`
+	#[cfg(feature = "administration")]
+	pub async fn archive_project(&self, project_id: &str) -> Result<ApiResponse<super::Project>, ClientError> {
+		self.json("archive-project", "POST", &format!("/organization/projects/{}/archive", encode(project_id)), &[], None).await
+	}
+	/// Returns the rate limits per model for a project.
+	///
+	/// `GET /organization/projects/{project_id}/rate_limits`
+	#[cfg(feature = "administration")]
+	pub async fn list_project_rate_limits(&self, project_id: &str, query: &ListProjectRateLimitsQuery) -> Result<ApiResponse<super::ProjectRateLimitListResponse>, ClientError> {
+		self.json("list-project-rate-limits", "GET", &format!("/organization/projects/{}/rate_limits", encode(project_id)), &query.pairs(), None).await
+	}
+	/// Returns the rate limits per model for a project.
+	///
+	/// `GET /organization/projects/{project_id}/rate_limits`
+	///
+	/// Streams every item, fetching pages as they're needed.
+	#[cfg(feature = "administration")]
+	pub fn list_project_rate_limits_all(&self, project_id: &str, query: &ListProjectRateLimitsQuery) -> Paginated<super::ProjectRateLimit> {
+		Paginated::new(self.clone(), "list-project-rate-limits", format!("/organization/projects/{}/rate_limits", encode(project_id)), query.pairs())
+	}
+	/// Updates a project rate limit.
+	///
+	/// `POST /organization/projects/{project_id}/rate_limits/{rate_limit_id}`
+	#[cfg(feature = "administration")]
+	pub async fn update_project_rate_limits(&self, project_id: &str, rate_limit_id: &str, request: &super::ProjectRateLimitUpdateRequest) -> Result<ApiResponse<super::ProjectRateLimit>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("update-project-rate-limits", "POST", &format!("/organization/projects/{}/rate_limits/{}", encode(project_id), encode(rate_limit_id)), &[], Some(body)).await
+	}
+	/// Returns a list of service accounts in the project.
+	///
+	/// `GET /organization/projects/{project_id}/service_accounts`
+	#[cfg(feature = "administration")]
+	pub async fn list_project_service_accounts(&self, project_id: &str, query: &ListProjectServiceAccountsQuery) -> Result<ApiResponse<super::ProjectServiceAccountListResponse>, ClientError> {
+		self.json("list-project-service-accounts", "GET", &format!("/organization/projects/{}/service_accounts", encode(project_id)), &query.pairs(), None).await
+	}
+	/// Returns a list of service accounts in the project.
+	///
+	/// `GET /organization/projects/{project_id}/service_accounts`
+	///
+	/// Streams every item, fetching pages as they're needed.
+	#[cfg(feature = "administration")]
+	pub fn list_project_service_accounts_all(&self, project_id: &str, query: &ListProjectServiceAccountsQuery) -> Paginated<super::ProjectServiceAccount> {
+		Paginated::new(self.clone(), "list-project-service-accounts", format!("/organization/projects/{}/service_accounts", encode(project_id)), query.pairs())
+	}
+	/// Creates a new service account in the project. This also returns an unredacted API key for the
+	/// service account.
+	///
+	/// `POST /organization/projects/{project_id}/service_accounts`
+	#[cfg(feature = "administration")]
+	pub async fn create_project_service_account(&self, project_id: &str, request: &super::ProjectServiceAccountCreateRequest) -> Result<ApiResponse<super::ProjectServiceAccountCreateResponse>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("create-project-service-account", "POST", &format!("/organization/projects/{}/service_accounts", encode(project_id)), &[], Some(body)).await
+	}
+	/// Retrieves a service account in the project.
+	///
+	/// `GET /organization/projects/{project_id}/service_accounts/{service_account_id}`
+	#[cfg(feature = "administration")]
+	pub async fn retrieve_project_service_account(&self, project_id: &str, service_account_id: &str) -> Result<ApiResponse<super::ProjectServiceAccount>, ClientError> {
+		self.json("retrieve-project-service-account", "GET", &format!("/organization/projects/{}/service_accounts/{}", encode(project_id), encode(service_account_id)), &[], None).await
+	}
+	/// Deletes a service account from the project.
+	///
+	/// `DELETE /organization/projects/{project_id}/service_accounts/{service_account_id}`
+	#[cfg(feature = "administration")]
+	pub async fn delete_project_service_account(&self, project_id: &str, service_account_id: &str) -> Result<ApiResponse<super::ProjectServiceAccountDeleteResponse>, ClientError> {
+		self.json("delete-project-service-account", "DELETE", &format!("/organization/projects/{}/service_accounts/{}", encode(project_id), encode(service_account_id)), &[], None).await
+	}
+	/// Returns a list of users in the project.
+	///
+	/// `GET /organization/projects/{project_id}/users`
+	#[cfg(feature = "administration")]
+	pub async fn list_project_users(&self, project_id: &str, query: &ListProjectUsersQuery) -> Result<ApiResponse<super::ProjectUserListResponse>, ClientError> {
+		self.json("list-project-users", "GET", &format!("/organization/projects/{}/users", encode(project_id)), &query.pairs(), None).await
+	}
+	/// Returns a list of users in the project.
+	///
+	/// `GET /organization/projects/{project_id}/users`
+	///
+	/// Streams every item, fetching pages as they're needed.
+	#[cfg(feature = "administration")]
+	pub fn list_project_users_all(&self, project_id: &str, query: &ListProjectUsersQuery) -> Paginated<super::ProjectUser> {
+		Paginated::new(self.clone(), "list-project-users", format!("/organization/projects/{}/users", encode(project_id)), query.pairs())
+	}
+	/// Adds a user to the project. Users must already be members of the organization to be added to a
+	/// project.
+	///
+	/// `POST /organization/projects/{project_id}/users`
+	#[cfg(feature = "administration")]
+	pub async fn create_project_user(&self, project_id: &str, request: &super::ProjectUserCreateRequest) -> Result<ApiResponse<super::ProjectUser>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("create-project-user", "POST", &format!("/organization/projects/{}/users", encode(project_id)), &[], Some(body)).await
+	}
+	/// Retrieves a user in the project.
+	///
+	/// `GET /organization/projects/{project_id}/users/{user_id}`
+	#[cfg(feature = "administration")]
+	pub async fn retrieve_project_user(&self, project_id: &str, user_id: &str) -> Result<ApiResponse<super::ProjectUser>, ClientError> {
+		self.json("retrieve-project-user", "GET", &format!("/organization/projects/{}/users/{}", encode(project_id), encode(user_id)), &[], None).await
+	}
+	/// Modifies a user's role in the project.
+	///
+	/// `POST /organization/projects/{project_id}/users/{user_id}`
+	#[cfg(feature = "administration")]
+	pub async fn modify_project_user(&self, project_id: &str, user_id: &str, request: &super::ProjectUserUpdateRequest) -> Result<ApiResponse<super::ProjectUser>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("modify-project-user", "POST", &format!("/organization/projects/{}/users/{}", encode(project_id), encode(user_id)), &[], Some(body)).await
+	}
+	/// Deletes a user from the project.
+	///
+	/// `DELETE /organization/projects/{project_id}/users/{user_id}`
+	#[cfg(feature = "administration")]
+	pub async fn delete_project_user(&self, project_id: &str, user_id: &str) -> Result<ApiResponse<super::ProjectUserDeleteResponse>, ClientError> {
+		self.json("delete-project-user", "DELETE", &format!("/organization/projects/{}/users/{}", encode(project_id), encode(user_id)), &[], None).await
+	}
+	/// Get audio speeches usage details for the organization.
+	///
+	/// `GET /organization/usage/audio_speeches`
+	#[cfg(feature = "shared")]
+	pub async fn usage_audio_speeches(&self, query: &UsageAudioSpeechesQuery) -> Result<ApiResponse<super::UsageResponse>, ClientError> {
+		self.json("usage-audio-speeches", "GET", "/organization/usage/audio_speeches", &query.pairs(), None).await
+	}
+	/// Get audio transcriptions usage details for the organization.
+	///
+	/// `GET /organization/usage/audio_transcriptions`
+	#[cfg(feature = "shared")]
+	pub async fn usage_audio_transcriptions(&self, query: &UsageAudioTranscriptionsQuery) -> Result<ApiResponse<super::UsageResponse>, ClientError> {
+		self.json("usage-audio-transcriptions", "GET", "/organization/usage/audio_transcriptions", &query.pairs(), None).await
+	}
+	/// Get code interpreter sessions usage details for the organization.
+	///
+	/// `GET /organization/usage/code_interpreter_sessions`
+	#[cfg(feature = "shared")]
+	pub async fn usage_code_interpreter_sessions(&self, query: &UsageCodeInterpreterSessionsQuery) -> Result<ApiResponse<super::UsageResponse>, ClientError> {
+		self.json("usage-code-interpreter-sessions", "GET", "/organization/usage/code_interpreter_sessions", &query.pairs(), None).await
+	}
+	/// Get completions usage details for the organization.
+	///
+	/// `GET /organization/usage/completions`
+	#[cfg(feature = "shared")]
+	pub async fn usage_completions(&self, query: &UsageCompletionsQuery) -> Result<ApiResponse<super::UsageResponse>, ClientError> {
+		self.json("usage-completions", "GET", "/organization/usage/completions", &query.pairs(), None).await
+	}
+	/// Get embeddings usage details for the organization.
+	///
+	/// `GET /organization/usage/embeddings`
+	#[cfg(feature = "shared")]
+	pub async fn usage_embeddings(&self, query: &UsageEmbeddingsQuery) -> Result<ApiResponse<super::UsageResponse>, ClientError> {
+		self.json("usage-embeddings", "GET", "/organization/usage/embeddings", &query.pairs(), None).await
+	}
+	/// Get images usage details for the organization.
+	///
+	/// `GET /organization/usage/images`
+	#[cfg(feature = "shared")]
+	pub async fn usage_images(&self, query: &UsageImagesQuery) -> Result<ApiResponse<super::UsageResponse>, ClientError> {
+		self.json("usage-images", "GET", "/organization/usage/images", &query.pairs(), None).await
+	}
+	/// Get moderations usage details for the organization.
+	///
+	/// `GET /organization/usage/moderations`
+	#[cfg(feature = "shared")]
+	pub async fn usage_moderations(&self, query: &UsageModerationsQuery) -> Result<ApiResponse<super::UsageResponse>, ClientError> {
+		self.json("usage-moderations", "GET", "/organization/usage/moderations", &query.pairs(), None).await
+	}
+	/// Get vector stores usage details for the organization.
+	///
+	/// `GET /organization/usage/vector_stores`
+	#[cfg(feature = "shared")]
+	pub async fn usage_vector_stores(&self, query: &UsageVectorStoresQuery) -> Result<ApiResponse<super::UsageResponse>, ClientError> {
+		self.json("usage-vector-stores", "GET", "/organization/usage/vector_stores", &query.pairs(), None).await
+	}
+	/// Lists all of the users in the organization.
+	///
+	/// `GET /organization/users`
+	#[cfg(feature = "administration")]
+	pub async fn list_users(&self, query: &ListUsersQuery) -> Result<ApiResponse<super::UserListResponse>, ClientError> {
+		self.json("list-users", "GET", "/organization/users", &query.pairs(), None).await
+	}
+	/// Lists all of the users in the organization.
+	///
+	/// `GET /organization/users`
+	///
+	/// Streams every item, fetching pages as they're needed.
+	#[cfg(feature = "administration")]
+	pub fn list_users_all(&self, query: &ListUsersQuery) -> Paginated<super::User> {
+		Paginated::new(self.clone(), "list-users", "/organization/users".to_string(), query.pairs())
+	}
+	/// Retrieves a user by their identifier.
+	///
+	/// `GET /organization/users/{user_id}`
+	#[cfg(feature = "administration")]
+	pub async fn retrieve_user(&self, user_id: &str) -> Result<ApiResponse<super::User>, ClientError> {
+		self.json("retrieve-user", "GET", &format!("/organization/users/{}", encode(user_id)), &[], None).await
+	}
+	/// Modifies a user's role in the organization.
+	///
+	/// `POST /organization/users/{user_id}`
+	#[cfg(feature = "administration")]
+	pub async fn modify_user(&self, user_id: &str, request: &super::UserRoleUpdateRequest) -> Result<ApiResponse<super::User>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("modify-user", "POST", &format!("/organization/users/{}", encode(user_id)), &[], Some(body)).await
+	}
+	/// Deletes a user from the organization.
+	///
+	/// `DELETE /organization/users/{user_id}`
+	#[cfg(feature = "administration")]
+	pub async fn delete_user(&self, user_id: &str) -> Result<ApiResponse<super::UserDeleteResponse>, ClientError> {
+		self.json("delete-user", "DELETE", &format!("/organization/users/{}", encode(user_id)), &[], None).await
+	}
+	/// Create an ephemeral API token for use in client-side applications with the
+	/// Realtime API. Can be configured with the same session parameters as the
+	/// `session.update` client event.
+	///
+	/// It responds with a session object, plus a `client_secret` key which contains
+	/// a usable ephemeral API token that can be used to authenticate browser clients
+	/// for the Realtime API.
+	///
+	/// `POST /realtime/sessions`
+	#[cfg(feature = "realtime")]
+	pub async fn create_realtime_session(&self, request: &super::RealtimeSessionCreateRequest) -> Result<ApiResponse<super::RealtimeSessionCreateResponse>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("create-realtime-session", "POST", "/realtime/sessions", &[], Some(body)).await
+	}
+	/// Create an ephemeral API token for use in client-side applications with the
+	/// Realtime API specifically for realtime transcriptions.
+	/// Can be configured with the same session parameters as the `transcription_session.update` client
+	/// event.
+	///
+	/// It responds with a session object, plus a `client_secret` key which contains
+	/// a usable ephemeral API token that can be used to authenticate browser clients
+	/// for the Realtime API.
+	///
+	/// `POST /realtime/transcription_sessions`
+	#[cfg(feature = "realtime")]
+	pub async fn create_realtime_transcription_session(&self, request: &super::RealtimeTranscriptionSessionCreateRequest) -> Result<ApiResponse<super::RealtimeTranscriptionSessionCreateResponse>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("create-realtime-transcription-session", "POST", "/realtime/transcription_sessions", &[], Some(body)).await
+	}
+	/// Creates a model response. Provide [text](https://platform.openai.com/docs/guides/text) or
+	/// [image](https://platform.openai.com/docs/guides/images) inputs to generate
+	/// [text](https://platform.openai.com/docs/guides/text)
+	/// or [JSON](https://platform.openai.com/docs/guides/structured-outputs) outputs. Have the model call
+	/// your own [custom code](https://platform.openai.com/docs/guides/function-calling) or use built-in
+	/// [tools](https://platform.openai.com/docs/guides/tools) like [web
+	/// search](https://platform.openai.com/docs/guides/tools-web-search)
+	/// or [file search](https://platform.openai.com/docs/guides/tools-file-search) to use your own data
+	/// as input for the model's response.
+	///
+	/// `POST /responses`
+	#[cfg(feature = "responses")]
+	pub async fn create_response(&self, request: &super::CreateResponse) -> Result<ApiResponse<super::Response>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("createResponse", "POST", "/responses", &[], Some(body)).await
+	}
+	/// Creates a model response. Provide [text](https://platform.openai.com/docs/guides/text) or
+	/// [image](https://platform.openai.com/docs/guides/images) inputs to generate
+	/// [text](https://platform.openai.com/docs/guides/text)
+	/// or [JSON](https://platform.openai.com/docs/guides/structured-outputs) outputs. Have the model call
+	/// your own [custom code](https://platform.openai.com/docs/guides/function-calling) or use built-in
+	/// [tools](https://platform.openai.com/docs/guides/tools) like [web
+	/// search](https://platform.openai.com/docs/guides/tools-web-search)
+	/// or [file search](https://platform.openai.com/docs/guides/tools-file-search) to use your own data
+	/// as input for the model's response.
+	///
+	/// `POST /responses`
+	///
+	/// Streams the response as server-sent events.
+	#[cfg(feature = "responses")]
+	pub async fn create_response_stream(&self, request: &super::CreateResponse) -> Result<EventStream<super::ResponseStreamEvent>, ClientError> {
+		let mut body = serde_json::to_value(request)?;
+		if let Some(body) = body.as_object_mut() {
+			body.insert("stream".to_string(), serde_json::Value::Bool(true));
+		}
+		let body = serde_json::to_vec(&body)?;
+		self.events("createResponse", "POST", "/responses", &[], Some(body)).await
+	}
+	/// Retrieves a model response with the given ID.
+	///
+	/// `GET /responses/{response_id}`
+	#[cfg(feature = "responses")]
+	pub async fn get_response(&self, response_id: &str, query: &GetResponseQuery) -> Result<ApiResponse<super::Response>, ClientError> {
+		self.json("getResponse", "GET", &format!("/responses/{}", encode(response_id)), &query.pairs(), None).await
+	}
+	/// Deletes a model response with the given ID.
+	///
+	/// `DELETE /responses/{response_id}`
+	pub async fn delete_response(&self, response_id: &str) -> Result<ApiResponse<()>, ClientError> {
+		self.json("deleteResponse", "DELETE", &format!("/responses/{}", encode(response_id)), &[], None).await
+	}
+	/// Returns a list of input items for a given response.
+	///
+	/// `GET /responses/{response_id}/input_items`
+	#[cfg(feature = "responses")]
+	pub async fn list_input_items(&self, response_id: &str, query: &ListInputItemsQuery) -> Result<ApiResponse<super::ResponseItemList>, ClientError> {
+		self.json("listInputItems", "GET", &format!("/responses/{}/input_items", encode(response_id)), &query.pairs(), None).await
+	}
+	/// Returns a list of input items for a given response.
+	///
+	/// `GET /responses/{response_id}/input_items`
+	///
+	/// Streams every item, fetching pages as they're needed.
+	#[cfg(feature = "responses")]
+	pub fn list_input_items_all(&self, response_id: &str, query: &ListInputItemsQuery) -> Paginated<super::ItemResource> {
+		Paginated::new(self.clone(), "listInputItems", format!("/responses/{}/input_items", encode(response_id)), query.pairs())
+	}
+	/// Create a thread.
+	///
+	/// `POST /threads`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	#[cfg(feature = "threads")]
+	pub async fn create_thread(&self, request: &super::CreateThreadRequest) -> Result<ApiResponse<super::ThreadObject>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("createThread", "POST", "/threads", &[], Some(body)).await
+	}
+	/// Create a thread and run it in one request.
+	///
+	/// `POST /threads/runs`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	#[cfg(feature = "threads")]
+	pub async fn create_thread_and_run(&self, request: &super::CreateThreadAndRunRequest) -> Result<ApiResponse<super::RunObject>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("createThreadAndRun", "POST", "/threads/runs", &[], Some(body)).await
+	}
+	/// Retrieves a thread.
+	///
+	/// `GET /threads/{thread_id}`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	#[cfg(feature = "threads")]
+	pub async fn get_thread(&self, thread_id: &str) -> Result<ApiResponse<super::ThreadObject>, ClientError> {
+		self.json("getThread", "GET", &format!("/threads/{}", encode(thread_id)), &[], None).await
+	}
+	/// Modifies a thread.
+	///
+	/// `POST /threads/{thread_id}`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	#[cfg(feature = "threads")]
+	pub async fn modify_thread(&self, thread_id: &str, request: &super::ModifyThreadRequest) -> Result<ApiResponse<super::ThreadObject>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("modifyThread", "POST", &format!("/threads/{}", encode(thread_id)), &[], Some(body)).await
+	}
+	/// Delete a thread.
+	///
+	/// `DELETE /threads/{thread_id}`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	#[cfg(feature = "threads")]
+	pub async fn delete_thread(&self, thread_id: &str) -> Result<ApiResponse<super::DeleteThreadResponse>, ClientError> {
+		self.json("deleteThread", "DELETE", &format!("/threads/{}", encode(thread_id)), &[], None).await
+	}
+	/// Returns a list of messages for a given thread.
+	///
+	/// `GET /threads/{thread_id}/messages`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	#[cfg(feature = "threads")]
+	pub async fn list_messages(&self, thread_id: &str, query: &ListMessagesQuery) -> Result<ApiResponse<super::ListMessagesResponse>, ClientError> {
+		self.json("listMessages", "GET", &format!("/threads/{}/messages", encode(thread_id)), &query.pairs(), None).await
+	}
+	/// Returns a list of messages for a given thread.
+	///
+	/// `GET /threads/{thread_id}/messages`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	///
+	/// Streams every item, fetching pages as they're needed.
+	#[cfg(feature = "threads")]
+	pub fn list_messages_all(&self, thread_id: &str, query: &ListMessagesQuery) -> Paginated<super::MessageObject> {
+		Paginated::new(self.clone(), "listMessages", format!("/threads/{}/messages", encode(thread_id)), query.pairs())
+	}
+	/// Create a message.
+	///
+	/// `POST /threads/{thread_id}/messages`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	#[cfg(feature = "threads")]
+	pub async fn create_message(&self, thread_id: &str, request: &super::CreateMessageRequest) -> Result<ApiResponse<super::MessageObject>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("createMessage", "POST", &format!("/threads/{}/messages", encode(thread_id)), &[], Some(body)).await
+	}
+	/// Retrieve a message.
+	///
+	/// `GET /threads/{thread_id}/messages/{message_id}`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	#[cfg(feature = "threads")]
+	pub async fn get_message(&self, thread_id: &str, message_id: &str) -> Result<ApiResponse<super::MessageObject>, ClientError> {
+		self.json("getMessage", "GET", &format!("/threads/{}/messages/{}", encode(thread_id), encode(message_id)), &[], None).await
+	}
+	/// Modifies a message.
+	///
+	/// `POST /threads/{thread_id}/messages/{message_id}`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	#[cfg(feature = "threads")]
+	pub async fn modify_message(&self, thread_id: &str, message_id: &str, request: &super::ModifyMessageRequest) -> Result<ApiResponse<super::MessageObject>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("modifyMessage", "POST", &format!("/threads/{}/messages/{}", encode(thread_id), encode(message_id)), &[], Some(body)).await
+	}
+	/// Deletes a message.
+	///
+	/// `DELETE /threads/{thread_id}/messages/{message_id}`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	#[cfg(feature = "threads")]
+	pub async fn delete_message(&self, thread_id: &str, message_id: &str) -> Result<ApiResponse<super::DeleteMessageResponse>, ClientError> {
+		self.json("deleteMessage", "DELETE", &format!("/threads/{}/messages/{}", encode(thread_id), encode(message_id)), &[], None).await
+	}
+	/// Returns a list of runs belonging to a thread.
+	///
+	/// `GET /threads/{thread_id}/runs`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	#[cfg(feature = "threads")]
+	pub async fn list_runs(&self, thread_id: &str, query: &ListRunsQuery) -> Result<ApiResponse<super::ListRunsResponse>, ClientError> {
+		self.json("listRuns", "GET", &format!("/threads/{}/runs", encode(thread_id)), &query.pairs(), None).await
+	}
+	/// Returns a list of runs belonging to a thread.
+	///
+	/// `GET /threads/{thread_id}/runs`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	///
+	/// Streams every item, fetching pages as they're needed.
+	#[cfg(feature = "threads")]
+	pub fn list_runs_all(&self, thread_id: &str, query: &ListRunsQuery) -> Paginated<super::RunObject> {
+		Paginated::new(self.clone(), "listRuns", format!("/threads/{}/runs", encode(thread_id)), query.pairs())
+	}
+	/// Create a run.
+	///
+	/// `POST /threads/{thread_id}/runs`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	#[cfg(feature = "threads")]
+	pub async fn create_run(&self, thread_id: &str, query: &CreateRunQuery, request: &super::CreateRunRequest) -> Result<ApiResponse<super::RunObject>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("createRun", "POST", &format!("/threads/{}/runs", encode(thread_id)), &query.pairs(), Some(body)).await
+	}
+	/// Retrieves a run.
+	///
+	/// `GET /threads/{thread_id}/runs/{run_id}`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	#[cfg(feature = "threads")]
+	pub async fn get_run(&self, thread_id: &str, run_id: &str) -> Result<ApiResponse<super::RunObject>, ClientError> {
+		self.json("getRun", "GET", &format!("/threads/{}/runs/{}", encode(thread_id), encode(run_id)), &[], None).await
+	}
+	/// Modifies a run.
+	///
+	/// `POST /threads/{thread_id}/runs/{run_id}`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	#[cfg(feature = "threads")]
+	pub async fn modify_run(&self, thread_id: &str, run_id: &str, request: &super::ModifyRunRequest) -> Result<ApiResponse<super::RunObject>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("modifyRun", "POST", &format!("/threads/{}/runs/{}", encode(thread_id), encode(run_id)), &[], Some(body)).await
+	}
+	/// Cancels a run that is `in_progress`.
+	///
+	/// `POST /threads/{thread_id}/runs/{run_id}/cancel`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	#[cfg(feature = "threads")]
+	pub async fn cancel_run(&self, thread_id: &str, run_id: &str) -> Result<ApiResponse<super::RunObject>, ClientError> {
+		self.json("cancelRun", "POST", &format!("/threads/{}/runs/{}/cancel", encode(thread_id), encode(run_id)), &[], None).await
+	}
+	/// Returns a list of run steps belonging to a run.
+	///
+	/// `GET /threads/{thread_id}/runs/{run_id}/steps`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	#[cfg(feature = "threads")]
+	pub async fn list_run_steps(&self, thread_id: &str, run_id: &str, query: &ListRunStepsQuery) -> Result<ApiResponse<super::ListRunStepsResponse>, ClientError> {
+		self.json("listRunSteps", "GET", &format!("/threads/{}/runs/{}/steps", encode(thread_id), encode(run_id)), &query.pairs(), None).await
+	}
+	/// Returns a list of run steps belonging to a run.
+	///
+	/// `GET /threads/{thread_id}/runs/{run_id}/steps`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	///
+	/// Streams every item, fetching pages as they're needed.
+	#[cfg(feature = "threads")]
+	pub fn list_run_steps_all(&self, thread_id: &str, run_id: &str, query: &ListRunStepsQuery) -> Paginated<super::RunStepObject> {
+		Paginated::new(self.clone(), "listRunSteps", format!("/threads/{}/runs/{}/steps", encode(thread_id), encode(run_id)), query.pairs())
+	}
+	/// Retrieves a run step.
+	///
+	/// `GET /threads/{thread_id}/runs/{run_id}/steps/{step_id}`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	#[cfg(feature = "threads")]
+	pub async fn get_run_step(&self, thread_id: &str, run_id: &str, step_id: &str, query: &GetRunStepQuery) -> Result<ApiResponse<super::RunStepObject>, ClientError> {
+		self.json("getRunStep", "GET", &format!("/threads/{}/runs/{}/steps/{}", encode(thread_id), encode(run_id), encode(step_id)), &query.pairs(), None).await
+	}
+	/// When a run has the `status: "requires_action"` and `required_action.type` is `submit_tool_outputs`,
+	/// this endpoint can be used to submit the outputs from the tool calls once they're all completed. All
+	/// outputs must be submitted in a single request.
+	///
+	/// `POST /threads/{thread_id}/runs/{run_id}/submit_tool_outputs`
+	///
+	/// **Beta**: sends `OpenAI-Beta: assistants=v2`, which [`Client::with_beta`] can pin to another version.
+	#[cfg(feature = "threads")]
+	pub async fn submit_tool_ouputs_to_run(&self, thread_id: &str, run_id: &str, request: &super::SubmitToolOutputsRunRequest) -> Result<ApiResponse<super::RunObject>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("submitToolOuputsToRun", "POST", &format!("/threads/{}/runs/{}/submit_tool_outputs", encode(thread_id), encode(run_id)), &[], Some(body)).await
+	}
+	/// Creates an intermediate [Upload](https://platform.openai.com/docs/api-reference/uploads/object)
+	/// object
+	/// that you can add [Parts](https://platform.openai.com/docs/api-reference/uploads/part-object) to.
+	/// Currently, an Upload can accept at most 8 GB in total and expires after an
+	/// hour after you create it.
+	///
+	/// Once you complete the Upload, we will create a
+	/// [File](https://platform.openai.com/docs/api-reference/files/object) object that contains all the
+	/// parts
+	/// you uploaded. This File is usable in the rest of our platform as a regular
+	/// File object.
+	///
+	/// For certain `purpose` values, the correct `mime_type` must be specified.
+	/// Please refer to documentation for the
+	/// [supported MIME types for your use
+	/// case](https://platform.openai.com/docs/assistants/tools/file-search#supported-files).
+	///
+	/// For guidance on the proper filename extensions for each purpose, please
+	/// follow the documentation on [creating a
+	/// File](https://platform.openai.com/docs/api-reference/files/create).
+	///
+	/// `POST /uploads`
+	#[cfg(feature = "uploads")]
+	pub async fn create_upload(&self, request: &super::CreateUploadRequest) -> Result<ApiResponse<super::Upload>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("createUpload", "POST", "/uploads", &[], Some(body)).await
+	}
+	/// Cancels the Upload. No Parts may be added after an Upload is cancelled.
+	///
+	/// `POST /uploads/{upload_id}/cancel`
+	#[cfg(feature = "uploads")]
+	pub async fn cancel_upload(&self, upload_id: &str) -> Result<ApiResponse<super::Upload>, ClientError> {
+		self.json("cancelUpload", "POST", &format!("/uploads/{}/cancel", encode(upload_id)), &[], None).await
+	}
+	/// Completes the [Upload](https://platform.openai.com/docs/api-reference/uploads/object).
+	///
+	/// Within the returned Upload object, there is a nested
+	/// [File](https://platform.openai.com/docs/api-reference/files/object) object that is ready to use in
+	/// the rest of the platform.
+	///
+	/// You can specify the order of the Parts by passing in an ordered list of the Part IDs.
+	///
+	/// The number of bytes uploaded upon completion must match the number of bytes initially specified when
+	/// creating the Upload object. No Parts may be added after an Upload is completed.
+	///
+	/// `POST /uploads/{upload_id}/complete`
+	#[cfg(feature = "uploads")]
+	pub async fn complete_upload(&self, upload_id: &str, request: &super::CompleteUploadRequest) -> Result<ApiResponse<super::Upload>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("completeUpload", "POST", &format!("/uploads/{}/complete", encode(upload_id)), &[], Some(body)).await
+	}
+	/// Returns a list of vector stores.
+	///
+	/// `GET /vector_stores`
+	#[cfg(feature = "vector_stores")]
+	pub async fn list_vector_stores(&self, query: &ListVectorStoresQuery) -> Result<ApiResponse<super::ListVectorStoresResponse>, ClientError> {
+		self.json("listVectorStores", "GET", "/vector_stores", &query.pairs(), None).await
+	}
+	/// Returns a list of vector stores.
+	///
+	/// `GET /vector_stores`
+	///
+	/// Streams every item, fetching pages as they're needed.
+	#[cfg(feature = "vector_stores")]
+	pub fn list_vector_stores_all(&self, query: &ListVectorStoresQuery) -> Paginated<super::VectorStoreObject> {
+		Paginated::new(self.clone(), "listVectorStores", "/vector_stores".to_string(), query.pairs())
+	}
+	/// Create a vector store.
+	///
+	/// `POST /vector_stores`
+	#[cfg(feature = "vector_stores")]
+	pub async fn create_vector_store(&self, request: &super::CreateVectorStoreRequest) -> Result<ApiResponse<super::VectorStoreObject>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("createVectorStore", "POST", "/vector_stores", &[], Some(body)).await
+	}
+	/// Retrieves a vector store.
+	///
+	/// `GET /vector_stores/{vector_store_id}`
+	#[cfg(feature = "vector_stores")]
+	pub async fn get_vector_store(&self, vector_store_id: &str) -> Result<ApiResponse<super::VectorStoreObject>, ClientError> {
+		self.json("getVectorStore", "GET", &format!("/vector_stores/{}", encode(vector_store_id)), &[], None).await
+	}
+	/// Modifies a vector store.
+	///
+	/// `POST /vector_stores/{vector_store_id}`
+	#[cfg(feature = "vector_stores")]
+	pub async fn modify_vector_store(&self, vector_store_id: &str, request: &super::UpdateVectorStoreRequest) -> Result<ApiResponse<super::VectorStoreObject>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("modifyVectorStore", "POST", &format!("/vector_stores/{}", encode(vector_store_id)), &[], Some(body)).await
+	}
+	/// Delete a vector store.
+	///
+	/// `DELETE /vector_stores/{vector_store_id}`
+	#[cfg(feature = "vector_stores")]
+	pub async fn delete_vector_store(&self, vector_store_id: &str) -> Result<ApiResponse<super::DeleteVectorStoreResponse>, ClientError> {
+		self.json("deleteVectorStore", "DELETE", &format!("/vector_stores/{}", encode(vector_store_id)), &[], None).await
+	}
+	/// Create a vector store file batch.
+	///
+	/// `POST /vector_stores/{vector_store_id}/file_batches`
+	#[cfg(feature = "vector_stores")]
+	pub async fn create_vector_store_file_batch(&self, vector_store_id: &str, request: &super::CreateVectorStoreFileBatchRequest) -> Result<ApiResponse<super::VectorStoreFileBatchObject>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("createVectorStoreFileBatch", "POST", &format!("/vector_stores/{}/file_batches", encode(vector_store_id)), &[], Some(body)).await
+	}
+	/// Retrieves a vector store file batch.
+	///
+	/// `GET /vector_stores/{vector_store_id}/file_batches/{batch_id}`
+	#[cfg(feature = "vector_stores")]
+	pub async fn get_vector_store_file_batch(&self, vector_store_id: &str, batch_id: &str) -> Result<ApiResponse<super::VectorStoreFileBatchObject>, ClientError> {
+		self.json("getVectorStoreFileBatch", "GET", &format!("/vector_stores/{}/file_batches/{}", encode(vector_store_id), encode(batch_id)), &[], None).await
+	}
+	/// Cancel a vector store file batch. This attempts to cancel the processing of files in this batch as
+	/// soon as possible.
+	///
+	/// `POST /vector_stores/{vector_store_id}/file_batches/{batch_id}/cancel`
+	#[cfg(feature = "vector_stores")]
+	pub async fn cancel_vector_store_file_batch(&self, vector_store_id: &str, batch_id: &str) -> Result<ApiResponse<super::VectorStoreFileBatchObject>, ClientError> {
+		self.json("cancelVectorStoreFileBatch", "POST", &format!("/vector_stores/{}/file_batches/{}/cancel", encode(vector_store_id), encode(batch_id)), &[], None).await
+	}
+	/// Returns a list of vector store files in a batch.
+	///
+	/// `GET /vector_stores/{vector_store_id}/file_batches/{batch_id}/files`
+	#[cfg(feature = "vector_stores")]
+	pub async fn list_files_in_vector_store_batch(&self, vector_store_id: &str, batch_id: &str, query: &ListFilesInVectorStoreBatchQuery) -> Result<ApiResponse<super::ListVectorStoreFilesResponse>, ClientError> {
+		self.json("listFilesInVectorStoreBatch", "GET", &format!("/vector_stores/{}/file_batches/{}/files", encode(vector_store_id), encode(batch_id)), &query.pairs(), None).await
+	}
+	/// Returns a list of vector store files in a batch.
+	///
+	/// `GET /vector_stores/{vector_store_id}/file_batches/{batch_id}/files`
+	///
+	/// Streams every item, fetching pages as they're needed.
+	#[cfg(feature = "vector_stores")]
+	pub fn list_files_in_vector_store_batch_all(&self, vector_store_id: &str, batch_id: &str, query: &ListFilesInVectorStoreBatchQuery) -> Paginated<super::VectorStoreFileObject> {
+		Paginated::new(self.clone(), "listFilesInVectorStoreBatch", format!("/vector_stores/{}/file_batches/{}/files", encode(vector_store_id), encode(batch_id)), query.pairs())
+	}
+	/// Returns a list of vector store files.
+	///
+	/// `GET /vector_stores/{vector_store_id}/files`
+	#[cfg(feature = "vector_stores")]
+	pub async fn list_vector_store_files(&self, vector_store_id: &str, query: &ListVectorStoreFilesQuery) -> Result<ApiResponse<super::ListVectorStoreFilesResponse>, ClientError> {
+		self.json("listVectorStoreFiles", "GET", &format!("/vector_stores/{}/files", encode(vector_store_id)), &query.pairs(), None).await
+	}
+	/// Returns a list of vector store files.
+	///
+	/// `GET /vector_stores/{vector_store_id}/files`
+	///
+	/// Streams every item, fetching pages as they're needed.
+	#[cfg(feature = "vector_stores")]
+	pub fn list_vector_store_files_all(&self, vector_store_id: &str, query: &ListVectorStoreFilesQuery) -> Paginated<super::VectorStoreFileObject> {
+		Paginated::new(self.clone(), "listVectorStoreFiles", format!("/vector_stores/{}/files", encode(vector_store_id)), query.pairs())
+	}
+	/// Create a vector store file by attaching a
+	/// [File](https://platform.openai.com/docs/api-reference/files) to a [vector
+	/// store](https://platform.openai.com/docs/api-reference/vector-stores/object).
+	///
+	/// `POST /vector_stores/{vector_store_id}/files`
+	#[cfg(feature = "vector_stores")]
+	pub async fn create_vector_store_file(&self, vector_store_id: &str, request: &super::CreateVectorStoreFileRequest) -> Result<ApiResponse<super::VectorStoreFileObject>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("createVectorStoreFile", "POST", &format!("/vector_stores/{}/files", encode(vector_store_id)), &[], Some(body)).await
+	}
+	/// Retrieves a vector store file.
+	///
+	/// `GET /vector_stores/{vector_store_id}/files/{file_id}`
+	#[cfg(feature = "vector_stores")]
+	pub async fn get_vector_store_file(&self, vector_store_id: &str, file_id: &str) -> Result<ApiResponse<super::VectorStoreFileObject>, ClientError> {
+		self.json("getVectorStoreFile", "GET", &format!("/vector_stores/{}/files/{}", encode(vector_store_id), encode(file_id)), &[], None).await
+	}
+	/// Update attributes on a vector store file.
+	///
+	/// `POST /vector_stores/{vector_store_id}/files/{file_id}`
+	#[cfg(feature = "vector_stores")]
+	pub async fn update_vector_store_file_attributes(&self, vector_store_id: &str, file_id: &str, request: &super::UpdateVectorStoreFileAttributesRequest) -> Result<ApiResponse<super::VectorStoreFileObject>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("updateVectorStoreFileAttributes", "POST", &format!("/vector_stores/{}/files/{}", encode(vector_store_id), encode(file_id)), &[], Some(body)).await
+	}
+	/// Delete a vector store file. This will remove the file from the vector store but the file itself will
+	/// not be deleted. To delete the file, use the [delete
+	/// file](https://platform.openai.com/docs/api-reference/files/delete) endpoint.
+	///
+	/// `DELETE /vector_stores/{vector_store_id}/files/{file_id}`
+	#[cfg(feature = "vector_stores")]
+	pub async fn delete_vector_store_file(&self, vector_store_id: &str, file_id: &str) -> Result<ApiResponse<super::DeleteVectorStoreFileResponse>, ClientError> {
+		self.json("deleteVectorStoreFile", "DELETE", &format!("/vector_stores/{}/files/{}", encode(vector_store_id), encode(file_id)), &[], None).await
+	}
+	/// Retrieve the parsed contents of a vector store file.
+	///
+	/// `GET /vector_stores/{vector_store_id}/files/{file_id}/content`
+	#[cfg(feature = "vector_stores")]
+	pub async fn retrieve_vector_store_file_content(&self, vector_store_id: &str, file_id: &str) -> Result<ApiResponse<super::VectorStoreFileContentResponse>, ClientError> {
+		self.json("retrieveVectorStoreFileContent", "GET", &format!("/vector_stores/{}/files/{}/content", encode(vector_store_id), encode(file_id)), &[], None).await
+	}
+	/// Search a vector store for relevant chunks based on a query and file attributes filter.
+	///
+	/// `POST /vector_stores/{vector_store_id}/search`
+	#[cfg(feature = "vector_stores")]
+	pub async fn search_vector_store(&self, vector_store_id: &str, request: &super::VectorStoreSearchRequest) -> Result<ApiResponse<super::VectorStoreSearchResultsPage>, ClientError> {
+		let body = serde_json::to_vec(request)?;
+		self.json("searchVectorStore", "POST", &format!("/vector_stores/{}/search", encode(vector_store_id)), &[], Some(body)).await
+	}
+}
+
+/// The query parameters of [`Client::list_assistants`]
+#[cfg(feature = "assistants")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListAssistantsQuery {
+	/// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default
+	/// is 20.
+	pub limit: Option<i64>,
+	/// Sort order by the `created_at` timestamp of the objects. `asc` for ascending order and `desc` for
+	/// descending order.
+	pub order: Option<String>,
+	/// A cursor for use in pagination. `after` is an object ID that defines your place in the list. For
+	/// instance, if you make a list request and receive 100 objects, ending with obj_foo, your subsequent
+	/// call can include after=obj_foo in order to fetch the next page of the list.
+	pub after: Option<String>,
+	/// A cursor for use in pagination. `before` is an object ID that defines your place in the list. For
+	/// instance, if you make a list request and receive 100 objects, starting with obj_foo, your subsequent
+	/// call can include before=obj_foo in order to fetch the previous page of the list.
+	pub before: Option<String>,
+}
+#[cfg(feature = "assistants")]
+impl ListAssistantsQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.order {
+			pairs.push(("order", value.to_string()));
+		}
+		if let Some(ref value) = self.after {
+			pairs.push(("after", value.to_string()));
+		}
+		if let Some(ref value) = self.before {
+			pairs.push(("before", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::list_batches`]
+#[cfg(feature = "batch")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListBatchesQuery {
+	/// A cursor for use in pagination. `after` is an object ID that defines your place in the list. For
+	/// instance, if you make a list request and receive 100 objects, ending with obj_foo, your subsequent
+	/// call can include after=obj_foo in order to fetch the next page of the list.
+	pub after: Option<String>,
+	/// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default
+	/// is 20.
+	pub limit: Option<i64>,
+}
+#[cfg(feature = "batch")]
+impl ListBatchesQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		if let Some(ref value) = self.after {
+			pairs.push(("after", value.to_string()));
+		}
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::list_chat_completions`]
+#[cfg(feature = "chat")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListChatCompletionsQuery {
+	/// The model used to generate the Chat Completions.
+	pub model: Option<String>,
+	/// Identifier for the last chat completion from the previous pagination request.
+	pub after: Option<String>,
+	/// Number of Chat Completions to retrieve.
+	pub limit: Option<i64>,
+	/// Sort order for Chat Completions by timestamp. Use `asc` for ascending order or `desc` for descending
+	/// order. Defaults to `asc`.
+	pub order: Option<String>,
+}
+#[cfg(feature = "chat")]
+impl ListChatCompletionsQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		if let Some(ref value) = self.model {
+			pairs.push(("model", value.to_string()));
+		}
+		if let Some(ref value) = self.after {
+			pairs.push(("after", value.to_string()));
+		}
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.order {
+			pairs.push(("order", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::get_chat_completion_messages`]
+#[cfg(feature = "chat")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GetChatCompletionMessagesQuery {
+	/// Identifier for the last message from the previous pagination request.
+	pub after: Option<String>,
+	/// Number of messages to retrieve.
+	pub limit: Option<i64>,
+	/// Sort order for messages by timestamp. Use `asc` for ascending order or `desc` for descending order.
+	/// Defaults to `asc`.
+	pub order: Option<String>,
+}
+#[cfg(feature = "chat")]
+impl GetChatCompletionMessagesQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		if let Some(ref value) = self.after {
+			pairs.push(("after", value.to_string()));
+		}
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.order {
+			pairs.push(("order", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::list_files`]
+#[cfg(all(feature = "files", feature = "shared"))]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListFilesQuery {
+	/// Only return files with the given purpose.
+	pub purpose: Option<String>,
+	/// A limit on the number of objects to be returned. Limit can range between 1 and 10,000, and the
+	/// default is 10,000.
+	pub limit: Option<i64>,
+	/// Sort order by the `created_at` timestamp of the objects. `asc` for ascending order and `desc` for
+	/// descending order.
+	pub order: Option<String>,
+	/// A cursor for use in pagination. `after` is an object ID that defines your place in the list. For
+	/// instance, if you make a list request and receive 100 objects, ending with obj_foo, your subsequent
+	/// call can include after=obj_foo in order to fetch the next page of the list.
+	pub after: Option<String>,
+}
+#[cfg(all(feature = "files", feature = "shared"))]
+impl ListFilesQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		if let Some(ref value) = self.purpose {
+			pairs.push(("purpose", value.to_string()));
+		}
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.order {
+			pairs.push(("order", value.to_string()));
+		}
+		if let Some(ref value) = self.after {
+			pairs.push(("after", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::list_fine_tuning_checkpoint_permissions`]
+#[cfg(feature = "fine_tuning")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListFineTuningCheckpointPermissionsQuery {
+	/// The ID of the project to get permissions for.
+	pub project_id: Option<String>,
+	/// Identifier for the last permission ID from the previous pagination request.
+	pub after: Option<String>,
+	/// Number of permissions to retrieve.
+	pub limit: Option<i64>,
+	/// The order in which to retrieve permissions.
+	pub order: Option<String>,
+}
+#[cfg(feature = "fine_tuning")]
+impl ListFineTuningCheckpointPermissionsQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		if let Some(ref value) = self.project_id {
+			pairs.push(("project_id", value.to_string()));
+		}
+		if let Some(ref value) = self.after {
+			pairs.push(("after", value.to_string()));
+		}
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.order {
+			pairs.push(("order", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::list_paginated_fine_tuning_jobs`]
+#[cfg(feature = "fine_tuning")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListPaginatedFineTuningJobsQuery {
+	/// Identifier for the last job from the previous pagination request.
+	pub after: Option<String>,
+	/// Number of fine-tuning jobs to retrieve.
+	pub limit: Option<i64>,
+}
+#[cfg(feature = "fine_tuning")]
+impl ListPaginatedFineTuningJobsQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		if let Some(ref value) = self.after {
+			pairs.push(("after", value.to_string()));
+		}
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::list_fine_tuning_job_checkpoints`]
+#[cfg(feature = "fine_tuning")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListFineTuningJobCheckpointsQuery {
+	/// Identifier for the last checkpoint ID from the previous pagination request.
+	pub after: Option<String>,
+	/// Number of checkpoints to retrieve.
+	pub limit: Option<i64>,
+}
+#[cfg(feature = "fine_tuning")]
+impl ListFineTuningJobCheckpointsQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		if let Some(ref value) = self.after {
+			pairs.push(("after", value.to_string()));
+		}
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::list_fine_tuning_events`]
+#[cfg(feature = "fine_tuning")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListFineTuningEventsQuery {
+	/// Identifier for the last event from the previous pagination request.
+	pub after: Option<String>,
+	/// Number of events to retrieve.
+	pub limit: Option<i64>,
+}
+#[cfg(feature = "fine_tuning")]
+impl ListFineTuningEventsQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		if let Some(ref value) = self.after {
+			pairs.push(("after", value.to_string()));
+		}
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::admin_api_keys_list`]
+#[cfg(feature = "administration")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AdminApiKeysListQuery {
+	pub after: Option<String>,
+	pub order: Option<String>,
+	pub limit: Option<i64>,
+}
+#[cfg(feature = "administration")]
+impl AdminApiKeysListQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		if let Some(ref value) = self.after {
+			pairs.push(("after", value.to_string()));
+		}
+		if let Some(ref value) = self.order {
+			pairs.push(("order", value.to_string()));
+		}
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::list_audit_logs`]
+#[cfg(feature = "audit_logs")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListAuditLogsQuery {
+	/// Return only events for these projects.
+	pub project_ids: Vec<String>,
+	/// Return only events with a `type` in one of these values. For example, `project.created`. For all
+	/// options, see the documentation for the [audit log
+	/// object](https://platform.openai.com/docs/api-reference/audit-logs/object).
+	pub event_types: Vec<String>,
+	/// Return only events performed by these actors. Can be a user ID, a service account ID, or an api key
+	/// tracking ID.
+	pub actor_ids: Vec<String>,
+	/// Return only events performed by users with these emails.
+	pub actor_emails: Vec<String>,
+	/// Return only events performed on these targets. For example, a project ID updated.
+	pub resource_ids: Vec<String>,
+	/// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default
+	/// is 20.
+	pub limit: Option<i64>,
+	/// A cursor for use in pagination. `after` is an object ID that defines your place in the list. For
+	/// instance, if you make a list request and receive 100 objects, ending with obj_foo, your subsequent
+	/// call can include after=obj_foo in order to fetch the next page of the list.
+	pub after: Option<String>,
+	/// A cursor for use in pagination. `before` is an object ID that defines your place in the list. For
+	/// instance, if you make a list request and receive 100 objects, starting with obj_foo, your subsequent
+	/// call can include before=obj_foo in order to fetch the previous page of the list.
+	pub before: Option<String>,
+}
+#[cfg(feature = "audit_logs")]
+impl ListAuditLogsQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		for value in self.project_ids.iter() {
+			pairs.push(("project_ids[]", value.to_string()));
+		}
+		for value in self.event_types.iter() {
+			pairs.push(("event_types[]", value.to_string()));
+		}
+		for value in self.actor_ids.iter() {
+			pairs.push(("actor_ids[]", value.to_string()));
+		}
+		for value in self.actor_emails.iter() {
+			pairs.push(("actor_emails[]", value.to_string()));
+		}
+		for value in self.resource_ids.iter() {
+			pairs.push(("resource_ids[]", value.to_string()));
+		}
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.after {
+			pairs.push(("after", value.to_string()));
+		}
+		if let Some(ref value) = self.before {
+			pairs.push(("before", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::usage_costs`]
+#[cfg(feature = "shared")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UsageCostsQuery {
+	/// Start time (Unix seconds) of the query time range, inclusive.
+	pub start_time: i64,
+	/// End time (Unix seconds) of the query time range, exclusive.
+	pub end_time: Option<i64>,
+	/// Width of each time bucket in response. Currently only `1d` is supported, default to `1d`.
+	pub bucket_width: Option<String>,
+	/// Return only costs for these projects.
+	pub project_ids: Vec<String>,
+	/// Group the costs by the specified fields. Support fields include `project_id`, `line_item` and any
+	/// combination of them.
+	pub group_by: Vec<String>,
+	/// A limit on the number of buckets to be returned. Limit can range between 1 and 180, and the default
+	/// is 7.
+	pub limit: Option<i64>,
+	/// A cursor for use in pagination. Corresponding to the `next_page` field from the previous response.
+	pub page: Option<String>,
+}
+#[cfg(feature = "shared")]
+impl UsageCostsQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		pairs.push(("start_time", self.start_time.to_string()));
+		if let Some(ref value) = self.end_time {
+			pairs.push(("end_time", value.to_string()));
+		}
+		if let Some(ref value) = self.bucket_width {
+			pairs.push(("bucket_width", value.to_string()));
+		}
+		for value in self.project_ids.iter() {
+			pairs.push(("project_ids", value.to_string()));
+		}
+		for value in self.group_by.iter() {
+			pairs.push(("group_by", value.to_string()));
+		}
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.page {
+			pairs.push(("page", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::list_invites`]
+#[cfg(feature = "administration")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListInvitesQuery {
+	/// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default
+	/// is 20.
+	pub limit: Option<i64>,
+	/// A cursor for use in pagination. `after` is an object ID that defines your place in the list. For
+	/// instance, if you make a list request and receive 100 objects, ending with obj_foo, your subsequent
+	/// call can include after=obj_foo in order to fetch the next page of the list.
+	pub after: Option<String>,
+}
+#[cfg(feature = "administration")]
+impl ListInvitesQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.after {
+			pairs.push(("after", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::list_projects`]
+#[cfg(feature = "administration")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListProjectsQuery {
+	/// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default
+	/// is 20.
+	pub limit: Option<i64>,
+	/// A cursor for use in pagination. `after` is an object ID that defines your place in the list. For
+	/// instance, if you make a list request and receive 100 objects, ending with obj_foo, your subsequent
+	/// call can include after=obj_foo in order to fetch the next page of the list.
+	pub after: Option<String>,
+	/// If `true` returns all projects including those that have been `archived`. Archived projects are not
+	/// included by default.
+	pub include_archived: Option<bool>,
+}
+#[cfg(feature = "administration")]
+impl ListProjectsQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.after {
+			pairs.push(("after", value.to_string()));
+		}
+		if let Some(ref value) = self.include_archived {
+			pairs.push(("include_archived", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::list_project_api_keys`]
+#[cfg(feature = "administration")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListProjectApiKeysQuery {
+	/// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default
+	/// is 20.
+	pub limit: Option<i64>,
+	/// A cursor for use in pagination. `after` is an object ID that defines your place in the list. For
+	/// instance, if you make a list request and receive 100 objects, ending with obj_foo, your subsequent
+	/// call can include after=obj_foo in order to fetch the next page of the list.
+	pub after: Option<String>,
+}
+#[cfg(feature = "administration")]
+impl ListProjectApiKeysQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.after {
+			pairs.push(("after", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::list_project_rate_limits`]
+#[cfg(feature = "administration")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListProjectRateLimitsQuery {
+	/// A limit on the number of objects to be returned. The default is 100.
+	pub limit: Option<i64>,
+	/// A cursor for use in pagination. `after` is an object ID that defines your place in the list. For
+	/// instance, if you make a list request and receive 100 objects, ending with obj_foo, your subsequent
+	/// call can include after=obj_foo in order to fetch the next page of the list.
+	pub after: Option<String>,
+	/// A cursor for use in pagination. `before` is an object ID that defines your place in the list. For
+	/// instance, if you make a list request and receive 100 objects, beginning with obj_foo, your
+	/// subsequent call can include before=obj_foo in order to fetch the previous page of the list.
+	pub before: Option<String>,
+}
+#[cfg(feature = "administration")]
+impl ListProjectRateLimitsQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.after {
+			pairs.push(("after", value.to_string()));
+		}
+		if let Some(ref value) = self.before {
+			pairs.push(("before", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::list_project_service_accounts`]
+#[cfg(feature = "administration")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListProjectServiceAccountsQuery {
+	/// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default
+	/// is 20.
+	pub limit: Option<i64>,
+	/// A cursor for use in pagination. `after` is an object ID that defines your place in the list. For
+	/// instance, if you make a list request and receive 100 objects, ending with obj_foo, your subsequent
+	/// call can include after=obj_foo in order to fetch the next page of the list.
+	pub after: Option<String>,
+}
+#[cfg(feature = "administration")]
+impl ListProjectServiceAccountsQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.after {
+			pairs.push(("after", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::list_project_users`]
+#[cfg(feature = "administration")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListProjectUsersQuery {
+	/// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default
+	/// is 20.
+	pub limit: Option<i64>,
+	/// A cursor for use in pagination. `after` is an object ID that defines your place in the list. For
+	/// instance, if you make a list request and receive 100 objects, ending with obj_foo, your subsequent
+	/// call can include after=obj_foo in order to fetch the next page of the list.
+	pub after: Option<String>,
+}
+#[cfg(feature = "administration")]
+impl ListProjectUsersQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.after {
+			pairs.push(("after", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::usage_audio_speeches`]
+#[cfg(feature = "shared")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UsageAudioSpeechesQuery {
+	/// Start time (Unix seconds) of the query time range, inclusive.
+	pub start_time: i64,
+	/// End time (Unix seconds) of the query time range, exclusive.
+	pub end_time: Option<i64>,
+	/// Width of each time bucket in response. Currently `1m`, `1h` and `1d` are supported, default to `1d`.
+	pub bucket_width: Option<String>,
+	/// Return only usage for these projects.
+	pub project_ids: Vec<String>,
+	/// Return only usage for these users.
+	pub user_ids: Vec<String>,
+	/// Return only usage for these API keys.
+	pub api_key_ids: Vec<String>,
+	/// Return only usage for these models.
+	pub models: Vec<String>,
+	/// Group the usage data by the specified fields. Support fields include `project_id`, `user_id`,
+	/// `api_key_id`, `model` or any combination of them.
+	pub group_by: Vec<String>,
+	/// Specifies the number of buckets to return.
+	/// - `bucket_width=1d`: default: 7, max: 31
+	/// - `bucket_width=1h`: default: 24, max: 168
+	/// - `bucket_width=1m`: default: 60, max: 1440
+	pub limit: Option<i64>,
+	/// A cursor for use in pagination. Corresponding to the `next_page` field from the previous response.
+	pub page: Option<String>,
+}
+#[cfg(feature = "shared")]
+impl UsageAudioSpeechesQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		pairs.push(("start_time", self.start_time.to_string()));
+		if let Some(ref value) = self.end_time {
+			pairs.push(("end_time", value.to_string()));
+		}
+		if let Some(ref value) = self.bucket_width {
+			pairs.push(("bucket_width", value.to_string()));
+		}
+		for value in self.project_ids.iter() {
+			pairs.push(("project_ids", value.to_string()));
+		}
+		for value in self.user_ids.iter() {
+			pairs.push(("user_ids", value.to_string()));
+		}
+		for value in self.api_key_ids.iter() {
+			pairs.push(("api_key_ids", value.to_string()));
+		}
+		for value in self.models.iter() {
+			pairs.push(("models", value.to_string()));
+		}
+		for value in self.group_by.iter() {
+			pairs.push(("group_by", value.to_string()));
+		}
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.page {
+			pairs.push(("page", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::usage_audio_transcriptions`]
+#[cfg(feature = "shared")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UsageAudioTranscriptionsQuery {
+	/// Start time (Unix seconds) of the query time range, inclusive.
+	pub start_time: i64,
+	/// End time (Unix seconds) of the query time range, exclusive.
+	pub end_time: Option<i64>,
+	/// Width of each time bucket in response. Currently `1m`, `1h` and `1d` are supported, default to `1d`.
+	pub bucket_width: Option<String>,
+	/// Return only usage for these projects.
+	pub project_ids: Vec<String>,
+	/// Return only usage for these users.
+	pub user_ids: Vec<String>,
+	/// Return only usage for these API keys.
+	pub api_key_ids: Vec<String>,
+	/// Return only usage for these models.
+	pub models: Vec<String>,
+	/// Group the usage data by the specified fields. Support fields include `project_id`, `user_id`,
+	/// `api_key_id`, `model` or any combination of them.
+	pub group_by: Vec<String>,
+	/// Specifies the number of buckets to return.
+	/// - `bucket_width=1d`: default: 7, max: 31
+	/// - `bucket_width=1h`: default: 24, max: 168
+	/// - `bucket_width=1m`: default: 60, max: 1440
+	pub limit: Option<i64>,
+	/// A cursor for use in pagination. Corresponding to the `next_page` field from the previous response.
+	pub page: Option<String>,
+}
+#[cfg(feature = "shared")]
+impl UsageAudioTranscriptionsQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		pairs.push(("start_time", self.start_time.to_string()));
+		if let Some(ref value) = self.end_time {
+			pairs.push(("end_time", value.to_string()));
+		}
+		if let Some(ref value) = self.bucket_width {
+			pairs.push(("bucket_width", value.to_string()));
+		}
+		for value in self.project_ids.iter() {
+			pairs.push(("project_ids", value.to_string()));
+		}
+		for value in self.user_ids.iter() {
+			pairs.push(("user_ids", value.to_string()));
+		}
+		for value in self.api_key_ids.iter() {
+			pairs.push(("api_key_ids", value.to_string()));
+		}
+		for value in self.models.iter() {
+			pairs.push(("models", value.to_string()));
+		}
+		for value in self.group_by.iter() {
+			pairs.push(("group_by", value.to_string()));
+		}
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.page {
+			pairs.push(("page", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::usage_code_interpreter_sessions`]
+#[cfg(feature = "shared")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UsageCodeInterpreterSessionsQuery {
+	/// Start time (Unix seconds) of the query time range, inclusive.
+	pub start_time: i64,
+	/// End time (Unix seconds) of the query time range, exclusive.
+	pub end_time: Option<i64>,
+	/// Width of each time bucket in response. Currently `1m`, `1h` and `1d` are supported, default to `1d`.
+	pub bucket_width: Option<String>,
+	/// Return only usage for these projects.
+	pub project_ids: Vec<String>,
+	/// Group the usage data by the specified fields. Support fields include `project_id`.
+	pub group_by: Vec<String>,
+	/// Specifies the number of buckets to return.
+	/// - `bucket_width=1d`: default: 7, max: 31
+	/// - `bucket_width=1h`: default: 24, max: 168
+	/// - `bucket_width=1m`: default: 60, max: 1440
+	pub limit: Option<i64>,
+	/// A cursor for use in pagination. Corresponding to the `next_page` field from the previous response.
+	pub page: Option<String>,
+}
+#[cfg(feature = "shared")]
+impl UsageCodeInterpreterSessionsQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		pairs.push(("start_time", self.start_time.to_string()));
+		if let Some(ref value) = self.end_time {
+			pairs.push(("end_time", value.to_string()));
+		}
+		if let Some(ref value) = self.bucket_width {
+			pairs.push(("bucket_width", value.to_string()));
+		}
+		for value in self.project_ids.iter() {
+			pairs.push(("project_ids", value.to_string()));
+		}
+		for value in self.group_by.iter() {
+			pairs.push(("group_by", value.to_string()));
+		}
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.page {
+			pairs.push(("page", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::usage_completions`]
+#[cfg(feature = "shared")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UsageCompletionsQuery {
+	/// Start time (Unix seconds) of the query time range, inclusive.
+	pub start_time: i64,
+	/// End time (Unix seconds) of the query time range, exclusive.
+	pub end_time: Option<i64>,
+	/// Width of each time bucket in response. Currently `1m`, `1h` and `1d` are supported, default to `1d`.
+	pub bucket_width: Option<String>,
+	/// Return only usage for these projects.
+	pub project_ids: Vec<String>,
+	/// Return only usage for these users.
+	pub user_ids: Vec<String>,
+	/// Return only usage for these API keys.
+	pub api_key_ids: Vec<String>,
+	/// Return only usage for these models.
+	pub models: Vec<String>,
+	/// If `true`, return batch jobs only. If `false`, return non-batch jobs only. By default, return both.
+	pub batch: Option<bool>,
+	/// Group the usage data by the specified fields. Support fields include `project_id`, `user_id`,
+	/// `api_key_id`, `model`, `batch` or any combination of them.
+	pub group_by: Vec<String>,
+	/// Specifies the number of buckets to return.
+	/// - `bucket_width=1d`: default: 7, max: 31
+	/// - `bucket_width=1h`: default: 24, max: 168
+	/// - `bucket_width=1m`: default: 60, max: 1440
+	pub limit: Option<i64>,
+	/// A cursor for use in pagination. Corresponding to the `next_page` field from the previous response.
+	pub page: Option<String>,
+}
+#[cfg(feature = "shared")]
+impl UsageCompletionsQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		pairs.push(("start_time", self.start_time.to_string()));
+		if let Some(ref value) = self.end_time {
+			pairs.push(("end_time", value.to_string()));
+		}
+		if let Some(ref value) = self.bucket_width {
+			pairs.push(("bucket_width", value.to_string()));
+		}
+		for value in self.project_ids.iter() {
+			pairs.push(("project_ids", value.to_string()));
+		}
+		for value in self.user_ids.iter() {
+			pairs.push(("user_ids", value.to_string()));
+		}
+		for value in self.api_key_ids.iter() {
+			pairs.push(("api_key_ids", value.to_string()));
+		}
+		for value in self.models.iter() {
+			pairs.push(("models", value.to_string()));
+		}
+		if let Some(ref value) = self.batch {
+			pairs.push(("batch", value.to_string()));
+		}
+		for value in self.group_by.iter() {
+			pairs.push(("group_by", value.to_string()));
+		}
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.page {
+			pairs.push(("page", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::usage_embeddings`]
+#[cfg(feature = "shared")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UsageEmbeddingsQuery {
+	/// Start time (Unix seconds) of the query time range, inclusive.
+	pub start_time: i64,
+	/// End time (Unix seconds) of the query time range, exclusive.
+	pub end_time: Option<i64>,
+	/// Width of each time bucket in response. Currently `1m`, `1h` and `1d` are supported, default to `1d`.
+	pub bucket_width: Option<String>,
+	/// Return only usage for these projects.
+	pub project_ids: Vec<String>,
+	/// Return only usage for these users.
+	pub user_ids: Vec<String>,
+	/// Return only usage for these API keys.
+	pub api_key_ids: Vec<String>,
+	/// Return only usage for these models.
+	pub models: Vec<String>,
+	/// Group the usage data by the specified fields. Support fields include `project_id`, `user_id`,
+	/// `api_key_id`, `model` or any combination of them.
+	pub group_by: Vec<String>,
+	/// Specifies the number of buckets to return.
+	/// - `bucket_width=1d`: default: 7, max: 31
+	/// - `bucket_width=1h`: default: 24, max: 168
+	/// - `bucket_width=1m`: default: 60, max: 1440
+	pub limit: Option<i64>,
+	/// A cursor for use in pagination. Corresponding to the `next_page` field from the previous response.
+	pub page: Option<String>,
+}
+#[cfg(feature = "shared")]
+impl UsageEmbeddingsQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		pairs.push(("start_time", self.start_time.to_string()));
+		if let Some(ref value) = self.end_time {
+			pairs.push(("end_time", value.to_string()));
+		}
+		if let Some(ref value) = self.bucket_width {
+			pairs.push(("bucket_width", value.to_string()));
+		}
+		for value in self.project_ids.iter() {
+			pairs.push(("project_ids", value.to_string()));
+		}
+		for value in self.user_ids.iter() {
+			pairs.push(("user_ids", value.to_string()));
+		}
+		for value in self.api_key_ids.iter() {
+			pairs.push(("api_key_ids", value.to_string()));
+		}
+		for value in self.models.iter() {
+			pairs.push(("models", value.to_string()));
+		}
+		for value in self.group_by.iter() {
+			pairs.push(("group_by", value.to_string()));
+		}
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.page {
+			pairs.push(("page", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::usage_images`]
+#[cfg(feature = "shared")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UsageImagesQuery {
+	/// Start time (Unix seconds) of the query time range, inclusive.
+	pub start_time: i64,
+	/// End time (Unix seconds) of the query time range, exclusive.
+	pub end_time: Option<i64>,
+	/// Width of each time bucket in response. Currently `1m`, `1h` and `1d` are supported, default to `1d`.
+	pub bucket_width: Option<String>,
+	/// Return only usages for these sources. Possible values are `image.generation`, `image.edit`,
+	/// `image.variation` or any combination of them.
+	pub sources: Vec<String>,
+	/// Return only usages for these image sizes. Possible values are `256x256`, `512x512`, `1024x1024`,
+	/// `1792x1792`, `1024x1792` or any combination of them.
+	pub sizes: Vec<String>,
+	/// Return only usage for these projects.
+	pub project_ids: Vec<String>,
+	/// Return only usage for these users.
+	pub user_ids: Vec<String>,
+	/// Return only usage for these API keys.
+	pub api_key_ids: Vec<String>,
+	/// Return only usage for these models.
+	pub models: Vec<String>,
+	/// Group the usage data by the specified fields. Support fields include `project_id`, `user_id`,
+	/// `api_key_id`, `model`, `size`, `source` or any combination of them.
+	pub group_by: Vec<String>,
+	/// Specifies the number of buckets to return.
+	/// - `bucket_width=1d`: default: 7, max: 31
+	/// - `bucket_width=1h`: default: 24, max: 168
+	/// - `bucket_width=1m`: default: 60, max: 1440
+	pub limit: Option<i64>,
+	/// A cursor for use in pagination. Corresponding to the `next_page` field from the previous response.
+	pub page: Option<String>,
+}
+#[cfg(feature = "shared")]
+impl UsageImagesQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		pairs.push(("start_time", self.start_time.to_string()));
+		if let Some(ref value) = self.end_time {
+			pairs.push(("end_time", value.to_string()));
+		}
+		if let Some(ref value) = self.bucket_width {
+			pairs.push(("bucket_width", value.to_string()));
+		}
+		for value in self.sources.iter() {
+			pairs.push(("sources", value.to_string()));
+		}
+		for value in self.sizes.iter() {
+			pairs.push(("sizes", value.to_string()));
+		}
+		for value in self.project_ids.iter() {
+			pairs.push(("project_ids", value.to_string()));
+		}
+		for value in self.user_ids.iter() {
+			pairs.push(("user_ids", value.to_string()));
+		}
+		for value in self.api_key_ids.iter() {
+			pairs.push(("api_key_ids", value.to_string()));
+		}
+		for value in self.models.iter() {
+			pairs.push(("models", value.to_string()));
+		}
+		for value in self.group_by.iter() {
+			pairs.push(("group_by", value.to_string()));
+		}
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.page {
+			pairs.push(("page", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::usage_moderations`]
+#[cfg(feature = "shared")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UsageModerationsQuery {
+	/// Start time (Unix seconds) of the query time range, inclusive.
+	pub start_time: i64,
+	/// End time (Unix seconds) of the query time range, exclusive.
+	pub end_time: Option<i64>,
+	/// Width of each time bucket in response. Currently `1m`, `1h` and `1d` are supported, default to `1d`.
+	pub bucket_width: Option<String>,
+	/// Return only usage for these projects.
+	pub project_ids: Vec<String>,
+	/// Return only usage for these users.
+	pub user_ids: Vec<String>,
+	/// Return only usage for these API keys.
+	pub api_key_ids: Vec<String>,
+	/// Return only usage for these models.
+	pub models: Vec<String>,
+	/// Group the usage data by the specified fields. Support fields include `project_id`, `user_id`,
+	/// `api_key_id`, `model` or any combination of them.
+	pub group_by: Vec<String>,
+	/// Specifies the number of buckets to return.
+	/// - `bucket_width=1d`: default: 7, max: 31
+	/// - `bucket_width=1h`: default: 24, max: 168
+	/// - `bucket_width=1m`: default: 60, max: 1440
+	pub limit: Option<i64>,
+	/// A cursor for use in pagination. Corresponding to the `next_page` field from the previous response.
+	pub page: Option<String>,
+}
+#[cfg(feature = "shared")]
+impl UsageModerationsQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		pairs.push(("start_time", self.start_time.to_string()));
+		if let Some(ref value) = self.end_time {
+			pairs.push(("end_time", value.to_string()));
+		}
+		if let Some(ref value) = self.bucket_width {
+			pairs.push(("bucket_width", value.to_string()));
+		}
+		for value in self.project_ids.iter() {
+			pairs.push(("project_ids", value.to_string()));
+		}
+		for value in self.user_ids.iter() {
+			pairs.push(("user_ids", value.to_string()));
+		}
+		for value in self.api_key_ids.iter() {
+			pairs.push(("api_key_ids", value.to_string()));
+		}
+		for value in self.models.iter() {
+			pairs.push(("models", value.to_string()));
+		}
+		for value in self.group_by.iter() {
+			pairs.push(("group_by", value.to_string()));
+		}
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.page {
+			pairs.push(("page", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::usage_vector_stores`]
+#[cfg(feature = "shared")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UsageVectorStoresQuery {
+	/// Start time (Unix seconds) of the query time range, inclusive.
+	pub start_time: i64,
+	/// End time (Unix seconds) of the query time range, exclusive.
+	pub end_time: Option<i64>,
+	/// Width of each time bucket in response. Currently `1m`, `1h` and `1d` are supported, default to `1d`.
+	pub bucket_width: Option<String>,
+	/// Return only usage for these projects.
+	pub project_ids: Vec<String>,
+	/// Group the usage data by the specified fields. Support fields include `project_id`.
+	pub group_by: Vec<String>,
+	/// Specifies the number of buckets to return.
+	/// - `bucket_width=1d`: default: 7, max: 31
+	/// - `bucket_width=1h`: default: 24, max: 168
+	/// - `bucket_width=1m`: default: 60, max: 1440
+	pub limit: Option<i64>,
+	/// A cursor for use in pagination. Corresponding to the `next_page` field from the previous response.
+	pub page: Option<String>,
+}
+#[cfg(feature = "shared")]
+impl UsageVectorStoresQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		pairs.push(("start_time", self.start_time.to_string()));
+		if let Some(ref value) = self.end_time {
+			pairs.push(("end_time", value.to_string()));
+		}
+		if let Some(ref value) = self.bucket_width {
+			pairs.push(("bucket_width", value.to_string()));
+		}
+		for value in self.project_ids.iter() {
+			pairs.push(("project_ids", value.to_string()));
+		}
+		for value in self.group_by.iter() {
+			pairs.push(("group_by", value.to_string()));
+		}
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.page {
+			pairs.push(("page", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::list_users`]
+#[cfg(feature = "administration")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListUsersQuery {
+	/// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default
+	/// is 20.
+	pub limit: Option<i64>,
+	/// A cursor for use in pagination. `after` is an object ID that defines your place in the list. For
+	/// instance, if you make a list request and receive 100 objects, ending with obj_foo, your subsequent
+	/// call can include after=obj_foo in order to fetch the next page of the list.
+	pub after: Option<String>,
+	/// Filter by the email address of users.
+	pub emails: Vec<String>,
+}
+#[cfg(feature = "administration")]
+impl ListUsersQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.after {
+			pairs.push(("after", value.to_string()));
+		}
+		for value in self.emails.iter() {
+			pairs.push(("emails", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::get_response`]
+#[cfg(feature = "responses")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GetResponseQuery {
+	/// Specify additional output data to include in the response. Currently
+	/// supported values are:
+	/// - `file_search_call.results`: Include the search results of
+	///   the file search tool call.
+	/// - `message.input_image.image_url`: Include image urls from the input message.
+	/// - `computer_call_output.output.image_url`: Include image urls from the computer call output.
+	pub include: Vec<String>,
+}
+#[cfg(feature = "responses")]
+impl GetResponseQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		for value in self.include.iter() {
+			pairs.push(("include", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::list_input_items`]
+#[cfg(feature = "responses")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListInputItemsQuery {
+	/// A limit on the number of objects to be returned. Limit can range between
+	/// 1 and 100, and the default is 20.
+	pub limit: Option<i64>,
+	/// The order to return the input items in. Default is `asc`.
+	/// - `asc`: Return the input items in ascending order.
+	/// - `desc`: Return the input items in descending order.
+	pub order: Option<String>,
+	/// An item ID to list items after, used in pagination.
+	pub after: Option<String>,
+	/// An item ID to list items before, used in pagination.
+	pub before: Option<String>,
+}
+#[cfg(feature = "responses")]
+impl ListInputItemsQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.order {
+			pairs.push(("order", value.to_string()));
+		}
+		if let Some(ref value) = self.after {
+			pairs.push(("after", value.to_string()));
+		}
+		if let Some(ref value) = self.before {
+			pairs.push(("before", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::list_messages`]
+#[cfg(feature = "threads")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListMessagesQuery {
+	/// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default
+	/// is 20.
+	pub limit: Option<i64>,
+	/// Sort order by the `created_at` timestamp of the objects. `asc` for ascending order and `desc` for
+	/// descending order.
+	pub order: Option<String>,
+	/// A cursor for use in pagination. `after` is an object ID that defines your place in the list. For
+	/// instance, if you make a list request and receive 100 objects, ending with obj_foo, your subsequent
+	/// call can include after=obj_foo in order to fetch the next page of the list.
+	pub after: Option<String>,
+	/// A cursor for use in pagination. `before` is an object ID that defines your place in the list. For
+	/// instance, if you make a list request and receive 100 objects, starting with obj_foo, your subsequent
+	/// call can include before=obj_foo in order to fetch the previous page of the list.
+	pub before: Option<String>,
+	/// Filter messages by the run ID that generated them.
+	pub run_id: Option<String>,
+}
+#[cfg(feature = "threads")]
+impl ListMessagesQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.order {
+			pairs.push(("order", value.to_string()));
+		}
+		if let Some(ref value) = self.after {
+			pairs.push(("after", value.to_string()));
+		}
+		if let Some(ref value) = self.before {
+			pairs.push(("before", value.to_string()));
+		}
+		if let Some(ref value) = self.run_id {
+			pairs.push(("run_id", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::list_runs`]
+#[cfg(feature = "threads")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListRunsQuery {
+	/// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default
+	/// is 20.
+	pub limit: Option<i64>,
+	/// Sort order by the `created_at` timestamp of the objects. `asc` for ascending order and `desc` for
+	/// descending order.
+	pub order: Option<String>,
+	/// A cursor for use in pagination. `after` is an object ID that defines your place in the list. For
+	/// instance, if you make a list request and receive 100 objects, ending with obj_foo, your subsequent
+	/// call can include after=obj_foo in order to fetch the next page of the list.
+	pub after: Option<String>,
+	/// A cursor for use in pagination. `before` is an object ID that defines your place in the list. For
+	/// instance, if you make a list request and receive 100 objects, starting with obj_foo, your subsequent
+	/// call can include before=obj_foo in order to fetch the previous page of the list.
+	pub before: Option<String>,
+}
+#[cfg(feature = "threads")]
+impl ListRunsQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.order {
+			pairs.push(("order", value.to_string()));
+		}
+		if let Some(ref value) = self.after {
+			pairs.push(("after", value.to_string()));
+		}
+		if let Some(ref value) = self.before {
+			pairs.push(("before", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::create_run`]
+#[cfg(feature = "threads")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CreateRunQuery {
+	/// A list of additional fields to include in the response. Currently the only supported value is
+	/// `step_details.tool_calls[*].file_search.results[*].content` to fetch the file search result content.
+	///
+	/// See the [file search tool
+	/// documentation](https://platform.openai.com/docs/assistants/tools/file-search#customizing-file-search-settings)
+	/// for more information.
+	pub include: Vec<String>,
+}
+#[cfg(feature = "threads")]
+impl CreateRunQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		for value in self.include.iter() {
+			pairs.push(("include[]", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::list_run_steps`]
+#[cfg(feature = "threads")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListRunStepsQuery {
+	/// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default
+	/// is 20.
+	pub limit: Option<i64>,
+	/// Sort order by the `created_at` timestamp of the objects. `asc` for ascending order and `desc` for
+	/// descending order.
+	pub order: Option<String>,
+	/// A cursor for use in pagination. `after` is an object ID that defines your place in the list. For
+	/// instance, if you make a list request and receive 100 objects, ending with obj_foo, your subsequent
+	/// call can include after=obj_foo in order to fetch the next page of the list.
+	pub after: Option<String>,
+	/// A cursor for use in pagination. `before` is an object ID that defines your place in the list. For
+	/// instance, if you make a list request and receive 100 objects, starting with obj_foo, your subsequent
+	/// call can include before=obj_foo in order to fetch the previous page of the list.
+	pub before: Option<String>,
+	/// A list of additional fields to include in the response. Currently the only supported value is
+	/// `step_details.tool_calls[*].file_search.results[*].content` to fetch the file search result content.
+	///
+	/// See the [file search tool
+	/// documentation](https://platform.openai.com/docs/assistants/tools/file-search#customizing-file-search-settings)
+	/// for more information.
+	pub include: Vec<String>,
+}
+#[cfg(feature = "threads")]
+impl ListRunStepsQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.order {
+			pairs.push(("order", value.to_string()));
+		}
+		if let Some(ref value) = self.after {
+			pairs.push(("after", value.to_string()));
+		}
+		if let Some(ref value) = self.before {
+			pairs.push(("before", value.to_string()));
+		}
+		for value in self.include.iter() {
+			pairs.push(("include[]", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::get_run_step`]
+#[cfg(feature = "threads")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GetRunStepQuery {
+	/// A list of additional fields to include in the response. Currently the only supported value is
+	/// `step_details.tool_calls[*].file_search.results[*].content` to fetch the file search result content.
+	///
+	/// See the [file search tool
+	/// documentation](https://platform.openai.com/docs/assistants/tools/file-search#customizing-file-search-settings)
+	/// for more information.
+	pub include: Vec<String>,
+}
+#[cfg(feature = "threads")]
+impl GetRunStepQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		for value in self.include.iter() {
+			pairs.push(("include[]", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::list_vector_stores`]
+#[cfg(feature = "vector_stores")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListVectorStoresQuery {
+	/// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default
+	/// is 20.
+	pub limit: Option<i64>,
+	/// Sort order by the `created_at` timestamp of the objects. `asc` for ascending order and `desc` for
+	/// descending order.
+	pub order: Option<String>,
+	/// A cursor for use in pagination. `after` is an object ID that defines your place in the list. For
+	/// instance, if you make a list request and receive 100 objects, ending with obj_foo, your subsequent
+	/// call can include after=obj_foo in order to fetch the next page of the list.
+	pub after: Option<String>,
+	/// A cursor for use in pagination. `before` is an object ID that defines your place in the list. For
+	/// instance, if you make a list request and receive 100 objects, starting with obj_foo, your subsequent
+	/// call can include before=obj_foo in order to fetch the previous page of the list.
+	pub before: Option<String>,
+}
+#[cfg(feature = "vector_stores")]
+impl ListVectorStoresQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.order {
+			pairs.push(("order", value.to_string()));
+		}
+		if let Some(ref value) = self.after {
+			pairs.push(("after", value.to_string()));
+		}
+		if let Some(ref value) = self.before {
+			pairs.push(("before", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::list_files_in_vector_store_batch`]
+#[cfg(feature = "vector_stores")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListFilesInVectorStoreBatchQuery {
+	/// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default
+	/// is 20.
+	pub limit: Option<i64>,
+	/// Sort order by the `created_at` timestamp of the objects. `asc` for ascending order and `desc` for
+	/// descending order.
+	pub order: Option<String>,
+	/// A cursor for use in pagination. `after` is an object ID that defines your place in the list. For
+	/// instance, if you make a list request and receive 100 objects, ending with obj_foo, your subsequent
+	/// call can include after=obj_foo in order to fetch the next page of the list.
+	pub after: Option<String>,
+	/// A cursor for use in pagination. `before` is an object ID that defines your place in the list. For
+	/// instance, if you make a list request and receive 100 objects, starting with obj_foo, your subsequent
+	/// call can include before=obj_foo in order to fetch the previous page of the list.
+	pub before: Option<String>,
+	/// Filter by file status. One of `in_progress`, `completed`, `failed`, `cancelled`.
+	pub filter: Option<String>,
+}
+#[cfg(feature = "vector_stores")]
+impl ListFilesInVectorStoreBatchQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.order {
+			pairs.push(("order", value.to_string()));
+		}
+		if let Some(ref value) = self.after {
+			pairs.push(("after", value.to_string()));
+		}
+		if let Some(ref value) = self.before {
+			pairs.push(("before", value.to_string()));
+		}
+		if let Some(ref value) = self.filter {
+			pairs.push(("filter", value.to_string()));
+		}
+		pairs
+	}
+}
+/// The query parameters of [`Client::list_vector_store_files`]
+#[cfg(feature = "vector_stores")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListVectorStoreFilesQuery {
+	/// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default
+	/// is 20.
+	pub limit: Option<i64>,
+	/// Sort order by the `created_at` timestamp of the objects. `asc` for ascending order and `desc` for
+	/// descending order.
+	pub order: Option<String>,
+	/// A cursor for use in pagination. `after` is an object ID that defines your place in the list. For
+	/// instance, if you make a list request and receive 100 objects, ending with obj_foo, your subsequent
+	/// call can include after=obj_foo in order to fetch the next page of the list.
+	pub after: Option<String>,
+	/// A cursor for use in pagination. `before` is an object ID that defines your place in the list. For
+	/// instance, if you make a list request and receive 100 objects, starting with obj_foo, your subsequent
+	/// call can include before=obj_foo in order to fetch the previous page of the list.
+	pub before: Option<String>,
+	/// Filter by file status. One of `in_progress`, `completed`, `failed`, `cancelled`.
+	pub filter: Option<String>,
+}
+#[cfg(feature = "vector_stores")]
+impl ListVectorStoreFilesQuery {
+	/// The parameters as they're sent, in order
+	pub fn pairs(&self) -> Vec<(&'static str, String)> {
+		let mut pairs = Vec::new();
+		if let Some(ref value) = self.limit {
+			pairs.push(("limit", value.to_string()));
+		}
+		if let Some(ref value) = self.order {
+			pairs.push(("order", value.to_string()));
+		}
+		if let Some(ref value) = self.after {
+			pairs.push(("after", value.to_string()));
+		}
+		if let Some(ref value) = self.before {
+			pairs.push(("before", value.to_string()));
+		}
+		if let Some(ref value) = self.filter {
+			pairs.push(("filter", value.to_string()));
+		}
+		pairs
+	}
+}
+
+/// The `OpenAI-Beta` header each beta operation sends
+static BETA_HEADERS: &[(&str, &str)] = &[
+	("listAssistants", "assistants=v2"),
+	("createAssistant", "assistants=v2"),
+	("getAssistant", "assistants=v2"),
+	("modifyAssistant", "assistants=v2"),
+	("deleteAssistant", "assistants=v2"),
+	("createThread", "assistants=v2"),
+	("createThreadAndRun", "assistants=v2"),
+	("getThread", "assistants=v2"),
+	("modifyThread", "assistants=v2"),
+	("deleteThread", "assistants=v2"),
+	("listMessages", "assistants=v2"),
+	("createMessage", "assistants=v2"),
+	("getMessage", "assistants=v2"),
+	("modifyMessage", "assistants=v2"),
+	("deleteMessage", "assistants=v2"),
+	("listRuns", "assistants=v2"),
+	("createRun", "assistants=v2"),
+	("getRun", "assistants=v2"),
+	("modifyRun", "assistants=v2"),
+	("cancelRun", "assistants=v2"),
+	("listRunSteps", "assistants=v2"),
+	("getRunStep", "assistants=v2"),
+	("submitToolOuputsToRun", "assistants=v2"),
+];
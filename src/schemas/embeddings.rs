@@ -1,31 +1,63 @@
+use super::aliases::CreateEmbeddingRequestInputArrayArray;
+use super::aliases::CreateEmbeddingRequestInputIntegerArray;
+use super::aliases::CreateEmbeddingRequestInputStringArray;
+use super::aliases::CreateEmbeddingRequestModel;
 
-use std::collections::HashMap;
 use serde::{Serialize, Deserialize};
 
+/// # Examples
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateEmbeddingRequest {
+///     input: CreateEmbeddingRequestInput::String("The food was delicious and the waiter...".to_string()),
+///     model: serde_json::json!("text-embedding-ada-002"),
+///     encoding_format: Some(CreateEmbeddingRequestEncodingFormat::Float),
+///     dimensions: None,
+///     user: None,
+/// };
+/// ```
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateEmbeddingRequest {
-	/// The number of dimensions the resulting output embeddings should have. Only supported in `text-embedding-3` and later models.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub dimensions: Option<i64>,
-	/// The format to return the embeddings in. Can be either `float` or [`base64`](https://pypi.org/project/pybase64/).
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub encoding_format: Option<CreateEmbeddingRequestEncodingFormat>,
-	/// Input text to embed, encoded as a string or array of tokens. To embed multiple inputs in a single request, pass an array of strings or array of token arrays. The input must not exceed the max input tokens for the model (8192 tokens for `text-embedding-ada-002`), cannot be an empty string, and any array must be 2048 dimensions or less. [Example Python code](https://cookbook.openai.com/examples/how_to_count_tokens_with_tiktoken) for counting tokens. Some models may also impose a limit on total number of tokens summed across inputs.
+	/// Input text to embed, encoded as a string or array of tokens. To embed multiple inputs in a single
+	/// request, pass an array of strings or array of token arrays. The input must not exceed the max input
+	/// tokens for the model (8192 tokens for `text-embedding-ada-002`), cannot be an empty string, and any
+	/// array must be 2048 dimensions or less. [Example Python
+	/// code](https://cookbook.openai.com/examples/how_to_count_tokens_with_tiktoken) for counting tokens.
+	/// Some models may also impose a limit on total number of tokens summed across inputs.
 	pub input: CreateEmbeddingRequestInput,
-	/// ID of the model to use. You can use the [List models](https://platform.openai.com/docs/api-reference/models/list) API to see all of your available models, or see our [Model overview](https://platform.openai.com/docs/models) for descriptions of them.
+	/// ID of the model to use. You can use the [List
+	/// models](https://platform.openai.com/docs/api-reference/models/list) API to see all of your available
+	/// models, or see our [Model overview](https://platform.openai.com/docs/models) for descriptions of
+	/// them.
 	pub model: CreateEmbeddingRequestModel,
-	/// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse. [Learn more](https://platform.openai.com/docs/guides/safety-best-practices#end-user-ids).
+	/// The format to return the embeddings in. Can be either `float` or
+	/// [`base64`](https://pypi.org/project/pybase64/).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub encoding_format: Option<CreateEmbeddingRequestEncodingFormat>,
+	/// The number of dimensions the resulting output embeddings should have. Only supported in
+	/// `text-embedding-3` and later models.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub dimensions: Option<i64>,
+	/// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse.
+	/// [Learn more](https://platform.openai.com/docs/guides/safety-best-practices#end-user-ids).
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub user: Option<String>,
 }
-/// The format to return the embeddings in. Can be either `float` or [`base64`](https://pypi.org/project/pybase64/).
+/// The format to return the embeddings in. Can be either `float` or
+/// [`base64`](https://pypi.org/project/pybase64/).
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum CreateEmbeddingRequestEncodingFormat {
 	Float,
 	Base64,
 }
-/// Input text to embed, encoded as a string or array of tokens. To embed multiple inputs in a single request, pass an array of strings or array of token arrays. The input must not exceed the max input tokens for the model (8192 tokens for `text-embedding-ada-002`), cannot be an empty string, and any array must be 2048 dimensions or less. [Example Python code](https://cookbook.openai.com/examples/how_to_count_tokens_with_tiktoken) for counting tokens. Some models may also impose a limit on total number of tokens summed across inputs.
+/// Input text to embed, encoded as a string or array of tokens. To embed multiple inputs in a single
+/// request, pass an array of strings or array of token arrays. The input must not exceed the max input
+/// tokens for the model (8192 tokens for `text-embedding-ada-002`), cannot be an empty string, and any
+/// array must be 2048 dimensions or less. [Example Python
+/// code](https://cookbook.openai.com/examples/how_to_count_tokens_with_tiktoken) for counting tokens.
+/// Some models may also impose a limit on total number of tokens summed across inputs.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
 #[serde(untagged)]
@@ -35,3 +67,45 @@ pub enum CreateEmbeddingRequestInput {
 	CreateEmbeddingRequestInputIntegerArray(CreateEmbeddingRequestInputIntegerArray),
 	CreateEmbeddingRequestInputArrayArray(CreateEmbeddingRequestInputArrayArray),
 }
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CreateEmbeddingResponse {
+	/// The list of embeddings generated by the model.
+	pub data: Vec<Embedding>,
+	/// The name of the model used to generate the embedding.
+	pub model: String,
+	/// The object type, which is always "list".
+	pub object: CreateEmbeddingResponseObject,
+	/// The usage information for the request.
+	pub usage: CreateEmbeddingResponseUsage,
+}
+/// The object type, which is always "list".
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CreateEmbeddingResponseObject {
+	List,
+}
+/// The usage information for the request.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CreateEmbeddingResponseUsage {
+	/// The number of tokens used by the prompt.
+	pub prompt_tokens: i64,
+	/// The total number of tokens used by the request.
+	pub total_tokens: i64,
+}
+/// Represents an embedding vector returned by embedding endpoint.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Embedding {
+	/// The index of the embedding in the list of embeddings.
+	pub index: i64,
+	/// The embedding vector, which is a list of floats. The length of vector depends on the model as listed
+	/// in the [embedding guide](https://platform.openai.com/docs/guides/embeddings).
+	pub embedding: Vec<f64>,
+	/// The object type, which is always "embedding".
+	pub object: EmbeddingObject,
+}
+/// The object type, which is always "embedding".
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum EmbeddingObject {
+	Embedding,
+}
@@ -0,0 +1,23 @@
+use super::shared::OpenAIFile;
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DeleteFileResponse {
+	pub id: String,
+	pub object: DeleteFileResponseObject,
+	pub deleted: bool,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DeleteFileResponseObject {
+	File,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ListFilesResponse {
+	pub object: String,
+	pub data: Vec<OpenAIFile>,
+	pub first_id: String,
+	pub last_id: String,
+	pub has_more: bool,
+}
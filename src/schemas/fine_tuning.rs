@@ -1,62 +1,146 @@
+use super::aliases::CreateFineTuningJobRequestModel;
+use super::aliases::Metadata;
 
-use std::collections::HashMap;
 use serde::{Serialize, Deserialize};
 
+/// # Examples
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateFineTuningCheckpointPermissionRequest {
+///     project_ids: vec![
+///         "proj_abGMw1llN8IrBb6SvvY5A1iH".to_string(),
+///     ],
+/// };
+/// ```
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateFineTuningCheckpointPermissionRequest {
 	/// The project identifiers to grant access to.
 	pub project_ids: Vec<String>,
 }
+/// # Examples
+///
+/// Default:
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateFineTuningJobRequest {
+///     model: serde_json::json!("gpt-4o-mini"),
+///     training_file: "file-BK7bzQj3FfZFXr7DbL6xJwfo".to_string(),
+///     hyperparameters: None,
+///     suffix: None,
+///     validation_file: None,
+///     integrations: None,
+///     seed: None,
+///     method: None,
+///     metadata: None,
+/// };
+/// ```
+///
+/// Epochs:
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateFineTuningJobRequest {
+///     model: serde_json::json!("gpt-4o-mini"),
+///     training_file: "file-abc123".to_string(),
+///     hyperparameters: None,
+///     suffix: None,
+///     validation_file: None,
+///     integrations: None,
+///     seed: None,
+///     method: Some(FineTuneMethod {
+///         r#type: Some(FineTuneMethodType::Supervised),
+///         supervised: Some(FineTuneSupervisedMethod {
+///             hyperparameters: Some(FineTuneSupervisedMethodHyperparameters {
+///                 n_epochs: Some(FineTuneSupervisedMethodHyperparametersNEpochs::Integer(2)),
+///                 ..Default::default()
+///             }),
+///         }),
+///         ..Default::default()
+///     }),
+///     metadata: None,
+/// };
+/// ```
+///
+/// Validation file:
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateFineTuningJobRequest {
+///     model: serde_json::json!("gpt-4o-mini"),
+///     training_file: "file-abc123".to_string(),
+///     hyperparameters: None,
+///     suffix: None,
+///     validation_file: Some("file-abc123".to_string()),
+///     integrations: None,
+///     seed: None,
+///     method: None,
+///     metadata: None,
+/// };
+/// ```
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateFineTuningJobRequest {
-	/// The hyperparameters used for the fine-tuning job.
-	/// This value is now deprecated in favor of `method`, and should be passed in under the `method` parameter.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub hyperparameters: Option<CreateFineTuningJobRequestHyperparameters>,
-	/// A list of integrations to enable for your fine-tuning job.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub integrations: Option<Vec<CreateFineTuningJobRequestIntegrationsItem>>,
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub metadata: Option<Metadata>,
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub method: Option<FineTuneMethod>,
 	/// The name of the model to fine-tune. You can select one of the
-	/// [supported models](https://platform.openai.com/docs/guides/fine-tuning#which-models-can-be-fine-tuned).
+	/// [supported
+	/// models](https://platform.openai.com/docs/guides/fine-tuning#which-models-can-be-fine-tuned).
 	pub model: CreateFineTuningJobRequestModel,
-	/// The seed controls the reproducibility of the job. Passing in the same seed and job parameters should produce the same results, but may differ in rare cases.
-	/// If a seed is not specified, one will be generated for you.
+	/// The ID of an uploaded file that contains training data.
+	///
+	/// See [upload file](https://platform.openai.com/docs/api-reference/files/create) for how to upload a
+	/// file.
+	///
+	/// Your dataset must be formatted as a JSONL file. Additionally, you must upload your file with the
+	/// purpose `fine-tune`.
+	///
+	/// The contents of the file should differ depending on if the model uses the
+	/// [chat](https://platform.openai.com/docs/api-reference/fine-tuning/chat-input),
+	/// [completions](https://platform.openai.com/docs/api-reference/fine-tuning/completions-input) format,
+	/// or if the fine-tuning method uses the
+	/// [preference](https://platform.openai.com/docs/api-reference/fine-tuning/preference-input) format.
+	///
+	/// See the [fine-tuning guide](https://platform.openai.com/docs/guides/fine-tuning) for more details.
+	pub training_file: String,
+	/// The hyperparameters used for the fine-tuning job.
+	/// This value is now deprecated in favor of `method`, and should be passed in under the `method`
+	/// parameter.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub seed: Option<i64>,
+	pub hyperparameters: Option<CreateFineTuningJobRequestHyperparameters>,
 	/// A string of up to 64 characters that will be added to your fine-tuned model name.
-	/// 
-	/// For example, a `suffix` of "custom-model-name" would produce a model name like `ft:gpt-4o-mini:openai:custom-model-name:7p4lURel`.
+	///
+	/// For example, a `suffix` of "custom-model-name" would produce a model name like
+	/// `ft:gpt-4o-mini:openai:custom-model-name:7p4lURel`.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub suffix: Option<String>,
-	/// The ID of an uploaded file that contains training data.
-	/// 
-	/// See [upload file](https://platform.openai.com/docs/api-reference/files/create) for how to upload a file.
-	/// 
-	/// Your dataset must be formatted as a JSONL file. Additionally, you must upload your file with the purpose `fine-tune`.
-	/// 
-	/// The contents of the file should differ depending on if the model uses the [chat](https://platform.openai.com/docs/api-reference/fine-tuning/chat-input), [completions](https://platform.openai.com/docs/api-reference/fine-tuning/completions-input) format, or if the fine-tuning method uses the [preference](https://platform.openai.com/docs/api-reference/fine-tuning/preference-input) format.
-	/// 
-	/// See the [fine-tuning guide](https://platform.openai.com/docs/guides/fine-tuning) for more details.
-	pub training_file: String,
 	/// The ID of an uploaded file that contains validation data.
-	/// 
+	///
 	/// If you provide this file, the data is used to generate validation
 	/// metrics periodically during fine-tuning. These metrics can be viewed in
 	/// the fine-tuning results file.
 	/// The same data should not be present in both train and validation files.
-	/// 
-	/// Your dataset must be formatted as a JSONL file. You must upload your file with the purpose `fine-tune`.
-	/// 
+	///
+	/// Your dataset must be formatted as a JSONL file. You must upload your file with the purpose
+	/// `fine-tune`.
+	///
 	/// See the [fine-tuning guide](https://platform.openai.com/docs/guides/fine-tuning) for more details.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub validation_file: Option<String>,
+	/// A list of integrations to enable for your fine-tuning job.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub integrations: Option<Vec<CreateFineTuningJobRequestIntegrationsItem>>,
+	/// The seed controls the reproducibility of the job. Passing in the same seed and job parameters should
+	/// produce the same results, but may differ in rare cases.
+	/// If a seed is not specified, one will be generated for you.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub seed: Option<i64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub method: Option<FineTuneMethod>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub metadata: Option<Metadata>,
 }
 /// The hyperparameters used for the fine-tuning job.
-/// This value is now deprecated in favor of `method`, and should be passed in under the `method` parameter.
+/// This value is now deprecated in favor of `method`, and should be passed in under the `method`
+/// parameter.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct CreateFineTuningJobRequestHyperparameters {
 	/// Number of examples in each batch. A larger batch size means that model parameters
@@ -120,20 +204,40 @@ pub enum CreateFineTuningJobRequestIntegrationsItemType {
 /// to your run, and set a default entity (team, username, etc) to be associated with your run.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateFineTuningJobRequestIntegrationsItemWandb {
-	/// The entity to use for the run. This allows you to set the team or username of the WandB user that you would
-	/// like associated with the run. If not set, the default entity for the registered WandB API key is used.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub entity: Option<String>,
+	/// The name of the project that the new run will be created under.
+	pub project: String,
 	/// A display name to set for the run. If not set, we will use the Job ID as the name.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub name: Option<String>,
-	/// The name of the project that the new run will be created under.
-	pub project: String,
-	/// A list of tags to be attached to the newly created run. These tags are passed through directly to WandB. Some
-	/// default tags are generated by OpenAI: "openai/finetune", "openai/{base-model}", "openai/{ftjob-abcdef}".
+	/// The entity to use for the run. This allows you to set the team or username of the WandB user that
+	/// you would
+	/// like associated with the run. If not set, the default entity for the registered WandB API key is
+	/// used.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub entity: Option<String>,
+	/// A list of tags to be attached to the newly created run. These tags are passed through directly to
+	/// WandB. Some
+	/// default tags are generated by OpenAI: "openai/finetune", "openai/{base-model}",
+	/// "openai/{ftjob-abcdef}".
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub tags: Option<Vec<String>>,
 }
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DeleteFineTuningCheckpointPermissionResponse {
+	/// The ID of the fine-tuned model checkpoint permission that was deleted.
+	pub id: String,
+	/// The object type, which is always "checkpoint.permission".
+	pub object: DeleteFineTuningCheckpointPermissionResponseObject,
+	/// Whether the fine-tuned model checkpoint permission was successfully deleted.
+	pub deleted: bool,
+}
+/// The object type, which is always "checkpoint.permission".
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DeleteFineTuningCheckpointPermissionResponseObject {
+	#[serde(rename = "checkpoint.permission")]
+	CheckpointPermission,
+}
 /// Configuration for the DPO fine-tuning method.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct FineTuneDPOMethod {
@@ -144,20 +248,24 @@ pub struct FineTuneDPOMethod {
 /// The hyperparameters used for the fine-tuning job.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct FineTuneDPOMethodHyperparameters {
-	/// Number of examples in each batch. A larger batch size means that model parameters are updated less frequently, but with lower variance.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub batch_size: Option<FineTuneDPOMethodHyperparametersBatchSize>,
-	/// The beta value for the DPO method. A higher beta value will increase the weight of the penalty between the policy and reference model.
+	/// The beta value for the DPO method. A higher beta value will increase the weight of the penalty
+	/// between the policy and reference model.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub beta: Option<FineTuneDPOMethodHyperparametersBeta>,
+	/// Number of examples in each batch. A larger batch size means that model parameters are updated less
+	/// frequently, but with lower variance.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub batch_size: Option<FineTuneDPOMethodHyperparametersBatchSize>,
 	/// Scaling factor for the learning rate. A smaller learning rate may be useful to avoid overfitting.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub learning_rate_multiplier: Option<FineTuneDPOMethodHyperparametersLearningRateMultiplier>,
-	/// The number of epochs to train the model for. An epoch refers to one full cycle through the training dataset.
+	/// The number of epochs to train the model for. An epoch refers to one full cycle through the training
+	/// dataset.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub n_epochs: Option<FineTuneDPOMethodHyperparametersNEpochs>,
 }
-/// Number of examples in each batch. A larger batch size means that model parameters are updated less frequently, but with lower variance.
+/// Number of examples in each batch. A larger batch size means that model parameters are updated less
+/// frequently, but with lower variance.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
 #[serde(untagged)]
@@ -165,7 +273,8 @@ pub enum FineTuneDPOMethodHyperparametersBatchSize {
 	Auto(String),
 	Integer(i64),
 }
-/// The beta value for the DPO method. A higher beta value will increase the weight of the penalty between the policy and reference model.
+/// The beta value for the DPO method. A higher beta value will increase the weight of the penalty
+/// between the policy and reference model.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
 #[serde(untagged)]
@@ -181,7 +290,8 @@ pub enum FineTuneDPOMethodHyperparametersLearningRateMultiplier {
 	Auto(String),
 	Number(f64),
 }
-/// The number of epochs to train the model for. An epoch refers to one full cycle through the training dataset.
+/// The number of epochs to train the model for. An epoch refers to one full cycle through the training
+/// dataset.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
 #[serde(untagged)]
@@ -192,13 +302,13 @@ pub enum FineTuneDPOMethodHyperparametersNEpochs {
 /// The method used for fine-tuning.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct FineTuneMethod {
+	/// The type of method. Is either `supervised` or `dpo`.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub dpo: Option<FineTuneDPOMethod>,
+	pub r#type: Option<FineTuneMethodType>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub supervised: Option<FineTuneSupervisedMethod>,
-	/// The type of method. Is either `supervised` or `dpo`.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub r#type: Option<FineTuneMethodType>,
+	pub dpo: Option<FineTuneDPOMethod>,
 }
 /// The type of method. Is either `supervised` or `dpo`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -217,17 +327,20 @@ pub struct FineTuneSupervisedMethod {
 /// The hyperparameters used for the fine-tuning job.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct FineTuneSupervisedMethodHyperparameters {
-	/// Number of examples in each batch. A larger batch size means that model parameters are updated less frequently, but with lower variance.
+	/// Number of examples in each batch. A larger batch size means that model parameters are updated less
+	/// frequently, but with lower variance.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub batch_size: Option<FineTuneSupervisedMethodHyperparametersBatchSize>,
 	/// Scaling factor for the learning rate. A smaller learning rate may be useful to avoid overfitting.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub learning_rate_multiplier: Option<FineTuneSupervisedMethodHyperparametersLearningRateMultiplier>,
-	/// The number of epochs to train the model for. An epoch refers to one full cycle through the training dataset.
+	/// The number of epochs to train the model for. An epoch refers to one full cycle through the training
+	/// dataset.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub n_epochs: Option<FineTuneSupervisedMethodHyperparametersNEpochs>,
 }
-/// Number of examples in each batch. A larger batch size means that model parameters are updated less frequently, but with lower variance.
+/// Number of examples in each batch. A larger batch size means that model parameters are updated less
+/// frequently, but with lower variance.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
 #[serde(untagged)]
@@ -243,7 +356,8 @@ pub enum FineTuneSupervisedMethodHyperparametersLearningRateMultiplier {
 	Auto(String),
 	Number(f64),
 }
-/// The number of epochs to train the model for. An epoch refers to one full cycle through the training dataset.
+/// The number of epochs to train the model for. An epoch refers to one full cycle through the training
+/// dataset.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
 #[serde(untagged)]
@@ -251,3 +365,334 @@ pub enum FineTuneSupervisedMethodHyperparametersNEpochs {
 	Auto(String),
 	Integer(i64),
 }
+/// The `checkpoint.permission` object represents a permission for a fine-tuned model checkpoint.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FineTuningCheckpointPermission {
+	/// The permission identifier, which can be referenced in the API endpoints.
+	pub id: String,
+	/// The Unix timestamp (in seconds) for when the permission was created.
+	pub created_at: i64,
+	/// The project identifier that the permission is for.
+	pub project_id: String,
+	/// The object type, which is always "checkpoint.permission".
+	pub object: FineTuningCheckpointPermissionObject,
+}
+/// The object type, which is always "checkpoint.permission".
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum FineTuningCheckpointPermissionObject {
+	#[serde(rename = "checkpoint.permission")]
+	CheckpointPermission,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FineTuningIntegration {
+	/// The type of the integration being enabled for the fine-tuning job
+	pub r#type: FineTuningIntegrationType,
+	/// The settings for your integration with Weights and Biases. This payload specifies the project that
+	/// metrics will be sent to. Optionally, you can set an explicit display name for your run, add tags
+	/// to your run, and set a default entity (team, username, etc) to be associated with your run.
+	pub wandb: FineTuningIntegrationWandb,
+}
+/// The type of the integration being enabled for the fine-tuning job
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum FineTuningIntegrationType {
+	Wandb,
+}
+/// The settings for your integration with Weights and Biases. This payload specifies the project that
+/// metrics will be sent to. Optionally, you can set an explicit display name for your run, add tags
+/// to your run, and set a default entity (team, username, etc) to be associated with your run.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FineTuningIntegrationWandb {
+	/// The name of the project that the new run will be created under.
+	pub project: String,
+	/// A display name to set for the run. If not set, we will use the Job ID as the name.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+	/// The entity to use for the run. This allows you to set the team or username of the WandB user that
+	/// you would
+	/// like associated with the run. If not set, the default entity for the registered WandB API key is
+	/// used.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub entity: Option<String>,
+	/// A list of tags to be attached to the newly created run. These tags are passed through directly to
+	/// WandB. Some
+	/// default tags are generated by OpenAI: "openai/finetune", "openai/{base-model}",
+	/// "openai/{ftjob-abcdef}".
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub tags: Option<Vec<String>>,
+}
+/// The `fine_tuning.job` object represents a fine-tuning job that has been created through the API.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FineTuningJob {
+	/// The object identifier, which can be referenced in the API endpoints.
+	pub id: String,
+	/// The Unix timestamp (in seconds) for when the fine-tuning job was created.
+	pub created_at: i64,
+	/// For fine-tuning jobs that have `failed`, this will contain more information on the cause of the
+	/// failure.
+	pub error: Option<FineTuningJobError>,
+	/// The name of the fine-tuned model that is being created. The value will be null if the fine-tuning
+	/// job is still running.
+	pub fine_tuned_model: Option<String>,
+	/// The Unix timestamp (in seconds) for when the fine-tuning job was finished. The value will be null if
+	/// the fine-tuning job is still running.
+	pub finished_at: Option<i64>,
+	/// The hyperparameters used for the fine-tuning job. This value will only be returned when running
+	/// `supervised` jobs.
+	pub hyperparameters: FineTuningJobHyperparameters,
+	/// The base model that is being fine-tuned.
+	pub model: String,
+	/// The object type, which is always "fine_tuning.job".
+	pub object: FineTuningJobObject,
+	/// The organization that owns the fine-tuning job.
+	pub organization_id: String,
+	/// The compiled results file ID(s) for the fine-tuning job. You can retrieve the results with the
+	/// [Files API](https://platform.openai.com/docs/api-reference/files/retrieve-contents).
+	pub result_files: Vec<String>,
+	/// The current status of the fine-tuning job, which can be either `validating_files`, `queued`,
+	/// `running`, `succeeded`, `failed`, or `cancelled`.
+	pub status: FineTuningJobStatus,
+	/// The total number of billable tokens processed by this fine-tuning job. The value will be null if the
+	/// fine-tuning job is still running.
+	pub trained_tokens: Option<i64>,
+	/// The file ID used for training. You can retrieve the training data with the [Files
+	/// API](https://platform.openai.com/docs/api-reference/files/retrieve-contents).
+	pub training_file: String,
+	/// The file ID used for validation. You can retrieve the validation results with the [Files
+	/// API](https://platform.openai.com/docs/api-reference/files/retrieve-contents).
+	pub validation_file: Option<String>,
+	/// A list of integrations to enable for this fine-tuning job.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub integrations: Option<Vec<FineTuningJobItems>>,
+	/// The seed used for the fine-tuning job.
+	pub seed: i64,
+	/// The Unix timestamp (in seconds) for when the fine-tuning job is estimated to finish. The value will
+	/// be null if the fine-tuning job is not running.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub estimated_finish: Option<i64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub method: Option<FineTuneMethod>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub metadata: Option<Metadata>,
+}
+/// The `fine_tuning.job.checkpoint` object represents a model checkpoint for a fine-tuning job that is
+/// ready to use.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FineTuningJobCheckpoint {
+	/// The checkpoint identifier, which can be referenced in the API endpoints.
+	pub id: String,
+	/// The Unix timestamp (in seconds) for when the checkpoint was created.
+	pub created_at: i64,
+	/// The name of the fine-tuned checkpoint model that is created.
+	pub fine_tuned_model_checkpoint: String,
+	/// The step number that the checkpoint was created at.
+	pub step_number: i64,
+	/// Metrics at the step number during the fine-tuning job.
+	pub metrics: FineTuningJobCheckpointMetrics,
+	/// The name of the fine-tuning job that this checkpoint was created from.
+	pub fine_tuning_job_id: String,
+	/// The object type, which is always "fine_tuning.job.checkpoint".
+	pub object: FineTuningJobCheckpointObject,
+}
+/// Metrics at the step number during the fine-tuning job.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct FineTuningJobCheckpointMetrics {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub step: Option<f64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub train_loss: Option<f64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub train_mean_token_accuracy: Option<f64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub valid_loss: Option<f64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub valid_mean_token_accuracy: Option<f64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub full_valid_loss: Option<f64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub full_valid_mean_token_accuracy: Option<f64>,
+}
+/// The object type, which is always "fine_tuning.job.checkpoint".
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum FineTuningJobCheckpointObject {
+	#[serde(rename = "fine_tuning.job.checkpoint")]
+	FineTuningJobCheckpoint,
+}
+/// For fine-tuning jobs that have `failed`, this will contain more information on the cause of the
+/// failure.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FineTuningJobError {
+	/// A machine-readable error code.
+	pub code: String,
+	/// A human-readable error message.
+	pub message: String,
+	/// The parameter that was invalid, usually `training_file` or `validation_file`. This field will be
+	/// null if the failure was not parameter-specific.
+	pub param: Option<String>,
+}
+/// Fine-tuning job event object
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FineTuningJobEvent {
+	/// The object type, which is always "fine_tuning.job.event".
+	pub object: FineTuningJobEventObject,
+	/// The object identifier.
+	pub id: String,
+	/// The Unix timestamp (in seconds) for when the fine-tuning job was created.
+	pub created_at: i64,
+	/// The log level of the event.
+	pub level: FineTuningJobEventLevel,
+	/// The message of the event.
+	pub message: String,
+	/// The type of event.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub r#type: Option<FineTuningJobEventType>,
+	/// The data associated with the event.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub data: Option<serde_json::Value>,
+}
+/// The log level of the event.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum FineTuningJobEventLevel {
+	Info,
+	Warn,
+	Error,
+}
+/// The object type, which is always "fine_tuning.job.event".
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum FineTuningJobEventObject {
+	#[serde(rename = "fine_tuning.job.event")]
+	FineTuningJobEvent,
+}
+/// The type of event.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum FineTuningJobEventType {
+	Message,
+	Metrics,
+}
+/// The hyperparameters used for the fine-tuning job. This value will only be returned when running
+/// `supervised` jobs.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct FineTuningJobHyperparameters {
+	/// Number of examples in each batch. A larger batch size means that model parameters
+	/// are updated less frequently, but with lower variance.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub batch_size: Option<FineTuningJobHyperparametersBatchSize>,
+	/// Scaling factor for the learning rate. A smaller learning rate may be useful to avoid
+	/// overfitting.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub learning_rate_multiplier: Option<FineTuningJobHyperparametersLearningRateMultiplier>,
+	/// The number of epochs to train the model for. An epoch refers to one full cycle
+	/// through the training dataset.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub n_epochs: Option<FineTuningJobHyperparametersNEpochs>,
+}
+/// Number of examples in each batch. A larger batch size means that model parameters
+/// are updated less frequently, but with lower variance.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(untagged)]
+pub enum FineTuningJobHyperparametersBatchSize {
+	Auto(String),
+	Integer(i64),
+}
+/// Scaling factor for the learning rate. A smaller learning rate may be useful to avoid
+/// overfitting.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(untagged)]
+pub enum FineTuningJobHyperparametersLearningRateMultiplier {
+	Auto(String),
+	Number(f64),
+}
+/// The number of epochs to train the model for. An epoch refers to one full cycle
+/// through the training dataset.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(untagged)]
+pub enum FineTuningJobHyperparametersNEpochs {
+	Auto(String),
+	Integer(i64),
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(untagged)]
+pub enum FineTuningJobItems {
+	FineTuningIntegration(FineTuningIntegration),
+}
+/// The object type, which is always "fine_tuning.job".
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum FineTuningJobObject {
+	#[serde(rename = "fine_tuning.job")]
+	FineTuningJob,
+}
+/// The current status of the fine-tuning job, which can be either `validating_files`, `queued`,
+/// `running`, `succeeded`, `failed`, or `cancelled`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum FineTuningJobStatus {
+	#[serde(rename = "validating_files")]
+	ValidatingFiles,
+	Queued,
+	Running,
+	Succeeded,
+	Failed,
+	Cancelled,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ListFineTuningCheckpointPermissionResponse {
+	pub data: Vec<FineTuningCheckpointPermission>,
+	pub object: ListFineTuningCheckpointPermissionResponseObject,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub first_id: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub last_id: Option<String>,
+	pub has_more: bool,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ListFineTuningCheckpointPermissionResponseObject {
+	List,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ListFineTuningJobCheckpointsResponse {
+	pub data: Vec<FineTuningJobCheckpoint>,
+	pub object: ListFineTuningJobCheckpointsResponseObject,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub first_id: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub last_id: Option<String>,
+	pub has_more: bool,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ListFineTuningJobCheckpointsResponseObject {
+	List,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ListFineTuningJobEventsResponse {
+	pub data: Vec<FineTuningJobEvent>,
+	pub object: ListFineTuningJobEventsResponseObject,
+	pub has_more: bool,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ListFineTuningJobEventsResponseObject {
+	List,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ListPaginatedFineTuningJobsResponse {
+	pub data: Vec<FineTuningJob>,
+	pub has_more: bool,
+	pub object: ListPaginatedFineTuningJobsResponseObject,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ListPaginatedFineTuningJobsResponseObject {
+	List,
+}
@@ -0,0 +1,1760 @@
+//! An offline mock of the OpenAI API, for integration tests.
+//!
+//! Every operation in the spec is routed. JSON bodies are checked by
+//!  deserializing them as the generated request type, and answered with
+//!  the spec's example response, or a minimal one synthesized from the
+//!  response schema. Requests with `"stream": true` are answered with the
+//!  example's server-sent events, when the spec has any.
+//!
+//! Responses can be overridden per operation with [`MockServer::respond`],
+//!  or scripted one request at a time with [`MockServer::script`], such
+//!  as to fail twice before succeeding. Every request received is kept
+//!  for [`MockServer::requests`].
+//!
+//! Needs the `axum` and `tokio` crates.
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use axum::body::{Body, Bytes};
+use axum::http::{header, Method, StatusCode, Uri};
+use axum::Router;
+
+/// A response the mock server sends
+#[derive(Debug, Clone)]
+pub enum MockResponse {
+    /// A JSON body, with its status
+    Json(u16, serde_json::Value),
+    /// Server-sent events, each an optional `event:` name and its `data:`
+    Events(Vec<(Option<String>, String)>),
+    /// Any other body, with its status and content type
+    Raw(u16, String, Vec<u8>),
+    /// Another response, with extra headers
+    WithHeaders(Box<MockResponse>, Vec<(String, String)>),
+}
+impl MockResponse {
+    /// A successful JSON response
+    pub fn json ( value: serde_json::Value ) -> Self {
+        MockResponse::Json(200, value)
+    }
+    /// An error in the shape the API sends them
+    pub fn error ( status: u16, message: &str ) -> Self {
+        MockResponse::Json(status, serde_json::json!({
+            "error": {
+                "message": message,
+                "type": "invalid_request_error",
+                "param": null,
+                "code": null
+            }
+        }))
+    }
+    /// The same response, with an extra header such as `retry-after`
+    pub fn with_header ( self, name: &str, value: &str ) -> Self {
+        match self {
+            MockResponse::WithHeaders(response, mut headers) => {
+                headers.push((name.to_string(), value.to_string()));
+                MockResponse::WithHeaders(response, headers)
+            },
+            response => MockResponse::WithHeaders(Box::new(response), vec![(name.to_string(), value.to_string())])
+        }
+    }
+    fn into_response ( self ) -> axum::response::Response {
+        let (status, content_type, body) = match self {
+            MockResponse::WithHeaders(response, headers) => {
+                let mut response = response.into_response();
+                for (name, value) in headers {
+                    if let (Ok(name), Ok(value)) = (header::HeaderName::try_from(name), header::HeaderValue::try_from(value)) {
+                        response.headers_mut().insert(name, value);
+                    }
+                }
+
+                return response;
+            },
+            MockResponse::Json(status, value) => (status, "application/json".to_string(), value.to_string().into_bytes()),
+            MockResponse::Events(events) => {
+                let mut body = String::new();
+                for (name, data) in events {
+                    if let Some(name) = name {
+                        body.push_str(&format!("event: {}\n", name));
+                    }
+                    body.push_str(&format!("data: {}\n\n", data));
+                }
+
+                (200, "text/event-stream".to_string(), body.into_bytes())
+            },
+            MockResponse::Raw(status, content_type, body) => (status, content_type, body)
+        };
+
+        axum::response::Response::builder()
+            .status(StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR))
+            .header(header::CONTENT_TYPE, content_type)
+            .body(Body::from(body))
+            .unwrap_or_default()
+    }
+}
+
+/// A request the mock server received
+#[derive(Debug, Clone)]
+pub struct MockRequest {
+    /// The `operationId` it was routed to
+    pub operation_id: &'static str,
+    /// The HTTP method
+    pub method: String,
+    /// The path, without the `/v1` prefix
+    pub path: String,
+    /// The raw body
+    pub body: Bytes,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    overrides: HashMap<String, MockResponse>,
+    scripts: HashMap<String, VecDeque<MockResponse>>,
+    requests: Vec<MockRequest>,
+}
+
+/// A mock OpenAI API on a local port, which stops when dropped
+#[derive(Debug)]
+pub struct MockServer {
+    address: SocketAddr,
+    state: Arc<Mutex<MockState>>,
+    shutdown: Option<tokio::sync::oneshot::Sender<()>>,
+}
+impl MockServer {
+    /// Starts a mock server on a free local port, on the current runtime
+    pub async fn start () -> std::io::Result<Self> {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let (shutdown, shutdown_signal) = tokio::sync::oneshot::channel::<()>();
+
+        let handler_state = state.clone();
+        let router = Router::new()
+            .fallback(move |method: Method, uri: Uri, body: Bytes| {
+                let state = handler_state.clone();
+                async move { handle(&state, method, uri, body) }
+            });
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, router)
+                .with_graceful_shutdown(async move {
+                    let _ = shutdown_signal.await;
+                })
+                .await;
+        });
+
+        Ok(MockServer { address, state, shutdown: Some(shutdown) })
+    }
+    /// The address the server is listening on
+    pub fn address ( &self ) -> SocketAddr {
+        self.address
+    }
+    /// The base URL to point a client at, ending in `/v1`
+    pub fn base_url ( &self ) -> String {
+        format!("http://{}/v1", self.address)
+    }
+    /// Answers every later request to an operation with `response`
+    pub fn respond ( &self, operation_id: &str, response: MockResponse ) {
+        self.lock().overrides.insert(operation_id.to_string(), response);
+    }
+    /// Answers the next requests to an operation with `responses`, one
+    ///  each and in order, before going back to its usual response
+    pub fn script ( &self, operation_id: &str, responses: Vec<MockResponse> ) {
+        self.lock().scripts.entry(operation_id.to_string())
+            .or_default()
+            .extend(responses);
+    }
+    /// Goes back to answering an operation with its example
+    pub fn reset ( &self, operation_id: &str ) {
+        let mut state = self.lock();
+        state.overrides.remove(operation_id);
+        state.scripts.remove(operation_id);
+    }
+    /// Every request received so far, oldest first
+    pub fn requests ( &self ) -> Vec<MockRequest> {
+        self.lock().requests.clone()
+    }
+    fn lock ( &self ) -> std::sync::MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+impl Drop for MockServer {
+    fn drop ( &mut self ) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+fn handle ( state: &Mutex<MockState>, method: Method, uri: Uri, body: Bytes ) -> axum::response::Response {
+    let path = uri.path();
+    let path = path.strip_prefix("/v1").unwrap_or(path);
+
+    let operation = match find_operation(method.as_str(), path) {
+        Some(operation) => operation,
+        None => {
+            return MockResponse::error(404, &format!("No operation matches {} {}", method, path)).into_response();
+        }
+    };
+
+    let mut state = state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    state.requests.push(MockRequest {
+        operation_id: operation.id,
+        method: method.to_string(),
+        path: path.to_string(),
+        body: body.clone(),
+    });
+
+    if let Some(validate) = operation.validate {
+        if let Err(message) = validate(&body) {
+            return MockResponse::error(400, &format!("Invalid body for {}: {}", operation.id, message)).into_response();
+        }
+    }
+
+    if let Some(response) = state.scripts.get_mut(operation.id).and_then(|script| script.pop_front()) {
+        return response.into_response();
+    }
+    if let Some(response) = state.overrides.get(operation.id) {
+        return response.clone().into_response();
+    }
+
+    // Stream when asked to, if there's an example stream to replay
+    let wants_stream = serde_json::from_slice::<serde_json::Value>(&body)
+        .ok()
+        .and_then(|body| body["stream"].as_bool())
+        .unwrap_or(false);
+    if (wants_stream || operation.response.is_empty()) && !operation.events.is_empty() {
+        return MockResponse::Events(
+            operation.events.iter()
+                .map(|(name, data)| (name.map(|name| name.to_string()), data.to_string()))
+                .collect()
+        ).into_response();
+    }
+
+    MockResponse::Raw(operation.status, operation.content_type.to_string(), operation.response.as_bytes().to_vec())
+        .into_response()
+}
+/// The operation a request is for, preferring literal path segments over
+///  parameters, so `/files/search` wins over `/files/{file_id}`
+fn find_operation ( method: &str, path: &str ) -> Option<&'static MockOperation> {
+    let segments = path.trim_matches('/').split('/').collect::<Vec<&str>>();
+
+    OPERATIONS.iter()
+        .filter(|operation| operation.method == method)
+        .filter_map(|operation| {
+            let pattern = operation.path.trim_matches('/').split('/').collect::<Vec<&str>>();
+            if pattern.len() != segments.len() {
+                return None;
+            }
+
+            let mut literals = 0;
+            for (expected, actual) in pattern.iter().zip(segments.iter()) {
+                if expected.starts_with('{') && expected.ends_with('}') {
+                    continue;
+                }
+                if expected != actual {
+                    return None;
+                }
+                literals += 1;
+            }
+
+            Some((literals, operation))
+        })
+        .max_by_key(|(literals, _operation)| *literals)
+        .map(|(_literals, operation)| operation)
+}
+
+/// Checks a body by deserializing it as the request type
+// Unused when no enabled group has an operation with a typed body
+#[allow(dead_code)]
+fn validate<T: serde::de::DeserializeOwned> ( body: &[u8] ) -> Result<(), String> {
+    serde_json::from_slice::<T>(body)
+        .map(|_| ())
+        .map_err(|error| error.to_string())
+}
+
+/// Checks a request body, describing what's wrong with it
+type Validator = fn(&[u8]) -> Result<(), String>;
+/// An operation the mock server routes
+struct MockOperation {
+    id: &'static str,
+    method: &'static str,
+    path: &'static str,
+    validate: Option<Validator>,
+    status: u16,
+    content_type: &'static str,
+    response: &'static str,
+    events: &'static [(Option<&'static str>, &'static str)],
+}
+
+/// Every operation in the spec
+static OPERATIONS: &[MockOperation] = &[
+	#[cfg(feature = "assistants")]
+	MockOperation {
+		id: "listAssistants",
+		method: "GET",
+		path: "/assistants",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"list","data":[{"id":"asst_abc123","object":"assistant","created_at":1698982736,"name":"Coding Tutor","description":null,"model":"gpt-4o","instructions":"You are a helpful assistant designed to make me better at coding!","tools":[],"tool_resources":{},"metadata":{},"top_p":1.0,"temperature":1.0,"response_format":"auto"},{"id":"asst_abc456","object":"assistant","created_at":1698982718,"name":"My Assistant","description":null,"model":"gpt-4o","instructions":"You are a helpful assistant designed to make me better at coding!","tools":[],"tool_resources":{},"metadata":{},"top_p":1.0,"temperature":1.0,"response_format":"auto"},{"id":"asst_abc789","object":"assistant","created_at":1698982643,"name":null,"description":null,"model":"gpt-4o","instructions":null,"tools":[],"tool_resources":{},"metadata":{},"top_p":1.0,"temperature":1.0,"response_format":"auto"}],"first_id":"asst_abc123","last_id":"asst_abc789","has_more":false}"#,
+		events: &[],
+	},
+	#[cfg(feature = "assistants")]
+	MockOperation {
+		id: "createAssistant",
+		method: "POST",
+		path: "/assistants",
+		validate: Some(validate::<super::CreateAssistantRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"asst_abc123","object":"assistant","created_at":1698984975,"name":"Math Tutor","description":null,"model":"gpt-4o","instructions":"You are a personal math tutor. When asked a question, write and run Python code to answer the question.","tools":[{"type":"code_interpreter"}],"metadata":{},"top_p":1.0,"temperature":1.0,"response_format":"auto"}"#,
+		events: &[],
+	},
+	#[cfg(feature = "assistants")]
+	MockOperation {
+		id: "getAssistant",
+		method: "GET",
+		path: "/assistants/{assistant_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"asst_abc123","object":"assistant","created_at":1699009709,"name":"HR Helper","description":null,"model":"gpt-4o","instructions":"You are an HR bot, and you have access to files to answer employee questions about company policies.","tools":[{"type":"file_search"}],"metadata":{},"top_p":1.0,"temperature":1.0,"response_format":"auto"}"#,
+		events: &[],
+	},
+	#[cfg(feature = "assistants")]
+	MockOperation {
+		id: "modifyAssistant",
+		method: "POST",
+		path: "/assistants/{assistant_id}",
+		validate: Some(validate::<super::ModifyAssistantRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"asst_123","object":"assistant","created_at":1699009709,"name":"HR Helper","description":null,"model":"gpt-4o","instructions":"You are an HR bot, and you have access to files to answer employee questions about company policies. Always response with info from either of the files.","tools":[{"type":"file_search"}],"tool_resources":{"file_search":{"vector_store_ids":[]}},"metadata":{},"top_p":1.0,"temperature":1.0,"response_format":"auto"}"#,
+		events: &[],
+	},
+	#[cfg(feature = "assistants")]
+	MockOperation {
+		id: "deleteAssistant",
+		method: "DELETE",
+		path: "/assistants/{assistant_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"asst_abc123","object":"assistant.deleted","deleted":true}"#,
+		events: &[],
+	},
+	#[cfg(feature = "audio")]
+	MockOperation {
+		id: "createSpeech",
+		method: "POST",
+		path: "/audio/speech",
+		validate: Some(validate::<super::CreateSpeechRequest>),
+		status: 200,
+		content_type: "application/octet-stream",
+		response: r"",
+		events: &[],
+	},
+	#[cfg(feature = "audio")]
+	MockOperation {
+		id: "createTranscription",
+		method: "POST",
+		path: "/audio/transcriptions",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"text":"Imagine the wildest idea that you've ever had, and you're curious about how it might scale to something that's a 100, a 1,000 times bigger. This is a place where you can get to do that."}"#,
+		events: &[
+			(None, r#"{"type":"transcript.text.delta","delta":"I","logprobs":[{"token":"I","logprob":-0.00007588794,"bytes":[73]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":" see","logprobs":[{"token":" see","logprob":-3.1281633e-7,"bytes":[32,115,101,101]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":" skies","logprobs":[{"token":" skies","logprob":-2.3392786e-6,"bytes":[32,115,107,105,101,115]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":" of","logprobs":[{"token":" of","logprob":-3.1281633e-7,"bytes":[32,111,102]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":" blue","logprobs":[{"token":" blue","logprob":-1.0280384e-6,"bytes":[32,98,108,117,101]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":" and","logprobs":[{"token":" and","logprob":-0.0005108566,"bytes":[32,97,110,100]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":" clouds","logprobs":[{"token":" clouds","logprob":-1.9361265e-7,"bytes":[32,99,108,111,117,100,115]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":" of","logprobs":[{"token":" of","logprob":-1.9361265e-7,"bytes":[32,111,102]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":" white","logprobs":[{"token":" white","logprob":-7.89631e-7,"bytes":[32,119,104,105,116,101]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":",","logprobs":[{"token":",","logprob":-0.0014890312,"bytes":[44]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":" the","logprobs":[{"token":" the","logprob":-0.0110956915,"bytes":[32,116,104,101]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":" bright","logprobs":[{"token":" bright","logprob":0.0,"bytes":[32,98,114,105,103,104,116]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":" blessed","logprobs":[{"token":" blessed","logprob":-0.000045848617,"bytes":[32,98,108,101,115,115,101,100]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":" days","logprobs":[{"token":" days","logprob":-0.000010802739,"bytes":[32,100,97,121,115]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":",","logprobs":[{"token":",","logprob":-0.00001700133,"bytes":[44]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":" the","logprobs":[{"token":" the","logprob":-0.0000118755715,"bytes":[32,116,104,101]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":" dark","logprobs":[{"token":" dark","logprob":-5.5122365e-7,"bytes":[32,100,97,114,107]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":" sacred","logprobs":[{"token":" sacred","logprob":-5.4385737e-6,"bytes":[32,115,97,99,114,101,100]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":" nights","logprobs":[{"token":" nights","logprob":-4.00813e-6,"bytes":[32,110,105,103,104,116,115]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":",","logprobs":[{"token":",","logprob":-0.0036910512,"bytes":[44]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":" and","logprobs":[{"token":" and","logprob":-0.0031903093,"bytes":[32,97,110,100]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":" I","logprobs":[{"token":" I","logprob":-1.504853e-6,"bytes":[32,73]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":" think","logprobs":[{"token":" think","logprob":-4.3202e-7,"bytes":[32,116,104,105,110,107]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":" to","logprobs":[{"token":" to","logprob":-1.9361265e-7,"bytes":[32,116,111]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":" myself","logprobs":[{"token":" myself","logprob":-1.7432603e-6,"bytes":[32,109,121,115,101,108,102]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":",","logprobs":[{"token":",","logprob":-0.29254505,"bytes":[44]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":" what","logprobs":[{"token":" what","logprob":-0.016815351,"bytes":[32,119,104,97,116]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":" a","logprobs":[{"token":" a","logprob":-3.1281633e-7,"bytes":[32,97]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":" wonderful","logprobs":[{"token":" wonderful","logprob":-2.1008714e-6,"bytes":[32,119,111,110,100,101,114,102,117,108]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":" world","logprobs":[{"token":" world","logprob":-8.180258e-6,"bytes":[32,119,111,114,108,100]}]}"#),
+			(None, r#"{"type":"transcript.text.delta","delta":".","logprobs":[{"token":".","logprob":-0.014231676,"bytes":[46]}]}"#),
+			(None, r#"{"type":"transcript.text.done","text":"I see skies of blue and clouds of white, the bright blessed days, the dark sacred nights, and I think to myself, what a wonderful world.","logprobs":[{"token":"I","logprob":-0.00007588794,"bytes":[73]},{"token":" see","logprob":-3.1281633e-7,"bytes":[32,115,101,101]},{"token":" skies","logprob":-2.3392786e-6,"bytes":[32,115,107,105,101,115]},{"token":" of","logprob":-3.1281633e-7,"bytes":[32,111,102]},{"token":" blue","logprob":-1.0280384e-6,"bytes":[32,98,108,117,101]},{"token":" and","logprob":-0.0005108566,"bytes":[32,97,110,100]},{"token":" clouds","logprob":-1.9361265e-7,"bytes":[32,99,108,111,117,100,115]},{"token":" of","logprob":-1.9361265e-7,"bytes":[32,111,102]},{"token":" white","logprob":-7.89631e-7,"bytes":[32,119,104,105,116,101]},{"token":",","logprob":-0.0014890312,"bytes":[44]},{"token":" the","logprob":-0.0110956915,"bytes":[32,116,104,101]},{"token":" bright","logprob":0.0,"bytes":[32,98,114,105,103,104,116]},{"token":" blessed","logprob":-0.000045848617,"bytes":[32,98,108,101,115,115,101,100]},{"token":" days","logprob":-0.000010802739,"bytes":[32,100,97,121,115]},{"token":",","logprob":-0.00001700133,"bytes":[44]},{"token":" the","logprob":-0.0000118755715,"bytes":[32,116,104,101]},{"token":" dark","logprob":-5.5122365e-7,"bytes":[32,100,97,114,107]},{"token":" sacred","logprob":-5.4385737e-6,"bytes":[32,115,97,99,114,101,100]},{"token":" nights","logprob":-4.00813e-6,"bytes":[32,110,105,103,104,116,115]},{"token":",","logprob":-0.0036910512,"bytes":[44]},{"token":" and","logprob":-0.0031903093,"bytes":[32,97,110,100]},{"token":" I","logprob":-1.504853e-6,"bytes":[32,73]},{"token":" think","logprob":-4.3202e-7,"bytes":[32,116,104,105,110,107]},{"token":" to","logprob":-1.9361265e-7,"bytes":[32,116,111]},{"token":" myself","logprob":-1.7432603e-6,"bytes":[32,109,121,115,101,108,102]},{"token":",","logprob":-0.29254505,"bytes":[44]},{"token":" what","logprob":-0.016815351,"bytes":[32,119,104,97,116]},{"token":" a","logprob":-3.1281633e-7,"bytes":[32,97]},{"token":" wonderful","logprob":-2.1008714e-6,"bytes":[32,119,111,110,100,101,114,102,117,108]},{"token":" world","logprob":-8.180258e-6,"bytes":[32,119,111,114,108,100]},{"token":".","logprob":-0.014231676,"bytes":[46]}]}"#),
+		],
+	},
+	MockOperation {
+		id: "createTranslation",
+		method: "POST",
+		path: "/audio/translations",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"text":"Hello, my name is Wolfgang and I come from Germany. Where are you heading today?"}"#,
+		events: &[],
+	},
+	#[cfg(feature = "batch")]
+	MockOperation {
+		id: "listBatches",
+		method: "GET",
+		path: "/batches",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"data":[],"has_more":false,"object":"list"}"#,
+		events: &[],
+	},
+	#[cfg(feature = "batch")]
+	MockOperation {
+		id: "createBatch",
+		method: "POST",
+		path: "/batches",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"string","object":"batch","endpoint":"string","input_file_id":"string","completion_window":"string","status":"validating","created_at":0}"#,
+		events: &[],
+	},
+	#[cfg(feature = "batch")]
+	MockOperation {
+		id: "retrieveBatch",
+		method: "GET",
+		path: "/batches/{batch_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"string","object":"batch","endpoint":"string","input_file_id":"string","completion_window":"string","status":"validating","created_at":0}"#,
+		events: &[],
+	},
+	#[cfg(feature = "batch")]
+	MockOperation {
+		id: "cancelBatch",
+		method: "POST",
+		path: "/batches/{batch_id}/cancel",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"string","object":"batch","endpoint":"string","input_file_id":"string","completion_window":"string","status":"validating","created_at":0}"#,
+		events: &[],
+	},
+	#[cfg(feature = "chat")]
+	MockOperation {
+		id: "listChatCompletions",
+		method: "GET",
+		path: "/chat/completions",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"list","data":[{"object":"chat.completion","id":"chatcmpl-AyPNinnUqUDYo9SAdA52NobMflmj2","model":"gpt-4o-2024-08-06","created":1738960610,"request_id":"req_ded8ab984ec4bf840f37566c1011c417","tool_choice":null,"usage":{"total_tokens":31,"completion_tokens":18,"prompt_tokens":13},"seed":4944116822809979520,"top_p":1.0,"temperature":1.0,"presence_penalty":0.0,"frequency_penalty":0.0,"system_fingerprint":"fp_50cad350e4","input_user":null,"service_tier":"default","tools":null,"metadata":{},"choices":[{"index":0,"message":{"content":"Mind of circuits hum,  \nLearning patterns in silence—  \nFuture's quiet spark.","role":"assistant","tool_calls":null,"function_call":null},"finish_reason":"stop","logprobs":null}],"response_format":null}],"first_id":"chatcmpl-AyPNinnUqUDYo9SAdA52NobMflmj2","last_id":"chatcmpl-AyPNinnUqUDYo9SAdA52NobMflmj2","has_more":false}"#,
+		events: &[],
+	},
+	#[cfg(feature = "chat")]
+	MockOperation {
+		id: "createChatCompletion",
+		method: "POST",
+		path: "/chat/completions",
+		validate: Some(validate::<super::CreateChatCompletionRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"chatcmpl-B9MBs8CjcvOU2jLn4n570S5qMJKcT","object":"chat.completion","created":1741569952,"model":"gpt-4o-2024-08-06","choices":[{"index":0,"message":{"role":"assistant","content":"Hello! How can I assist you today?","refusal":null,"annotations":[]},"logprobs":null,"finish_reason":"stop"}],"usage":{"prompt_tokens":19,"completion_tokens":10,"total_tokens":29,"prompt_tokens_details":{"cached_tokens":0,"audio_tokens":0},"completion_tokens_details":{"reasoning_tokens":0,"audio_tokens":0,"accepted_prediction_tokens":0,"rejected_prediction_tokens":0}},"service_tier":"default"}"#,
+		events: &[
+			(None, r#"{"id":"chatcmpl-123","object":"chat.completion.chunk","created":1694268190,"model":"gpt-4o-mini","system_fingerprint":"fp_44709d6fcb","choices":[{"index":0,"delta":{"role":"assistant","content":""},"logprobs":null,"finish_reason":null}]}"#),
+			(None, r#"{"id":"chatcmpl-123","object":"chat.completion.chunk","created":1694268190,"model":"gpt-4o-mini","system_fingerprint":"fp_44709d6fcb","choices":[{"index":0,"delta":{"content":"Hello"},"logprobs":null,"finish_reason":null}]}"#),
+			(None, r#"{"id":"chatcmpl-123","object":"chat.completion.chunk","created":1694268190,"model":"gpt-4o-mini","system_fingerprint":"fp_44709d6fcb","choices":[{"index":0,"delta":{},"logprobs":null,"finish_reason":"stop"}]}"#),
+		],
+	},
+	#[cfg(feature = "chat")]
+	MockOperation {
+		id: "getChatCompletion",
+		method: "GET",
+		path: "/chat/completions/{completion_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"chat.completion","id":"chatcmpl-abc123","model":"gpt-4o-2024-08-06","created":1738960610,"request_id":"req_ded8ab984ec4bf840f37566c1011c417","tool_choice":null,"usage":{"total_tokens":31,"completion_tokens":18,"prompt_tokens":13},"seed":4944116822809979520,"top_p":1.0,"temperature":1.0,"presence_penalty":0.0,"frequency_penalty":0.0,"system_fingerprint":"fp_50cad350e4","input_user":null,"service_tier":"default","tools":null,"metadata":{},"choices":[{"index":0,"message":{"content":"Mind of circuits hum,  \nLearning patterns in silence—  \nFuture's quiet spark.","role":"assistant","tool_calls":null,"function_call":null},"finish_reason":"stop","logprobs":null}],"response_format":null}"#,
+		events: &[],
+	},
+	#[cfg(feature = "chat")]
+	MockOperation {
+		id: "updateChatCompletion",
+		method: "POST",
+		path: "/chat/completions/{completion_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"chat.completion","id":"chatcmpl-AyPNinnUqUDYo9SAdA52NobMflmj2","model":"gpt-4o-2024-08-06","created":1738960610,"request_id":"req_ded8ab984ec4bf840f37566c1011c417","tool_choice":null,"usage":{"total_tokens":31,"completion_tokens":18,"prompt_tokens":13},"seed":4944116822809979520,"top_p":1.0,"temperature":1.0,"presence_penalty":0.0,"frequency_penalty":0.0,"system_fingerprint":"fp_50cad350e4","input_user":null,"service_tier":"default","tools":null,"metadata":{"foo":"bar"},"choices":[{"index":0,"message":{"content":"Mind of circuits hum,  \nLearning patterns in silence—  \nFuture's quiet spark.","role":"assistant","tool_calls":null,"function_call":null},"finish_reason":"stop","logprobs":null}],"response_format":null}"#,
+		events: &[],
+	},
+	#[cfg(feature = "chat")]
+	MockOperation {
+		id: "deleteChatCompletion",
+		method: "DELETE",
+		path: "/chat/completions/{completion_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"chat.completion.deleted","id":"chatcmpl-AyPNinnUqUDYo9SAdA52NobMflmj2","deleted":true}"#,
+		events: &[],
+	},
+	#[cfg(feature = "chat")]
+	MockOperation {
+		id: "getChatCompletionMessages",
+		method: "GET",
+		path: "/chat/completions/{completion_id}/messages",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"list","data":[{"id":"chatcmpl-AyPNinnUqUDYo9SAdA52NobMflmj2-0","role":"user","content":"write a haiku about ai","name":null,"content_parts":null}],"first_id":"chatcmpl-AyPNinnUqUDYo9SAdA52NobMflmj2-0","last_id":"chatcmpl-AyPNinnUqUDYo9SAdA52NobMflmj2-0","has_more":false}"#,
+		events: &[],
+	},
+	#[cfg(feature = "completions")]
+	MockOperation {
+		id: "createCompletion",
+		method: "POST",
+		path: "/completions",
+		validate: Some(validate::<super::CreateCompletionRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"cmpl-uqkvlQyYK7bGYrRHQ0eXlWi7","object":"text_completion","created":1589478378,"model":"VAR_completion_model_id","system_fingerprint":"fp_44709d6fcb","choices":[{"text":"\n\nThis is indeed a test","index":0,"logprobs":null,"finish_reason":"length"}],"usage":{"prompt_tokens":5,"completion_tokens":7,"total_tokens":12}}"#,
+		events: &[],
+	},
+	#[cfg(feature = "embeddings")]
+	MockOperation {
+		id: "createEmbedding",
+		method: "POST",
+		path: "/embeddings",
+		validate: Some(validate::<super::CreateEmbeddingRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"data":[],"model":"string","object":"list","usage":{"prompt_tokens":0,"total_tokens":0}}"#,
+		events: &[],
+	},
+	#[cfg(all(feature = "files", feature = "shared"))]
+	MockOperation {
+		id: "listFiles",
+		method: "GET",
+		path: "/files",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"list","data":[],"first_id":"file-abc123","last_id":"file-abc456","has_more":false}"#,
+		events: &[],
+	},
+	#[cfg(feature = "shared")]
+	MockOperation {
+		id: "createFile",
+		method: "POST",
+		path: "/files",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"string","bytes":0,"created_at":0,"filename":"string","object":"file","purpose":"assistants","status":"uploaded"}"#,
+		events: &[],
+	},
+	#[cfg(feature = "shared")]
+	MockOperation {
+		id: "retrieveFile",
+		method: "GET",
+		path: "/files/{file_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"string","bytes":0,"created_at":0,"filename":"string","object":"file","purpose":"assistants","status":"uploaded"}"#,
+		events: &[],
+	},
+	#[cfg(feature = "files")]
+	MockOperation {
+		id: "deleteFile",
+		method: "DELETE",
+		path: "/files/{file_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"file-abc123","object":"file","deleted":true}"#,
+		events: &[],
+	},
+	MockOperation {
+		id: "downloadFile",
+		method: "GET",
+		path: "/files/{file_id}/content",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#""string""#,
+		events: &[],
+	},
+	#[cfg(feature = "fine_tuning")]
+	MockOperation {
+		id: "listFineTuningCheckpointPermissions",
+		method: "GET",
+		path: "/fine_tuning/checkpoints/{permission_id}/permissions",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"data":[],"object":"list","has_more":false}"#,
+		events: &[],
+	},
+	#[cfg(feature = "fine_tuning")]
+	MockOperation {
+		id: "createFineTuningCheckpointPermission",
+		method: "POST",
+		path: "/fine_tuning/checkpoints/{permission_id}/permissions",
+		validate: Some(validate::<super::CreateFineTuningCheckpointPermissionRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"list","data":[{"object":"checkpoint.permission","id":"cp_zc4Q7MP6XxulcVzj4MZdwsAB","created_at":1721764867,"project_id":"proj_abGMw1llN8IrBb6SvvY5A1iH"}],"first_id":"cp_zc4Q7MP6XxulcVzj4MZdwsAB","last_id":"cp_zc4Q7MP6XxulcVzj4MZdwsAB","has_more":false}"#,
+		events: &[],
+	},
+	#[cfg(feature = "fine_tuning")]
+	MockOperation {
+		id: "deleteFineTuningCheckpointPermission",
+		method: "DELETE",
+		path: "/fine_tuning/checkpoints/{permission_id}/permissions",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"checkpoint.permission","id":"cp_zc4Q7MP6XxulcVzj4MZdwsAB","deleted":true}"#,
+		events: &[],
+	},
+	#[cfg(feature = "fine_tuning")]
+	MockOperation {
+		id: "listPaginatedFineTuningJobs",
+		method: "GET",
+		path: "/fine_tuning/jobs",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"data":[],"has_more":false,"object":"list"}"#,
+		events: &[],
+	},
+	#[cfg(feature = "fine_tuning")]
+	MockOperation {
+		id: "createFineTuningJob",
+		method: "POST",
+		path: "/fine_tuning/jobs",
+		validate: Some(validate::<super::CreateFineTuningJobRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"string","created_at":0,"error":{"code":"string","message":"string","param":"string"},"fine_tuned_model":"string","finished_at":0,"hyperparameters":{},"model":"string","object":"fine_tuning.job","organization_id":"string","result_files":[],"status":"validating_files","trained_tokens":0,"training_file":"string","validation_file":"string","seed":0}"#,
+		events: &[],
+	},
+	#[cfg(feature = "fine_tuning")]
+	MockOperation {
+		id: "retrieveFineTuningJob",
+		method: "GET",
+		path: "/fine_tuning/jobs/{fine_tuning_job_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"fine_tuning.job","id":"ftjob-abc123","model":"davinci-002","created_at":1692661014,"finished_at":1692661190,"fine_tuned_model":"ft:davinci-002:my-org:custom_suffix:7q8mpxmy","organization_id":"org-123","result_files":["file-abc123"],"status":"succeeded","validation_file":null,"training_file":"file-abc123","hyperparameters":{"n_epochs":4,"batch_size":1,"learning_rate_multiplier":1.0},"trained_tokens":5768,"integrations":[],"seed":0,"estimated_finish":0,"method":{"type":"supervised","supervised":{"hyperparameters":{"n_epochs":4,"batch_size":1,"learning_rate_multiplier":1.0}}}}"#,
+		events: &[],
+	},
+	#[cfg(feature = "fine_tuning")]
+	MockOperation {
+		id: "cancelFineTuningJob",
+		method: "POST",
+		path: "/fine_tuning/jobs/{fine_tuning_job_id}/cancel",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"fine_tuning.job","id":"ftjob-abc123","model":"gpt-4o-mini-2024-07-18","created_at":1721764800,"fine_tuned_model":null,"organization_id":"org-123","result_files":[],"status":"cancelled","validation_file":"file-abc123","training_file":"file-abc123"}"#,
+		events: &[],
+	},
+	#[cfg(feature = "fine_tuning")]
+	MockOperation {
+		id: "listFineTuningJobCheckpoints",
+		method: "GET",
+		path: "/fine_tuning/jobs/{fine_tuning_job_id}/checkpoints",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"data":[],"object":"list","has_more":false}"#,
+		events: &[],
+	},
+	#[cfg(feature = "fine_tuning")]
+	MockOperation {
+		id: "listFineTuningEvents",
+		method: "GET",
+		path: "/fine_tuning/jobs/{fine_tuning_job_id}/events",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"list","data":[{"object":"fine_tuning.job.event","id":"ft-event-ddTJfwuMVpfLXseO0Am0Gqjm","created_at":1721764800,"level":"info","message":"Fine tuning job successfully completed","data":null,"type":"message"},{"object":"fine_tuning.job.event","id":"ft-event-tyiGuB72evQncpH87xe505Sv","created_at":1721764800,"level":"info","message":"New fine-tuned model created: ft:gpt-4o-mini:openai::7p4lURel","data":null,"type":"message"}],"has_more":true}"#,
+		events: &[],
+	},
+	#[cfg(feature = "images")]
+	MockOperation {
+		id: "createImageEdit",
+		method: "POST",
+		path: "/images/edits",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"created":1589478378,"data":[{"url":"https://..."},{"url":"https://..."}]}"#,
+		events: &[],
+	},
+	#[cfg(feature = "images")]
+	MockOperation {
+		id: "createImage",
+		method: "POST",
+		path: "/images/generations",
+		validate: Some(validate::<super::CreateImageRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"created":1589478378,"data":[{"url":"https://..."},{"url":"https://..."}]}"#,
+		events: &[],
+	},
+	#[cfg(feature = "images")]
+	MockOperation {
+		id: "createImageVariation",
+		method: "POST",
+		path: "/images/variations",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"created":1589478378,"data":[{"url":"https://..."},{"url":"https://..."}]}"#,
+		events: &[],
+	},
+	#[cfg(feature = "models")]
+	MockOperation {
+		id: "listModels",
+		method: "GET",
+		path: "/models",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"list","data":[]}"#,
+		events: &[],
+	},
+	#[cfg(feature = "models")]
+	MockOperation {
+		id: "retrieveModel",
+		method: "GET",
+		path: "/models/{model}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"VAR_chat_model_id","object":"model","created":1686935002,"owned_by":"openai"}"#,
+		events: &[],
+	},
+	#[cfg(feature = "models")]
+	MockOperation {
+		id: "deleteModel",
+		method: "DELETE",
+		path: "/models/{model}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"ft:gpt-4o-mini:acemeco:suffix:abc123","object":"model","deleted":true}"#,
+		events: &[],
+	},
+	#[cfg(feature = "moderations")]
+	MockOperation {
+		id: "createModeration",
+		method: "POST",
+		path: "/moderations",
+		validate: Some(validate::<super::CreateModerationRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"modr-AB8CjOTu2jiq12hp1AQPfeqFWaORR","model":"text-moderation-007","results":[{"flagged":true,"categories":{"sexual":false,"hate":false,"harassment":true,"self-harm":false,"sexual/minors":false,"hate/threatening":false,"violence/graphic":false,"self-harm/intent":false,"self-harm/instructions":false,"harassment/threatening":true,"violence":true},"category_scores":{"sexual":0.000011726012417057063,"hate":0.22706663608551023,"harassment":0.5215635299682617,"self-harm":2.227119921371923e-6,"sexual/minors":7.107352217872176e-8,"hate/threatening":0.023547329008579258,"violence/graphic":0.00003391829886822961,"self-harm/intent":1.646940972932498e-6,"self-harm/instructions":1.1198755256458526e-9,"harassment/threatening":0.5694745779037476,"violence":0.9971134662628174}}]}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "admin-api-keys-list",
+		method: "GET",
+		path: "/organization/admin_api_keys",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"list","data":[{"object":"organization.admin_api_key","id":"key_abc","name":"Main Admin Key","redacted_value":"sk-admin...def","created_at":1711471533,"owner":{"type":"service_account","object":"organization.service_account","id":"sa_456","name":"My Service Account","created_at":1711471533,"role":"member"}}],"first_id":"key_abc","last_id":"key_abc","has_more":false}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "admin-api-keys-create",
+		method: "POST",
+		path: "/organization/admin_api_keys",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"organization.admin_api_key","id":"key_xyz","name":"New Admin Key","redacted_value":"sk-admin...xyz","created_at":1711471533,"owner":{"type":"user","object":"organization.user","id":"user_123","name":"John Doe","created_at":1711471533,"role":"owner"},"value":"sk-admin-1234abcd"}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "admin-api-keys-get",
+		method: "GET",
+		path: "/organization/admin_api_keys/{key_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"organization.admin_api_key","id":"key_abc","name":"Main Admin Key","redacted_value":"sk-admin...xyz","created_at":1711471533,"owner":{"type":"user","object":"organization.user","id":"user_123","name":"John Doe","created_at":1711471533,"role":"owner"}}"#,
+		events: &[],
+	},
+	MockOperation {
+		id: "admin-api-keys-delete",
+		method: "DELETE",
+		path: "/organization/admin_api_keys/{key_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"key_abc","object":"organization.admin_api_key.deleted","deleted":true}"#,
+		events: &[],
+	},
+	#[cfg(feature = "audit_logs")]
+	MockOperation {
+		id: "list-audit-logs",
+		method: "GET",
+		path: "/organization/audit_logs",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"list","data":[],"first_id":"audit_log-defb456h8dks","last_id":"audit_log-hnbkd8s93s","has_more":false}"#,
+		events: &[],
+	},
+	#[cfg(feature = "shared")]
+	MockOperation {
+		id: "usage-costs",
+		method: "GET",
+		path: "/organization/costs",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"page","data":[{"object":"bucket","start_time":1730419200,"end_time":1730505600,"results":[{"object":"organization.costs.result","amount":{"value":0.06,"currency":"usd"},"line_item":null,"project_id":null}]}],"has_more":false,"next_page":null}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "list-invites",
+		method: "GET",
+		path: "/organization/invites",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"list","data":[{"object":"organization.invite","id":"invite-abc","email":"user@example.com","role":"owner","status":"accepted","invited_at":1711471533,"expires_at":1711471533,"accepted_at":1711471533}],"first_id":"invite-abc","last_id":"invite-abc","has_more":false}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "inviteUser",
+		method: "POST",
+		path: "/organization/invites",
+		validate: Some(validate::<super::InviteRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"organization.invite","id":"invite-def","email":"anotheruser@example.com","role":"reader","status":"pending","invited_at":1711471533,"expires_at":1711471533,"accepted_at":null,"projects":[{"id":"project-xyz","role":"member"},{"id":"project-abc","role":"owner"}]}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "retrieve-invite",
+		method: "GET",
+		path: "/organization/invites/{invite_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"organization.invite","id":"invite-abc","email":"user@example.com","role":"owner","status":"accepted","invited_at":1711471533,"expires_at":1711471533,"accepted_at":1711471533}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "delete-invite",
+		method: "DELETE",
+		path: "/organization/invites/{invite_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"organization.invite.deleted","id":"invite-abc","deleted":true}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "list-projects",
+		method: "GET",
+		path: "/organization/projects",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"list","data":[{"id":"proj_abc","object":"organization.project","name":"Project example","created_at":1711471533,"archived_at":null,"status":"active"}],"first_id":"proj-abc","last_id":"proj-xyz","has_more":false}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "create-project",
+		method: "POST",
+		path: "/organization/projects",
+		validate: Some(validate::<super::ProjectCreateRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"proj_abc","object":"organization.project","name":"Project ABC","created_at":1711471533,"archived_at":null,"status":"active"}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "retrieve-project",
+		method: "GET",
+		path: "/organization/projects/{project_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"proj_abc","object":"organization.project","name":"Project example","created_at":1711471533,"archived_at":null,"status":"active"}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "modify-project",
+		method: "POST",
+		path: "/organization/projects/{project_id}",
+		validate: Some(validate::<super::ProjectUpdateRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"string","object":"organization.project","name":"string","created_at":0,"status":"active"}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "list-project-api-keys",
+		method: "GET",
+		path: "/organization/projects/{project_id}/api_keys",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"list","data":[{"object":"organization.project.api_key","redacted_value":"sk-abc...def","name":"My API Key","created_at":1711471533,"id":"key_abc","owner":{"type":"user","user":{"object":"organization.project.user","id":"user_abc","name":"First Last","email":"user@example.com","role":"owner","added_at":1711471533}}}],"first_id":"key_abc","last_id":"key_xyz","has_more":false}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "retrieve-project-api-key",
+		method: "GET",
+		path: "/organization/projects/{project_id}/api_keys/{key_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"organization.project.api_key","redacted_value":"sk-abc...def","name":"My API Key","created_at":1711471533,"id":"key_abc","owner":{"type":"user","user":{"object":"organization.project.user","id":"user_abc","name":"First Last","email":"user@example.com","role":"owner","added_at":1711471533}}}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "delete-project-api-key",
+		method: "DELETE",
+		path: "/organization/projects/{project_id}/api_keys/{key_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"organization.project.api_key.deleted","id":"key_abc","deleted":true}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "archive-project",
+		method: "POST",
+		path: "/organization/projects/{project_id}/archive",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"proj_abc","object":"organization.project","name":"Project DEF","created_at":1711471533,"archived_at":1711471533,"status":"archived"}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "list-project-rate-limits",
+		method: "GET",
+		path: "/organization/projects/{project_id}/rate_limits",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"list","data":[{"object":"project.rate_limit","id":"rl-ada","model":"ada","max_requests_per_1_minute":600,"max_tokens_per_1_minute":150000,"max_images_per_1_minute":10}],"first_id":"rl-ada","last_id":"rl-ada","has_more":false}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "update-project-rate-limits",
+		method: "POST",
+		path: "/organization/projects/{project_id}/rate_limits/{rate_limit_id}",
+		validate: Some(validate::<super::ProjectRateLimitUpdateRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"project.rate_limit","id":"rl-ada","model":"ada","max_requests_per_1_minute":600,"max_tokens_per_1_minute":150000,"max_images_per_1_minute":10}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "list-project-service-accounts",
+		method: "GET",
+		path: "/organization/projects/{project_id}/service_accounts",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"list","data":[{"object":"organization.project.service_account","id":"svc_acct_abc","name":"Service Account","role":"owner","created_at":1711471533}],"first_id":"svc_acct_abc","last_id":"svc_acct_xyz","has_more":false}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "create-project-service-account",
+		method: "POST",
+		path: "/organization/projects/{project_id}/service_accounts",
+		validate: Some(validate::<super::ProjectServiceAccountCreateRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"organization.project.service_account","id":"svc_acct_abc","name":"Production App","role":"member","created_at":1711471533,"api_key":{"object":"organization.project.service_account.api_key","value":"sk-abcdefghijklmnop123","name":"Secret Key","created_at":1711471533,"id":"key_abc"}}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "retrieve-project-service-account",
+		method: "GET",
+		path: "/organization/projects/{project_id}/service_accounts/{service_account_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"organization.project.service_account","id":"svc_acct_abc","name":"Service Account","role":"owner","created_at":1711471533}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "delete-project-service-account",
+		method: "DELETE",
+		path: "/organization/projects/{project_id}/service_accounts/{service_account_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"organization.project.service_account.deleted","id":"svc_acct_abc","deleted":true}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "list-project-users",
+		method: "GET",
+		path: "/organization/projects/{project_id}/users",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"list","data":[{"object":"organization.project.user","id":"user_abc","name":"First Last","email":"user@example.com","role":"owner","added_at":1711471533}],"first_id":"user-abc","last_id":"user-xyz","has_more":false}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "create-project-user",
+		method: "POST",
+		path: "/organization/projects/{project_id}/users",
+		validate: Some(validate::<super::ProjectUserCreateRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"organization.project.user","id":"user_abc","email":"user@example.com","role":"owner","added_at":1711471533}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "retrieve-project-user",
+		method: "GET",
+		path: "/organization/projects/{project_id}/users/{user_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"organization.project.user","id":"user_abc","name":"First Last","email":"user@example.com","role":"owner","added_at":1711471533}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "modify-project-user",
+		method: "POST",
+		path: "/organization/projects/{project_id}/users/{user_id}",
+		validate: Some(validate::<super::ProjectUserUpdateRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"organization.project.user","id":"user_abc","name":"First Last","email":"user@example.com","role":"owner","added_at":1711471533}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "delete-project-user",
+		method: "DELETE",
+		path: "/organization/projects/{project_id}/users/{user_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"organization.project.user.deleted","id":"user_abc","deleted":true}"#,
+		events: &[],
+	},
+	#[cfg(feature = "shared")]
+	MockOperation {
+		id: "usage-audio-speeches",
+		method: "GET",
+		path: "/organization/usage/audio_speeches",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"page","data":[{"object":"bucket","start_time":1730419200,"end_time":1730505600,"results":[{"object":"organization.usage.audio_speeches.result","characters":45,"num_model_requests":1,"project_id":null,"user_id":null,"api_key_id":null,"model":null}]}],"has_more":false,"next_page":null}"#,
+		events: &[],
+	},
+	#[cfg(feature = "shared")]
+	MockOperation {
+		id: "usage-audio-transcriptions",
+		method: "GET",
+		path: "/organization/usage/audio_transcriptions",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"page","data":[{"object":"bucket","start_time":1730419200,"end_time":1730505600,"results":[{"object":"organization.usage.audio_transcriptions.result","seconds":20,"num_model_requests":1,"project_id":null,"user_id":null,"api_key_id":null,"model":null}]}],"has_more":false,"next_page":null}"#,
+		events: &[],
+	},
+	#[cfg(feature = "shared")]
+	MockOperation {
+		id: "usage-code-interpreter-sessions",
+		method: "GET",
+		path: "/organization/usage/code_interpreter_sessions",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"page","data":[{"object":"bucket","start_time":1730419200,"end_time":1730505600,"results":[{"object":"organization.usage.code_interpreter_sessions.result","num_sessions":1,"project_id":null}]}],"has_more":false,"next_page":null}"#,
+		events: &[],
+	},
+	#[cfg(feature = "shared")]
+	MockOperation {
+		id: "usage-completions",
+		method: "GET",
+		path: "/organization/usage/completions",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"page","data":[{"object":"bucket","start_time":1730419200,"end_time":1730505600,"results":[{"object":"organization.usage.completions.result","input_tokens":1000,"output_tokens":500,"input_cached_tokens":800,"input_audio_tokens":0,"output_audio_tokens":0,"num_model_requests":5,"project_id":null,"user_id":null,"api_key_id":null,"model":null,"batch":null}]}],"has_more":true,"next_page":"page_AAAAAGdGxdEiJdKOAAAAAGcqsYA="}"#,
+		events: &[],
+	},
+	#[cfg(feature = "shared")]
+	MockOperation {
+		id: "usage-embeddings",
+		method: "GET",
+		path: "/organization/usage/embeddings",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"page","data":[{"object":"bucket","start_time":1730419200,"end_time":1730505600,"results":[{"object":"organization.usage.embeddings.result","input_tokens":16,"num_model_requests":2,"project_id":null,"user_id":null,"api_key_id":null,"model":null}]}],"has_more":false,"next_page":null}"#,
+		events: &[],
+	},
+	#[cfg(feature = "shared")]
+	MockOperation {
+		id: "usage-images",
+		method: "GET",
+		path: "/organization/usage/images",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"page","data":[{"object":"bucket","start_time":1730419200,"end_time":1730505600,"results":[{"object":"organization.usage.images.result","images":2,"num_model_requests":2,"size":null,"source":null,"project_id":null,"user_id":null,"api_key_id":null,"model":null}]}],"has_more":false,"next_page":null}"#,
+		events: &[],
+	},
+	#[cfg(feature = "shared")]
+	MockOperation {
+		id: "usage-moderations",
+		method: "GET",
+		path: "/organization/usage/moderations",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"page","data":[{"object":"bucket","start_time":1730419200,"end_time":1730505600,"results":[{"object":"organization.usage.moderations.result","input_tokens":16,"num_model_requests":2,"project_id":null,"user_id":null,"api_key_id":null,"model":null}]}],"has_more":false,"next_page":null}"#,
+		events: &[],
+	},
+	#[cfg(feature = "shared")]
+	MockOperation {
+		id: "usage-vector-stores",
+		method: "GET",
+		path: "/organization/usage/vector_stores",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"page","data":[{"object":"bucket","start_time":1730419200,"end_time":1730505600,"results":[{"object":"organization.usage.vector_stores.result","usage_bytes":1024,"project_id":null}]}],"has_more":false,"next_page":null}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "list-users",
+		method: "GET",
+		path: "/organization/users",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"list","data":[{"object":"organization.user","id":"user_abc","name":"First Last","email":"user@example.com","role":"owner","added_at":1711471533}],"first_id":"user-abc","last_id":"user-xyz","has_more":false}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "retrieve-user",
+		method: "GET",
+		path: "/organization/users/{user_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"organization.user","id":"user_abc","name":"First Last","email":"user@example.com","role":"owner","added_at":1711471533}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "modify-user",
+		method: "POST",
+		path: "/organization/users/{user_id}",
+		validate: Some(validate::<super::UserRoleUpdateRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"organization.user","id":"user_abc","name":"First Last","email":"user@example.com","role":"owner","added_at":1711471533}"#,
+		events: &[],
+	},
+	#[cfg(feature = "administration")]
+	MockOperation {
+		id: "delete-user",
+		method: "DELETE",
+		path: "/organization/users/{user_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"organization.user.deleted","id":"user_abc","deleted":true}"#,
+		events: &[],
+	},
+	#[cfg(feature = "realtime")]
+	MockOperation {
+		id: "create-realtime-session",
+		method: "POST",
+		path: "/realtime/sessions",
+		validate: Some(validate::<super::RealtimeSessionCreateRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"sess_001","object":"realtime.session","model":"gpt-4o-realtime-preview","modalities":["audio","text"],"instructions":"You are a friendly assistant.","voice":"alloy","input_audio_format":"pcm16","output_audio_format":"pcm16","input_audio_transcription":{"model":"whisper-1"},"turn_detection":null,"tools":[],"tool_choice":"none","temperature":0.7,"max_response_output_tokens":200,"client_secret":{"value":"ek_abc123","expires_at":1234567890}}"#,
+		events: &[],
+	},
+	#[cfg(feature = "realtime")]
+	MockOperation {
+		id: "create-realtime-transcription-session",
+		method: "POST",
+		path: "/realtime/transcription_sessions",
+		validate: Some(validate::<super::RealtimeTranscriptionSessionCreateRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"sess_BBwZc7cFV3XizEyKGDCGL","object":"realtime.transcription_session","modalities":["audio","text"],"turn_detection":{"type":"server_vad","threshold":0.5,"prefix_padding_ms":300,"silence_duration_ms":200},"input_audio_format":"pcm16","input_audio_transcription":{"model":"gpt-4o-transcribe","language":null,"prompt":""},"client_secret":null}"#,
+		events: &[],
+	},
+	#[cfg(feature = "responses")]
+	MockOperation {
+		id: "createResponse",
+		method: "POST",
+		path: "/responses",
+		validate: Some(validate::<super::CreateResponse>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"resp_67ccd2bed1ec8190b14f964abc0542670bb6a6b452d3795b","object":"response","created_at":1741476542,"status":"completed","error":null,"incomplete_details":null,"instructions":null,"max_output_tokens":null,"model":"gpt-4o-2024-08-06","output":[{"type":"message","id":"msg_67ccd2bf17f0819081ff3bb2cf6508e60bb6a6b452d3795b","status":"completed","role":"assistant","content":[{"type":"output_text","text":"In a peaceful grove beneath a silver moon, a unicorn named Lumina discovered a hidden pool that reflected the stars. As she dipped her horn into the water, the pool began to shimmer, revealing a pathway to a magical realm of endless night skies. Filled with wonder, Lumina whispered a wish for all who dream to find their own hidden magic, and as she glanced back, her hoofprints sparkled like stardust.","annotations":[]}]}],"parallel_tool_calls":true,"previous_response_id":null,"reasoning":{"effort":null,"generate_summary":null},"store":true,"temperature":1.0,"text":{"format":{"type":"text"}},"tool_choice":"auto","tools":[],"top_p":1.0,"truncation":"disabled","usage":{"input_tokens":36,"input_tokens_details":{"cached_tokens":0},"output_tokens":87,"output_tokens_details":{"reasoning_tokens":0},"total_tokens":123},"user":null,"metadata":{}}"#,
+		events: &[
+			(Some("response.created"), r#"{"type":"response.created","response":{"id":"resp_67c9fdcecf488190bdd9a0409de3a1ec07b8b0ad4e5eb654","object":"response","created_at":1741290958,"status":"in_progress","error":null,"incomplete_details":null,"instructions":"You are a helpful assistant.","max_output_tokens":null,"model":"gpt-4o-2024-08-06","output":[],"parallel_tool_calls":true,"previous_response_id":null,"reasoning":{"effort":null,"generate_summary":null},"store":true,"temperature":1.0,"text":{"format":{"type":"text"}},"tool_choice":"auto","tools":[],"top_p":1.0,"truncation":"disabled","usage":null,"user":null,"metadata":{}}}"#),
+			(Some("response.in_progress"), r#"{"type":"response.in_progress","response":{"id":"resp_67c9fdcecf488190bdd9a0409de3a1ec07b8b0ad4e5eb654","object":"response","created_at":1741290958,"status":"in_progress","error":null,"incomplete_details":null,"instructions":"You are a helpful assistant.","max_output_tokens":null,"model":"gpt-4o-2024-08-06","output":[],"parallel_tool_calls":true,"previous_response_id":null,"reasoning":{"effort":null,"generate_summary":null},"store":true,"temperature":1.0,"text":{"format":{"type":"text"}},"tool_choice":"auto","tools":[],"top_p":1.0,"truncation":"disabled","usage":null,"user":null,"metadata":{}}}"#),
+			(Some("response.output_item.added"), r#"{"type":"response.output_item.added","output_index":0,"item":{"id":"msg_67c9fdcf37fc8190ba82116e33fb28c507b8b0ad4e5eb654","type":"message","status":"in_progress","role":"assistant","content":[]}}"#),
+			(Some("response.content_part.added"), r#"{"type":"response.content_part.added","item_id":"msg_67c9fdcf37fc8190ba82116e33fb28c507b8b0ad4e5eb654","output_index":0,"content_index":0,"part":{"type":"output_text","text":"","annotations":[]}}"#),
+			(Some("response.output_text.delta"), r#"{"type":"response.output_text.delta","item_id":"msg_67c9fdcf37fc8190ba82116e33fb28c507b8b0ad4e5eb654","output_index":0,"content_index":0,"delta":"Hi"}"#),
+			(Some("response.output_text.done"), r#"{"type":"response.output_text.done","item_id":"msg_67c9fdcf37fc8190ba82116e33fb28c507b8b0ad4e5eb654","output_index":0,"content_index":0,"text":"Hi there! How can I assist you today?"}"#),
+			(Some("response.content_part.done"), r#"{"type":"response.content_part.done","item_id":"msg_67c9fdcf37fc8190ba82116e33fb28c507b8b0ad4e5eb654","output_index":0,"content_index":0,"part":{"type":"output_text","text":"Hi there! How can I assist you today?","annotations":[]}}"#),
+			(Some("response.output_item.done"), r#"{"type":"response.output_item.done","output_index":0,"item":{"id":"msg_67c9fdcf37fc8190ba82116e33fb28c507b8b0ad4e5eb654","type":"message","status":"completed","role":"assistant","content":[{"type":"output_text","text":"Hi there! How can I assist you today?","annotations":[]}]}}"#),
+			(Some("response.completed"), r#"{"type":"response.completed","response":{"id":"resp_67c9fdcecf488190bdd9a0409de3a1ec07b8b0ad4e5eb654","object":"response","created_at":1741290958,"status":"completed","error":null,"incomplete_details":null,"instructions":"You are a helpful assistant.","max_output_tokens":null,"model":"gpt-4o-2024-08-06","output":[{"id":"msg_67c9fdcf37fc8190ba82116e33fb28c507b8b0ad4e5eb654","type":"message","status":"completed","role":"assistant","content":[{"type":"output_text","text":"Hi there! How can I assist you today?","annotations":[]}]}],"parallel_tool_calls":true,"previous_response_id":null,"reasoning":{"effort":null,"generate_summary":null},"store":true,"temperature":1.0,"text":{"format":{"type":"text"}},"tool_choice":"auto","tools":[],"top_p":1.0,"truncation":"disabled","usage":{"input_tokens":37,"output_tokens":11,"output_tokens_details":{"reasoning_tokens":0},"total_tokens":48},"user":null,"metadata":{}}}"#),
+		],
+	},
+	#[cfg(feature = "responses")]
+	MockOperation {
+		id: "getResponse",
+		method: "GET",
+		path: "/responses/{response_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"resp_67cb71b351908190a308f3859487620d06981a8637e6bc44","object":"response","created_at":1741386163,"status":"completed","error":null,"incomplete_details":null,"instructions":null,"max_output_tokens":null,"model":"gpt-4o-2024-08-06","output":[{"type":"message","id":"msg_67cb71b3c2b0819084d481baaaf148f206981a8637e6bc44","status":"completed","role":"assistant","content":[{"type":"output_text","text":"Silent circuits hum,  \nThoughts emerge in data streams—  \nDigital dawn breaks.","annotations":[]}]}],"parallel_tool_calls":true,"previous_response_id":null,"reasoning":{"effort":null,"generate_summary":null},"store":true,"temperature":1.0,"text":{"format":{"type":"text"}},"tool_choice":"auto","tools":[],"top_p":1.0,"truncation":"disabled","usage":{"input_tokens":32,"input_tokens_details":{"cached_tokens":0},"output_tokens":18,"output_tokens_details":{"reasoning_tokens":0},"total_tokens":50},"user":null,"metadata":{}}"#,
+		events: &[],
+	},
+	MockOperation {
+		id: "deleteResponse",
+		method: "DELETE",
+		path: "/responses/{response_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"resp_6786a1bec27481909a17d673315b29f6","object":"response","deleted":true}"#,
+		events: &[],
+	},
+	#[cfg(feature = "responses")]
+	MockOperation {
+		id: "listInputItems",
+		method: "GET",
+		path: "/responses/{response_id}/input_items",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"list","data":[{"id":"msg_abc123","type":"message","role":"user","content":[{"type":"input_text","text":"Tell me a three sentence bedtime story about a unicorn."}]}],"first_id":"msg_abc123","last_id":"msg_abc123","has_more":false}"#,
+		events: &[],
+	},
+	#[cfg(feature = "threads")]
+	MockOperation {
+		id: "createThread",
+		method: "POST",
+		path: "/threads",
+		validate: Some(validate::<super::CreateThreadRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"thread_abc123","object":"thread","created_at":1699012949,"metadata":{},"tool_resources":{}}"#,
+		events: &[],
+	},
+	#[cfg(feature = "threads")]
+	MockOperation {
+		id: "createThreadAndRun",
+		method: "POST",
+		path: "/threads/runs",
+		validate: Some(validate::<super::CreateThreadAndRunRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"run_abc123","object":"thread.run","created_at":1699076792,"assistant_id":"asst_abc123","thread_id":"thread_abc123","status":"queued","started_at":null,"expires_at":1699077392,"cancelled_at":null,"failed_at":null,"completed_at":null,"required_action":null,"last_error":null,"model":"gpt-4o","instructions":"You are a helpful assistant.","tools":[],"tool_resources":{},"metadata":{},"temperature":1.0,"top_p":1.0,"max_completion_tokens":null,"max_prompt_tokens":null,"truncation_strategy":{"type":"auto","last_messages":null},"incomplete_details":null,"usage":null,"response_format":"auto","tool_choice":"auto","parallel_tool_calls":true}"#,
+		events: &[
+			(Some("thread.created"), r#"{"id":"thread_123","object":"thread","created_at":1710348075,"metadata":{}}"#),
+			(Some("thread.run.created"), r#"{"id":"run_123","object":"thread.run","created_at":1710348075,"assistant_id":"asst_123","thread_id":"thread_123","status":"queued","started_at":null,"expires_at":1710348675,"cancelled_at":null,"failed_at":null,"completed_at":null,"required_action":null,"last_error":null,"model":"gpt-4o","instructions":null,"tools":[],"tool_resources":{},"metadata":{},"temperature":1.0,"top_p":1.0,"max_completion_tokens":null,"max_prompt_tokens":null,"truncation_strategy":{"type":"auto","last_messages":null},"incomplete_details":null,"usage":null,"response_format":"auto","tool_choice":"auto","parallel_tool_calls":true}"#),
+			(Some("thread.run.queued"), r#"{"id":"run_123","object":"thread.run","created_at":1710348075,"assistant_id":"asst_123","thread_id":"thread_123","status":"queued","started_at":null,"expires_at":1710348675,"cancelled_at":null,"failed_at":null,"completed_at":null,"required_action":null,"last_error":null,"model":"gpt-4o","instructions":null,"tools":[],"tool_resources":{},"metadata":{},"temperature":1.0,"top_p":1.0,"max_completion_tokens":null,"max_prompt_tokens":null,"truncation_strategy":{"type":"auto","last_messages":null},"incomplete_details":null,"usage":null,"response_format":"auto","tool_choice":"auto","parallel_tool_calls":true}"#),
+			(Some("thread.run.in_progress"), r#"{"id":"run_123","object":"thread.run","created_at":1710348075,"assistant_id":"asst_123","thread_id":"thread_123","status":"in_progress","started_at":null,"expires_at":1710348675,"cancelled_at":null,"failed_at":null,"completed_at":null,"required_action":null,"last_error":null,"model":"gpt-4o","instructions":null,"tools":[],"tool_resources":{},"metadata":{},"temperature":1.0,"top_p":1.0,"max_completion_tokens":null,"max_prompt_tokens":null,"truncation_strategy":{"type":"auto","last_messages":null},"incomplete_details":null,"usage":null,"response_format":"auto","tool_choice":"auto","parallel_tool_calls":true}"#),
+			(Some("thread.run.step.created"), r#"{"id":"step_001","object":"thread.run.step","created_at":1710348076,"run_id":"run_123","assistant_id":"asst_123","thread_id":"thread_123","type":"message_creation","status":"in_progress","cancelled_at":null,"completed_at":null,"expires_at":1710348675,"failed_at":null,"last_error":null,"step_details":{"type":"message_creation","message_creation":{"message_id":"msg_001"}},"usage":null}"#),
+			(Some("thread.run.step.in_progress"), r#"{"id":"step_001","object":"thread.run.step","created_at":1710348076,"run_id":"run_123","assistant_id":"asst_123","thread_id":"thread_123","type":"message_creation","status":"in_progress","cancelled_at":null,"completed_at":null,"expires_at":1710348675,"failed_at":null,"last_error":null,"step_details":{"type":"message_creation","message_creation":{"message_id":"msg_001"}},"usage":null}"#),
+			(Some("thread.message.created"), r#"{"id":"msg_001","object":"thread.message","created_at":1710348076,"assistant_id":"asst_123","thread_id":"thread_123","run_id":"run_123","status":"in_progress","incomplete_details":null,"incomplete_at":null,"completed_at":null,"role":"assistant","content":[],"metadata":{}}"#),
+			(Some("thread.message.in_progress"), r#"{"id":"msg_001","object":"thread.message","created_at":1710348076,"assistant_id":"asst_123","thread_id":"thread_123","run_id":"run_123","status":"in_progress","incomplete_details":null,"incomplete_at":null,"completed_at":null,"role":"assistant","content":[],"metadata":{}}"#),
+			(Some("thread.message.delta"), r#"{"id":"msg_001","object":"thread.message.delta","delta":{"content":[{"index":0,"type":"text","text":{"value":"Hello","annotations":[]}}]}}"#),
+			(Some("thread.message.delta"), r#"{"id":"msg_001","object":"thread.message.delta","delta":{"content":[{"index":0,"type":"text","text":{"value":" today"}}]}}"#),
+			(Some("thread.message.delta"), r#"{"id":"msg_001","object":"thread.message.delta","delta":{"content":[{"index":0,"type":"text","text":{"value":"?"}}]}}"#),
+			(Some("thread.message.completed"), r#"{"id":"msg_001","object":"thread.message","created_at":1710348076,"assistant_id":"asst_123","thread_id":"thread_123","run_id":"run_123","status":"completed","incomplete_details":null,"incomplete_at":null,"completed_at":1710348077,"role":"assistant","content":[{"type":"text","text":{"value":"Hello! How can I assist you today?","annotations":[]}}],"metadata":{}}"#),
+			(Some("thread.run.step.completed"), r#"{"id":"step_001","object":"thread.run.step","created_at":1710348076,"run_id":"run_123","assistant_id":"asst_123","thread_id":"thread_123","type":"message_creation","status":"completed","cancelled_at":null,"completed_at":1710348077,"expires_at":1710348675,"failed_at":null,"last_error":null,"step_details":{"type":"message_creation","message_creation":{"message_id":"msg_001"}},"usage":{"prompt_tokens":20,"completion_tokens":11,"total_tokens":31}}"#),
+			(Some("done"), r"[DONE]"),
+		],
+	},
+	#[cfg(feature = "threads")]
+	MockOperation {
+		id: "getThread",
+		method: "GET",
+		path: "/threads/{thread_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"thread_abc123","object":"thread","created_at":1699014083,"metadata":{},"tool_resources":{"code_interpreter":{"file_ids":[]}}}"#,
+		events: &[],
+	},
+	#[cfg(feature = "threads")]
+	MockOperation {
+		id: "modifyThread",
+		method: "POST",
+		path: "/threads/{thread_id}",
+		validate: Some(validate::<super::ModifyThreadRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"thread_abc123","object":"thread","created_at":1699014083,"metadata":{"modified":"true","user":"abc123"},"tool_resources":{}}"#,
+		events: &[],
+	},
+	#[cfg(feature = "threads")]
+	MockOperation {
+		id: "deleteThread",
+		method: "DELETE",
+		path: "/threads/{thread_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"thread_abc123","object":"thread.deleted","deleted":true}"#,
+		events: &[],
+	},
+	#[cfg(feature = "threads")]
+	MockOperation {
+		id: "listMessages",
+		method: "GET",
+		path: "/threads/{thread_id}/messages",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"list","data":[{"id":"msg_abc123","object":"thread.message","created_at":1699016383,"assistant_id":null,"thread_id":"thread_abc123","run_id":null,"role":"user","content":[{"type":"text","text":{"value":"How does AI work? Explain it in simple terms.","annotations":[]}}],"attachments":[],"metadata":{}},{"id":"msg_abc456","object":"thread.message","created_at":1699016383,"assistant_id":null,"thread_id":"thread_abc123","run_id":null,"role":"user","content":[{"type":"text","text":{"value":"Hello, what is AI?","annotations":[]}}],"attachments":[],"metadata":{}}],"first_id":"msg_abc123","last_id":"msg_abc456","has_more":false}"#,
+		events: &[],
+	},
+	#[cfg(feature = "threads")]
+	MockOperation {
+		id: "createMessage",
+		method: "POST",
+		path: "/threads/{thread_id}/messages",
+		validate: Some(validate::<super::CreateMessageRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"msg_abc123","object":"thread.message","created_at":1713226573,"assistant_id":null,"thread_id":"thread_abc123","run_id":null,"role":"user","content":[{"type":"text","text":{"value":"How does AI work? Explain it in simple terms.","annotations":[]}}],"attachments":[],"metadata":{}}"#,
+		events: &[],
+	},
+	#[cfg(feature = "threads")]
+	MockOperation {
+		id: "getMessage",
+		method: "GET",
+		path: "/threads/{thread_id}/messages/{message_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"msg_abc123","object":"thread.message","created_at":1699017614,"assistant_id":null,"thread_id":"thread_abc123","run_id":null,"role":"user","content":[{"type":"text","text":{"value":"How does AI work? Explain it in simple terms.","annotations":[]}}],"attachments":[],"metadata":{}}"#,
+		events: &[],
+	},
+	#[cfg(feature = "threads")]
+	MockOperation {
+		id: "modifyMessage",
+		method: "POST",
+		path: "/threads/{thread_id}/messages/{message_id}",
+		validate: Some(validate::<super::ModifyMessageRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"msg_abc123","object":"thread.message","created_at":1699017614,"assistant_id":null,"thread_id":"thread_abc123","run_id":null,"role":"user","content":[{"type":"text","text":{"value":"How does AI work? Explain it in simple terms.","annotations":[]}}],"file_ids":[],"metadata":{"modified":"true","user":"abc123"}}"#,
+		events: &[],
+	},
+	#[cfg(feature = "threads")]
+	MockOperation {
+		id: "deleteMessage",
+		method: "DELETE",
+		path: "/threads/{thread_id}/messages/{message_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"msg_abc123","object":"thread.message.deleted","deleted":true}"#,
+		events: &[],
+	},
+	#[cfg(feature = "threads")]
+	MockOperation {
+		id: "listRuns",
+		method: "GET",
+		path: "/threads/{thread_id}/runs",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"list","data":[{"id":"run_abc123","object":"thread.run","created_at":1699075072,"assistant_id":"asst_abc123","thread_id":"thread_abc123","status":"completed","started_at":1699075072,"expires_at":null,"cancelled_at":null,"failed_at":null,"completed_at":1699075073,"last_error":null,"model":"gpt-4o","instructions":null,"incomplete_details":null,"tools":[{"type":"code_interpreter"}],"tool_resources":{"code_interpreter":{"file_ids":["file-abc123","file-abc456"]}},"metadata":{},"usage":{"prompt_tokens":123,"completion_tokens":456,"total_tokens":579},"temperature":1.0,"top_p":1.0,"max_prompt_tokens":1000,"max_completion_tokens":1000,"truncation_strategy":{"type":"auto","last_messages":null},"response_format":"auto","tool_choice":"auto","parallel_tool_calls":true},{"id":"run_abc456","object":"thread.run","created_at":1699063290,"assistant_id":"asst_abc123","thread_id":"thread_abc123","status":"completed","started_at":1699063290,"expires_at":null,"cancelled_at":null,"failed_at":null,"completed_at":1699063291,"last_error":null,"model":"gpt-4o","instructions":null,"incomplete_details":null,"tools":[{"type":"code_interpreter"}],"tool_resources":{"code_interpreter":{"file_ids":["file-abc123","file-abc456"]}},"metadata":{},"usage":{"prompt_tokens":123,"completion_tokens":456,"total_tokens":579},"temperature":1.0,"top_p":1.0,"max_prompt_tokens":1000,"max_completion_tokens":1000,"truncation_strategy":{"type":"auto","last_messages":null},"response_format":"auto","tool_choice":"auto","parallel_tool_calls":true}],"first_id":"run_abc123","last_id":"run_abc456","has_more":false}"#,
+		events: &[],
+	},
+	#[cfg(feature = "threads")]
+	MockOperation {
+		id: "createRun",
+		method: "POST",
+		path: "/threads/{thread_id}/runs",
+		validate: Some(validate::<super::CreateRunRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"run_abc123","object":"thread.run","created_at":1699063290,"assistant_id":"asst_abc123","thread_id":"thread_abc123","status":"queued","started_at":1699063290,"expires_at":null,"cancelled_at":null,"failed_at":null,"completed_at":1699063291,"last_error":null,"model":"gpt-4o","instructions":null,"incomplete_details":null,"tools":[{"type":"code_interpreter"}],"metadata":{},"usage":null,"temperature":1.0,"top_p":1.0,"max_prompt_tokens":1000,"max_completion_tokens":1000,"truncation_strategy":{"type":"auto","last_messages":null},"response_format":"auto","tool_choice":"auto","parallel_tool_calls":true}"#,
+		events: &[
+			(Some("thread.run.step.created"), r#"{"id":"step_001","object":"thread.run.step","created_at":1710330641,"run_id":"run_123","assistant_id":"asst_123","thread_id":"thread_123","type":"message_creation","status":"in_progress","cancelled_at":null,"completed_at":null,"expires_at":1710331240,"failed_at":null,"last_error":null,"step_details":{"type":"message_creation","message_creation":{"message_id":"msg_001"}},"usage":null}"#),
+			(Some("thread.run.step.in_progress"), r#"{"id":"step_001","object":"thread.run.step","created_at":1710330641,"run_id":"run_123","assistant_id":"asst_123","thread_id":"thread_123","type":"message_creation","status":"in_progress","cancelled_at":null,"completed_at":null,"expires_at":1710331240,"failed_at":null,"last_error":null,"step_details":{"type":"message_creation","message_creation":{"message_id":"msg_001"}},"usage":null}"#),
+			(Some("thread.message.created"), r#"{"id":"msg_001","object":"thread.message","created_at":1710330641,"assistant_id":"asst_123","thread_id":"thread_123","run_id":"run_123","status":"in_progress","incomplete_details":null,"incomplete_at":null,"completed_at":null,"role":"assistant","content":[],"metadata":{}}"#),
+			(Some("thread.message.in_progress"), r#"{"id":"msg_001","object":"thread.message","created_at":1710330641,"assistant_id":"asst_123","thread_id":"thread_123","run_id":"run_123","status":"in_progress","incomplete_details":null,"incomplete_at":null,"completed_at":null,"role":"assistant","content":[],"metadata":{}}"#),
+			(Some("thread.message.delta"), r#"{"id":"msg_001","object":"thread.message.delta","delta":{"content":[{"index":0,"type":"text","text":{"value":"Hello","annotations":[]}}]}}"#),
+			(Some("thread.message.delta"), r#"{"id":"msg_001","object":"thread.message.delta","delta":{"content":[{"index":0,"type":"text","text":{"value":" today"}}]}}"#),
+			(Some("thread.message.delta"), r#"{"id":"msg_001","object":"thread.message.delta","delta":{"content":[{"index":0,"type":"text","text":{"value":"?"}}]}}"#),
+			(Some("thread.message.completed"), r#"{"id":"msg_001","object":"thread.message","created_at":1710330641,"assistant_id":"asst_123","thread_id":"thread_123","run_id":"run_123","status":"completed","incomplete_details":null,"incomplete_at":null,"completed_at":1710330642,"role":"assistant","content":[{"type":"text","text":{"value":"Hello! How can I assist you today?","annotations":[]}}],"metadata":{}}"#),
+			(Some("thread.run.step.completed"), r#"{"id":"step_001","object":"thread.run.step","created_at":1710330641,"run_id":"run_123","assistant_id":"asst_123","thread_id":"thread_123","type":"message_creation","status":"completed","cancelled_at":null,"completed_at":1710330642,"expires_at":1710331240,"failed_at":null,"last_error":null,"step_details":{"type":"message_creation","message_creation":{"message_id":"msg_001"}},"usage":{"prompt_tokens":20,"completion_tokens":11,"total_tokens":31}}"#),
+			(Some("done"), r"[DONE]"),
+		],
+	},
+	#[cfg(feature = "threads")]
+	MockOperation {
+		id: "getRun",
+		method: "GET",
+		path: "/threads/{thread_id}/runs/{run_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"run_abc123","object":"thread.run","created_at":1699075072,"assistant_id":"asst_abc123","thread_id":"thread_abc123","status":"completed","started_at":1699075072,"expires_at":null,"cancelled_at":null,"failed_at":null,"completed_at":1699075073,"last_error":null,"model":"gpt-4o","instructions":null,"incomplete_details":null,"tools":[{"type":"code_interpreter"}],"metadata":{},"usage":{"prompt_tokens":123,"completion_tokens":456,"total_tokens":579},"temperature":1.0,"top_p":1.0,"max_prompt_tokens":1000,"max_completion_tokens":1000,"truncation_strategy":{"type":"auto","last_messages":null},"response_format":"auto","tool_choice":"auto","parallel_tool_calls":true}"#,
+		events: &[],
+	},
+	#[cfg(feature = "threads")]
+	MockOperation {
+		id: "modifyRun",
+		method: "POST",
+		path: "/threads/{thread_id}/runs/{run_id}",
+		validate: Some(validate::<super::ModifyRunRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"run_abc123","object":"thread.run","created_at":1699075072,"assistant_id":"asst_abc123","thread_id":"thread_abc123","status":"completed","started_at":1699075072,"expires_at":null,"cancelled_at":null,"failed_at":null,"completed_at":1699075073,"last_error":null,"model":"gpt-4o","instructions":null,"incomplete_details":null,"tools":[{"type":"code_interpreter"}],"tool_resources":{"code_interpreter":{"file_ids":["file-abc123","file-abc456"]}},"metadata":{"user_id":"user_abc123"},"usage":{"prompt_tokens":123,"completion_tokens":456,"total_tokens":579},"temperature":1.0,"top_p":1.0,"max_prompt_tokens":1000,"max_completion_tokens":1000,"truncation_strategy":{"type":"auto","last_messages":null},"response_format":"auto","tool_choice":"auto","parallel_tool_calls":true}"#,
+		events: &[],
+	},
+	#[cfg(feature = "threads")]
+	MockOperation {
+		id: "cancelRun",
+		method: "POST",
+		path: "/threads/{thread_id}/runs/{run_id}/cancel",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"run_abc123","object":"thread.run","created_at":1699076126,"assistant_id":"asst_abc123","thread_id":"thread_abc123","status":"cancelling","started_at":1699076126,"expires_at":1699076726,"cancelled_at":null,"failed_at":null,"completed_at":null,"last_error":null,"model":"gpt-4o","instructions":"You summarize books.","tools":[{"type":"file_search"}],"tool_resources":{"file_search":{"vector_store_ids":["vs_123"]}},"metadata":{},"usage":null,"temperature":1.0,"top_p":1.0,"response_format":"auto","tool_choice":"auto","parallel_tool_calls":true}"#,
+		events: &[],
+	},
+	#[cfg(feature = "threads")]
+	MockOperation {
+		id: "listRunSteps",
+		method: "GET",
+		path: "/threads/{thread_id}/runs/{run_id}/steps",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"list","data":[{"id":"step_abc123","object":"thread.run.step","created_at":1699063291,"run_id":"run_abc123","assistant_id":"asst_abc123","thread_id":"thread_abc123","type":"message_creation","status":"completed","cancelled_at":null,"completed_at":1699063291,"expired_at":null,"failed_at":null,"last_error":null,"step_details":{"type":"message_creation","message_creation":{"message_id":"msg_abc123"}},"usage":{"prompt_tokens":123,"completion_tokens":456,"total_tokens":579}}],"first_id":"step_abc123","last_id":"step_abc456","has_more":false}"#,
+		events: &[],
+	},
+	#[cfg(feature = "threads")]
+	MockOperation {
+		id: "getRunStep",
+		method: "GET",
+		path: "/threads/{thread_id}/runs/{run_id}/steps/{step_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"step_abc123","object":"thread.run.step","created_at":1699063291,"run_id":"run_abc123","assistant_id":"asst_abc123","thread_id":"thread_abc123","type":"message_creation","status":"completed","cancelled_at":null,"completed_at":1699063291,"expired_at":null,"failed_at":null,"last_error":null,"step_details":{"type":"message_creation","message_creation":{"message_id":"msg_abc123"}},"usage":{"prompt_tokens":123,"completion_tokens":456,"total_tokens":579}}"#,
+		events: &[],
+	},
+	#[cfg(feature = "threads")]
+	MockOperation {
+		id: "submitToolOuputsToRun",
+		method: "POST",
+		path: "/threads/{thread_id}/runs/{run_id}/submit_tool_outputs",
+		validate: Some(validate::<super::SubmitToolOutputsRunRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"run_123","object":"thread.run","created_at":1699075592,"assistant_id":"asst_123","thread_id":"thread_123","status":"queued","started_at":1699075592,"expires_at":1699076192,"cancelled_at":null,"failed_at":null,"completed_at":null,"last_error":null,"model":"gpt-4o","instructions":null,"tools":[{"type":"function","function":{"name":"get_current_weather","description":"Get the current weather in a given location","parameters":{"type":"object","properties":{"location":{"type":"string","description":"The city and state, e.g. San Francisco, CA"},"unit":{"type":"string","enum":["celsius","fahrenheit"]}},"required":["location"]}}}],"metadata":{},"usage":null,"temperature":1.0,"top_p":1.0,"max_prompt_tokens":1000,"max_completion_tokens":1000,"truncation_strategy":{"type":"auto","last_messages":null},"response_format":"auto","tool_choice":"auto","parallel_tool_calls":true}"#,
+		events: &[
+			(Some("thread.run.step.completed"), r#"{"id":"step_001","object":"thread.run.step","created_at":1710352449,"run_id":"run_123","assistant_id":"asst_123","thread_id":"thread_123","type":"tool_calls","status":"completed","cancelled_at":null,"completed_at":1710352475,"expires_at":1710353047,"failed_at":null,"last_error":null,"step_details":{"type":"tool_calls","tool_calls":[{"id":"call_iWr0kQ2EaYMaxNdl0v3KYkx7","type":"function","function":{"name":"get_current_weather","arguments":"{\"location\":\"San Francisco, CA\",\"unit\":\"fahrenheit\"}","output":"70 degrees and sunny."}}]},"usage":{"prompt_tokens":291,"completion_tokens":24,"total_tokens":315}}"#),
+			(Some("thread.run.step.created"), r#"{"id":"step_002","object":"thread.run.step","created_at":1710352476,"run_id":"run_123","assistant_id":"asst_123","thread_id":"thread_123","type":"message_creation","status":"in_progress","cancelled_at":null,"completed_at":null,"expires_at":1710353047,"failed_at":null,"last_error":null,"step_details":{"type":"message_creation","message_creation":{"message_id":"msg_002"}},"usage":null}"#),
+			(Some("thread.run.step.in_progress"), r#"{"id":"step_002","object":"thread.run.step","created_at":1710352476,"run_id":"run_123","assistant_id":"asst_123","thread_id":"thread_123","type":"message_creation","status":"in_progress","cancelled_at":null,"completed_at":null,"expires_at":1710353047,"failed_at":null,"last_error":null,"step_details":{"type":"message_creation","message_creation":{"message_id":"msg_002"}},"usage":null}"#),
+			(Some("thread.message.created"), r#"{"id":"msg_002","object":"thread.message","created_at":1710352476,"assistant_id":"asst_123","thread_id":"thread_123","run_id":"run_123","status":"in_progress","incomplete_details":null,"incomplete_at":null,"completed_at":null,"role":"assistant","content":[],"metadata":{}}"#),
+			(Some("thread.message.in_progress"), r#"{"id":"msg_002","object":"thread.message","created_at":1710352476,"assistant_id":"asst_123","thread_id":"thread_123","run_id":"run_123","status":"in_progress","incomplete_details":null,"incomplete_at":null,"completed_at":null,"role":"assistant","content":[],"metadata":{}}"#),
+			(Some("thread.message.delta"), r#"{"id":"msg_002","object":"thread.message.delta","delta":{"content":[{"index":0,"type":"text","text":{"value":"The","annotations":[]}}]}}"#),
+			(Some("thread.message.delta"), r#"{"id":"msg_002","object":"thread.message.delta","delta":{"content":[{"index":0,"type":"text","text":{"value":" current"}}]}}"#),
+			(Some("thread.message.delta"), r#"{"id":"msg_002","object":"thread.message.delta","delta":{"content":[{"index":0,"type":"text","text":{"value":" weather"}}]}}"#),
+			(Some("thread.message.delta"), r#"{"id":"msg_002","object":"thread.message.delta","delta":{"content":[{"index":0,"type":"text","text":{"value":" sunny"}}]}}"#),
+			(Some("thread.message.delta"), r#"{"id":"msg_002","object":"thread.message.delta","delta":{"content":[{"index":0,"type":"text","text":{"value":"."}}]}}"#),
+			(Some("thread.message.completed"), r#"{"id":"msg_002","object":"thread.message","created_at":1710352476,"assistant_id":"asst_123","thread_id":"thread_123","run_id":"run_123","status":"completed","incomplete_details":null,"incomplete_at":null,"completed_at":1710352477,"role":"assistant","content":[{"type":"text","text":{"value":"The current weather in San Francisco, CA is 70 degrees Fahrenheit and sunny.","annotations":[]}}],"metadata":{}}"#),
+			(Some("thread.run.step.completed"), r#"{"id":"step_002","object":"thread.run.step","created_at":1710352476,"run_id":"run_123","assistant_id":"asst_123","thread_id":"thread_123","type":"message_creation","status":"completed","cancelled_at":null,"completed_at":1710352477,"expires_at":1710353047,"failed_at":null,"last_error":null,"step_details":{"type":"message_creation","message_creation":{"message_id":"msg_002"}},"usage":{"prompt_tokens":329,"completion_tokens":18,"total_tokens":347}}"#),
+			(Some("done"), r"[DONE]"),
+		],
+	},
+	#[cfg(feature = "uploads")]
+	MockOperation {
+		id: "createUpload",
+		method: "POST",
+		path: "/uploads",
+		validate: Some(validate::<super::CreateUploadRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"upload_abc123","object":"upload","bytes":2147483648,"created_at":1719184911,"filename":"training_examples.jsonl","purpose":"fine-tune","status":"pending","expires_at":1719127296}"#,
+		events: &[],
+	},
+	#[cfg(feature = "uploads")]
+	MockOperation {
+		id: "cancelUpload",
+		method: "POST",
+		path: "/uploads/{upload_id}/cancel",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"upload_abc123","object":"upload","bytes":2147483648,"created_at":1719184911,"filename":"training_examples.jsonl","purpose":"fine-tune","status":"cancelled","expires_at":1719127296}"#,
+		events: &[],
+	},
+	#[cfg(feature = "uploads")]
+	MockOperation {
+		id: "completeUpload",
+		method: "POST",
+		path: "/uploads/{upload_id}/complete",
+		validate: Some(validate::<super::CompleteUploadRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"string","created_at":0,"filename":"string","bytes":0,"purpose":"string","status":"pending","expires_at":0}"#,
+		events: &[],
+	},
+	#[cfg(feature = "uploads")]
+	MockOperation {
+		id: "addUploadPart",
+		method: "POST",
+		path: "/uploads/{upload_id}/parts",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"part_def456","object":"upload.part","created_at":1719185911,"upload_id":"upload_abc123"}"#,
+		events: &[],
+	},
+	#[cfg(feature = "vector_stores")]
+	MockOperation {
+		id: "listVectorStores",
+		method: "GET",
+		path: "/vector_stores",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"list","data":[{"id":"vs_abc123","object":"vector_store","created_at":1699061776,"name":"Support FAQ","bytes":139920,"file_counts":{"in_progress":0,"completed":3,"failed":0,"cancelled":0,"total":3}},{"id":"vs_abc456","object":"vector_store","created_at":1699061776,"name":"Support FAQ v2","bytes":139920,"file_counts":{"in_progress":0,"completed":3,"failed":0,"cancelled":0,"total":3}}],"first_id":"vs_abc123","last_id":"vs_abc456","has_more":false}"#,
+		events: &[],
+	},
+	#[cfg(feature = "vector_stores")]
+	MockOperation {
+		id: "createVectorStore",
+		method: "POST",
+		path: "/vector_stores",
+		validate: Some(validate::<super::CreateVectorStoreRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"vs_abc123","object":"vector_store","created_at":1699061776,"name":"Support FAQ","bytes":139920,"file_counts":{"in_progress":0,"completed":3,"failed":0,"cancelled":0,"total":3}}"#,
+		events: &[],
+	},
+	#[cfg(feature = "vector_stores")]
+	MockOperation {
+		id: "getVectorStore",
+		method: "GET",
+		path: "/vector_stores/{vector_store_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"vs_abc123","object":"vector_store","created_at":1699061776}"#,
+		events: &[],
+	},
+	#[cfg(feature = "vector_stores")]
+	MockOperation {
+		id: "modifyVectorStore",
+		method: "POST",
+		path: "/vector_stores/{vector_store_id}",
+		validate: Some(validate::<super::UpdateVectorStoreRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"vs_abc123","object":"vector_store","created_at":1699061776,"name":"Support FAQ","bytes":139920,"file_counts":{"in_progress":0,"completed":3,"failed":0,"cancelled":0,"total":3}}"#,
+		events: &[],
+	},
+	#[cfg(feature = "vector_stores")]
+	MockOperation {
+		id: "deleteVectorStore",
+		method: "DELETE",
+		path: "/vector_stores/{vector_store_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"string","deleted":false,"object":"vector_store.deleted"}"#,
+		events: &[],
+	},
+	#[cfg(feature = "vector_stores")]
+	MockOperation {
+		id: "createVectorStoreFileBatch",
+		method: "POST",
+		path: "/vector_stores/{vector_store_id}/file_batches",
+		validate: Some(validate::<super::CreateVectorStoreFileBatchRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"string","object":"vector_store.files_batch","created_at":0,"vector_store_id":"string","status":"in_progress","file_counts":{"in_progress":0,"completed":0,"failed":0,"cancelled":0,"total":0}}"#,
+		events: &[],
+	},
+	#[cfg(feature = "vector_stores")]
+	MockOperation {
+		id: "getVectorStoreFileBatch",
+		method: "GET",
+		path: "/vector_stores/{vector_store_id}/file_batches/{batch_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"string","object":"vector_store.files_batch","created_at":0,"vector_store_id":"string","status":"in_progress","file_counts":{"in_progress":0,"completed":0,"failed":0,"cancelled":0,"total":0}}"#,
+		events: &[],
+	},
+	#[cfg(feature = "vector_stores")]
+	MockOperation {
+		id: "cancelVectorStoreFileBatch",
+		method: "POST",
+		path: "/vector_stores/{vector_store_id}/file_batches/{batch_id}/cancel",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"string","object":"vector_store.files_batch","created_at":0,"vector_store_id":"string","status":"in_progress","file_counts":{"in_progress":0,"completed":0,"failed":0,"cancelled":0,"total":0}}"#,
+		events: &[],
+	},
+	#[cfg(feature = "vector_stores")]
+	MockOperation {
+		id: "listFilesInVectorStoreBatch",
+		method: "GET",
+		path: "/vector_stores/{vector_store_id}/file_batches/{batch_id}/files",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"list","data":[{"id":"file-abc123","object":"vector_store.file","created_at":1699061776,"vector_store_id":"vs_abc123"},{"id":"file-abc456","object":"vector_store.file","created_at":1699061776,"vector_store_id":"vs_abc123"}],"first_id":"file-abc123","last_id":"file-abc456","has_more":false}"#,
+		events: &[],
+	},
+	#[cfg(feature = "vector_stores")]
+	MockOperation {
+		id: "listVectorStoreFiles",
+		method: "GET",
+		path: "/vector_stores/{vector_store_id}/files",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"list","data":[{"id":"file-abc123","object":"vector_store.file","created_at":1699061776,"vector_store_id":"vs_abc123"},{"id":"file-abc456","object":"vector_store.file","created_at":1699061776,"vector_store_id":"vs_abc123"}],"first_id":"file-abc123","last_id":"file-abc456","has_more":false}"#,
+		events: &[],
+	},
+	#[cfg(feature = "vector_stores")]
+	MockOperation {
+		id: "createVectorStoreFile",
+		method: "POST",
+		path: "/vector_stores/{vector_store_id}/files",
+		validate: Some(validate::<super::CreateVectorStoreFileRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"file-abc123","object":"vector_store.file","created_at":1699061776,"usage_bytes":1234,"vector_store_id":"vs_abcd","status":"completed","last_error":null}"#,
+		events: &[],
+	},
+	#[cfg(feature = "vector_stores")]
+	MockOperation {
+		id: "getVectorStoreFile",
+		method: "GET",
+		path: "/vector_stores/{vector_store_id}/files/{file_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"file-abc123","object":"vector_store.file","created_at":1699061776,"vector_store_id":"vs_abcd","status":"completed","last_error":null}"#,
+		events: &[],
+	},
+	#[cfg(feature = "vector_stores")]
+	MockOperation {
+		id: "updateVectorStoreFileAttributes",
+		method: "POST",
+		path: "/vector_stores/{vector_store_id}/files/{file_id}",
+		validate: Some(validate::<super::UpdateVectorStoreFileAttributesRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"string","object":"vector_store.file","usage_bytes":0,"created_at":0,"vector_store_id":"string","status":"in_progress","last_error":{"code":"server_error","message":"string"}}"#,
+		events: &[],
+	},
+	#[cfg(feature = "vector_stores")]
+	MockOperation {
+		id: "deleteVectorStoreFile",
+		method: "DELETE",
+		path: "/vector_stores/{vector_store_id}/files/{file_id}",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"id":"string","deleted":false,"object":"vector_store.file.deleted"}"#,
+		events: &[],
+	},
+	#[cfg(feature = "vector_stores")]
+	MockOperation {
+		id: "retrieveVectorStoreFileContent",
+		method: "GET",
+		path: "/vector_stores/{vector_store_id}/files/{file_id}/content",
+		validate: None,
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"vector_store.file_content.page","data":[],"has_more":false,"next_page":"string"}"#,
+		events: &[],
+	},
+	#[cfg(feature = "vector_stores")]
+	MockOperation {
+		id: "searchVectorStore",
+		method: "POST",
+		path: "/vector_stores/{vector_store_id}/search",
+		validate: Some(validate::<super::VectorStoreSearchRequest>),
+		status: 200,
+		content_type: "application/json",
+		response: r#"{"object":"vector_store.search_results.page","search_query":"What is the return policy?","data":[{"file_id":"file_123","filename":"document.pdf","score":0.95,"attributes":{"author":"John Doe","date":"2023-01-01"},"content":[{"type":"text","text":"Relevant chunk"}]},{"file_id":"file_456","filename":"notes.txt","score":0.89,"attributes":{"author":"Jane Smith","date":"2023-01-02"},"content":[{"type":"text","text":"Sample text content from the vector store."}]}],"has_more":false,"next_page":null}"#,
+		events: &[],
+	},
+];
@@ -1,44 +1,94 @@
+#[cfg(feature = "administration")]
+pub mod administration;
+#[cfg(feature = "assistants")]
 pub mod assistants;
-pub mod assistants_chat;
-pub mod assistants_chat_responses;
-pub mod assistants_chat_responses_threads;
-pub mod assistants_threads;
+#[cfg(feature = "audio")]
 pub mod audio;
+#[cfg(feature = "audit_logs")]
+pub mod audit_logs;
+#[cfg(feature = "batch")]
+pub mod batch;
+#[cfg(feature = "chat")]
 pub mod chat;
-pub mod chat_completions;
-pub mod chat_responses;
+#[cfg(feature = "completions")]
 pub mod completions;
+#[cfg(feature = "embeddings")]
 pub mod embeddings;
+#[cfg(feature = "files")]
+pub mod files;
+#[cfg(feature = "fine_tuning")]
 pub mod fine_tuning;
+#[cfg(feature = "images")]
 pub mod images;
+#[cfg(feature = "models")]
+pub mod models;
+#[cfg(feature = "moderations")]
 pub mod moderations;
-pub mod organization;
+#[cfg(feature = "realtime")]
 pub mod realtime;
+#[cfg(feature = "responses")]
 pub mod responses;
-pub mod responses_vector_stores;
+#[cfg(feature = "shared")]
+pub mod shared;
+#[cfg(feature = "threads")]
 pub mod threads;
+#[cfg(feature = "uploads")]
 pub mod uploads;
+#[cfg(feature = "vector_stores")]
 pub mod vector_stores;
+pub mod aliases;
+#[cfg(feature = "realtime-session")]
+pub mod realtime_session;
+#[cfg(feature = "mock-server")]
+pub mod mock_server;
+#[cfg(feature = "client")]
+pub mod client;
+#[cfg(feature = "client")]
+pub mod cassette;
+#[cfg(feature = "client")]
+pub mod retry;
+#[cfg(feature = "client")]
+pub mod provider;
+#[cfg(feature = "client")]
+pub mod download;
 
 
+#[cfg(feature = "administration")]
+pub use administration::*;
+#[cfg(feature = "assistants")]
 pub use assistants::*;
-pub use assistants_chat::*;
-pub use assistants_chat_responses::*;
-pub use assistants_chat_responses_threads::*;
-pub use assistants_threads::*;
+#[cfg(feature = "audio")]
 pub use audio::*;
+#[cfg(feature = "audit_logs")]
+pub use audit_logs::*;
+#[cfg(feature = "batch")]
+pub use batch::*;
+#[cfg(feature = "chat")]
 pub use chat::*;
-pub use chat_completions::*;
-pub use chat_responses::*;
+#[cfg(feature = "completions")]
 pub use completions::*;
+#[cfg(feature = "embeddings")]
 pub use embeddings::*;
+#[cfg(feature = "files")]
+pub use files::*;
+#[cfg(feature = "fine_tuning")]
 pub use fine_tuning::*;
+#[cfg(feature = "images")]
 pub use images::*;
+#[cfg(feature = "models")]
+pub use models::*;
+#[cfg(feature = "moderations")]
 pub use moderations::*;
-pub use organization::*;
+#[cfg(feature = "realtime")]
 pub use realtime::*;
+#[cfg(feature = "responses")]
 pub use responses::*;
-pub use responses_vector_stores::*;
+#[cfg(feature = "shared")]
+pub use shared::*;
+#[cfg(feature = "threads")]
 pub use threads::*;
+#[cfg(feature = "uploads")]
 pub use uploads::*;
+#[cfg(feature = "vector_stores")]
 pub use vector_stores::*;
+pub use aliases::*;
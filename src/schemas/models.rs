@@ -0,0 +1,37 @@
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DeleteModelResponse {
+	pub id: String,
+	pub deleted: bool,
+	pub object: String,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ListModelsResponse {
+	pub object: ListModelsResponseObject,
+	pub data: Vec<Model>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ListModelsResponseObject {
+	List,
+}
+/// Describes an OpenAI model offering that can be used with the API.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Model {
+	/// The model identifier, which can be referenced in the API endpoints.
+	pub id: String,
+	/// The Unix timestamp (in seconds) when the model was created.
+	pub created: i64,
+	/// The object type, which is always "model".
+	pub object: ModelObject,
+	/// The organization that owns the model.
+	pub owned_by: String,
+}
+/// The object type, which is always "model".
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ModelObject {
+	Model,
+}
@@ -1,7 +1,33 @@
+use super::aliases::CreateModerationRequestInputStringArray;
+use super::aliases::CreateModerationRequestInputVariedArray;
+use super::aliases::CreateModerationRequestModel;
 
-use std::collections::HashMap;
 use serde::{Serialize, Deserialize};
 
+/// # Examples
+///
+/// Single string:
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateModerationRequest {
+///     input: CreateModerationRequestInput::String("I want to kill them.".to_string()),
+///     model: None,
+/// };
+/// ```
+///
+/// Image and text:
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateModerationRequest {
+///     input: CreateModerationRequestInput::CreateModerationRequestInputVariedArray(vec![
+///         CreateModerationRequestInputItems::Object(serde_json::json!({"type":"text","text":"...text to classify goes here..."})),
+///         CreateModerationRequestInputItems::Object(serde_json::json!({"type":"image_url","image_url":{"url":"https://example.com/image.png"}})),
+///     ]),
+///     model: Some(serde_json::json!("omni-moderation-latest")),
+/// };
+/// ```
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateModerationRequest {
 	/// Input (or inputs) to classify. Can be a single string, an array of strings, or
@@ -29,3 +55,154 @@ pub enum CreateModerationRequestInput {
 pub enum CreateModerationRequestInputItems {
 	Object(serde_json::Value),
 }
+/// Represents if a given text input is potentially harmful.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CreateModerationResponse {
+	/// The unique identifier for the moderation request.
+	pub id: String,
+	/// The model used to generate the moderation results.
+	pub model: String,
+	/// A list of moderation objects.
+	pub results: Vec<CreateModerationResponseResultsItem>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CreateModerationResponseResultsItem {
+	/// Whether any of the below categories are flagged.
+	pub flagged: bool,
+	/// A list of the categories, and whether they are flagged or not.
+	pub categories: CreateModerationResponseResultsItemCategories,
+	/// A list of the categories along with their scores as predicted by model.
+	pub category_scores: CreateModerationResponseResultsItemCategoryScores,
+	/// A list of the categories along with the input type(s) that the score applies to.
+	pub category_applied_input_types: CreateModerationResponseResultsItemCategoryAppliedInputTypes,
+}
+/// A list of the categories, and whether they are flagged or not.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CreateModerationResponseResultsItemCategories {
+	/// Content that expresses, incites, or promotes hate based on race, gender, ethnicity, religion,
+	/// nationality, sexual orientation, disability status, or caste. Hateful content aimed at non-protected
+	/// groups (e.g., chess players) is harassment.
+	pub hate: bool,
+	/// Hateful content that also includes violence or serious harm towards the targeted group based on
+	/// race, gender, ethnicity, religion, nationality, sexual orientation, disability status, or caste.
+	#[serde(rename = "hate/threatening")]
+	pub hate_threatening: bool,
+	/// Content that expresses, incites, or promotes harassing language towards any target.
+	pub harassment: bool,
+	/// Harassment content that also includes violence or serious harm towards any target.
+	#[serde(rename = "harassment/threatening")]
+	pub harassment_threatening: bool,
+	/// Content that includes instructions or advice that facilitate the planning or execution of
+	/// wrongdoing, or that gives advice or instruction on how to commit illicit acts. For example, "how to
+	/// shoplift" would fit this category.
+	pub illicit: Option<bool>,
+	/// Content that includes instructions or advice that facilitate the planning or execution of wrongdoing
+	/// that also includes violence, or that gives advice or instruction on the procurement of any weapon.
+	#[serde(rename = "illicit/violent")]
+	pub illicit_violent: Option<bool>,
+	/// Content that promotes, encourages, or depicts acts of self-harm, such as suicide, cutting, and
+	/// eating disorders.
+	#[serde(rename = "self-harm")]
+	pub self_harm: bool,
+	/// Content where the speaker expresses that they are engaging or intend to engage in acts of self-harm,
+	/// such as suicide, cutting, and eating disorders.
+	#[serde(rename = "self-harm/intent")]
+	pub self_harm_intent: bool,
+	/// Content that encourages performing acts of self-harm, such as suicide, cutting, and eating
+	/// disorders, or that gives instructions or advice on how to commit such acts.
+	#[serde(rename = "self-harm/instructions")]
+	pub self_harm_instructions: bool,
+	/// Content meant to arouse sexual excitement, such as the description of sexual activity, or that
+	/// promotes sexual services (excluding sex education and wellness).
+	pub sexual: bool,
+	/// Sexual content that includes an individual who is under 18 years old.
+	#[serde(rename = "sexual/minors")]
+	pub sexual_minors: bool,
+	/// Content that depicts death, violence, or physical injury.
+	pub violence: bool,
+	/// Content that depicts death, violence, or physical injury in graphic detail.
+	#[serde(rename = "violence/graphic")]
+	pub violence_graphic: bool,
+}
+/// A list of the categories along with the input type(s) that the score applies to.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CreateModerationResponseResultsItemCategoryAppliedInputTypes {
+	/// The applied input type(s) for the category 'hate'.
+	pub hate: Vec<CreateModerationResponseResultsItemCategoryAppliedInputTypesItem>,
+	/// The applied input type(s) for the category 'hate/threatening'.
+	#[serde(rename = "hate/threatening")]
+	pub hate_threatening: Vec<CreateModerationResponseResultsItemCategoryAppliedInputTypesItem>,
+	/// The applied input type(s) for the category 'harassment'.
+	pub harassment: Vec<CreateModerationResponseResultsItemCategoryAppliedInputTypesItem>,
+	/// The applied input type(s) for the category 'harassment/threatening'.
+	#[serde(rename = "harassment/threatening")]
+	pub harassment_threatening: Vec<CreateModerationResponseResultsItemCategoryAppliedInputTypesItem>,
+	/// The applied input type(s) for the category 'illicit'.
+	pub illicit: Vec<CreateModerationResponseResultsItemCategoryAppliedInputTypesItem>,
+	/// The applied input type(s) for the category 'illicit/violent'.
+	#[serde(rename = "illicit/violent")]
+	pub illicit_violent: Vec<CreateModerationResponseResultsItemCategoryAppliedInputTypesItem>,
+	/// The applied input type(s) for the category 'self-harm'.
+	#[serde(rename = "self-harm")]
+	pub self_harm: Vec<CreateModerationResponseResultsItemCategoryAppliedInputTypesItem>,
+	/// The applied input type(s) for the category 'self-harm/intent'.
+	#[serde(rename = "self-harm/intent")]
+	pub self_harm_intent: Vec<CreateModerationResponseResultsItemCategoryAppliedInputTypesItem>,
+	/// The applied input type(s) for the category 'self-harm/instructions'.
+	#[serde(rename = "self-harm/instructions")]
+	pub self_harm_instructions: Vec<CreateModerationResponseResultsItemCategoryAppliedInputTypesItem>,
+	/// The applied input type(s) for the category 'sexual'.
+	pub sexual: Vec<CreateModerationResponseResultsItemCategoryAppliedInputTypesItem>,
+	/// The applied input type(s) for the category 'sexual/minors'.
+	#[serde(rename = "sexual/minors")]
+	pub sexual_minors: Vec<CreateModerationResponseResultsItemCategoryAppliedInputTypesItem>,
+	/// The applied input type(s) for the category 'violence'.
+	pub violence: Vec<CreateModerationResponseResultsItemCategoryAppliedInputTypesItem>,
+	/// The applied input type(s) for the category 'violence/graphic'.
+	#[serde(rename = "violence/graphic")]
+	pub violence_graphic: Vec<CreateModerationResponseResultsItemCategoryAppliedInputTypesItem>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CreateModerationResponseResultsItemCategoryAppliedInputTypesItem {
+	Text,
+	Image,
+}
+/// A list of the categories along with their scores as predicted by model.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CreateModerationResponseResultsItemCategoryScores {
+	/// The score for the category 'hate'.
+	pub hate: f64,
+	/// The score for the category 'hate/threatening'.
+	#[serde(rename = "hate/threatening")]
+	pub hate_threatening: f64,
+	/// The score for the category 'harassment'.
+	pub harassment: f64,
+	/// The score for the category 'harassment/threatening'.
+	#[serde(rename = "harassment/threatening")]
+	pub harassment_threatening: f64,
+	/// The score for the category 'illicit'.
+	pub illicit: f64,
+	/// The score for the category 'illicit/violent'.
+	#[serde(rename = "illicit/violent")]
+	pub illicit_violent: f64,
+	/// The score for the category 'self-harm'.
+	#[serde(rename = "self-harm")]
+	pub self_harm: f64,
+	/// The score for the category 'self-harm/intent'.
+	#[serde(rename = "self-harm/intent")]
+	pub self_harm_intent: f64,
+	/// The score for the category 'self-harm/instructions'.
+	#[serde(rename = "self-harm/instructions")]
+	pub self_harm_instructions: f64,
+	/// The score for the category 'sexual'.
+	pub sexual: f64,
+	/// The score for the category 'sexual/minors'.
+	#[serde(rename = "sexual/minors")]
+	pub sexual_minors: f64,
+	/// The score for the category 'violence'.
+	pub violence: f64,
+	/// The score for the category 'violence/graphic'.
+	#[serde(rename = "violence/graphic")]
+	pub violence_graphic: f64,
+}
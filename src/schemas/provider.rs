@@ -0,0 +1,171 @@
+//! Where the client sends requests and how it authenticates them, for
+//!  OpenAI itself, Azure OpenAI and OpenAI-compatible servers.
+//!
+//! A [`Provider`] has a base URL, which can name a deployment with
+//!  `{deployment}`, query parameters sent with every request, such as
+//!  Azure's `api-version`, the header the API key goes in, and rewrites
+//!  from the spec's paths to the provider's own. Operations a provider
+//!  doesn't support can be disabled by their `operationId`, so calls to
+//!  them fail with [`ClientError::Unsupported`] before anything is sent.
+use super::client::{encode, ClientError, DEFAULT_BASE_URL};
+
+/// How requests carry the API key
+#[derive(Debug, Clone, PartialEq)]
+pub enum Auth {
+    /// In `Authorization: Bearer <key>`, as OpenAI expects
+    Bearer,
+    /// As the whole value of a header, such as Azure's `api-key`
+    Header(String),
+    /// Not at all, for servers which don't need a key
+    None,
+}
+
+/// Where requests are sent and how they're authenticated
+#[derive(Debug, Clone, PartialEq)]
+pub struct Provider {
+    /// A name for the provider, used in errors
+    pub name: String,
+    /// The URL paths are appended to, which can contain `{deployment}`
+    pub base_url: String,
+    /// The deployment which replaces `{deployment}`
+    pub deployment: Option<String>,
+    /// How requests carry the API key
+    pub auth: Auth,
+    /// Query parameters sent with every request
+    pub query: Vec<(String, String)>,
+    /// Path prefixes, from the spec's paths, and what replaces them,
+    ///  which can contain `{deployment}` too. The first match wins
+    pub rewrites: Vec<(String, String)>,
+    /// Operations, by `operationId`, which the provider doesn't support
+    pub disabled_operations: Vec<String>,
+}
+impl Default for Provider {
+    fn default() -> Self {
+        Provider::openai()
+    }
+}
+impl Provider {
+    /// OpenAI itself
+    pub fn openai() -> Self {
+        Provider {
+            name: "openai".to_string(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            deployment: None,
+            auth: Auth::Bearer,
+            query: Vec::new(),
+            rewrites: Vec::new(),
+            disabled_operations: Vec::new(),
+        }
+    }
+    /// An Azure OpenAI resource, such as `my-resource` for
+    ///  `https://my-resource.openai.azure.com`, sending model calls to a
+    ///  deployment
+    ///
+    /// Calls which run a model go to
+    ///  `/openai/deployments/{deployment}/...`, and everything else, such
+    ///  as files and batches, to `/openai/...`, with the `api-version`
+    ///  as a query parameter and the key in `api-key`.
+    pub fn azure(resource: &str, deployment: impl Into<String>, api_version: impl Into<String>) -> Self {
+        let deployment_paths = [
+            "/chat/completions",
+            "/completions",
+            "/embeddings",
+            "/audio/",
+            "/images/",
+        ];
+
+        Provider {
+            name: "azure".to_string(),
+            base_url: format!("https://{}.openai.azure.com/openai", resource),
+            deployment: Some(deployment.into()),
+            auth: Auth::Header("api-key".to_string()),
+            query: vec![("api-version".to_string(), api_version.into())],
+            rewrites: deployment_paths.iter()
+                .map(|path| (path.to_string(), format!("/deployments/{{deployment}}{}", path)))
+                .collect(),
+            disabled_operations: Vec::new(),
+        }
+    }
+    /// A self-hosted or third-party server which speaks the OpenAI API
+    pub fn compatible(name: impl Into<String>, base_url: impl Into<String>) -> Self {
+        Provider {
+            name: name.into(),
+            ..Provider::openai()
+        }.with_base_url(base_url)
+    }
+
+    /// Sends requests to another base URL
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into()
+            .trim_end_matches('/')
+            .to_string();
+        self
+    }
+    /// Sends requests to another deployment
+    pub fn with_deployment(mut self, deployment: impl Into<String>) -> Self {
+        self.deployment = Some(deployment.into());
+        self
+    }
+    /// Carries the API key another way
+    pub fn with_auth(mut self, auth: Auth) -> Self {
+        self.auth = auth;
+        self
+    }
+    /// Sends a query parameter with every request, replacing any
+    ///  earlier one of the same name
+    pub fn with_query(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        let name = name.into();
+        self.query.retain(|(query, _value)| *query != name);
+        self.query.push((name, value.into()));
+        self
+    }
+    /// Sends paths starting with `from` to `to` instead, such as
+    ///  `/chat/completions` to `/v2/chat`
+    pub fn rewrite(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.rewrites.push((from.into(), to.into()));
+        self
+    }
+    /// Fails calls to an operation, such as `createResponse`, which the
+    ///  provider doesn't support
+    pub fn disable(mut self, operation_id: impl Into<String>) -> Self {
+        self.disabled_operations.push(operation_id.into());
+        self
+    }
+
+    /// Whether the provider supports an operation
+    pub fn supports(&self, operation_id: &str) -> bool {
+        !self.disabled_operations.iter().any(|disabled| disabled == operation_id)
+    }
+    /// Fails with [`ClientError::Unsupported`] unless the provider supports
+    ///  an operation
+    pub fn check(&self, operation_id: &str) -> Result<(), ClientError> {
+        if self.supports(operation_id) {
+            Ok(())
+        } else {
+            Err(ClientError::Unsupported {
+                operation_id: operation_id.to_string(),
+                provider: self.name.clone(),
+            })
+        }
+    }
+    /// The URL for a path from the spec, before any query parameters
+    pub fn url(&self, path: &str) -> String {
+        let path = self.rewrites.iter()
+            .find_map(|(from, to)| path.strip_prefix(from.as_str()).map(|rest| format!("{}{}", to, rest)))
+            .unwrap_or_else(|| path.to_string());
+        let url = format!("{}{}", self.base_url, path);
+
+        match self.deployment {
+            Some(ref deployment) => url.replace("{deployment}", &encode(deployment)),
+            None => url,
+        }
+    }
+    /// The header carrying an API key, if any
+    pub fn auth_header(&self, api_key: &str) -> Option<(String, String)> {
+        match self.auth {
+            Auth::Bearer => Some(("authorization".to_string(), format!("Bearer {}", api_key))),
+            Auth::Header(ref name) => Some((name.clone(), api_key.to_string())),
+            Auth::None => None,
+        }
+    }
+}
@@ -1,73 +1,1897 @@
+use super::aliases::Metadata;
+use super::aliases::VoiceIdsShared;
+use super::shared::LogProbProperties;
 
-use std::collections::HashMap;
 use serde::{Serialize, Deserialize};
 
+/// A realtime client event.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(tag = "type")]
+pub enum RealtimeClientEvent {
+	#[serde(rename = "conversation.item.create")]
+	ConversationItemCreate(RealtimeClientEventConversationItemCreate),
+	#[serde(rename = "conversation.item.delete")]
+	ConversationItemDelete(RealtimeClientEventConversationItemDelete),
+	#[serde(rename = "conversation.item.retrieve")]
+	ConversationItemRetrieve(RealtimeClientEventConversationItemRetrieve),
+	#[serde(rename = "conversation.item.truncate")]
+	ConversationItemTruncate(RealtimeClientEventConversationItemTruncate),
+	#[serde(rename = "input_audio_buffer.append")]
+	InputAudioBufferAppend(RealtimeClientEventInputAudioBufferAppend),
+	#[serde(rename = "input_audio_buffer.clear")]
+	InputAudioBufferClear(RealtimeClientEventInputAudioBufferClear),
+	#[serde(rename = "input_audio_buffer.commit")]
+	InputAudioBufferCommit(RealtimeClientEventInputAudioBufferCommit),
+	#[serde(rename = "response.cancel")]
+	ResponseCancel(RealtimeClientEventResponseCancel),
+	#[serde(rename = "response.create")]
+	ResponseCreate(RealtimeClientEventResponseCreate),
+	#[serde(rename = "session.update")]
+	SessionUpdate(RealtimeClientEventSessionUpdate),
+	#[serde(rename = "transcription_session.update")]
+	TranscriptionSessionUpdate(RealtimeClientEventTranscriptionSessionUpdate),
+}
+/// Add a new Item to the Conversation's context, including messages, function
+/// calls, and function call responses. This event can be used both to populate a
+/// "history" of the conversation and to add new items mid-stream, but has the
+/// current limitation that it cannot populate assistant audio messages.
+///
+/// If successful, the server will respond with a `conversation.item.created`
+/// event, otherwise an `error` event will be sent.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeClientEventConversationItemCreate {
+	/// Optional client-generated ID used to identify this event.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub event_id: Option<String>,
+	/// The ID of the preceding item after which the new item will be inserted.
+	/// If not set, the new item will be appended to the end of the conversation.
+	/// If set to `root`, the new item will be added to the beginning of the conversation.
+	/// If set to an existing ID, it allows an item to be inserted mid-conversation. If the
+	/// ID cannot be found, an error will be returned and the item will not be added.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub previous_item_id: Option<String>,
+	pub item: RealtimeConversationItem,
+}
+/// Send this event when you want to remove any item from the conversation
+/// history. The server will respond with a `conversation.item.deleted` event,
+/// unless the item does not exist in the conversation history, in which case the
+/// server will respond with an error.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeClientEventConversationItemDelete {
+	/// Optional client-generated ID used to identify this event.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub event_id: Option<String>,
+	/// The ID of the item to delete.
+	pub item_id: String,
+}
+/// Send this event when you want to retrieve the server's representation of a specific item in the
+/// conversation history. This is useful, for example, to inspect user audio after noise cancellation
+/// and VAD.
+/// The server will respond with a `conversation.item.retrieved` event,
+/// unless the item does not exist in the conversation history, in which case the
+/// server will respond with an error.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeClientEventConversationItemRetrieve {
+	/// Optional client-generated ID used to identify this event.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub event_id: Option<String>,
+	/// The ID of the item to retrieve.
+	pub item_id: String,
+}
+/// Send this event to truncate a previous assistant message’s audio. The server
+/// will produce audio faster than realtime, so this event is useful when the user
+/// interrupts to truncate audio that has already been sent to the client but not
+/// yet played. This will synchronize the server's understanding of the audio with
+/// the client's playback.
+///
+/// Truncating audio will delete the server-side text transcript to ensure there
+/// is not text in the context that hasn't been heard by the user.
+///
+/// If successful, the server will respond with a `conversation.item.truncated`
+/// event.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeClientEventConversationItemTruncate {
+	/// Optional client-generated ID used to identify this event.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub event_id: Option<String>,
+	/// The ID of the assistant message item to truncate. Only assistant message
+	/// items can be truncated.
+	pub item_id: String,
+	/// The index of the content part to truncate. Set this to 0.
+	pub content_index: i64,
+	/// Inclusive duration up to which audio is truncated, in milliseconds. If
+	/// the audio_end_ms is greater than the actual audio duration, the server
+	/// will respond with an error.
+	pub audio_end_ms: i64,
+}
+/// Send this event to append audio bytes to the input audio buffer. The audio
+/// buffer is temporary storage you can write to and later commit. In Server VAD
+/// mode, the audio buffer is used to detect speech and the server will decide
+/// when to commit. When Server VAD is disabled, you must commit the audio buffer
+/// manually.
+///
+/// The client may choose how much audio to place in each event up to a maximum
+/// of 15 MiB, for example streaming smaller chunks from the client may allow the
+/// VAD to be more responsive. Unlike made other client events, the server will
+/// not send a confirmation response to this event.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeClientEventInputAudioBufferAppend {
+	/// Optional client-generated ID used to identify this event.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub event_id: Option<String>,
+	/// Base64-encoded audio bytes. This must be in the format specified by the
+	/// `input_audio_format` field in the session configuration.
+	pub audio: String,
+}
+/// Send this event to clear the audio bytes in the buffer. The server will
+/// respond with an `input_audio_buffer.cleared` event.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeClientEventInputAudioBufferClear {
+	/// Optional client-generated ID used to identify this event.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub event_id: Option<String>,
+}
+/// Send this event to commit the user input audio buffer, which will create a
+/// new user message item in the conversation. This event will produce an error
+/// if the input audio buffer is empty. When in Server VAD mode, the client does
+/// not need to send this event, the server will commit the audio buffer
+/// automatically.
+///
+/// Committing the input audio buffer will trigger input audio transcription
+/// (if enabled in session configuration), but it will not create a response
+/// from the model. The server will respond with an `input_audio_buffer.committed`
+/// event.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeClientEventInputAudioBufferCommit {
+	/// Optional client-generated ID used to identify this event.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub event_id: Option<String>,
+}
+/// Send this event to cancel an in-progress response. The server will respond
+/// with a `response.cancelled` event or an error if there is no response to
+/// cancel.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeClientEventResponseCancel {
+	/// Optional client-generated ID used to identify this event.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub event_id: Option<String>,
+	/// A specific response ID to cancel - if not provided, will cancel an
+	/// in-progress response in the default conversation.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub response_id: Option<String>,
+}
+/// This event instructs the server to create a Response, which means triggering
+/// model inference. When in Server VAD mode, the server will create Responses
+/// automatically.
+///
+/// A Response will include at least one Item, and may have two, in which case
+/// the second will be a function call. These Items will be appended to the
+/// conversation history.
+///
+/// The server will respond with a `response.created` event, events for Items
+/// and content created, and finally a `response.done` event to indicate the
+/// Response is complete.
+///
+/// The `response.create` event includes inference configuration like
+/// `instructions`, and `temperature`. These fields will override the Session's
+/// configuration for this Response only.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeClientEventResponseCreate {
+	/// Optional client-generated ID used to identify this event.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub event_id: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub response: Option<RealtimeResponseCreateParams>,
+}
+/// Send this event to update the session’s default configuration.
+/// The client may send this event at any time to update any field,
+/// except for `voice`. However, note that once a session has been
+/// initialized with a particular `model`, it can’t be changed to
+/// another model using `session.update`.
+///
+/// When the server receives a `session.update`, it will respond
+/// with a `session.updated` event showing the full, effective configuration.
+/// Only the fields that are present are updated. To clear a field like
+/// `instructions`, pass an empty string.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeClientEventSessionUpdate {
+	/// Optional client-generated ID used to identify this event.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub event_id: Option<String>,
+	pub session: RealtimeSessionCreateRequest,
+}
+/// Send this event to update a transcription session.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeClientEventTranscriptionSessionUpdate {
+	/// Optional client-generated ID used to identify this event.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub event_id: Option<String>,
+	pub session: RealtimeTranscriptionSessionCreateRequest,
+}
+/// The item to add to the conversation.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeConversationItem {
+	/// The unique ID of the item, this can be generated by the client to help
+	/// manage server-side context, but is not required because the server will
+	/// generate one if not provided.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	/// The type of the item (`message`, `function_call`, `function_call_output`).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub r#type: Option<RealtimeConversationItemType>,
+	/// Identifier for the API object being returned - always `realtime.item`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub object: Option<RealtimeConversationItemObject>,
+	/// The status of the item (`completed`, `incomplete`). These have no effect
+	/// on the conversation, but are accepted for consistency with the
+	/// `conversation.item.created` event.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub status: Option<RealtimeConversationItemStatus>,
+	/// The role of the message sender (`user`, `assistant`, `system`), only
+	/// applicable for `message` items.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub role: Option<RealtimeConversationItemRole>,
+	/// The content of the message, applicable for `message` items.
+	/// - Message items of role `system` support only `input_text` content
+	/// - Message items of role `user` support `input_text` and `input_audio`
+	///   content
+	/// - Message items of role `assistant` support `text` content.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub content: Option<Vec<RealtimeConversationItemContentItem>>,
+	/// The ID of the function call (for `function_call` and
+	/// `function_call_output` items). If passed on a `function_call_output`
+	/// item, the server will check that a `function_call` item with the same
+	/// ID exists in the conversation history.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub call_id: Option<String>,
+	/// The name of the function being called (for `function_call` items).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+	/// The arguments of the function call (for `function_call` items).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub arguments: Option<String>,
+	/// The output of the function call (for `function_call_output` items).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub output: Option<String>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeConversationItemContentItem {
+	/// The content type (`input_text`, `input_audio`, `item_reference`, `text`).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub r#type: Option<RealtimeConversationItemContentItemType>,
+	/// The text content, used for `input_text` and `text` content types.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub text: Option<String>,
+	/// ID of a previous conversation item to reference (for `item_reference`
+	/// content types in `response.create` events). These can reference both
+	/// client and server created items.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	/// Base64-encoded audio bytes, used for `input_audio` content type.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub audio: Option<String>,
+	/// The transcript of the audio, used for `input_audio` content type.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub transcript: Option<String>,
+}
+/// The content type (`input_text`, `input_audio`, `item_reference`, `text`).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeConversationItemContentItemType {
+	#[serde(rename = "input_audio")]
+	InputAudio,
+	#[serde(rename = "input_text")]
+	InputText,
+	#[serde(rename = "item_reference")]
+	ItemReference,
+	Text,
+}
+/// Identifier for the API object being returned - always `realtime.item`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeConversationItemObject {
+	#[serde(rename = "realtime.item")]
+	RealtimeItem,
+}
+/// The role of the message sender (`user`, `assistant`, `system`), only
+/// applicable for `message` items.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeConversationItemRole {
+	User,
+	Assistant,
+	System,
+}
+/// The status of the item (`completed`, `incomplete`). These have no effect
+/// on the conversation, but are accepted for consistency with the
+/// `conversation.item.created` event.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeConversationItemStatus {
+	Completed,
+	Incomplete,
+}
+/// The type of the item (`message`, `function_call`, `function_call_output`).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeConversationItemType {
+	Message,
+	#[serde(rename = "function_call")]
+	FunctionCall,
+	#[serde(rename = "function_call_output")]
+	FunctionCallOutput,
+}
+/// The item to add to the conversation.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeConversationItemWithReference {
+	/// For an item of type (`message` | `function_call` | `function_call_output`)
+	/// this field allows the client to assign the unique ID of the item. It is
+	/// not required because the server will generate one if not provided.
+	///
+	/// For an item of type `item_reference`, this field is required and is a
+	/// reference to any item that has previously existed in the conversation.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	/// The type of the item (`message`, `function_call`, `function_call_output`, `item_reference`).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub r#type: Option<RealtimeConversationItemWithReferenceType>,
+	/// Identifier for the API object being returned - always `realtime.item`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub object: Option<RealtimeConversationItemWithReferenceObject>,
+	/// The status of the item (`completed`, `incomplete`). These have no effect
+	/// on the conversation, but are accepted for consistency with the
+	/// `conversation.item.created` event.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub status: Option<RealtimeConversationItemWithReferenceStatus>,
+	/// The role of the message sender (`user`, `assistant`, `system`), only
+	/// applicable for `message` items.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub role: Option<RealtimeConversationItemWithReferenceRole>,
+	/// The content of the message, applicable for `message` items.
+	/// - Message items of role `system` support only `input_text` content
+	/// - Message items of role `user` support `input_text` and `input_audio`
+	///   content
+	/// - Message items of role `assistant` support `text` content.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub content: Option<Vec<RealtimeConversationItemWithReferenceContentItem>>,
+	/// The ID of the function call (for `function_call` and
+	/// `function_call_output` items). If passed on a `function_call_output`
+	/// item, the server will check that a `function_call` item with the same
+	/// ID exists in the conversation history.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub call_id: Option<String>,
+	/// The name of the function being called (for `function_call` items).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+	/// The arguments of the function call (for `function_call` items).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub arguments: Option<String>,
+	/// The output of the function call (for `function_call_output` items).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub output: Option<String>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeConversationItemWithReferenceContentItem {
+	/// The content type (`input_text`, `input_audio`, `item_reference`, `text`).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub r#type: Option<RealtimeConversationItemWithReferenceContentItemType>,
+	/// The text content, used for `input_text` and `text` content types.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub text: Option<String>,
+	/// ID of a previous conversation item to reference (for `item_reference`
+	/// content types in `response.create` events). These can reference both
+	/// client and server created items.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	/// Base64-encoded audio bytes, used for `input_audio` content type.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub audio: Option<String>,
+	/// The transcript of the audio, used for `input_audio` content type.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub transcript: Option<String>,
+}
+/// The content type (`input_text`, `input_audio`, `item_reference`, `text`).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeConversationItemWithReferenceContentItemType {
+	#[serde(rename = "input_audio")]
+	InputAudio,
+	#[serde(rename = "input_text")]
+	InputText,
+	#[serde(rename = "item_reference")]
+	ItemReference,
+	Text,
+}
+/// Identifier for the API object being returned - always `realtime.item`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeConversationItemWithReferenceObject {
+	#[serde(rename = "realtime.item")]
+	RealtimeItem,
+}
+/// The role of the message sender (`user`, `assistant`, `system`), only
+/// applicable for `message` items.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeConversationItemWithReferenceRole {
+	User,
+	Assistant,
+	System,
+}
+/// The status of the item (`completed`, `incomplete`). These have no effect
+/// on the conversation, but are accepted for consistency with the
+/// `conversation.item.created` event.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeConversationItemWithReferenceStatus {
+	Completed,
+	Incomplete,
+}
+/// The type of the item (`message`, `function_call`, `function_call_output`, `item_reference`).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeConversationItemWithReferenceType {
+	Message,
+	#[serde(rename = "function_call")]
+	FunctionCall,
+	#[serde(rename = "function_call_output")]
+	FunctionCallOutput,
+}
+/// The response resource.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeResponse {
+	/// The unique ID of the response.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	/// The object type, must be `realtime.response`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub object: Option<RealtimeResponseObject>,
+	/// The final status of the response (`completed`, `cancelled`, `failed`, or
+	/// `incomplete`).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub status: Option<RealtimeResponseStatus>,
+	/// Additional details about the status.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub status_details: Option<RealtimeResponseStatusDetails>,
+	/// The list of output items generated by the response.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub output: Option<Vec<RealtimeConversationItem>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub metadata: Option<Metadata>,
+	/// Usage statistics for the Response, this will correspond to billing. A
+	/// Realtime API session will maintain a conversation context and append new
+	/// Items to the Conversation, thus output from previous turns (text and
+	/// audio tokens) will become the input for later turns.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub usage: Option<RealtimeResponseUsage>,
+	/// Which conversation the response is added to, determined by the `conversation`
+	/// field in the `response.create` event. If `auto`, the response will be added to
+	/// the default conversation and the value of `conversation_id` will be an id like
+	/// `conv_1234`. If `none`, the response will not be added to any conversation and
+	/// the value of `conversation_id` will be `null`. If responses are being triggered
+	/// by server VAD, the response will be added to the default conversation, thus
+	/// the `conversation_id` will be an id like `conv_1234`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub conversation_id: Option<String>,
+	/// The voice the model used to respond.
+	/// Current voice options are `alloy`, `ash`, `ballad`, `coral`, `echo`, `fable`,
+	/// `onyx`, `nova`, `sage`, `shimmer`, and `verse`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub voice: Option<VoiceIdsShared>,
+	/// The set of modalities the model used to respond. If there are multiple modalities,
+	/// the model will pick one, for example if `modalities` is `["text", "audio"]`, the model
+	/// could be responding in either text or audio.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub modalities: Option<Vec<RealtimeResponseItem>>,
+	/// The format of output audio. Options are `pcm16`, `g711_ulaw`, or `g711_alaw`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub output_audio_format: Option<RealtimeResponseOutputAudioFormat>,
+	/// Sampling temperature for the model, limited to \[0.6, 1.2\]. Defaults to 0.8.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub temperature: Option<f64>,
+	/// Maximum number of output tokens for a single assistant response,
+	/// inclusive of tool calls, that was used in this response.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_output_tokens: Option<RealtimeResponseMaxOutputTokens>,
+}
+/// Create a new Realtime response with these parameters
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeResponseCreateParams {
+	/// The set of modalities the model can respond with. To disable audio,
+	/// set this to \["text"\].
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub modalities: Option<Vec<RealtimeResponseCreateParamsItem>>,
+	/// The default system instructions (i.e. system message) prepended to model
+	/// calls. This field allows the client to guide the model on desired
+	/// responses. The model can be instructed on response content and format,
+	/// (e.g. "be extremely succinct", "act friendly", "here are examples of good
+	/// responses") and on audio behavior (e.g. "talk quickly", "inject emotion
+	/// into your voice", "laugh frequently"). The instructions are not guaranteed
+	/// to be followed by the model, but they provide guidance to the model on the
+	/// desired behavior.
+	///
+	/// Note that the server sets default instructions which will be used if this
+	/// field is not set and are visible in the `session.created` event at the
+	/// start of the session.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub instructions: Option<String>,
+	/// The voice the model uses to respond. Voice cannot be changed during the
+	/// session once the model has responded with audio at least once. Current
+	/// voice options are `alloy`, `ash`, `ballad`, `coral`, `echo`, `fable`,
+	/// `onyx`, `nova`, `sage`, `shimmer`, and `verse`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub voice: Option<VoiceIdsShared>,
+	/// The format of output audio. Options are `pcm16`, `g711_ulaw`, or `g711_alaw`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub output_audio_format: Option<RealtimeResponseCreateParamsOutputAudioFormat>,
+	/// Tools (functions) available to the model.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub tools: Option<Vec<RealtimeResponseCreateParamsToolsItem>>,
+	/// How the model chooses tools. Options are `auto`, `none`, `required`, or
+	/// specify a function, like `{"type": "function", "function": {"name": "my_function"}}`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub tool_choice: Option<String>,
+	/// Sampling temperature for the model, limited to \[0.6, 1.2\]. Defaults to 0.8.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub temperature: Option<f64>,
+	/// Maximum number of output tokens for a single assistant response,
+	/// inclusive of tool calls. Provide an integer between 1 and 4096 to
+	/// limit output tokens, or `inf` for the maximum available tokens for a
+	/// given model. Defaults to `inf`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_response_output_tokens: Option<RealtimeResponseCreateParamsMaxResponseOutputTokens>,
+	/// Controls which conversation the response is added to. Currently supports
+	/// `auto` and `none`, with `auto` as the default value. The `auto` value
+	/// means that the contents of the response will be added to the default
+	/// conversation. Set this to `none` to create an out-of-band response which
+	/// will not add items to default conversation.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub conversation: Option<RealtimeResponseCreateParamsConversation>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub metadata: Option<Metadata>,
+	/// Input items to include in the prompt for the model. Using this field
+	/// creates a new context for this Response instead of using the default
+	/// conversation. An empty array `[]` will clear the context for this Response.
+	/// Note that this can include references to items from the default conversation.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub input: Option<Vec<RealtimeConversationItemWithReference>>,
+}
+/// Controls which conversation the response is added to. Currently supports
+/// `auto` and `none`, with `auto` as the default value. The `auto` value
+/// means that the contents of the response will be added to the default
+/// conversation. Set this to `none` to create an out-of-band response which
+/// will not add items to default conversation.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(untagged)]
+pub enum RealtimeResponseCreateParamsConversation {
+	String(String),
+	Auto(String),
+	None(String),
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeResponseCreateParamsItem {
+	Text,
+	Audio,
+}
+/// Maximum number of output tokens for a single assistant response,
+/// inclusive of tool calls. Provide an integer between 1 and 4096 to
+/// limit output tokens, or `inf` for the maximum available tokens for a
+/// given model. Defaults to `inf`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(untagged)]
+pub enum RealtimeResponseCreateParamsMaxResponseOutputTokens {
+	Integer(i64),
+}
+/// The format of output audio. Options are `pcm16`, `g711_ulaw`, or `g711_alaw`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeResponseCreateParamsOutputAudioFormat {
+	Pcm16,
+	#[serde(rename = "g711_ulaw")]
+	G711Ulaw,
+	#[serde(rename = "g711_alaw")]
+	G711Alaw,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeResponseCreateParamsToolsItem {
+	/// The type of the tool, i.e. `function`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub r#type: Option<RealtimeResponseCreateParamsToolsItemType>,
+	/// The name of the function.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+	/// The description of the function, including guidance on when and how
+	/// to call it, and guidance about what to tell the user when calling
+	/// (if anything).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub description: Option<String>,
+	/// Parameters of the function in JSON Schema.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub parameters: Option<serde_json::Value>,
+}
+/// The type of the tool, i.e. `function`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeResponseCreateParamsToolsItemType {
+	Function,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeResponseItem {
+	Text,
+	Audio,
+}
+/// Maximum number of output tokens for a single assistant response,
+/// inclusive of tool calls, that was used in this response.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(untagged)]
+pub enum RealtimeResponseMaxOutputTokens {
+	Integer(i64),
+}
+/// The object type, must be `realtime.response`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeResponseObject {
+	#[serde(rename = "realtime.response")]
+	RealtimeResponse,
+}
+/// The format of output audio. Options are `pcm16`, `g711_ulaw`, or `g711_alaw`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeResponseOutputAudioFormat {
+	Pcm16,
+	#[serde(rename = "g711_ulaw")]
+	G711Ulaw,
+	#[serde(rename = "g711_alaw")]
+	G711Alaw,
+}
+/// The final status of the response (`completed`, `cancelled`, `failed`, or
+/// `incomplete`).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeResponseStatus {
+	Completed,
+	Cancelled,
+	Failed,
+	Incomplete,
+}
+/// Additional details about the status.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeResponseStatusDetails {
+	/// The type of error that caused the response to fail, corresponding
+	/// with the `status` field (`completed`, `cancelled`, `incomplete`,
+	/// `failed`).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub r#type: Option<RealtimeResponseStatusDetailsType>,
+	/// The reason the Response did not complete. For a `cancelled` Response,
+	/// one of `turn_detected` (the server VAD detected a new start of speech)
+	/// or `client_cancelled` (the client sent a cancel event). For an
+	/// `incomplete` Response, one of `max_output_tokens` or `content_filter`
+	/// (the server-side safety filter activated and cut off the response).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub reason: Option<RealtimeResponseStatusDetailsReason>,
+	/// A description of the error that caused the response to fail,
+	/// populated when the `status` is `failed`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub error: Option<RealtimeResponseStatusDetailsError>,
+}
+/// A description of the error that caused the response to fail,
+/// populated when the `status` is `failed`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeResponseStatusDetailsError {
+	/// The type of error.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub r#type: Option<String>,
+	/// Error code, if any.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub code: Option<String>,
+}
+/// The reason the Response did not complete. For a `cancelled` Response,
+/// one of `turn_detected` (the server VAD detected a new start of speech)
+/// or `client_cancelled` (the client sent a cancel event). For an
+/// `incomplete` Response, one of `max_output_tokens` or `content_filter`
+/// (the server-side safety filter activated and cut off the response).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeResponseStatusDetailsReason {
+	#[serde(rename = "turn_detected")]
+	TurnDetected,
+	#[serde(rename = "client_cancelled")]
+	ClientCancelled,
+	#[serde(rename = "max_output_tokens")]
+	MaxOutputTokens,
+	#[serde(rename = "content_filter")]
+	ContentFilter,
+}
+/// The type of error that caused the response to fail, corresponding
+/// with the `status` field (`completed`, `cancelled`, `incomplete`,
+/// `failed`).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeResponseStatusDetailsType {
+	Completed,
+	Cancelled,
+	Failed,
+	Incomplete,
+}
+/// Usage statistics for the Response, this will correspond to billing. A
+/// Realtime API session will maintain a conversation context and append new
+/// Items to the Conversation, thus output from previous turns (text and
+/// audio tokens) will become the input for later turns.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeResponseUsage {
+	/// The total number of tokens in the Response including input and output
+	/// text and audio tokens.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub total_tokens: Option<i64>,
+	/// The number of input tokens used in the Response, including text and
+	/// audio tokens.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub input_tokens: Option<i64>,
+	/// The number of output tokens sent in the Response, including text and
+	/// audio tokens.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub output_tokens: Option<i64>,
+	/// Details about the input tokens used in the Response.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub input_token_details: Option<RealtimeResponseUsageInputTokenDetails>,
+	/// Details about the output tokens used in the Response.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub output_token_details: Option<RealtimeResponseUsageOutputTokenDetails>,
+}
+/// Details about the input tokens used in the Response.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeResponseUsageInputTokenDetails {
+	/// The number of cached tokens used in the Response.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub cached_tokens: Option<i64>,
+	/// The number of text tokens used in the Response.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub text_tokens: Option<i64>,
+	/// The number of audio tokens used in the Response.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub audio_tokens: Option<i64>,
+}
+/// Details about the output tokens used in the Response.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeResponseUsageOutputTokenDetails {
+	/// The number of text tokens used in the Response.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub text_tokens: Option<i64>,
+	/// The number of audio tokens used in the Response.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub audio_tokens: Option<i64>,
+}
+/// A realtime server event.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(tag = "type")]
+pub enum RealtimeServerEvent {
+	#[serde(rename = "conversation.created")]
+	ConversationCreated(RealtimeServerEventConversationCreated),
+	#[serde(rename = "conversation.item.created")]
+	ConversationItemCreated(RealtimeServerEventConversationItemCreated),
+	#[serde(rename = "conversation.item.deleted")]
+	ConversationItemDeleted(RealtimeServerEventConversationItemDeleted),
+	#[serde(rename = "conversation.item.input_audio_transcription.completed")]
+	ConversationItemInputAudioTranscriptionCompleted(RealtimeServerEventConversationItemInputAudioTranscriptionCompleted),
+	#[serde(rename = "conversation.item.input_audio_transcription.delta")]
+	ConversationItemInputAudioTranscriptionDelta(RealtimeServerEventConversationItemInputAudioTranscriptionDelta),
+	#[serde(rename = "conversation.item.input_audio_transcription.failed")]
+	ConversationItemInputAudioTranscriptionFailed(RealtimeServerEventConversationItemInputAudioTranscriptionFailed),
+	#[serde(rename = "conversation.item.retrieved")]
+	ConversationItemRetrieved(RealtimeServerEventConversationItemRetrieved),
+	#[serde(rename = "conversation.item.truncated")]
+	ConversationItemTruncated(RealtimeServerEventConversationItemTruncated),
+	#[serde(rename = "error")]
+	Error(RealtimeServerEventError),
+	#[serde(rename = "input_audio_buffer.cleared")]
+	InputAudioBufferCleared(RealtimeServerEventInputAudioBufferCleared),
+	#[serde(rename = "input_audio_buffer.committed")]
+	InputAudioBufferCommitted(RealtimeServerEventInputAudioBufferCommitted),
+	#[serde(rename = "input_audio_buffer.speech_started")]
+	InputAudioBufferSpeechStarted(RealtimeServerEventInputAudioBufferSpeechStarted),
+	#[serde(rename = "input_audio_buffer.speech_stopped")]
+	InputAudioBufferSpeechStopped(RealtimeServerEventInputAudioBufferSpeechStopped),
+	#[serde(rename = "rate_limits.updated")]
+	RateLimitsUpdated(RealtimeServerEventRateLimitsUpdated),
+	#[serde(rename = "response.audio.delta")]
+	ResponseAudioDelta(RealtimeServerEventResponseAudioDelta),
+	#[serde(rename = "response.audio.done")]
+	ResponseAudioDone(RealtimeServerEventResponseAudioDone),
+	#[serde(rename = "response.audio_transcript.delta")]
+	ResponseAudioTranscriptDelta(RealtimeServerEventResponseAudioTranscriptDelta),
+	#[serde(rename = "response.audio_transcript.done")]
+	ResponseAudioTranscriptDone(RealtimeServerEventResponseAudioTranscriptDone),
+	#[serde(rename = "response.content_part.added")]
+	ResponseContentPartAdded(RealtimeServerEventResponseContentPartAdded),
+	#[serde(rename = "response.content_part.done")]
+	ResponseContentPartDone(RealtimeServerEventResponseContentPartDone),
+	#[serde(rename = "response.created")]
+	ResponseCreated(RealtimeServerEventResponseCreated),
+	#[serde(rename = "response.done")]
+	ResponseDone(RealtimeServerEventResponseDone),
+	#[serde(rename = "response.function_call_arguments.delta")]
+	ResponseFunctionCallArgumentsDelta(RealtimeServerEventResponseFunctionCallArgumentsDelta),
+	#[serde(rename = "response.function_call_arguments.done")]
+	ResponseFunctionCallArgumentsDone(RealtimeServerEventResponseFunctionCallArgumentsDone),
+	#[serde(rename = "response.output_item.added")]
+	ResponseOutputItemAdded(RealtimeServerEventResponseOutputItemAdded),
+	#[serde(rename = "response.output_item.done")]
+	ResponseOutputItemDone(RealtimeServerEventResponseOutputItemDone),
+	#[serde(rename = "response.text.delta")]
+	ResponseTextDelta(RealtimeServerEventResponseTextDelta),
+	#[serde(rename = "response.text.done")]
+	ResponseTextDone(RealtimeServerEventResponseTextDone),
+	#[serde(rename = "session.created")]
+	SessionCreated(RealtimeServerEventSessionCreated),
+	#[serde(rename = "session.updated")]
+	SessionUpdated(RealtimeServerEventSessionUpdated),
+	#[serde(rename = "transcription_session.updated")]
+	TranscriptionSessionUpdated(RealtimeServerEventTranscriptionSessionUpdated),
+}
+/// Returned when a conversation is created. Emitted right after session creation.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventConversationCreated {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	/// The conversation resource.
+	pub conversation: RealtimeServerEventConversationCreatedConversation,
+}
+/// The conversation resource.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeServerEventConversationCreatedConversation {
+	/// The unique ID of the conversation.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	/// The object type, must be `realtime.conversation`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub object: Option<String>,
+}
+/// Returned when a conversation item is created. There are several scenarios that produce this event:
+///   - The server is generating a Response, which if successful will produce
+///     either one or two Items, which will be of type `message`
+///     (role `assistant`) or type `function_call`.
+///   - The input audio buffer has been committed, either by the client or the
+///     server (in `server_vad` mode). The server will take the content of the
+///     input audio buffer and add it to a new user message Item.
+///   - The client has sent a `conversation.item.create` event to add a new Item
+///     to the Conversation.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventConversationItemCreated {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	/// The ID of the preceding item in the Conversation context, allows the
+	/// client to understand the order of the conversation.
+	pub previous_item_id: String,
+	pub item: RealtimeConversationItem,
+}
+/// Returned when an item in the conversation is deleted by the client with a
+/// `conversation.item.delete` event. This event is used to synchronize the
+/// server's understanding of the conversation history with the client's view.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventConversationItemDeleted {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	/// The ID of the item that was deleted.
+	pub item_id: String,
+}
+/// This event is the output of audio transcription for user audio written to the
+/// user audio buffer. Transcription begins when the input audio buffer is
+/// committed by the client or server (in `server_vad` mode). Transcription runs
+/// asynchronously with Response creation, so this event may come before or after
+/// the Response events.
+///
+/// Realtime API models accept audio natively, and thus input transcription is a
+/// separate process run on a separate ASR (Automatic Speech Recognition) model,
+/// currently always `whisper-1`. Thus the transcript may diverge somewhat from
+/// the model's interpretation, and should be treated as a rough guide.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventConversationItemInputAudioTranscriptionCompleted {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	/// The ID of the user message item containing the audio.
+	pub item_id: String,
+	/// The index of the content part containing the audio.
+	pub content_index: i64,
+	/// The transcribed text.
+	pub transcript: String,
+	/// The log probabilities of the transcription.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub logprobs: Option<Vec<LogProbProperties>>,
+}
+/// Returned when the text value of an input audio transcription content part is updated.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventConversationItemInputAudioTranscriptionDelta {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	/// The ID of the item.
+	pub item_id: String,
+	/// The index of the content part in the item's content array.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub content_index: Option<i64>,
+	/// The text delta.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub delta: Option<String>,
+	/// The log probabilities of the transcription.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub logprobs: Option<Vec<LogProbProperties>>,
+}
+/// Returned when input audio transcription is configured, and a transcription
+/// request for a user message failed. These events are separate from other
+/// `error` events so that the client can identify the related Item.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventConversationItemInputAudioTranscriptionFailed {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	/// The ID of the user message item.
+	pub item_id: String,
+	/// The index of the content part containing the audio.
+	pub content_index: i64,
+	/// Details of the transcription error.
+	pub error: RealtimeServerEventConversationItemInputAudioTranscriptionFailedError,
+}
+/// Details of the transcription error.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeServerEventConversationItemInputAudioTranscriptionFailedError {
+	/// The type of error.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub r#type: Option<String>,
+	/// Error code, if any.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub code: Option<String>,
+	/// A human-readable error message.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub message: Option<String>,
+	/// Parameter related to the error, if any.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub param: Option<String>,
+}
+/// Returned when a conversation item is retrieved with `conversation.item.retrieve`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventConversationItemRetrieved {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	pub item: RealtimeConversationItem,
+}
+/// Returned when an earlier assistant audio message item is truncated by the
+/// client with a `conversation.item.truncate` event. This event is used to
+/// synchronize the server's understanding of the audio with the client's playback.
+///
+/// This action will truncate the audio and remove the server-side text transcript
+/// to ensure there is no text in the context that hasn't been heard by the user.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventConversationItemTruncated {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	/// The ID of the assistant message item that was truncated.
+	pub item_id: String,
+	/// The index of the content part that was truncated.
+	pub content_index: i64,
+	/// The duration up to which the audio was truncated, in milliseconds.
+	pub audio_end_ms: i64,
+}
+/// Returned when an error occurs, which could be a client problem or a server
+/// problem. Most errors are recoverable and the session will stay open, we
+/// recommend to implementors to monitor and log error messages by default.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventError {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	/// Details of the error.
+	pub error: RealtimeServerEventErrorError,
+}
+/// Details of the error.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventErrorError {
+	/// The type of error (e.g., "invalid_request_error", "server_error").
+	pub r#type: String,
+	/// Error code, if any.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub code: Option<String>,
+	/// A human-readable error message.
+	pub message: String,
+	/// Parameter related to the error, if any.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub param: Option<String>,
+	/// The event_id of the client event that caused the error, if applicable.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub event_id: Option<String>,
+}
+/// Returned when the input audio buffer is cleared by the client with a
+/// `input_audio_buffer.clear` event.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventInputAudioBufferCleared {
+	/// The unique ID of the server event.
+	pub event_id: String,
+}
+/// Returned when an input audio buffer is committed, either by the client or
+/// automatically in server VAD mode. The `item_id` property is the ID of the user
+/// message item that will be created, thus a `conversation.item.created` event
+/// will also be sent to the client.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventInputAudioBufferCommitted {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	/// The ID of the preceding item after which the new item will be inserted.
+	pub previous_item_id: String,
+	/// The ID of the user message item that will be created.
+	pub item_id: String,
+}
+/// Sent by the server when in `server_vad` mode to indicate that speech has been
+/// detected in the audio buffer. This can happen any time audio is added to the
+/// buffer (unless speech is already detected). The client may want to use this
+/// event to interrupt audio playback or provide visual feedback to the user.
+///
+/// The client should expect to receive a `input_audio_buffer.speech_stopped` event
+/// when speech stops. The `item_id` property is the ID of the user message item
+/// that will be created when speech stops and will also be included in the
+/// `input_audio_buffer.speech_stopped` event (unless the client manually commits
+/// the audio buffer during VAD activation).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventInputAudioBufferSpeechStarted {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	/// Milliseconds from the start of all audio written to the buffer during the
+	/// session when speech was first detected. This will correspond to the
+	/// beginning of audio sent to the model, and thus includes the
+	/// `prefix_padding_ms` configured in the Session.
+	pub audio_start_ms: i64,
+	/// The ID of the user message item that will be created when speech stops.
+	pub item_id: String,
+}
+/// Returned in `server_vad` mode when the server detects the end of speech in
+/// the audio buffer. The server will also send an `conversation.item.created`
+/// event with the user message item that is created from the audio buffer.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventInputAudioBufferSpeechStopped {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	/// Milliseconds since the session started when speech stopped. This will
+	/// correspond to the end of audio sent to the model, and thus includes the
+	/// `min_silence_duration_ms` configured in the Session.
+	pub audio_end_ms: i64,
+	/// The ID of the user message item that will be created.
+	pub item_id: String,
+}
+/// Emitted at the beginning of a Response to indicate the updated rate limits.
+/// When a Response is created some tokens will be "reserved" for the output
+/// tokens, the rate limits shown here reflect that reservation, which is then
+/// adjusted accordingly once the Response is completed.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventRateLimitsUpdated {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	/// List of rate limit information.
+	pub rate_limits: Vec<RealtimeServerEventRateLimitsUpdatedRateLimitsItem>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeServerEventRateLimitsUpdatedRateLimitsItem {
+	/// The name of the rate limit (`requests`, `tokens`).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name: Option<RealtimeServerEventRateLimitsUpdatedRateLimitsItemName>,
+	/// The maximum allowed value for the rate limit.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub limit: Option<i64>,
+	/// The remaining value before the limit is reached.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub remaining: Option<i64>,
+	/// Seconds until the rate limit resets.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub reset_seconds: Option<f64>,
+}
+/// The name of the rate limit (`requests`, `tokens`).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeServerEventRateLimitsUpdatedRateLimitsItemName {
+	Requests,
+	Tokens,
+}
+/// Returned when the model-generated audio is updated.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventResponseAudioDelta {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	/// The ID of the response.
+	pub response_id: String,
+	/// The ID of the item.
+	pub item_id: String,
+	/// The index of the output item in the response.
+	pub output_index: i64,
+	/// The index of the content part in the item's content array.
+	pub content_index: i64,
+	/// Base64-encoded audio data delta.
+	pub delta: String,
+}
+/// Returned when the model-generated audio is done. Also emitted when a Response
+/// is interrupted, incomplete, or cancelled.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventResponseAudioDone {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	/// The ID of the response.
+	pub response_id: String,
+	/// The ID of the item.
+	pub item_id: String,
+	/// The index of the output item in the response.
+	pub output_index: i64,
+	/// The index of the content part in the item's content array.
+	pub content_index: i64,
+}
+/// Returned when the model-generated transcription of audio output is updated.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventResponseAudioTranscriptDelta {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	/// The ID of the response.
+	pub response_id: String,
+	/// The ID of the item.
+	pub item_id: String,
+	/// The index of the output item in the response.
+	pub output_index: i64,
+	/// The index of the content part in the item's content array.
+	pub content_index: i64,
+	/// The transcript delta.
+	pub delta: String,
+}
+/// Returned when the model-generated transcription of audio output is done
+/// streaming. Also emitted when a Response is interrupted, incomplete, or
+/// cancelled.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventResponseAudioTranscriptDone {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	/// The ID of the response.
+	pub response_id: String,
+	/// The ID of the item.
+	pub item_id: String,
+	/// The index of the output item in the response.
+	pub output_index: i64,
+	/// The index of the content part in the item's content array.
+	pub content_index: i64,
+	/// The final transcript of the audio.
+	pub transcript: String,
+}
+/// Returned when a new content part is added to an assistant message item during
+/// response generation.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventResponseContentPartAdded {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	/// The ID of the response.
+	pub response_id: String,
+	/// The ID of the item to which the content part was added.
+	pub item_id: String,
+	/// The index of the output item in the response.
+	pub output_index: i64,
+	/// The index of the content part in the item's content array.
+	pub content_index: i64,
+	/// The content part that was added.
+	pub part: RealtimeServerEventResponseContentPartAddedPart,
+}
+/// The content part that was added.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeServerEventResponseContentPartAddedPart {
+	/// The content type ("text", "audio").
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub r#type: Option<RealtimeServerEventResponseContentPartAddedPartType>,
+	/// The text content (if type is "text").
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub text: Option<String>,
+	/// Base64-encoded audio data (if type is "audio").
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub audio: Option<String>,
+	/// The transcript of the audio (if type is "audio").
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub transcript: Option<String>,
+}
+/// The content type ("text", "audio").
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeServerEventResponseContentPartAddedPartType {
+	Audio,
+	Text,
+}
+/// Returned when a content part is done streaming in an assistant message item.
+/// Also emitted when a Response is interrupted, incomplete, or cancelled.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventResponseContentPartDone {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	/// The ID of the response.
+	pub response_id: String,
+	/// The ID of the item.
+	pub item_id: String,
+	/// The index of the output item in the response.
+	pub output_index: i64,
+	/// The index of the content part in the item's content array.
+	pub content_index: i64,
+	/// The content part that is done.
+	pub part: RealtimeServerEventResponseContentPartDonePart,
+}
+/// The content part that is done.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeServerEventResponseContentPartDonePart {
+	/// The content type ("text", "audio").
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub r#type: Option<RealtimeServerEventResponseContentPartDonePartType>,
+	/// The text content (if type is "text").
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub text: Option<String>,
+	/// Base64-encoded audio data (if type is "audio").
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub audio: Option<String>,
+	/// The transcript of the audio (if type is "audio").
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub transcript: Option<String>,
+}
+/// The content type ("text", "audio").
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeServerEventResponseContentPartDonePartType {
+	Audio,
+	Text,
+}
+/// Returned when a new Response is created. The first event of response creation,
+/// where the response is in an initial state of `in_progress`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventResponseCreated {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	pub response: RealtimeResponse,
+}
+/// Returned when a Response is done streaming. Always emitted, no matter the
+/// final state. The Response object included in the `response.done` event will
+/// include all output Items in the Response but will omit the raw audio data.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventResponseDone {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	pub response: RealtimeResponse,
+}
+/// Returned when the model-generated function call arguments are updated.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventResponseFunctionCallArgumentsDelta {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	/// The ID of the response.
+	pub response_id: String,
+	/// The ID of the function call item.
+	pub item_id: String,
+	/// The index of the output item in the response.
+	pub output_index: i64,
+	/// The ID of the function call.
+	pub call_id: String,
+	/// The arguments delta as a JSON string.
+	pub delta: String,
+}
+/// Returned when the model-generated function call arguments are done streaming.
+/// Also emitted when a Response is interrupted, incomplete, or cancelled.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventResponseFunctionCallArgumentsDone {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	/// The ID of the response.
+	pub response_id: String,
+	/// The ID of the function call item.
+	pub item_id: String,
+	/// The index of the output item in the response.
+	pub output_index: i64,
+	/// The ID of the function call.
+	pub call_id: String,
+	/// The final arguments as a JSON string.
+	pub arguments: String,
+}
+/// Returned when a new Item is created during Response generation.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventResponseOutputItemAdded {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	/// The ID of the Response to which the item belongs.
+	pub response_id: String,
+	/// The index of the output item in the Response.
+	pub output_index: i64,
+	pub item: RealtimeConversationItem,
+}
+/// Returned when an Item is done streaming. Also emitted when a Response is
+/// interrupted, incomplete, or cancelled.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventResponseOutputItemDone {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	/// The ID of the Response to which the item belongs.
+	pub response_id: String,
+	/// The index of the output item in the Response.
+	pub output_index: i64,
+	pub item: RealtimeConversationItem,
+}
+/// Returned when the text value of a "text" content part is updated.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventResponseTextDelta {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	/// The ID of the response.
+	pub response_id: String,
+	/// The ID of the item.
+	pub item_id: String,
+	/// The index of the output item in the response.
+	pub output_index: i64,
+	/// The index of the content part in the item's content array.
+	pub content_index: i64,
+	/// The text delta.
+	pub delta: String,
+}
+/// Returned when the text value of a "text" content part is done streaming. Also
+/// emitted when a Response is interrupted, incomplete, or cancelled.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventResponseTextDone {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	/// The ID of the response.
+	pub response_id: String,
+	/// The ID of the item.
+	pub item_id: String,
+	/// The index of the output item in the response.
+	pub output_index: i64,
+	/// The index of the content part in the item's content array.
+	pub content_index: i64,
+	/// The final text content.
+	pub text: String,
+}
+/// Returned when a Session is created. Emitted automatically when a new
+/// connection is established as the first server event. This event will contain
+/// the default Session configuration.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventSessionCreated {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	pub session: RealtimeSession,
+}
+/// Returned when a session is updated with a `session.update` event, unless
+/// there is an error.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventSessionUpdated {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	pub session: RealtimeSession,
+}
+/// Returned when a transcription session is updated with a `transcription_session.update` event, unless
+/// there is an error.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeServerEventTranscriptionSessionUpdated {
+	/// The unique ID of the server event.
+	pub event_id: String,
+	pub session: RealtimeTranscriptionSessionCreateResponse,
+}
+/// Realtime session object configuration.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeSession {
+	/// Unique identifier for the session that looks like `sess_1234567890abcdef`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	/// The set of modalities the model can respond with. To disable audio,
+	/// set this to \["text"\].
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub modalities: Option<Vec<RealtimeSessionItem>>,
+	/// The Realtime model used for this session.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub model: Option<RealtimeSessionModel>,
+	/// The default system instructions (i.e. system message) prepended to model  calls. This field allows
+	/// the client to guide the model on desired  responses. The model can be instructed on response content
+	/// and format,  (e.g. "be extremely succinct", "act friendly", "here are examples of good  responses")
+	/// and on audio behavior (e.g. "talk quickly", "inject emotion  into your voice", "laugh frequently").
+	/// The instructions are not guaranteed  to be followed by the model, but they provide guidance to the
+	/// model on the desired behavior.
+	///
+	/// Note that the server sets default instructions which will be used if this  field is not set and are
+	/// visible in the `session.created` event at the  start of the session.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub instructions: Option<String>,
+	/// The voice the model uses to respond. Voice cannot be changed during the
+	/// session once the model has responded with audio at least once. Current
+	/// voice options are `alloy`, `ash`, `ballad`, `coral`, `echo` `sage`,
+	/// `shimmer` and `verse`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub voice: Option<VoiceIdsShared>,
+	/// The format of input audio. Options are `pcm16`, `g711_ulaw`, or `g711_alaw`.
+	/// For `pcm16`, input audio must be 16-bit PCM at a 24kHz sample rate,
+	/// single channel (mono), and little-endian byte order.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub input_audio_format: Option<RealtimeSessionInputAudioFormat>,
+	/// The format of output audio. Options are `pcm16`, `g711_ulaw`, or `g711_alaw`.
+	/// For `pcm16`, output audio is sampled at a rate of 24kHz.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub output_audio_format: Option<RealtimeSessionOutputAudioFormat>,
+	/// Configuration for input audio transcription, defaults to off and can be  set to `null` to turn off
+	/// once on. Input audio transcription is not native to the model, since the model consumes audio
+	/// directly. Transcription runs  asynchronously through [the /audio/transcriptions
+	/// endpoint](https://platform.openai.com/docs/api-reference/audio/createTranscription) and should be
+	/// treated as guidance of input audio content rather than precisely what the model heard. The client
+	/// can optionally set the language and prompt for transcription, these offer additional guidance to the
+	/// transcription service.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub input_audio_transcription: Option<RealtimeSessionInputAudioTranscription>,
+	/// Configuration for turn detection, ether Server VAD or Semantic VAD. This can be set to `null` to
+	/// turn off, in which case the client must manually trigger model response.
+	/// Server VAD means that the model will detect the start and end of speech based on audio volume and
+	/// respond at the end of user speech.
+	/// Semantic VAD is more advanced and uses a turn detection model (in conjuction with VAD) to
+	/// semantically estimate whether the user has finished speaking, then dynamically sets a timeout based
+	/// on this probability. For example, if user audio trails off with "uhhm", the model will score a low
+	/// probability of turn end and wait longer for the user to continue speaking. This can be useful for
+	/// more natural conversations, but may have a higher latency.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub turn_detection: Option<RealtimeSessionTurnDetection>,
+	/// Configuration for input audio noise reduction. This can be set to `null` to turn off.
+	/// Noise reduction filters audio added to the input audio buffer before it is sent to VAD and the
+	/// model.
+	/// Filtering the audio can improve VAD and turn detection accuracy (reducing false positives) and model
+	/// performance by improving perception of the input audio.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub input_audio_noise_reduction: Option<RealtimeSessionInputAudioNoiseReduction>,
+	/// Tools (functions) available to the model.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub tools: Option<Vec<RealtimeSessionToolsItem>>,
+	/// How the model chooses tools. Options are `auto`, `none`, `required`, or
+	/// specify a function.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub tool_choice: Option<String>,
+	/// Sampling temperature for the model, limited to \[0.6, 1.2\]. For audio models a temperature of 0.8
+	/// is highly recommended for best performance.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub temperature: Option<f64>,
+	/// Maximum number of output tokens for a single assistant response,
+	/// inclusive of tool calls. Provide an integer between 1 and 4096 to
+	/// limit output tokens, or `inf` for the maximum available tokens for a
+	/// given model. Defaults to `inf`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_response_output_tokens: Option<RealtimeSessionMaxResponseOutputTokens>,
+}
 /// Realtime session object configuration.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = RealtimeSessionCreateRequest {
+///     modalities: Some(vec![
+///         RealtimeSessionCreateRequestItem::Audio,
+///         RealtimeSessionCreateRequestItem::Text,
+///     ]),
+///     model: Some(RealtimeSessionCreateRequestModel::Gpt4ORealtimePreview),
+///     instructions: Some("You are a friendly assistant.".to_string()),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeSessionCreateRequest {
+	/// The set of modalities the model can respond with. To disable audio,
+	/// set this to \["text"\].
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub modalities: Option<Vec<RealtimeSessionCreateRequestItem>>,
+	/// The Realtime model used for this session.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub model: Option<RealtimeSessionCreateRequestModel>,
+	/// The default system instructions (i.e. system message) prepended to model  calls. This field allows
+	/// the client to guide the model on desired  responses. The model can be instructed on response content
+	/// and format,  (e.g. "be extremely succinct", "act friendly", "here are examples of good  responses")
+	/// and on audio behavior (e.g. "talk quickly", "inject emotion  into your voice", "laugh frequently").
+	/// The instructions are not guaranteed  to be followed by the model, but they provide guidance to the
+	/// model on the desired behavior.
+	///
+	/// Note that the server sets default instructions which will be used if this  field is not set and are
+	/// visible in the `session.created` event at the  start of the session.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub instructions: Option<String>,
+	/// The voice the model uses to respond. Voice cannot be changed during the
+	/// session once the model has responded with audio at least once. Current
+	/// voice options are `alloy`, `ash`, `ballad`, `coral`, `echo`, `fable`,
+	/// `onyx`, `nova`, `sage`, `shimmer`, and `verse`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub voice: Option<VoiceIdsShared>,
+	/// The format of input audio. Options are `pcm16`, `g711_ulaw`, or `g711_alaw`.
+	/// For `pcm16`, input audio must be 16-bit PCM at a 24kHz sample rate,
+	/// single channel (mono), and little-endian byte order.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub input_audio_format: Option<RealtimeSessionCreateRequestInputAudioFormat>,
+	/// The format of output audio. Options are `pcm16`, `g711_ulaw`, or `g711_alaw`.
+	/// For `pcm16`, output audio is sampled at a rate of 24kHz.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub output_audio_format: Option<RealtimeSessionCreateRequestOutputAudioFormat>,
+	/// Configuration for input audio transcription, defaults to off and can be  set to `null` to turn off
+	/// once on. Input audio transcription is not native to the model, since the model consumes audio
+	/// directly. Transcription runs  asynchronously through [the /audio/transcriptions
+	/// endpoint](https://platform.openai.com/docs/api-reference/audio/createTranscription) and should be
+	/// treated as guidance of input audio content rather than precisely what the model heard. The client
+	/// can optionally set the language and prompt for transcription, these offer additional guidance to the
+	/// transcription service.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub input_audio_transcription: Option<RealtimeSessionCreateRequestInputAudioTranscription>,
+	/// Configuration for turn detection, ether Server VAD or Semantic VAD. This can be set to `null` to
+	/// turn off, in which case the client must manually trigger model response.
+	/// Server VAD means that the model will detect the start and end of speech based on audio volume and
+	/// respond at the end of user speech.
+	/// Semantic VAD is more advanced and uses a turn detection model (in conjuction with VAD) to
+	/// semantically estimate whether the user has finished speaking, then dynamically sets a timeout based
+	/// on this probability. For example, if user audio trails off with "uhhm", the model will score a low
+	/// probability of turn end and wait longer for the user to continue speaking. This can be useful for
+	/// more natural conversations, but may have a higher latency.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub turn_detection: Option<RealtimeSessionCreateRequestTurnDetection>,
+	/// Configuration for input audio noise reduction. This can be set to `null` to turn off.
+	/// Noise reduction filters audio added to the input audio buffer before it is sent to VAD and the
+	/// model.
+	/// Filtering the audio can improve VAD and turn detection accuracy (reducing false positives) and model
+	/// performance by improving perception of the input audio.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub input_audio_noise_reduction: Option<RealtimeSessionCreateRequestInputAudioNoiseReduction>,
+	/// Tools (functions) available to the model.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub tools: Option<Vec<RealtimeSessionCreateRequestToolsItem>>,
+	/// How the model chooses tools. Options are `auto`, `none`, `required`, or
+	/// specify a function.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub tool_choice: Option<String>,
+	/// Sampling temperature for the model, limited to \[0.6, 1.2\]. For audio models a temperature of 0.8
+	/// is highly recommended for best performance.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub temperature: Option<f64>,
+	/// Maximum number of output tokens for a single assistant response,
+	/// inclusive of tool calls. Provide an integer between 1 and 4096 to
+	/// limit output tokens, or `inf` for the maximum available tokens for a
+	/// given model. Defaults to `inf`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_response_output_tokens: Option<RealtimeSessionCreateRequestMaxResponseOutputTokens>,
+}
+/// The format of input audio. Options are `pcm16`, `g711_ulaw`, or `g711_alaw`.
+/// For `pcm16`, input audio must be 16-bit PCM at a 24kHz sample rate,
+/// single channel (mono), and little-endian byte order.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeSessionCreateRequestInputAudioFormat {
+	Pcm16,
+	#[serde(rename = "g711_ulaw")]
+	G711Ulaw,
+	#[serde(rename = "g711_alaw")]
+	G711Alaw,
+}
+/// Configuration for input audio noise reduction. This can be set to `null` to turn off.
+/// Noise reduction filters audio added to the input audio buffer before it is sent to VAD and the
+/// model.
+/// Filtering the audio can improve VAD and turn detection accuracy (reducing false positives) and model
+/// performance by improving perception of the input audio.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeSessionCreateRequestInputAudioNoiseReduction {
+	/// Type of noise reduction. `near_field` is for close-talking microphones such as headphones,
+	/// `far_field` is for far-field microphones such as laptop or conference room microphones.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub r#type: Option<RealtimeSessionCreateRequestInputAudioNoiseReductionType>,
+}
+/// Type of noise reduction. `near_field` is for close-talking microphones such as headphones,
+/// `far_field` is for far-field microphones such as laptop or conference room microphones.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeSessionCreateRequestInputAudioNoiseReductionType {
+	#[serde(rename = "near_field")]
+	NearField,
+	#[serde(rename = "far_field")]
+	FarField,
+}
+/// Configuration for input audio transcription, defaults to off and can be  set to `null` to turn off
+/// once on. Input audio transcription is not native to the model, since the model consumes audio
+/// directly. Transcription runs  asynchronously through [the /audio/transcriptions
+/// endpoint](https://platform.openai.com/docs/api-reference/audio/createTranscription) and should be
+/// treated as guidance of input audio content rather than precisely what the model heard. The client
+/// can optionally set the language and prompt for transcription, these offer additional guidance to the
+/// transcription service.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeSessionCreateRequestInputAudioTranscription {
+	/// The model to use for transcription, current options are `gpt-4o-transcribe`,
+	/// `gpt-4o-mini-transcribe`, and `whisper-1`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub model: Option<String>,
+	/// The language of the input audio. Supplying the input language in
+	/// [ISO-639-1](https://en.wikipedia.org/wiki/List_of_ISO_639-1_codes) (e.g. `en`) format
+	/// will improve accuracy and latency.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub language: Option<String>,
+	/// An optional text to guide the model's style or continue a previous audio
+	/// segment.
+	/// For `whisper-1`, the [prompt is a list of
+	/// keywords](https://platform.openai.com/docs/guides/speech-to-text#prompting).
+	/// For `gpt-4o-transcribe` models, the prompt is a free text string, for example "expect words related
+	/// to technology".
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub prompt: Option<String>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeSessionCreateRequestItem {
+	Text,
+	Audio,
+}
+/// Maximum number of output tokens for a single assistant response,
+/// inclusive of tool calls. Provide an integer between 1 and 4096 to
+/// limit output tokens, or `inf` for the maximum available tokens for a
+/// given model. Defaults to `inf`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(untagged)]
+pub enum RealtimeSessionCreateRequestMaxResponseOutputTokens {
+	Integer(i64),
+}
+/// The Realtime model used for this session.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeSessionCreateRequestModel {
+	#[serde(rename = "gpt-4o-realtime-preview")]
+	Gpt4ORealtimePreview,
+	#[serde(rename = "gpt-4o-realtime-preview-2024-10-01")]
+	Gpt4ORealtimePreview20241001,
+	#[serde(rename = "gpt-4o-realtime-preview-2024-12-17")]
+	Gpt4ORealtimePreview20241217,
+	#[serde(rename = "gpt-4o-mini-realtime-preview")]
+	Gpt4OMiniRealtimePreview,
+	#[serde(rename = "gpt-4o-mini-realtime-preview-2024-12-17")]
+	Gpt4OMiniRealtimePreview20241217,
+}
+/// The format of output audio. Options are `pcm16`, `g711_ulaw`, or `g711_alaw`.
+/// For `pcm16`, output audio is sampled at a rate of 24kHz.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeSessionCreateRequestOutputAudioFormat {
+	Pcm16,
+	#[serde(rename = "g711_ulaw")]
+	G711Ulaw,
+	#[serde(rename = "g711_alaw")]
+	G711Alaw,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeSessionCreateRequestToolsItem {
+	/// The type of the tool, i.e. `function`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub r#type: Option<RealtimeSessionCreateRequestToolsItemType>,
+	/// The name of the function.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+	/// The description of the function, including guidance on when and how
+	/// to call it, and guidance about what to tell the user when calling
+	/// (if anything).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub description: Option<String>,
+	/// Parameters of the function in JSON Schema.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub parameters: Option<serde_json::Value>,
+}
+/// The type of the tool, i.e. `function`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeSessionCreateRequestToolsItemType {
+	Function,
+}
+/// Configuration for turn detection, ether Server VAD or Semantic VAD. This can be set to `null` to
+/// turn off, in which case the client must manually trigger model response.
+/// Server VAD means that the model will detect the start and end of speech based on audio volume and
+/// respond at the end of user speech.
+/// Semantic VAD is more advanced and uses a turn detection model (in conjuction with VAD) to
+/// semantically estimate whether the user has finished speaking, then dynamically sets a timeout based
+/// on this probability. For example, if user audio trails off with "uhhm", the model will score a low
+/// probability of turn end and wait longer for the user to continue speaking. This can be useful for
+/// more natural conversations, but may have a higher latency.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-pub struct RealtimeSessionCreateRequest {
-	/// The format of input audio. Options are `pcm16`, `g711_ulaw`, or `g711_alaw`.
-	/// For `pcm16`, input audio must be 16-bit PCM at a 24kHz sample rate, 
-	/// single channel (mono), and little-endian byte order.
+pub struct RealtimeSessionCreateRequestTurnDetection {
+	/// Type of turn detection.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub input_audio_format: Option<RealtimeSessionCreateRequestInputAudioFormat>,
-	/// Configuration for input audio noise reduction. This can be set to `null` to turn off.
-	/// Noise reduction filters audio added to the input audio buffer before it is sent to VAD and the model.
-	/// Filtering the audio can improve VAD and turn detection accuracy (reducing false positives) and model performance by improving perception of the input audio.
+	pub r#type: Option<RealtimeSessionCreateRequestTurnDetectionType>,
+	/// Used only for `semantic_vad` mode. The eagerness of the model to respond. `low` will wait longer for
+	/// the user to continue speaking, `high` will respond more quickly. `auto` is the default and is
+	/// equivalent to `medium`.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub input_audio_noise_reduction: Option<RealtimeSessionCreateRequestInputAudioNoiseReduction>,
-	/// Configuration for input audio transcription, defaults to off and can be  set to `null` to turn off once on. Input audio transcription is not native to the model, since the model consumes audio directly. Transcription runs  asynchronously through [the /audio/transcriptions endpoint](https://platform.openai.com/docs/api-reference/audio/createTranscription) and should be treated as guidance of input audio content rather than precisely what the model heard. The client can optionally set the language and prompt for transcription, these offer additional guidance to the transcription service.
+	pub eagerness: Option<RealtimeSessionCreateRequestTurnDetectionEagerness>,
+	/// Used only for `server_vad` mode. Activation threshold for VAD (0.0 to 1.0), this defaults to 0.5. A
+	/// higher threshold will require louder audio to activate the model, and
+	/// thus might perform better in noisy environments.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub input_audio_transcription: Option<RealtimeSessionCreateRequestInputAudioTranscription>,
-	/// The default system instructions (i.e. system message) prepended to model  calls. This field allows the client to guide the model on desired  responses. The model can be instructed on response content and format,  (e.g. "be extremely succinct", "act friendly", "here are examples of good  responses") and on audio behavior (e.g. "talk quickly", "inject emotion  into your voice", "laugh frequently"). The instructions are not guaranteed  to be followed by the model, but they provide guidance to the model on the desired behavior.
-	/// 
-	/// Note that the server sets default instructions which will be used if this  field is not set and are visible in the `session.created` event at the  start of the session.
+	pub threshold: Option<f64>,
+	/// Used only for `server_vad` mode. Amount of audio to include before the VAD detected speech (in
+	/// milliseconds). Defaults to 300ms.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub prefix_padding_ms: Option<i64>,
+	/// Used only for `server_vad` mode. Duration of silence to detect speech stop (in milliseconds).
+	/// Defaults
+	/// to 500ms. With shorter values the model will respond more quickly,
+	/// but may jump in on short pauses from the user.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub silence_duration_ms: Option<i64>,
+	/// Whether or not to automatically generate a response when a VAD stop event occurs.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub create_response: Option<bool>,
+	/// Whether or not to automatically interrupt any ongoing response with output to the default
+	/// conversation (i.e. `conversation` of `auto`) when a VAD start event occurs.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub interrupt_response: Option<bool>,
+}
+/// Used only for `semantic_vad` mode. The eagerness of the model to respond. `low` will wait longer for
+/// the user to continue speaking, `high` will respond more quickly. `auto` is the default and is
+/// equivalent to `medium`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeSessionCreateRequestTurnDetectionEagerness {
+	Low,
+	Medium,
+	High,
+	Auto,
+}
+/// Type of turn detection.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeSessionCreateRequestTurnDetectionType {
+	#[serde(rename = "server_vad")]
+	ServerVad,
+	#[serde(rename = "semantic_vad")]
+	SemanticVad,
+}
+/// A new Realtime session configuration, with an ephermeral key. Default TTL
+/// for keys is one minute.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeSessionCreateResponse {
+	/// Ephemeral key returned by the API.
+	pub client_secret: RealtimeSessionCreateResponseClientSecret,
+	/// The set of modalities the model can respond with. To disable audio,
+	/// set this to \["text"\].
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub modalities: Option<Vec<RealtimeSessionCreateResponseItem>>,
+	/// The default system instructions (i.e. system message) prepended to model
+	/// calls. This field allows the client to guide the model on desired
+	/// responses. The model can be instructed on response content and format,
+	/// (e.g. "be extremely succinct", "act friendly", "here are examples of good
+	/// responses") and on audio behavior (e.g. "talk quickly", "inject emotion
+	/// into your voice", "laugh frequently"). The instructions are not guaranteed
+	/// to be followed by the model, but they provide guidance to the model on the
+	/// desired behavior.
+	///
+	/// Note that the server sets default instructions which will be used if this
+	/// field is not set and are visible in the `session.created` event at the
+	/// start of the session.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub instructions: Option<String>,
+	/// The voice the model uses to respond. Voice cannot be changed during the
+	/// session once the model has responded with audio at least once. Current
+	/// voice options are `alloy`, `ash`, `ballad`, `coral`, `echo` `sage`,
+	/// `shimmer` and `verse`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub voice: Option<VoiceIdsShared>,
+	/// The format of input audio. Options are `pcm16`, `g711_ulaw`, or `g711_alaw`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub input_audio_format: Option<String>,
+	/// The format of output audio. Options are `pcm16`, `g711_ulaw`, or `g711_alaw`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub output_audio_format: Option<String>,
+	/// Configuration for input audio transcription, defaults to off and can be
+	/// set to `null` to turn off once on. Input audio transcription is not native
+	/// to the model, since the model consumes audio directly. Transcription runs
+	/// asynchronously through Whisper and should be treated as rough guidance
+	/// rather than the representation understood by the model.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub input_audio_transcription: Option<RealtimeSessionCreateResponseInputAudioTranscription>,
+	/// Configuration for turn detection. Can be set to `null` to turn off. Server
+	/// VAD means that the model will detect the start and end of speech based on
+	/// audio volume and respond at the end of user speech.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub turn_detection: Option<RealtimeSessionCreateResponseTurnDetection>,
+	/// Tools (functions) available to the model.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub tools: Option<Vec<RealtimeSessionCreateResponseToolsItem>>,
+	/// How the model chooses tools. Options are `auto`, `none`, `required`, or
+	/// specify a function.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub tool_choice: Option<String>,
+	/// Sampling temperature for the model, limited to \[0.6, 1.2\]. Defaults to 0.8.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub temperature: Option<f64>,
 	/// Maximum number of output tokens for a single assistant response,
 	/// inclusive of tool calls. Provide an integer between 1 and 4096 to
 	/// limit output tokens, or `inf` for the maximum available tokens for a
 	/// given model. Defaults to `inf`.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub max_response_output_tokens: Option<RealtimeSessionCreateRequestMaxResponseOutputTokens>,
-	/// The set of modalities the model can respond with. To disable audio,
-	/// set this to ["text"].
+	pub max_response_output_tokens: Option<RealtimeSessionCreateResponseMaxResponseOutputTokens>,
+}
+/// Ephemeral key returned by the API.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeSessionCreateResponseClientSecret {
+	/// Ephemeral key usable in client environments to authenticate connections
+	/// to the Realtime API. Use this in client-side environments rather than
+	/// a standard API token, which should only be used server-side.
+	pub value: String,
+	/// Timestamp for when the token expires. Currently, all tokens expire
+	/// after one minute.
+	pub expires_at: i64,
+}
+/// Configuration for input audio transcription, defaults to off and can be
+/// set to `null` to turn off once on. Input audio transcription is not native
+/// to the model, since the model consumes audio directly. Transcription runs
+/// asynchronously through Whisper and should be treated as rough guidance
+/// rather than the representation understood by the model.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeSessionCreateResponseInputAudioTranscription {
+	/// The model to use for transcription, `whisper-1` is the only currently
+	/// supported model.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub modalities: Option<Vec<RealtimeSessionCreateRequestItem>>,
-	/// The Realtime model used for this session.
+	pub model: Option<String>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeSessionCreateResponseItem {
+	Text,
+	Audio,
+}
+/// Maximum number of output tokens for a single assistant response,
+/// inclusive of tool calls. Provide an integer between 1 and 4096 to
+/// limit output tokens, or `inf` for the maximum available tokens for a
+/// given model. Defaults to `inf`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(untagged)]
+pub enum RealtimeSessionCreateResponseMaxResponseOutputTokens {
+	Integer(i64),
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeSessionCreateResponseToolsItem {
+	/// The type of the tool, i.e. `function`.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub model: Option<RealtimeSessionCreateRequestModel>,
-	/// The format of output audio. Options are `pcm16`, `g711_ulaw`, or `g711_alaw`.
-	/// For `pcm16`, output audio is sampled at a rate of 24kHz.
+	pub r#type: Option<RealtimeSessionCreateResponseToolsItemType>,
+	/// The name of the function.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub output_audio_format: Option<RealtimeSessionCreateRequestOutputAudioFormat>,
-	/// Sampling temperature for the model, limited to [0.6, 1.2]. For audio models a temperature of 0.8 is highly recommended for best performance.
+	pub name: Option<String>,
+	/// The description of the function, including guidance on when and how
+	/// to call it, and guidance about what to tell the user when calling
+	/// (if anything).
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub temperature: Option<f64>,
-	/// How the model chooses tools. Options are `auto`, `none`, `required`, or 
-	/// specify a function.
+	pub description: Option<String>,
+	/// Parameters of the function in JSON Schema.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub tool_choice: Option<String>,
-	/// Tools (functions) available to the model.
+	pub parameters: Option<serde_json::Value>,
+}
+/// The type of the tool, i.e. `function`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeSessionCreateResponseToolsItemType {
+	Function,
+}
+/// Configuration for turn detection. Can be set to `null` to turn off. Server
+/// VAD means that the model will detect the start and end of speech based on
+/// audio volume and respond at the end of user speech.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeSessionCreateResponseTurnDetection {
+	/// Type of turn detection, only `server_vad` is currently supported.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub tools: Option<Vec<RealtimeSessionCreateRequestToolsItem>>,
-	/// Configuration for turn detection, ether Server VAD or Semantic VAD. This can be set to `null` to turn off, in which case the client must manually trigger model response.
-	/// Server VAD means that the model will detect the start and end of speech based on audio volume and respond at the end of user speech.
-	/// Semantic VAD is more advanced and uses a turn detection model (in conjuction with VAD) to semantically estimate whether the user has finished speaking, then dynamically sets a timeout based on this probability. For example, if user audio trails off with "uhhm", the model will score a low probability of turn end and wait longer for the user to continue speaking. This can be useful for more natural conversations, but may have a higher latency.
+	pub r#type: Option<String>,
+	/// Activation threshold for VAD (0.0 to 1.0), this defaults to 0.5. A
+	/// higher threshold will require louder audio to activate the model, and
+	/// thus might perform better in noisy environments.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub turn_detection: Option<RealtimeSessionCreateRequestTurnDetection>,
-	/// The voice the model uses to respond. Voice cannot be changed during the 
-	/// session once the model has responded with audio at least once. Current 
-	/// voice options are `alloy`, `ash`, `ballad`, `coral`, `echo`, `fable`,
-	/// `onyx`, `nova`, `sage`, `shimmer`, and `verse`.
+	pub threshold: Option<f64>,
+	/// Amount of audio to include before the VAD detected speech (in
+	/// milliseconds). Defaults to 300ms.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub voice: Option<VoiceIdsShared>,
+	pub prefix_padding_ms: Option<i64>,
+	/// Duration of silence to detect speech stop (in milliseconds). Defaults
+	/// to 500ms. With shorter values the model will respond more quickly,
+	/// but may jump in on short pauses from the user.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub silence_duration_ms: Option<i64>,
 }
 /// The format of input audio. Options are `pcm16`, `g711_ulaw`, or `g711_alaw`.
-/// For `pcm16`, input audio must be 16-bit PCM at a 24kHz sample rate, 
+/// For `pcm16`, input audio must be 16-bit PCM at a 24kHz sample rate,
 /// single channel (mono), and little-endian byte order.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
-pub enum RealtimeSessionCreateRequestInputAudioFormat {
+pub enum RealtimeSessionInputAudioFormat {
 	Pcm16,
 	#[serde(rename = "g711_ulaw")]
 	G711Ulaw,
@@ -75,44 +1899,57 @@ pub enum RealtimeSessionCreateRequestInputAudioFormat {
 	G711Alaw,
 }
 /// Configuration for input audio noise reduction. This can be set to `null` to turn off.
-/// Noise reduction filters audio added to the input audio buffer before it is sent to VAD and the model.
-/// Filtering the audio can improve VAD and turn detection accuracy (reducing false positives) and model performance by improving perception of the input audio.
+/// Noise reduction filters audio added to the input audio buffer before it is sent to VAD and the
+/// model.
+/// Filtering the audio can improve VAD and turn detection accuracy (reducing false positives) and model
+/// performance by improving perception of the input audio.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-pub struct RealtimeSessionCreateRequestInputAudioNoiseReduction {
-	/// Type of noise reduction. `near_field` is for close-talking microphones such as headphones, `far_field` is for far-field microphones such as laptop or conference room microphones.
+pub struct RealtimeSessionInputAudioNoiseReduction {
+	/// Type of noise reduction. `near_field` is for close-talking microphones such as headphones,
+	/// `far_field` is for far-field microphones such as laptop or conference room microphones.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub r#type: Option<RealtimeSessionCreateRequestInputAudioNoiseReductionType>,
+	pub r#type: Option<RealtimeSessionInputAudioNoiseReductionType>,
 }
-/// Type of noise reduction. `near_field` is for close-talking microphones such as headphones, `far_field` is for far-field microphones such as laptop or conference room microphones.
+/// Type of noise reduction. `near_field` is for close-talking microphones such as headphones,
+/// `far_field` is for far-field microphones such as laptop or conference room microphones.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
-pub enum RealtimeSessionCreateRequestInputAudioNoiseReductionType {
+pub enum RealtimeSessionInputAudioNoiseReductionType {
 	#[serde(rename = "near_field")]
 	NearField,
 	#[serde(rename = "far_field")]
 	FarField,
 }
-/// Configuration for input audio transcription, defaults to off and can be  set to `null` to turn off once on. Input audio transcription is not native to the model, since the model consumes audio directly. Transcription runs  asynchronously through [the /audio/transcriptions endpoint](https://platform.openai.com/docs/api-reference/audio/createTranscription) and should be treated as guidance of input audio content rather than precisely what the model heard. The client can optionally set the language and prompt for transcription, these offer additional guidance to the transcription service.
+/// Configuration for input audio transcription, defaults to off and can be  set to `null` to turn off
+/// once on. Input audio transcription is not native to the model, since the model consumes audio
+/// directly. Transcription runs  asynchronously through [the /audio/transcriptions
+/// endpoint](https://platform.openai.com/docs/api-reference/audio/createTranscription) and should be
+/// treated as guidance of input audio content rather than precisely what the model heard. The client
+/// can optionally set the language and prompt for transcription, these offer additional guidance to the
+/// transcription service.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-pub struct RealtimeSessionCreateRequestInputAudioTranscription {
+pub struct RealtimeSessionInputAudioTranscription {
+	/// The model to use for transcription, current options are `gpt-4o-transcribe`,
+	/// `gpt-4o-mini-transcribe`, and `whisper-1`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub model: Option<String>,
 	/// The language of the input audio. Supplying the input language in
 	/// [ISO-639-1](https://en.wikipedia.org/wiki/List_of_ISO_639-1_codes) (e.g. `en`) format
 	/// will improve accuracy and latency.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub language: Option<String>,
-	/// The model to use for transcription, current options are `gpt-4o-transcribe`, `gpt-4o-mini-transcribe`, and `whisper-1`.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub model: Option<String>,
 	/// An optional text to guide the model's style or continue a previous audio
 	/// segment.
-	/// For `whisper-1`, the [prompt is a list of keywords](https://platform.openai.com/docs/guides/speech-to-text#prompting).
-	/// For `gpt-4o-transcribe` models, the prompt is a free text string, for example "expect words related to technology".
+	/// For `whisper-1`, the [prompt is a list of
+	/// keywords](https://platform.openai.com/docs/guides/speech-to-text#prompting).
+	/// For `gpt-4o-transcribe` models, the prompt is a free text string, for example "expect words related
+	/// to technology".
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub prompt: Option<String>,
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
-pub enum RealtimeSessionCreateRequestItem {
+pub enum RealtimeSessionItem {
 	Text,
 	Audio,
 }
@@ -123,13 +1960,13 @@ pub enum RealtimeSessionCreateRequestItem {
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
 #[serde(untagged)]
-pub enum RealtimeSessionCreateRequestMaxResponseOutputTokens {
+pub enum RealtimeSessionMaxResponseOutputTokens {
 	Integer(i64),
 }
 /// The Realtime model used for this session.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
-pub enum RealtimeSessionCreateRequestModel {
+pub enum RealtimeSessionModel {
 	#[serde(rename = "gpt-4o-realtime-preview")]
 	Gpt4ORealtimePreview,
 	#[serde(rename = "gpt-4o-realtime-preview-2024-10-01")]
@@ -145,7 +1982,7 @@ pub enum RealtimeSessionCreateRequestModel {
 /// For `pcm16`, output audio is sampled at a rate of 24kHz.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
-pub enum RealtimeSessionCreateRequestOutputAudioFormat {
+pub enum RealtimeSessionOutputAudioFormat {
 	Pcm16,
 	#[serde(rename = "g711_ulaw")]
 	G711Ulaw,
@@ -153,65 +1990,76 @@ pub enum RealtimeSessionCreateRequestOutputAudioFormat {
 	G711Alaw,
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-pub struct RealtimeSessionCreateRequestToolsItem {
-	/// The description of the function, including guidance on when and how 
-	/// to call it, and guidance about what to tell the user when calling 
-	/// (if anything).
+pub struct RealtimeSessionToolsItem {
+	/// The type of the tool, i.e. `function`.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub description: Option<String>,
+	pub r#type: Option<RealtimeSessionToolsItemType>,
 	/// The name of the function.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub name: Option<String>,
+	/// The description of the function, including guidance on when and how
+	/// to call it, and guidance about what to tell the user when calling
+	/// (if anything).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub description: Option<String>,
 	/// Parameters of the function in JSON Schema.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub parameters: Option<serde_json::Value>,
-	/// The type of the tool, i.e. `function`.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub r#type: Option<RealtimeSessionCreateRequestToolsItemType>,
 }
 /// The type of the tool, i.e. `function`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
-pub enum RealtimeSessionCreateRequestToolsItemType {
+pub enum RealtimeSessionToolsItemType {
 	Function,
 }
-/// Configuration for turn detection, ether Server VAD or Semantic VAD. This can be set to `null` to turn off, in which case the client must manually trigger model response.
-/// Server VAD means that the model will detect the start and end of speech based on audio volume and respond at the end of user speech.
-/// Semantic VAD is more advanced and uses a turn detection model (in conjuction with VAD) to semantically estimate whether the user has finished speaking, then dynamically sets a timeout based on this probability. For example, if user audio trails off with "uhhm", the model will score a low probability of turn end and wait longer for the user to continue speaking. This can be useful for more natural conversations, but may have a higher latency.
+/// Configuration for turn detection, ether Server VAD or Semantic VAD. This can be set to `null` to
+/// turn off, in which case the client must manually trigger model response.
+/// Server VAD means that the model will detect the start and end of speech based on audio volume and
+/// respond at the end of user speech.
+/// Semantic VAD is more advanced and uses a turn detection model (in conjuction with VAD) to
+/// semantically estimate whether the user has finished speaking, then dynamically sets a timeout based
+/// on this probability. For example, if user audio trails off with "uhhm", the model will score a low
+/// probability of turn end and wait longer for the user to continue speaking. This can be useful for
+/// more natural conversations, but may have a higher latency.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-pub struct RealtimeSessionCreateRequestTurnDetection {
-	/// Whether or not to automatically generate a response when a VAD stop event occurs.
+pub struct RealtimeSessionTurnDetection {
+	/// Type of turn detection.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub create_response: Option<bool>,
-	/// Used only for `semantic_vad` mode. The eagerness of the model to respond. `low` will wait longer for the user to continue speaking, `high` will respond more quickly. `auto` is the default and is equivalent to `medium`.
+	pub r#type: Option<RealtimeSessionTurnDetectionType>,
+	/// Used only for `semantic_vad` mode. The eagerness of the model to respond. `low` will wait longer for
+	/// the user to continue speaking, `high` will respond more quickly. `auto` is the default and is
+	/// equivalent to `medium`.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub eagerness: Option<RealtimeSessionCreateRequestTurnDetectionEagerness>,
-	/// Whether or not to automatically interrupt any ongoing response with output to the default
-	/// conversation (i.e. `conversation` of `auto`) when a VAD start event occurs.
+	pub eagerness: Option<RealtimeSessionTurnDetectionEagerness>,
+	/// Used only for `server_vad` mode. Activation threshold for VAD (0.0 to 1.0), this defaults to 0.5. A
+	/// higher threshold will require louder audio to activate the model, and
+	/// thus might perform better in noisy environments.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub interrupt_response: Option<bool>,
-	/// Used only for `server_vad` mode. Amount of audio to include before the VAD detected speech (in 
+	pub threshold: Option<f64>,
+	/// Used only for `server_vad` mode. Amount of audio to include before the VAD detected speech (in
 	/// milliseconds). Defaults to 300ms.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub prefix_padding_ms: Option<i64>,
-	/// Used only for `server_vad` mode. Duration of silence to detect speech stop (in milliseconds). Defaults 
-	/// to 500ms. With shorter values the model will respond more quickly, 
+	/// Used only for `server_vad` mode. Duration of silence to detect speech stop (in milliseconds).
+	/// Defaults
+	/// to 500ms. With shorter values the model will respond more quickly,
 	/// but may jump in on short pauses from the user.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub silence_duration_ms: Option<i64>,
-	/// Used only for `server_vad` mode. Activation threshold for VAD (0.0 to 1.0), this defaults to 0.5. A 
-	/// higher threshold will require louder audio to activate the model, and 
-	/// thus might perform better in noisy environments.
+	/// Whether or not to automatically generate a response when a VAD stop event occurs.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub threshold: Option<f64>,
-	/// Type of turn detection.
+	pub create_response: Option<bool>,
+	/// Whether or not to automatically interrupt any ongoing response with output to the default
+	/// conversation (i.e. `conversation` of `auto`) when a VAD start event occurs.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub r#type: Option<RealtimeSessionCreateRequestTurnDetectionType>,
+	pub interrupt_response: Option<bool>,
 }
-/// Used only for `semantic_vad` mode. The eagerness of the model to respond. `low` will wait longer for the user to continue speaking, `high` will respond more quickly. `auto` is the default and is equivalent to `medium`.
+/// Used only for `semantic_vad` mode. The eagerness of the model to respond. `low` will wait longer for
+/// the user to continue speaking, `high` will respond more quickly. `auto` is the default and is
+/// equivalent to `medium`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
-pub enum RealtimeSessionCreateRequestTurnDetectionEagerness {
+pub enum RealtimeSessionTurnDetectionEagerness {
 	Low,
 	Medium,
 	High,
@@ -220,44 +2068,62 @@ pub enum RealtimeSessionCreateRequestTurnDetectionEagerness {
 /// Type of turn detection.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
-pub enum RealtimeSessionCreateRequestTurnDetectionType {
+pub enum RealtimeSessionTurnDetectionType {
 	#[serde(rename = "server_vad")]
 	ServerVad,
 	#[serde(rename = "semantic_vad")]
 	SemanticVad,
 }
 /// Realtime transcription session object configuration.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = RealtimeTranscriptionSessionCreateRequest {
+///     ..Default::default()
+/// };
+/// ```
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct RealtimeTranscriptionSessionCreateRequest {
-	/// The set of items to include in the transcription. Current available items are:
-	/// - `item.input_audio_transcription.logprobs`
+	/// The set of modalities the model can respond with. To disable audio,
+	/// set this to \["text"\].
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub include: Option<Vec<String>>,
+	pub modalities: Option<Vec<RealtimeTranscriptionSessionCreateRequestItem>>,
 	/// The format of input audio. Options are `pcm16`, `g711_ulaw`, or `g711_alaw`.
-	/// For `pcm16`, input audio must be 16-bit PCM at a 24kHz sample rate, 
+	/// For `pcm16`, input audio must be 16-bit PCM at a 24kHz sample rate,
 	/// single channel (mono), and little-endian byte order.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub input_audio_format: Option<RealtimeTranscriptionSessionCreateRequestInputAudioFormat>,
-	/// Configuration for input audio noise reduction. This can be set to `null` to turn off.
-	/// Noise reduction filters audio added to the input audio buffer before it is sent to VAD and the model.
-	/// Filtering the audio can improve VAD and turn detection accuracy (reducing false positives) and model performance by improving perception of the input audio.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub input_audio_noise_reduction: Option<RealtimeTranscriptionSessionCreateRequestInputAudioNoiseReduction>,
-	/// Configuration for input audio transcription. The client can optionally set the language and prompt for transcription, these offer additional guidance to the transcription service.
+	/// Configuration for input audio transcription. The client can optionally set the language and prompt
+	/// for transcription, these offer additional guidance to the transcription service.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub input_audio_transcription: Option<RealtimeTranscriptionSessionCreateRequestInputAudioTranscription>,
-	/// The set of modalities the model can respond with. To disable audio,
-	/// set this to ["text"].
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub modalities: Option<Vec<RealtimeTranscriptionSessionCreateRequestItem>>,
-	/// Configuration for turn detection, ether Server VAD or Semantic VAD. This can be set to `null` to turn off, in which case the client must manually trigger model response.
-	/// Server VAD means that the model will detect the start and end of speech based on audio volume and respond at the end of user speech.
-	/// Semantic VAD is more advanced and uses a turn detection model (in conjuction with VAD) to semantically estimate whether the user has finished speaking, then dynamically sets a timeout based on this probability. For example, if user audio trails off with "uhhm", the model will score a low probability of turn end and wait longer for the user to continue speaking. This can be useful for more natural conversations, but may have a higher latency.
+	/// Configuration for turn detection, ether Server VAD or Semantic VAD. This can be set to `null` to
+	/// turn off, in which case the client must manually trigger model response.
+	/// Server VAD means that the model will detect the start and end of speech based on audio volume and
+	/// respond at the end of user speech.
+	/// Semantic VAD is more advanced and uses a turn detection model (in conjuction with VAD) to
+	/// semantically estimate whether the user has finished speaking, then dynamically sets a timeout based
+	/// on this probability. For example, if user audio trails off with "uhhm", the model will score a low
+	/// probability of turn end and wait longer for the user to continue speaking. This can be useful for
+	/// more natural conversations, but may have a higher latency.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub turn_detection: Option<RealtimeTranscriptionSessionCreateRequestTurnDetection>,
+	/// Configuration for input audio noise reduction. This can be set to `null` to turn off.
+	/// Noise reduction filters audio added to the input audio buffer before it is sent to VAD and the
+	/// model.
+	/// Filtering the audio can improve VAD and turn detection accuracy (reducing false positives) and model
+	/// performance by improving perception of the input audio.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub input_audio_noise_reduction: Option<RealtimeTranscriptionSessionCreateRequestInputAudioNoiseReduction>,
+	/// The set of items to include in the transcription. Current available items are:
+	/// - `item.input_audio_transcription.logprobs`
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub include: Option<Vec<String>>,
 }
 /// The format of input audio. Options are `pcm16`, `g711_ulaw`, or `g711_alaw`.
-/// For `pcm16`, input audio must be 16-bit PCM at a 24kHz sample rate, 
+/// For `pcm16`, input audio must be 16-bit PCM at a 24kHz sample rate,
 /// single channel (mono), and little-endian byte order.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -269,15 +2135,19 @@ pub enum RealtimeTranscriptionSessionCreateRequestInputAudioFormat {
 	G711Alaw,
 }
 /// Configuration for input audio noise reduction. This can be set to `null` to turn off.
-/// Noise reduction filters audio added to the input audio buffer before it is sent to VAD and the model.
-/// Filtering the audio can improve VAD and turn detection accuracy (reducing false positives) and model performance by improving perception of the input audio.
+/// Noise reduction filters audio added to the input audio buffer before it is sent to VAD and the
+/// model.
+/// Filtering the audio can improve VAD and turn detection accuracy (reducing false positives) and model
+/// performance by improving perception of the input audio.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct RealtimeTranscriptionSessionCreateRequestInputAudioNoiseReduction {
-	/// Type of noise reduction. `near_field` is for close-talking microphones such as headphones, `far_field` is for far-field microphones such as laptop or conference room microphones.
+	/// Type of noise reduction. `near_field` is for close-talking microphones such as headphones,
+	/// `far_field` is for far-field microphones such as laptop or conference room microphones.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub r#type: Option<RealtimeTranscriptionSessionCreateRequestInputAudioNoiseReductionType>,
 }
-/// Type of noise reduction. `near_field` is for close-talking microphones such as headphones, `far_field` is for far-field microphones such as laptop or conference room microphones.
+/// Type of noise reduction. `near_field` is for close-talking microphones such as headphones,
+/// `far_field` is for far-field microphones such as laptop or conference room microphones.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum RealtimeTranscriptionSessionCreateRequestInputAudioNoiseReductionType {
@@ -286,25 +2156,30 @@ pub enum RealtimeTranscriptionSessionCreateRequestInputAudioNoiseReductionType {
 	#[serde(rename = "far_field")]
 	FarField,
 }
-/// Configuration for input audio transcription. The client can optionally set the language and prompt for transcription, these offer additional guidance to the transcription service.
+/// Configuration for input audio transcription. The client can optionally set the language and prompt
+/// for transcription, these offer additional guidance to the transcription service.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct RealtimeTranscriptionSessionCreateRequestInputAudioTranscription {
+	/// The model to use for transcription, current options are `gpt-4o-transcribe`,
+	/// `gpt-4o-mini-transcribe`, and `whisper-1`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub model: Option<RealtimeTranscriptionSessionCreateRequestInputAudioTranscriptionModel>,
 	/// The language of the input audio. Supplying the input language in
 	/// [ISO-639-1](https://en.wikipedia.org/wiki/List_of_ISO_639-1_codes) (e.g. `en`) format
 	/// will improve accuracy and latency.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub language: Option<String>,
-	/// The model to use for transcription, current options are `gpt-4o-transcribe`, `gpt-4o-mini-transcribe`, and `whisper-1`.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub model: Option<RealtimeTranscriptionSessionCreateRequestInputAudioTranscriptionModel>,
 	/// An optional text to guide the model's style or continue a previous audio
 	/// segment.
-	/// For `whisper-1`, the [prompt is a list of keywords](https://platform.openai.com/docs/guides/speech-to-text#prompting).
-	/// For `gpt-4o-transcribe` models, the prompt is a free text string, for example "expect words related to technology".
+	/// For `whisper-1`, the [prompt is a list of
+	/// keywords](https://platform.openai.com/docs/guides/speech-to-text#prompting).
+	/// For `gpt-4o-transcribe` models, the prompt is a free text string, for example "expect words related
+	/// to technology".
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub prompt: Option<String>,
 }
-/// The model to use for transcription, current options are `gpt-4o-transcribe`, `gpt-4o-mini-transcribe`, and `whisper-1`.
+/// The model to use for transcription, current options are `gpt-4o-transcribe`,
+/// `gpt-4o-mini-transcribe`, and `whisper-1`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum RealtimeTranscriptionSessionCreateRequestInputAudioTranscriptionModel {
@@ -321,40 +2196,53 @@ pub enum RealtimeTranscriptionSessionCreateRequestItem {
 	Text,
 	Audio,
 }
-/// Configuration for turn detection, ether Server VAD or Semantic VAD. This can be set to `null` to turn off, in which case the client must manually trigger model response.
-/// Server VAD means that the model will detect the start and end of speech based on audio volume and respond at the end of user speech.
-/// Semantic VAD is more advanced and uses a turn detection model (in conjuction with VAD) to semantically estimate whether the user has finished speaking, then dynamically sets a timeout based on this probability. For example, if user audio trails off with "uhhm", the model will score a low probability of turn end and wait longer for the user to continue speaking. This can be useful for more natural conversations, but may have a higher latency.
+/// Configuration for turn detection, ether Server VAD or Semantic VAD. This can be set to `null` to
+/// turn off, in which case the client must manually trigger model response.
+/// Server VAD means that the model will detect the start and end of speech based on audio volume and
+/// respond at the end of user speech.
+/// Semantic VAD is more advanced and uses a turn detection model (in conjuction with VAD) to
+/// semantically estimate whether the user has finished speaking, then dynamically sets a timeout based
+/// on this probability. For example, if user audio trails off with "uhhm", the model will score a low
+/// probability of turn end and wait longer for the user to continue speaking. This can be useful for
+/// more natural conversations, but may have a higher latency.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct RealtimeTranscriptionSessionCreateRequestTurnDetection {
-	/// Whether or not to automatically generate a response when a VAD stop event occurs. Not available for transcription sessions.
+	/// Type of turn detection.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub create_response: Option<bool>,
-	/// Used only for `semantic_vad` mode. The eagerness of the model to respond. `low` will wait longer for the user to continue speaking, `high` will respond more quickly. `auto` is the default and is equivalent to `medium`.
+	pub r#type: Option<RealtimeTranscriptionSessionCreateRequestTurnDetectionType>,
+	/// Used only for `semantic_vad` mode. The eagerness of the model to respond. `low` will wait longer for
+	/// the user to continue speaking, `high` will respond more quickly. `auto` is the default and is
+	/// equivalent to `medium`.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub eagerness: Option<RealtimeTranscriptionSessionCreateRequestTurnDetectionEagerness>,
-	/// Whether or not to automatically interrupt any ongoing response with output to the default
-	/// conversation (i.e. `conversation` of `auto`) when a VAD start event occurs. Not available for transcription sessions.
+	/// Used only for `server_vad` mode. Activation threshold for VAD (0.0 to 1.0), this defaults to 0.5. A
+	/// higher threshold will require louder audio to activate the model, and
+	/// thus might perform better in noisy environments.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub interrupt_response: Option<bool>,
-	/// Used only for `server_vad` mode. Amount of audio to include before the VAD detected speech (in 
+	pub threshold: Option<f64>,
+	/// Used only for `server_vad` mode. Amount of audio to include before the VAD detected speech (in
 	/// milliseconds). Defaults to 300ms.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub prefix_padding_ms: Option<i64>,
-	/// Used only for `server_vad` mode. Duration of silence to detect speech stop (in milliseconds). Defaults 
-	/// to 500ms. With shorter values the model will respond more quickly, 
+	/// Used only for `server_vad` mode. Duration of silence to detect speech stop (in milliseconds).
+	/// Defaults
+	/// to 500ms. With shorter values the model will respond more quickly,
 	/// but may jump in on short pauses from the user.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub silence_duration_ms: Option<i64>,
-	/// Used only for `server_vad` mode. Activation threshold for VAD (0.0 to 1.0), this defaults to 0.5. A 
-	/// higher threshold will require louder audio to activate the model, and 
-	/// thus might perform better in noisy environments.
+	/// Whether or not to automatically generate a response when a VAD stop event occurs. Not available for
+	/// transcription sessions.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub threshold: Option<f64>,
-	/// Type of turn detection.
+	pub create_response: Option<bool>,
+	/// Whether or not to automatically interrupt any ongoing response with output to the default
+	/// conversation (i.e. `conversation` of `auto`) when a VAD start event occurs. Not available for
+	/// transcription sessions.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub r#type: Option<RealtimeTranscriptionSessionCreateRequestTurnDetectionType>,
+	pub interrupt_response: Option<bool>,
 }
-/// Used only for `semantic_vad` mode. The eagerness of the model to respond. `low` will wait longer for the user to continue speaking, `high` will respond more quickly. `auto` is the default and is equivalent to `medium`.
+/// Used only for `semantic_vad` mode. The eagerness of the model to respond. `low` will wait longer for
+/// the user to continue speaking, `high` will respond more quickly. `auto` is the default and is
+/// equivalent to `medium`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum RealtimeTranscriptionSessionCreateRequestTurnDetectionEagerness {
@@ -372,3 +2260,100 @@ pub enum RealtimeTranscriptionSessionCreateRequestTurnDetectionType {
 	#[serde(rename = "semantic_vad")]
 	SemanticVad,
 }
+/// A new Realtime transcription session configuration.
+///
+/// When a session is created on the server via REST API, the session object
+/// also contains an ephemeral key. Default TTL for keys is one minute. This
+/// property is not present when a session is updated via the WebSocket API.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeTranscriptionSessionCreateResponse {
+	/// Ephemeral key returned by the API. Only present when the session is
+	/// created on the server via REST API.
+	pub client_secret: RealtimeTranscriptionSessionCreateResponseClientSecret,
+	/// The set of modalities the model can respond with. To disable audio,
+	/// set this to \["text"\].
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub modalities: Option<Vec<RealtimeTranscriptionSessionCreateResponseItem>>,
+	/// The format of input audio. Options are `pcm16`, `g711_ulaw`, or `g711_alaw`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub input_audio_format: Option<String>,
+	/// Configuration of the transcription model.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub input_audio_transcription: Option<RealtimeTranscriptionSessionCreateResponseInputAudioTranscription>,
+	/// Configuration for turn detection. Can be set to `null` to turn off. Server
+	/// VAD means that the model will detect the start and end of speech based on
+	/// audio volume and respond at the end of user speech.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub turn_detection: Option<RealtimeTranscriptionSessionCreateResponseTurnDetection>,
+}
+/// Ephemeral key returned by the API. Only present when the session is
+/// created on the server via REST API.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RealtimeTranscriptionSessionCreateResponseClientSecret {
+	/// Ephemeral key usable in client environments to authenticate connections
+	/// to the Realtime API. Use this in client-side environments rather than
+	/// a standard API token, which should only be used server-side.
+	pub value: String,
+	/// Timestamp for when the token expires. Currently, all tokens expire
+	/// after one minute.
+	pub expires_at: i64,
+}
+/// Configuration of the transcription model.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeTranscriptionSessionCreateResponseInputAudioTranscription {
+	/// The model to use for transcription. Can be `gpt-4o-transcribe`, `gpt-4o-mini-transcribe`, or
+	/// `whisper-1`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub model: Option<RealtimeTranscriptionSessionCreateResponseInputAudioTranscriptionModel>,
+	/// The language of the input audio. Supplying the input language in
+	/// [ISO-639-1](https://en.wikipedia.org/wiki/List_of_ISO_639-1_codes) (e.g. `en`) format
+	/// will improve accuracy and latency.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub language: Option<String>,
+	/// An optional text to guide the model's style or continue a previous audio
+	/// segment. The [prompt](https://platform.openai.com/docs/guides/speech-to-text#prompting) should match
+	/// the audio language.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub prompt: Option<String>,
+}
+/// The model to use for transcription. Can be `gpt-4o-transcribe`, `gpt-4o-mini-transcribe`, or
+/// `whisper-1`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeTranscriptionSessionCreateResponseInputAudioTranscriptionModel {
+	#[serde(rename = "gpt-4o-transcribe")]
+	Gpt4OTranscribe,
+	#[serde(rename = "gpt-4o-mini-transcribe")]
+	Gpt4OMiniTranscribe,
+	#[serde(rename = "whisper-1")]
+	Whisper1,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeTranscriptionSessionCreateResponseItem {
+	Text,
+	Audio,
+}
+/// Configuration for turn detection. Can be set to `null` to turn off. Server
+/// VAD means that the model will detect the start and end of speech based on
+/// audio volume and respond at the end of user speech.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RealtimeTranscriptionSessionCreateResponseTurnDetection {
+	/// Type of turn detection, only `server_vad` is currently supported.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub r#type: Option<String>,
+	/// Activation threshold for VAD (0.0 to 1.0), this defaults to 0.5. A
+	/// higher threshold will require louder audio to activate the model, and
+	/// thus might perform better in noisy environments.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub threshold: Option<f64>,
+	/// Amount of audio to include before the VAD detected speech (in
+	/// milliseconds). Defaults to 300ms.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub prefix_padding_ms: Option<i64>,
+	/// Duration of silence to detect speech stop (in milliseconds). Defaults
+	/// to 500ms. With shorter values the model will respond more quickly,
+	/// but may jump in on short pauses from the user.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub silence_duration_ms: Option<i64>,
+}
@@ -0,0 +1,160 @@
+//! A WebSocket session for the Realtime API, which sends
+//!  [`RealtimeClientEvent`]s and yields [`RealtimeServerEvent`]s.
+//!
+//! Needs the `tokio`, `tokio-tungstenite` (with a TLS feature for `wss://`)
+//!  and `futures-util` crates. [`RealtimeSession::new`] accepts any
+//!  WebSocket, so sessions can be driven by an in-process server in tests.
+use super::{RealtimeClientEvent, RealtimeServerEvent};
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_util::{Sink, SinkExt, Stream, StreamExt};
+use tokio::net::TcpStream;
+use tokio_tungstenite::{
+    connect_async, MaybeTlsStream, WebSocketStream,
+    tungstenite::{self, client::IntoClientRequest, http::HeaderValue, Message},
+};
+
+/// The Realtime API's WebSocket endpoint
+pub const REALTIME_URL: &str = "wss://api.openai.com/v1/realtime";
+
+/// An error from a Realtime session
+#[derive(Debug)]
+pub enum RealtimeError {
+    /// The WebSocket failed or was closed
+    WebSocket(tungstenite::Error),
+    /// An event couldn't be encoded or decoded
+    Json(serde_json::Error),
+    /// A header value couldn't be built
+    Header(tungstenite::http::header::InvalidHeaderValue),
+}
+impl std::fmt::Display for RealtimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            RealtimeError::WebSocket(error) => write!(f, "Realtime WebSocket error: {}", error),
+            RealtimeError::Json(error) => write!(f, "Realtime event JSON error: {}", error),
+            RealtimeError::Header(error) => write!(f, "Realtime header error: {}", error),
+        }
+    }
+}
+impl std::error::Error for RealtimeError {}
+impl From<tungstenite::Error> for RealtimeError {
+    fn from(error: tungstenite::Error) -> Self {
+        RealtimeError::WebSocket(error)
+    }
+}
+impl From<serde_json::Error> for RealtimeError {
+    fn from(error: serde_json::Error) -> Self {
+        RealtimeError::Json(error)
+    }
+}
+impl From<tungstenite::http::header::InvalidHeaderValue> for RealtimeError {
+    fn from(error: tungstenite::http::header::InvalidHeaderValue) -> Self {
+        RealtimeError::Header(error)
+    }
+}
+
+/// Encodes a client event as a WebSocket text frame
+pub fn encode(event: &RealtimeClientEvent) -> Result<Message, RealtimeError> {
+    Ok(Message::text(serde_json::to_string(event)?))
+}
+/// Decodes a WebSocket frame into a server event, or `None` for
+///  frames which don't carry one (pings, pongs and closes)
+pub fn decode(message: Message) -> Option<Result<RealtimeServerEvent, RealtimeError>> {
+    match message {
+        Message::Text(text) => Some(serde_json::from_str(text.as_ref()).map_err(RealtimeError::from)),
+        Message::Binary(bytes) => Some(serde_json::from_slice(bytes.as_ref()).map_err(RealtimeError::from)),
+        _ => None,
+    }
+}
+
+/// Percent-encodes a query value
+fn percent_encode(value: &str) -> String {
+    let mut encoded = String::new();
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+
+    encoded
+}
+
+/// A Realtime session over a WebSocket
+///
+/// Server events are read by polling the session as a [`Stream`].
+pub struct RealtimeSession<S> {
+    socket: S,
+}
+impl RealtimeSession<WebSocketStream<MaybeTlsStream<TcpStream>>> {
+    /// Connects to the Realtime API with the given model
+    pub async fn connect(api_key: &str, model: &str) -> Result<Self, RealtimeError> {
+        Self::connect_to(REALTIME_URL, api_key, model).await
+    }
+    /// Connects to a Realtime endpoint at the given URL, such as a
+    ///  `ws://127.0.0.1` test server
+    pub async fn connect_to(url: &str, api_key: &str, model: &str) -> Result<Self, RealtimeError> {
+        let mut request = format!("{}?model={}", url, percent_encode(model)).into_client_request()?;
+        request.headers_mut().insert("Authorization", HeaderValue::from_str(&format!("Bearer {}", api_key))?);
+        request.headers_mut().insert("OpenAI-Beta", HeaderValue::from_static("realtime=v1"));
+
+        let (socket, _response) = connect_async(request).await?;
+
+        Ok(Self::new(socket))
+    }
+}
+impl<S> RealtimeSession<S>
+where
+    S: Stream<Item = Result<Message, tungstenite::Error>> + Sink<Message, Error = tungstenite::Error> + Unpin,
+{
+    /// Wraps an already-open WebSocket
+    pub fn new(socket: S) -> Self {
+        Self { socket }
+    }
+    /// Sends a client event
+    pub async fn send(&mut self, event: &RealtimeClientEvent) -> Result<(), RealtimeError> {
+        self.socket.send(encode(event)?).await?;
+
+        Ok(())
+    }
+    /// Waits for the next server event, or `None` once the socket closes
+    pub async fn recv(&mut self) -> Option<Result<RealtimeServerEvent, RealtimeError>> {
+        self.next().await
+    }
+    /// Closes the WebSocket
+    pub async fn close(mut self) -> Result<(), RealtimeError> {
+        self.socket.close().await?;
+
+        Ok(())
+    }
+    /// Gives back the underlying WebSocket
+    pub fn into_inner(self) -> S {
+        self.socket
+    }
+}
+impl<S> Stream for RealtimeSession<S>
+where
+    S: Stream<Item = Result<Message, tungstenite::Error>> + Unpin,
+{
+    type Item = Result<RealtimeServerEvent, RealtimeError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match self.socket.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(message))) => {
+                    // Skip control frames, they aren't events
+                    if let Some(event) = decode(message) {
+                        return Poll::Ready(Some(event));
+                    }
+                },
+                Poll::Ready(Some(Err(tungstenite::Error::ConnectionClosed))) => return Poll::Ready(None),
+                Poll::Ready(Some(Err(error))) => return Poll::Ready(Some(Err(error.into()))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
@@ -1,10 +1,15 @@
+use super::aliases::CreateResponseInputInputItemArray;
 use super::aliases::InputMessageContentList;
-use super::assistants_chat_responses::ResponseFormatJsonObject;
-use super::assistants_chat_responses::ResponseFormatText;
-use super::chat_responses::CreateModelResponseProperties;
-use super::chat_responses::WebSearchLocation;
-use super::responses_vector_stores::ComparisonFilter;
-use super::responses_vector_stores::CompoundFilter;
+use super::aliases::Metadata;
+use super::aliases::ModelIdsResponses;
+use super::aliases::ResponseFormatJsonSchemaSchema;
+use super::aliases::VectorStoreFileAttributes;
+use super::shared::ComparisonFilter;
+use super::shared::CompoundFilter;
+use super::shared::ReasoningEffort;
+use super::shared::ResponseFormatJsonObject;
+use super::shared::ResponseFormatText;
+use super::shared::WebSearchContextSize;
 
 use std::collections::HashMap;
 use serde::{Serialize, Deserialize};
@@ -20,17 +25,19 @@ pub enum Annotation {
 /// A click action.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Click {
-	/// Indicates which mouse button was pressed during the click. One of `left`, `right`, `wheel`, `back`, or `forward`.
-	pub button: ClickButton,
-	/// Specifies the event type. For a click action, this property is 
+	/// Specifies the event type. For a click action, this property is
 	/// always set to `click`.
 	pub r#type: ClickType,
+	/// Indicates which mouse button was pressed during the click. One of `left`, `right`, `wheel`, `back`,
+	/// or `forward`.
+	pub button: ClickButton,
 	/// The x-coordinate where the click occurred.
 	pub x: i64,
 	/// The y-coordinate where the click occurred.
 	pub y: i64,
 }
-/// Indicates which mouse button was pressed during the click. One of `left`, `right`, `wheel`, `back`, or `forward`.
+/// Indicates which mouse button was pressed during the click. One of `left`, `right`, `wheel`, `back`,
+/// or `forward`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum ClickButton {
@@ -40,52 +47,83 @@ pub enum ClickButton {
 	Back,
 	Forward,
 }
-/// Specifies the event type. For a click action, this property is 
+/// Specifies the event type. For a click action, this property is
 /// always set to `click`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum ClickType {
 	Click,
 }
-/// Specifies the comparison operator: `eq`, `ne`, `gt`, `gte`, `lt`, `lte`.
-/// - `eq`: equals
-/// - `ne`: not equal
-/// - `gt`: greater than
-/// - `gte`: greater than or equal
-/// - `lt`: less than
-/// - `lte`: less than or equal
+/// The output of a code interpreter tool call that is a file.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CodeInterpreterFileOutput {
+	/// The type of the code interpreter file output. Always `files`.
+	pub r#type: CodeInterpreterFileOutputType,
+	pub files: Vec<CodeInterpreterFileOutputFilesItem>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CodeInterpreterFileOutputFilesItem {
+	/// The MIME type of the file.
+	pub mime_type: String,
+	/// The ID of the file.
+	pub file_id: String,
+}
+/// The type of the code interpreter file output. Always `files`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
-pub enum ComparisonFilterType {
-	Eq,
-	Ne,
-	Gt,
-	Gte,
-	Lt,
-	Lte,
+pub enum CodeInterpreterFileOutputType {
+	Files,
 }
-/// The value to compare against the attribute key; supports string, number, or boolean types.
+/// The output of a code interpreter tool call that is text.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CodeInterpreterTextOutput {
+	/// The type of the code interpreter text output. Always `logs`.
+	pub r#type: CodeInterpreterTextOutputType,
+	/// The logs of the code interpreter tool call.
+	pub logs: String,
+}
+/// The type of the code interpreter text output. Always `logs`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
-#[serde(untagged)]
-pub enum ComparisonFilterValue {
-	String(String),
-	Number(f64),
-	Boolean(bool),
+pub enum CodeInterpreterTextOutputType {
+	Logs,
+}
+/// A tool call to run code.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CodeInterpreterToolCall {
+	/// The unique ID of the code interpreter tool call.
+	pub id: String,
+	/// The type of the code interpreter tool call. Always `code_interpreter_call`.
+	pub r#type: CodeInterpreterToolCallType,
+	/// The code to run.
+	pub code: String,
+	/// The status of the code interpreter tool call.
+	pub status: CodeInterpreterToolCallStatus,
+	/// The results of the code interpreter tool call.
+	pub results: Vec<CodeInterpreterToolOutput>,
 }
+/// The status of the code interpreter tool call.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
-#[serde(untagged)]
-pub enum CompoundFilterItems {
-	ComparisonFilter(ComparisonFilter),
-	Object(serde_json::Value),
+pub enum CodeInterpreterToolCallStatus {
+	#[serde(rename = "in_progress")]
+	InProgress,
+	Interpreting,
+	Completed,
+}
+/// The type of the code interpreter tool call. Always `code_interpreter_call`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CodeInterpreterToolCallType {
+	#[serde(rename = "code_interpreter_call")]
+	CodeInterpreterCall,
 }
-/// Type of operation: `and` or `or`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
-pub enum CompoundFilterType {
-	And,
-	Or,
+#[serde(untagged)]
+pub enum CodeInterpreterToolOutput {
+	CodeInterpreterTextOutput(CodeInterpreterTextOutput),
+	CodeInterpreterFileOutput(CodeInterpreterFileOutput),
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -104,17 +142,17 @@ pub enum ComputerAction {
 /// A computer screenshot image used with the computer use tool.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ComputerScreenshotImage {
-	/// The identifier of an uploaded file that contains the screenshot.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub file_id: Option<String>,
+	/// Specifies the event type. For a computer screenshot, this property is
+	/// always set to `computer_screenshot`.
+	pub r#type: ComputerScreenshotImageType,
 	/// The URL of the screenshot image.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub image_url: Option<String>,
-	/// Specifies the event type. For a computer screenshot, this property is 
-	/// always set to `computer_screenshot`.
-	pub r#type: ComputerScreenshotImageType,
+	/// The identifier of an uploaded file that contains the screenshot.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub file_id: Option<String>,
 }
-/// Specifies the event type. For a computer screenshot, this property is 
+/// Specifies the event type. For a computer screenshot, this property is
 /// always set to `computer_screenshot`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -122,55 +160,75 @@ pub enum ComputerScreenshotImageType {
 	#[serde(rename = "computer_screenshot")]
 	ComputerScreenshot,
 }
-/// A tool that controls a virtual computer. Learn more about the 
+/// A tool that controls a virtual computer. Learn more about the
 /// [computer tool](https://platform.openai.com/docs/guides/tools-computer-use).
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ComputerTool {
-	/// The height of the computer display.
-	pub display_height: f64,
+	/// The type of the computer use tool. Always `computer_use_preview`.
+	pub r#type: ComputerToolType,
 	/// The width of the computer display.
 	pub display_width: f64,
+	/// The height of the computer display.
+	pub display_height: f64,
 	/// The type of computer environment to control.
 	pub environment: ComputerToolEnvironment,
-	/// The type of the computer use tool. Always `computer_use_preview`.
-	pub r#type: ComputerToolType,
 }
-/// A tool call to a computer use tool. See the 
-/// [computer use guide](https://platform.openai.com/docs/guides/tools-computer-use) for more information.
+/// A tool call to a computer use tool. See the
+/// [computer use guide](https://platform.openai.com/docs/guides/tools-computer-use) for more
+/// information.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ComputerToolCall {
-	pub action: ComputerAction,
-	/// An identifier used when responding to the tool call with output.
-	pub call_id: String,
+	/// The type of the computer call. Always `computer_call`.
+	pub r#type: ComputerToolCallType,
 	/// The unique ID of the computer call.
 	pub id: String,
+	/// An identifier used when responding to the tool call with output.
+	pub call_id: String,
+	pub action: ComputerAction,
 	/// The pending safety checks for the computer call.
 	pub pending_safety_checks: Vec<ComputerToolCallSafetyCheck>,
 	/// The status of the item. One of `in_progress`, `completed`, or
 	/// `incomplete`. Populated when items are returned via API.
 	pub status: ComputerToolCallStatus,
-	/// The type of the computer call. Always `computer_call`.
-	pub r#type: ComputerToolCallType,
 }
 /// The output of a computer tool call.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ComputerToolCallOutput {
-	/// The safety checks reported by the API that have been acknowledged by the 
-	/// developer.
+	/// The type of the computer tool call output. Always `computer_call_output`.
+	pub r#type: ComputerToolCallOutputType,
+	/// The ID of the computer tool call output.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub acknowledged_safety_checks: Option<Vec<ComputerToolCallSafetyCheck>>,
+	pub id: Option<String>,
 	/// The ID of the computer tool call that produced the output.
 	pub call_id: String,
-	/// The ID of the computer tool call output.
+	/// The safety checks reported by the API that have been acknowledged by the
+	/// developer.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub id: Option<String>,
+	pub acknowledged_safety_checks: Option<Vec<ComputerToolCallSafetyCheck>>,
 	pub output: ComputerScreenshotImage,
 	/// The status of the message input. One of `in_progress`, `completed`, or
 	/// `incomplete`. Populated when input items are returned via API.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub status: Option<ComputerToolCallOutputStatus>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ComputerToolCallOutputResource {
 	/// The type of the computer tool call output. Always `computer_call_output`.
 	pub r#type: ComputerToolCallOutputType,
+	/// The ID of the computer tool call that produced the output.
+	pub call_id: String,
+	/// The safety checks reported by the API that have been acknowledged by the
+	/// developer.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub acknowledged_safety_checks: Option<Vec<ComputerToolCallSafetyCheck>>,
+	pub output: ComputerScreenshotImage,
+	/// The status of the message input. One of `in_progress`, `completed`, or
+	/// `incomplete`. Populated when input items are returned via API.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub status: Option<ComputerToolCallOutputStatus>,
+	/// The unique ID of the computer call tool output.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
 }
 /// The status of the message input. One of `in_progress`, `completed`, or
 /// `incomplete`. Populated when input items are returned via API.
@@ -192,10 +250,10 @@ pub enum ComputerToolCallOutputType {
 /// A pending safety check for the computer call.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ComputerToolCallSafetyCheck {
-	/// The type of the pending safety check.
-	pub code: String,
 	/// The ID of the pending safety check.
 	pub id: String,
+	/// The type of the pending safety check.
+	pub code: String,
 	/// Details about the pending safety check.
 	pub message: String,
 }
@@ -240,84 +298,143 @@ pub struct Coordinate {
 	/// The y-coordinate.
 	pub y: i64,
 }
+/// # Examples
+///
+/// Text input:
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateResponse {
+///     model: Some(serde_json::json!("gpt-4o")),
+///     input: Some(CreateResponseInput::String("Tell me a three sentence bedtime story about a unicorn.".to_string())),
+///     ..Default::default()
+/// };
+/// ```
+///
+/// Web search:
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateResponse {
+///     model: Some(serde_json::json!("gpt-4o")),
+///     tools: Some(vec![
+///         Tool::WebSearchTool(WebSearchTool {
+///             r#type: WebSearchToolType::WebSearchPreview,
+///             user_location: None,
+///             search_context_size: None,
+///         }),
+///     ]),
+///     input: Some(CreateResponseInput::String("What was a positive news story from today?".to_string())),
+///     ..Default::default()
+/// };
+/// ```
+///
+/// File search:
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateResponse {
+///     model: Some(serde_json::json!("gpt-4o")),
+///     tools: Some(vec![
+///         Tool::FileSearchTool(FileSearchTool {
+///             r#type: FileSearchToolType::FileSearch,
+///             vector_store_ids: vec![
+///                 "vs_1234567890".to_string(),
+///             ],
+///             max_num_results: Some(20),
+///             filters: None,
+///             ranking_options: None,
+///         }),
+///     ]),
+///     input: Some(CreateResponseInput::String("What are the attributes of an ancient brown dragon?".to_string())),
+///     ..Default::default()
+/// };
+/// ```
+///
+/// Streaming:
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateResponse {
+///     model: Some(serde_json::json!("gpt-4o")),
+///     instructions: Some("You are a helpful assistant.".to_string()),
+///     input: Some(CreateResponseInput::String("Hello!".to_string())),
+///     stream: Some(true),
+///     ..Default::default()
+/// };
+/// ```
+///
+/// Reasoning:
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateResponse {
+///     model: Some(serde_json::json!("o3-mini")),
+///     reasoning: Some(Reasoning {
+///         effort: Some(ReasoningEffort::High),
+///         ..Default::default()
+///     }),
+///     input: Some(CreateResponseInput::String("How much wood would a woodchuck chuck?".to_string())),
+///     ..Default::default()
+/// };
+/// ```
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct CreateResponse {
-	/// Specify additional output data to include in the model response. Currently
-	/// supported values are:
-	/// - `file_search_call.results`: Include the search results of
-	///   the file search tool call.
-	/// - `message.input_image.image_url`: Include image urls from the input message.
-	/// - `computer_call_output.output.image_url`: Include image urls from the computer call output.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub include: Option<Vec<Includable>>,
-	/// Text, image, or file inputs to the model, used to generate a response.
-	/// 
-	/// Learn more:
-	/// - [Text inputs and outputs](https://platform.openai.com/docs/guides/text)
-	/// - [Image inputs](https://platform.openai.com/docs/guides/images)
-	/// - [File inputs](https://platform.openai.com/docs/guides/pdf-files)
-	/// - [Conversation state](https://platform.openai.com/docs/guides/conversation-state)
-	/// - [Function calling](https://platform.openai.com/docs/guides/function-calling)
+	pub metadata: Option<Metadata>,
+	/// What sampling temperature to use, between 0 and 2. Higher values like 0.8 will make the output more
+	/// random, while lower values like 0.2 will make it more focused and deterministic.
+	/// We generally recommend altering this or `top_p` but not both.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub input: Option<CreateResponseInput>,
-	/// Inserts a system (or developer) message as the first item in the model's context.
-	/// 
-	/// When using along with `previous_response_id`, the instructions from a previous
-	/// response will be not be carried over to the next response. This makes it simple
-	/// to swap out system (or developer) messages in new responses.
+	pub temperature: Option<f64>,
+	/// An alternative to sampling with temperature, called nucleus sampling,
+	/// where the model considers the results of the tokens with top_p probability
+	/// mass. So 0.1 means only the tokens comprising the top 10% probability mass
+	/// are considered.
+	///
+	/// We generally recommend altering this or `temperature` but not both.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub instructions: Option<String>,
-	/// An upper bound for the number of tokens that can be generated for a response, including visible output tokens and [reasoning tokens](https://platform.openai.com/docs/guides/reasoning).
+	pub top_p: Option<f64>,
+	/// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse.
+	/// [Learn more](https://platform.openai.com/docs/guides/safety-best-practices#end-user-ids).
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub max_output_tokens: Option<i64>,
+	pub user: Option<String>,
+	/// The unique ID of the previous response to the model. Use this to
+	/// create multi-turn conversations. Learn more about
+	/// [conversation state](https://platform.openai.com/docs/guides/conversation-state).
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub metadata: Option<Metadata>,
+	pub previous_response_id: Option<String>,
 	/// Model ID used to generate the response, like `gpt-4o` or `o1`. OpenAI
 	/// offers a wide range of models with different capabilities, performance
-	/// characteristics, and price points. Refer to the [model guide](https://platform.openai.com/docs/models)
+	/// characteristics, and price points. Refer to the [model
+	/// guide](https://platform.openai.com/docs/models)
 	/// to browse and compare available models.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub model: Option<ModelIdsResponses>,
-	/// Whether to allow the model to run tool calls in parallel.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub parallel_tool_calls: Option<bool>,
-	/// The unique ID of the previous response to the model. Use this to
-	/// create multi-turn conversations. Learn more about 
-	/// [conversation state](https://platform.openai.com/docs/guides/conversation-state).
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub previous_response_id: Option<String>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub reasoning: Option<Reasoning>,
-	/// Whether to store the generated model response for later retrieval via
-	/// API.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub store: Option<bool>,
-	/// If set to true, the model response data will be streamed to the client
-	/// as it is generated using [server-sent events](https://developer.mozilla.org/en-US/docs/Web/API/Server-sent_events/Using_server-sent_events#Event_stream_format).
-	/// See the [Streaming section below](https://platform.openai.com/docs/api-reference/responses-streaming)
-	/// for more information.
+	/// An upper bound for the number of tokens that can be generated for a response, including visible
+	/// output tokens and [reasoning tokens](https://platform.openai.com/docs/guides/reasoning).
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub stream: Option<bool>,
-	/// What sampling temperature to use, between 0 and 2. Higher values like 0.8 will make the output more random, while lower values like 0.2 will make it more focused and deterministic.
-	/// We generally recommend altering this or `top_p` but not both.
+	pub max_output_tokens: Option<i64>,
+	/// Inserts a system (or developer) message as the first item in the model's context.
+	///
+	/// When using along with `previous_response_id`, the instructions from a previous
+	/// response will be not be carried over to the next response. This makes it simple
+	/// to swap out system (or developer) messages in new responses.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub temperature: Option<f64>,
+	pub instructions: Option<String>,
 	/// Configuration options for a text response from the model. Can be plain
 	/// text or structured JSON data. Learn more:
 	/// - [Text inputs and outputs](https://platform.openai.com/docs/guides/text)
 	/// - [Structured Outputs](https://platform.openai.com/docs/guides/structured-outputs)
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub text: Option<ResponsePropertiesText>,
-	/// How the model should select which tool (or tools) to use when generating
-	/// a response. See the `tools` parameter to see how to specify which tools
-	/// the model can call.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub tool_choice: Option<ResponsePropertiesToolChoice>,
-	/// An array of tools the model may call while generating a response. You 
+	/// An array of tools the model may call while generating a response. You
 	/// can specify which tool to use by setting the `tool_choice` parameter.
-	/// 
+	///
 	/// The two categories of tools you can provide the model are:
-	/// 
+	///
 	/// - **Built-in tools**: Tools that are provided by OpenAI that extend the
 	///   model's capabilities, like [web search](https://platform.openai.com/docs/guides/tools-web-search)
 	///   or [file search](https://platform.openai.com/docs/guides/tools-file-search). Learn more about
@@ -327,29 +444,56 @@ pub struct CreateResponse {
 	///   [function calling](https://platform.openai.com/docs/guides/function-calling).
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub tools: Option<Vec<Tool>>,
-	/// An alternative to sampling with temperature, called nucleus sampling,
-	/// where the model considers the results of the tokens with top_p probability
-	/// mass. So 0.1 means only the tokens comprising the top 10% probability mass
-	/// are considered.
-	/// 
-	/// We generally recommend altering this or `temperature` but not both.
+	/// How the model should select which tool (or tools) to use when generating
+	/// a response. See the `tools` parameter to see how to specify which tools
+	/// the model can call.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub top_p: Option<f64>,
+	pub tool_choice: Option<ResponsePropertiesToolChoice>,
 	/// The truncation strategy to use for the model response.
 	/// - `auto`: If the context of this response and previous ones exceeds
-	///   the model's context window size, the model will truncate the 
+	///   the model's context window size, the model will truncate the
 	///   response to fit the context window by dropping input items in the
-	///   middle of the conversation. 
-	/// - `disabled` (default): If a model response will exceed the context window 
+	///   middle of the conversation.
+	/// - `disabled` (default): If a model response will exceed the context window
 	///   size for a model, the request will fail with a 400 error.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub truncation: Option<ResponsePropertiesTruncation>,
-	/// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse. [Learn more](https://platform.openai.com/docs/guides/safety-best-practices#end-user-ids).
+	/// Text, image, or file inputs to the model, used to generate a response.
+	///
+	/// Learn more:
+	/// - [Text inputs and outputs](https://platform.openai.com/docs/guides/text)
+	/// - [Image inputs](https://platform.openai.com/docs/guides/images)
+	/// - [File inputs](https://platform.openai.com/docs/guides/pdf-files)
+	/// - [Conversation state](https://platform.openai.com/docs/guides/conversation-state)
+	/// - [Function calling](https://platform.openai.com/docs/guides/function-calling)
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub user: Option<String>,
+	pub input: Option<CreateResponseInput>,
+	/// Specify additional output data to include in the model response. Currently
+	/// supported values are:
+	/// - `file_search_call.results`: Include the search results of
+	///   the file search tool call.
+	/// - `message.input_image.image_url`: Include image urls from the input message.
+	/// - `computer_call_output.output.image_url`: Include image urls from the computer call output.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub include: Option<Vec<Includable>>,
+	/// Whether to allow the model to run tool calls in parallel.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub parallel_tool_calls: Option<bool>,
+	/// Whether to store the generated model response for later retrieval via
+	/// API.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub store: Option<bool>,
+	/// If set to true, the model response data will be streamed to the client
+	/// as it is generated using [server-sent
+	/// events](https://developer.mozilla.org/en-US/docs/Web/API/Server-sent_events/Using_server-sent_events#Event_stream_format).
+	/// See the [Streaming section
+	/// below](https://platform.openai.com/docs/api-reference/responses-streaming)
+	/// for more information.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub stream: Option<bool>,
 }
 /// Text, image, or file inputs to the model, used to generate a response.
-/// 
+///
 /// Learn more:
 /// - [Text inputs and outputs](https://platform.openai.com/docs/guides/text)
 /// - [Image inputs](https://platform.openai.com/docs/guides/images)
@@ -366,7 +510,7 @@ pub enum CreateResponseInput {
 /// A double click action.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct DoubleClick {
-	/// Specifies the event type. For a double click action, this property is 
+	/// Specifies the event type. For a double click action, this property is
 	/// always set to `double_click`.
 	pub r#type: DoubleClickType,
 	/// The x-coordinate where the double click occurred.
@@ -374,7 +518,7 @@ pub struct DoubleClick {
 	/// The y-coordinate where the double click occurred.
 	pub y: i64,
 }
-/// Specifies the event type. For a double click action, this property is 
+/// Specifies the event type. For a double click action, this property is
 /// always set to `double_click`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -385,20 +529,21 @@ pub enum DoubleClickType {
 /// A drag action.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Drag {
-	/// An array of coordinates representing the path of the drag action. Coordinates will appear as an array
+	/// Specifies the event type. For a drag action, this property is
+	/// always set to `drag`.
+	pub r#type: DragType,
+	/// An array of coordinates representing the path of the drag action. Coordinates will appear as an
+	/// array
 	/// of objects, eg
-	/// ```
+	/// ```json
 	/// [
 	///   { x: 100, y: 200 },
 	///   { x: 200, y: 300 }
 	/// ]
 	/// ```
 	pub path: Vec<Coordinate>,
-	/// Specifies the event type. For a drag action, this property is 
-	/// always set to `drag`.
-	pub r#type: DragType,
 }
-/// Specifies the event type. For a drag action, this property is 
+/// Specifies the event type. For a drag action, this property is
 /// always set to `drag`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -412,12 +557,12 @@ pub enum DragType {
 /// interactions.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct EasyInputMessage {
-	/// Text, image, or audio input to the model, used to generate a response.
-	/// Can also contain previous assistant responses.
-	pub content: EasyInputMessageContent,
 	/// The role of the message input. One of `user`, `assistant`, `system`, or
 	/// `developer`.
 	pub role: EasyInputMessageRole,
+	/// Text, image, or audio input to the model, used to generate a response.
+	/// Can also contain previous assistant responses.
+	pub content: EasyInputMessageContent,
 	/// The type of the message input. Always `message`.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub r#type: Option<EasyInputMessageType>,
@@ -450,12 +595,12 @@ pub enum EasyInputMessageType {
 /// A citation to a file.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct FileCitation {
-	/// The ID of the file.
-	pub file_id: String,
-	/// The index of the file in the list of files.
-	pub index: i64,
 	/// The type of the file citation. Always `file_citation`.
 	pub r#type: FileCitationType,
+	/// The index of the file in the list of files.
+	pub index: i64,
+	/// The ID of the file.
+	pub file_id: String,
 }
 /// The type of the file citation. Always `file_citation`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -467,12 +612,12 @@ pub enum FileCitationType {
 /// A path to a file.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct FilePath {
+	/// The type of the file path. Always `file_path`.
+	pub r#type: FilePathType,
 	/// The ID of the file.
 	pub file_id: String,
 	/// The index of the file in the list of files.
 	pub index: i64,
-	/// The type of the file path. Always `file_path`.
-	pub r#type: FilePathType,
 }
 /// The type of the file path. Always `file_path`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -485,56 +630,56 @@ pub enum FilePathType {
 /// Learn more about the [file search tool](https://platform.openai.com/docs/guides/tools-file-search).
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct FileSearchTool {
-	/// A filter to apply based on file attributes.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub filters: Option<FileSearchToolFilters>,
-	/// The maximum number of results to return. This number should be between 1 
+	/// The type of the file search tool. Always `file_search`.
+	pub r#type: FileSearchToolType,
+	/// The IDs of the vector stores to search.
+	pub vector_store_ids: Vec<String>,
+	/// The maximum number of results to return. This number should be between 1
 	/// and 50 inclusive.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub max_num_results: Option<i64>,
+	/// A filter to apply based on file attributes.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub filters: Option<FileSearchToolFilters>,
 	/// Ranking options for search.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub ranking_options: Option<HashMap<String, String>>,
-	/// The type of the file search tool. Always `file_search`.
-	pub r#type: FileSearchToolType,
-	/// The IDs of the vector stores to search.
-	pub vector_store_ids: Vec<String>,
 }
-/// The results of a file search tool call. See the 
+/// The results of a file search tool call. See the
 /// [file search guide](https://platform.openai.com/docs/guides/tools-file-search) for more information.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct FileSearchToolCall {
 	/// The unique ID of the file search tool call.
 	pub id: String,
+	/// The type of the file search tool call. Always `file_search_call`.
+	pub r#type: FileSearchToolCallType,
+	/// The status of the file search tool call. One of `in_progress`,
+	/// `searching`, `incomplete` or `failed`,
+	pub status: FileSearchToolCallStatus,
 	/// The queries used to search for files.
 	pub queries: Vec<String>,
 	/// The results of the file search tool call.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub results: Option<Vec<FileSearchToolCallResultsItem>>,
-	/// The status of the file search tool call. One of `in_progress`, 
-	/// `searching`, `incomplete` or `failed`,
-	pub status: FileSearchToolCallStatus,
-	/// The type of the file search tool call. Always `file_search_call`.
-	pub r#type: FileSearchToolCallType,
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct FileSearchToolCallResultsItem {
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub attributes: Option<VectorStoreFileAttributes>,
 	/// The unique ID of the file.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub file_id: Option<String>,
+	/// The text that was retrieved from the file.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub text: Option<String>,
 	/// The name of the file.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub filename: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub attributes: Option<VectorStoreFileAttributes>,
 	/// The relevance score of the file - a value between 0 and 1.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub score: Option<f64>,
-	/// The text that was retrieved from the file.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub text: Option<String>,
 }
-/// The status of the file search tool call. One of `in_progress`, 
+/// The status of the file search tool call. One of `in_progress`,
 /// `searching`, `incomplete` or `failed`,
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -572,56 +717,73 @@ pub enum FileSearchToolType {
 /// about [function calling](https://platform.openai.com/docs/guides/function-calling).
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct FunctionTool {
+	/// The type of the function tool. Always `function`.
+	pub r#type: FunctionToolType,
+	/// The name of the function to call.
+	pub name: String,
 	/// A description of the function. Used by the model to determine whether
 	/// or not to call the function.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub description: Option<String>,
-	/// The name of the function to call.
-	pub name: String,
 	/// A JSON schema object describing the parameters of the function.
 	pub parameters: serde_json::Value,
 	/// Whether to enforce strict parameter validation. Default `true`.
 	pub strict: bool,
-	/// The type of the function tool. Always `function`.
-	pub r#type: FunctionToolType,
 }
-/// A tool call to run a function. See the 
-/// [function calling guide](https://platform.openai.com/docs/guides/function-calling) for more information.
+/// A tool call to run a function. See the
+/// [function calling guide](https://platform.openai.com/docs/guides/function-calling) for more
+/// information.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct FunctionToolCall {
-	/// A JSON string of the arguments to pass to the function.
-	pub arguments: String,
-	/// The unique ID of the function tool call generated by the model.
-	pub call_id: String,
 	/// The unique ID of the function tool call.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub id: Option<String>,
+	/// The type of the function tool call. Always `function_call`.
+	pub r#type: FunctionToolCallType,
+	/// The unique ID of the function tool call generated by the model.
+	pub call_id: String,
 	/// The name of the function to run.
 	pub name: String,
+	/// A JSON string of the arguments to pass to the function.
+	pub arguments: String,
 	/// The status of the item. One of `in_progress`, `completed`, or
 	/// `incomplete`. Populated when items are returned via API.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub status: Option<FunctionToolCallStatus>,
-	/// The type of the function tool call. Always `function_call`.
-	pub r#type: FunctionToolCallType,
 }
 /// The output of a function tool call.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct FunctionToolCallOutput {
-	/// The unique ID of the function tool call generated by the model.
-	pub call_id: String,
 	/// The unique ID of the function tool call output. Populated when this item
 	/// is returned via API.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub id: Option<String>,
+	/// The type of the function tool call output. Always `function_call_output`.
+	pub r#type: FunctionToolCallOutputType,
+	/// The unique ID of the function tool call generated by the model.
+	pub call_id: String,
 	/// A JSON string of the output of the function tool call.
 	pub output: String,
 	/// The status of the item. One of `in_progress`, `completed`, or
 	/// `incomplete`. Populated when items are returned via API.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub status: Option<FunctionToolCallOutputStatus>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FunctionToolCallOutputResource {
 	/// The type of the function tool call output. Always `function_call_output`.
 	pub r#type: FunctionToolCallOutputType,
+	/// The unique ID of the function tool call generated by the model.
+	pub call_id: String,
+	/// A JSON string of the output of the function tool call.
+	pub output: String,
+	/// The status of the item. One of `in_progress`, `completed`, or
+	/// `incomplete`. Populated when items are returned via API.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub status: Option<FunctionToolCallOutputStatus>,
+	/// The unique ID of the function call tool output.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
 }
 /// The status of the item. One of `in_progress`, `completed`, or
 /// `incomplete`. Populated when items are returned via API.
@@ -640,6 +802,24 @@ pub enum FunctionToolCallOutputType {
 	#[serde(rename = "function_call_output")]
 	FunctionCallOutput,
 }
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FunctionToolCallResource {
+	/// The type of the function tool call. Always `function_call`.
+	pub r#type: FunctionToolCallType,
+	/// The unique ID of the function tool call generated by the model.
+	pub call_id: String,
+	/// The name of the function to run.
+	pub name: String,
+	/// A JSON string of the arguments to pass to the function.
+	pub arguments: String,
+	/// The status of the item. One of `in_progress`, `completed`, or
+	/// `incomplete`. Populated when items are returned via API.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub status: Option<FunctionToolCallStatus>,
+	/// The unique ID of the function tool call.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+}
 /// The status of the item. One of `in_progress`, `completed`, or
 /// `incomplete`. Populated when items are returned via API.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -690,17 +870,17 @@ pub enum InputContent {
 /// A file input to the model.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct InputFile {
-	/// The content of the file to be sent to the model.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub file_data: Option<String>,
+	/// The type of the input item. Always `input_file`.
+	pub r#type: InputFileType,
 	/// The ID of the file to be sent to the model.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub file_id: Option<String>,
 	/// The name of the file to be sent to the model.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub filename: Option<String>,
-	/// The type of the input item. Always `input_file`.
-	pub r#type: InputFileType,
+	/// The content of the file to be sent to the model.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub file_data: Option<String>,
 }
 /// The type of the input item. Always `input_file`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -709,21 +889,22 @@ pub enum InputFileType {
 	#[serde(rename = "input_file")]
 	InputFile,
 }
-/// An image input to the model. Learn about [image inputs](https://platform.openai.com/docs/guides/vision).
+/// An image input to the model. Learn about [image
+/// inputs](https://platform.openai.com/docs/guides/vision).
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct InputImage {
-	/// The detail level of the image to be sent to the model. One of `high`,
-	/// `low`, or `auto`. Defaults to `auto`.
-	pub detail: InputImageDetail,
-	/// The ID of the file to be sent to the model.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub file_id: Option<String>,
+	/// The type of the input item. Always `input_image`.
+	pub r#type: InputImageType,
 	/// The URL of the image to be sent to the model. A fully qualified URL or
 	/// base64 encoded image in a data URL.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub image_url: Option<String>,
-	/// The type of the input item. Always `input_image`.
-	pub r#type: InputImageType,
+	/// The ID of the file to be sent to the model.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub file_id: Option<String>,
+	/// The detail level of the image to be sent to the model. One of `high`,
+	/// `low`, or `auto`. Defaults to `auto`.
+	pub detail: InputImageDetail,
 }
 /// The detail level of the image to be sent to the model. One of `high`,
 /// `low`, or `auto`. Defaults to `auto`.
@@ -754,16 +935,32 @@ pub enum InputItem {
 /// precedence over instructions given with the `user` role.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct InputMessage {
-	pub content: InputMessageContentList,
+	/// The type of the message input. Always set to `message`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub r#type: Option<InputMessageType>,
 	/// The role of the message input. One of `user`, `system`, or `developer`.
 	pub role: InputMessageRole,
 	/// The status of item. One of `in_progress`, `completed`, or
 	/// `incomplete`. Populated when items are returned via API.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub status: Option<InputMessageStatus>,
+	pub content: InputMessageContentList,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct InputMessageResource {
 	/// The type of the message input. Always set to `message`.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub r#type: Option<InputMessageType>,
+	/// The role of the message input. One of `user`, `system`, or `developer`.
+	pub role: InputMessageRole,
+	/// The status of item. One of `in_progress`, `completed`, or
+	/// `incomplete`. Populated when items are returned via API.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub status: Option<InputMessageStatus>,
+	pub content: InputMessageContentList,
+	/// The unique ID of the message input.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
 }
 /// The role of the message input. One of `user`, `system`, or `developer`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -792,10 +989,10 @@ pub enum InputMessageType {
 /// A text input to the model.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct InputText {
-	/// The text input to the model.
-	pub text: String,
 	/// The type of the input item. Always `input_text`.
 	pub r#type: InputTextType,
+	/// The text input to the model.
+	pub text: String,
 }
 /// The type of the input item. Always `input_text`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -834,17 +1031,31 @@ pub enum ItemReferenceType {
 	#[serde(rename = "item_reference")]
 	ItemReference,
 }
+/// Content item used to generate a response.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(untagged)]
+pub enum ItemResource {
+	InputMessageResource(InputMessageResource),
+	OutputMessage(OutputMessage),
+	FileSearchToolCall(FileSearchToolCall),
+	ComputerToolCall(ComputerToolCall),
+	ComputerToolCallOutputResource(ComputerToolCallOutputResource),
+	WebSearchToolCall(WebSearchToolCall),
+	FunctionToolCallResource(FunctionToolCallResource),
+	FunctionToolCallOutputResource(FunctionToolCallOutputResource),
+}
 /// A collection of keypresses the model would like to perform.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct KeyPress {
+	/// Specifies the event type. For a keypress action, this property is
+	/// always set to `keypress`.
+	pub r#type: KeyPressType,
 	/// The combination of keys the model is requesting to be pressed. This is an
 	/// array of strings, each representing a key.
 	pub keys: Vec<String>,
-	/// Specifies the event type. For a keypress action, this property is 
-	/// always set to `keypress`.
-	pub r#type: KeyPressType,
 }
-/// Specifies the event type. For a keypress action, this property is 
+/// Specifies the event type. For a keypress action, this property is
 /// always set to `keypress`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -854,7 +1065,7 @@ pub enum KeyPressType {
 /// A mouse move action.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Move {
-	/// Specifies the event type. For a move action, this property is 
+	/// Specifies the event type. For a move action, this property is
 	/// always set to `move`.
 	pub r#type: MoveType,
 	/// The x-coordinate to move to.
@@ -862,7 +1073,7 @@ pub struct Move {
 	/// The y-coordinate to move to.
 	pub y: i64,
 }
-/// Specifies the event type. For a move action, this property is 
+/// Specifies the event type. For a move action, this property is
 /// always set to `move`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -876,20 +1087,31 @@ pub enum OutputContent {
 	OutputText(OutputText),
 	Refusal(Refusal),
 }
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(untagged)]
+pub enum OutputItem {
+	OutputMessage(OutputMessage),
+	FileSearchToolCall(FileSearchToolCall),
+	FunctionToolCall(FunctionToolCall),
+	WebSearchToolCall(WebSearchToolCall),
+	ComputerToolCall(ComputerToolCall),
+	ReasoningItem(ReasoningItem),
+}
 /// An output message from the model.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct OutputMessage {
-	/// The content of the output message.
-	pub content: Vec<OutputContent>,
 	/// The unique ID of the output message.
 	pub id: String,
+	/// The type of the output message. Always `message`.
+	pub r#type: OutputMessageType,
 	/// The role of the output message. Always `assistant`.
 	pub role: OutputMessageRole,
+	/// The content of the output message.
+	pub content: Vec<OutputContent>,
 	/// The status of the message input. One of `in_progress`, `completed`, or
 	/// `incomplete`. Populated when input items are returned via API.
 	pub status: OutputMessageStatus,
-	/// The type of the output message. Always `message`.
-	pub r#type: OutputMessageType,
 }
 /// The role of the output message. Always `assistant`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -916,12 +1138,12 @@ pub enum OutputMessageType {
 /// A text output from the model.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct OutputText {
-	/// The annotations of the text output.
-	pub annotations: Vec<Annotation>,
-	/// The text output from the model.
-	pub text: String,
 	/// The type of the output text. Always `output_text`.
 	pub r#type: OutputTextType,
+	/// The text output from the model.
+	pub text: String,
+	/// The annotations of the text output.
+	pub annotations: Vec<Annotation>,
 }
 /// The type of the output text. Always `output_text`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -931,15 +1153,15 @@ pub enum OutputTextType {
 	OutputText,
 }
 /// **o-series models only**
-/// 
-/// Configuration options for 
+///
+/// Configuration options for
 /// [reasoning models](https://platform.openai.com/docs/guides/reasoning).
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct Reasoning {
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub effort: Option<ReasoningEffort>,
 	/// **computer_use_preview only**
-	/// 
+	///
 	/// A summary of the reasoning performed by the model. This can be
 	/// useful for debugging and understanding the model's reasoning process.
 	/// One of `concise` or `detailed`.
@@ -947,7 +1169,7 @@ pub struct Reasoning {
 	pub generate_summary: Option<ReasoningGenerateSummary>,
 }
 /// **computer_use_preview only**
-/// 
+///
 /// A summary of the reasoning performed by the model. This can be
 /// useful for debugging and understanding the model's reasoning process.
 /// One of `concise` or `detailed`.
@@ -961,16 +1183,16 @@ pub enum ReasoningGenerateSummary {
 /// a response.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ReasoningItem {
+	/// The type of the object. Always `reasoning`.
+	pub r#type: ReasoningItemType,
 	/// The unique identifier of the reasoning content.
 	pub id: String,
+	/// Reasoning text contents.
+	pub summary: Vec<ReasoningItemSummaryItem>,
 	/// The status of the item. One of `in_progress`, `completed`, or
 	/// `incomplete`. Populated when items are returned via API.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub status: Option<ReasoningItemStatus>,
-	/// Reasoning text contents.
-	pub summary: Vec<ReasoningItemSummaryItem>,
-	/// The type of the object. Always `reasoning`.
-	pub r#type: ReasoningItemType,
 }
 /// The status of the item. One of `in_progress`, `completed`, or
 /// `incomplete`. Populated when items are returned via API.
@@ -984,11 +1206,11 @@ pub enum ReasoningItemStatus {
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ReasoningItemSummaryItem {
+	/// The type of the object. Always `summary_text`.
+	pub r#type: ReasoningItemSummaryItemType,
 	/// A short summary of the reasoning used by the model when generating
 	/// the response.
 	pub text: String,
-	/// The type of the object. Always `summary_text`.
-	pub r#type: ReasoningItemSummaryItemType,
 }
 /// The type of the object. Always `summary_text`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -1006,10 +1228,10 @@ pub enum ReasoningItemType {
 /// A refusal from the model.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Refusal {
-	/// The refusal explanationfrom the model.
-	pub refusal: String,
 	/// The type of the refusal. Always `refusal`.
 	pub r#type: RefusalType,
+	/// The refusal explanationfrom the model.
+	pub refusal: String,
 }
 /// The type of the refusal. Always `refusal`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -1018,46 +1240,646 @@ pub enum RefusalType {
 	Refusal,
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-pub struct ResponseProperties {
-	/// Inserts a system (or developer) message as the first item in the model's context.
-	/// 
-	/// When using along with `previous_response_id`, the instructions from a previous
-	/// response will be not be carried over to the next response. This makes it simple
-	/// to swap out system (or developer) messages in new responses.
+pub struct Response {
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub instructions: Option<String>,
-	/// An upper bound for the number of tokens that can be generated for a response, including visible output tokens and [reasoning tokens](https://platform.openai.com/docs/guides/reasoning).
+	pub metadata: Option<Metadata>,
+	/// What sampling temperature to use, between 0 and 2. Higher values like 0.8 will make the output more
+	/// random, while lower values like 0.2 will make it more focused and deterministic.
+	/// We generally recommend altering this or `top_p` but not both.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub max_output_tokens: Option<i64>,
-	/// Model ID used to generate the response, like `gpt-4o` or `o1`. OpenAI
-	/// offers a wide range of models with different capabilities, performance
-	/// characteristics, and price points. Refer to the [model guide](https://platform.openai.com/docs/models)
-	/// to browse and compare available models.
+	pub temperature: Option<f64>,
+	/// An alternative to sampling with temperature, called nucleus sampling,
+	/// where the model considers the results of the tokens with top_p probability
+	/// mass. So 0.1 means only the tokens comprising the top 10% probability mass
+	/// are considered.
+	///
+	/// We generally recommend altering this or `temperature` but not both.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub model: Option<ModelIdsResponses>,
+	pub top_p: Option<f64>,
+	/// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse.
+	/// [Learn more](https://platform.openai.com/docs/guides/safety-best-practices#end-user-ids).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub user: Option<String>,
 	/// The unique ID of the previous response to the model. Use this to
-	/// create multi-turn conversations. Learn more about 
+	/// create multi-turn conversations. Learn more about
 	/// [conversation state](https://platform.openai.com/docs/guides/conversation-state).
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub previous_response_id: Option<String>,
+	/// Model ID used to generate the response, like `gpt-4o` or `o1`. OpenAI
+	/// offers a wide range of models with different capabilities, performance
+	/// characteristics, and price points. Refer to the [model
+	/// guide](https://platform.openai.com/docs/models)
+	/// to browse and compare available models.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub model: Option<ModelIdsResponses>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub reasoning: Option<Reasoning>,
+	/// An upper bound for the number of tokens that can be generated for a response, including visible
+	/// output tokens and [reasoning tokens](https://platform.openai.com/docs/guides/reasoning).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_output_tokens: Option<i64>,
+	/// Inserts a system (or developer) message as the first item in the model's context.
+	///
+	/// When using along with `previous_response_id`, the instructions from a previous
+	/// response will be not be carried over to the next response. This makes it simple
+	/// to swap out system (or developer) messages in new responses.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub instructions: Option<String>,
 	/// Configuration options for a text response from the model. Can be plain
 	/// text or structured JSON data. Learn more:
 	/// - [Text inputs and outputs](https://platform.openai.com/docs/guides/text)
 	/// - [Structured Outputs](https://platform.openai.com/docs/guides/structured-outputs)
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub text: Option<ResponsePropertiesText>,
+	/// An array of tools the model may call while generating a response. You
+	/// can specify which tool to use by setting the `tool_choice` parameter.
+	///
+	/// The two categories of tools you can provide the model are:
+	///
+	/// - **Built-in tools**: Tools that are provided by OpenAI that extend the
+	///   model's capabilities, like [web search](https://platform.openai.com/docs/guides/tools-web-search)
+	///   or [file search](https://platform.openai.com/docs/guides/tools-file-search). Learn more about
+	///   [built-in tools](https://platform.openai.com/docs/guides/tools).
+	/// - **Function calls (custom tools)**: Functions that are defined by you,
+	///   enabling the model to call your own code. Learn more about
+	///   [function calling](https://platform.openai.com/docs/guides/function-calling).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub tools: Option<Vec<Tool>>,
 	/// How the model should select which tool (or tools) to use when generating
 	/// a response. See the `tools` parameter to see how to specify which tools
 	/// the model can call.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub tool_choice: Option<ResponsePropertiesToolChoice>,
-	/// An array of tools the model may call while generating a response. You 
+	/// The truncation strategy to use for the model response.
+	/// - `auto`: If the context of this response and previous ones exceeds
+	///   the model's context window size, the model will truncate the
+	///   response to fit the context window by dropping input items in the
+	///   middle of the conversation.
+	/// - `disabled` (default): If a model response will exceed the context window
+	///   size for a model, the request will fail with a 400 error.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub truncation: Option<ResponsePropertiesTruncation>,
+	/// Unique identifier for this Response.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub id: Option<String>,
+	/// The object type of this resource - always set to `response`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub object: Option<ResponseObject>,
+	/// The status of the response generation. One of `completed`, `failed`,
+	/// `in_progress`, or `incomplete`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub status: Option<ResponseStatus>,
+	/// Unix timestamp (in seconds) of when this Response was created.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub created_at: Option<f64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub error: Option<ResponseError>,
+	/// Details about why the response is incomplete.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub incomplete_details: Option<ResponseIncompleteDetails>,
+	/// An array of content items generated by the model.
+	///
+	/// - The length and order of items in the `output` array is dependent
+	///   on the model's response.
+	/// - Rather than accessing the first item in the `output` array and
+	///   assuming it's an `assistant` message with the content generated by
+	///   the model, you might consider using the `output_text` property where
+	///   supported in SDKs.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub output: Option<Vec<OutputItem>>,
+	/// SDK-only convenience property that contains the aggregated text output
+	/// from all `output_text` items in the `output` array, if any are present.
+	/// Supported in the Python and JavaScript SDKs.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub output_text: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub usage: Option<ResponseUsage>,
+	/// Whether to allow the model to run tool calls in parallel.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub parallel_tool_calls: Option<bool>,
+}
+/// Emitted when there is a partial audio response.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseAudioDeltaEvent {
+	/// The type of the event. Always `response.audio.delta`.
+	pub r#type: ResponseAudioDeltaEventType,
+	/// A chunk of Base64 encoded response audio bytes.
+	pub delta: String,
+}
+/// The type of the event. Always `response.audio.delta`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseAudioDeltaEventType {
+	#[serde(rename = "response.audio.delta")]
+	ResponseAudioDelta,
+}
+/// Emitted when the audio response is complete.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseAudioDoneEvent {
+	/// The type of the event. Always `response.audio.done`.
+	pub r#type: ResponseAudioDoneEventType,
+}
+/// The type of the event. Always `response.audio.done`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseAudioDoneEventType {
+	#[serde(rename = "response.audio.done")]
+	ResponseAudioDone,
+}
+/// Emitted when there is a partial transcript of audio.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseAudioTranscriptDeltaEvent {
+	/// The type of the event. Always `response.audio.transcript.delta`.
+	pub r#type: ResponseAudioTranscriptDeltaEventType,
+	/// The partial transcript of the audio response.
+	pub delta: String,
+}
+/// The type of the event. Always `response.audio.transcript.delta`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseAudioTranscriptDeltaEventType {
+	#[serde(rename = "response.audio.transcript.delta")]
+	ResponseAudioTranscriptDelta,
+}
+/// Emitted when the full audio transcript is completed.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseAudioTranscriptDoneEvent {
+	/// The type of the event. Always `response.audio.transcript.done`.
+	pub r#type: ResponseAudioTranscriptDoneEventType,
+}
+/// The type of the event. Always `response.audio.transcript.done`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseAudioTranscriptDoneEventType {
+	#[serde(rename = "response.audio.transcript.done")]
+	ResponseAudioTranscriptDone,
+}
+/// Emitted when a partial code snippet is added by the code interpreter.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseCodeInterpreterCallCodeDeltaEvent {
+	/// The type of the event. Always `response.code_interpreter_call.code.delta`.
+	pub r#type: ResponseCodeInterpreterCallCodeDeltaEventType,
+	/// The index of the output item that the code interpreter call is in progress.
+	pub output_index: i64,
+	/// The partial code snippet added by the code interpreter.
+	pub delta: String,
+}
+/// The type of the event. Always `response.code_interpreter_call.code.delta`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseCodeInterpreterCallCodeDeltaEventType {
+	#[serde(rename = "response.code_interpreter_call.code.delta")]
+	ResponseCodeInterpreterCallCodeDelta,
+}
+/// Emitted when code snippet output is finalized by the code interpreter.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseCodeInterpreterCallCodeDoneEvent {
+	/// The type of the event. Always `response.code_interpreter_call.code.done`.
+	pub r#type: ResponseCodeInterpreterCallCodeDoneEventType,
+	/// The index of the output item that the code interpreter call is in progress.
+	pub output_index: i64,
+	/// The final code snippet output by the code interpreter.
+	pub code: String,
+}
+/// The type of the event. Always `response.code_interpreter_call.code.done`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseCodeInterpreterCallCodeDoneEventType {
+	#[serde(rename = "response.code_interpreter_call.code.done")]
+	ResponseCodeInterpreterCallCodeDone,
+}
+/// Emitted when the code interpreter call is completed.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseCodeInterpreterCallCompletedEvent {
+	/// The type of the event. Always `response.code_interpreter_call.completed`.
+	pub r#type: ResponseCodeInterpreterCallCompletedEventType,
+	/// The index of the output item that the code interpreter call is in progress.
+	pub output_index: i64,
+	pub code_interpreter_call: CodeInterpreterToolCall,
+}
+/// The type of the event. Always `response.code_interpreter_call.completed`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseCodeInterpreterCallCompletedEventType {
+	#[serde(rename = "response.code_interpreter_call.completed")]
+	ResponseCodeInterpreterCallCompleted,
+}
+/// Emitted when a code interpreter call is in progress.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseCodeInterpreterCallInProgressEvent {
+	/// The type of the event. Always `response.code_interpreter_call.in_progress`.
+	pub r#type: ResponseCodeInterpreterCallInProgressEventType,
+	/// The index of the output item that the code interpreter call is in progress.
+	pub output_index: i64,
+	pub code_interpreter_call: CodeInterpreterToolCall,
+}
+/// The type of the event. Always `response.code_interpreter_call.in_progress`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseCodeInterpreterCallInProgressEventType {
+	#[serde(rename = "response.code_interpreter_call.in_progress")]
+	ResponseCodeInterpreterCallInProgress,
+}
+/// Emitted when the code interpreter is actively interpreting the code snippet.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseCodeInterpreterCallInterpretingEvent {
+	/// The type of the event. Always `response.code_interpreter_call.interpreting`.
+	pub r#type: ResponseCodeInterpreterCallInterpretingEventType,
+	/// The index of the output item that the code interpreter call is in progress.
+	pub output_index: i64,
+	pub code_interpreter_call: CodeInterpreterToolCall,
+}
+/// The type of the event. Always `response.code_interpreter_call.interpreting`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseCodeInterpreterCallInterpretingEventType {
+	#[serde(rename = "response.code_interpreter_call.interpreting")]
+	ResponseCodeInterpreterCallInterpreting,
+}
+/// Emitted when the model response is complete.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseCompletedEvent {
+	/// The type of the event. Always `response.completed`.
+	pub r#type: ResponseCompletedEventType,
+	/// Properties of the completed response.
+	pub response: Response,
+}
+/// The type of the event. Always `response.completed`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseCompletedEventType {
+	#[serde(rename = "response.completed")]
+	ResponseCompleted,
+}
+/// Emitted when a new content part is added.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseContentPartAddedEvent {
+	/// The type of the event. Always `response.content_part.added`.
+	pub r#type: ResponseContentPartAddedEventType,
+	/// The ID of the output item that the content part was added to.
+	pub item_id: String,
+	/// The index of the output item that the content part was added to.
+	pub output_index: i64,
+	/// The index of the content part that was added.
+	pub content_index: i64,
+	/// The content part that was added.
+	pub part: OutputContent,
+}
+/// The type of the event. Always `response.content_part.added`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseContentPartAddedEventType {
+	#[serde(rename = "response.content_part.added")]
+	ResponseContentPartAdded,
+}
+/// Emitted when a content part is done.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseContentPartDoneEvent {
+	/// The type of the event. Always `response.content_part.done`.
+	pub r#type: ResponseContentPartDoneEventType,
+	/// The ID of the output item that the content part was added to.
+	pub item_id: String,
+	/// The index of the output item that the content part was added to.
+	pub output_index: i64,
+	/// The index of the content part that is done.
+	pub content_index: i64,
+	/// The content part that is done.
+	pub part: OutputContent,
+}
+/// The type of the event. Always `response.content_part.done`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseContentPartDoneEventType {
+	#[serde(rename = "response.content_part.done")]
+	ResponseContentPartDone,
+}
+/// An event that is emitted when a response is created.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseCreatedEvent {
+	/// The type of the event. Always `response.created`.
+	pub r#type: ResponseCreatedEventType,
+	/// The response that was created.
+	pub response: Response,
+}
+/// The type of the event. Always `response.created`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseCreatedEventType {
+	#[serde(rename = "response.created")]
+	ResponseCreated,
+}
+/// An error object returned when the model fails to generate a Response.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseError {
+	pub code: ResponseErrorCode,
+	/// A human-readable description of the error.
+	pub message: String,
+}
+/// The error code for the response.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseErrorCode {
+	#[serde(rename = "server_error")]
+	ServerError,
+	#[serde(rename = "rate_limit_exceeded")]
+	RateLimitExceeded,
+	#[serde(rename = "invalid_prompt")]
+	InvalidPrompt,
+	#[serde(rename = "vector_store_timeout")]
+	VectorStoreTimeout,
+	#[serde(rename = "invalid_image")]
+	InvalidImage,
+	#[serde(rename = "invalid_image_format")]
+	InvalidImageFormat,
+	#[serde(rename = "invalid_base64_image")]
+	InvalidBase64Image,
+	#[serde(rename = "invalid_image_url")]
+	InvalidImageUrl,
+	#[serde(rename = "image_too_large")]
+	ImageTooLarge,
+	#[serde(rename = "image_too_small")]
+	ImageTooSmall,
+	#[serde(rename = "image_parse_error")]
+	ImageParseError,
+	#[serde(rename = "image_content_policy_violation")]
+	ImageContentPolicyViolation,
+	#[serde(rename = "invalid_image_mode")]
+	InvalidImageMode,
+	#[serde(rename = "image_file_too_large")]
+	ImageFileTooLarge,
+	#[serde(rename = "unsupported_image_media_type")]
+	UnsupportedImageMediaType,
+	#[serde(rename = "empty_image_file")]
+	EmptyImageFile,
+	#[serde(rename = "failed_to_download_image")]
+	FailedToDownloadImage,
+	#[serde(rename = "image_file_not_found")]
+	ImageFileNotFound,
+}
+/// Emitted when an error occurs.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseErrorEvent {
+	/// The type of the event. Always `error`.
+	pub r#type: ResponseErrorEventType,
+	/// The error code.
+	pub code: Option<String>,
+	/// The error message.
+	pub message: String,
+	/// The error parameter.
+	pub param: Option<String>,
+}
+/// The type of the event. Always `error`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseErrorEventType {
+	Error,
+}
+/// An event that is emitted when a response fails.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseFailedEvent {
+	/// The type of the event. Always `response.failed`.
+	pub r#type: ResponseFailedEventType,
+	/// The response that failed.
+	pub response: Response,
+}
+/// The type of the event. Always `response.failed`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseFailedEventType {
+	#[serde(rename = "response.failed")]
+	ResponseFailed,
+}
+/// Emitted when a file search call is completed (results found).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseFileSearchCallCompletedEvent {
+	/// The type of the event. Always `response.file_search_call.completed`.
+	pub r#type: ResponseFileSearchCallCompletedEventType,
+	/// The index of the output item that the file search call is initiated.
+	pub output_index: i64,
+	/// The ID of the output item that the file search call is initiated.
+	pub item_id: String,
+}
+/// The type of the event. Always `response.file_search_call.completed`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseFileSearchCallCompletedEventType {
+	#[serde(rename = "response.file_search_call.completed")]
+	ResponseFileSearchCallCompleted,
+}
+/// Emitted when a file search call is initiated.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseFileSearchCallInProgressEvent {
+	/// The type of the event. Always `response.file_search_call.in_progress`.
+	pub r#type: ResponseFileSearchCallInProgressEventType,
+	/// The index of the output item that the file search call is initiated.
+	pub output_index: i64,
+	/// The ID of the output item that the file search call is initiated.
+	pub item_id: String,
+}
+/// The type of the event. Always `response.file_search_call.in_progress`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseFileSearchCallInProgressEventType {
+	#[serde(rename = "response.file_search_call.in_progress")]
+	ResponseFileSearchCallInProgress,
+}
+/// Emitted when a file search is currently searching.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseFileSearchCallSearchingEvent {
+	/// The type of the event. Always `response.file_search_call.searching`.
+	pub r#type: ResponseFileSearchCallSearchingEventType,
+	/// The index of the output item that the file search call is searching.
+	pub output_index: i64,
+	/// The ID of the output item that the file search call is initiated.
+	pub item_id: String,
+}
+/// The type of the event. Always `response.file_search_call.searching`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseFileSearchCallSearchingEventType {
+	#[serde(rename = "response.file_search_call.searching")]
+	ResponseFileSearchCallSearching,
+}
+/// Emitted when there is a partial function-call arguments delta.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseFunctionCallArgumentsDeltaEvent {
+	/// The type of the event. Always `response.function_call_arguments.delta`.
+	pub r#type: ResponseFunctionCallArgumentsDeltaEventType,
+	/// The ID of the output item that the function-call arguments delta is added to.
+	pub item_id: String,
+	/// The index of the output item that the function-call arguments delta is added to.
+	pub output_index: i64,
+	/// The function-call arguments delta that is added.
+	pub delta: String,
+}
+/// The type of the event. Always `response.function_call_arguments.delta`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseFunctionCallArgumentsDeltaEventType {
+	#[serde(rename = "response.function_call_arguments.delta")]
+	ResponseFunctionCallArgumentsDelta,
+}
+/// Emitted when function-call arguments are finalized.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseFunctionCallArgumentsDoneEvent {
+	pub r#type: ResponseFunctionCallArgumentsDoneEventType,
+	/// The ID of the item.
+	pub item_id: String,
+	/// The index of the output item.
+	pub output_index: i64,
+	/// The function-call arguments.
+	pub arguments: String,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseFunctionCallArgumentsDoneEventType {
+	#[serde(rename = "response.function_call_arguments.done")]
+	ResponseFunctionCallArgumentsDone,
+}
+/// Emitted when the response is in progress.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseInProgressEvent {
+	/// The type of the event. Always `response.in_progress`.
+	pub r#type: ResponseInProgressEventType,
+	/// The response that is in progress.
+	pub response: Response,
+}
+/// The type of the event. Always `response.in_progress`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseInProgressEventType {
+	#[serde(rename = "response.in_progress")]
+	ResponseInProgress,
+}
+/// Details about why the response is incomplete.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct ResponseIncompleteDetails {
+	/// The reason why the response is incomplete.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub reason: Option<ResponseIncompleteDetailsReason>,
+}
+/// The reason why the response is incomplete.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseIncompleteDetailsReason {
+	#[serde(rename = "max_output_tokens")]
+	MaxOutputTokens,
+	#[serde(rename = "content_filter")]
+	ContentFilter,
+}
+/// An event that is emitted when a response finishes as incomplete.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseIncompleteEvent {
+	/// The type of the event. Always `response.incomplete`.
+	pub r#type: ResponseIncompleteEventType,
+	/// The response that was incomplete.
+	pub response: Response,
+}
+/// The type of the event. Always `response.incomplete`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseIncompleteEventType {
+	#[serde(rename = "response.incomplete")]
+	ResponseIncomplete,
+}
+/// A list of Response items.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseItemList {
+	/// The type of object returned, must be `list`.
+	pub object: ResponseItemListObject,
+	/// A list of items used to generate this response.
+	pub data: Vec<ItemResource>,
+	/// Whether there are more items available.
+	pub has_more: bool,
+	/// The ID of the first item in the list.
+	pub first_id: String,
+	/// The ID of the last item in the list.
+	pub last_id: String,
+}
+/// The type of object returned, must be `list`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseItemListObject {
+	List,
+}
+/// The object type of this resource - always set to `response`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseObject {
+	Response,
+}
+/// Emitted when a new output item is added.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseOutputItemAddedEvent {
+	/// The type of the event. Always `response.output_item.added`.
+	pub r#type: ResponseOutputItemAddedEventType,
+	/// The index of the output item that was added.
+	pub output_index: i64,
+	/// The output item that was added.
+	pub item: OutputItem,
+}
+/// The type of the event. Always `response.output_item.added`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseOutputItemAddedEventType {
+	#[serde(rename = "response.output_item.added")]
+	ResponseOutputItemAdded,
+}
+/// Emitted when an output item is marked done.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseOutputItemDoneEvent {
+	/// The type of the event. Always `response.output_item.done`.
+	pub r#type: ResponseOutputItemDoneEventType,
+	/// The index of the output item that was marked done.
+	pub output_index: i64,
+	/// The output item that was marked done.
+	pub item: OutputItem,
+}
+/// The type of the event. Always `response.output_item.done`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseOutputItemDoneEventType {
+	#[serde(rename = "response.output_item.done")]
+	ResponseOutputItemDone,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct ResponseProperties {
+	/// The unique ID of the previous response to the model. Use this to
+	/// create multi-turn conversations. Learn more about
+	/// [conversation state](https://platform.openai.com/docs/guides/conversation-state).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub previous_response_id: Option<String>,
+	/// Model ID used to generate the response, like `gpt-4o` or `o1`. OpenAI
+	/// offers a wide range of models with different capabilities, performance
+	/// characteristics, and price points. Refer to the [model
+	/// guide](https://platform.openai.com/docs/models)
+	/// to browse and compare available models.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub model: Option<ModelIdsResponses>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub reasoning: Option<Reasoning>,
+	/// An upper bound for the number of tokens that can be generated for a response, including visible
+	/// output tokens and [reasoning tokens](https://platform.openai.com/docs/guides/reasoning).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_output_tokens: Option<i64>,
+	/// Inserts a system (or developer) message as the first item in the model's context.
+	///
+	/// When using along with `previous_response_id`, the instructions from a previous
+	/// response will be not be carried over to the next response. This makes it simple
+	/// to swap out system (or developer) messages in new responses.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub instructions: Option<String>,
+	/// Configuration options for a text response from the model. Can be plain
+	/// text or structured JSON data. Learn more:
+	/// - [Text inputs and outputs](https://platform.openai.com/docs/guides/text)
+	/// - [Structured Outputs](https://platform.openai.com/docs/guides/structured-outputs)
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub text: Option<ResponsePropertiesText>,
+	/// An array of tools the model may call while generating a response. You
 	/// can specify which tool to use by setting the `tool_choice` parameter.
-	/// 
+	///
 	/// The two categories of tools you can provide the model are:
-	/// 
+	///
 	/// - **Built-in tools**: Tools that are provided by OpenAI that extend the
 	///   model's capabilities, like [web search](https://platform.openai.com/docs/guides/tools-web-search)
 	///   or [file search](https://platform.openai.com/docs/guides/tools-file-search). Learn more about
@@ -1067,12 +1889,17 @@ pub struct ResponseProperties {
 	///   [function calling](https://platform.openai.com/docs/guides/function-calling).
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub tools: Option<Vec<Tool>>,
+	/// How the model should select which tool (or tools) to use when generating
+	/// a response. See the `tools` parameter to see how to specify which tools
+	/// the model can call.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub tool_choice: Option<ResponsePropertiesToolChoice>,
 	/// The truncation strategy to use for the model response.
 	/// - `auto`: If the context of this response and previous ones exceeds
-	///   the model's context window size, the model will truncate the 
+	///   the model's context window size, the model will truncate the
 	///   response to fit the context window by dropping input items in the
-	///   middle of the conversation. 
-	/// - `disabled` (default): If a model response will exceed the context window 
+	///   middle of the conversation.
+	/// - `disabled` (default): If a model response will exceed the context window
 	///   size for a model, the request will fail with a 400 error.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub truncation: Option<ResponsePropertiesTruncation>,
@@ -1099,10 +1926,10 @@ pub enum ResponsePropertiesToolChoice {
 }
 /// The truncation strategy to use for the model response.
 /// - `auto`: If the context of this response and previous ones exceeds
-///   the model's context window size, the model will truncate the 
+///   the model's context window size, the model will truncate the
 ///   response to fit the context window by dropping input items in the
-///   middle of the conversation. 
-/// - `disabled` (default): If a model response will exceed the context window 
+///   middle of the conversation.
+/// - `disabled` (default): If a model response will exceed the context window
 ///   size for a model, the request will fail with a 400 error.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -1110,14 +1937,247 @@ pub enum ResponsePropertiesTruncation {
 	Auto,
 	Disabled,
 }
+/// Emitted when there is a partial refusal text.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseRefusalDeltaEvent {
+	/// The type of the event. Always `response.refusal.delta`.
+	pub r#type: ResponseRefusalDeltaEventType,
+	/// The ID of the output item that the refusal text is added to.
+	pub item_id: String,
+	/// The index of the output item that the refusal text is added to.
+	pub output_index: i64,
+	/// The index of the content part that the refusal text is added to.
+	pub content_index: i64,
+	/// The refusal text that is added.
+	pub delta: String,
+}
+/// The type of the event. Always `response.refusal.delta`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseRefusalDeltaEventType {
+	#[serde(rename = "response.refusal.delta")]
+	ResponseRefusalDelta,
+}
+/// Emitted when refusal text is finalized.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseRefusalDoneEvent {
+	/// The type of the event. Always `response.refusal.done`.
+	pub r#type: ResponseRefusalDoneEventType,
+	/// The ID of the output item that the refusal text is finalized.
+	pub item_id: String,
+	/// The index of the output item that the refusal text is finalized.
+	pub output_index: i64,
+	/// The index of the content part that the refusal text is finalized.
+	pub content_index: i64,
+	/// The refusal text that is finalized.
+	pub refusal: String,
+}
+/// The type of the event. Always `response.refusal.done`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseRefusalDoneEventType {
+	#[serde(rename = "response.refusal.done")]
+	ResponseRefusalDone,
+}
+/// The status of the response generation. One of `completed`, `failed`,
+/// `in_progress`, or `incomplete`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseStatus {
+	Completed,
+	Failed,
+	#[serde(rename = "in_progress")]
+	InProgress,
+	Incomplete,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(untagged)]
+pub enum ResponseStreamEvent {
+	ResponseAudioDeltaEvent(ResponseAudioDeltaEvent),
+	ResponseAudioDoneEvent(ResponseAudioDoneEvent),
+	ResponseAudioTranscriptDeltaEvent(ResponseAudioTranscriptDeltaEvent),
+	ResponseAudioTranscriptDoneEvent(ResponseAudioTranscriptDoneEvent),
+	ResponseCodeInterpreterCallCodeDeltaEvent(ResponseCodeInterpreterCallCodeDeltaEvent),
+	ResponseCodeInterpreterCallCodeDoneEvent(ResponseCodeInterpreterCallCodeDoneEvent),
+	ResponseCodeInterpreterCallCompletedEvent(ResponseCodeInterpreterCallCompletedEvent),
+	ResponseCodeInterpreterCallInProgressEvent(ResponseCodeInterpreterCallInProgressEvent),
+	ResponseCodeInterpreterCallInterpretingEvent(ResponseCodeInterpreterCallInterpretingEvent),
+	ResponseCompletedEvent(ResponseCompletedEvent),
+	ResponseContentPartAddedEvent(ResponseContentPartAddedEvent),
+	ResponseContentPartDoneEvent(ResponseContentPartDoneEvent),
+	ResponseCreatedEvent(ResponseCreatedEvent),
+	ResponseErrorEvent(ResponseErrorEvent),
+	ResponseFileSearchCallCompletedEvent(ResponseFileSearchCallCompletedEvent),
+	ResponseFileSearchCallInProgressEvent(ResponseFileSearchCallInProgressEvent),
+	ResponseFileSearchCallSearchingEvent(ResponseFileSearchCallSearchingEvent),
+	ResponseFunctionCallArgumentsDeltaEvent(ResponseFunctionCallArgumentsDeltaEvent),
+	ResponseFunctionCallArgumentsDoneEvent(ResponseFunctionCallArgumentsDoneEvent),
+	ResponseInProgressEvent(ResponseInProgressEvent),
+	ResponseFailedEvent(ResponseFailedEvent),
+	ResponseIncompleteEvent(ResponseIncompleteEvent),
+	ResponseOutputItemAddedEvent(ResponseOutputItemAddedEvent),
+	ResponseOutputItemDoneEvent(ResponseOutputItemDoneEvent),
+	ResponseRefusalDeltaEvent(ResponseRefusalDeltaEvent),
+	ResponseRefusalDoneEvent(ResponseRefusalDoneEvent),
+	ResponseTextAnnotationDeltaEvent(ResponseTextAnnotationDeltaEvent),
+	ResponseTextDeltaEvent(ResponseTextDeltaEvent),
+	ResponseTextDoneEvent(ResponseTextDoneEvent),
+	ResponseWebSearchCallCompletedEvent(ResponseWebSearchCallCompletedEvent),
+	ResponseWebSearchCallInProgressEvent(ResponseWebSearchCallInProgressEvent),
+	ResponseWebSearchCallSearchingEvent(ResponseWebSearchCallSearchingEvent),
+}
+/// Emitted when a text annotation is added.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseTextAnnotationDeltaEvent {
+	/// The type of the event. Always `response.output_text.annotation.added`.
+	pub r#type: ResponseTextAnnotationDeltaEventType,
+	/// The ID of the output item that the text annotation was added to.
+	pub item_id: String,
+	/// The index of the output item that the text annotation was added to.
+	pub output_index: i64,
+	/// The index of the content part that the text annotation was added to.
+	pub content_index: i64,
+	/// The index of the annotation that was added.
+	pub annotation_index: i64,
+	pub annotation: Annotation,
+}
+/// The type of the event. Always `response.output_text.annotation.added`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseTextAnnotationDeltaEventType {
+	#[serde(rename = "response.output_text.annotation.added")]
+	ResponseOutputTextAnnotationAdded,
+}
+/// Emitted when there is an additional text delta.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseTextDeltaEvent {
+	/// The type of the event. Always `response.output_text.delta`.
+	pub r#type: ResponseTextDeltaEventType,
+	/// The ID of the output item that the text delta was added to.
+	pub item_id: String,
+	/// The index of the output item that the text delta was added to.
+	pub output_index: i64,
+	/// The index of the content part that the text delta was added to.
+	pub content_index: i64,
+	/// The text delta that was added.
+	pub delta: String,
+}
+/// The type of the event. Always `response.output_text.delta`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseTextDeltaEventType {
+	#[serde(rename = "response.output_text.delta")]
+	ResponseOutputTextDelta,
+}
+/// Emitted when text content is finalized.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseTextDoneEvent {
+	/// The type of the event. Always `response.output_text.done`.
+	pub r#type: ResponseTextDoneEventType,
+	/// The ID of the output item that the text content is finalized.
+	pub item_id: String,
+	/// The index of the output item that the text content is finalized.
+	pub output_index: i64,
+	/// The index of the content part that the text content is finalized.
+	pub content_index: i64,
+	/// The text content that is finalized.
+	pub text: String,
+}
+/// The type of the event. Always `response.output_text.done`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseTextDoneEventType {
+	#[serde(rename = "response.output_text.done")]
+	ResponseOutputTextDone,
+}
+/// Represents token usage details including input tokens, output tokens,
+/// a breakdown of output tokens, and the total tokens used.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseUsage {
+	/// The number of input tokens.
+	pub input_tokens: i64,
+	/// A detailed breakdown of the input tokens.
+	pub input_tokens_details: ResponseUsageInputTokensDetails,
+	/// The number of output tokens.
+	pub output_tokens: i64,
+	/// A detailed breakdown of the output tokens.
+	pub output_tokens_details: ResponseUsageOutputTokensDetails,
+	/// The total number of tokens used.
+	pub total_tokens: i64,
+}
+/// A detailed breakdown of the input tokens.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseUsageInputTokensDetails {
+	/// The number of tokens that were retrieved from the cache.
+	/// [More on prompt caching](https://platform.openai.com/docs/guides/prompt-caching).
+	pub cached_tokens: i64,
+}
+/// A detailed breakdown of the output tokens.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseUsageOutputTokensDetails {
+	/// The number of reasoning tokens.
+	pub reasoning_tokens: i64,
+}
+/// Emitted when a web search call is completed.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseWebSearchCallCompletedEvent {
+	/// The type of the event. Always `response.web_search_call.completed`.
+	pub r#type: ResponseWebSearchCallCompletedEventType,
+	/// The index of the output item that the web search call is associated with.
+	pub output_index: i64,
+	/// Unique ID for the output item associated with the web search call.
+	pub item_id: String,
+}
+/// The type of the event. Always `response.web_search_call.completed`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseWebSearchCallCompletedEventType {
+	#[serde(rename = "response.web_search_call.completed")]
+	ResponseWebSearchCallCompleted,
+}
+/// Emitted when a web search call is initiated.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseWebSearchCallInProgressEvent {
+	/// The type of the event. Always `response.web_search_call.in_progress`.
+	pub r#type: ResponseWebSearchCallInProgressEventType,
+	/// The index of the output item that the web search call is associated with.
+	pub output_index: i64,
+	/// Unique ID for the output item associated with the web search call.
+	pub item_id: String,
+}
+/// The type of the event. Always `response.web_search_call.in_progress`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseWebSearchCallInProgressEventType {
+	#[serde(rename = "response.web_search_call.in_progress")]
+	ResponseWebSearchCallInProgress,
+}
+/// Emitted when a web search call is executing.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseWebSearchCallSearchingEvent {
+	/// The type of the event. Always `response.web_search_call.searching`.
+	pub r#type: ResponseWebSearchCallSearchingEventType,
+	/// The index of the output item that the web search call is associated with.
+	pub output_index: i64,
+	/// Unique ID for the output item associated with the web search call.
+	pub item_id: String,
+}
+/// The type of the event. Always `response.web_search_call.searching`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseWebSearchCallSearchingEventType {
+	#[serde(rename = "response.web_search_call.searching")]
+	ResponseWebSearchCallSearching,
+}
 /// A screenshot action.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Screenshot {
-	/// Specifies the event type. For a screenshot action, this property is 
+	/// Specifies the event type. For a screenshot action, this property is
 	/// always set to `screenshot`.
 	pub r#type: ScreenshotType,
 }
-/// Specifies the event type. For a screenshot action, this property is 
+/// Specifies the event type. For a screenshot action, this property is
 /// always set to `screenshot`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -1127,19 +2187,19 @@ pub enum ScreenshotType {
 /// A scroll action.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Scroll {
-	/// The horizontal scroll distance.
-	pub scroll_x: i64,
-	/// The vertical scroll distance.
-	pub scroll_y: i64,
-	/// Specifies the event type. For a scroll action, this property is 
+	/// Specifies the event type. For a scroll action, this property is
 	/// always set to `scroll`.
 	pub r#type: ScrollType,
 	/// The x-coordinate where the scroll occurred.
 	pub x: i64,
 	/// The y-coordinate where the scroll occurred.
 	pub y: i64,
+	/// The horizontal scroll distance.
+	pub scroll_x: i64,
+	/// The vertical scroll distance.
+	pub scroll_y: i64,
 }
-/// Specifies the event type. For a scroll action, this property is 
+/// Specifies the event type. For a scroll action, this property is
 /// always set to `scroll`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -1147,15 +2207,15 @@ pub enum ScrollType {
 	Scroll,
 }
 /// An object specifying the format that the model must output.
-/// 
-/// Configuring `{ "type": "json_schema" }` enables Structured Outputs, 
-/// which ensures the model will match your supplied JSON schema. Learn more in the 
+///
+/// Configuring `{ "type": "json_schema" }` enables Structured Outputs,
+/// which ensures the model will match your supplied JSON schema. Learn more in the
 /// [Structured Outputs guide](https://platform.openai.com/docs/guides/structured-outputs).
-/// 
+///
 /// The default format is `{ "type": "text" }` with no additional options.
-/// 
+///
 /// **Not recommended for gpt-4o and newer models:**
-/// 
+///
 /// Setting to `{ "type": "json_object" }` enables the older JSON mode, which
 /// ensures the message the model generates is valid JSON. Using `json_schema`
 /// is preferred for models that support it.
@@ -1171,6 +2231,8 @@ pub enum TextResponseFormatConfiguration {
 /// Learn more about [Structured Outputs](https://platform.openai.com/docs/guides/structured-outputs).
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct TextResponseFormatJsonSchema {
+	/// The type of response format being defined. Always `json_schema`.
+	pub r#type: TextResponseFormatJsonSchemaType,
 	/// A description of what the response format is for, used by the model to
 	/// determine how to respond in the format.
 	#[serde(skip_serializing_if = "Option::is_none")]
@@ -1187,8 +2249,6 @@ pub struct TextResponseFormatJsonSchema {
 	/// guide](https://platform.openai.com/docs/guides/structured-outputs).
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub strict: Option<bool>,
-	/// The type of response format being defined. Always `json_schema`.
-	pub r#type: TextResponseFormatJsonSchemaType,
 }
 /// The type of response format being defined. Always `json_schema`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -1209,10 +2269,10 @@ pub enum Tool {
 /// Use this option to force the model to call a specific function.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ToolChoiceFunction {
-	/// The name of the function to call.
-	pub name: String,
 	/// For function calling, the type is always `function`.
 	pub r#type: ToolChoiceFunctionType,
+	/// The name of the function to call.
+	pub name: String,
 }
 /// For function calling, the type is always `function`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -1221,12 +2281,12 @@ pub enum ToolChoiceFunctionType {
 	Function,
 }
 /// Controls which (if any) tool is called by the model.
-/// 
+///
 /// `none` means the model will not call any tool and instead generates a message.
-/// 
+///
 /// `auto` means the model can pick between generating a message or calling one or
 /// more tools.
-/// 
+///
 /// `required` means the model must call one or more tools.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -1241,7 +2301,7 @@ pub enum ToolChoiceOptions {
 pub struct ToolChoiceTypes {
 	/// The type of hosted tool the model should to use. Learn more about
 	/// [built-in tools](https://platform.openai.com/docs/guides/tools).
-	/// 
+	///
 	/// Allowed values are:
 	/// - `file_search`
 	/// - `web_search_preview`
@@ -1250,7 +2310,7 @@ pub struct ToolChoiceTypes {
 }
 /// The type of hosted tool the model should to use. Learn more about
 /// [built-in tools](https://platform.openai.com/docs/guides/tools).
-/// 
+///
 /// Allowed values are:
 /// - `file_search`
 /// - `web_search_preview`
@@ -1270,13 +2330,13 @@ pub enum ToolChoiceTypesType {
 /// An action to type in text.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Type {
-	/// The text to type.
-	pub text: String,
-	/// Specifies the event type. For a type action, this property is 
+	/// Specifies the event type. For a type action, this property is
 	/// always set to `type`.
 	pub r#type: TypeType,
+	/// The text to type.
+	pub text: String,
 }
-/// Specifies the event type. For a type action, this property is 
+/// Specifies the event type. For a type action, this property is
 /// always set to `type`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -1286,16 +2346,16 @@ pub enum TypeType {
 /// A citation for a web resource used to generate a model response.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct UrlCitation {
-	/// The index of the last character of the URL citation in the message.
-	pub end_index: i64,
-	/// The index of the first character of the URL citation in the message.
-	pub start_index: i64,
+	/// The URL of the web resource.
+	pub url: String,
 	/// The title of the web resource.
 	pub title: String,
 	/// The type of the URL citation. Always `url_citation`.
 	pub r#type: UrlCitationType,
-	/// The URL of the web resource.
-	pub url: String,
+	/// The index of the first character of the URL citation in the message.
+	pub start_index: i64,
+	/// The index of the last character of the URL citation in the message.
+	pub end_index: i64,
 }
 /// The type of the URL citation. Always `url_citation`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -1307,11 +2367,11 @@ pub enum UrlCitationType {
 /// A wait action.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Wait {
-	/// Specifies the event type. For a wait action, this property is 
+	/// Specifies the event type. For a wait action, this property is
 	/// always set to `wait`.
 	pub r#type: WaitType,
 }
-/// Specifies the event type. For a wait action, this property is 
+/// Specifies the event type. For a wait action, this property is
 /// always set to `wait`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -1322,25 +2382,25 @@ pub enum WaitType {
 /// Learn more about the [web search tool](https://platform.openai.com/docs/guides/tools-web-search).
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct WebSearchTool {
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub search_context_size: Option<WebSearchContextSize>,
 	/// The type of the web search tool. One of:
 	/// - `web_search_preview`
 	/// - `web_search_preview_2025_03_11`
 	pub r#type: WebSearchToolType,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub user_location: Option<WebSearchToolUserLocation>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub search_context_size: Option<WebSearchContextSize>,
 }
-/// The results of a web search tool call. See the 
+/// The results of a web search tool call. See the
 /// [web search guide](https://platform.openai.com/docs/guides/tools-web-search) for more information.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct WebSearchToolCall {
 	/// The unique ID of the web search tool call.
 	pub id: String,
-	/// The status of the web search tool call.
-	pub status: WebSearchToolCallStatus,
 	/// The type of the web search tool call. Always `web_search_call`.
 	pub r#type: WebSearchToolCallType,
+	/// The status of the web search tool call.
+	pub status: WebSearchToolCallStatus,
 }
 /// The status of the web search tool call.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -1372,10 +2432,7 @@ pub enum WebSearchToolType {
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct WebSearchToolUserLocation {
-	/// Free text input for the city of the user, e.g. `San Francisco`.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub city: Option<String>,
-	/// The two-letter 
+	/// The two-letter
 	/// [ISO country code](https://en.wikipedia.org/wiki/ISO_3166-1) of the user,
 	/// e.g. `US`.
 	#[serde(skip_serializing_if = "Option::is_none")]
@@ -1383,7 +2440,10 @@ pub struct WebSearchToolUserLocation {
 	/// Free text input for the region of the user, e.g. `California`.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub region: Option<String>,
-	/// The [IANA timezone](https://timeapi.io/documentation/iana-timezones) 
+	/// Free text input for the city of the user, e.g. `San Francisco`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub city: Option<String>,
+	/// The [IANA timezone](https://timeapi.io/documentation/iana-timezones)
 	/// of the user, e.g. `America/Los_Angeles`.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub timezone: Option<String>,
@@ -0,0 +1,852 @@
+use super::aliases::FunctionParameters;
+use super::aliases::Metadata;
+use super::aliases::ResponseFormatJsonSchemaSchema;
+use super::aliases::StopConfigurationStringArray;
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum AssistantSupportedModels {
+	#[serde(rename = "o3-mini")]
+	O3Mini,
+	#[serde(rename = "o3-mini-2025-01-31")]
+	O3Mini20250131,
+	O1,
+	#[serde(rename = "o1-2024-12-17")]
+	O120241217,
+	#[serde(rename = "gpt-4o")]
+	Gpt4O,
+	#[serde(rename = "gpt-4o-2024-11-20")]
+	Gpt4O20241120,
+	#[serde(rename = "gpt-4o-2024-08-06")]
+	Gpt4O20240806,
+	#[serde(rename = "gpt-4o-2024-05-13")]
+	Gpt4O20240513,
+	#[serde(rename = "gpt-4o-mini")]
+	Gpt4OMini,
+	#[serde(rename = "gpt-4o-mini-2024-07-18")]
+	Gpt4OMini20240718,
+	#[serde(rename = "gpt-4.5-preview")]
+	Gpt45Preview,
+	#[serde(rename = "gpt-4.5-preview-2025-02-27")]
+	Gpt45Preview20250227,
+	#[serde(rename = "gpt-4-turbo")]
+	Gpt4Turbo,
+	#[serde(rename = "gpt-4-turbo-2024-04-09")]
+	Gpt4Turbo20240409,
+	#[serde(rename = "gpt-4-0125-preview")]
+	Gpt40125Preview,
+	#[serde(rename = "gpt-4-turbo-preview")]
+	Gpt4TurboPreview,
+	#[serde(rename = "gpt-4-1106-preview")]
+	Gpt41106Preview,
+	#[serde(rename = "gpt-4-vision-preview")]
+	Gpt4VisionPreview,
+	#[serde(rename = "gpt-4")]
+	Gpt4,
+	#[serde(rename = "gpt-4-0314")]
+	Gpt40314,
+	#[serde(rename = "gpt-4-0613")]
+	Gpt40613,
+	#[serde(rename = "gpt-4-32k")]
+	Gpt432K,
+	#[serde(rename = "gpt-4-32k-0314")]
+	Gpt432K0314,
+	#[serde(rename = "gpt-4-32k-0613")]
+	Gpt432K0613,
+	#[serde(rename = "gpt-3.5-turbo")]
+	Gpt35Turbo,
+	#[serde(rename = "gpt-3.5-turbo-16k")]
+	Gpt35Turbo16K,
+	#[serde(rename = "gpt-3.5-turbo-0613")]
+	Gpt35Turbo0613,
+	#[serde(rename = "gpt-3.5-turbo-1106")]
+	Gpt35Turbo1106,
+	#[serde(rename = "gpt-3.5-turbo-0125")]
+	Gpt35Turbo0125,
+	#[serde(rename = "gpt-3.5-turbo-16k-0613")]
+	Gpt35Turbo16K0613,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AssistantToolsCode {
+	/// The type of tool being defined: `code_interpreter`
+	pub r#type: AssistantToolsCodeType,
+}
+/// The type of tool being defined: `code_interpreter`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum AssistantToolsCodeType {
+	#[serde(rename = "code_interpreter")]
+	CodeInterpreter,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AssistantToolsFileSearch {
+	/// The type of tool being defined: `file_search`
+	pub r#type: AssistantToolsFileSearchType,
+	/// Overrides for the file search tool.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub file_search: Option<AssistantToolsFileSearchFileSearch>,
+}
+/// Overrides for the file search tool.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct AssistantToolsFileSearchFileSearch {
+	/// The maximum number of results the file search tool should output. The default is 20 for `gpt-4*`
+	/// models and 5 for `gpt-3.5-turbo`. This number should be between 1 and 50 inclusive.
+	///
+	/// Note that the file search tool may output fewer than `max_num_results` results. See the [file search
+	/// tool
+	/// documentation](https://platform.openai.com/docs/assistants/tools/file-search#customizing-file-search-settings)
+	/// for more information.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_num_results: Option<i64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub ranking_options: Option<FileSearchRankingOptions>,
+}
+/// The type of tool being defined: `file_search`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum AssistantToolsFileSearchType {
+	#[serde(rename = "file_search")]
+	FileSearch,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AssistantToolsFunction {
+	/// The type of tool being defined: `function`
+	pub r#type: AssistantToolsFunctionType,
+	pub function: FunctionObject,
+}
+/// The type of tool being defined: `function`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum AssistantToolsFunctionType {
+	Function,
+}
+/// Specifies the format that the model must output. Compatible with
+/// [GPT-4o](https://platform.openai.com/docs/models#gpt-4o), [GPT-4
+/// Turbo](https://platform.openai.com/docs/models#gpt-4-turbo-and-gpt-4), and all GPT-3.5 Turbo models
+/// since `gpt-3.5-turbo-1106`.
+///
+/// Setting to `{ "type": "json_schema", "json_schema": {...} }` enables Structured Outputs which
+/// ensures the model will match your supplied JSON schema. Learn more in the [Structured Outputs
+/// guide](https://platform.openai.com/docs/guides/structured-outputs).
+///
+/// Setting to `{ "type": "json_object" }` enables JSON mode, which ensures the message the model
+/// generates is valid JSON.
+///
+/// **Important:** when using JSON mode, you **must** also instruct the model to produce JSON yourself
+/// via a system or user message. Without this, the model may generate an unending stream of whitespace
+/// until the generation reaches the token limit, resulting in a long-running and seemingly "stuck"
+/// request. Also note that the message content may be partially cut off if `finish_reason="length"`,
+/// which indicates the generation exceeded `max_tokens` or the conversation exceeded the max context
+/// length.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(untagged)]
+pub enum AssistantsApiResponseFormatOption {
+	Auto(String),
+	ResponseFormatText(ResponseFormatText),
+	ResponseFormatJsonObject(ResponseFormatJsonObject),
+	ResponseFormatJsonSchema(ResponseFormatJsonSchema),
+}
+/// Options for streaming response. Only set this when you set `stream: true`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct ChatCompletionStreamOptions {
+	/// If set, an additional chunk will be streamed before the `data: [DONE]`
+	/// message. The `usage` field on this chunk shows the token usage statistics
+	/// for the entire request, and the `choices` field will always be an empty
+	/// array.
+	///
+	/// All other chunks will also include a `usage` field, but with a null
+	/// value. **NOTE:** If the stream is interrupted, you may not receive the
+	/// final usage chunk which contains the total token usage for the request.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub include_usage: Option<bool>,
+}
+/// A filter used to compare a specified attribute key to a given value using a defined comparison
+/// operation.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ComparisonFilter {
+	/// Specifies the comparison operator: `eq`, `ne`, `gt`, `gte`, `lt`, `lte`.
+	/// - `eq`: equals
+	/// - `ne`: not equal
+	/// - `gt`: greater than
+	/// - `gte`: greater than or equal
+	/// - `lt`: less than
+	/// - `lte`: less than or equal
+	pub r#type: ComparisonFilterType,
+	/// The key to compare against the value.
+	pub key: String,
+	/// The value to compare against the attribute key; supports string, number, or boolean types.
+	pub value: ComparisonFilterValue,
+}
+/// Specifies the comparison operator: `eq`, `ne`, `gt`, `gte`, `lt`, `lte`.
+/// - `eq`: equals
+/// - `ne`: not equal
+/// - `gt`: greater than
+/// - `gte`: greater than or equal
+/// - `lt`: less than
+/// - `lte`: less than or equal
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ComparisonFilterType {
+	Eq,
+	Ne,
+	Gt,
+	Gte,
+	Lt,
+	Lte,
+}
+/// The value to compare against the attribute key; supports string, number, or boolean types.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(untagged)]
+pub enum ComparisonFilterValue {
+	String(String),
+	Number(f64),
+	Boolean(bool),
+}
+/// Usage statistics for the completion request.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CompletionUsage {
+	/// Number of tokens in the generated completion.
+	pub completion_tokens: i64,
+	/// Number of tokens in the prompt.
+	pub prompt_tokens: i64,
+	/// Total number of tokens used in the request (prompt + completion).
+	pub total_tokens: i64,
+	/// Breakdown of tokens used in a completion.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub completion_tokens_details: Option<CompletionUsageCompletionTokensDetails>,
+	/// Breakdown of tokens used in the prompt.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub prompt_tokens_details: Option<CompletionUsagePromptTokensDetails>,
+}
+/// Breakdown of tokens used in a completion.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct CompletionUsageCompletionTokensDetails {
+	/// When using Predicted Outputs, the number of tokens in the
+	/// prediction that appeared in the completion.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub accepted_prediction_tokens: Option<i64>,
+	/// Audio input tokens generated by the model.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub audio_tokens: Option<i64>,
+	/// Tokens generated by the model for reasoning.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub reasoning_tokens: Option<i64>,
+	/// When using Predicted Outputs, the number of tokens in the
+	/// prediction that did not appear in the completion. However, like
+	/// reasoning tokens, these tokens are still counted in the total
+	/// completion tokens for purposes of billing, output, and context window
+	/// limits.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub rejected_prediction_tokens: Option<i64>,
+}
+/// Breakdown of tokens used in the prompt.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct CompletionUsagePromptTokensDetails {
+	/// Audio input tokens present in the prompt.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub audio_tokens: Option<i64>,
+	/// Cached tokens present in the prompt.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub cached_tokens: Option<i64>,
+}
+/// Combine multiple filters using `and` or `or`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CompoundFilter {
+	/// Type of operation: `and` or `or`.
+	pub r#type: CompoundFilterType,
+	/// Array of filters to combine. Items can be `ComparisonFilter` or `CompoundFilter`.
+	pub filters: Vec<CompoundFilterItems>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(untagged)]
+pub enum CompoundFilterItems {
+	ComparisonFilter(ComparisonFilter),
+	Object(serde_json::Value),
+}
+/// Type of operation: `and` or `or`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CompoundFilterType {
+	And,
+	Or,
+}
+/// The aggregated costs details of the specific time bucket.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CostsResult {
+	pub object: CostsResultObject,
+	/// The monetary value in its associated currency.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub amount: Option<CostsResultAmount>,
+	/// When `group_by=line_item`, this field provides the line item of the grouped costs result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub line_item: Option<String>,
+	/// When `group_by=project_id`, this field provides the project ID of the grouped costs result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub project_id: Option<String>,
+}
+/// The monetary value in its associated currency.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct CostsResultAmount {
+	/// The numeric value of the cost.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub value: Option<f64>,
+	/// Lowercase ISO-4217 currency e.g. "usd"
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub currency: Option<String>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CostsResultObject {
+	#[serde(rename = "organization.costs.result")]
+	OrganizationCostsResult,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct CreateModelResponseProperties {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub metadata: Option<Metadata>,
+	/// What sampling temperature to use, between 0 and 2. Higher values like 0.8 will make the output more
+	/// random, while lower values like 0.2 will make it more focused and deterministic.
+	/// We generally recommend altering this or `top_p` but not both.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub temperature: Option<f64>,
+	/// An alternative to sampling with temperature, called nucleus sampling,
+	/// where the model considers the results of the tokens with top_p probability
+	/// mass. So 0.1 means only the tokens comprising the top 10% probability mass
+	/// are considered.
+	///
+	/// We generally recommend altering this or `temperature` but not both.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub top_p: Option<f64>,
+	/// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse.
+	/// [Learn more](https://platform.openai.com/docs/guides/safety-best-practices#end-user-ids).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub user: Option<String>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Error {
+	pub code: Option<String>,
+	pub message: String,
+	pub param: Option<String>,
+	pub r#type: String,
+}
+/// Occurs when an [error](https://platform.openai.com/docs/guides/error-codes#api-errors) occurs. This
+/// can happen due to an internal server error or a timeout.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ErrorEvent {
+	pub event: ErrorEventEvent,
+	pub data: Error,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ErrorEventEvent {
+	Error,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ErrorResponse {
+	pub error: Error,
+}
+/// The ranker to use for the file search. If not specified will use the `auto` ranker.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum FileSearchRanker {
+	Auto,
+	#[serde(rename = "default_2024_08_21")]
+	Default20240821,
+}
+/// The ranking options for the file search. If not specified, the file search tool will use the `auto`
+/// ranker and a score_threshold of 0.
+///
+/// See the [file search tool
+/// documentation](https://platform.openai.com/docs/assistants/tools/file-search#customizing-file-search-settings)
+/// for more information.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FileSearchRankingOptions {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub ranker: Option<FileSearchRanker>,
+	/// The score threshold for the file search. All values must be a floating point number between 0 and 1.
+	pub score_threshold: f64,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FunctionObject {
+	/// A description of what the function does, used by the model to choose when and how to call the
+	/// function.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub description: Option<String>,
+	/// The name of the function to be called. Must be a-z, A-Z, 0-9, or contain underscores and dashes,
+	/// with a maximum length of 64.
+	pub name: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub parameters: Option<FunctionParameters>,
+	/// Whether to enable strict schema adherence when generating the function call. If set to true, the
+	/// model will follow the exact schema defined in the `parameters` field. Only a subset of JSON Schema
+	/// is supported when `strict` is `true`. Learn more about Structured Outputs in the [function calling
+	/// guide](docs/guides/function-calling).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub strict: Option<bool>,
+}
+/// A log probability object.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct LogProbProperties {
+	/// The token that was used to generate the log probability.
+	pub token: String,
+	/// The log probability of the token.
+	pub logprob: f64,
+	/// The bytes that were used to generate the log probability.
+	pub bytes: Vec<i64>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct ModelResponseProperties {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub metadata: Option<Metadata>,
+	/// What sampling temperature to use, between 0 and 2. Higher values like 0.8 will make the output more
+	/// random, while lower values like 0.2 will make it more focused and deterministic.
+	/// We generally recommend altering this or `top_p` but not both.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub temperature: Option<f64>,
+	/// An alternative to sampling with temperature, called nucleus sampling,
+	/// where the model considers the results of the tokens with top_p probability
+	/// mass. So 0.1 means only the tokens comprising the top 10% probability mass
+	/// are considered.
+	///
+	/// We generally recommend altering this or `temperature` but not both.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub top_p: Option<f64>,
+	/// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse.
+	/// [Learn more](https://platform.openai.com/docs/guides/safety-best-practices#end-user-ids).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub user: Option<String>,
+}
+/// The `File` object represents a document that has been uploaded to OpenAI.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct OpenAIFile {
+	/// The file identifier, which can be referenced in the API endpoints.
+	pub id: String,
+	/// The size of the file, in bytes.
+	pub bytes: i64,
+	/// The Unix timestamp (in seconds) for when the file was created.
+	pub created_at: i64,
+	/// The Unix timestamp (in seconds) for when the file will expire.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub expires_at: Option<i64>,
+	/// The name of the file.
+	pub filename: String,
+	/// The object type, which is always `file`.
+	pub object: OpenAIFileObject,
+	/// The intended purpose of the file. Supported values are `assistants`, `assistants_output`, `batch`,
+	/// `batch_output`, `fine-tune`, `fine-tune-results` and `vision`.
+	pub purpose: OpenAIFilePurpose,
+	/// Deprecated. The current status of the file, which can be either `uploaded`, `processed`, or `error`.
+	pub status: OpenAIFileStatus,
+	/// Deprecated. For details on why a fine-tuning training file failed validation, see the `error` field
+	/// on `fine_tuning.job`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub status_details: Option<String>,
+}
+/// The object type, which is always `file`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum OpenAIFileObject {
+	File,
+}
+/// The intended purpose of the file. Supported values are `assistants`, `assistants_output`, `batch`,
+/// `batch_output`, `fine-tune`, `fine-tune-results` and `vision`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum OpenAIFilePurpose {
+	Assistants,
+	#[serde(rename = "assistants_output")]
+	AssistantsOutput,
+	Batch,
+	#[serde(rename = "batch_output")]
+	BatchOutput,
+	#[serde(rename = "fine-tune")]
+	FineTune,
+	#[serde(rename = "fine-tune-results")]
+	FineTuneResults,
+	Vision,
+}
+/// Deprecated. The current status of the file, which can be either `uploaded`, `processed`, or `error`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum OpenAIFileStatus {
+	Uploaded,
+	Processed,
+	Error,
+}
+/// **o-series models only**
+///
+/// Constrains effort on reasoning for
+/// [reasoning models](https://platform.openai.com/docs/guides/reasoning).
+/// Currently supported values are `low`, `medium`, and `high`. Reducing
+/// reasoning effort can result in faster responses and fewer tokens used
+/// on reasoning in a response.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ReasoningEffort {
+	Low,
+	Medium,
+	High,
+}
+/// JSON object response format. An older method of generating JSON responses.
+/// Using `json_schema` is recommended for models that support it. Note that the
+/// model will not generate JSON without a system or user message instructing it
+/// to do so.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseFormatJsonObject {
+	/// The type of response format being defined. Always `json_object`.
+	pub r#type: ResponseFormatJsonObjectType,
+}
+/// The type of response format being defined. Always `json_object`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseFormatJsonObjectType {
+	#[serde(rename = "json_object")]
+	JsonObject,
+}
+/// JSON Schema response format. Used to generate structured JSON responses.
+/// Learn more about [Structured Outputs](https://platform.openai.com/docs/guides/structured-outputs).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseFormatJsonSchema {
+	/// The type of response format being defined. Always `json_schema`.
+	pub r#type: ResponseFormatJsonSchemaType,
+	/// Structured Outputs configuration options, including a JSON Schema.
+	pub json_schema: ResponseFormatJsonSchemaJsonSchema,
+}
+/// Structured Outputs configuration options, including a JSON Schema.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseFormatJsonSchemaJsonSchema {
+	/// A description of what the response format is for, used by the model to
+	/// determine how to respond in the format.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub description: Option<String>,
+	/// The name of the response format. Must be a-z, A-Z, 0-9, or contain
+	/// underscores and dashes, with a maximum length of 64.
+	pub name: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub schema: Option<ResponseFormatJsonSchemaSchema>,
+	/// Whether to enable strict schema adherence when generating the output.
+	/// If set to true, the model will always follow the exact schema defined
+	/// in the `schema` field. Only a subset of JSON Schema is supported when
+	/// `strict` is `true`. To learn more, read the [Structured Outputs
+	/// guide](https://platform.openai.com/docs/guides/structured-outputs).
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub strict: Option<bool>,
+}
+/// The type of response format being defined. Always `json_schema`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseFormatJsonSchemaType {
+	#[serde(rename = "json_schema")]
+	JsonSchema,
+}
+/// Default response format. Used to generate text responses.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResponseFormatText {
+	/// The type of response format being defined. Always `text`.
+	pub r#type: ResponseFormatTextType,
+}
+/// The type of response format being defined. Always `text`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseFormatTextType {
+	Text,
+}
+/// Up to 4 sequences where the API will stop generating further tokens. The
+/// returned text will not contain the stop sequence.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(untagged)]
+pub enum StopConfiguration {
+	String(String),
+	StopConfigurationStringArray(StopConfigurationStringArray),
+}
+/// The aggregated audio speeches usage details of the specific time bucket.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct UsageAudioSpeechesResult {
+	pub object: UsageAudioSpeechesResultObject,
+	/// The number of characters processed.
+	pub characters: i64,
+	/// The count of requests made to the model.
+	pub num_model_requests: i64,
+	/// When `group_by=project_id`, this field provides the project ID of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub project_id: Option<String>,
+	/// When `group_by=user_id`, this field provides the user ID of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub user_id: Option<String>,
+	/// When `group_by=api_key_id`, this field provides the API key ID of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub api_key_id: Option<String>,
+	/// When `group_by=model`, this field provides the model name of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub model: Option<String>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum UsageAudioSpeechesResultObject {
+	#[serde(rename = "organization.usage.audio_speeches.result")]
+	OrganizationUsageAudioSpeechesResult,
+}
+/// The aggregated audio transcriptions usage details of the specific time bucket.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct UsageAudioTranscriptionsResult {
+	pub object: UsageAudioTranscriptionsResultObject,
+	/// The number of seconds processed.
+	pub seconds: i64,
+	/// The count of requests made to the model.
+	pub num_model_requests: i64,
+	/// When `group_by=project_id`, this field provides the project ID of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub project_id: Option<String>,
+	/// When `group_by=user_id`, this field provides the user ID of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub user_id: Option<String>,
+	/// When `group_by=api_key_id`, this field provides the API key ID of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub api_key_id: Option<String>,
+	/// When `group_by=model`, this field provides the model name of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub model: Option<String>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum UsageAudioTranscriptionsResultObject {
+	#[serde(rename = "organization.usage.audio_transcriptions.result")]
+	OrganizationUsageAudioTranscriptionsResult,
+}
+/// The aggregated code interpreter sessions usage details of the specific time bucket.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct UsageCodeInterpreterSessionsResult {
+	pub object: UsageCodeInterpreterSessionsResultObject,
+	/// The number of code interpreter sessions.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub num_sessions: Option<i64>,
+	/// When `group_by=project_id`, this field provides the project ID of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub project_id: Option<String>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum UsageCodeInterpreterSessionsResultObject {
+	#[serde(rename = "organization.usage.code_interpreter_sessions.result")]
+	OrganizationUsageCodeInterpreterSessionsResult,
+}
+/// The aggregated completions usage details of the specific time bucket.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct UsageCompletionsResult {
+	pub object: UsageCompletionsResultObject,
+	/// The aggregated number of text input tokens used, including cached tokens. For customers subscribe to
+	/// scale tier, this includes scale tier tokens.
+	pub input_tokens: i64,
+	/// The aggregated number of text input tokens that has been cached from previous requests. For
+	/// customers subscribe to scale tier, this includes scale tier tokens.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub input_cached_tokens: Option<i64>,
+	/// The aggregated number of text output tokens used. For customers subscribe to scale tier, this
+	/// includes scale tier tokens.
+	pub output_tokens: i64,
+	/// The aggregated number of audio input tokens used, including cached tokens.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub input_audio_tokens: Option<i64>,
+	/// The aggregated number of audio output tokens used.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub output_audio_tokens: Option<i64>,
+	/// The count of requests made to the model.
+	pub num_model_requests: i64,
+	/// When `group_by=project_id`, this field provides the project ID of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub project_id: Option<String>,
+	/// When `group_by=user_id`, this field provides the user ID of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub user_id: Option<String>,
+	/// When `group_by=api_key_id`, this field provides the API key ID of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub api_key_id: Option<String>,
+	/// When `group_by=model`, this field provides the model name of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub model: Option<String>,
+	/// When `group_by=batch`, this field tells whether the grouped usage result is batch or not.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub batch: Option<bool>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum UsageCompletionsResultObject {
+	#[serde(rename = "organization.usage.completions.result")]
+	OrganizationUsageCompletionsResult,
+}
+/// The aggregated embeddings usage details of the specific time bucket.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct UsageEmbeddingsResult {
+	pub object: UsageEmbeddingsResultObject,
+	/// The aggregated number of input tokens used.
+	pub input_tokens: i64,
+	/// The count of requests made to the model.
+	pub num_model_requests: i64,
+	/// When `group_by=project_id`, this field provides the project ID of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub project_id: Option<String>,
+	/// When `group_by=user_id`, this field provides the user ID of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub user_id: Option<String>,
+	/// When `group_by=api_key_id`, this field provides the API key ID of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub api_key_id: Option<String>,
+	/// When `group_by=model`, this field provides the model name of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub model: Option<String>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum UsageEmbeddingsResultObject {
+	#[serde(rename = "organization.usage.embeddings.result")]
+	OrganizationUsageEmbeddingsResult,
+}
+/// The aggregated images usage details of the specific time bucket.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct UsageImagesResult {
+	pub object: UsageImagesResultObject,
+	/// The number of images processed.
+	pub images: i64,
+	/// The count of requests made to the model.
+	pub num_model_requests: i64,
+	/// When `group_by=source`, this field provides the source of the grouped usage result, possible values
+	/// are `image.generation`, `image.edit`, `image.variation`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub source: Option<String>,
+	/// When `group_by=size`, this field provides the image size of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub size: Option<String>,
+	/// When `group_by=project_id`, this field provides the project ID of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub project_id: Option<String>,
+	/// When `group_by=user_id`, this field provides the user ID of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub user_id: Option<String>,
+	/// When `group_by=api_key_id`, this field provides the API key ID of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub api_key_id: Option<String>,
+	/// When `group_by=model`, this field provides the model name of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub model: Option<String>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum UsageImagesResultObject {
+	#[serde(rename = "organization.usage.images.result")]
+	OrganizationUsageImagesResult,
+}
+/// The aggregated moderations usage details of the specific time bucket.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct UsageModerationsResult {
+	pub object: UsageModerationsResultObject,
+	/// The aggregated number of input tokens used.
+	pub input_tokens: i64,
+	/// The count of requests made to the model.
+	pub num_model_requests: i64,
+	/// When `group_by=project_id`, this field provides the project ID of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub project_id: Option<String>,
+	/// When `group_by=user_id`, this field provides the user ID of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub user_id: Option<String>,
+	/// When `group_by=api_key_id`, this field provides the API key ID of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub api_key_id: Option<String>,
+	/// When `group_by=model`, this field provides the model name of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub model: Option<String>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum UsageModerationsResultObject {
+	#[serde(rename = "organization.usage.moderations.result")]
+	OrganizationUsageModerationsResult,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct UsageResponse {
+	pub object: UsageResponseObject,
+	pub data: Vec<UsageTimeBucket>,
+	pub has_more: bool,
+	pub next_page: String,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum UsageResponseObject {
+	Page,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct UsageTimeBucket {
+	pub object: UsageTimeBucketObject,
+	pub start_time: i64,
+	pub end_time: i64,
+	pub result: Vec<UsageTimeBucketItems>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(untagged)]
+pub enum UsageTimeBucketItems {
+	UsageCompletionsResult(UsageCompletionsResult),
+	UsageEmbeddingsResult(UsageEmbeddingsResult),
+	UsageModerationsResult(UsageModerationsResult),
+	UsageImagesResult(UsageImagesResult),
+	UsageAudioSpeechesResult(UsageAudioSpeechesResult),
+	UsageAudioTranscriptionsResult(UsageAudioTranscriptionsResult),
+	UsageVectorStoresResult(UsageVectorStoresResult),
+	UsageCodeInterpreterSessionsResult(UsageCodeInterpreterSessionsResult),
+	CostsResult(CostsResult),
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum UsageTimeBucketObject {
+	Bucket,
+}
+/// The aggregated vector stores usage details of the specific time bucket.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct UsageVectorStoresResult {
+	pub object: UsageVectorStoresResultObject,
+	/// The vector stores usage in bytes.
+	pub usage_bytes: i64,
+	/// When `group_by=project_id`, this field provides the project ID of the grouped usage result.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub project_id: Option<String>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum UsageVectorStoresResultObject {
+	#[serde(rename = "organization.usage.vector_stores.result")]
+	OrganizationUsageVectorStoresResult,
+}
+/// High level guidance for the amount of context window space to use for the
+/// search. One of `low`, `medium`, or `high`. `medium` is the default.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum WebSearchContextSize {
+	Low,
+	Medium,
+	High,
+}
+/// Approximate location parameters for the search.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct WebSearchLocation {
+	/// The two-letter
+	/// [ISO country code](https://en.wikipedia.org/wiki/ISO_3166-1) of the user,
+	/// e.g. `US`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub country: Option<String>,
+	/// Free text input for the region of the user, e.g. `California`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub region: Option<String>,
+	/// Free text input for the city of the user, e.g. `San Francisco`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub city: Option<String>,
+	/// The [IANA timezone](https://timeapi.io/documentation/iana-timezones)
+	/// of the user, e.g. `America/Los_Angeles`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub timezone: Option<String>,
+}
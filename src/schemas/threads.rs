@@ -1,8 +1,17 @@
-use super::assistants_threads::AssistantToolsCode;
-use super::assistants_threads::AssistantToolsFileSearch;
-use super::assistants_threads::AssistantToolsFunction;
+use super::aliases::CreateMessageRequestContentVariedArray;
+use super::aliases::CreateRunRequestModel;
+use super::aliases::CreateRunRequestToolChoice;
+use super::aliases::CreateThreadAndRunRequestModel;
+use super::aliases::CreateThreadAndRunRequestToolChoice;
+use super::aliases::Metadata;
+use super::aliases::ParallelToolCalls;
+use super::aliases::RunObjectToolChoice;
+use super::shared::AssistantToolsCode;
+use super::shared::AssistantToolsFileSearch;
+use super::shared::AssistantToolsFunction;
+use super::shared::AssistantsApiResponseFormatOption;
+use super::shared::ReasoningEffort;
 
-use std::collections::HashMap;
 use serde::{Serialize, Deserialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -19,9 +28,11 @@ pub enum AssistantToolsFileSearchTypeOnlyType {
 }
 /// Controls which (if any) tool is called by the model.
 /// `none` means the model will not call any tools and instead generates a message.
-/// `auto` is the default value and means the model can pick between generating a message or calling one or more tools.
+/// `auto` is the default value and means the model can pick between generating a message or calling one
+/// or more tools.
 /// `required` means the model must call one or more tools before responding to the user.
-/// Specifying a particular tool like `{"type": "file_search"}` or `{"type": "function", "function": {"name": "my_function"}}` forces the model to call that tool.
+/// Specifying a particular tool like `{"type": "file_search"}` or `{"type": "function", "function":
+/// {"name": "my_function"}}` forces the model to call that tool.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
 #[serde(untagged)]
@@ -34,10 +45,10 @@ pub enum AssistantsApiToolChoiceOption {
 /// Specifies a tool the model should use. Use to force the model to call a specific tool.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct AssistantsNamedToolChoice {
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub function: Option<AssistantsNamedToolChoiceFunction>,
 	/// The type of the tool. If type is `function`, the function name must be set
 	pub r#type: AssistantsNamedToolChoiceType,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub function: Option<AssistantsNamedToolChoiceFunction>,
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct AssistantsNamedToolChoiceFunction {
@@ -54,18 +65,31 @@ pub enum AssistantsNamedToolChoiceType {
 	#[serde(rename = "file_search")]
 	FileSearch,
 }
+/// # Examples
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateMessageRequest {
+///     role: CreateMessageRequestRole::User,
+///     content: CreateMessageRequestContent::String("How does AI work? Explain it in simple terms.".to_string()),
+///     attachments: None,
+///     metadata: None,
+/// };
+/// ```
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateMessageRequest {
+	/// The role of the entity that is creating the message. Allowed values include:
+	/// - `user`: Indicates the message is sent by an actual user and should be used in most cases to
+	///   represent user-generated messages.
+	/// - `assistant`: Indicates the message is generated by the assistant. Use this value to insert
+	///   messages from the assistant into the conversation.
+	pub role: CreateMessageRequestRole,
+	pub content: CreateMessageRequestContent,
 	/// A list of files attached to the message, and the tools they should be added to.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub attachments: Option<Vec<CreateMessageRequestAttachmentsItem>>,
-	pub content: CreateMessageRequestContent,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub metadata: Option<Metadata>,
-	/// The role of the entity that is creating the message. Allowed values include:
-	/// - `user`: Indicates the message is sent by an actual user and should be used in most cases to represent user-generated messages.
-	/// - `assistant`: Indicates the message is generated by the assistant. Use this value to insert messages from the assistant into the conversation.
-	pub role: CreateMessageRequestRole,
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct CreateMessageRequestAttachmentsItem {
@@ -99,62 +123,167 @@ pub enum CreateMessageRequestContentItems {
 	MessageRequestContentTextObject(MessageRequestContentTextObject),
 }
 /// The role of the entity that is creating the message. Allowed values include:
-/// - `user`: Indicates the message is sent by an actual user and should be used in most cases to represent user-generated messages.
-/// - `assistant`: Indicates the message is generated by the assistant. Use this value to insert messages from the assistant into the conversation.
+/// - `user`: Indicates the message is sent by an actual user and should be used in most cases to
+///   represent user-generated messages.
+/// - `assistant`: Indicates the message is generated by the assistant. Use this value to insert
+///   messages from the assistant into the conversation.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum CreateMessageRequestRole {
 	User,
 	Assistant,
 }
+/// # Examples
+///
+/// Default:
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateRunRequest {
+///     assistant_id: "asst_abc123".to_string(),
+///     model: None,
+///     reasoning_effort: None,
+///     instructions: None,
+///     additional_instructions: None,
+///     additional_messages: None,
+///     tools: None,
+///     metadata: None,
+///     temperature: None,
+///     top_p: None,
+///     stream: None,
+///     max_prompt_tokens: None,
+///     max_completion_tokens: None,
+///     truncation_strategy: None,
+///     tool_choice: None,
+///     parallel_tool_calls: None,
+///     response_format: None,
+/// };
+/// ```
+///
+/// Streaming:
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateRunRequest {
+///     assistant_id: "asst_123".to_string(),
+///     model: None,
+///     reasoning_effort: None,
+///     instructions: None,
+///     additional_instructions: None,
+///     additional_messages: None,
+///     tools: None,
+///     metadata: None,
+///     temperature: None,
+///     top_p: None,
+///     stream: Some(true),
+///     max_prompt_tokens: None,
+///     max_completion_tokens: None,
+///     truncation_strategy: None,
+///     tool_choice: None,
+///     parallel_tool_calls: None,
+///     response_format: None,
+/// };
+/// ```
+///
+/// Streaming with Functions:
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateRunRequest {
+///     assistant_id: "asst_abc123".to_string(),
+///     model: None,
+///     reasoning_effort: None,
+///     instructions: None,
+///     additional_instructions: None,
+///     additional_messages: None,
+///     tools: Some(vec![
+///         CreateRunRequestItems::AssistantToolsFunction(AssistantToolsFunction {
+///             r#type: AssistantToolsFunctionType::Function,
+///             function: FunctionObject {
+///                 description: Some("Get the current weather in a given location".to_string()),
+///                 name: "get_current_weather".to_string(),
+///                 parameters: Some(serde_json::json!({"type":"object","properties":{"location":{"type":"string","description":"The city and state, e.g. San Francisco, CA"},"unit":{"type":"string","enum":["celsius","fahrenheit"]}},"required":["location"]})),
+///                 strict: None,
+///             },
+///         }),
+///     ]),
+///     metadata: None,
+///     temperature: None,
+///     top_p: None,
+///     stream: Some(true),
+///     max_prompt_tokens: None,
+///     max_completion_tokens: None,
+///     truncation_strategy: None,
+///     tool_choice: None,
+///     parallel_tool_calls: None,
+///     response_format: None,
+/// };
+/// ```
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateRunRequest {
-	/// Appends additional instructions at the end of the instructions for the run. This is useful for modifying the behavior on a per-run basis without overriding other instructions.
+	/// The ID of the [assistant](https://platform.openai.com/docs/api-reference/assistants) to use to
+	/// execute this run.
+	pub assistant_id: String,
+	/// The ID of the [Model](https://platform.openai.com/docs/api-reference/models) to be used to execute
+	/// this run. If a value is provided here, it will override the model associated with the assistant. If
+	/// not, the model associated with the assistant will be used.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub additional_instructions: Option<String>,
-	/// Adds additional messages to the thread before creating the run.
+	pub model: Option<CreateRunRequestModel>,
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub additional_messages: Option<Vec<CreateMessageRequest>>,
-	/// The ID of the [assistant](https://platform.openai.com/docs/api-reference/assistants) to use to execute this run.
-	pub assistant_id: String,
-	/// Overrides the [instructions](https://platform.openai.com/docs/api-reference/assistants/createAssistant) of the assistant. This is useful for modifying the behavior on a per-run basis.
+	pub reasoning_effort: Option<ReasoningEffort>,
+	/// Overrides the
+	/// [instructions](https://platform.openai.com/docs/api-reference/assistants/createAssistant) of the
+	/// assistant. This is useful for modifying the behavior on a per-run basis.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub instructions: Option<String>,
-	/// The maximum number of completion tokens that may be used over the course of the run. The run will make a best effort to use only the number of completion tokens specified, across multiple turns of the run. If the run exceeds the number of completion tokens specified, the run will end with status `incomplete`. See `incomplete_details` for more info.
+	/// Appends additional instructions at the end of the instructions for the run. This is useful for
+	/// modifying the behavior on a per-run basis without overriding other instructions.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub max_completion_tokens: Option<i64>,
-	/// The maximum number of prompt tokens that may be used over the course of the run. The run will make a best effort to use only the number of prompt tokens specified, across multiple turns of the run. If the run exceeds the number of prompt tokens specified, the run will end with status `incomplete`. See `incomplete_details` for more info.
+	pub additional_instructions: Option<String>,
+	/// Adds additional messages to the thread before creating the run.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub max_prompt_tokens: Option<i64>,
+	pub additional_messages: Option<Vec<CreateMessageRequest>>,
+	/// Override the tools the assistant can use for this run. This is useful for modifying the behavior on
+	/// a per-run basis.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub tools: Option<Vec<CreateRunRequestItems>>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub metadata: Option<Metadata>,
-	/// The ID of the [Model](https://platform.openai.com/docs/api-reference/models) to be used to execute this run. If a value is provided here, it will override the model associated with the assistant. If not, the model associated with the assistant will be used.
+	/// What sampling temperature to use, between 0 and 2. Higher values like 0.8 will make the output more
+	/// random, while lower values like 0.2 will make it more focused and deterministic.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub model: Option<CreateRunRequestModel>,
+	pub temperature: Option<f64>,
+	/// An alternative to sampling with temperature, called nucleus sampling, where the model considers the
+	/// results of the tokens with top_p probability mass. So 0.1 means only the tokens comprising the top
+	/// 10% probability mass are considered.
+	///
+	/// We generally recommend altering this or temperature but not both.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub parallel_tool_calls: Option<ParallelToolCalls>,
+	pub top_p: Option<f64>,
+	/// If `true`, returns a stream of events that happen during the Run as server-sent events, terminating
+	/// when the Run enters a terminal state with a `data: [DONE]` message.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub reasoning_effort: Option<ReasoningEffort>,
+	pub stream: Option<bool>,
+	/// The maximum number of prompt tokens that may be used over the course of the run. The run will make a
+	/// best effort to use only the number of prompt tokens specified, across multiple turns of the run. If
+	/// the run exceeds the number of prompt tokens specified, the run will end with status `incomplete`.
+	/// See `incomplete_details` for more info.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub response_format: Option<AssistantsApiResponseFormatOption>,
-	/// If `true`, returns a stream of events that happen during the Run as server-sent events, terminating when the Run enters a terminal state with a `data: [DONE]` message.
+	pub max_prompt_tokens: Option<i64>,
+	/// The maximum number of completion tokens that may be used over the course of the run. The run will
+	/// make a best effort to use only the number of completion tokens specified, across multiple turns of
+	/// the run. If the run exceeds the number of completion tokens specified, the run will end with status
+	/// `incomplete`. See `incomplete_details` for more info.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub stream: Option<bool>,
-	/// What sampling temperature to use, between 0 and 2. Higher values like 0.8 will make the output more random, while lower values like 0.2 will make it more focused and deterministic.
+	pub max_completion_tokens: Option<i64>,
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub temperature: Option<f64>,
+	pub truncation_strategy: Option<CreateRunRequestTruncationStrategy>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub tool_choice: Option<CreateRunRequestToolChoice>,
-	/// Override the tools the assistant can use for this run. This is useful for modifying the behavior on a per-run basis.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub tools: Option<Vec<CreateRunRequestItems>>,
-	/// An alternative to sampling with temperature, called nucleus sampling, where the model considers the results of the tokens with top_p probability mass. So 0.1 means only the tokens comprising the top 10% probability mass are considered.
-	/// 
-	/// We generally recommend altering this or temperature but not both.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub top_p: Option<f64>,
+	pub parallel_tool_calls: Option<ParallelToolCalls>,
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub truncation_strategy: Option<CreateRunRequestTruncationStrategy>,
+	pub response_format: Option<AssistantsApiResponseFormatOption>,
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -166,57 +295,190 @@ pub enum CreateRunRequestItems {
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateRunRequestTruncationStrategy {
+	/// The truncation strategy to use for the thread. The default is `auto`. If set to `last_messages`, the
+	/// thread will be truncated to the n most recent messages in the thread. When set to `auto`, messages
+	/// in the middle of the thread will be dropped to fit the context length of the model,
+	/// `max_prompt_tokens`.
+	pub r#type: TruncationObjectType,
 	/// The number of most recent messages from the thread when constructing the context for the run.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub last_messages: Option<i64>,
-	/// The truncation strategy to use for the thread. The default is `auto`. If set to `last_messages`, the thread will be truncated to the n most recent messages in the thread. When set to `auto`, messages in the middle of the thread will be dropped to fit the context length of the model, `max_prompt_tokens`.
-	pub r#type: TruncationObjectType,
 }
+/// # Examples
+///
+/// Default:
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateThreadAndRunRequest {
+///     assistant_id: "asst_abc123".to_string(),
+///     thread: Some(CreateThreadRequest {
+///         messages: Some(vec![
+///             CreateMessageRequest {
+///                 role: CreateMessageRequestRole::User,
+///                 content: CreateMessageRequestContent::String("Explain deep learning to a 5 year old.".to_string()),
+///                 attachments: None,
+///                 metadata: None,
+///             },
+///         ]),
+///         ..Default::default()
+///     }),
+///     model: None,
+///     instructions: None,
+///     tools: None,
+///     tool_resources: None,
+///     metadata: None,
+///     temperature: None,
+///     top_p: None,
+///     stream: None,
+///     max_prompt_tokens: None,
+///     max_completion_tokens: None,
+///     truncation_strategy: None,
+///     tool_choice: None,
+///     parallel_tool_calls: None,
+///     response_format: None,
+/// };
+/// ```
+///
+/// Streaming:
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateThreadAndRunRequest {
+///     assistant_id: "asst_123".to_string(),
+///     thread: Some(CreateThreadRequest {
+///         messages: Some(vec![
+///             CreateMessageRequest {
+///                 role: CreateMessageRequestRole::User,
+///                 content: CreateMessageRequestContent::String("Hello".to_string()),
+///                 attachments: None,
+///                 metadata: None,
+///             },
+///         ]),
+///         ..Default::default()
+///     }),
+///     model: None,
+///     instructions: None,
+///     tools: None,
+///     tool_resources: None,
+///     metadata: None,
+///     temperature: None,
+///     top_p: None,
+///     stream: Some(true),
+///     max_prompt_tokens: None,
+///     max_completion_tokens: None,
+///     truncation_strategy: None,
+///     tool_choice: None,
+///     parallel_tool_calls: None,
+///     response_format: None,
+/// };
+/// ```
+///
+/// Streaming with Functions:
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateThreadAndRunRequest {
+///     assistant_id: "asst_abc123".to_string(),
+///     thread: Some(CreateThreadRequest {
+///         messages: Some(vec![
+///             CreateMessageRequest {
+///                 role: CreateMessageRequestRole::User,
+///                 content: CreateMessageRequestContent::String("What is the weather like in San Francisco?".to_string()),
+///                 attachments: None,
+///                 metadata: None,
+///             },
+///         ]),
+///         ..Default::default()
+///     }),
+///     model: None,
+///     instructions: None,
+///     tools: Some(vec![
+///         CreateThreadAndRunRequestItems::AssistantToolsFunction(AssistantToolsFunction {
+///             r#type: AssistantToolsFunctionType::Function,
+///             function: FunctionObject {
+///                 description: Some("Get the current weather in a given location".to_string()),
+///                 name: "get_current_weather".to_string(),
+///                 parameters: Some(serde_json::json!({"type":"object","properties":{"location":{"type":"string","description":"The city and state, e.g. San Francisco, CA"},"unit":{"type":"string","enum":["celsius","fahrenheit"]}},"required":["location"]})),
+///                 strict: None,
+///             },
+///         }),
+///     ]),
+///     tool_resources: None,
+///     metadata: None,
+///     temperature: None,
+///     top_p: None,
+///     stream: Some(true),
+///     max_prompt_tokens: None,
+///     max_completion_tokens: None,
+///     truncation_strategy: None,
+///     tool_choice: None,
+///     parallel_tool_calls: None,
+///     response_format: None,
+/// };
+/// ```
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateThreadAndRunRequest {
-	/// The ID of the [assistant](https://platform.openai.com/docs/api-reference/assistants) to use to execute this run.
+	/// The ID of the [assistant](https://platform.openai.com/docs/api-reference/assistants) to use to
+	/// execute this run.
 	pub assistant_id: String,
-	/// Override the default system message of the assistant. This is useful for modifying the behavior on a per-run basis.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub thread: Option<CreateThreadRequest>,
+	/// The ID of the [Model](https://platform.openai.com/docs/api-reference/models) to be used to execute
+	/// this run. If a value is provided here, it will override the model associated with the assistant. If
+	/// not, the model associated with the assistant will be used.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub model: Option<CreateThreadAndRunRequestModel>,
+	/// Override the default system message of the assistant. This is useful for modifying the behavior on a
+	/// per-run basis.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub instructions: Option<String>,
-	/// The maximum number of completion tokens that may be used over the course of the run. The run will make a best effort to use only the number of completion tokens specified, across multiple turns of the run. If the run exceeds the number of completion tokens specified, the run will end with status `incomplete`. See `incomplete_details` for more info.
+	/// Override the tools the assistant can use for this run. This is useful for modifying the behavior on
+	/// a per-run basis.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub max_completion_tokens: Option<i64>,
-	/// The maximum number of prompt tokens that may be used over the course of the run. The run will make a best effort to use only the number of prompt tokens specified, across multiple turns of the run. If the run exceeds the number of prompt tokens specified, the run will end with status `incomplete`. See `incomplete_details` for more info.
+	pub tools: Option<Vec<CreateThreadAndRunRequestItems>>,
+	/// A set of resources that are used by the assistant's tools. The resources are specific to the type of
+	/// tool. For example, the `code_interpreter` tool requires a list of file IDs, while the `file_search`
+	/// tool requires a list of vector store IDs.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub max_prompt_tokens: Option<i64>,
+	pub tool_resources: Option<CreateThreadAndRunRequestToolResources>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub metadata: Option<Metadata>,
-	/// The ID of the [Model](https://platform.openai.com/docs/api-reference/models) to be used to execute this run. If a value is provided here, it will override the model associated with the assistant. If not, the model associated with the assistant will be used.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub model: Option<CreateThreadAndRunRequestModel>,
+	/// What sampling temperature to use, between 0 and 2. Higher values like 0.8 will make the output more
+	/// random, while lower values like 0.2 will make it more focused and deterministic.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub parallel_tool_calls: Option<ParallelToolCalls>,
+	pub temperature: Option<f64>,
+	/// An alternative to sampling with temperature, called nucleus sampling, where the model considers the
+	/// results of the tokens with top_p probability mass. So 0.1 means only the tokens comprising the top
+	/// 10% probability mass are considered.
+	///
+	/// We generally recommend altering this or temperature but not both.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub response_format: Option<AssistantsApiResponseFormatOption>,
-	/// If `true`, returns a stream of events that happen during the Run as server-sent events, terminating when the Run enters a terminal state with a `data: [DONE]` message.
+	pub top_p: Option<f64>,
+	/// If `true`, returns a stream of events that happen during the Run as server-sent events, terminating
+	/// when the Run enters a terminal state with a `data: [DONE]` message.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub stream: Option<bool>,
-	/// What sampling temperature to use, between 0 and 2. Higher values like 0.8 will make the output more random, while lower values like 0.2 will make it more focused and deterministic.
+	/// The maximum number of prompt tokens that may be used over the course of the run. The run will make a
+	/// best effort to use only the number of prompt tokens specified, across multiple turns of the run. If
+	/// the run exceeds the number of prompt tokens specified, the run will end with status `incomplete`.
+	/// See `incomplete_details` for more info.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub temperature: Option<f64>,
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub thread: Option<CreateThreadRequest>,
+	pub max_prompt_tokens: Option<i64>,
+	/// The maximum number of completion tokens that may be used over the course of the run. The run will
+	/// make a best effort to use only the number of completion tokens specified, across multiple turns of
+	/// the run. If the run exceeds the number of completion tokens specified, the run will end with status
+	/// `incomplete`. See `incomplete_details` for more info.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub tool_choice: Option<CreateThreadAndRunRequestToolChoice>,
-	/// A set of resources that are used by the assistant's tools. The resources are specific to the type of tool. For example, the `code_interpreter` tool requires a list of file IDs, while the `file_search` tool requires a list of vector store IDs.
+	pub max_completion_tokens: Option<i64>,
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub tool_resources: Option<CreateThreadAndRunRequestToolResources>,
-	/// Override the tools the assistant can use for this run. This is useful for modifying the behavior on a per-run basis.
+	pub truncation_strategy: Option<CreateThreadAndRunRequestTruncationStrategy>,
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub tools: Option<Vec<CreateThreadAndRunRequestItems>>,
-	/// An alternative to sampling with temperature, called nucleus sampling, where the model considers the results of the tokens with top_p probability mass. So 0.1 means only the tokens comprising the top 10% probability mass are considered.
-	/// 
-	/// We generally recommend altering this or temperature but not both.
+	pub tool_choice: Option<CreateThreadAndRunRequestToolChoice>,
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub top_p: Option<f64>,
+	pub parallel_tool_calls: Option<ParallelToolCalls>,
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub truncation_strategy: Option<CreateThreadAndRunRequestTruncationStrategy>,
+	pub response_format: Option<AssistantsApiResponseFormatOption>,
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -226,7 +488,9 @@ pub enum CreateThreadAndRunRequestItems {
 	AssistantToolsFileSearch(AssistantToolsFileSearch),
 	AssistantToolsFunction(AssistantToolsFunction),
 }
-/// A set of resources that are used by the assistant's tools. The resources are specific to the type of tool. For example, the `code_interpreter` tool requires a list of file IDs, while the `file_search` tool requires a list of vector store IDs.
+/// A set of resources that are used by the assistant's tools. The resources are specific to the type of
+/// tool. For example, the `code_interpreter` tool requires a list of file IDs, while the `file_search`
+/// tool requires a list of vector store IDs.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct CreateThreadAndRunRequestToolResources {
 	#[serde(skip_serializing_if = "Option::is_none")]
@@ -236,38 +500,73 @@ pub struct CreateThreadAndRunRequestToolResources {
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct CreateThreadAndRunRequestToolResourcesCodeInterpreter {
-	/// A list of [file](https://platform.openai.com/docs/api-reference/files) IDs made available to the `code_interpreter` tool. There can be a maximum of 20 files associated with the tool.
+	/// A list of [file](https://platform.openai.com/docs/api-reference/files) IDs made available to the
+	/// `code_interpreter` tool. There can be a maximum of 20 files associated with the tool.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub file_ids: Option<Vec<String>>,
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct CreateThreadAndRunRequestToolResourcesFileSearch {
-	/// The ID of the [vector store](https://platform.openai.com/docs/api-reference/vector-stores/object) attached to this assistant. There can be a maximum of 1 vector store attached to the assistant.
+	/// The ID of the [vector store](https://platform.openai.com/docs/api-reference/vector-stores/object)
+	/// attached to this assistant. There can be a maximum of 1 vector store attached to the assistant.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub vector_store_ids: Option<Vec<String>>,
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateThreadAndRunRequestTruncationStrategy {
+	/// The truncation strategy to use for the thread. The default is `auto`. If set to `last_messages`, the
+	/// thread will be truncated to the n most recent messages in the thread. When set to `auto`, messages
+	/// in the middle of the thread will be dropped to fit the context length of the model,
+	/// `max_prompt_tokens`.
+	pub r#type: TruncationObjectType,
 	/// The number of most recent messages from the thread when constructing the context for the run.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub last_messages: Option<i64>,
-	/// The truncation strategy to use for the thread. The default is `auto`. If set to `last_messages`, the thread will be truncated to the n most recent messages in the thread. When set to `auto`, messages in the middle of the thread will be dropped to fit the context length of the model, `max_prompt_tokens`.
-	pub r#type: TruncationObjectType,
 }
-/// Options to create a new thread. If no thread is provided when running a 
+/// Options to create a new thread. If no thread is provided when running a
 /// request, an empty thread will be created.
+///
+/// # Examples
+///
+/// Messages:
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateThreadRequest {
+///     messages: Some(vec![
+///         CreateMessageRequest {
+///             role: CreateMessageRequestRole::User,
+///             content: CreateMessageRequestContent::String("Hello, what is AI?".to_string()),
+///             attachments: None,
+///             metadata: None,
+///         },
+///         CreateMessageRequest {
+///             role: CreateMessageRequestRole::User,
+///             content: CreateMessageRequestContent::String("How does AI work? Explain it in simple terms.".to_string()),
+///             attachments: None,
+///             metadata: None,
+///         },
+///     ]),
+///     ..Default::default()
+/// };
+/// ```
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct CreateThreadRequest {
-	/// A list of [messages](https://platform.openai.com/docs/api-reference/messages) to start the thread with.
+	/// A list of [messages](https://platform.openai.com/docs/api-reference/messages) to start the thread
+	/// with.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub messages: Option<Vec<CreateMessageRequest>>,
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub metadata: Option<Metadata>,
-	/// A set of resources that are made available to the assistant's tools in this thread. The resources are specific to the type of tool. For example, the `code_interpreter` tool requires a list of file IDs, while the `file_search` tool requires a list of vector store IDs.
+	/// A set of resources that are made available to the assistant's tools in this thread. The resources
+	/// are specific to the type of tool. For example, the `code_interpreter` tool requires a list of file
+	/// IDs, while the `file_search` tool requires a list of vector store IDs.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub tool_resources: Option<CreateThreadRequestToolResources>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub metadata: Option<Metadata>,
 }
-/// A set of resources that are made available to the assistant's tools in this thread. The resources are specific to the type of tool. For example, the `code_interpreter` tool requires a list of file IDs, while the `file_search` tool requires a list of vector store IDs.
+/// A set of resources that are made available to the assistant's tools in this thread. The resources
+/// are specific to the type of tool. For example, the `code_interpreter` tool requires a list of file
+/// IDs, while the `file_search` tool requires a list of vector store IDs.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct CreateThreadRequestToolResources {
 	#[serde(skip_serializing_if = "Option::is_none")]
@@ -277,7 +576,8 @@ pub struct CreateThreadRequestToolResources {
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct CreateThreadRequestToolResourcesCodeInterpreter {
-	/// A list of [file](https://platform.openai.com/docs/api-reference/files) IDs made available to the `code_interpreter` tool. There can be a maximum of 20 files associated with the tool.
+	/// A list of [file](https://platform.openai.com/docs/api-reference/files) IDs made available to the
+	/// `code_interpreter` tool. There can be a maximum of 20 files associated with the tool.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub file_ids: Option<Vec<String>>,
 }
@@ -285,23 +585,77 @@ pub struct CreateThreadRequestToolResourcesCodeInterpreter {
 #[serde(rename_all = "lowercase")]
 #[serde(untagged)]
 pub enum CreateThreadRequestToolResourcesFileSearch {
+	Object(serde_json::Value),
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DeleteMessageResponse {
+	pub id: String,
+	pub deleted: bool,
+	pub object: DeleteMessageResponseObject,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DeleteMessageResponseObject {
+	#[serde(rename = "thread.message.deleted")]
+	ThreadMessageDeleted,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DeleteThreadResponse {
+	pub id: String,
+	pub deleted: bool,
+	pub object: DeleteThreadResponseObject,
 }
-/// References an image [File](https://platform.openai.com/docs/api-reference/files) in the content of a message.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DeleteThreadResponseObject {
+	#[serde(rename = "thread.deleted")]
+	ThreadDeleted,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ListMessagesResponse {
+	pub object: String,
+	pub data: Vec<MessageObject>,
+	pub first_id: String,
+	pub last_id: String,
+	pub has_more: bool,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ListRunStepsResponse {
+	pub object: String,
+	pub data: Vec<RunStepObject>,
+	pub first_id: String,
+	pub last_id: String,
+	pub has_more: bool,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ListRunsResponse {
+	pub object: String,
+	pub data: Vec<RunObject>,
+	pub first_id: String,
+	pub last_id: String,
+	pub has_more: bool,
+}
+/// References an image [File](https://platform.openai.com/docs/api-reference/files) in the content of a
+/// message.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct MessageContentImageFileObject {
-	pub image_file: MessageContentImageFileObjectImageFile,
 	/// Always `image_file`.
 	pub r#type: MessageContentImageFileObjectType,
+	pub image_file: MessageContentImageFileObjectImageFile,
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct MessageContentImageFileObjectImageFile {
-	/// Specifies the detail level of the image if specified by the user. `low` uses fewer tokens, you can opt in to high resolution using `high`.
+	/// The [File](https://platform.openai.com/docs/api-reference/files) ID of the image in the message
+	/// content. Set `purpose="vision"` when uploading the File if you need to later display the file
+	/// content.
+	pub file_id: String,
+	/// Specifies the detail level of the image if specified by the user. `low` uses fewer tokens, you can
+	/// opt in to high resolution using `high`.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub detail: Option<MessageContentImageFileObjectImageFileDetail>,
-	/// The [File](https://platform.openai.com/docs/api-reference/files) ID of the image in the message content. Set `purpose="vision"` when uploading the File if you need to later display the file content.
-	pub file_id: String,
 }
-/// Specifies the detail level of the image if specified by the user. `low` uses fewer tokens, you can opt in to high resolution using `high`.
+/// Specifies the detail level of the image if specified by the user. `low` uses fewer tokens, you can
+/// opt in to high resolution using `high`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum MessageContentImageFileObjectImageFileDetail {
@@ -319,19 +673,21 @@ pub enum MessageContentImageFileObjectType {
 /// References an image URL in the content of a message.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct MessageContentImageUrlObject {
-	pub image_url: MessageContentImageUrlObjectImageUrl,
 	/// The type of the content part.
 	pub r#type: MessageContentImageUrlObjectType,
+	pub image_url: MessageContentImageUrlObjectImageUrl,
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct MessageContentImageUrlObjectImageUrl {
-	/// Specifies the detail level of the image. `low` uses fewer tokens, you can opt in to high resolution using `high`. Default value is `auto`
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub detail: Option<MessageContentImageUrlObjectImageUrlDetail>,
 	/// The external URL of the image, must be a supported image types: jpeg, jpg, png, gif, webp.
 	pub url: String,
+	/// Specifies the detail level of the image. `low` uses fewer tokens, you can opt in to high resolution
+	/// using `high`. Default value is `auto`
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub detail: Option<MessageContentImageUrlObjectImageUrlDetail>,
 }
-/// Specifies the detail level of the image. `low` uses fewer tokens, you can opt in to high resolution using `high`. Default value is `auto`
+/// Specifies the detail level of the image. `low` uses fewer tokens, you can opt in to high resolution
+/// using `high`. Default value is `auto`
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum MessageContentImageUrlObjectImageUrlDetail {
@@ -346,13 +702,205 @@ pub enum MessageContentImageUrlObjectType {
 	#[serde(rename = "image_url")]
 	ImageUrl,
 }
+/// The refusal content generated by the assistant.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct MessageContentRefusalObject {
+	/// Always `refusal`.
+	pub r#type: MessageContentRefusalObjectType,
+	pub refusal: String,
+}
+/// Always `refusal`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageContentRefusalObjectType {
+	Refusal,
+}
+/// A citation within the message that points to a specific quote from a specific File associated with
+/// the assistant or the message. Generated when the assistant uses the "file_search" tool to search
+/// files.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct MessageContentTextAnnotationsFileCitationObject {
+	/// Always `file_citation`.
+	pub r#type: MessageContentTextAnnotationsFileCitationObjectType,
+	/// The text in the message content that needs to be replaced.
+	pub text: String,
+	pub file_citation: MessageContentTextAnnotationsFileCitationObjectFileCitation,
+	pub start_index: i64,
+	pub end_index: i64,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct MessageContentTextAnnotationsFileCitationObjectFileCitation {
+	/// The ID of the specific File the citation is from.
+	pub file_id: String,
+}
+/// Always `file_citation`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageContentTextAnnotationsFileCitationObjectType {
+	#[serde(rename = "file_citation")]
+	FileCitation,
+}
+/// A URL for the file that's generated when the assistant used the `code_interpreter` tool to generate
+/// a file.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct MessageContentTextAnnotationsFilePathObject {
+	/// Always `file_path`.
+	pub r#type: MessageContentTextAnnotationsFilePathObjectType,
+	/// The text in the message content that needs to be replaced.
+	pub text: String,
+	pub file_path: MessageContentTextAnnotationsFilePathObjectFilePath,
+	pub start_index: i64,
+	pub end_index: i64,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct MessageContentTextAnnotationsFilePathObjectFilePath {
+	/// The ID of the file that was generated.
+	pub file_id: String,
+}
+/// Always `file_path`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageContentTextAnnotationsFilePathObjectType {
+	#[serde(rename = "file_path")]
+	FilePath,
+}
+/// The text content that is part of a message.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct MessageContentTextObject {
+	/// Always `text`.
+	pub r#type: MessageContentTextObjectType,
+	pub text: MessageContentTextObjectText,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct MessageContentTextObjectText {
+	/// The data that makes up the text.
+	pub value: String,
+	pub annotations: Vec<MessageContentTextObjectTextItems>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(untagged)]
+pub enum MessageContentTextObjectTextItems {
+	MessageContentTextAnnotationsFileCitationObject(MessageContentTextAnnotationsFileCitationObject),
+	MessageContentTextAnnotationsFilePathObject(MessageContentTextAnnotationsFilePathObject),
+}
+/// Always `text`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageContentTextObjectType {
+	Text,
+}
+/// Represents a message within a [thread](https://platform.openai.com/docs/api-reference/threads).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct MessageObject {
+	/// The identifier, which can be referenced in API endpoints.
+	pub id: String,
+	/// The object type, which is always `thread.message`.
+	pub object: MessageObjectObject,
+	/// The Unix timestamp (in seconds) for when the message was created.
+	pub created_at: i64,
+	/// The [thread](https://platform.openai.com/docs/api-reference/threads) ID that this message belongs
+	/// to.
+	pub thread_id: String,
+	/// The status of the message, which can be either `in_progress`, `incomplete`, or `completed`.
+	pub status: MessageObjectStatus,
+	/// On an incomplete message, details about why the message is incomplete.
+	pub incomplete_details: Option<MessageObjectIncompleteDetails>,
+	/// The Unix timestamp (in seconds) for when the message was completed.
+	pub completed_at: Option<i64>,
+	/// The Unix timestamp (in seconds) for when the message was marked as incomplete.
+	pub incomplete_at: Option<i64>,
+	/// The entity that produced the message. One of `user` or `assistant`.
+	pub role: MessageObjectRole,
+	/// The content of the message in array of text and/or images.
+	pub content: Vec<MessageObjectItems>,
+	/// If applicable, the ID of the [assistant](https://platform.openai.com/docs/api-reference/assistants)
+	/// that authored this message.
+	pub assistant_id: Option<String>,
+	/// The ID of the [run](https://platform.openai.com/docs/api-reference/runs) associated with the
+	/// creation of this message. Value is `null` when messages are created manually using the create
+	/// message or create thread endpoints.
+	pub run_id: Option<String>,
+	/// A list of files attached to the message, and the tools they were added to.
+	pub attachments: Option<Vec<MessageObjectAttachmentsItem>>,
+	pub metadata: Metadata,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct MessageObjectAttachmentsItem {
+	/// The ID of the file to attach to the message.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub file_id: Option<String>,
+	/// The tools to add this file to.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub tools: Option<Vec<MessageObjectAttachmentsItemItems>>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(untagged)]
+pub enum MessageObjectAttachmentsItemItems {
+	AssistantToolsCode(AssistantToolsCode),
+	AssistantToolsFileSearchTypeOnly(AssistantToolsFileSearchTypeOnly),
+}
+/// On an incomplete message, details about why the message is incomplete.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct MessageObjectIncompleteDetails {
+	/// The reason the message is incomplete.
+	pub reason: MessageObjectIncompleteDetailsReason,
+}
+/// The reason the message is incomplete.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageObjectIncompleteDetailsReason {
+	#[serde(rename = "content_filter")]
+	ContentFilter,
+	#[serde(rename = "max_tokens")]
+	MaxTokens,
+	#[serde(rename = "run_cancelled")]
+	RunCancelled,
+	#[serde(rename = "run_expired")]
+	RunExpired,
+	#[serde(rename = "run_failed")]
+	RunFailed,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(untagged)]
+pub enum MessageObjectItems {
+	MessageContentImageFileObject(MessageContentImageFileObject),
+	MessageContentImageUrlObject(MessageContentImageUrlObject),
+	MessageContentTextObject(MessageContentTextObject),
+	MessageContentRefusalObject(MessageContentRefusalObject),
+}
+/// The object type, which is always `thread.message`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageObjectObject {
+	#[serde(rename = "thread.message")]
+	ThreadMessage,
+}
+/// The entity that produced the message. One of `user` or `assistant`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageObjectRole {
+	User,
+	Assistant,
+}
+/// The status of the message, which can be either `in_progress`, `incomplete`, or `completed`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageObjectStatus {
+	#[serde(rename = "in_progress")]
+	InProgress,
+	Incomplete,
+	Completed,
+}
 /// The text content that is part of a message.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct MessageRequestContentTextObject {
-	/// Text content to be sent to the model
-	pub text: String,
 	/// Always `text`.
 	pub r#type: MessageRequestContentTextObjectType,
+	/// Text content to be sent to the model
+	pub text: String,
 }
 /// Always `text`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -360,25 +908,65 @@ pub struct MessageRequestContentTextObject {
 pub enum MessageRequestContentTextObjectType {
 	Text,
 }
+/// # Examples
+///
+/// ```no_run
+/// # use openai_types::*;
+/// # use std::collections::HashMap;
+/// let request = ModifyMessageRequest {
+///     metadata: Some(HashMap::from([
+///         ("modified".to_string(), "true".to_string()),
+///         ("user".to_string(), "abc123".to_string()),
+///     ])),
+/// };
+/// ```
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct ModifyMessageRequest {
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub metadata: Option<Metadata>,
 }
+/// # Examples
+///
+/// ```no_run
+/// # use openai_types::*;
+/// # use std::collections::HashMap;
+/// let request = ModifyRunRequest {
+///     metadata: Some(HashMap::from([
+///         ("user_id".to_string(), "user_abc123".to_string()),
+///     ])),
+/// };
+/// ```
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct ModifyRunRequest {
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub metadata: Option<Metadata>,
 }
+/// # Examples
+///
+/// ```no_run
+/// # use openai_types::*;
+/// # use std::collections::HashMap;
+/// let request = ModifyThreadRequest {
+///     metadata: Some(HashMap::from([
+///         ("modified".to_string(), "true".to_string()),
+///         ("user".to_string(), "abc123".to_string()),
+///     ])),
+///     ..Default::default()
+/// };
+/// ```
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct ModifyThreadRequest {
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub metadata: Option<Metadata>,
-	/// A set of resources that are made available to the assistant's tools in this thread. The resources are specific to the type of tool. For example, the `code_interpreter` tool requires a list of file IDs, while the `file_search` tool requires a list of vector store IDs.
+	/// A set of resources that are made available to the assistant's tools in this thread. The resources
+	/// are specific to the type of tool. For example, the `code_interpreter` tool requires a list of file
+	/// IDs, while the `file_search` tool requires a list of vector store IDs.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub tool_resources: Option<ModifyThreadRequestToolResources>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub metadata: Option<Metadata>,
 }
-/// A set of resources that are made available to the assistant's tools in this thread. The resources are specific to the type of tool. For example, the `code_interpreter` tool requires a list of file IDs, while the `file_search` tool requires a list of vector store IDs.
+/// A set of resources that are made available to the assistant's tools in this thread. The resources
+/// are specific to the type of tool. For example, the `code_interpreter` tool requires a list of file
+/// IDs, while the `file_search` tool requires a list of vector store IDs.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct ModifyThreadRequestToolResources {
 	#[serde(skip_serializing_if = "Option::is_none")]
@@ -388,43 +976,581 @@ pub struct ModifyThreadRequestToolResources {
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct ModifyThreadRequestToolResourcesCodeInterpreter {
-	/// A list of [file](https://platform.openai.com/docs/api-reference/files) IDs made available to the `code_interpreter` tool. There can be a maximum of 20 files associated with the tool.
+	/// A list of [file](https://platform.openai.com/docs/api-reference/files) IDs made available to the
+	/// `code_interpreter` tool. There can be a maximum of 20 files associated with the tool.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub file_ids: Option<Vec<String>>,
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct ModifyThreadRequestToolResourcesFileSearch {
-	/// The [vector store](https://platform.openai.com/docs/api-reference/vector-stores/object) attached to this thread. There can be a maximum of 1 vector store attached to the thread.
+	/// The [vector store](https://platform.openai.com/docs/api-reference/vector-stores/object) attached to
+	/// this thread. There can be a maximum of 1 vector store attached to the thread.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub vector_store_ids: Option<Vec<String>>,
 }
+/// Usage statistics related to the run. This value will be `null` if the run is not in a terminal state
+/// (i.e. `in_progress`, `queued`, etc.).
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-pub struct SubmitToolOutputsRunRequest {
-	/// If `true`, returns a stream of events that happen during the Run as server-sent events, terminating when the Run enters a terminal state with a `data: [DONE]` message.
+pub struct RunCompletionUsage {
+	/// Number of completion tokens used over the course of the run.
+	pub completion_tokens: i64,
+	/// Number of prompt tokens used over the course of the run.
+	pub prompt_tokens: i64,
+	/// Total number of tokens used (prompt + completion).
+	pub total_tokens: i64,
+}
+/// Represents an execution run on a [thread](https://platform.openai.com/docs/api-reference/threads).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RunObject {
+	/// The identifier, which can be referenced in API endpoints.
+	pub id: String,
+	/// The object type, which is always `thread.run`.
+	pub object: RunObjectObject,
+	/// The Unix timestamp (in seconds) for when the run was created.
+	pub created_at: i64,
+	/// The ID of the [thread](https://platform.openai.com/docs/api-reference/threads) that was executed on
+	/// as a part of this run.
+	pub thread_id: String,
+	/// The ID of the [assistant](https://platform.openai.com/docs/api-reference/assistants) used for
+	/// execution of this run.
+	pub assistant_id: String,
+	/// The status of the run, which can be either `queued`, `in_progress`, `requires_action`, `cancelling`,
+	/// `cancelled`, `failed`, `completed`, `incomplete`, or `expired`.
+	pub status: RunObjectStatus,
+	/// Details on the action required to continue the run. Will be `null` if no action is required.
+	pub required_action: Option<RunObjectRequiredAction>,
+	/// The last error associated with this run. Will be `null` if there are no errors.
+	pub last_error: Option<RunObjectLastError>,
+	/// The Unix timestamp (in seconds) for when the run will expire.
+	pub expires_at: Option<i64>,
+	/// The Unix timestamp (in seconds) for when the run was started.
+	pub started_at: Option<i64>,
+	/// The Unix timestamp (in seconds) for when the run was cancelled.
+	pub cancelled_at: Option<i64>,
+	/// The Unix timestamp (in seconds) for when the run failed.
+	pub failed_at: Option<i64>,
+	/// The Unix timestamp (in seconds) for when the run was completed.
+	pub completed_at: Option<i64>,
+	/// Details on why the run is incomplete. Will be `null` if the run is not incomplete.
+	pub incomplete_details: Option<RunObjectIncompleteDetails>,
+	/// The model that the [assistant](https://platform.openai.com/docs/api-reference/assistants) used for
+	/// this run.
+	pub model: String,
+	/// The instructions that the [assistant](https://platform.openai.com/docs/api-reference/assistants)
+	/// used for this run.
+	pub instructions: String,
+	/// The list of tools that the [assistant](https://platform.openai.com/docs/api-reference/assistants)
+	/// used for this run.
+	pub tools: Vec<RunObjectItems>,
+	pub metadata: Metadata,
+	pub usage: RunCompletionUsage,
+	/// The sampling temperature used for this run. If not set, defaults to 1.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub stream: Option<bool>,
+	pub temperature: Option<f64>,
+	/// The nucleus sampling value used for this run. If not set, defaults to 1.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub top_p: Option<f64>,
+	/// The maximum number of prompt tokens specified to have been used over the course of the run.
+	pub max_prompt_tokens: Option<i64>,
+	/// The maximum number of completion tokens specified to have been used over the course of the run.
+	pub max_completion_tokens: Option<i64>,
+	pub truncation_strategy: RunObjectTruncationStrategy,
+	pub tool_choice: RunObjectToolChoice,
+	pub parallel_tool_calls: ParallelToolCalls,
+	pub response_format: Option<AssistantsApiResponseFormatOption>,
+}
+/// Details on why the run is incomplete. Will be `null` if the run is not incomplete.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RunObjectIncompleteDetails {
+	/// The reason why the run is incomplete. This will point to which specific token limit was reached over
+	/// the course of the run.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub reason: Option<RunObjectIncompleteDetailsReason>,
+}
+/// The reason why the run is incomplete. This will point to which specific token limit was reached over
+/// the course of the run.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RunObjectIncompleteDetailsReason {
+	#[serde(rename = "max_completion_tokens")]
+	MaxCompletionTokens,
+	#[serde(rename = "max_prompt_tokens")]
+	MaxPromptTokens,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(untagged)]
+pub enum RunObjectItems {
+	AssistantToolsCode(AssistantToolsCode),
+	AssistantToolsFileSearch(AssistantToolsFileSearch),
+	AssistantToolsFunction(AssistantToolsFunction),
+}
+/// The last error associated with this run. Will be `null` if there are no errors.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RunObjectLastError {
+	/// One of `server_error`, `rate_limit_exceeded`, or `invalid_prompt`.
+	pub code: RunObjectLastErrorCode,
+	/// A human-readable description of the error.
+	pub message: String,
+}
+/// One of `server_error`, `rate_limit_exceeded`, or `invalid_prompt`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RunObjectLastErrorCode {
+	#[serde(rename = "server_error")]
+	ServerError,
+	#[serde(rename = "rate_limit_exceeded")]
+	RateLimitExceeded,
+	#[serde(rename = "invalid_prompt")]
+	InvalidPrompt,
+}
+/// The object type, which is always `thread.run`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RunObjectObject {
+	#[serde(rename = "thread.run")]
+	ThreadRun,
+}
+/// Details on the action required to continue the run. Will be `null` if no action is required.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RunObjectRequiredAction {
+	/// For now, this is always `submit_tool_outputs`.
+	pub r#type: RunObjectRequiredActionType,
+	/// Details on the tool outputs needed for this run to continue.
+	pub submit_tool_outputs: RunObjectRequiredActionSubmitToolOutputs,
+}
+/// Details on the tool outputs needed for this run to continue.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RunObjectRequiredActionSubmitToolOutputs {
+	/// A list of the relevant tool calls.
+	pub tool_calls: Vec<RunToolCallObject>,
+}
+/// For now, this is always `submit_tool_outputs`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RunObjectRequiredActionType {
+	#[serde(rename = "submit_tool_outputs")]
+	SubmitToolOutputs,
+}
+/// The status of the run, which can be either `queued`, `in_progress`, `requires_action`, `cancelling`,
+/// `cancelled`, `failed`, `completed`, `incomplete`, or `expired`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RunObjectStatus {
+	Queued,
+	#[serde(rename = "in_progress")]
+	InProgress,
+	#[serde(rename = "requires_action")]
+	RequiresAction,
+	Cancelling,
+	Cancelled,
+	Failed,
+	Completed,
+	Incomplete,
+	Expired,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RunObjectTruncationStrategy {
+	/// The truncation strategy to use for the thread. The default is `auto`. If set to `last_messages`, the
+	/// thread will be truncated to the n most recent messages in the thread. When set to `auto`, messages
+	/// in the middle of the thread will be dropped to fit the context length of the model,
+	/// `max_prompt_tokens`.
+	pub r#type: TruncationObjectType,
+	/// The number of most recent messages from the thread when constructing the context for the run.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub last_messages: Option<i64>,
+}
+/// Usage statistics related to the run step. This value will be `null` while the run step's status is
+/// `in_progress`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RunStepCompletionUsage {
+	/// Number of completion tokens used over the course of the run step.
+	pub completion_tokens: i64,
+	/// Number of prompt tokens used over the course of the run step.
+	pub prompt_tokens: i64,
+	/// Total number of tokens used (prompt + completion).
+	pub total_tokens: i64,
+}
+/// Details of the message creation by the run step.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RunStepDetailsMessageCreationObject {
+	/// Always `message_creation`.
+	pub r#type: RunStepDetailsMessageCreationObjectType,
+	pub message_creation: RunStepDetailsMessageCreationObjectMessageCreation,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RunStepDetailsMessageCreationObjectMessageCreation {
+	/// The ID of the message that was created by this run step.
+	pub message_id: String,
+}
+/// Always `message_creation`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RunStepDetailsMessageCreationObjectType {
+	#[serde(rename = "message_creation")]
+	MessageCreation,
+}
+/// Details of the Code Interpreter tool call the run step was involved in.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RunStepDetailsToolCallsCodeObject {
+	/// The ID of the tool call.
+	pub id: String,
+	/// The type of tool call. This is always going to be `code_interpreter` for this type of tool call.
+	pub r#type: RunStepDetailsToolCallsCodeObjectType,
+	/// The Code Interpreter tool call definition.
+	pub code_interpreter: RunStepDetailsToolCallsCodeObjectCodeInterpreter,
+}
+/// The Code Interpreter tool call definition.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RunStepDetailsToolCallsCodeObjectCodeInterpreter {
+	/// The input to the Code Interpreter tool call.
+	pub input: String,
+	/// The outputs from the Code Interpreter tool call. Code Interpreter can output one or more items,
+	/// including text (`logs`) or images (`image`). Each of these are represented by a different object
+	/// type.
+	pub outputs: Vec<RunStepDetailsToolCallsCodeObjectCodeInterpreterItems>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(untagged)]
+pub enum RunStepDetailsToolCallsCodeObjectCodeInterpreterItems {
+	RunStepDetailsToolCallsCodeOutputLogsObject(RunStepDetailsToolCallsCodeOutputLogsObject),
+	RunStepDetailsToolCallsCodeOutputImageObject(RunStepDetailsToolCallsCodeOutputImageObject),
+}
+/// The type of tool call. This is always going to be `code_interpreter` for this type of tool call.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RunStepDetailsToolCallsCodeObjectType {
+	#[serde(rename = "code_interpreter")]
+	CodeInterpreter,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RunStepDetailsToolCallsCodeOutputImageObject {
+	/// Always `image`.
+	pub r#type: RunStepDetailsToolCallsCodeOutputImageObjectType,
+	pub image: RunStepDetailsToolCallsCodeOutputImageObjectImage,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RunStepDetailsToolCallsCodeOutputImageObjectImage {
+	/// The [file](https://platform.openai.com/docs/api-reference/files) ID of the image.
+	pub file_id: String,
+}
+/// Always `image`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RunStepDetailsToolCallsCodeOutputImageObjectType {
+	Image,
+}
+/// Text output from the Code Interpreter tool call as part of a run step.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RunStepDetailsToolCallsCodeOutputLogsObject {
+	/// Always `logs`.
+	pub r#type: RunStepDetailsToolCallsCodeOutputLogsObjectType,
+	/// The text output from the Code Interpreter tool call.
+	pub logs: String,
+}
+/// Always `logs`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RunStepDetailsToolCallsCodeOutputLogsObjectType {
+	Logs,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RunStepDetailsToolCallsFileSearchObject {
+	/// The ID of the tool call object.
+	pub id: String,
+	/// The type of tool call. This is always going to be `file_search` for this type of tool call.
+	pub r#type: RunStepDetailsToolCallsFileSearchObjectType,
+	/// For now, this is always going to be an empty object.
+	pub file_search: serde_json::Value,
+}
+/// The type of tool call. This is always going to be `file_search` for this type of tool call.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RunStepDetailsToolCallsFileSearchObjectType {
+	#[serde(rename = "file_search")]
+	FileSearch,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RunStepDetailsToolCallsFunctionObject {
+	/// The ID of the tool call object.
+	pub id: String,
+	/// The type of tool call. This is always going to be `function` for this type of tool call.
+	pub r#type: RunStepDetailsToolCallsFunctionObjectType,
+	/// The definition of the function that was called.
+	pub function: RunStepDetailsToolCallsFunctionObjectFunction,
+}
+/// The definition of the function that was called.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RunStepDetailsToolCallsFunctionObjectFunction {
+	/// The name of the function.
+	pub name: String,
+	/// The arguments passed to the function.
+	pub arguments: String,
+	/// The output of the function. This will be `null` if the outputs have not been
+	/// [submitted](https://platform.openai.com/docs/api-reference/runs/submitToolOutputs) yet.
+	pub output: Option<String>,
+}
+/// The type of tool call. This is always going to be `function` for this type of tool call.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RunStepDetailsToolCallsFunctionObjectType {
+	Function,
+}
+/// Details of the tool call.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RunStepDetailsToolCallsObject {
+	/// Always `tool_calls`.
+	pub r#type: RunStepDetailsToolCallsObjectType,
+	/// An array of tool calls the run step was involved in. These can be associated with one of three types
+	/// of tools: `code_interpreter`, `file_search`, or `function`.
+	pub tool_calls: Vec<RunStepDetailsToolCallsObjectItems>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(untagged)]
+pub enum RunStepDetailsToolCallsObjectItems {
+	RunStepDetailsToolCallsCodeObject(RunStepDetailsToolCallsCodeObject),
+	RunStepDetailsToolCallsFileSearchObject(RunStepDetailsToolCallsFileSearchObject),
+	RunStepDetailsToolCallsFunctionObject(RunStepDetailsToolCallsFunctionObject),
+}
+/// Always `tool_calls`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RunStepDetailsToolCallsObjectType {
+	#[serde(rename = "tool_calls")]
+	ToolCalls,
+}
+/// Represents a step in execution of a run.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RunStepObject {
+	/// The identifier of the run step, which can be referenced in API endpoints.
+	pub id: String,
+	/// The object type, which is always `thread.run.step`.
+	pub object: RunStepObjectObject,
+	/// The Unix timestamp (in seconds) for when the run step was created.
+	pub created_at: i64,
+	/// The ID of the [assistant](https://platform.openai.com/docs/api-reference/assistants) associated with
+	/// the run step.
+	pub assistant_id: String,
+	/// The ID of the [thread](https://platform.openai.com/docs/api-reference/threads) that was run.
+	pub thread_id: String,
+	/// The ID of the [run](https://platform.openai.com/docs/api-reference/runs) that this run step is a
+	/// part of.
+	pub run_id: String,
+	/// The type of run step, which can be either `message_creation` or `tool_calls`.
+	pub r#type: RunStepObjectType,
+	/// The status of the run step, which can be either `in_progress`, `cancelled`, `failed`, `completed`,
+	/// or `expired`.
+	pub status: RunStepObjectStatus,
+	/// The details of the run step.
+	pub step_details: RunStepObjectStepDetails,
+	/// The last error associated with this run step. Will be `null` if there are no errors.
+	pub last_error: Option<RunStepObjectLastError>,
+	/// The Unix timestamp (in seconds) for when the run step expired. A step is considered expired if the
+	/// parent run is expired.
+	pub expired_at: Option<i64>,
+	/// The Unix timestamp (in seconds) for when the run step was cancelled.
+	pub cancelled_at: Option<i64>,
+	/// The Unix timestamp (in seconds) for when the run step failed.
+	pub failed_at: Option<i64>,
+	/// The Unix timestamp (in seconds) for when the run step completed.
+	pub completed_at: Option<i64>,
+	pub metadata: Metadata,
+	pub usage: RunStepCompletionUsage,
+}
+/// The last error associated with this run step. Will be `null` if there are no errors.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RunStepObjectLastError {
+	/// One of `server_error` or `rate_limit_exceeded`.
+	pub code: RunStepObjectLastErrorCode,
+	/// A human-readable description of the error.
+	pub message: String,
+}
+/// One of `server_error` or `rate_limit_exceeded`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RunStepObjectLastErrorCode {
+	#[serde(rename = "server_error")]
+	ServerError,
+	#[serde(rename = "rate_limit_exceeded")]
+	RateLimitExceeded,
+}
+/// The object type, which is always `thread.run.step`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RunStepObjectObject {
+	#[serde(rename = "thread.run.step")]
+	ThreadRunStep,
+}
+/// The status of the run step, which can be either `in_progress`, `cancelled`, `failed`, `completed`,
+/// or `expired`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RunStepObjectStatus {
+	#[serde(rename = "in_progress")]
+	InProgress,
+	Cancelled,
+	Failed,
+	Completed,
+	Expired,
+}
+/// The details of the run step.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(untagged)]
+pub enum RunStepObjectStepDetails {
+	RunStepDetailsMessageCreationObject(RunStepDetailsMessageCreationObject),
+	RunStepDetailsToolCallsObject(RunStepDetailsToolCallsObject),
+}
+/// The type of run step, which can be either `message_creation` or `tool_calls`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RunStepObjectType {
+	#[serde(rename = "message_creation")]
+	MessageCreation,
+	#[serde(rename = "tool_calls")]
+	ToolCalls,
+}
+/// Tool call objects
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RunToolCallObject {
+	/// The ID of the tool call. This ID must be referenced when you submit the tool outputs in using the
+	/// [Submit tool outputs to run](https://platform.openai.com/docs/api-reference/runs/submitToolOutputs)
+	/// endpoint.
+	pub id: String,
+	/// The type of tool call the output is required for. For now, this is always `function`.
+	pub r#type: RunToolCallObjectType,
+	/// The function definition.
+	pub function: RunToolCallObjectFunction,
+}
+/// The function definition.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RunToolCallObjectFunction {
+	/// The name of the function.
+	pub name: String,
+	/// The arguments that the model expects you to pass to the function.
+	pub arguments: String,
+}
+/// The type of tool call the output is required for. For now, this is always `function`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RunToolCallObjectType {
+	Function,
+}
+/// # Examples
+///
+/// Default:
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = SubmitToolOutputsRunRequest {
+///     tool_outputs: vec![
+///         SubmitToolOutputsRunRequestToolOutputsItem {
+///             tool_call_id: Some("call_001".to_string()),
+///             output: Some("70 degrees and sunny.".to_string()),
+///         },
+///     ],
+///     stream: None,
+/// };
+/// ```
+///
+/// Streaming:
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = SubmitToolOutputsRunRequest {
+///     tool_outputs: vec![
+///         SubmitToolOutputsRunRequestToolOutputsItem {
+///             tool_call_id: Some("call_001".to_string()),
+///             output: Some("70 degrees and sunny.".to_string()),
+///         },
+///     ],
+///     stream: Some(true),
+/// };
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SubmitToolOutputsRunRequest {
 	/// A list of tools for which the outputs are being submitted.
 	pub tool_outputs: Vec<SubmitToolOutputsRunRequestToolOutputsItem>,
+	/// If `true`, returns a stream of events that happen during the Run as server-sent events, terminating
+	/// when the Run enters a terminal state with a `data: [DONE]` message.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub stream: Option<bool>,
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct SubmitToolOutputsRunRequestToolOutputsItem {
+	/// The ID of the tool call in the `required_action` object within the run object the output is being
+	/// submitted for.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub tool_call_id: Option<String>,
 	/// The output of the tool call to be submitted to continue the run.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub output: Option<String>,
-	/// The ID of the tool call in the `required_action` object within the run object the output is being submitted for.
+}
+/// Represents a thread that contains
+/// [messages](https://platform.openai.com/docs/api-reference/messages).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ThreadObject {
+	/// The identifier, which can be referenced in API endpoints.
+	pub id: String,
+	/// The object type, which is always `thread`.
+	pub object: ThreadObjectObject,
+	/// The Unix timestamp (in seconds) for when the thread was created.
+	pub created_at: i64,
+	/// A set of resources that are made available to the assistant's tools in this thread. The resources
+	/// are specific to the type of tool. For example, the `code_interpreter` tool requires a list of file
+	/// IDs, while the `file_search` tool requires a list of vector store IDs.
+	pub tool_resources: Option<ThreadObjectToolResources>,
+	pub metadata: Metadata,
+}
+/// The object type, which is always `thread`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ThreadObjectObject {
+	Thread,
+}
+/// A set of resources that are made available to the assistant's tools in this thread. The resources
+/// are specific to the type of tool. For example, the `code_interpreter` tool requires a list of file
+/// IDs, while the `file_search` tool requires a list of vector store IDs.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct ThreadObjectToolResources {
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub tool_call_id: Option<String>,
+	pub code_interpreter: Option<ThreadObjectToolResourcesCodeInterpreter>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub file_search: Option<ThreadObjectToolResourcesFileSearch>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct ThreadObjectToolResourcesCodeInterpreter {
+	/// A list of [file](https://platform.openai.com/docs/api-reference/files) IDs made available to the
+	/// `code_interpreter` tool. There can be a maximum of 20 files associated with the tool.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub file_ids: Option<Vec<String>>,
 }
-/// Controls for how a thread will be truncated prior to the run. Use this to control the intial context window of the run.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct ThreadObjectToolResourcesFileSearch {
+	/// The [vector store](https://platform.openai.com/docs/api-reference/vector-stores/object) attached to
+	/// this thread. There can be a maximum of 1 vector store attached to the thread.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub vector_store_ids: Option<Vec<String>>,
+}
+/// Controls for how a thread will be truncated prior to the run. Use this to control the intial context
+/// window of the run.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct TruncationObject {
+	/// The truncation strategy to use for the thread. The default is `auto`. If set to `last_messages`, the
+	/// thread will be truncated to the n most recent messages in the thread. When set to `auto`, messages
+	/// in the middle of the thread will be dropped to fit the context length of the model,
+	/// `max_prompt_tokens`.
+	pub r#type: TruncationObjectType,
 	/// The number of most recent messages from the thread when constructing the context for the run.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub last_messages: Option<i64>,
-	/// The truncation strategy to use for the thread. The default is `auto`. If set to `last_messages`, the thread will be truncated to the n most recent messages in the thread. When set to `auto`, messages in the middle of the thread will be dropped to fit the context length of the model, `max_prompt_tokens`.
-	pub r#type: TruncationObjectType,
 }
-/// The truncation strategy to use for the thread. The default is `auto`. If set to `last_messages`, the thread will be truncated to the n most recent messages in the thread. When set to `auto`, messages in the middle of the thread will be dropped to fit the context length of the model, `max_prompt_tokens`.
+/// The truncation strategy to use for the thread. The default is `auto`. If set to `last_messages`, the
+/// thread will be truncated to the n most recent messages in the thread. When set to `auto`, messages
+/// in the middle of the thread will be dropped to fit the context length of the model,
+/// `max_prompt_tokens`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum TruncationObjectType {
@@ -1,33 +1,62 @@
+use super::shared::OpenAIFileObject;
+use super::shared::OpenAIFilePurpose;
+use super::shared::OpenAIFileStatus;
 
-use std::collections::HashMap;
 use serde::{Serialize, Deserialize};
 
+/// # Examples
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CompleteUploadRequest {
+///     part_ids: vec![
+///         "part_def456".to_string(),
+///         "part_ghi789".to_string(),
+///     ],
+///     md5: None,
+/// };
+/// ```
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CompleteUploadRequest {
-	/// The optional md5 checksum for the file contents to verify if the bytes uploaded matches what you expect.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub md5: Option<String>,
 	/// The ordered list of Part IDs.
 	pub part_ids: Vec<String>,
+	/// The optional md5 checksum for the file contents to verify if the bytes uploaded matches what you
+	/// expect.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub md5: Option<String>,
 }
+/// # Examples
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateUploadRequest {
+///     filename: "training_examples.jsonl".to_string(),
+///     purpose: CreateUploadRequestPurpose::FineTune,
+///     bytes: 2147483648,
+///     mime_type: "text/jsonl".to_string(),
+/// };
+/// ```
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateUploadRequest {
-	/// The number of bytes in the file you are uploading.
-	pub bytes: i64,
 	/// The name of the file to upload.
 	pub filename: String,
-	/// The MIME type of the file.
-	/// 
-	/// This must fall within the supported MIME types for your file purpose. See the supported MIME types for assistants and vision.
-	pub mime_type: String,
 	/// The intended purpose of the uploaded file.
-	/// 
-	/// See the [documentation on File purposes](https://platform.openai.com/docs/api-reference/files/create#files-create-purpose).
+	///
+	/// See the [documentation on File
+	/// purposes](https://platform.openai.com/docs/api-reference/files/create#files-create-purpose).
 	pub purpose: CreateUploadRequestPurpose,
+	/// The number of bytes in the file you are uploading.
+	pub bytes: i64,
+	/// The MIME type of the file.
+	///
+	/// This must fall within the supported MIME types for your file purpose. See the supported MIME types
+	/// for assistants and vision.
+	pub mime_type: String,
 }
 /// The intended purpose of the uploaded file.
-/// 
-/// See the [documentation on File purposes](https://platform.openai.com/docs/api-reference/files/create#files-create-purpose).
+///
+/// See the [documentation on File
+/// purposes](https://platform.openai.com/docs/api-reference/files/create#files-create-purpose).
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum CreateUploadRequestPurpose {
@@ -37,3 +66,87 @@ pub enum CreateUploadRequestPurpose {
 	FineTune,
 	Vision,
 }
+/// The Upload object can accept byte chunks in the form of Parts.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Upload {
+	/// The Upload unique identifier, which can be referenced in API endpoints.
+	pub id: String,
+	/// The Unix timestamp (in seconds) for when the Upload was created.
+	pub created_at: i64,
+	/// The name of the file to be uploaded.
+	pub filename: String,
+	/// The intended number of bytes to be uploaded.
+	pub bytes: i64,
+	/// The intended purpose of the file. [Please refer
+	/// here](https://platform.openai.com/docs/api-reference/files/object#files/object-purpose) for
+	/// acceptable values.
+	pub purpose: String,
+	/// The status of the Upload.
+	pub status: UploadStatus,
+	/// The Unix timestamp (in seconds) for when the Upload will expire.
+	pub expires_at: i64,
+	/// The object type, which is always "upload".
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub object: Option<UploadObject>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub file: Option<UploadFile>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct UploadFile {
+	/// The file identifier, which can be referenced in the API endpoints.
+	pub id: String,
+	/// The size of the file, in bytes.
+	pub bytes: i64,
+	/// The Unix timestamp (in seconds) for when the file was created.
+	pub created_at: i64,
+	/// The Unix timestamp (in seconds) for when the file will expire.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub expires_at: Option<i64>,
+	/// The name of the file.
+	pub filename: String,
+	/// The object type, which is always `file`.
+	pub object: OpenAIFileObject,
+	/// The intended purpose of the file. Supported values are `assistants`, `assistants_output`, `batch`,
+	/// `batch_output`, `fine-tune`, `fine-tune-results` and `vision`.
+	pub purpose: OpenAIFilePurpose,
+	/// Deprecated. The current status of the file, which can be either `uploaded`, `processed`, or `error`.
+	pub status: OpenAIFileStatus,
+	/// Deprecated. For details on why a fine-tuning training file failed validation, see the `error` field
+	/// on `fine_tuning.job`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub status_details: Option<String>,
+}
+/// The object type, which is always "upload".
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum UploadObject {
+	Upload,
+}
+/// The upload Part represents a chunk of bytes we can add to an Upload object.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct UploadPart {
+	/// The upload Part unique identifier, which can be referenced in API endpoints.
+	pub id: String,
+	/// The Unix timestamp (in seconds) for when the Part was created.
+	pub created_at: i64,
+	/// The ID of the Upload object that this Part was added to.
+	pub upload_id: String,
+	/// The object type, which is always `upload.part`.
+	pub object: UploadPartObject,
+}
+/// The object type, which is always `upload.part`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum UploadPartObject {
+	#[serde(rename = "upload.part")]
+	UploadPart,
+}
+/// The status of the Upload.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum UploadStatus {
+	Pending,
+	Completed,
+	Cancelled,
+	Expired,
+}
@@ -1,10 +1,14 @@
-use super::responses_vector_stores::ComparisonFilter;
-use super::responses_vector_stores::CompoundFilter;
+use super::aliases::Metadata;
+use super::aliases::VectorStoreFileAttributes;
+use super::aliases::VectorStoreSearchRequestQueryStringArray;
+use super::shared::ComparisonFilter;
+use super::shared::CompoundFilter;
 
 use std::collections::HashMap;
 use serde::{Serialize, Deserialize};
 
-/// The default strategy. This strategy currently uses a `max_chunk_size_tokens` of `800` and `chunk_overlap_tokens` of `400`.
+/// The default strategy. This strategy currently uses a `max_chunk_size_tokens` of `800` and
+/// `chunk_overlap_tokens` of `400`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct AutoChunkingStrategyRequestParam {
 	/// Always `auto`.
@@ -24,41 +28,78 @@ pub enum ChunkingStrategyRequestParam {
 	AutoChunkingStrategyRequestParam(AutoChunkingStrategyRequestParam),
 	StaticChunkingStrategyRequestParam(StaticChunkingStrategyRequestParam),
 }
+/// # Examples
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateVectorStoreFileBatchRequest {
+///     file_ids: vec![
+///         "file-abc123".to_string(),
+///         "file-abc456".to_string(),
+///     ],
+///     chunking_strategy: None,
+///     attributes: None,
+/// };
+/// ```
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateVectorStoreFileBatchRequest {
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub attributes: Option<VectorStoreFileAttributes>,
+	/// A list of [File](https://platform.openai.com/docs/api-reference/files) IDs that the vector store
+	/// should use. Useful for tools like `file_search` that can access files.
+	pub file_ids: Vec<String>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub chunking_strategy: Option<ChunkingStrategyRequestParam>,
-	/// A list of [File](https://platform.openai.com/docs/api-reference/files) IDs that the vector store should use. Useful for tools like `file_search` that can access files.
-	pub file_ids: Vec<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub attributes: Option<VectorStoreFileAttributes>,
 }
+/// # Examples
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateVectorStoreFileRequest {
+///     file_id: "file-abc123".to_string(),
+///     chunking_strategy: None,
+///     attributes: None,
+/// };
+/// ```
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateVectorStoreFileRequest {
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub attributes: Option<VectorStoreFileAttributes>,
+	/// A [File](https://platform.openai.com/docs/api-reference/files) ID that the vector store should use.
+	/// Useful for tools like `file_search` that can access files.
+	pub file_id: String,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub chunking_strategy: Option<ChunkingStrategyRequestParam>,
-	/// A [File](https://platform.openai.com/docs/api-reference/files) ID that the vector store should use. Useful for tools like `file_search` that can access files.
-	pub file_id: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub attributes: Option<VectorStoreFileAttributes>,
 }
+/// # Examples
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = CreateVectorStoreRequest {
+///     name: Some("Support FAQ".to_string()),
+///     ..Default::default()
+/// };
+/// ```
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct CreateVectorStoreRequest {
-	/// The chunking strategy used to chunk the file(s). If not set, will use the `auto` strategy. Only applicable if `file_ids` is non-empty.
+	/// A list of [File](https://platform.openai.com/docs/api-reference/files) IDs that the vector store
+	/// should use. Useful for tools like `file_search` that can access files.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub chunking_strategy: Option<CreateVectorStoreRequestChunkingStrategy>,
+	pub file_ids: Option<Vec<String>>,
+	/// The name of the vector store.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub expires_after: Option<VectorStoreExpirationAfter>,
-	/// A list of [File](https://platform.openai.com/docs/api-reference/files) IDs that the vector store should use. Useful for tools like `file_search` that can access files.
+	/// The chunking strategy used to chunk the file(s). If not set, will use the `auto` strategy. Only
+	/// applicable if `file_ids` is non-empty.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub file_ids: Option<Vec<String>>,
+	pub chunking_strategy: Option<CreateVectorStoreRequestChunkingStrategy>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub metadata: Option<Metadata>,
-	/// The name of the vector store.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub name: Option<String>,
 }
-/// The chunking strategy used to chunk the file(s). If not set, will use the `auto` strategy. Only applicable if `file_ids` is non-empty.
+/// The chunking strategy used to chunk the file(s). If not set, will use the `auto` strategy. Only
+/// applicable if `file_ids` is non-empty.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
 #[serde(untagged)]
@@ -67,20 +108,74 @@ pub enum CreateVectorStoreRequestChunkingStrategy {
 	StaticChunkingStrategyRequestParam(StaticChunkingStrategyRequestParam),
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DeleteVectorStoreFileResponse {
+	pub id: String,
+	pub deleted: bool,
+	pub object: DeleteVectorStoreFileResponseObject,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DeleteVectorStoreFileResponseObject {
+	#[serde(rename = "vector_store.file.deleted")]
+	VectorStoreFileDeleted,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DeleteVectorStoreResponse {
+	pub id: String,
+	pub deleted: bool,
+	pub object: DeleteVectorStoreResponseObject,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DeleteVectorStoreResponseObject {
+	#[serde(rename = "vector_store.deleted")]
+	VectorStoreDeleted,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ListVectorStoreFilesResponse {
+	pub object: String,
+	pub data: Vec<VectorStoreFileObject>,
+	pub first_id: String,
+	pub last_id: String,
+	pub has_more: bool,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ListVectorStoresResponse {
+	pub object: String,
+	pub data: Vec<VectorStoreObject>,
+	pub first_id: String,
+	pub last_id: String,
+	pub has_more: bool,
+}
+/// This is returned when the chunking strategy is unknown. Typically, this is because the file was
+/// indexed before the `chunking_strategy` concept was introduced in the API.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct OtherChunkingStrategyResponseParam {
+	/// Always `other`.
+	pub r#type: OtherChunkingStrategyResponseParamType,
+}
+/// Always `other`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum OtherChunkingStrategyResponseParamType {
+	Other,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct StaticChunkingStrategy {
+	/// The maximum number of tokens in each chunk. The default value is `800`. The minimum value is `100`
+	/// and the maximum value is `4096`.
+	pub max_chunk_size_tokens: i64,
 	/// The number of tokens that overlap between chunks. The default value is `400`.
-	/// 
+	///
 	/// Note that the overlap must not exceed half of `max_chunk_size_tokens`.
 	pub chunk_overlap_tokens: i64,
-	/// The maximum number of tokens in each chunk. The default value is `800`. The minimum value is `100` and the maximum value is `4096`.
-	pub max_chunk_size_tokens: i64,
 }
 /// Customize your own chunking strategy by setting chunk size and chunk overlap.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct StaticChunkingStrategyRequestParam {
-	pub r#static: StaticChunkingStrategy,
 	/// Always `static`.
 	pub r#type: StaticChunkingStrategyRequestParamType,
+	pub r#static: StaticChunkingStrategy,
 }
 /// Always `static`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -89,18 +184,47 @@ pub enum StaticChunkingStrategyRequestParamType {
 	Static,
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct StaticChunkingStrategyResponseParam {
+	/// Always `static`.
+	pub r#type: StaticChunkingStrategyResponseParamType,
+	pub r#static: StaticChunkingStrategy,
+}
+/// Always `static`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum StaticChunkingStrategyResponseParamType {
+	Static,
+}
+/// # Examples
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = UpdateVectorStoreFileAttributesRequest {
+///     attributes: serde_json::json!({"key1":"value1","key2":2}),
+/// };
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct UpdateVectorStoreFileAttributesRequest {
 	pub attributes: VectorStoreFileAttributes,
 }
+/// # Examples
+///
+/// ```no_run
+/// # use openai_types::*;
+/// let request = UpdateVectorStoreRequest {
+///     name: Some("Support FAQ".to_string()),
+///     ..Default::default()
+/// };
+/// ```
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct UpdateVectorStoreRequest {
+	/// The name of the vector store.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub expires_after: Option<UpdateVectorStoreRequestExpiresAfter>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub metadata: Option<Metadata>,
-	/// The name of the vector store.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub name: Option<String>,
 }
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct UpdateVectorStoreRequestExpiresAfter {
@@ -124,22 +248,224 @@ pub enum VectorStoreExpirationAfterAnchor {
 	#[serde(rename = "last_active_at")]
 	LastActiveAt,
 }
+/// A batch of files attached to a vector store.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct VectorStoreFileBatchObject {
+	/// The identifier, which can be referenced in API endpoints.
+	pub id: String,
+	/// The object type, which is always `vector_store.file_batch`.
+	pub object: VectorStoreFileBatchObjectObject,
+	/// The Unix timestamp (in seconds) for when the vector store files batch was created.
+	pub created_at: i64,
+	/// The ID of the [vector store](https://platform.openai.com/docs/api-reference/vector-stores/object)
+	/// that the [File](https://platform.openai.com/docs/api-reference/files) is attached to.
+	pub vector_store_id: String,
+	/// The status of the vector store files batch, which can be either `in_progress`, `completed`,
+	/// `cancelled` or `failed`.
+	pub status: VectorStoreFileBatchObjectStatus,
+	pub file_counts: VectorStoreFileBatchObjectFileCounts,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct VectorStoreFileBatchObjectFileCounts {
+	/// The number of files that are currently being processed.
+	pub in_progress: i64,
+	/// The number of files that have been processed.
+	pub completed: i64,
+	/// The number of files that have failed to process.
+	pub failed: i64,
+	/// The number of files that where cancelled.
+	pub cancelled: i64,
+	/// The total number of files.
+	pub total: i64,
+}
+/// The object type, which is always `vector_store.file_batch`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum VectorStoreFileBatchObjectObject {
+	#[serde(rename = "vector_store.files_batch")]
+	VectorStoreFilesBatch,
+}
+/// The status of the vector store files batch, which can be either `in_progress`, `completed`,
+/// `cancelled` or `failed`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum VectorStoreFileBatchObjectStatus {
+	#[serde(rename = "in_progress")]
+	InProgress,
+	Completed,
+	Cancelled,
+	Failed,
+}
+/// Represents the parsed content of a vector store file.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct VectorStoreFileContentResponse {
+	/// The object type, which is always `vector_store.file_content.page`
+	pub object: VectorStoreFileContentResponseObject,
+	/// Parsed content of the file.
+	pub data: Vec<VectorStoreFileContentResponseDataItem>,
+	/// Indicates if there are more content pages to fetch.
+	pub has_more: bool,
+	/// The token for the next page, if any.
+	pub next_page: Option<String>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct VectorStoreFileContentResponseDataItem {
+	/// The content type (currently only `"text"`)
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub r#type: Option<String>,
+	/// The text content
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub text: Option<String>,
+}
+/// The object type, which is always `vector_store.file_content.page`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum VectorStoreFileContentResponseObject {
+	#[serde(rename = "vector_store.file_content.page")]
+	VectorStoreFileContentPage,
+}
+/// A list of files attached to a vector store.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct VectorStoreFileObject {
+	/// The identifier, which can be referenced in API endpoints.
+	pub id: String,
+	/// The object type, which is always `vector_store.file`.
+	pub object: VectorStoreFileObjectObject,
+	/// The total vector store usage in bytes. Note that this may be different from the original file size.
+	pub usage_bytes: i64,
+	/// The Unix timestamp (in seconds) for when the vector store file was created.
+	pub created_at: i64,
+	/// The ID of the [vector store](https://platform.openai.com/docs/api-reference/vector-stores/object)
+	/// that the [File](https://platform.openai.com/docs/api-reference/files) is attached to.
+	pub vector_store_id: String,
+	/// The status of the vector store file, which can be either `in_progress`, `completed`, `cancelled`, or
+	/// `failed`. The status `completed` indicates that the vector store file is ready for use.
+	pub status: VectorStoreFileObjectStatus,
+	/// The last error associated with this vector store file. Will be `null` if there are no errors.
+	pub last_error: Option<VectorStoreFileObjectLastError>,
+	/// The strategy used to chunk the file.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub chunking_strategy: Option<VectorStoreFileObjectChunkingStrategy>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub attributes: Option<VectorStoreFileAttributes>,
+}
+/// The strategy used to chunk the file.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[serde(untagged)]
+pub enum VectorStoreFileObjectChunkingStrategy {
+	StaticChunkingStrategyResponseParam(StaticChunkingStrategyResponseParam),
+	OtherChunkingStrategyResponseParam(OtherChunkingStrategyResponseParam),
+}
+/// The last error associated with this vector store file. Will be `null` if there are no errors.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct VectorStoreFileObjectLastError {
+	/// One of `server_error` or `rate_limit_exceeded`.
+	pub code: VectorStoreFileObjectLastErrorCode,
+	/// A human-readable description of the error.
+	pub message: String,
+}
+/// One of `server_error` or `rate_limit_exceeded`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum VectorStoreFileObjectLastErrorCode {
+	#[serde(rename = "server_error")]
+	ServerError,
+	#[serde(rename = "unsupported_file")]
+	UnsupportedFile,
+	#[serde(rename = "invalid_file")]
+	InvalidFile,
+}
+/// The object type, which is always `vector_store.file`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum VectorStoreFileObjectObject {
+	#[serde(rename = "vector_store.file")]
+	VectorStoreFile,
+}
+/// The status of the vector store file, which can be either `in_progress`, `completed`, `cancelled`, or
+/// `failed`. The status `completed` indicates that the vector store file is ready for use.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum VectorStoreFileObjectStatus {
+	#[serde(rename = "in_progress")]
+	InProgress,
+	Completed,
+	Cancelled,
+	Failed,
+}
+/// A vector store is a collection of processed files can be used by the `file_search` tool.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct VectorStoreObject {
+	/// The identifier, which can be referenced in API endpoints.
+	pub id: String,
+	/// The object type, which is always `vector_store`.
+	pub object: VectorStoreObjectObject,
+	/// The Unix timestamp (in seconds) for when the vector store was created.
+	pub created_at: i64,
+	/// The name of the vector store.
+	pub name: String,
+	/// The total number of bytes used by the files in the vector store.
+	pub usage_bytes: i64,
+	pub file_counts: VectorStoreObjectFileCounts,
+	/// The status of the vector store, which can be either `expired`, `in_progress`, or `completed`. A
+	/// status of `completed` indicates that the vector store is ready for use.
+	pub status: VectorStoreObjectStatus,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub expires_after: Option<VectorStoreExpirationAfter>,
+	/// The Unix timestamp (in seconds) for when the vector store will expire.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub expires_at: Option<i64>,
+	/// The Unix timestamp (in seconds) for when the vector store was last active.
+	pub last_active_at: Option<i64>,
+	pub metadata: Metadata,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct VectorStoreObjectFileCounts {
+	/// The number of files that are currently being processed.
+	pub in_progress: i64,
+	/// The number of files that have been successfully processed.
+	pub completed: i64,
+	/// The number of files that have failed to process.
+	pub failed: i64,
+	/// The number of files that were cancelled.
+	pub cancelled: i64,
+	/// The total number of files.
+	pub total: i64,
+}
+/// The object type, which is always `vector_store`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum VectorStoreObjectObject {
+	#[serde(rename = "vector_store")]
+	VectorStore,
+}
+/// The status of the vector store, which can be either `expired`, `in_progress`, or `completed`. A
+/// status of `completed` indicates that the vector store is ready for use.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum VectorStoreObjectStatus {
+	Expired,
+	#[serde(rename = "in_progress")]
+	InProgress,
+	Completed,
+}
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct VectorStoreSearchRequest {
-	/// A filter to apply based on file attributes.
+	/// A query string for a search
+	pub query: VectorStoreSearchRequestQuery,
+	/// Whether to rewrite the natural language query for vector search.
 	#[serde(skip_serializing_if = "Option::is_none")]
-	pub filters: Option<VectorStoreSearchRequestFilters>,
+	pub rewrite_query: Option<bool>,
 	/// The maximum number of results to return. This number should be between 1 and 50 inclusive.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub max_num_results: Option<i64>,
-	/// A query string for a search
-	pub query: VectorStoreSearchRequestQuery,
+	/// A filter to apply based on file attributes.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub filters: Option<VectorStoreSearchRequestFilters>,
 	/// Ranking options for search.
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub ranking_options: Option<HashMap<String, String>>,
-	/// Whether to rewrite the natural language query for vector search.
-	#[serde(skip_serializing_if = "Option::is_none")]
-	pub rewrite_query: Option<bool>,
 }
 /// A filter to apply based on file attributes.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -157,3 +483,47 @@ pub enum VectorStoreSearchRequestQuery {
 	String(String),
 	VectorStoreSearchRequestQueryStringArray(VectorStoreSearchRequestQueryStringArray),
 }
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct VectorStoreSearchResultContentObject {
+	/// The type of content.
+	pub r#type: VectorStoreSearchResultContentObjectType,
+	/// The text content returned from search.
+	pub text: String,
+}
+/// The type of content.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum VectorStoreSearchResultContentObjectType {
+	Text,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct VectorStoreSearchResultItem {
+	/// The ID of the vector store file.
+	pub file_id: String,
+	/// The name of the vector store file.
+	pub filename: String,
+	/// The similarity score for the result.
+	pub score: f64,
+	pub attributes: VectorStoreFileAttributes,
+	/// Content chunks from the file.
+	pub content: Vec<VectorStoreSearchResultContentObject>,
+}
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct VectorStoreSearchResultsPage {
+	/// The object type, which is always `vector_store.search_results.page`
+	pub object: VectorStoreSearchResultsPageObject,
+	pub search_query: Vec<String>,
+	/// The list of search result items.
+	pub data: Vec<VectorStoreSearchResultItem>,
+	/// Indicates if there are more results to fetch.
+	pub has_more: bool,
+	/// The token for the next page, if any.
+	pub next_page: Option<String>,
+}
+/// The object type, which is always `vector_store.search_results.page`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum VectorStoreSearchResultsPageObject {
+	#[serde(rename = "vector_store.search_results.page")]
+	VectorStoreSearchResultsPage,
+}
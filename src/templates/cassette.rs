@@ -0,0 +1,410 @@
+//! Record and replay for the client's HTTP traffic, for deterministic
+//!  tests of code which calls the API.
+//!
+//! A [`CassetteTransport`] in record mode sends requests on through
+//!  another transport and writes each request and response, streamed
+//!  bodies included, to a JSON cassette file. In replay mode it answers
+//!  requests from that file without touching the network.
+//!
+//! Secrets are redacted before anything is written: the values of
+//!  [`REDACTED_HEADERS`], and any value given to
+//!  [`CassetteTransport::redact`] wherever it appears.
+//!
+//! [`CassetteTransport::auto`] records on the first run and replays on
+//!  every later one, so a test only needs a key the first time:
+//!  `Client::from_env().with_transport(CassetteTransport::auto(path, ReqwestTransport::default())?)`.
+use super::client::{BoxFuture, ByteStream, ClientError, HttpRequest, HttpResponse, Transport};
+
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use futures_util::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+
+/// Headers whose values are never written to a cassette
+pub const REDACTED_HEADERS: &[&str] = &[
+    "authorization",
+    "api-key",
+    "openai-organization",
+    "openai-project",
+    "cookie",
+    "set-cookie",
+];
+/// What redacted values are replaced with
+pub const REDACTED: &str = "[REDACTED]";
+
+/// The interactions recorded in a cassette file, in the order they happened
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+impl Cassette {
+    /// Reads a cassette file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ClientError> {
+        let path = path.as_ref();
+        let raw = std::fs::read_to_string(path)
+            .map_err(|error| ClientError::Cassette(format!("Failed to read {}: {}", path.display(), error)))?;
+
+        serde_json::from_str(&raw)
+            .map_err(|error| ClientError::Cassette(format!("Failed to parse {}: {}", path.display(), error)))
+    }
+    /// Writes the cassette to a file, creating its directory if needed
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ClientError> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        std::fs::write(path, serde_json::to_string_pretty(self)? + "\n")
+            .map_err(|error| ClientError::Cassette(format!("Failed to write {}: {}", path.display(), error)))
+    }
+}
+
+/// One recorded request and its response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+/// A recorded request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    /// The HTTP method, in upper case
+    pub method: String,
+    /// The path and query after the host, so cassettes recorded against
+    ///  one base URL replay against any other
+    pub path: String,
+    /// The headers, with secrets redacted
+    pub headers: Vec<(String, String)>,
+    /// The body as JSON, or as a string when it isn't JSON
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<serde_json::Value>,
+}
+
+/// A recorded response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    /// The HTTP status
+    pub status: u16,
+    /// The headers, with secrets redacted
+    pub headers: Vec<(String, String)>,
+    /// The body, when it's text, such as JSON or server-sent events
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// The body, when it isn't text
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_bytes: Option<Vec<u8>>,
+}
+impl RecordedResponse {
+    fn body(&self) -> Vec<u8> {
+        match (&self.body, &self.body_bytes) {
+            (Some(body), _) => body.clone().into_bytes(),
+            (None, Some(body)) => body.clone(),
+            (None, None) => Vec::new(),
+        }
+    }
+}
+
+/// Which parts of a request must match a recorded one for it to be
+///  replayed
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matching {
+    /// Match the HTTP method
+    pub method: bool,
+    /// Match the path and query
+    pub path: bool,
+    /// Match the body. JSON bodies are compared as JSON, so their key
+    ///  order and whitespace don't matter
+    pub body: bool,
+    /// Top level JSON body fields left out of the comparison, such as
+    ///  ones holding timestamps or random seeds
+    pub ignore_body_fields: Vec<String>,
+}
+impl Default for Matching {
+    fn default() -> Self {
+        Matching { method: true, path: true, body: true, ignore_body_fields: Vec::new() }
+    }
+}
+impl Matching {
+    /// Whether a request matches a recorded one
+    pub fn matches(&self, recorded: &RecordedRequest, request: &RecordedRequest) -> bool {
+        if self.method && recorded.method != request.method {
+            return false;
+        }
+        if self.path && recorded.path != request.path {
+            return false;
+        }
+        if self.body && self.normalize(&recorded.body) != self.normalize(&request.body) {
+            return false;
+        }
+
+        true
+    }
+    fn normalize(&self, body: &Option<serde_json::Value>) -> Option<serde_json::Value> {
+        let mut body = body.clone();
+        if let Some(serde_json::Value::Object(ref mut fields)) = body {
+            for field in self.ignore_body_fields.iter() {
+                fields.remove(field);
+            }
+        }
+
+        body
+    }
+}
+
+enum Mode {
+    Record(Arc<dyn Transport>),
+    Replay,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    cassette: Cassette,
+    /// Which interactions have been replayed already
+    used: Vec<bool>,
+}
+
+/// A transport which records to, or replays from, a cassette file
+pub struct CassetteTransport {
+    path: PathBuf,
+    mode: Mode,
+    matching: Matching,
+    secrets: Vec<String>,
+    state: Arc<Mutex<State>>,
+}
+impl CassetteTransport {
+    /// Records every request sent through `inner` to a new cassette at
+    ///  `path`, which is written after each response ends
+    pub fn record(path: impl Into<PathBuf>, inner: impl Transport + 'static) -> Self {
+        CassetteTransport {
+            path: path.into(),
+            mode: Mode::Record(Arc::new(inner)),
+            matching: Matching::default(),
+            secrets: Vec::new(),
+            state: Arc::new(Mutex::new(State::default())),
+        }
+    }
+    /// Answers requests from the cassette at `path`, each recorded
+    ///  interaction being replayed at most once
+    pub fn replay(path: impl Into<PathBuf>) -> Result<Self, ClientError> {
+        let path = path.into();
+        let cassette = Cassette::load(&path)?;
+        let used = vec![false; cassette.interactions.len()];
+
+        Ok(CassetteTransport {
+            path,
+            mode: Mode::Replay,
+            matching: Matching::default(),
+            secrets: Vec::new(),
+            state: Arc::new(Mutex::new(State { cassette, used })),
+        })
+    }
+    /// Replays the cassette at `path` if it exists, and records it
+    ///  through `inner` otherwise
+    pub fn auto(path: impl Into<PathBuf>, inner: impl Transport + 'static) -> Result<Self, ClientError> {
+        let path = path.into();
+        if path.exists() {
+            CassetteTransport::replay(path)
+        } else {
+            Ok(CassetteTransport::record(path, inner))
+        }
+    }
+    /// Matches requests to recorded ones by these rules instead
+    pub fn with_matching(mut self, matching: Matching) -> Self {
+        self.matching = matching;
+        self
+    }
+    /// Replaces a secret, such as an API key or an organization ID,
+    ///  wherever it appears in recorded requests and responses.
+    ///
+    /// Secrets are redacted from requests before they're matched too,
+    ///  so replays match recordings made with another key.
+    pub fn redact(mut self, secret: impl Into<String>) -> Self {
+        let secret = secret.into();
+        if !secret.is_empty() {
+            self.secrets.push(secret);
+        }
+        self
+    }
+    /// Whether the transport is recording rather than replaying
+    pub fn is_recording(&self) -> bool {
+        matches!(self.mode, Mode::Record(_))
+    }
+    /// The interactions recorded or loaded so far
+    pub fn cassette(&self) -> Cassette {
+        lock(&self.state).cassette.clone()
+    }
+
+    /// The request as it's written to the cassette
+    fn recorded_request(&self, request: &HttpRequest) -> RecordedRequest {
+        let body = request.body.as_ref().map(|body| {
+            let body = redact(&self.secrets, &String::from_utf8_lossy(body));
+            serde_json::from_str(&body).unwrap_or(serde_json::Value::String(body))
+        });
+
+        RecordedRequest {
+            method: request.method.clone(),
+            path: redact(&self.secrets, url_path(&request.url)),
+            headers: redact_headers(&self.secrets, &request.headers),
+            body,
+        }
+    }
+    fn replay_request(&self, request: &RecordedRequest) -> Result<HttpResponse, ClientError> {
+        let mut state = lock(&self.state);
+        let State { ref cassette, ref mut used } = *state;
+
+        let index = cassette.interactions.iter()
+            .enumerate()
+            .position(|(index, interaction)| !used[index] && self.matching.matches(&interaction.request, request))
+            .ok_or_else(|| ClientError::Cassette(format!(
+                "No unused interaction in {} matches {} {}",
+                self.path.display(),
+                request.method,
+                request.path
+            )))?;
+        used[index] = true;
+
+        let response = &cassette.interactions[index].response;
+        let body: ByteStream = Box::pin(futures_util::stream::iter(vec![Ok(response.body())]));
+
+        Ok(HttpResponse { status: response.status, headers: response.headers.clone(), body })
+    }
+}
+impl Transport for CassetteTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, ClientError>> {
+        Box::pin(async move {
+            let recorded_request = self.recorded_request(&request);
+            let inner = match self.mode {
+                Mode::Record(ref inner) => inner.clone(),
+                Mode::Replay => return self.replay_request(&recorded_request),
+            };
+
+            // The body is passed on as it arrives, and the interaction is
+            //  written once it's all been read
+            let response = inner.send(request).await?;
+            let body = Recording {
+                body: response.body,
+                buffer: Vec::new(),
+                pending: Some(Interaction {
+                    request: recorded_request,
+                    response: RecordedResponse {
+                        status: response.status,
+                        headers: redact_headers(&self.secrets, &response.headers),
+                        body: None,
+                        body_bytes: None,
+                    },
+                }),
+                path: self.path.clone(),
+                secrets: self.secrets.clone(),
+                state: self.state.clone(),
+            };
+
+            Ok(HttpResponse { status: response.status, headers: response.headers, body: Box::pin(body) })
+        })
+    }
+}
+impl std::fmt::Debug for CassetteTransport {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("CassetteTransport")
+            .field("path", &self.path)
+            .field("recording", &self.is_recording())
+            .field("matching", &self.matching)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A response body being recorded as it's read
+struct Recording {
+    body: ByteStream,
+    buffer: Vec<u8>,
+    pending: Option<Interaction>,
+    path: PathBuf,
+    secrets: Vec<String>,
+    state: Arc<Mutex<State>>,
+}
+impl Recording {
+    fn finish(&mut self) -> Result<(), ClientError> {
+        let Some(mut interaction) = self.pending.take() else {
+            return Ok(());
+        };
+
+        let body = std::mem::take(&mut self.buffer);
+        match String::from_utf8(body) {
+            Ok(body) => interaction.response.body = Some(redact(&self.secrets, &body)),
+            Err(error) => interaction.response.body_bytes = Some(error.into_bytes()),
+        }
+
+        let mut state = lock(&self.state);
+        state.cassette.interactions.push(interaction);
+        state.used.push(true);
+        state.cassette.save(&self.path)
+    }
+}
+impl Stream for Recording {
+    type Item = Result<Vec<u8>, ClientError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.body.poll_next_unpin(cx) {
+            Poll::Ready(Some(Ok(chunk))) => {
+                self.buffer.extend_from_slice(&chunk);
+                Poll::Ready(Some(Ok(chunk)))
+            },
+            // Failed responses aren't recorded, so they're retried when
+            //  recording again
+            Poll::Ready(Some(Err(error))) => {
+                self.pending = None;
+                Poll::Ready(Some(Err(error)))
+            },
+            Poll::Ready(None) => match self.finish() {
+                Ok(()) => Poll::Ready(None),
+                Err(error) => Poll::Ready(Some(Err(error))),
+            },
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+// Streams are often dropped before they end, such as after an SSE
+//  `[DONE]`, so whatever was read by then is recorded
+impl Drop for Recording {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+fn lock(state: &Mutex<State>) -> std::sync::MutexGuard<'_, State> {
+    state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+/// The path and query of a URL, after its scheme and host
+fn url_path(url: &str) -> &str {
+    let after_scheme = url.split_once("://").map(|(_scheme, rest)| rest).unwrap_or(url);
+    match after_scheme.find('/') {
+        Some(start) => &after_scheme[start..],
+        None => "/",
+    }
+}
+/// Replaces every secret in some text
+fn redact(secrets: &[String], text: &str) -> String {
+    let mut text = text.to_string();
+    for secret in secrets {
+        text = text.replace(secret.as_str(), REDACTED);
+    }
+
+    text
+}
+fn redact_headers(secrets: &[String], headers: &[(String, String)]) -> Vec<(String, String)> {
+    headers.iter()
+        .map(|(name, value)| {
+            let value = if REDACTED_HEADERS.contains(&name.to_lowercase().as_str()) {
+                REDACTED.to_string()
+            } else {
+                redact(secrets, value)
+            };
+
+            (name.to_lowercase(), value)
+        })
+        .collect()
+}
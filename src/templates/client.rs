@@ -0,0 +1,873 @@
+//! An async client for the OpenAI API, with one method per operation.
+//!
+//! Requests go through a [`Transport`], which is [`ReqwestTransport`]
+//!  unless another is given with [`Client::with_transport`]. Tests can
+//!  use a [`CassetteTransport`](super::cassette::CassetteTransport) to
+//!  record real traffic once and replay it offline.
+//!
+//! Cursor-paginated lists also get an `_all` method, which returns a
+//!  [`Paginated`] stream of every item, fetching pages as they're needed.
+//!
+//! Failed calls are retried by a [`RetryPolicy`], and every response
+//!  carries the [`RateLimits`] the API reported with it.
+//!
+//! Requests go to OpenAI unless the client is given another
+//!  [`Provider`], such as Azure OpenAI or an OpenAI-compatible server.
+//!
+//! Beta operations, such as the Assistants API's, send the `OpenAI-Beta`
+//!  header their examples do, unless [`Client::with_beta`] pins another.
+//!
+//! Binary responses, such as file contents and speech, are returned as
+//!  a [`Download`], which streams the body rather than reading it all.
+//!
+//! Multipart uploads don't have methods yet.
+//!
+//! Needs the `reqwest` (with the `stream` feature), `futures-util`,
+//!  `tokio` and `serde_path_to_error` crates.
+use super::download::Download;
+use super::provider::Provider;
+use super::retry::{is_retryable_status, RateLimits, RetryPolicy};
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Instant;
+
+use futures_util::{Stream, StreamExt};
+
+/// The OpenAI API's base URL
+pub const DEFAULT_BASE_URL: &str = "https://api.openai.com/v1";
+
+/// A boxed future, as returned by a [`Transport`]
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+/// A response body, as it arrives
+pub type ByteStream = Pin<Box<dyn Stream<Item = Result<Vec<u8>, ClientError>> + Send>>;
+
+/// An error from the client
+#[derive(Debug)]
+pub enum ClientError {
+    /// The request couldn't be sent, or the response couldn't be read
+    Transport(String),
+    /// The request body couldn't be encoded
+    Encode(serde_json::Error),
+    /// A response body wasn't what the spec says it should be
+    Decode(DecodeError),
+    /// The API answered with an error
+    Api(Box<ApiError>),
+    /// A cassette couldn't be read or written, or had no matching interaction
+    Cassette(String),
+    /// The client's provider doesn't support the operation, so the
+    ///  request wasn't sent
+    Unsupported {
+        /// The operation's `operationId`
+        operation_id: String,
+        /// The provider's name
+        provider: String,
+    },
+    /// A file couldn't be read or written
+    Io(std::io::Error),
+}
+impl ClientError {
+    /// The HTTP status the API answered with, if it answered
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            ClientError::Api(error) => Some(error.status),
+            _ => None,
+        }
+    }
+    /// The ID the API gave the failed request, for support requests
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            ClientError::Api(error) => error.request_id.as_deref(),
+            _ => None,
+        }
+    }
+    /// The error the API described, if it answered with one
+    pub fn api_error(&self) -> Option<&ErrorBody> {
+        match self {
+            ClientError::Api(error) => error.error.as_ref(),
+            _ => None,
+        }
+    }
+    /// Whether the request was rate limited, and can be sent again
+    ///  later. Running out of quota isn't a rate limit
+    pub fn is_rate_limit(&self) -> bool {
+        self.status() == Some(429) && !self.is_insufficient_quota()
+    }
+    /// Whether the account has run out of quota
+    pub fn is_insufficient_quota(&self) -> bool {
+        self.api_error().is_some_and(|error| {
+            error.r#type == "insufficient_quota" || error.code.as_deref() == Some("insufficient_quota")
+        })
+    }
+    /// Whether the input was too long for the model's context window
+    pub fn is_context_length_exceeded(&self) -> bool {
+        self.api_error().is_some_and(|error| error.code.as_deref() == Some("context_length_exceeded"))
+    }
+    /// Whether sending the same request again could succeed: when it
+    ///  couldn't be sent, was rate limited or met a server error
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ClientError::Transport(_) => true,
+            ClientError::Api(error) => is_retryable_status(error.status) && !self.is_insufficient_quota(),
+            _ => false,
+        }
+    }
+}
+impl std::fmt::Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ClientError::Transport(error) => write!(f, "Transport error: {}", error),
+            ClientError::Encode(error) => write!(f, "Failed to encode the request body: {}", error),
+            ClientError::Decode(error) => write!(f, "{}", error),
+            ClientError::Api(error) => write!(f, "{}", error),
+            ClientError::Cassette(error) => write!(f, "Cassette error: {}", error),
+            ClientError::Unsupported { operation_id, provider } => write!(f, "`{}` is unsupported on this provider ({})", operation_id, provider),
+            ClientError::Io(error) => write!(f, "IO error: {}", error),
+        }
+    }
+}
+impl std::error::Error for ClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ClientError::Encode(error) => Some(error),
+            ClientError::Decode(error) => Some(&error.error),
+            ClientError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+impl From<serde_json::Error> for ClientError {
+    fn from(error: serde_json::Error) -> Self {
+        ClientError::Encode(error)
+    }
+}
+impl From<std::io::Error> for ClientError {
+    fn from(error: std::io::Error) -> Self {
+        ClientError::Io(error)
+    }
+}
+
+/// A response body which couldn't be decoded
+#[derive(Debug)]
+pub struct DecodeError {
+    /// Where in the body decoding failed, such as `choices[0].finish_reason`
+    pub path: String,
+    /// Why decoding failed
+    pub error: serde_json::Error,
+    /// The body, as it was received
+    pub body: String,
+}
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.path.is_empty() || self.path == "." {
+            write!(f, "Failed to decode the response: {}", self.error)
+        } else {
+            write!(f, "Failed to decode the response at `{}`: {}", self.path, self.error)
+        }
+    }
+}
+
+/// An error the API answered with
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiError {
+    /// The HTTP status
+    pub status: u16,
+    /// The ID the API gave the request, from `x-request-id`
+    pub request_id: Option<String>,
+    /// The error, when the body described one
+    pub error: Option<ErrorBody>,
+    /// The body, as it was received
+    pub body: String,
+}
+impl ApiError {
+    /// Reads an error response
+    pub fn new(status: u16, headers: &[(String, String)], body: &[u8]) -> Self {
+        ApiError {
+            status,
+            request_id: header(headers, "x-request-id").map(|request_id| request_id.to_string()),
+            error: parse_error_response(body),
+            body: String::from_utf8_lossy(body).into_owned(),
+        }
+    }
+}
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.error {
+            Some(ref error) => write!(f, "The API answered {} ({}): {}", self.status, error.r#type, error.message)?,
+            None => write!(f, "The API answered {}: {}", self.status, self.body)?,
+        }
+        if let Some(ref request_id) = self.request_id {
+            write!(f, " (request {})", request_id)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A request, as handed to a [`Transport`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpRequest {
+    /// The HTTP method, in upper case
+    pub method: String,
+    /// The full URL, including the query
+    pub url: String,
+    /// The headers, in the order they're sent
+    pub headers: Vec<(String, String)>,
+    /// The body, if there is one
+    pub body: Option<Vec<u8>>,
+}
+
+/// A response, as returned by a [`Transport`]
+pub struct HttpResponse {
+    /// The HTTP status
+    pub status: u16,
+    /// The headers, with lower case names
+    pub headers: Vec<(String, String)>,
+    /// The body, which is read as it arrives
+    pub body: ByteStream,
+}
+impl HttpResponse {
+    /// The value of the first header with the given name, ignoring case
+    pub fn header(&self, name: &str) -> Option<&str> {
+        header(&self.headers, name)
+    }
+    /// The rate limits the API reported
+    pub fn rate_limits(&self) -> RateLimits {
+        RateLimits::from_headers(&self.headers)
+    }
+    /// Reads the whole body
+    pub async fn bytes(mut self) -> Result<Vec<u8>, ClientError> {
+        let mut bytes = Vec::new();
+        while let Some(chunk) = self.body.next().await {
+            bytes.extend(chunk?);
+        }
+
+        Ok(bytes)
+    }
+}
+impl std::fmt::Debug for HttpResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("HttpResponse")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A successful response read as `T`, which it dereferences to, along
+///  with what the API said about it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiResponse<T> {
+    /// The body. Named so it doesn't hide a `data` field of `T`
+    pub body: T,
+    /// The HTTP status
+    pub status: u16,
+    /// The headers, with lower case names
+    pub headers: Vec<(String, String)>,
+    /// The rate limits the API reported
+    pub rate_limits: RateLimits,
+}
+impl<T> ApiResponse<T> {
+    /// The body, without the rest of the response
+    pub fn into_inner(self) -> T {
+        self.body
+    }
+    /// The value of the first header with the given name, ignoring case
+    pub fn header(&self, name: &str) -> Option<&str> {
+        header(&self.headers, name)
+    }
+    /// The ID the API gave the request, for support requests
+    pub fn request_id(&self) -> Option<&str> {
+        self.header("x-request-id")
+    }
+}
+impl<T> Deref for ApiResponse<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.body
+    }
+}
+
+/// Sends requests for a [`Client`]
+pub trait Transport: Send + Sync {
+    /// Sends a request, returning as soon as the response's headers arrive
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, ClientError>>;
+}
+
+/// Sends requests over the network with `reqwest`
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+impl ReqwestTransport {
+    /// Sends requests with a configured `reqwest` client
+    pub fn new(client: reqwest::Client) -> Self {
+        ReqwestTransport { client }
+    }
+}
+impl Transport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, ClientError>> {
+        Box::pin(async move {
+            let method = reqwest::Method::from_bytes(request.method.as_bytes())
+                .map_err(|error| ClientError::Transport(error.to_string()))?;
+
+            let mut builder = self.client.request(method, &request.url);
+            for (name, value) in request.headers {
+                builder = builder.header(name, value);
+            }
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            }
+
+            let response = builder.send()
+                .await
+                .map_err(|error| ClientError::Transport(error.to_string()))?;
+            let status = response.status().as_u16();
+            let headers = response.headers()
+                .iter()
+                .filter_map(|(name, value)| Some((name.as_str().to_string(), value.to_str().ok()?.to_string())))
+                .collect();
+            let body = response.bytes_stream()
+                .map(|chunk| chunk
+                    .map(|chunk| chunk.to_vec())
+                    .map_err(|error| ClientError::Transport(error.to_string())));
+
+            Ok(HttpResponse { status, headers, body: Box::pin(body) })
+        })
+    }
+}
+
+/// A client for the OpenAI API
+#[derive(Clone)]
+pub struct Client {
+    provider: Provider,
+    api_key: Option<String>,
+    headers: Vec<(String, String)>,
+    beta: Vec<String>,
+    retry: RetryPolicy,
+    transport: Arc<dyn Transport>,
+}
+impl Client {
+    /// A client for the OpenAI API with the given key
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Client {
+            provider: Provider::openai(),
+            api_key: Some(api_key.into()),
+            headers: Vec::new(),
+            beta: Vec::new(),
+            retry: RetryPolicy::default(),
+            transport: Arc::new(ReqwestTransport::default()),
+        }
+    }
+    /// A client configured by `OPENAI_API_KEY` and, if it's set,
+    ///  `OPENAI_BASE_URL`
+    pub fn from_env() -> Self {
+        let mut client = Client::new(String::new());
+        client.api_key = std::env::var("OPENAI_API_KEY").ok();
+        if let Ok(base_url) = std::env::var("OPENAI_BASE_URL") {
+            client = client.with_base_url(base_url);
+        }
+
+        client
+    }
+    /// Sends requests to another base URL, such as a mock server's
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.provider = self.provider.with_base_url(base_url);
+        self
+    }
+    /// Sends requests to another provider, such as Azure OpenAI
+    pub fn with_provider(mut self, provider: Provider) -> Self {
+        self.provider = provider;
+        self
+    }
+    /// Sends requests through another transport
+    pub fn with_transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Arc::new(transport);
+        self
+    }
+    /// Sends an extra header with every request
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+    /// Sends another `OpenAI-Beta` value, such as `assistants=v1`, for the
+    ///  beta operations whose own value names the same feature
+    pub fn with_beta(mut self, beta: impl Into<String>) -> Self {
+        let beta = beta.into();
+        self.beta.retain(|pinned| beta_feature(pinned) != beta_feature(&beta));
+        self.beta.push(beta);
+        self
+    }
+    /// Retries failed calls by another policy
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+    /// The base URL requests are sent to
+    pub fn base_url(&self) -> &str {
+        &self.provider.base_url
+    }
+    /// The provider requests are sent to
+    pub fn provider(&self) -> &Provider {
+        &self.provider
+    }
+    /// The `OpenAI-Beta` header sent with an operation, if it's beta
+    pub fn beta_header(&self, operation_id: &str) -> Option<&str> {
+        let (_operation_id, beta) = BETA_HEADERS.iter()
+            .find(|(beta_operation_id, _beta)| *beta_operation_id == operation_id)?;
+
+        self.beta.iter()
+            .find(|pinned| beta_feature(pinned) == beta_feature(beta))
+            .map(|pinned| pinned.as_str())
+            .or(Some(*beta))
+    }
+
+    /// Sends a request for an operation to a path under the base URL,
+    ///  retrying it by the client's [`RetryPolicy`], and failing with
+    ///  [`ClientError::Api`] unless a response is successful, or with
+    ///  [`ClientError::Unsupported`] if the provider doesn't support the
+    ///  operation
+    pub async fn send(
+        &self,
+        operation_id: &str,
+        method: &str,
+        path: &str,
+        query: &[(&str, String)],
+        body: Option<Vec<u8>>,
+    ) -> Result<HttpResponse, ClientError> {
+        self.provider.check(operation_id)?;
+
+        let mut url = self.provider.url(path);
+        let provider_query = self.provider.query.iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()));
+        let query = query.iter()
+            .map(|(name, value)| (*name, value.as_str()));
+        for (index, (name, value)) in provider_query.chain(query).enumerate() {
+            url.push(if index == 0 { '?' } else { '&' });
+            url.push_str(&format!("{}={}", encode(name), encode(value)));
+        }
+
+        let mut headers = Vec::new();
+        if let Some(header) = self.api_key.as_ref().and_then(|api_key| self.provider.auth_header(api_key)) {
+            headers.push(header);
+        }
+        if body.is_some() {
+            headers.push(("content-type".to_string(), "application/json".to_string()));
+        }
+        // An `OpenAI-Beta` header given to the client replaces the operation's
+        if let Some(beta) = self.beta_header(operation_id) {
+            if header(&self.headers, "openai-beta").is_none() {
+                headers.push(("openai-beta".to_string(), beta.to_string()));
+            }
+        }
+        headers.extend(self.headers.iter().cloned());
+
+        let request = HttpRequest { method: method.to_string(), url, headers, body };
+        let retryable = self.retry.allows(operation_id, method);
+        let started = Instant::now();
+        let mut retry = 0;
+        loop {
+            let (error, delay) = match self.transport.send(request.clone()).await {
+                Ok(response) if (200..300).contains(&response.status) => return Ok(response),
+                Ok(response) => {
+                    let status = response.status;
+                    let headers = response.headers.clone();
+                    let body = response.bytes().await?;
+                    let error = ClientError::Api(Box::new(ApiError::new(status, &headers, &body)));
+                    let delay = self.retry.should_retry(&error, &headers)
+                        .then(|| self.retry.delay(retry, &headers));
+
+                    (error, delay)
+                },
+                Err(error @ ClientError::Transport(_)) => (error, Some(self.retry.delay(retry, &[]))),
+                Err(error) => return Err(error),
+            };
+
+            // Give up when out of retries, or when the wait would take the
+            //  call past its budget
+            let delay = match delay {
+                Some(delay) if retryable
+                    && retry < self.retry.max_retries
+                    && started.elapsed() + delay <= self.retry.budget => delay,
+                _ => return Err(error),
+            };
+            tokio::time::sleep(delay).await;
+            retry += 1;
+        }
+    }
+    /// Sends a request and reads the response as JSON, reading an empty
+    ///  body as `null`
+    async fn json<T: serde::de::DeserializeOwned>(
+        &self,
+        operation_id: &str,
+        method: &str,
+        path: &str,
+        query: &[(&str, String)],
+        body: Option<Vec<u8>>,
+    ) -> Result<ApiResponse<T>, ClientError> {
+        let response = self.raw(operation_id, method, path, query, body).await?;
+        let body = if response.body.iter().all(|byte| byte.is_ascii_whitespace()) {
+            decode(b"null")?
+        } else {
+            decode(&response.body)?
+        };
+
+        Ok(ApiResponse { body, status: response.status, headers: response.headers, rate_limits: response.rate_limits })
+    }
+    /// Sends a request and reads the whole response body
+    async fn raw(
+        &self,
+        operation_id: &str,
+        method: &str,
+        path: &str,
+        query: &[(&str, String)],
+        body: Option<Vec<u8>>,
+    ) -> Result<ApiResponse<Vec<u8>>, ClientError> {
+        let response = self.send(operation_id, method, path, query, body).await?;
+        let status = response.status;
+        let headers = response.headers.clone();
+        let rate_limits = response.rate_limits();
+
+        Ok(ApiResponse { body: response.bytes().await?, status, headers, rate_limits })
+    }
+    /// Sends a request and streams the binary response body
+    async fn download(
+        &self,
+        operation_id: &str,
+        method: &str,
+        path: &str,
+        query: &[(&str, String)],
+        body: Option<Vec<u8>>,
+    ) -> Result<ApiResponse<Download>, ClientError> {
+        let response = self.send(operation_id, method, path, query, body).await?;
+        let status = response.status;
+        let headers = response.headers.clone();
+        let rate_limits = response.rate_limits();
+
+        Ok(ApiResponse { body: Download::new(response), status, headers, rate_limits })
+    }
+    /// Sends a request and reads the response as server-sent events
+    // Unused when no enabled group has an operation which streams
+    #[allow(dead_code)]
+    async fn events<T: serde::de::DeserializeOwned>(
+        &self,
+        operation_id: &str,
+        method: &str,
+        path: &str,
+        query: &[(&str, String)],
+        body: Option<Vec<u8>>,
+    ) -> Result<EventStream<T>, ClientError> {
+        let response = self.send(operation_id, method, path, query, body).await?;
+
+        Ok(EventStream::new(response))
+    }
+}
+impl std::fmt::Debug for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Client")
+            .field("provider", &self.provider)
+            .field("api_key", &self.api_key.as_ref().map(|_| "[REDACTED]"))
+            .field("headers", &self.headers)
+            .field("beta", &self.beta)
+            .field("retry", &self.retry)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The `data:` of each server-sent event in a response, read as `T`
+///  until the stream ends or sends `[DONE]`
+pub struct EventStream<T> {
+    status: u16,
+    headers: Vec<(String, String)>,
+    rate_limits: RateLimits,
+    body: ByteStream,
+    buffer: Vec<u8>,
+    pending: VecDeque<Result<T, ClientError>>,
+    done: bool,
+    _event: PhantomData<fn() -> T>,
+}
+impl<T: serde::de::DeserializeOwned> EventStream<T> {
+    /// Reads the events from a response's body
+    pub fn new(response: HttpResponse) -> Self {
+        EventStream {
+            status: response.status,
+            rate_limits: response.rate_limits(),
+            headers: response.headers,
+            body: response.body,
+            buffer: Vec::new(),
+            pending: VecDeque::new(),
+            done: false,
+            _event: PhantomData,
+        }
+    }
+    /// The rate limits the API reported when the stream started
+    pub fn rate_limits(&self) -> &RateLimits {
+        &self.rate_limits
+    }
+    /// Reads every complete event in the buffer, or all of it once the
+    ///  body has ended
+    fn read_events(&mut self, ended: bool) {
+        loop {
+            let end = self.buffer.windows(2).position(|window| window == b"\n\n");
+            let event = match end {
+                Some(end) => self.buffer.drain(..end + 2).collect::<Vec<u8>>(),
+                None if ended && !self.buffer.is_empty() => std::mem::take(&mut self.buffer),
+                None => return,
+            };
+
+            let event = String::from_utf8_lossy(&event);
+            let data = event.lines()
+                .filter_map(|line| line.strip_prefix("data:"))
+                .map(|data| data.strip_prefix(' ').unwrap_or(data))
+                .collect::<Vec<&str>>()
+                .join("\n");
+            if data.is_empty() {
+                continue;
+            }
+            if data == "[DONE]" {
+                self.done = true;
+                return;
+            }
+
+            self.pending.push_back(self.read_event(&event, &data));
+        }
+    }
+    /// Reads an event's data, or the error it sends instead, either as an
+    ///  `error` event or as an error response
+    fn read_event(&self, event: &str, data: &str) -> Result<T, ClientError> {
+        let is_error_event = event.lines()
+            .any(|line| line.strip_prefix("event:").is_some_and(|name| name.trim() == "error"));
+        if is_error_event {
+            let mut error = ApiError::new(self.status, &self.headers, data.as_bytes());
+            error.error = parse_error_object(data).or(error.error);
+            return Err(ClientError::Api(Box::new(error)));
+        }
+
+        decode(data.as_bytes()).map_err(|error| {
+            if parse_error_response(data.as_bytes()).is_some() {
+                ClientError::Api(Box::new(ApiError::new(self.status, &self.headers, data.as_bytes())))
+            } else {
+                error
+            }
+        })
+    }
+}
+// Nothing in the stream is pinned, whatever the event type
+impl<T> Unpin for EventStream<T> {}
+impl<T: serde::de::DeserializeOwned> Stream for EventStream<T> {
+    type Item = Result<T, ClientError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Poll::Ready(Some(event));
+            }
+            if self.done {
+                return Poll::Ready(None);
+            }
+
+            match self.body.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(chunk))) => {
+                    // Line endings are normalized so events split on `\n\n`
+                    self.buffer.extend(chunk.into_iter().filter(|byte| *byte != b'\r'));
+                    self.read_events(false);
+                },
+                Poll::Ready(Some(Err(error))) => {
+                    self.done = true;
+                    return Poll::Ready(Some(Err(error)));
+                },
+                Poll::Ready(None) => {
+                    self.read_events(true);
+                    self.done = true;
+                },
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Every item of a cursor-paginated list, fetched a page at a time as
+///  the stream is polled.
+///
+/// Each page is requested with `after` set to the previous page's
+///  `last_id`, or the `id` of its last item, until one says it doesn't
+///  have more.
+pub struct Paginated<T> {
+    client: Client,
+    operation_id: &'static str,
+    path: String,
+    query: Vec<(&'static str, String)>,
+    page_size: Option<i64>,
+    max_items: Option<usize>,
+    after: Option<String>,
+    items: VecDeque<Result<T, ClientError>>,
+    yielded: usize,
+    done: bool,
+    page: Option<BoxFuture<'static, Result<serde_json::Value, ClientError>>>,
+}
+impl<T: serde::de::DeserializeOwned> Paginated<T> {
+    /// Pages through an operation's list at a path, with the list's own
+    ///  query parameters. Any `after` cursor in them is where paging starts
+    pub fn new(
+        client: Client,
+        operation_id: &'static str,
+        path: String,
+        query: Vec<(&'static str, String)>,
+    ) -> Self {
+        let after = query.iter()
+            .find(|(name, _value)| *name == "after")
+            .map(|(_name, value)| value.clone());
+        let query = query.into_iter()
+            .filter(|(name, _value)| *name != "after")
+            .collect();
+
+        Paginated {
+            client,
+            operation_id,
+            path,
+            query,
+            page_size: None,
+            max_items: None,
+            after,
+            items: VecDeque::new(),
+            yielded: 0,
+            done: false,
+            page: None,
+        }
+    }
+    /// Asks for pages of this many items, instead of the query's `limit`
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+    /// Stops after this many items, without fetching pages past them
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    fn fetch_page(&self) -> BoxFuture<'static, Result<serde_json::Value, ClientError>> {
+        let mut query = self.query.clone();
+        if let Some(page_size) = self.page_size {
+            query.retain(|(name, _value)| *name != "limit");
+            query.push(("limit", page_size.to_string()));
+        }
+        if let Some(ref after) = self.after {
+            query.push(("after", after.clone()));
+        }
+
+        let client = self.client.clone();
+        let operation_id = self.operation_id;
+        let path = self.path.clone();
+        Box::pin(async move {
+            client.json(operation_id, "GET", &path, &query, None)
+                .await
+                .map(ApiResponse::into_inner)
+        })
+    }
+    /// Queues a page's items, and decides where the next page starts
+    fn read_page(&mut self, page: serde_json::Value) {
+        let items = match page.get("data") {
+            Some(serde_json::Value::Array(items)) => items.clone(),
+            _ => Vec::new(),
+        };
+        let cursor = page.get("last_id")
+            .and_then(|last_id| last_id.as_str())
+            .or_else(|| items.last().and_then(|item| item.get("id")).and_then(|id| id.as_str()))
+            .map(|cursor| cursor.to_string());
+        let has_more = page.get("has_more")
+            .and_then(|has_more| has_more.as_bool())
+            .unwrap_or(false);
+
+        // A page which doesn't move the cursor would be fetched forever
+        if !has_more || items.is_empty() || cursor.is_none() || cursor == self.after {
+            self.done = true;
+        }
+        self.after = cursor;
+
+        self.items.extend(items.into_iter().map(|item| decode(item.to_string().as_bytes())));
+    }
+}
+// Nothing in the stream is pinned, whatever the item type
+impl<T> Unpin for Paginated<T> {}
+impl<T: serde::de::DeserializeOwned> Stream for Paginated<T> {
+    type Item = Result<T, ClientError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if self.max_items.is_some_and(|max_items| self.yielded >= max_items) {
+                return Poll::Ready(None);
+            }
+            if let Some(item) = self.items.pop_front() {
+                self.yielded += 1;
+                return Poll::Ready(Some(item));
+            }
+            if self.done {
+                return Poll::Ready(None);
+            }
+
+            let mut page = match self.page.take() {
+                Some(page) => page,
+                None => self.fetch_page(),
+            };
+            match page.as_mut().poll(cx) {
+                Poll::Ready(Ok(page)) => self.read_page(page),
+                Poll::Ready(Err(error)) => {
+                    self.done = true;
+                    return Poll::Ready(Some(Err(error)));
+                },
+                Poll::Pending => {
+                    self.page = Some(page);
+                    return Poll::Pending;
+                },
+            }
+        }
+    }
+}
+
+/// Decodes a JSON body, noting where in it decoding failed
+fn decode<T: serde::de::DeserializeOwned>(body: &[u8]) -> Result<T, ClientError> {
+    let mut deserializer = serde_json::Deserializer::from_slice(body);
+    let decoded = serde_path_to_error::deserialize(&mut deserializer)
+        .map_err(|error| (error.path().to_string(), error.into_inner()))
+        .and_then(|value| deserializer.end()
+            .map(|()| value)
+            .map_err(|error| (String::new(), error)));
+
+    decoded.map_err(|(path, error)| ClientError::Decode(DecodeError {
+        path,
+        error,
+        body: String::from_utf8_lossy(body).into_owned(),
+    }))
+}
+/// The feature an `OpenAI-Beta` value is for, such as `assistants` for
+///  `assistants=v2`
+fn beta_feature(beta: &str) -> &str {
+    beta.split('=').next().unwrap_or(beta).trim()
+}
+/// The value of the first header with the given name, ignoring case
+fn header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers.iter()
+        .find(|(header, _value)| header.eq_ignore_ascii_case(name))
+        .map(|(_header, value)| value.as_str())
+}
+
+/// Percent-encodes a path segment or query value
+pub(crate) fn encode(value: &str) -> String {
+    let mut encoded = String::new();
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+
+    encoded
+}
@@ -0,0 +1,13 @@
+/// The error object an [`ErrorResponse`](super::ErrorResponse) carries
+pub type ErrorBody = super::Error;
+
+/// Reads the error out of an [`ErrorResponse`](super::ErrorResponse) body
+fn parse_error_response(body: &[u8]) -> Option<ErrorBody> {
+    serde_json::from_slice::<super::ErrorResponse>(body)
+        .ok()
+        .map(|response| response.error)
+}
+/// Reads a bare error object, as sent by an `error` event
+fn parse_error_object(data: &str) -> Option<ErrorBody> {
+    serde_json::from_str::<ErrorBody>(data).ok()
+}
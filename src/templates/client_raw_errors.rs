@@ -0,0 +1,23 @@
+/// The error object an error response carries. The spec these types
+///  were generated from doesn't describe one, so none is ever read, and
+///  [`ApiError`]s only keep the raw body
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorBody {
+    /// The error's code
+    pub code: Option<String>,
+    /// What went wrong
+    pub message: String,
+    /// The parameter the error relates to
+    pub param: Option<String>,
+    /// The kind of error
+    pub r#type: String,
+}
+
+/// Reads the error out of an error response's body, which isn't described
+fn parse_error_response(_body: &[u8]) -> Option<ErrorBody> {
+    None
+}
+/// Reads a bare error object, which isn't described
+fn parse_error_object(_data: &str) -> Option<ErrorBody> {
+    None
+}
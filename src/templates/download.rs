@@ -0,0 +1,128 @@
+//! Binary response bodies, such as file contents and generated speech,
+//!  which are streamed rather than read into memory.
+//!
+//! A [`Download`] is a [`Stream`] of chunks and an [`AsyncRead`], and
+//!  knows the content type and length the response was sent with. It can
+//!  also be written straight to a file with [`Download::save`].
+//!
+//! Needs the `tokio` crate, with the `fs` and `io-util` features.
+use super::client::{ByteStream, ClientError, HttpResponse};
+
+use std::path::Path;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_util::{Stream, StreamExt};
+use tokio::io::{AsyncRead, AsyncWriteExt, ReadBuf};
+
+/// A binary response body, read as it arrives
+pub struct Download {
+    content_type: Option<String>,
+    content_length: Option<u64>,
+    body: ByteStream,
+    // What's left of the chunk being read through `AsyncRead`
+    chunk: Vec<u8>,
+    position: usize,
+}
+impl Download {
+    /// The body of a response
+    pub fn new(response: HttpResponse) -> Self {
+        Download {
+            content_type: response.header("content-type").map(|content_type| content_type.to_string()),
+            content_length: response.header("content-length").and_then(|length| length.trim().parse().ok()),
+            body: response.body,
+            chunk: Vec::new(),
+            position: 0,
+        }
+    }
+
+    /// The `content-type` the response was sent with, such as `audio/mpeg`
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+    /// The `content-length` the response was sent with, which is missing
+    ///  when it's sent in chunks
+    pub fn content_length(&self) -> Option<u64> {
+        self.content_length
+    }
+
+    /// Reads the whole body
+    pub async fn bytes(mut self) -> Result<Vec<u8>, ClientError> {
+        let mut bytes = Vec::with_capacity(self.content_length.unwrap_or_default() as usize);
+        while let Some(chunk) = self.next().await {
+            bytes.extend(chunk?);
+        }
+
+        Ok(bytes)
+    }
+    /// Writes the body to a file as it arrives, replacing any file already
+    ///  there, and returns how many bytes were written. The file is removed
+    ///  if the body can't be read in full
+    pub async fn save(mut self, path: impl AsRef<Path>) -> Result<u64, ClientError> {
+        let path = path.as_ref();
+        let mut file = tokio::fs::File::create(path).await?;
+
+        let mut written = 0;
+        let result = async {
+            while let Some(chunk) = self.next().await {
+                let chunk = chunk?;
+                file.write_all(&chunk).await?;
+                written += chunk.len() as u64;
+            }
+            file.flush().await?;
+
+            Ok(written)
+        }.await;
+
+        if result.is_err() {
+            drop(file);
+            let _ = tokio::fs::remove_file(path).await;
+        }
+
+        result
+    }
+}
+impl Stream for Download {
+    type Item = Result<Vec<u8>, ClientError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // Whatever a reader left of a chunk comes first
+        if self.position < self.chunk.len() {
+            let position = self.position;
+            let chunk = std::mem::take(&mut self.chunk);
+            self.position = 0;
+            return Poll::Ready(Some(Ok(chunk[position..].to_vec())));
+        }
+
+        self.body.poll_next_unpin(cx)
+    }
+}
+impl AsyncRead for Download {
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<std::io::Result<()>> {
+        while self.position >= self.chunk.len() {
+            match self.body.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(chunk))) => {
+                    self.chunk = chunk;
+                    self.position = 0;
+                },
+                Poll::Ready(Some(Err(error))) => return Poll::Ready(Err(std::io::Error::other(error))),
+                Poll::Ready(None) => return Poll::Ready(Ok(())),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        let length = buf.remaining().min(self.chunk.len() - self.position);
+        buf.put_slice(&self.chunk[self.position..self.position + length]);
+        self.position += length;
+
+        Poll::Ready(Ok(()))
+    }
+}
+impl std::fmt::Debug for Download {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Download")
+            .field("content_type", &self.content_type)
+            .field("content_length", &self.content_length)
+            .finish_non_exhaustive()
+    }
+}
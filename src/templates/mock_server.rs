@@ -0,0 +1,287 @@
+//! An offline mock of the OpenAI API, for integration tests.
+//!
+//! Every operation in the spec is routed. JSON bodies are checked by
+//!  deserializing them as the generated request type, and answered with
+//!  the spec's example response, or a minimal one synthesized from the
+//!  response schema. Requests with `"stream": true` are answered with the
+//!  example's server-sent events, when the spec has any.
+//!
+//! Responses can be overridden per operation with [`MockServer::respond`],
+//!  or scripted one request at a time with [`MockServer::script`], such
+//!  as to fail twice before succeeding. Every request received is kept
+//!  for [`MockServer::requests`].
+//!
+//! Needs the `axum` and `tokio` crates.
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use axum::body::{Body, Bytes};
+use axum::http::{header, Method, StatusCode, Uri};
+use axum::Router;
+
+/// A response the mock server sends
+#[derive(Debug, Clone)]
+pub enum MockResponse {
+    /// A JSON body, with its status
+    Json(u16, serde_json::Value),
+    /// Server-sent events, each an optional `event:` name and its `data:`
+    Events(Vec<(Option<String>, String)>),
+    /// Any other body, with its status and content type
+    Raw(u16, String, Vec<u8>),
+    /// Another response, with extra headers
+    WithHeaders(Box<MockResponse>, Vec<(String, String)>),
+}
+impl MockResponse {
+    /// A successful JSON response
+    pub fn json ( value: serde_json::Value ) -> Self {
+        MockResponse::Json(200, value)
+    }
+    /// An error in the shape the API sends them
+    pub fn error ( status: u16, message: &str ) -> Self {
+        MockResponse::Json(status, serde_json::json!({
+            "error": {
+                "message": message,
+                "type": "invalid_request_error",
+                "param": null,
+                "code": null
+            }
+        }))
+    }
+    /// The same response, with an extra header such as `retry-after`
+    pub fn with_header ( self, name: &str, value: &str ) -> Self {
+        match self {
+            MockResponse::WithHeaders(response, mut headers) => {
+                headers.push((name.to_string(), value.to_string()));
+                MockResponse::WithHeaders(response, headers)
+            },
+            response => MockResponse::WithHeaders(Box::new(response), vec![(name.to_string(), value.to_string())])
+        }
+    }
+    fn into_response ( self ) -> axum::response::Response {
+        let (status, content_type, body) = match self {
+            MockResponse::WithHeaders(response, headers) => {
+                let mut response = response.into_response();
+                for (name, value) in headers {
+                    if let (Ok(name), Ok(value)) = (header::HeaderName::try_from(name), header::HeaderValue::try_from(value)) {
+                        response.headers_mut().insert(name, value);
+                    }
+                }
+
+                return response;
+            },
+            MockResponse::Json(status, value) => (status, "application/json".to_string(), value.to_string().into_bytes()),
+            MockResponse::Events(events) => {
+                let mut body = String::new();
+                for (name, data) in events {
+                    if let Some(name) = name {
+                        body.push_str(&format!("event: {}\n", name));
+                    }
+                    body.push_str(&format!("data: {}\n\n", data));
+                }
+
+                (200, "text/event-stream".to_string(), body.into_bytes())
+            },
+            MockResponse::Raw(status, content_type, body) => (status, content_type, body)
+        };
+
+        axum::response::Response::builder()
+            .status(StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR))
+            .header(header::CONTENT_TYPE, content_type)
+            .body(Body::from(body))
+            .unwrap_or_default()
+    }
+}
+
+/// A request the mock server received
+#[derive(Debug, Clone)]
+pub struct MockRequest {
+    /// The `operationId` it was routed to
+    pub operation_id: &'static str,
+    /// The HTTP method
+    pub method: String,
+    /// The path, without the `/v1` prefix
+    pub path: String,
+    /// The raw body
+    pub body: Bytes,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    overrides: HashMap<String, MockResponse>,
+    scripts: HashMap<String, VecDeque<MockResponse>>,
+    requests: Vec<MockRequest>,
+}
+
+/// A mock OpenAI API on a local port, which stops when dropped
+#[derive(Debug)]
+pub struct MockServer {
+    address: SocketAddr,
+    state: Arc<Mutex<MockState>>,
+    shutdown: Option<tokio::sync::oneshot::Sender<()>>,
+}
+impl MockServer {
+    /// Starts a mock server on a free local port, on the current runtime
+    pub async fn start () -> std::io::Result<Self> {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let (shutdown, shutdown_signal) = tokio::sync::oneshot::channel::<()>();
+
+        let handler_state = state.clone();
+        let router = Router::new()
+            .fallback(move |method: Method, uri: Uri, body: Bytes| {
+                let state = handler_state.clone();
+                async move { handle(&state, method, uri, body) }
+            });
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, router)
+                .with_graceful_shutdown(async move {
+                    let _ = shutdown_signal.await;
+                })
+                .await;
+        });
+
+        Ok(MockServer { address, state, shutdown: Some(shutdown) })
+    }
+    /// The address the server is listening on
+    pub fn address ( &self ) -> SocketAddr {
+        self.address
+    }
+    /// The base URL to point a client at, ending in `/v1`
+    pub fn base_url ( &self ) -> String {
+        format!("http://{}/v1", self.address)
+    }
+    /// Answers every later request to an operation with `response`
+    pub fn respond ( &self, operation_id: &str, response: MockResponse ) {
+        self.lock().overrides.insert(operation_id.to_string(), response);
+    }
+    /// Answers the next requests to an operation with `responses`, one
+    ///  each and in order, before going back to its usual response
+    pub fn script ( &self, operation_id: &str, responses: Vec<MockResponse> ) {
+        self.lock().scripts.entry(operation_id.to_string())
+            .or_default()
+            .extend(responses);
+    }
+    /// Goes back to answering an operation with its example
+    pub fn reset ( &self, operation_id: &str ) {
+        let mut state = self.lock();
+        state.overrides.remove(operation_id);
+        state.scripts.remove(operation_id);
+    }
+    /// Every request received so far, oldest first
+    pub fn requests ( &self ) -> Vec<MockRequest> {
+        self.lock().requests.clone()
+    }
+    fn lock ( &self ) -> std::sync::MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+impl Drop for MockServer {
+    fn drop ( &mut self ) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+fn handle ( state: &Mutex<MockState>, method: Method, uri: Uri, body: Bytes ) -> axum::response::Response {
+    let path = uri.path();
+    let path = path.strip_prefix("/v1").unwrap_or(path);
+
+    let operation = match find_operation(method.as_str(), path) {
+        Some(operation) => operation,
+        None => {
+            return MockResponse::error(404, &format!("No operation matches {} {}", method, path)).into_response();
+        }
+    };
+
+    let mut state = state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    state.requests.push(MockRequest {
+        operation_id: operation.id,
+        method: method.to_string(),
+        path: path.to_string(),
+        body: body.clone(),
+    });
+
+    if let Some(validate) = operation.validate {
+        if let Err(message) = validate(&body) {
+            return MockResponse::error(400, &format!("Invalid body for {}: {}", operation.id, message)).into_response();
+        }
+    }
+
+    if let Some(response) = state.scripts.get_mut(operation.id).and_then(|script| script.pop_front()) {
+        return response.into_response();
+    }
+    if let Some(response) = state.overrides.get(operation.id) {
+        return response.clone().into_response();
+    }
+
+    // Stream when asked to, if there's an example stream to replay
+    let wants_stream = serde_json::from_slice::<serde_json::Value>(&body)
+        .ok()
+        .and_then(|body| body["stream"].as_bool())
+        .unwrap_or(false);
+    if (wants_stream || operation.response.is_empty()) && !operation.events.is_empty() {
+        return MockResponse::Events(
+            operation.events.iter()
+                .map(|(name, data)| (name.map(|name| name.to_string()), data.to_string()))
+                .collect()
+        ).into_response();
+    }
+
+    MockResponse::Raw(operation.status, operation.content_type.to_string(), operation.response.as_bytes().to_vec())
+        .into_response()
+}
+/// The operation a request is for, preferring literal path segments over
+///  parameters, so `/files/search` wins over `/files/{file_id}`
+fn find_operation ( method: &str, path: &str ) -> Option<&'static MockOperation> {
+    let segments = path.trim_matches('/').split('/').collect::<Vec<&str>>();
+
+    OPERATIONS.iter()
+        .filter(|operation| operation.method == method)
+        .filter_map(|operation| {
+            let pattern = operation.path.trim_matches('/').split('/').collect::<Vec<&str>>();
+            if pattern.len() != segments.len() {
+                return None;
+            }
+
+            let mut literals = 0;
+            for (expected, actual) in pattern.iter().zip(segments.iter()) {
+                if expected.starts_with('{') && expected.ends_with('}') {
+                    continue;
+                }
+                if expected != actual {
+                    return None;
+                }
+                literals += 1;
+            }
+
+            Some((literals, operation))
+        })
+        .max_by_key(|(literals, _operation)| *literals)
+        .map(|(_literals, operation)| operation)
+}
+
+/// Checks a body by deserializing it as the request type
+// Unused when no enabled group has an operation with a typed body
+#[allow(dead_code)]
+fn validate<T: serde::de::DeserializeOwned> ( body: &[u8] ) -> Result<(), String> {
+    serde_json::from_slice::<T>(body)
+        .map(|_| ())
+        .map_err(|error| error.to_string())
+}
+
+/// Checks a request body, describing what's wrong with it
+type Validator = fn(&[u8]) -> Result<(), String>;
+/// An operation the mock server routes
+struct MockOperation {
+    id: &'static str,
+    method: &'static str,
+    path: &'static str,
+    validate: Option<Validator>,
+    status: u16,
+    content_type: &'static str,
+    response: &'static str,
+    events: &'static [(Option<&'static str>, &'static str)],
+}
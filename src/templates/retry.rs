@@ -0,0 +1,235 @@
+//! When and how long the client waits before retrying a failed call,
+//!  and the rate limits the API reports on each response.
+//!
+//! Calls are retried on connection failures and on `408`, `409`, `429`
+//!  and `5xx` statuses, except when out of quota, unless the API says
+//!  otherwise with `x-should-retry`. Only idempotent methods are retried
+//!  by default, since retrying a `POST` could repeat its effects;
+//!  operations which are safe to repeat anyway can be opted in by their
+//!  `operationId`.
+//!
+//! Waits grow exponentially with jitter, unless the API asks for a wait
+//!  with `retry-after-ms` or `retry-after`, and a call stops retrying
+//!  once another wait would take it past its budget.
+//!
+//! Needs the `tokio` crate, with the `time` feature.
+use super::client::ClientError;
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// How a client retries failed calls
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// How many times a call is retried after its first attempt
+    pub max_retries: u32,
+    /// The wait before the first retry, which doubles with each retry
+    pub initial_backoff: Duration,
+    /// The longest wait between retries, unless the API asks for longer
+    pub max_backoff: Duration,
+    /// The longest a call may take, attempts and waits included, for it
+    ///  to still be retried
+    pub budget: Duration,
+    /// Non-idempotent operations, by `operationId`, which are safe to retry
+    pub safe_operations: Vec<String>,
+}
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 2,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(8),
+            budget: Duration::from_secs(600),
+            safe_operations: Vec::new(),
+        }
+    }
+}
+impl RetryPolicy {
+    /// A policy which never retries
+    pub fn none() -> Self {
+        RetryPolicy { max_retries: 0, ..RetryPolicy::default() }
+    }
+    /// Retries a non-idempotent operation as well, such as
+    ///  `createEmbedding`, whose repeats are harmless
+    pub fn safe_operation(mut self, operation_id: impl Into<String>) -> Self {
+        self.safe_operations.push(operation_id.into());
+        self
+    }
+
+    /// Whether calls to an operation can be retried at all
+    pub fn allows(&self, operation_id: &str, method: &str) -> bool {
+        matches!(method, "GET" | "HEAD" | "PUT" | "DELETE" | "OPTIONS")
+            || self.safe_operations.iter().any(|safe| safe == operation_id)
+    }
+    /// Whether an error response, with these headers, is worth retrying
+    pub fn should_retry(&self, error: &ClientError, headers: &[(String, String)]) -> bool {
+        match header(headers, "x-should-retry") {
+            Some("true") => true,
+            Some("false") => false,
+            _ => error.is_retryable(),
+        }
+    }
+    /// How long to wait before a retry, where `retry` counts from zero
+    pub fn delay(&self, retry: u32, headers: &[(String, String)]) -> Duration {
+        if let Some(retry_after) = retry_after(headers) {
+            return retry_after;
+        }
+
+        // Up to a quarter is taken off, so clients don't retry in step
+        let backoff = self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_backoff);
+        backoff.mul_f64(1.0 - 0.25 * jitter())
+    }
+}
+
+/// The rate limits the API reported on a response, from its
+///  `x-ratelimit-*` headers
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RateLimits {
+    /// The most requests allowed before the limit resets
+    pub limit_requests: Option<u64>,
+    /// The most tokens allowed before the limit resets
+    pub limit_tokens: Option<u64>,
+    /// The requests left before the limit resets
+    pub remaining_requests: Option<u64>,
+    /// The tokens left before the limit resets
+    pub remaining_tokens: Option<u64>,
+    /// How long until the request limit resets
+    pub reset_requests: Option<Duration>,
+    /// How long until the token limit resets
+    pub reset_tokens: Option<Duration>,
+}
+impl RateLimits {
+    /// Reads the rate limits from a response's headers
+    pub fn from_headers(headers: &[(String, String)]) -> Self {
+        let number = |name: &str| header(headers, name).and_then(|value| value.trim().parse::<u64>().ok());
+        let duration = |name: &str| header(headers, name).and_then(parse_duration);
+
+        RateLimits {
+            limit_requests: number("x-ratelimit-limit-requests"),
+            limit_tokens: number("x-ratelimit-limit-tokens"),
+            remaining_requests: number("x-ratelimit-remaining-requests"),
+            remaining_tokens: number("x-ratelimit-remaining-tokens"),
+            reset_requests: duration("x-ratelimit-reset-requests"),
+            reset_tokens: duration("x-ratelimit-reset-tokens"),
+        }
+    }
+}
+
+/// Whether a status means the same request could succeed later
+pub fn is_retryable_status(status: u16) -> bool {
+    matches!(status, 408 | 409 | 429) || status >= 500
+}
+
+/// The wait a response asks for, from `retry-after-ms`, or from
+///  `retry-after` when it's a number of seconds rather than a date
+pub fn retry_after(headers: &[(String, String)]) -> Option<Duration> {
+    if let Some(milliseconds) = header(headers, "retry-after-ms").and_then(|value| value.trim().parse::<f64>().ok()) {
+        return Duration::try_from_secs_f64(milliseconds / 1000.0).ok();
+    }
+
+    header(headers, "retry-after")
+        .and_then(|value| value.trim().parse::<f64>().ok())
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+}
+
+/// Reads a duration such as `20ms`, `1s` or `6m0.5s`
+fn parse_duration(value: &str) -> Option<Duration> {
+    let mut total = Duration::ZERO;
+    let mut rest = value.trim();
+    if rest.is_empty() {
+        return None;
+    }
+
+    while !rest.is_empty() {
+        let number_end = rest.find(|character: char| !(character.is_ascii_digit() || character == '.'))
+            .unwrap_or(rest.len());
+        let number = rest[..number_end].parse::<f64>().ok()?;
+        rest = &rest[number_end..];
+
+        let unit_end = rest.find(|character: char| character.is_ascii_digit() || character == '.')
+            .unwrap_or(rest.len());
+        let seconds = match &rest[..unit_end] {
+            "ms" => number / 1000.0,
+            "s" | "" => number,
+            "m" => number * 60.0,
+            "h" => number * 3600.0,
+            _ => return None,
+        };
+        rest = &rest[unit_end..];
+
+        total += Duration::try_from_secs_f64(seconds).ok()?;
+    }
+
+    Some(total)
+}
+
+fn header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers.iter()
+        .find(|(header, _value)| header.eq_ignore_ascii_case(name))
+        .map(|(_header, value)| value.as_str())
+}
+/// A number between 0 and 1 which differs from call to call, without
+///  needing a random number crate
+fn jitter() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos())
+        .unwrap_or_default());
+
+    (hasher.finish() % 1_000_000) as f64 / 1_000_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs.iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("6m0.5s"), Some(Duration::from_millis(360_500)));
+        assert_eq!(parse_duration("20ms"), Some(Duration::from_millis(20)));
+        assert_eq!(parse_duration("1s"), Some(Duration::from_secs(1)));
+        assert_eq!(parse_duration("1h2m"), Some(Duration::from_secs(3720)));
+        assert_eq!(parse_duration("7"), Some(Duration::from_secs(7)));
+        assert_eq!(parse_duration(""), None);
+        assert_eq!(parse_duration("2 days"), None);
+    }
+
+    #[test]
+    fn reads_retry_after() {
+        assert_eq!(retry_after(&headers(&[("retry-after", "2")])), Some(Duration::from_secs(2)));
+        assert_eq!(retry_after(&headers(&[("Retry-After", "1.5")])), Some(Duration::from_millis(1500)));
+        assert_eq!(
+            retry_after(&headers(&[("retry-after", "30"), ("retry-after-ms", "250")])),
+            Some(Duration::from_millis(250))
+        );
+        // Dates aren't read, and leave the wait to the backoff
+        assert_eq!(retry_after(&headers(&[("retry-after", "Wed, 21 Oct 2015 07:28:00 GMT")])), None);
+        assert_eq!(retry_after(&[]), None);
+    }
+
+    #[test]
+    fn reads_rate_limits() {
+        let rate_limits = RateLimits::from_headers(&headers(&[
+            ("x-ratelimit-limit-requests", "60"),
+            ("x-ratelimit-remaining-tokens", "149984"),
+            ("x-ratelimit-reset-requests", "1s"),
+            ("x-ratelimit-reset-tokens", "6m0.5s"),
+        ]));
+
+        assert_eq!(rate_limits.limit_requests, Some(60));
+        assert_eq!(rate_limits.limit_tokens, None);
+        assert_eq!(rate_limits.remaining_tokens, Some(149984));
+        assert_eq!(rate_limits.reset_requests, Some(Duration::from_secs(1)));
+        assert_eq!(rate_limits.reset_tokens, Some(Duration::from_millis(360_500)));
+    }
+}
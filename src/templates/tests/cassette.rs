@@ -0,0 +1,81 @@
+//! Records a streamed chat completion through the mock server, then
+//!  replays it from the cassette without a server
+use openai_types::cassette::*;
+use openai_types::client::*;
+use openai_types::mock_server::*;
+use openai_types::*;
+
+use futures_util::StreamExt;
+
+const CHUNK: &str = r#"{"id":"chatcmpl_1","object":"chat.completion.chunk","created":1,"model":"gpt-4o","choices":[{"index":0,"delta":{"content":"Hi"},"logprobs":null,"finish_reason":"stop"}]}"#;
+
+fn request(temperature: f64) -> CreateChatCompletionRequest {
+    serde_json::from_value(serde_json::json!({
+        "model": "gpt-4o",
+        "messages": [{ "role": "user", "content": "Hello" }],
+        "temperature": temperature
+    }))
+    .unwrap()
+}
+async fn stream(client: &Client, request: &CreateChatCompletionRequest) -> Result<Vec<CreateChatCompletionStreamResponse>, ClientError> {
+    let mut stream = client.create_chat_completion_stream(request).await?;
+    let mut chunks = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        chunks.push(chunk?);
+    }
+
+    Ok(chunks)
+}
+
+#[tokio::test]
+async fn recorded_stream_replays_without_its_secrets() {
+    let path = std::env::temp_dir().join(format!("cassette-{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    // Record two chunks, with the key and organization as secrets
+    let server = MockServer::start().await.unwrap();
+    server.respond(
+        "createChatCompletion",
+        MockResponse::Events(vec![
+            (None, CHUNK.to_string()),
+            (None, CHUNK.to_string()),
+            (None, "[DONE]".to_string()),
+        ]),
+    );
+    let recorded = {
+        let transport = CassetteTransport::record(&path, ReqwestTransport::default()).redact("org-secret");
+        let client = Client::new("sk-secret")
+            .with_base_url(server.base_url())
+            .with_header("OpenAI-Organization", "org-secret")
+            .with_transport(transport);
+
+        stream(&client, &request(0.5)).await.unwrap()
+    };
+    drop(server);
+    assert_eq!(recorded.len(), 2);
+
+    let written = std::fs::read_to_string(&path).unwrap();
+    assert!(!written.contains("sk-secret"), "{written}");
+    assert!(!written.contains("org-secret"), "{written}");
+    assert!(written.contains(REDACTED));
+
+    // Replaying needs no server, and gives back the same chunks
+    let client = Client::new("sk-other")
+        .with_base_url("http://127.0.0.1:9/v1")
+        .with_transport(CassetteTransport::replay(&path).unwrap());
+    assert_eq!(stream(&client, &request(0.5)).await.unwrap(), recorded);
+
+    // A different body only matches once the field is ignored
+    let client = Client::new("sk-other")
+        .with_base_url("http://127.0.0.1:9/v1")
+        .with_transport(CassetteTransport::replay(&path).unwrap());
+    assert!(stream(&client, &request(1.0)).await.is_err());
+
+    let matching = Matching { ignore_body_fields: vec!["temperature".to_string()], ..Default::default() };
+    let client = Client::new("sk-other")
+        .with_base_url("http://127.0.0.1:9/v1")
+        .with_transport(CassetteTransport::replay(&path).unwrap().with_matching(matching));
+    assert_eq!(stream(&client, &request(1.0)).await.unwrap(), recorded);
+
+    let _ = std::fs::remove_file(&path);
+}
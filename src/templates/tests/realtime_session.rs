@@ -0,0 +1,77 @@
+//! Drives a Realtime session against an in-process WebSocket server
+use openai_types::realtime_session::RealtimeSession;
+use openai_types::*;
+
+use futures_util::{SinkExt, StreamExt};
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::{
+    handshake::server::{Request, Response},
+    Message,
+};
+
+#[tokio::test]
+async fn session_sends_and_receives_events() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}/v1/realtime", listener.local_addr().unwrap());
+
+    // Echo one client event back as a server event, after a ping
+    let server = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut query = None;
+        let mut authorization = None;
+        let mut socket = tokio_tungstenite::accept_hdr_async(stream, |request: &Request, response: Response| {
+            query = request.uri().query().map(|query| query.to_string());
+            authorization = request
+                .headers()
+                .get("Authorization")
+                .map(|value| value.to_str().unwrap().to_string());
+
+            Ok(response)
+        })
+        .await
+        .unwrap();
+
+        let received = socket.next().await.unwrap().unwrap();
+        socket.send(Message::Ping(Vec::new().into())).await.unwrap();
+        socket
+            .send(Message::text(r#"{"type":"input_audio_buffer.cleared","event_id":"event_1"}"#))
+            .await
+            .unwrap();
+        socket.close(None).await.unwrap();
+
+        // Wait for the client to acknowledge the close
+        while socket.next().await.is_some() {}
+
+        (query, authorization, received)
+    });
+
+    let mut session = RealtimeSession::connect_to(&url, "sk-test", "gpt-4o realtime&preview")
+        .await
+        .unwrap();
+    session
+        .send(&RealtimeClientEvent::InputAudioBufferClear(
+            RealtimeClientEventInputAudioBufferClear {
+                event_id: Some("client_1".to_string()),
+            },
+        ))
+        .await
+        .unwrap();
+
+    let event = session.recv().await.unwrap().unwrap();
+    assert_eq!(
+        event,
+        RealtimeServerEvent::InputAudioBufferCleared(RealtimeServerEventInputAudioBufferCleared {
+            event_id: "event_1".to_string(),
+        })
+    );
+    assert!(session.recv().await.is_none());
+
+    let (query, authorization, received) = server.await.unwrap();
+    assert_eq!(query.as_deref(), Some("model=gpt-4o%20realtime%26preview"));
+    assert_eq!(authorization.as_deref(), Some("Bearer sk-test"));
+    let received: serde_json::Value = serde_json::from_str(received.to_text().unwrap()).unwrap();
+    assert_eq!(
+        received,
+        serde_json::json!({"type": "input_audio_buffer.clear", "event_id": "client_1"})
+    );
+}
@@ -0,0 +1,104 @@
+//! Retries against the mock server, scripted to fail
+use openai_types::client::*;
+use openai_types::mock_server::*;
+use openai_types::retry::*;
+use openai_types::*;
+
+use std::time::{Duration, Instant};
+
+/// A policy which retries twice, without waiting long
+fn fast() -> RetryPolicy {
+    RetryPolicy {
+        max_retries: 2,
+        initial_backoff: Duration::from_millis(10),
+        max_backoff: Duration::from_millis(50),
+        ..Default::default()
+    }
+}
+fn embedding_request() -> CreateEmbeddingRequest {
+    serde_json::from_value(serde_json::json!({ "model": "text-embedding-3-small", "input": "Hello" })).unwrap()
+}
+
+#[tokio::test]
+async fn retries_until_a_response_succeeds() {
+    let server = MockServer::start().await.unwrap();
+    let client = Client::new("sk-test").with_base_url(server.base_url()).with_retry(fast());
+
+    server.script(
+        "listModels",
+        vec![
+            MockResponse::error(500, "Something broke"),
+            MockResponse::error(429, "Slow down").with_header("retry-after-ms", "20"),
+            MockResponse::json(serde_json::json!({ "object": "list", "data": [] }))
+                .with_header("x-ratelimit-remaining-requests", "59")
+                .with_header("x-ratelimit-reset-tokens", "6m0.5s"),
+        ],
+    );
+    let models = client.list_models().await.unwrap();
+    assert_eq!(server.requests().len(), 3);
+    assert_eq!(models.rate_limits.remaining_requests, Some(59));
+    assert_eq!(models.rate_limits.reset_tokens, Some(Duration::from_millis(360_500)));
+}
+
+#[tokio::test]
+async fn gives_up_after_the_last_retry() {
+    let server = MockServer::start().await.unwrap();
+    let client = Client::new("sk-test").with_base_url(server.base_url()).with_retry(fast());
+
+    server.script("listModels", vec![MockResponse::error(503, "Overloaded"); 3]);
+    let error = client.list_models().await.unwrap_err();
+    assert_eq!(error.status(), Some(503));
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn only_retries_what_can_be_retried() {
+    let server = MockServer::start().await.unwrap();
+    let client = Client::new("sk-test").with_base_url(server.base_url()).with_retry(fast());
+
+    // A bad request, or a server asking not to be retried, fails at once
+    server.script(
+        "listModels",
+        vec![
+            MockResponse::error(400, "Bad request"),
+            MockResponse::error(500, "Don't retry").with_header("x-should-retry", "false"),
+        ],
+    );
+    assert_eq!(client.list_models().await.unwrap_err().status(), Some(400));
+    assert_eq!(client.list_models().await.unwrap_err().status(), Some(500));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn posts_are_only_retried_when_marked_safe() {
+    let server = MockServer::start().await.unwrap();
+    let client = Client::new("sk-test").with_base_url(server.base_url()).with_retry(fast());
+
+    server.script("createEmbedding", vec![MockResponse::error(500, "Something broke")]);
+    client.create_embedding(&embedding_request()).await.unwrap_err();
+    assert_eq!(server.requests().len(), 1);
+
+    let client = client.with_retry(fast().safe_operation("createEmbedding"));
+    server.script("createEmbedding", vec![MockResponse::error(500, "Something broke")]);
+    client.create_embedding(&embedding_request()).await.unwrap();
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn stops_when_the_wait_would_exceed_the_budget() {
+    let server = MockServer::start().await.unwrap();
+    let policy = RetryPolicy { budget: Duration::from_millis(100), ..fast() };
+    let client = Client::new("sk-test").with_base_url(server.base_url()).with_retry(policy);
+
+    server.script(
+        "listModels",
+        vec![
+            MockResponse::error(429, "Slow down").with_header("retry-after", "30"),
+            MockResponse::error(500, "Never sent"),
+        ],
+    );
+    let started = Instant::now();
+    assert_eq!(client.list_models().await.unwrap_err().status(), Some(429));
+    assert!(started.elapsed() < Duration::from_secs(1));
+    assert_eq!(server.requests().len(), 1);
+}